@@ -0,0 +1,155 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![no_main]
+
+use std::collections::BTreeSet;
+
+use futures::TryStreamExt;
+use libfuzzer_sys::arbitrary::Arbitrary;
+use libfuzzer_sys::arbitrary::Unstructured;
+use libfuzzer_sys::fuzz_target;
+use opendal::raw::normalize_path;
+use opendal::raw::tests::init_test_service;
+use opendal::raw::tests::TEST_RUNTIME;
+use opendal::ErrorKind;
+use opendal::Operator;
+use opendal::Result;
+use tracing::warn;
+
+const MAX_FILES: usize = 16;
+const MAX_DEPTH: usize = 8;
+const MAX_SEGMENT_CHARS: usize = 32;
+
+#[derive(Debug, Clone)]
+struct FuzzInput {
+    /// The dir that all files will be written into.
+    dir: String,
+    /// Paths of files relative to dir.
+    paths: Vec<String>,
+}
+
+/// Generate a segment with arbitrary unicode and control chars except `/` and `\0`.
+///
+/// Segment will be prefixed with given char so that it will never be empty, `.` or `..`.
+fn arbitrary_segment(u: &mut Unstructured<'_>, prefix: char) -> arbitrary::Result<String> {
+    let mut segment = String::from(prefix);
+    let count = u.int_in_range(0..=MAX_SEGMENT_CHARS)?;
+    for _ in 0..count {
+        let c: char = u.arbitrary()?;
+        if c != '/' && c != '\0' {
+            segment.push(c);
+        }
+    }
+    Ok(segment)
+}
+
+impl Arbitrary<'_> for FuzzInput {
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        let count = u.int_in_range(1..=MAX_FILES)?;
+        let mut paths = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let depth = u.int_in_range(0..=MAX_DEPTH)?;
+            let mut path = String::new();
+            // Dirs and files use different prefix, so a file will never conflict with a dir.
+            for _ in 0..depth {
+                path.push_str(&arbitrary_segment(u, 'd')?);
+                // Use duplicated `/` sometimes to make sure they are normalized.
+                path.push_str(if u.ratio(1, 8)? { "//" } else { "/" });
+            }
+            path.push_str(&arbitrary_segment(u, 'f')?);
+            paths.push(path);
+        }
+
+        Ok(FuzzInput {
+            dir: format!("{}/", uuid::Uuid::new_v4()),
+            paths,
+        })
+    }
+}
+
+async fn fuzz_path(op: Operator, input: FuzzInput) -> Result<()> {
+    let cap = op.info().full_capability();
+
+    let mut expected = BTreeSet::new();
+    for path in &input.paths {
+        let path = format!("{}{}", input.dir, path);
+        op.write(&path, path.clone()).await?;
+        expected.insert(normalize_path(&path));
+    }
+
+    for path in &expected {
+        let meta = op.stat(path).await?;
+        assert!(meta.is_file(), "stat {path:?} must be file");
+        assert_eq!(
+            meta.content_length(),
+            op.read(path).await?.len() as u64,
+            "stat {path:?} must return the same length with read"
+        );
+    }
+
+    if cap.list && cap.list_with_recursive {
+        let actual = op
+            .lister_with(&input.dir)
+            .recursive(true)
+            .await?
+            .try_filter(|entry| futures::future::ready(!entry.path().ends_with('/')))
+            .map_ok(|entry| entry.path().to_string())
+            .try_collect::<BTreeSet<_>>()
+            .await?;
+        assert_eq!(
+            actual, expected,
+            "list must return exactly the written paths"
+        );
+    }
+
+    for path in &expected {
+        op.delete(path).await?;
+        let err = op
+            .stat(path)
+            .await
+            .expect_err("stat must fail after delete");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    op.remove_all(&input.dir).await?;
+    Ok(())
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let _ = tracing_subscriber::fmt()
+        .pretty()
+        .with_test_writer()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .try_init();
+
+    let op = init_test_service().expect("operator init must succeed");
+    if let Some(op) = op {
+        let cap = op.info().full_capability();
+        if !(cap.write && cap.stat && cap.delete) {
+            warn!("service doesn't support write, stat and delete, skip fuzzing");
+            return;
+        }
+
+        TEST_RUNTIME.block_on(async {
+            fuzz_path(op, input.clone())
+                .await
+                .unwrap_or_else(|err| panic!("fuzz path must succeed: {err:?}"));
+        })
+    }
+});
@@ -35,6 +35,8 @@ struct FuzzInput {
     actions: Vec<WriteAction>,
     buffer: Option<usize>,
     concurrent: Option<usize>,
+    /// Drive the writer via `FuturesAsyncWriter` instead.
+    futures_async_write: bool,
 }
 
 impl Arbitrary<'_> for FuzzInput {
@@ -50,18 +52,34 @@ impl Arbitrary<'_> for FuzzInput {
         } else {
             None
         };
+        let futures_async_write = u.int_in_range(0..=1)? == 1;
 
         let count = u.int_in_range(1..=1024)?;
 
         for _ in 0..count {
-            let size = u.int_in_range(1..=MAX_DATA_SIZE)?;
-            actions.push(WriteAction::Write(size));
+            match u.int_in_range(0..=9)? {
+                0 => actions.push(WriteAction::Flush),
+                // FuturesAsyncWriter doesn't support abort.
+                1 if !futures_async_write => {
+                    actions.push(WriteAction::Abort);
+                    break;
+                }
+                2 => {
+                    actions.push(WriteAction::Close);
+                    break;
+                }
+                _ => {
+                    let size = u.int_in_range(1..=MAX_DATA_SIZE)?;
+                    actions.push(WriteAction::Write(size));
+                }
+            }
         }
 
         Ok(FuzzInput {
             actions,
             buffer,
             concurrent,
+            futures_async_write,
         })
     }
 }
@@ -69,15 +87,7 @@ impl Arbitrary<'_> for FuzzInput {
 async fn fuzz_writer(op: Operator, input: FuzzInput) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
 
-    let total_size = input
-        .actions
-        .iter()
-        .map(|a| match a {
-            WriteAction::Write(size) => *size,
-        })
-        .collect();
-
-    let checker = WriteChecker::new(total_size);
+    let checker = WriteChecker::with_actions(&input.actions);
 
     let mut writer = op.writer_with(&path);
     if let Some(buffer) = input.buffer {
@@ -89,18 +99,23 @@ async fn fuzz_writer(op: Operator, input: FuzzInput) -> Result<()> {
         writer = writer.concurrent(concurrent);
     }
 
-    let mut writer = writer.await?;
-
-    for chunk in checker.chunks() {
-        writer.write(chunk.clone()).await?;
+    let writer = writer.await?;
+
+    if input.futures_async_write {
+        checker
+            .check_futures_async_writer(
+                &op,
+                &path,
+                writer.into_futures_async_write(),
+                &input.actions,
+            )
+            .await;
+    } else {
+        checker
+            .check_writer(&op, &path, writer, &input.actions)
+            .await;
     }
 
-    writer.close().await?;
-
-    let result = op.read(&path).await?.to_bytes();
-
-    checker.check(&result);
-
     op.delete(&path).await?;
     Ok(())
 }
@@ -122,7 +137,7 @@ fuzz_target!(|input: FuzzInput| {
         TEST_RUNTIME.block_on(async {
             fuzz_writer(op, input.clone())
                 .await
-                .unwrap_or_else(|err| panic!("fuzz reader must succeed: {err:?}"));
+                .unwrap_or_else(|err| panic!("fuzz writer must succeed: {err:?}"));
         })
     }
 });
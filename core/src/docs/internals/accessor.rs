@@ -270,6 +270,22 @@
 //! }
 //! ```
 //!
+//! Most of the code above is boilerplate, services can generate it via
+//! macros in [`raw`] instead:
+//!
+//! ```ignore
+//! impl_config_debug!(DuckConfig { root });
+//!
+//! impl_configurator!(DuckConfig => DuckBuilder);
+//!
+//! builder_setters!(DuckBuilder {
+//!     /// Set root of this backend.
+//!     ///
+//!     /// All operations will happen under this root.
+//!     root: &str,
+//! });
+//! ```
+//!
 //! `DuckBuilder` is ready now, let's try to play with real ducks!
 //!
 //! ## Backend
@@ -328,3 +344,4 @@
 //! [`AccessorInfo`]: crate::raw::AccessorInfo
 //! [`Scheme`]: crate::Scheme
 //! [`Builder`]: crate::Builder
+//! [`raw`]: crate::raw
@@ -0,0 +1,754 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::info;
+
+use crate::raw::*;
+use crate::*;
+
+/// Record every mutating operation and optionally suppress its execution.
+///
+/// # Audit
+///
+/// - `create_dir`, `write`, `delete`, `copy`, `rename` and `batch` (and their blocking
+///   variants) will be reported to the [`AuditInterceptor`] as an [`AuditRecord`].
+/// - Writes are reported once the writer is closed, so that the total size is known.
+///   Aborted writes are not reported.
+/// - Read-only operations like `read`, `stat` and `list` are passed through untouched.
+///
+/// # Dry Run
+///
+/// With [`AuditLayer::with_dry_run`] enabled, the mutating operations are only
+/// recorded and never reach the underlying service. They will return an empty
+/// reply as if they succeeded, which makes it possible for tools to offer a
+/// `--dry-run` mode backed by OpenDAL itself.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::AuditLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+/// use opendal::Scheme;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(AuditLayer::default().with_dry_run(true))
+///     .finish();
+/// ```
+///
+/// # Audit Interceptor
+///
+/// You can implement your own audit interceptor to collect the planned mutations.
+///
+/// ```no_run
+/// use opendal::layers::AuditInterceptor;
+/// use opendal::layers::AuditLayer;
+/// use opendal::layers::AuditRecord;
+/// use opendal::raw;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// #[derive(Debug, Clone)]
+/// struct MyAuditInterceptor;
+///
+/// impl AuditInterceptor for MyAuditInterceptor {
+///     fn audit(&self, info: &raw::AccessorInfo, record: &AuditRecord) {
+///         println!("{} {}", record.operation(), record.path());
+///     }
+/// }
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(AuditLayer::new(MyAuditInterceptor).with_dry_run(true))
+///     .finish();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AuditLayer<I = DefaultAuditInterceptor> {
+    auditor: I,
+    dry_run: bool,
+}
+
+impl Default for AuditLayer {
+    fn default() -> Self {
+        Self {
+            auditor: DefaultAuditInterceptor,
+            dry_run: false,
+        }
+    }
+}
+
+impl AuditLayer {
+    /// Create the layer with specific audit interceptor.
+    pub fn new<I: AuditInterceptor>(auditor: I) -> AuditLayer<I> {
+        AuditLayer {
+            auditor,
+            dry_run: false,
+        }
+    }
+}
+
+impl<I: AuditInterceptor> AuditLayer<I> {
+    /// Set whether mutating operations should be suppressed.
+    ///
+    /// Default to `false`.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+impl<A: Access, I: AuditInterceptor> Layer<A> for AuditLayer<I> {
+    type LayeredAccess = AuditAccessor<A, I>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        let info = inner.info();
+        AuditAccessor {
+            inner,
+
+            info,
+            auditor: self.auditor.clone(),
+            dry_run: self.dry_run,
+        }
+    }
+}
+
+/// AuditInterceptor is used to receive the records of mutating operations.
+pub trait AuditInterceptor: Debug + Clone + Send + Sync + Unpin + 'static {
+    /// Everytime a mutating operation is planned, this function will be called.
+    ///
+    /// # Inputs
+    ///
+    /// - info: The service's access info.
+    /// - record: The record of the mutating operation.
+    ///
+    /// # Note
+    ///
+    /// This function is called before the operation is sent to the service (except for
+    /// `write`, which is reported at close), so the operation could still fail afterwards.
+    fn audit(&self, info: &AccessorInfo, record: &AuditRecord);
+}
+
+/// The DefaultAuditInterceptor will log the record by the standard logging macro.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct DefaultAuditInterceptor;
+
+impl AuditInterceptor for DefaultAuditInterceptor {
+    #[inline]
+    fn audit(&self, info: &AccessorInfo, record: &AuditRecord) {
+        info!(
+            target: AUDIT_TARGET,
+            "service={} name={}: {} path={}{}{} dry_run={}",
+            info.scheme(),
+            info.name(),
+            record.operation(),
+            record.path(),
+            record
+                .target()
+                .map(|v| format!(" target={v}"))
+                .unwrap_or_default(),
+            record
+                .size()
+                .map(|v| format!(" size={v}"))
+                .unwrap_or_default(),
+            record.dry_run(),
+        );
+    }
+}
+
+static AUDIT_TARGET: &str = "opendal::audit";
+
+/// AuditRecord describes a mutating operation observed by [`AuditLayer`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct AuditRecord {
+    operation: Operation,
+    path: String,
+    target: Option<String>,
+    size: Option<u64>,
+    dry_run: bool,
+}
+
+impl AuditRecord {
+    fn new(operation: Operation, path: &str, dry_run: bool) -> Self {
+        Self {
+            operation,
+            path: path.to_string(),
+            target: None,
+            size: None,
+            dry_run,
+        }
+    }
+
+    fn with_target(mut self, target: &str) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+
+    fn with_size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Get the operation of this record.
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Get the path of this record.
+    ///
+    /// For `copy` and `rename`, this is the source path.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get the target path of this record.
+    ///
+    /// Only `copy` and `rename` have a target path.
+    pub fn target(&self) -> Option<&str> {
+        self.target.as_deref()
+    }
+
+    /// Get the size in bytes of this record.
+    ///
+    /// Only `write`, `write_at`, `allocate` and `stage_block` have a size.
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Whether the operation is suppressed by dry run.
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AuditAccessor<A: Access, I: AuditInterceptor> {
+    inner: A,
+
+    info: Arc<AccessorInfo>,
+    auditor: I,
+    dry_run: bool,
+}
+
+impl<A: Access, I: AuditInterceptor> AuditAccessor<A, I> {
+    fn audit(&self, record: AuditRecord) {
+        self.auditor.audit(&self.info, &record);
+    }
+
+    fn audit_batch(&self, args: &OpBatch) {
+        for (path, op) in args.operation() {
+            self.audit(AuditRecord::new(op.operation(), path, self.dry_run));
+        }
+    }
+
+    fn dry_run_batch(args: OpBatch) -> RpBatch {
+        RpBatch::new(
+            args.into_operation()
+                .into_iter()
+                .map(|(path, op)| match op {
+                    BatchOperation::Delete(_) => (path, Ok(RpDelete::default().into())),
+                    BatchOperation::Stat(_) => (
+                        path,
+                        Err(Error::new(
+                            ErrorKind::Unsupported,
+                            "stat mixed with delete in batch is not supported in dry run",
+                        )),
+                    ),
+                })
+                .collect(),
+        )
+    }
+
+    fn writer<W>(&self, operation: Operation, path: &str, inner: Option<W>) -> AuditWriter<W, I> {
+        AuditWriter {
+            inner,
+            info: self.info.clone(),
+            auditor: self.auditor.clone(),
+            operation,
+            path: path.to_string(),
+            dry_run: self.dry_run,
+            written: 0,
+        }
+    }
+}
+
+impl<A: Access, I: AuditInterceptor> LayeredAccess for AuditAccessor<A, I> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = AuditWriter<A::Writer, I>;
+    type BlockingWriter = AuditWriter<A::BlockingWriter, I>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.audit(AuditRecord::new(Operation::CreateDir, path, self.dry_run));
+        if self.dry_run {
+            return Ok(RpCreateDir::default());
+        }
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        if self.dry_run {
+            return Ok((
+                RpWrite::default(),
+                self.writer(Operation::Write, path, None),
+            ));
+        }
+
+        let (rp, w) = self.inner.write(path, args).await?;
+        Ok((rp, self.writer(Operation::Write, path, Some(w))))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.audit(AuditRecord::new(Operation::Copy, from, self.dry_run).with_target(to));
+        if self.dry_run {
+            return Ok(RpCopy::default());
+        }
+        self.inner.copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.audit(AuditRecord::new(Operation::Rename, from, self.dry_run).with_target(to));
+        if self.dry_run {
+            return Ok(RpRename::default());
+        }
+        self.inner.rename(from, to, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.audit(AuditRecord::new(Operation::Delete, path, self.dry_run));
+        if self.dry_run {
+            return Ok(RpDelete::default());
+        }
+        self.inner.delete(path, args).await
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        self.audit(
+            AuditRecord::new(Operation::WriteAt, path, self.dry_run)
+                .with_size(args.body().len() as u64),
+        );
+        if self.dry_run {
+            return Ok(RpWriteAt::default());
+        }
+        self.inner.write_at(path, args).await
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        self.audit(
+            AuditRecord::new(Operation::Allocate, path, self.dry_run).with_size(args.size()),
+        );
+        if self.dry_run {
+            return Ok(RpAllocate::default());
+        }
+        self.inner.allocate(path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.audit(AuditRecord::new(Operation::Concat, path, self.dry_run));
+        if self.dry_run {
+            return Ok(RpConcat::default());
+        }
+        self.inner.concat(path, args).await
+    }
+
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        self.audit(AuditRecord::new(Operation::SetLifecycle, "/", self.dry_run));
+        if self.dry_run {
+            return Ok(RpSetLifecycle::default());
+        }
+        self.inner.set_lifecycle(args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        self.audit(AuditRecord::new(
+            Operation::SetRetention,
+            path,
+            self.dry_run,
+        ));
+        if self.dry_run {
+            return Ok(RpSetRetention::default());
+        }
+        self.inner.set_retention(path, args).await
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        self.audit(AuditRecord::new(Operation::SetAcl, path, self.dry_run));
+        if self.dry_run {
+            return Ok(RpSetAcl::default());
+        }
+        self.inner.set_acl(path, args).await
+    }
+
+    async fn create_bucket(&self, args: OpCreateBucket) -> Result<RpCreateBucket> {
+        self.audit(AuditRecord::new(Operation::CreateBucket, "/", self.dry_run));
+        if self.dry_run {
+            return Ok(RpCreateBucket::default());
+        }
+        self.inner.create_bucket(args).await
+    }
+
+    async fn delete_bucket(&self, args: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        self.audit(AuditRecord::new(Operation::DeleteBucket, "/", self.dry_run));
+        if self.dry_run {
+            return Ok(RpDeleteBucket::default());
+        }
+        self.inner.delete_bucket(args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        self.audit(AuditRecord::new(
+            Operation::AbortMultipartUpload,
+            path,
+            self.dry_run,
+        ));
+        if self.dry_run {
+            return Ok(RpAbortMultipartUpload::default());
+        }
+        self.inner.abort_multipart_upload(path, args).await
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        self.audit(
+            AuditRecord::new(Operation::StageBlock, path, self.dry_run)
+                .with_size(args.body().len() as u64),
+        );
+        if self.dry_run {
+            return Ok(RpStageBlock::default());
+        }
+        self.inner.stage_block(path, args).await
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        self.audit(AuditRecord::new(
+            Operation::CommitBlockList,
+            path,
+            self.dry_run,
+        ));
+        if self.dry_run {
+            return Ok(RpCommitBlockList::default());
+        }
+        self.inner.commit_block_list(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.audit_batch(&args);
+        // Batched stats are read only and still sent in dry run like `stat`.
+        let has_delete = args
+            .operation()
+            .iter()
+            .any(|(_, op)| matches!(op, BatchOperation::Delete(_)));
+        if self.dry_run && has_delete {
+            return Ok(Self::dry_run_batch(args));
+        }
+        self.inner.batch(args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.audit(AuditRecord::new(
+            Operation::BlockingCreateDir,
+            path,
+            self.dry_run,
+        ));
+        if self.dry_run {
+            return Ok(RpCreateDir::default());
+        }
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        if self.dry_run {
+            return Ok((
+                RpWrite::default(),
+                self.writer(Operation::BlockingWrite, path, None),
+            ));
+        }
+
+        let (rp, w) = self.inner.blocking_write(path, args)?;
+        Ok((rp, self.writer(Operation::BlockingWrite, path, Some(w))))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.audit(AuditRecord::new(Operation::BlockingCopy, from, self.dry_run).with_target(to));
+        if self.dry_run {
+            return Ok(RpCopy::default());
+        }
+        self.inner.blocking_copy(from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.audit(AuditRecord::new(Operation::BlockingRename, from, self.dry_run).with_target(to));
+        if self.dry_run {
+            return Ok(RpRename::default());
+        }
+        self.inner.blocking_rename(from, to, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.audit(AuditRecord::new(
+            Operation::BlockingDelete,
+            path,
+            self.dry_run,
+        ));
+        if self.dry_run {
+            return Ok(RpDelete::default());
+        }
+        self.inner.blocking_delete(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// AuditWriter counts the written bytes and reports the write while closing.
+///
+/// `inner` will be `None` while dry run is enabled, and all input will be discarded.
+pub struct AuditWriter<W, I: AuditInterceptor> {
+    inner: Option<W>,
+
+    info: Arc<AccessorInfo>,
+    auditor: I,
+    operation: Operation,
+    path: String,
+    dry_run: bool,
+    written: u64,
+}
+
+impl<W, I: AuditInterceptor> AuditWriter<W, I> {
+    fn audit(&self) {
+        let record =
+            AuditRecord::new(self.operation, &self.path, self.dry_run).with_size(self.written);
+        self.auditor.audit(&self.info, &record);
+    }
+}
+
+impl<W: oio::Write, I: AuditInterceptor> oio::Write for AuditWriter<W, I> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let size = bs.len();
+        if let Some(w) = self.inner.as_mut() {
+            w.write(bs).await?;
+        }
+        self.written += size as u64;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.audit();
+        if let Some(w) = self.inner.as_mut() {
+            w.close().await?;
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        if let Some(w) = self.inner.as_mut() {
+            w.abort().await?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: oio::BlockingWrite, I: AuditInterceptor> oio::BlockingWrite for AuditWriter<W, I> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        let size = bs.len();
+        if let Some(w) = self.inner.as_mut() {
+            w.write(bs)?;
+        }
+        self.written += size as u64;
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.audit();
+        if let Some(w) = self.inner.as_mut() {
+            w.close()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::services::Memory;
+
+    #[derive(Debug, Clone, Default)]
+    struct RecordingInterceptor {
+        records: Arc<Mutex<Vec<AuditRecord>>>,
+    }
+
+    impl AuditInterceptor for RecordingInterceptor {
+        fn audit(&self, _: &AccessorInfo, record: &AuditRecord) {
+            self.records.lock().unwrap().push(record.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit() {
+        let auditor = RecordingInterceptor::default();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(AuditLayer::new(auditor.clone()))
+            .finish();
+
+        op.write("test", "hello").await.unwrap();
+        op.create_dir("dir/").await.unwrap();
+        op.delete("test").await.unwrap();
+        assert!(!op.is_exist("test").await.unwrap());
+        assert!(op.is_exist("dir/").await.unwrap());
+
+        let records = auditor.records.lock().unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].operation(), Operation::Write);
+        assert_eq!(records[0].path(), "test");
+        assert_eq!(records[0].size(), Some(5));
+        assert_eq!(records[1].operation(), Operation::CreateDir);
+        assert_eq!(records[1].path(), "dir/");
+        assert_eq!(records[2].operation(), Operation::Delete);
+        assert!(records.iter().all(|v| !v.dry_run()));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run() {
+        let auditor = RecordingInterceptor::default();
+        let op = Operator::new(Memory::default()).unwrap().finish();
+        op.write("exist", "hello").await.unwrap();
+
+        let dry = op
+            .clone()
+            .layer(AuditLayer::new(auditor.clone()).with_dry_run(true));
+        dry.write("test", "hello, world").await.unwrap();
+        dry.create_dir("dir/").await.unwrap();
+        dry.delete("exist").await.unwrap();
+        dry.rename("exist", "renamed").await.unwrap();
+
+        assert!(!op.is_exist("test").await.unwrap());
+        assert!(!op.is_exist("dir/").await.unwrap());
+        assert!(op.is_exist("exist").await.unwrap());
+        assert!(!op.is_exist("renamed").await.unwrap());
+
+        let records = auditor.records.lock().unwrap();
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].size(), Some(12));
+        assert_eq!(records[1].operation(), Operation::CreateDir);
+        assert_eq!(records[2].operation(), Operation::Delete);
+        assert_eq!(records[3].operation(), Operation::Rename);
+        assert_eq!(records[3].path(), "exist");
+        assert_eq!(records[3].target(), Some("renamed"));
+        assert!(records.iter().all(|v| v.dry_run()));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_abort_multipart_upload() {
+        let auditor = RecordingInterceptor::default();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(AuditLayer::new(auditor.clone()).with_dry_run(true))
+            .finish();
+
+        // Memory doesn't support multipart uploads, dry run must not reach it.
+        op.into_inner()
+            .abort_multipart_upload("test", OpAbortMultipartUpload::new("upload-id"))
+            .await
+            .unwrap();
+
+        let records = auditor.records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].operation(), Operation::AbortMultipartUpload);
+        assert_eq!(records[0].path(), "test");
+        assert!(records[0].dry_run());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_block_list() {
+        let auditor = RecordingInterceptor::default();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(AuditLayer::new(auditor.clone()).with_dry_run(true))
+            .finish();
+
+        let acc = op.clone().into_inner();
+        acc.stage_block("test", OpStageBlock::new("b0", Buffer::from("hello")))
+            .await
+            .unwrap();
+        acc.commit_block_list("test", OpCommitBlockList::new(vec!["b0".to_string()]))
+            .await
+            .unwrap();
+        assert!(!op.is_exist("test").await.unwrap());
+
+        let records = auditor.records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].operation(), Operation::StageBlock);
+        assert_eq!(records[0].size(), Some(5));
+        assert_eq!(records[1].operation(), Operation::CommitBlockList);
+        assert!(records.iter().all(|v| v.dry_run()));
+    }
+
+    #[test]
+    fn test_blocking_dry_run() {
+        let auditor = RecordingInterceptor::default();
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(AuditLayer::new(auditor.clone()).with_dry_run(true))
+            .finish()
+            .blocking();
+
+        op.write("test", "hello").unwrap();
+        op.delete("test").unwrap();
+        assert!(!op.is_exist("test").unwrap());
+
+        let records = auditor.records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].operation(), Operation::BlockingWrite);
+        assert_eq!(records[0].size(), Some(5));
+        assert_eq!(records[1].operation(), Operation::BlockingDelete);
+    }
+}
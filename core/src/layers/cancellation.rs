@@ -0,0 +1,691 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::future::pending;
+use std::future::Future;
+use std::pin::pin;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use futures::future::select;
+use futures::future::Either;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::raw::*;
+use crate::*;
+
+/// Enforce the deadline and cancellation token carried by [`OpContext`].
+///
+/// This layer is added by [`OperatorBuilder::new`] automatically, users don't
+/// need to use it directly.
+///
+/// The context is also made current while calling the inner operations, so that
+/// [`HttpClient`] can send the headers carried by it.
+///
+/// - Async calls will race with the deadline and token, the in-flight future
+///   will be dropped once any of them fired.
+/// - Blocking calls can't be interrupted, they will be checked before starting.
+///
+/// Readers, writers and listers inherit the context of the operation that
+/// creates them. `abort` of writers will never be interrupted so that the
+/// cleanup can always be done.
+///
+/// Interrupted operations return [`ErrorKind::Cancelled`] or
+/// [`ErrorKind::DeadlineExceeded`]. The deadline is tracked by the default
+/// executor of [`Operator`], or [`Executor::new`] if not set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CancellationLayer;
+
+impl<A: Access> Layer<A> for CancellationLayer {
+    type LayeredAccess = CancellationAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        CancellationAccessor { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CancellationAccessor<A: Access> {
+    inner: A,
+}
+
+/// The deadline and cancellation token extracted from context.
+#[derive(Debug, Clone)]
+struct Interrupter {
+    deadline: Option<Instant>,
+    token: Option<CancellationToken>,
+    executor: Option<Executor>,
+}
+
+impl Interrupter {
+    /// Returns `None` if neither deadline nor token is set.
+    fn new(ctx: &OpContext) -> Option<Self> {
+        let deadline = ctx.deadline();
+        let token = ctx.cancellation().cloned();
+        if deadline.is_none() && token.is_none() {
+            return None;
+        }
+        Some(Self {
+            deadline,
+            token,
+            executor: ctx.executor().cloned(),
+        })
+    }
+
+    fn check(&self, op: impl Into<&'static str>) -> Result<()> {
+        if self.token.as_ref().is_some_and(|v| v.is_cancelled()) {
+            return Err(new_cancelled_error(op.into()));
+        }
+        if self.deadline.is_some_and(|v| v <= Instant::now()) {
+            return Err(new_deadline_error(op.into()));
+        }
+        Ok(())
+    }
+
+    async fn run<F: Future<Output = Result<T>>, T>(
+        &self,
+        op: impl Into<&'static str>,
+        fut: F,
+    ) -> Result<T> {
+        let op = op.into();
+        self.check(op)?;
+
+        let cancelled = async {
+            match &self.token {
+                Some(token) => token.cancelled().await,
+                None => pending().await,
+            }
+        };
+        let deadline = async {
+            match self.deadline {
+                Some(v) => {
+                    let dur = v.saturating_duration_since(Instant::now());
+                    match &self.executor {
+                        Some(executor) => executor.sleep(dur).await,
+                        None => Executor::new().sleep(dur).await,
+                    }
+                }
+                None => pending().await,
+            }
+        };
+
+        match select(pin!(fut), select(pin!(cancelled), pin!(deadline))).await {
+            Either::Left((output, _)) => output,
+            Either::Right((Either::Left(_), _)) => Err(new_cancelled_error(op)),
+            Either::Right((Either::Right(_), _)) => Err(new_deadline_error(op)),
+        }
+    }
+}
+
+fn new_cancelled_error(op: &'static str) -> Error {
+    Error::new(ErrorKind::Cancelled, "operation has been cancelled").with_operation(op)
+}
+
+fn new_deadline_error(op: &'static str) -> Error {
+    Error::new(ErrorKind::DeadlineExceeded, "operation deadline exceeded").with_operation(op)
+}
+
+/// Run the future with given context as current, and with interrupter if set.
+async fn run<F: Future<Output = Result<T>>, T>(
+    ctx: &OpContext,
+    interrupter: Option<&Interrupter>,
+    op: impl Into<&'static str>,
+    fut: F,
+) -> Result<T> {
+    let fut = ctx.clone().scope(fut);
+    match interrupter {
+        Some(v) => v.run(op, fut).await,
+        None => fut.await,
+    }
+}
+
+/// Check the interrupter if set.
+fn check(interrupter: Option<&Interrupter>, op: impl Into<&'static str>) -> Result<()> {
+    match interrupter {
+        Some(v) => v.check(op),
+        None => Ok(()),
+    }
+}
+
+impl<A: Access> LayeredAccess for CancellationAccessor<A> {
+    type Inner = A;
+    type Reader = CancellationWrapper<A::Reader>;
+    type BlockingReader = CancellationWrapper<A::BlockingReader>;
+    type Writer = CancellationWrapper<A::Writer>;
+    type BlockingWriter = CancellationWrapper<A::BlockingWriter>;
+    type Lister = CancellationWrapper<A::Lister>;
+    type BlockingLister = CancellationWrapper<A::BlockingLister>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::CreateDir,
+            self.inner.create_dir(path, args),
+        )
+        .await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        let (rp, r) = run(
+            &ctx,
+            it.as_ref(),
+            Operation::Read,
+            self.inner.read(path, args),
+        )
+        .await?;
+        Ok((rp, CancellationWrapper::new(r, ctx, it)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        let (rp, w) = run(
+            &ctx,
+            it.as_ref(),
+            Operation::Write,
+            self.inner.write(path, args),
+        )
+        .await?;
+        Ok((rp, CancellationWrapper::new(w, ctx, it)))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::Copy,
+            self.inner.copy(from, to, args),
+        )
+        .await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::Rename,
+            self.inner.rename(from, to, args),
+        )
+        .await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::Stat,
+            self.inner.stat(path, args),
+        )
+        .await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::Delete,
+            self.inner.delete(path, args),
+        )
+        .await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        let (rp, l) = run(
+            &ctx,
+            it.as_ref(),
+            Operation::List,
+            self.inner.list(path, args),
+        )
+        .await?;
+        Ok((rp, CancellationWrapper::new(l, ctx, it)))
+    }
+
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::ListMultipartUploads,
+            self.inner.list_multipart_uploads(path, args),
+        )
+        .await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::AbortMultipartUpload,
+            self.inner.abort_multipart_upload(path, args),
+        )
+        .await
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::StageBlock,
+            self.inner.stage_block(path, args),
+        )
+        .await
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::CommitBlockList,
+            self.inner.commit_block_list(path, args),
+        )
+        .await
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::WriteAt,
+            self.inner.write_at(path, args),
+        )
+        .await
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::Allocate,
+            self.inner.allocate(path, args),
+        )
+        .await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        run(
+            &ctx,
+            it.as_ref(),
+            Operation::Concat,
+            self.inner.concat(path, args),
+        )
+        .await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let ctx = args.context().clone();
+        check(
+            Interrupter::new(&ctx).as_ref(),
+            Operation::BlockingCreateDir,
+        )?;
+        ctx.enter(|| self.inner.blocking_create_dir(path, args))
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        check(it.as_ref(), Operation::BlockingRead)?;
+        let (rp, r) = ctx.enter(|| self.inner.blocking_read(path, args))?;
+        Ok((rp, CancellationWrapper::new(r, ctx, it)))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        check(it.as_ref(), Operation::BlockingWrite)?;
+        let (rp, w) = ctx.enter(|| self.inner.blocking_write(path, args))?;
+        Ok((rp, CancellationWrapper::new(w, ctx, it)))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let ctx = args.context().clone();
+        check(Interrupter::new(&ctx).as_ref(), Operation::BlockingCopy)?;
+        ctx.enter(|| self.inner.blocking_copy(from, to, args))
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let ctx = args.context().clone();
+        check(Interrupter::new(&ctx).as_ref(), Operation::BlockingRename)?;
+        ctx.enter(|| self.inner.blocking_rename(from, to, args))
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let ctx = args.context().clone();
+        check(Interrupter::new(&ctx).as_ref(), Operation::BlockingStat)?;
+        ctx.enter(|| self.inner.blocking_stat(path, args))
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let ctx = args.context().clone();
+        check(Interrupter::new(&ctx).as_ref(), Operation::BlockingDelete)?;
+        ctx.enter(|| self.inner.blocking_delete(path, args))
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        let ctx = args.context().clone();
+        let it = Interrupter::new(&ctx);
+        check(it.as_ref(), Operation::BlockingList)?;
+        let (rp, l) = ctx.enter(|| self.inner.blocking_list(path, args))?;
+        Ok((rp, CancellationWrapper::new(l, ctx, it)))
+    }
+}
+
+pub struct CancellationWrapper<R> {
+    inner: R,
+    ctx: OpContext,
+    interrupter: Option<Interrupter>,
+}
+
+impl<R> CancellationWrapper<R> {
+    fn new(inner: R, ctx: OpContext, interrupter: Option<Interrupter>) -> Self {
+        Self {
+            inner,
+            ctx,
+            interrupter,
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for CancellationWrapper<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        run(
+            &self.ctx,
+            self.interrupter.as_ref(),
+            Operation::ReaderRead,
+            self.inner.read(),
+        )
+        .await
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for CancellationWrapper<R> {
+    fn read(&mut self) -> Result<Buffer> {
+        check(self.interrupter.as_ref(), Operation::BlockingReaderRead)?;
+        self.ctx.enter(|| self.inner.read())
+    }
+}
+
+impl<R: oio::Write> oio::Write for CancellationWrapper<R> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        run(
+            &self.ctx,
+            self.interrupter.as_ref(),
+            Operation::WriterWrite,
+            self.inner.write(bs),
+        )
+        .await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        run(
+            &self.ctx,
+            self.interrupter.as_ref(),
+            Operation::WriterClose,
+            self.inner.close(),
+        )
+        .await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.ctx.clone().scope(self.inner.abort()).await
+    }
+}
+
+impl<R: oio::BlockingWrite> oio::BlockingWrite for CancellationWrapper<R> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        check(self.interrupter.as_ref(), Operation::BlockingWriterWrite)?;
+        self.ctx.enter(|| self.inner.write(bs))
+    }
+
+    fn close(&mut self) -> Result<()> {
+        check(self.interrupter.as_ref(), Operation::BlockingWriterClose)?;
+        self.ctx.enter(|| self.inner.close())
+    }
+}
+
+impl<R: oio::List> oio::List for CancellationWrapper<R> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        run(
+            &self.ctx,
+            self.interrupter.as_ref(),
+            Operation::ListerNext,
+            self.inner.next(),
+        )
+        .await
+    }
+}
+
+impl<R: oio::BlockingList> oio::BlockingList for CancellationWrapper<R> {
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        check(self.interrupter.as_ref(), Operation::BlockingListerNext)?;
+        self.ctx.enter(|| self.inner.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::pending;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Default)]
+    struct MockService;
+
+    impl Access for MockService {
+        type Reader = MockReader;
+        type Writer = ();
+        type Lister = ();
+        type BlockingReader = ();
+        type BlockingWriter = ();
+        type BlockingLister = ();
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let mut am = AccessorInfo::default();
+            am.set_native_capability(Capability {
+                read: true,
+                stat: true,
+                delete: true,
+                ..Default::default()
+            });
+
+            am.into()
+        }
+
+        /// Returns the request id of current context as etag.
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            let mut meta = Metadata::new(EntryMode::FILE);
+            if let Some(v) = OpContext::current().request_id() {
+                meta.set_etag(v);
+            }
+            Ok(RpStat::new(meta))
+        }
+
+        async fn read(&self, _: &str, _: OpRead) -> Result<(RpRead, Self::Reader)> {
+            Ok((RpRead::new(), MockReader))
+        }
+
+        /// This function will never return.
+        async fn delete(&self, _: &str, _: OpDelete) -> Result<RpDelete> {
+            pending().await
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct MockReader;
+
+    impl oio::Read for MockReader {
+        fn read(&mut self) -> impl Future<Output = Result<Buffer>> {
+            pending()
+        }
+    }
+
+    fn new_operator() -> Operator {
+        OperatorBuilder::new(MockService).finish()
+    }
+
+    #[tokio::test]
+    async fn test_deadline() {
+        let op = new_operator();
+
+        let fut = op
+            .delete_with("test")
+            .context(OpContext::new().with_deadline(Instant::now() + Duration::from_millis(100)));
+        let err = timeout(Duration::from_secs(2), fut)
+            .await
+            .expect("deadline must be reached")
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DeadlineExceeded);
+
+        // Reader inherits the deadline from read.
+        let reader = op
+            .reader_with("test")
+            .deadline(Instant::now() + Duration::from_millis(100))
+            .await
+            .unwrap();
+        let err = timeout(Duration::from_secs(2), reader.read(0..4))
+            .await
+            .expect("deadline must be reached")
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DeadlineExceeded);
+    }
+
+    /// Executor that never spawns and wakes up all sleeps immediately.
+    struct ImmediateExecutor;
+
+    impl Execute for ImmediateExecutor {
+        fn execute(&self, _: BoxedStaticFuture<()>) {
+            unreachable!("no task should be executed")
+        }
+
+        fn sleep(&self, _: Duration) -> BoxedStaticFuture<()> {
+            Box::pin(futures::future::ready(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deadline_with_default_executor() {
+        let op = new_operator().with_default_executor(Executor::with(ImmediateExecutor));
+
+        // Deadline must be reached at once since the timer comes from the executor.
+        let reader = op
+            .reader_with("test")
+            .deadline(Instant::now() + Duration::from_secs(3600))
+            .await
+            .unwrap();
+        let err = timeout(Duration::from_secs(1), reader.read(0..4))
+            .await
+            .expect("timer of executor must be used")
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DeadlineExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_cancellation() {
+        let op = new_operator();
+
+        let token = CancellationToken::new();
+        let cloned = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            cloned.cancel();
+        });
+
+        let reader = op
+            .reader_with("test")
+            .cancellation(token.clone())
+            .await
+            .unwrap();
+        let err = timeout(Duration::from_secs(2), reader.read(0..4))
+            .await
+            .expect("operation must be cancelled")
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Cancelled);
+
+        // Cancelled token fails new operations immediately.
+        let err = op
+            .delete_with("test")
+            .context(OpContext::new().with_cancellation(token))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_current_context() {
+        let op = new_operator();
+
+        let meta = op.stat("test").await.unwrap();
+        assert_eq!(meta.etag(), None);
+
+        let meta = op
+            .stat_with("test")
+            .context(OpContext::new().with_request_id("req-1"))
+            .await
+            .unwrap();
+        assert_eq!(meta.etag(), Some("req-1"));
+        // The context must be restored after the operation.
+        assert!(OpContext::current().is_empty());
+    }
+}
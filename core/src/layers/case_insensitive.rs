@@ -0,0 +1,371 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::raw::*;
+use crate::*;
+
+/// Add case-insensitive compatibility for path matching.
+///
+/// Some services like fs on windows or macOS are case-insensitive, while most of the
+/// object storage services are case-sensitive. Migrating data between them could
+/// lead to surprising behaviors: `Abc` and `abc` are different objects on s3, but
+/// writing them into windows fs will overwrite each other.
+///
+/// This layer makes paths matching case-insensitive on all services:
+///
+/// - For lookups (`read`, `stat`, `delete`, `list` and the source of `copy`/`rename`),
+///   if the given path doesn't exist, the path with the same case-insensitive name
+///   will be used instead. Exact matches are always preferred.
+/// - For writes (`write`, `create_dir` and the target of `copy`/`rename`), if there
+///   is another path with the same name but in different case, the operation will
+///   fail with [`ErrorKind::AlreadyExists`] instead of creating (or overwriting) it.
+///
+/// # Notes
+///
+/// The case-insensitive lookup and collision detection are implemented by listing
+/// every parent dir of the path, which could be expensive for large dirs. Lookups
+/// will only list while the exact path is not found, but writes will always list.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::CaseInsensitiveLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+/// use opendal::Scheme;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(CaseInsensitiveLayer)
+///     .finish();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaseInsensitiveLayer;
+
+impl<A: Access> Layer<A> for CaseInsensitiveLayer {
+    type LayeredAccess = CaseInsensitiveAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        CaseInsensitiveAccessor { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CaseInsensitiveAccessor<A: Access> {
+    inner: A,
+}
+
+/// Split path into segments, every segment carries whether it's a dir.
+fn segments(path: &str) -> Vec<(&str, bool)> {
+    let is_dir = path.ends_with('/');
+    let segs = path
+        .trim_end_matches('/')
+        .split('/')
+        .filter(|v| !v.is_empty())
+        .collect::<Vec<_>>();
+    let last = segs.len().saturating_sub(1);
+    segs.into_iter()
+        .enumerate()
+        .map(|(idx, v)| (v, idx != last || is_dir))
+        .collect()
+}
+
+/// Pick the entry that matches given segment from the entries of `dir`.
+///
+/// Exact match is preferred, otherwise the first case-insensitive match
+/// will be returned.
+fn pick(dir: &str, entries: Vec<oio::Entry>, seg: &str, is_dir: bool) -> Option<String> {
+    let mut found = None;
+    for entry in entries {
+        let path = entry.path();
+        if path == dir || entry.mode().is_dir() != is_dir {
+            continue;
+        }
+
+        let name = get_basename(path).trim_end_matches('/');
+        if name == seg {
+            return Some(path.to_string());
+        }
+        if found.is_none() && name.to_lowercase() == seg.to_lowercase() {
+            found = Some(path.to_string());
+        }
+    }
+    found
+}
+
+fn new_collision_error(op: Operation, path: &str, existing: &str) -> Error {
+    Error::new(
+        ErrorKind::AlreadyExists,
+        "path collides with an existing path in different case",
+    )
+    .with_operation(op)
+    .with_context("path", path)
+    .with_context("existing", existing)
+}
+
+impl<A: Access> CaseInsensitiveAccessor<A> {
+    async fn list_dir(&self, dir: &str) -> Result<Vec<oio::Entry>> {
+        let mut lister = match self.inner.list(dir, OpList::new()).await {
+            Ok((_, lister)) => lister,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+
+        let mut entries = vec![];
+        while let Some(entry) = oio::List::next(&mut lister).await? {
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    /// Find the existing path that matches given path case-insensitively.
+    async fn find(&self, path: &str) -> Result<Option<String>> {
+        let mut cur = "/".to_string();
+        for (seg, is_dir) in segments(path) {
+            let entries = self.list_dir(&cur).await?;
+            match pick(&cur, entries, seg, is_dir) {
+                Some(p) => cur = p,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(cur))
+    }
+
+    /// Resolve given path to the existing path, returns the input path if not found.
+    async fn resolve(&self, path: &str) -> Result<String> {
+        match self.inner.stat(path, OpStat::new()).await {
+            Ok(_) => return Ok(path.to_string()),
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        Ok(self.find(path).await?.unwrap_or_else(|| path.to_string()))
+    }
+
+    /// Check if given path collides with an existing path in different case.
+    async fn check_collision(&self, op: Operation, path: &str) -> Result<()> {
+        match self.find(path).await? {
+            Some(existing) if existing != path => Err(new_collision_error(op, path, &existing)),
+            _ => Ok(()),
+        }
+    }
+
+    fn blocking_list_dir(&self, dir: &str) -> Result<Vec<oio::Entry>> {
+        let mut lister = match self.inner.blocking_list(dir, OpList::new()) {
+            Ok((_, lister)) => lister,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+
+        let mut entries = vec![];
+        while let Some(entry) = oio::BlockingList::next(&mut lister)? {
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    fn blocking_find(&self, path: &str) -> Result<Option<String>> {
+        let mut cur = "/".to_string();
+        for (seg, is_dir) in segments(path) {
+            let entries = self.blocking_list_dir(&cur)?;
+            match pick(&cur, entries, seg, is_dir) {
+                Some(p) => cur = p,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(cur))
+    }
+
+    fn blocking_resolve(&self, path: &str) -> Result<String> {
+        match self.inner.blocking_stat(path, OpStat::new()) {
+            Ok(_) => return Ok(path.to_string()),
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        Ok(self
+            .blocking_find(path)?
+            .unwrap_or_else(|| path.to_string()))
+    }
+
+    fn blocking_check_collision(&self, op: Operation, path: &str) -> Result<()> {
+        match self.blocking_find(path)? {
+            Some(existing) if existing != path => Err(new_collision_error(op, path, &existing)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<A: Access> LayeredAccess for CaseInsensitiveAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.check_collision(Operation::CreateDir, path).await?;
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let path = self.resolve(path).await?;
+        self.inner.read(&path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.check_collision(Operation::Write, path).await?;
+        self.inner.write(path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let from = self.resolve(from).await?;
+        self.check_collision(Operation::Copy, to).await?;
+        self.inner.copy(&from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let from = self.resolve(from).await?;
+        self.check_collision(Operation::Rename, to).await?;
+        self.inner.rename(&from, to, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.inner.stat(path, args.clone()).await {
+            Err(err) if err.kind() == ErrorKind::NotFound => match self.find(path).await? {
+                Some(p) => self.inner.stat(&p, args).await,
+                None => Err(err),
+            },
+            v => v,
+        }
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let path = self.resolve(path).await?;
+        self.inner.delete(&path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let path = self.resolve(path).await?;
+        self.inner.list(&path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.blocking_check_collision(Operation::BlockingCreateDir, path)?;
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let path = self.blocking_resolve(path)?;
+        self.inner.blocking_read(&path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.blocking_check_collision(Operation::BlockingWrite, path)?;
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let from = self.blocking_resolve(from)?;
+        self.blocking_check_collision(Operation::BlockingCopy, to)?;
+        self.inner.blocking_copy(&from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let from = self.blocking_resolve(from)?;
+        self.blocking_check_collision(Operation::BlockingRename, to)?;
+        self.inner.blocking_rename(&from, to, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.inner.blocking_stat(path, args.clone()) {
+            Err(err) if err.kind() == ErrorKind::NotFound => match self.blocking_find(path)? {
+                Some(p) => self.inner.blocking_stat(&p, args),
+                None => Err(err),
+            },
+            v => v,
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let path = self.blocking_resolve(path)?;
+        self.inner.blocking_delete(&path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        let path = self.blocking_resolve(path)?;
+        self.inner.blocking_list(&path, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[test]
+    fn test_segments() {
+        assert_eq!(segments("/"), vec![]);
+        assert_eq!(segments("abc"), vec![("abc", false)]);
+        assert_eq!(segments("abc/def/"), vec![("abc", true), ("def", true)]);
+        assert_eq!(segments("abc/def"), vec![("abc", true), ("def", false)]);
+    }
+
+    #[tokio::test]
+    async fn test_lookup() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(CaseInsensitiveLayer)
+            .finish();
+
+        op.write("Dir/Hello.txt", "hello").await.unwrap();
+
+        let meta = op.stat("dir/hello.TXT").await.unwrap();
+        assert_eq!(meta.content_length(), 5);
+        let bs = op.read("DIR/hello.txt").await.unwrap();
+        assert_eq!(bs.to_vec(), b"hello");
+
+        let err = op.stat("dir/world.txt").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_collision() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(CaseInsensitiveLayer)
+            .finish();
+
+        op.write("Dir/Hello.txt", "hello").await.unwrap();
+        // Overwrite the exact path is allowed.
+        op.write("Dir/Hello.txt", "world").await.unwrap();
+
+        let err = op.write("Dir/hello.txt", "world").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        let err = op.write("dir/Hello.txt", "world").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        op.write("Dir/World.txt", "world").await.unwrap();
+    }
+}
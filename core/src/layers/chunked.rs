@@ -0,0 +1,801 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::mem;
+use std::sync::Arc;
+
+use bytes::Buf;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::oio::BlockingRead;
+use crate::raw::oio::BlockingWrite;
+use crate::raw::oio::Read;
+use crate::raw::oio::Write;
+use crate::raw::*;
+use crate::*;
+
+/// Split large objects into fixed size chunks for services with limited value size.
+///
+/// KV services like redis and memcached can't hold multi-GB values. This layer
+/// stores objects larger than `chunk_size` as separated chunks, so they can be
+/// written in streaming and read by range without loading the whole object.
+///
+/// # Layout
+///
+/// - Objects not larger than `chunk_size` are stored at their path as is. Objects
+///   that happen to start with the marker of manifest are stored with an extra
+///   marker, so they will never be taken as manifests.
+/// - Larger objects are split into chunks stored at `.chunks/<path>/<id>/<index>`,
+///   and a small manifest that records the chunks is stored at the path.
+///
+/// Every write uses a new `id`, and the manifest is written after all chunks, so
+/// readers will see either the old content or the new one. Chunks of the old
+/// content are removed after the manifest has been replaced or deleted.
+///
+/// # Notes
+///
+/// - `stat` may read the value at the path to check whether it's a manifest.
+/// - Chunks of failed writes may be left if the writer is dropped without
+///   being closed or aborted, so are chunks failed to be removed after their
+///   manifest has been deleted.
+/// - `list` is not aware of the chunks, entries under `.chunks/` will be returned
+///   when listing the root.
+/// - `copy` and `rename` are not supported, since they would leave the manifest
+///   pointing to chunks of another path.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::ChunkedLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(ChunkedLayer::new(4 * 1024 * 1024))
+///     .finish();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkedLayer {
+    chunk_size: usize,
+}
+
+impl ChunkedLayer {
+    /// Create a new ChunkedLayer that splits objects into chunks of `chunk_size` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn new(chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be larger than 0");
+        Self { chunk_size }
+    }
+}
+
+impl<A: Access> Layer<A> for ChunkedLayer {
+    type LayeredAccess = ChunkedAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        ChunkedAccessor {
+            inner: Arc::new(inner),
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+/// The prefix shared by all markers of this layer.
+const MAGIC_PREFIX: &[u8] = b"OPENDAL_CHUNKED_";
+/// The prefix of the encoded manifest that tells it apart from plain content.
+const MANIFEST_MAGIC: &[u8] = b"OPENDAL_CHUNKED_MANIFEST\n";
+/// The prefix of plain content that starts with [`MAGIC_PREFIX`] by itself.
+const PLAIN_MAGIC: &[u8] = b"OPENDAL_CHUNKED_PLAIN\n";
+/// Values larger than this are never manifests, so we don't need to read them.
+const MANIFEST_MAX_SIZE: u64 = 4096;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ChunkManifest {
+    id: String,
+    size: u64,
+    chunk_size: u64,
+    chunks: u64,
+}
+
+impl ChunkManifest {
+    fn encode(&self) -> Buffer {
+        let mut bs = MANIFEST_MAGIC.to_vec();
+        serde_json::to_writer(&mut bs, self).expect("manifest must be serializable");
+        Buffer::from(bs)
+    }
+
+    fn decode(bs: Buffer) -> Option<Self> {
+        let bs = bs.to_bytes();
+        let body = bs.strip_prefix(MANIFEST_MAGIC)?;
+        serde_json::from_slice(body).ok()
+    }
+
+    fn chunk_path(&self, path: &str, index: u64) -> String {
+        chunk_path(path, &self.id, index)
+    }
+}
+
+fn chunk_path(path: &str, id: &str, index: u64) -> String {
+    format!(".chunks/{path}/{id}/{index}")
+}
+
+/// Content is the value stored at the path.
+enum Content {
+    Plain(Buffer),
+    Chunked(ChunkManifest),
+}
+
+impl Content {
+    fn new(bs: Buffer) -> Self {
+        let head = bs.to_bytes();
+        if head.starts_with(PLAIN_MAGIC) {
+            return Content::Plain(bs.slice(PLAIN_MAGIC.len()..));
+        }
+        if !head.starts_with(MANIFEST_MAGIC) {
+            return Content::Plain(bs);
+        }
+        match ChunkManifest::decode(bs.clone()) {
+            Some(manifest) => Content::Chunked(manifest),
+            None => Content::Plain(bs),
+        }
+    }
+
+    /// Encode the plain content to store, the content will be marked if it could
+    /// be mistaken for a manifest.
+    fn encode_plain(bs: Buffer) -> Buffer {
+        if !bs.to_bytes().starts_with(MAGIC_PREFIX) {
+            return bs;
+        }
+        PLAIN_MAGIC
+            .iter()
+            .copied()
+            .chain(bs.to_bytes())
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+/// Clamp the range into `[start, end)` of an object with `size` bytes.
+fn clamp_range(range: BytesRange, size: u64) -> (u64, u64) {
+    let start = range.offset().min(size);
+    let end = range
+        .size()
+        .map_or(size, |n| start.saturating_add(n).min(size));
+    (start, end)
+}
+
+#[derive(Debug)]
+pub struct ChunkedAccessor<A: Access> {
+    inner: Arc<A>,
+    chunk_size: usize,
+}
+
+impl<A: Access> ChunkedAccessor<A> {
+    /// Read the first `size` bytes of the value stored at the path.
+    async fn read_value(&self, path: &str, args: &OpRead, size: Option<u64>) -> Result<Buffer> {
+        let args = args.clone().with_range(BytesRange::new(0, size));
+        let (_, mut r) = self.inner.read(path, args).await?;
+        r.read_all().await
+    }
+
+    fn blocking_read_value(&self, path: &str, args: &OpRead, size: Option<u64>) -> Result<Buffer> {
+        let args = args.clone().with_range(BytesRange::new(0, size));
+        let (_, mut r) = self.inner.blocking_read(path, args)?;
+        blocking_read_all(&mut r)
+    }
+
+    async fn load(&self, path: &str, args: &OpRead) -> Result<Content> {
+        Ok(Content::new(self.read_value(path, args, None).await?))
+    }
+
+    fn blocking_load(&self, path: &str, args: &OpRead) -> Result<Content> {
+        Ok(Content::new(self.blocking_read_value(path, args, None)?))
+    }
+
+    /// The bytes to read for stat.
+    ///
+    /// Manifests must be read whole, while plain content only needs its marker.
+    fn stat_read_size(meta: &Metadata) -> Option<Option<u64>> {
+        let size = meta.content_length();
+        if meta.is_dir() || size < MAGIC_PREFIX.len() as u64 {
+            None
+        } else if size <= MANIFEST_MAX_SIZE {
+            Some(None)
+        } else {
+            Some(Some(PLAIN_MAGIC.len() as u64))
+        }
+    }
+
+    fn stat_manifest_args(args: &OpStat) -> OpRead {
+        let mut op = OpRead::new();
+        if let Some(v) = args.version() {
+            op = op.with_version(v);
+        }
+        op
+    }
+
+    fn apply_manifest(meta: Metadata, head: Buffer) -> Metadata {
+        let size = meta.content_length();
+        if head.to_bytes().starts_with(PLAIN_MAGIC) {
+            return meta.with_content_length(size - PLAIN_MAGIC.len() as u64);
+        }
+        match Content::new(head) {
+            Content::Chunked(m) => meta.with_content_length(m.size),
+            Content::Plain(_) => meta,
+        }
+    }
+
+    fn new_unsupported_error(op: Operation) -> Error {
+        Error::new(
+            ErrorKind::Unsupported,
+            "chunked objects can't be copied or renamed",
+        )
+        .with_operation(op)
+    }
+
+    fn new_reader<R>(
+        &self,
+        path: &str,
+        content: Content,
+        range: BytesRange,
+    ) -> (RpRead, TwoWays<Buffer, ChunkedReader<A, R>>) {
+        match content {
+            Content::Plain(bs) => {
+                let (start, end) = clamp_range(range, bs.len() as u64);
+                let rp = RpRead::new().with_size(Some(end - start));
+                (rp, TwoWays::One(bs.slice(start as usize..end as usize)))
+            }
+            Content::Chunked(manifest) => {
+                let (start, end) = clamp_range(range, manifest.size);
+                let rp = RpRead::new().with_size(Some(end - start));
+                let r = ChunkedReader {
+                    inner: self.inner.clone(),
+                    path: path.to_string(),
+                    manifest,
+                    offset: start,
+                    end,
+                    current: None,
+                    remaining: 0,
+                };
+                (rp, TwoWays::Two(r))
+            }
+        }
+    }
+}
+
+impl<A: Access> LayeredAccess for ChunkedAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<Buffer, ChunkedReader<A, A::Reader>>;
+    type BlockingReader = TwoWays<Buffer, ChunkedReader<A, A::BlockingReader>>;
+    type Writer = ChunkedWriter<A>;
+    type BlockingWriter = ChunkedWriter<A>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn metadata(&self) -> Arc<AccessorInfo> {
+        let mut meta = self.inner.info().as_ref().clone();
+        // Content will be split into chunks by this layer.
+        let cap = meta.full_capability_mut();
+        cap.write_can_multi = true;
+        cap.copy = false;
+        cap.rename = false;
+        meta.into()
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let content = self.load(path, &args).await?;
+        Ok(self.new_reader(path, content, args.range()))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        Ok((
+            RpWrite::new(),
+            ChunkedWriter::new(self.inner.clone(), path, args, self.chunk_size),
+        ))
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let read_args = Self::stat_manifest_args(&args);
+        let meta = self.inner.stat(path, args).await?.into_metadata();
+        let Some(size) = Self::stat_read_size(&meta) else {
+            return Ok(RpStat::new(meta));
+        };
+
+        let head = self.read_value(path, &read_args, size).await?;
+        Ok(RpStat::new(Self::apply_manifest(meta, head)))
+    }
+
+    async fn copy(&self, _: &str, _: &str, _: OpCopy) -> Result<RpCopy> {
+        Err(Self::new_unsupported_error(Operation::Copy))
+    }
+
+    async fn rename(&self, _: &str, _: &str, _: OpRename) -> Result<RpRename> {
+        Err(Self::new_unsupported_error(Operation::Rename))
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let manifest = match self.load(path, &OpRead::new()).await {
+            Ok(Content::Chunked(m)) => Some(m),
+            Ok(Content::Plain(_)) => None,
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+
+        // Delete the manifest first so that readers never see missing chunks.
+        let rp = self.inner.delete(path, args).await?;
+        if let Some(m) = manifest {
+            for index in 0..m.chunks {
+                let _ = self
+                    .inner
+                    .delete(&m.chunk_path(path, index), OpDelete::new())
+                    .await;
+            }
+        }
+        Ok(rp)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let content = self.blocking_load(path, &args)?;
+        Ok(self.new_reader(path, content, args.range()))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        Ok((
+            RpWrite::new(),
+            ChunkedWriter::new(self.inner.clone(), path, args, self.chunk_size),
+        ))
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let read_args = Self::stat_manifest_args(&args);
+        let meta = self.inner.blocking_stat(path, args)?.into_metadata();
+        let Some(size) = Self::stat_read_size(&meta) else {
+            return Ok(RpStat::new(meta));
+        };
+
+        let head = self.blocking_read_value(path, &read_args, size)?;
+        Ok(RpStat::new(Self::apply_manifest(meta, head)))
+    }
+
+    fn blocking_copy(&self, _: &str, _: &str, _: OpCopy) -> Result<RpCopy> {
+        Err(Self::new_unsupported_error(Operation::BlockingCopy))
+    }
+
+    fn blocking_rename(&self, _: &str, _: &str, _: OpRename) -> Result<RpRename> {
+        Err(Self::new_unsupported_error(Operation::BlockingRename))
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let manifest = match self.blocking_load(path, &OpRead::new()) {
+            Ok(Content::Chunked(m)) => Some(m),
+            Ok(Content::Plain(_)) => None,
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+
+        // Delete the manifest first so that readers never see missing chunks.
+        let rp = self.inner.blocking_delete(path, args)?;
+        if let Some(m) = manifest {
+            for index in 0..m.chunks {
+                let _ = self
+                    .inner
+                    .blocking_delete(&m.chunk_path(path, index), OpDelete::new());
+            }
+        }
+        Ok(rp)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+fn blocking_read_all(r: &mut impl BlockingRead) -> Result<Buffer> {
+    let mut bufs = vec![];
+    loop {
+        let bs = r.read()?;
+        if bs.is_empty() {
+            break;
+        }
+        bufs.push(bs);
+    }
+    Ok(bufs.into_iter().flatten().collect())
+}
+
+/// ChunkedReader reads the content of given range across chunks.
+pub struct ChunkedReader<A: Access, R> {
+    inner: Arc<A>,
+    path: String,
+    manifest: ChunkManifest,
+
+    offset: u64,
+    end: u64,
+    /// The reader of current chunk and the bytes remaining in it.
+    current: Option<R>,
+    remaining: u64,
+}
+
+impl<A: Access, R> ChunkedReader<A, R> {
+    /// Build the path and the args to read the chunk that contains `offset`.
+    fn next_chunk(&mut self) -> (String, OpRead) {
+        let chunk_size = self.manifest.chunk_size;
+        let index = self.offset / chunk_size;
+        let within = self.offset % chunk_size;
+        let size = (chunk_size - within).min(self.end - self.offset);
+        self.remaining = size;
+
+        (
+            self.manifest.chunk_path(&self.path, index),
+            OpRead::new().with_range(BytesRange::new(within, Some(size))),
+        )
+    }
+
+    /// Advance with the bytes read from current chunk.
+    fn advance(&mut self, bs: &Buffer) -> Result<()> {
+        if bs.is_empty() {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "chunk is shorter than expected")
+                    .with_context("path", &self.path)
+                    .with_context("offset", self.offset.to_string()),
+            );
+        }
+
+        let n = bs.len() as u64;
+        self.offset += n;
+        self.remaining = self.remaining.saturating_sub(n);
+        if self.remaining == 0 {
+            self.current = None;
+        }
+        Ok(())
+    }
+}
+
+impl<A: Access> oio::Read for ChunkedReader<A, A::Reader> {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.offset >= self.end {
+            return Ok(Buffer::new());
+        }
+
+        if self.current.is_none() {
+            let (path, args) = self.next_chunk();
+            let (_, r) = self.inner.read(&path, args).await?;
+            self.current = Some(r);
+        }
+
+        let bs = self
+            .current
+            .as_mut()
+            .expect("current chunk must be opened")
+            .read()
+            .await?;
+        self.advance(&bs)?;
+        Ok(bs)
+    }
+}
+
+impl<A: Access> oio::BlockingRead for ChunkedReader<A, A::BlockingReader> {
+    fn read(&mut self) -> Result<Buffer> {
+        if self.offset >= self.end {
+            return Ok(Buffer::new());
+        }
+
+        if self.current.is_none() {
+            let (path, args) = self.next_chunk();
+            let (_, r) = self.inner.blocking_read(&path, args)?;
+            self.current = Some(r);
+        }
+
+        let bs = self
+            .current
+            .as_mut()
+            .expect("current chunk must be opened")
+            .read()?;
+        self.advance(&bs)?;
+        Ok(bs)
+    }
+}
+
+/// ChunkedWriter buffers the content and writes every full chunk out.
+pub struct ChunkedWriter<A: Access> {
+    inner: Arc<A>,
+    path: String,
+    args: OpWrite,
+    chunk_size: usize,
+
+    id: String,
+    buf: oio::QueueBuf,
+    size: u64,
+    chunks: u64,
+}
+
+impl<A: Access> ChunkedWriter<A> {
+    fn new(inner: Arc<A>, path: &str, args: OpWrite, chunk_size: usize) -> Self {
+        Self {
+            inner,
+            path: path.to_string(),
+            args,
+            chunk_size,
+            id: uuid::Uuid::new_v4().to_string(),
+            buf: oio::QueueBuf::new(),
+            size: 0,
+            chunks: 0,
+        }
+    }
+
+    /// Take the full chunks out of the buffer.
+    ///
+    /// A chunk is only taken if more content follows, so that objects fit in one
+    /// chunk are stored as is.
+    fn take_chunks(&mut self, bs: Buffer) -> Vec<Buffer> {
+        self.size += bs.len() as u64;
+        self.buf.push(bs);
+        if self.buf.len() <= self.chunk_size {
+            return vec![];
+        }
+
+        let mut all = mem::take(&mut self.buf).collect();
+        let mut chunks = vec![];
+        while all.len() > self.chunk_size {
+            chunks.push(all.slice(..self.chunk_size));
+            all.advance(self.chunk_size);
+        }
+        self.buf.push(all);
+        chunks
+    }
+
+    fn manifest(&self) -> ChunkManifest {
+        ChunkManifest {
+            id: self.id.clone(),
+            size: self.size,
+            chunk_size: self.chunk_size as u64,
+            chunks: self.chunks,
+        }
+    }
+
+    async fn write_value(&self, path: &str, args: OpWrite, bs: Buffer) -> Result<()> {
+        let (_, mut w) = self.inner.write(path, args).await?;
+        w.write(bs).await?;
+        w.close().await
+    }
+
+    fn blocking_write_value(&self, path: &str, args: OpWrite, bs: Buffer) -> Result<()> {
+        let (_, mut w) = self.inner.blocking_write(path, args)?;
+        w.write(bs)?;
+        w.close()
+    }
+
+    /// Load the manifest of the content that is going to be replaced.
+    async fn load_previous(&self) -> Option<ChunkManifest> {
+        let (_, mut r) = self.inner.read(&self.path, OpRead::new()).await.ok()?;
+        match Content::new(r.read_all().await.ok()?) {
+            Content::Chunked(m) => Some(m),
+            Content::Plain(_) => None,
+        }
+    }
+
+    fn blocking_load_previous(&self) -> Option<ChunkManifest> {
+        let (_, mut r) = self.inner.blocking_read(&self.path, OpRead::new()).ok()?;
+        match Content::new(blocking_read_all(&mut r).ok()?) {
+            Content::Chunked(m) => Some(m),
+            Content::Plain(_) => None,
+        }
+    }
+}
+
+impl<A: Access> oio::Write for ChunkedWriter<A> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        for chunk in self.take_chunks(bs) {
+            let path = chunk_path(&self.path, &self.id, self.chunks);
+            self.write_value(&path, OpWrite::default(), chunk).await?;
+            self.chunks += 1;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let rest = mem::take(&mut self.buf).collect();
+        let previous = self.load_previous().await;
+
+        if self.chunks == 0 {
+            self.write_value(&self.path, self.args.clone(), Content::encode_plain(rest))
+                .await?;
+        } else {
+            if !rest.is_empty() {
+                let path = chunk_path(&self.path, &self.id, self.chunks);
+                self.write_value(&path, OpWrite::default(), rest).await?;
+                self.chunks += 1;
+            }
+            let manifest = self.manifest().encode();
+            self.write_value(&self.path, self.args.clone(), manifest)
+                .await?;
+        }
+
+        // Chunks of the replaced content are not referenced anymore.
+        if let Some(m) = previous {
+            for index in 0..m.chunks {
+                let _ = self
+                    .inner
+                    .delete(&m.chunk_path(&self.path, index), OpDelete::new())
+                    .await;
+            }
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.buf.clear();
+        for index in 0..self.chunks {
+            self.inner
+                .delete(&chunk_path(&self.path, &self.id, index), OpDelete::new())
+                .await?;
+        }
+        self.chunks = 0;
+        Ok(())
+    }
+}
+
+impl<A: Access> oio::BlockingWrite for ChunkedWriter<A> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        for chunk in self.take_chunks(bs) {
+            let path = chunk_path(&self.path, &self.id, self.chunks);
+            self.blocking_write_value(&path, OpWrite::default(), chunk)?;
+            self.chunks += 1;
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        let rest = mem::take(&mut self.buf).collect();
+        let previous = self.blocking_load_previous();
+
+        if self.chunks == 0 {
+            self.blocking_write_value(&self.path, self.args.clone(), Content::encode_plain(rest))?;
+        } else {
+            if !rest.is_empty() {
+                let path = chunk_path(&self.path, &self.id, self.chunks);
+                self.blocking_write_value(&path, OpWrite::default(), rest)?;
+                self.chunks += 1;
+            }
+            let manifest = self.manifest().encode();
+            self.blocking_write_value(&self.path, self.args.clone(), manifest)?;
+        }
+
+        // Chunks of the replaced content are not referenced anymore.
+        if let Some(m) = previous {
+            for index in 0..m.chunks {
+                let _ = self
+                    .inner
+                    .blocking_delete(&m.chunk_path(&self.path, index), OpDelete::new());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    async fn list_chunks(op: &Operator) -> Vec<String> {
+        let mut paths: Vec<String> = op
+            .lister_with(".chunks/")
+            .recursive(true)
+            .await
+            .unwrap()
+            .map_ok(|e| e.path().to_string())
+            .try_filter(|p| futures::future::ready(!p.ends_with('/')))
+            .try_collect()
+            .await
+            .unwrap();
+        paths.sort();
+        paths
+    }
+
+    #[tokio::test]
+    async fn test_chunked_read_write() {
+        let inner = Operator::new(Memory::default()).unwrap().finish();
+        let op = inner.clone().layer(ChunkedLayer::new(4));
+
+        op.write("small", "abcd").await.unwrap();
+        assert_eq!(inner.read("small").await.unwrap().to_vec(), b"abcd");
+
+        let mut w = op.writer("large").await.unwrap();
+        w.write("hello, ").await.unwrap();
+        w.write("world").await.unwrap();
+        w.close().await.unwrap();
+        assert_eq!(list_chunks(&inner).await.len(), 3);
+
+        assert_eq!(op.stat("large").await.unwrap().content_length(), 12);
+        assert_eq!(op.read("large").await.unwrap().to_vec(), b"hello, world");
+        assert_eq!(
+            op.read_with("large").range(3..9).await.unwrap().to_vec(),
+            b"lo, wo"
+        );
+        assert_eq!(
+            op.read_with("large").range(8..).await.unwrap().to_vec(),
+            b"orld"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chunked_overwrite_and_delete() {
+        let inner = Operator::new(Memory::default()).unwrap().finish();
+        let op = inner.clone().layer(ChunkedLayer::new(4));
+
+        op.write("file", "hello, world").await.unwrap();
+        op.write("file", "hello, opendal").await.unwrap();
+        // Chunks of the replaced content should be removed.
+        assert_eq!(list_chunks(&inner).await.len(), 4);
+        assert_eq!(op.read("file").await.unwrap().to_vec(), b"hello, opendal");
+
+        op.write("file", "hi").await.unwrap();
+        assert!(list_chunks(&inner).await.is_empty());
+        assert_eq!(op.read("file").await.unwrap().to_vec(), b"hi");
+
+        op.write("file", "hello, world").await.unwrap();
+        op.delete("file").await.unwrap();
+        assert!(list_chunks(&inner).await.is_empty());
+        assert!(!inner.is_exist("file").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_chunked_plain_content_like_manifest() {
+        let inner = Operator::new(Memory::default()).unwrap().finish();
+        let op = inner.clone().layer(ChunkedLayer::new(16 * 1024));
+
+        let manifest = ChunkManifest {
+            id: "id".to_string(),
+            size: 1024,
+            chunk_size: 4,
+            chunks: 256,
+        };
+        let content = manifest.encode().to_vec();
+        op.write("manifest", content.clone()).await.unwrap();
+        assert_eq!(op.read("manifest").await.unwrap().to_vec(), content);
+        assert_eq!(
+            op.stat("manifest").await.unwrap().content_length(),
+            content.len() as u64
+        );
+        op.delete("manifest").await.unwrap();
+        assert!(!inner.is_exist("manifest").await.unwrap());
+
+        let content = [PLAIN_MAGIC, &[b'x'; 8192]].concat();
+        op.write("plain", content.clone()).await.unwrap();
+        assert_eq!(op.read("plain").await.unwrap().to_vec(), content);
+        assert_eq!(
+            op.stat("plain").await.unwrap().content_length(),
+            content.len() as u64
+        );
+    }
+}
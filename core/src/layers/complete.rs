@@ -22,6 +22,7 @@ use std::sync::Arc;
 
 use crate::raw::oio::FlatLister;
 use crate::raw::oio::PrefixLister;
+use crate::raw::oio::SortedLister;
 use crate::raw::*;
 use crate::*;
 
@@ -139,6 +140,59 @@ impl<A: Access> CompleteAccessor<A> {
         .with_operation(op)
     }
 
+    /// Conditional writes must not be ignored silently, return `Unsupported`
+    /// if the service can't handle them.
+    fn check_write_conditions(&self, args: &OpWrite) -> Result<()> {
+        let capability = self.meta.full_capability();
+        if args.if_match().is_some() && !capability.write_with_if_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with if_match",
+                    self.meta.scheme()
+                ),
+            ));
+        }
+        if args.if_none_match().is_some() && !capability.write_with_if_none_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with if_none_match",
+                    self.meta.scheme()
+                ),
+            ));
+        }
+        if args.if_generation_match().is_some() && !capability.write_with_if_generation_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with if_generation_match",
+                    self.meta.scheme()
+                ),
+            ));
+        }
+        if args.ttl().is_some() && !capability.write_with_ttl {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with ttl",
+                    self.meta.scheme()
+                ),
+            ));
+        }
+        if args.acl().is_some() && !capability.write_with_acl {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with acl",
+                    self.meta.scheme()
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn complete_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let capability = self.meta.full_capability();
         if capability.create_dir {
@@ -274,7 +328,7 @@ impl<A: Access> CompleteAccessor<A> {
         &self,
         path: &str,
         args: OpList,
-    ) -> Result<(RpList, CompleteLister<A, A::Lister>)> {
+    ) -> Result<(RpList, CompleteInnerLister<A, A::Lister>)> {
         let cap = self.meta.full_capability();
         if !cap.list {
             return Err(self.new_unsupported_error(Operation::List));
@@ -286,19 +340,19 @@ impl<A: Access> CompleteAccessor<A> {
             // - If service can list_with_recursive, we can forward list to it directly.
             (_, true) => {
                 let (rp, p) = self.inner.list(path, args).await?;
-                Ok((rp, CompleteLister::One(p)))
+                Ok((rp, FourWays::One(p)))
             }
             // If recursive is true but service can't list_with_recursive
             (true, false) => {
                 // Forward path that ends with /
                 if path.ends_with('/') {
                     let p = FlatLister::new(self.inner.clone(), path);
-                    Ok((RpList::default(), CompleteLister::Two(p)))
+                    Ok((RpList::default(), FourWays::Two(p)))
                 } else {
                     let parent = get_parent(path);
                     let p = FlatLister::new(self.inner.clone(), parent);
                     let p = PrefixLister::new(p, path);
-                    Ok((RpList::default(), CompleteLister::Four(p)))
+                    Ok((RpList::default(), FourWays::Four(p)))
                 }
             }
             // If recursive and service doesn't support list_with_recursive, we need to handle
@@ -307,12 +361,12 @@ impl<A: Access> CompleteAccessor<A> {
                 // Forward path that ends with /
                 if path.ends_with('/') {
                     let (rp, p) = self.inner.list(path, args).await?;
-                    Ok((rp, CompleteLister::One(p)))
+                    Ok((rp, FourWays::One(p)))
                 } else {
                     let parent = get_parent(path);
                     let (rp, p) = self.inner.list(parent, args).await?;
                     let p = PrefixLister::new(p, path);
-                    Ok((rp, CompleteLister::Three(p)))
+                    Ok((rp, FourWays::Three(p)))
                 }
             }
         }
@@ -322,7 +376,7 @@ impl<A: Access> CompleteAccessor<A> {
         &self,
         path: &str,
         args: OpList,
-    ) -> Result<(RpList, CompleteLister<A, A::BlockingLister>)> {
+    ) -> Result<(RpList, CompleteInnerLister<A, A::BlockingLister>)> {
         let cap = self.meta.full_capability();
         if !cap.list {
             return Err(self.new_unsupported_error(Operation::BlockingList));
@@ -334,19 +388,19 @@ impl<A: Access> CompleteAccessor<A> {
             // - If service can list_with_recursive, we can forward list to it directly.
             (_, true) => {
                 let (rp, p) = self.inner.blocking_list(path, args)?;
-                Ok((rp, CompleteLister::One(p)))
+                Ok((rp, FourWays::One(p)))
             }
             // If recursive is true but service can't list_with_recursive
             (true, false) => {
                 // Forward path that ends with /
                 if path.ends_with('/') {
                     let p = FlatLister::new(self.inner.clone(), path);
-                    Ok((RpList::default(), CompleteLister::Two(p)))
+                    Ok((RpList::default(), FourWays::Two(p)))
                 } else {
                     let parent = get_parent(path);
                     let p = FlatLister::new(self.inner.clone(), parent);
                     let p = PrefixLister::new(p, path);
-                    Ok((RpList::default(), CompleteLister::Four(p)))
+                    Ok((RpList::default(), FourWays::Four(p)))
                 }
             }
             // If recursive and service doesn't support list_with_recursive, we need to handle
@@ -355,12 +409,12 @@ impl<A: Access> CompleteAccessor<A> {
                 // Forward path that ends with /
                 if path.ends_with('/') {
                     let (rp, p) = self.inner.blocking_list(path, args)?;
-                    Ok((rp, CompleteLister::One(p)))
+                    Ok((rp, FourWays::One(p)))
                 } else {
                     let parent = get_parent(path);
                     let (rp, p) = self.inner.blocking_list(parent, args)?;
                     let p = PrefixLister::new(p, path);
-                    Ok((rp, CompleteLister::Three(p)))
+                    Ok((rp, FourWays::Three(p)))
                 }
             }
         }
@@ -399,6 +453,15 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
         if !capability.read {
             return Err(self.new_unsupported_error(Operation::Read));
         }
+        if args.if_generation_match().is_some() && !capability.read_with_if_generation_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation read with if_generation_match",
+                    self.meta.scheme()
+                ),
+            ));
+        }
 
         let size = args.range().size();
         self.inner
@@ -421,6 +484,7 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
                 ),
             ));
         }
+        self.check_write_conditions(&args)?;
 
         let (rp, w) = self.inner.write(path, args.clone()).await?;
         let w = CompleteWriter::new(w);
@@ -454,6 +518,15 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
         if !capability.delete {
             return Err(self.new_unsupported_error(Operation::Delete));
         }
+        if args.if_generation_match().is_some() && !capability.delete_with_if_generation_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation delete with if_generation_match",
+                    self.meta.scheme()
+                ),
+            ));
+        }
 
         self.inner().delete(path, args).await
     }
@@ -464,7 +537,14 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
             return Err(self.new_unsupported_error(Operation::List));
         }
 
-        self.complete_list(path, args).await
+        let sorted = args.sorted() && !capability.list_sorted;
+        let (rp, p) = self.complete_list(path, args).await?;
+        let p = if sorted {
+            TwoWays::Two(SortedLister::new(p))
+        } else {
+            TwoWays::One(p)
+        };
+        Ok((rp, p))
     }
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
@@ -472,10 +552,233 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
         if !capability.batch {
             return Err(self.new_unsupported_error(Operation::Batch));
         }
+        let has_stat = args
+            .operation()
+            .iter()
+            .any(|(_, op)| matches!(op, BatchOperation::Stat(_)));
+        if has_stat && !capability.batch_stat {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support stat in batch",
+                    self.meta.scheme()
+                ),
+            )
+            .with_operation(Operation::Batch));
+        }
 
         self.inner().batch(args).await
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let capability = self.meta.full_capability();
+        if !capability.list_multipart_uploads {
+            return Err(self.new_unsupported_error(Operation::ListMultipartUploads));
+        }
+
+        self.inner().list_multipart_uploads(path, args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let capability = self.meta.full_capability();
+        if !capability.abort_multipart_upload {
+            return Err(self.new_unsupported_error(Operation::AbortMultipartUpload));
+        }
+
+        self.inner().abort_multipart_upload(path, args).await
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        let capability = self.meta.full_capability();
+        if !capability.stage_block {
+            return Err(self.new_unsupported_error(Operation::StageBlock));
+        }
+
+        self.inner().stage_block(path, args).await
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        let capability = self.meta.full_capability();
+        if !capability.commit_block_list {
+            return Err(self.new_unsupported_error(Operation::CommitBlockList));
+        }
+        self.check_write_conditions(args.write())?;
+
+        self.inner().commit_block_list(path, args).await
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        let capability = self.meta.full_capability();
+        if !capability.write_at {
+            return Err(self.new_unsupported_error(Operation::WriteAt));
+        }
+        if let Some(align) = capability.write_at_align_size {
+            let align = align as u64;
+            if args.offset() % align != 0 || args.body().len() as u64 % align != 0 {
+                return Err(Error::new(
+                    ErrorKind::RangeNotSatisfied,
+                    format!(
+                        "service {} requires offset and size of write_at to be aligned to {align}",
+                        self.meta.scheme()
+                    ),
+                ));
+            }
+        }
+
+        self.inner().write_at(path, args).await
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        let capability = self.meta.full_capability();
+        if !capability.allocate {
+            return Err(self.new_unsupported_error(Operation::Allocate));
+        }
+        if let Some(align) = capability.allocate_align_size {
+            if args.size() % align as u64 != 0 {
+                return Err(Error::new(
+                    ErrorKind::RangeNotSatisfied,
+                    format!(
+                        "service {} requires size of allocate to be aligned to {align}",
+                        self.meta.scheme()
+                    ),
+                ));
+            }
+        }
+
+        self.inner().allocate(path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let capability = self.meta.full_capability();
+        if !capability.concat {
+            return Err(self.new_unsupported_error(Operation::Concat));
+        }
+        if args.if_generation_match().is_some() && !capability.write_with_if_generation_match {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation concat with if_generation_match",
+                    self.meta.scheme()
+                ),
+            ));
+        }
+        if let Some(max) = capability.concat_max_sources {
+            if args.sources().len() > max {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "the number of sources exceeds the max that service supports in concat",
+                )
+                .with_operation(Operation::Concat)
+                .with_context("sources", args.sources().len())
+                .with_context("max", max));
+            }
+        }
+
+        self.inner().concat(path, args).await
+    }
+
+    async fn locate(&self, path: &str, args: OpLocate) -> Result<RpLocate> {
+        let capability = self.meta.full_capability();
+        if !capability.locate {
+            return Err(self.new_unsupported_error(Operation::Locate));
+        }
+
+        self.inner().locate(path, args).await
+    }
+
+    async fn get_lifecycle(&self, args: OpGetLifecycle) -> Result<RpGetLifecycle> {
+        let capability = self.meta.full_capability();
+        if !capability.lifecycle {
+            return Err(self.new_unsupported_error(Operation::GetLifecycle));
+        }
+
+        self.inner().get_lifecycle(args).await
+    }
+
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        let capability = self.meta.full_capability();
+        if !capability.lifecycle {
+            return Err(self.new_unsupported_error(Operation::SetLifecycle));
+        }
+
+        self.inner().set_lifecycle(args).await
+    }
+
+    async fn get_retention(&self, path: &str, args: OpGetRetention) -> Result<RpGetRetention> {
+        let capability = self.meta.full_capability();
+        if !capability.retention {
+            return Err(self.new_unsupported_error(Operation::GetRetention));
+        }
+
+        self.inner().get_retention(path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        let capability = self.meta.full_capability();
+        if !capability.retention {
+            return Err(self.new_unsupported_error(Operation::SetRetention));
+        }
+
+        self.inner().set_retention(path, args).await
+    }
+
+    async fn get_acl(&self, path: &str, args: OpGetAcl) -> Result<RpGetAcl> {
+        let capability = self.meta.full_capability();
+        if !capability.acl {
+            return Err(self.new_unsupported_error(Operation::GetAcl));
+        }
+
+        self.inner().get_acl(path, args).await
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        let capability = self.meta.full_capability();
+        if !capability.acl {
+            return Err(self.new_unsupported_error(Operation::SetAcl));
+        }
+
+        self.inner().set_acl(path, args).await
+    }
+
+    async fn create_bucket(&self, args: OpCreateBucket) -> Result<RpCreateBucket> {
+        let capability = self.meta.full_capability();
+        if !capability.create_bucket {
+            return Err(self.new_unsupported_error(Operation::CreateBucket));
+        }
+
+        self.inner().create_bucket(args).await
+    }
+
+    async fn delete_bucket(&self, args: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        let capability = self.meta.full_capability();
+        if !capability.delete_bucket {
+            return Err(self.new_unsupported_error(Operation::DeleteBucket));
+        }
+
+        self.inner().delete_bucket(args).await
+    }
+
+    async fn list_buckets(&self, args: OpListBuckets) -> Result<RpListBuckets> {
+        let capability = self.meta.full_capability();
+        if !capability.list_buckets {
+            return Err(self.new_unsupported_error(Operation::ListBuckets));
+        }
+
+        self.inner().list_buckets(args).await
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let capability = self.meta.full_capability();
         if !capability.presign {
@@ -516,6 +819,7 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
                 ),
             ));
         }
+        self.check_write_conditions(&args)?;
 
         self.inner
             .blocking_write(path, args)
@@ -559,11 +863,21 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
             return Err(self.new_unsupported_error(Operation::BlockingList));
         }
 
-        self.complete_blocking_list(path, args)
+        let sorted = args.sorted() && !capability.list_sorted;
+        let (rp, p) = self.complete_blocking_list(path, args)?;
+        let p = if sorted {
+            TwoWays::Two(SortedLister::new(p))
+        } else {
+            TwoWays::One(p)
+        };
+        Ok((rp, p))
     }
 }
 
 pub type CompleteLister<A, P> =
+    TwoWays<CompleteInnerLister<A, P>, SortedLister<CompleteInnerLister<A, P>>>;
+
+type CompleteInnerLister<A, P> =
     FourWays<P, FlatLister<Arc<A>, P>, PrefixLister<P>, PrefixLister<FlatLister<Arc<A>, P>>>;
 
 pub struct CompleteReader<R> {
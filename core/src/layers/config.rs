@@ -0,0 +1,357 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::layers::*;
+use crate::raw::*;
+use crate::*;
+
+/// LayerConfig declares a layer and its parameters, so that layer stacks can be
+/// described in config files instead of code.
+///
+/// LayerConfig can be deserialized via serde with the layer name in `type`:
+///
+/// ```json
+/// [
+///   {"type": "retry", "max_times": 3, "min_delay": 0.5, "jitter": true},
+///   {"type": "timeout", "timeout": 30},
+///   {"type": "logging"}
+/// ]
+/// ```
+///
+/// Or parsed from strings in query style like `retry?max_times=3&jitter=true`.
+///
+/// All durations are in seconds.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::layers::LayerConfig;
+/// use opendal::services::Memory;
+/// use opendal::Operator;
+///
+/// fn test() -> Result<()> {
+///     let layers: Vec<LayerConfig> = serde_json::from_str(
+///         r#"[{"type": "retry", "max_times": 3}, {"type": "logging"}]"#,
+///     )?;
+///     let timeout: LayerConfig = "timeout?timeout=30&io_timeout=5".parse()?;
+///
+///     let op = Operator::new(Memory::default())?
+///         .finish()
+///         .with_layers(layers.into_iter().chain([timeout]))?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum LayerConfig {
+    /// Config for [`RetryLayer`].
+    Retry(RetryLayerConfig),
+    /// Config for [`TimeoutLayer`].
+    Timeout(TimeoutLayerConfig),
+    /// Config for [`ConcurrentLimitLayer`].
+    ConcurrentLimit(ConcurrentLimitLayerConfig),
+    /// Config for [`LoggingLayer`] with the default logger.
+    Logging,
+    /// Config for [`ThrottleLayer`].
+    #[cfg(feature = "layers-throttle")]
+    Throttle(ThrottleLayerConfig),
+    /// Config for [`MetricsLayer`].
+    #[cfg(feature = "layers-metrics")]
+    Metrics,
+    /// Config for [`TracingLayer`].
+    #[cfg(feature = "layers-tracing")]
+    Tracing,
+}
+
+/// Config for [`RetryLayer`], unset fields will use the defaults of [`RetryLayer`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct RetryLayerConfig {
+    /// Max retry times.
+    pub max_times: Option<usize>,
+    /// Min delay between retries in seconds.
+    pub min_delay: Option<f64>,
+    /// Max delay between retries in seconds.
+    pub max_delay: Option<f64>,
+    /// Factor of the backoff, must not be smaller than `1.0`.
+    pub factor: Option<f32>,
+    /// Enable jitter for the backoff or not.
+    pub jitter: bool,
+}
+
+/// Config for [`TimeoutLayer`], unset fields will use the defaults of [`TimeoutLayer`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct TimeoutLayerConfig {
+    /// Timeout for non-io operations in seconds.
+    pub timeout: Option<f64>,
+    /// Timeout for every io operation in seconds.
+    pub io_timeout: Option<f64>,
+}
+
+/// Config for [`ConcurrentLimitLayer`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ConcurrentLimitLayerConfig {
+    /// Max concurrent operations, must be larger than `0`.
+    pub permits: usize,
+}
+
+/// Config for [`ThrottleLayer`].
+#[cfg(feature = "layers-throttle")]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ThrottleLayerConfig {
+    /// Max bytes allowed to pass through per second, must be larger than `0`.
+    pub bandwidth: u32,
+    /// Max bytes allowed to pass through at once, must be larger than `0`.
+    pub burst: u32,
+}
+
+impl LayerConfig {
+    /// Apply this layer to the given operator.
+    pub fn apply(&self, op: Operator) -> Result<Operator> {
+        let op = match self {
+            LayerConfig::Retry(cfg) => {
+                let mut layer = RetryLayer::new();
+                if let Some(v) = cfg.max_times {
+                    layer = layer.with_max_times(v);
+                }
+                if let Some(v) = cfg.min_delay {
+                    layer = layer.with_min_delay(parse_duration("min_delay", v)?);
+                }
+                if let Some(v) = cfg.max_delay {
+                    layer = layer.with_max_delay(parse_duration("max_delay", v)?);
+                }
+                if let Some(v) = cfg.factor {
+                    // RetryLayer panics on invalid factor, check it here instead.
+                    if v.is_nan() || v < 1.0 {
+                        return Err(new_invalid_error("factor must not be smaller than 1.0")
+                            .with_context("factor", v.to_string()));
+                    }
+                    layer = layer.with_factor(v);
+                }
+                if cfg.jitter {
+                    layer = layer.with_jitter();
+                }
+                op.layer(layer)
+            }
+            LayerConfig::Timeout(cfg) => {
+                let mut layer = TimeoutLayer::new();
+                if let Some(v) = cfg.timeout {
+                    layer = layer.with_timeout(parse_duration("timeout", v)?);
+                }
+                if let Some(v) = cfg.io_timeout {
+                    layer = layer.with_io_timeout(parse_duration("io_timeout", v)?);
+                }
+                op.layer(layer)
+            }
+            LayerConfig::ConcurrentLimit(cfg) => {
+                if cfg.permits == 0 {
+                    return Err(new_invalid_error("permits must be larger than 0"));
+                }
+                op.layer(ConcurrentLimitLayer::new(cfg.permits))
+            }
+            LayerConfig::Logging => op.layer(LoggingLayer::default()),
+            #[cfg(feature = "layers-throttle")]
+            LayerConfig::Throttle(cfg) => {
+                if cfg.bandwidth == 0 || cfg.burst == 0 {
+                    return Err(new_invalid_error(
+                        "bandwidth and burst must be larger than 0",
+                    ));
+                }
+                op.layer(ThrottleLayer::new(cfg.bandwidth, cfg.burst))
+            }
+            #[cfg(feature = "layers-metrics")]
+            LayerConfig::Metrics => op.layer(MetricsLayer),
+            #[cfg(feature = "layers-tracing")]
+            LayerConfig::Tracing => op.layer(TracingLayer),
+        };
+
+        Ok(op)
+    }
+}
+
+impl FromStr for LayerConfig {
+    type Err = Error;
+
+    /// Parse layer config from string like `retry?max_times=3&jitter=true`.
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, query) = s.split_once('?').unwrap_or((s, ""));
+
+        let mut map = HashMap::new();
+        for pair in query.split('&') {
+            if pair.is_empty() {
+                continue;
+            }
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            map.insert(key.to_string(), value.to_string());
+        }
+
+        let no_options = |map: HashMap<String, String>, cfg: LayerConfig| {
+            if map.is_empty() {
+                Ok(cfg)
+            } else {
+                Err(new_invalid_error("layer doesn't accept any options").with_context("layer", s))
+            }
+        };
+
+        match name.trim().to_lowercase().as_str() {
+            "retry" => Ok(LayerConfig::Retry(from_map(s, map)?)),
+            "timeout" => Ok(LayerConfig::Timeout(from_map(s, map)?)),
+            "concurrent_limit" => Ok(LayerConfig::ConcurrentLimit(from_map(s, map)?)),
+            "logging" => no_options(map, LayerConfig::Logging),
+            #[cfg(feature = "layers-throttle")]
+            "throttle" => Ok(LayerConfig::Throttle(from_map(s, map)?)),
+            #[cfg(feature = "layers-metrics")]
+            "metrics" => no_options(map, LayerConfig::Metrics),
+            #[cfg(feature = "layers-tracing")]
+            "tracing" => no_options(map, LayerConfig::Tracing),
+            _ => Err(new_invalid_error("layer is unknown or not enabled").with_context("layer", s)),
+        }
+    }
+}
+
+fn from_map<T: DeserializeOwned>(input: &str, map: HashMap<String, String>) -> Result<T> {
+    T::deserialize(ConfigDeserializer::new(map)).map_err(|err| {
+        new_invalid_error("failed to deserialize layer config")
+            .with_context("layer", input)
+            .set_source(err)
+    })
+}
+
+fn parse_duration(name: &'static str, secs: f64) -> Result<Duration> {
+    Duration::try_from_secs_f64(secs).map_err(|err| {
+        new_invalid_error("duration is invalid")
+            .with_context(name, secs.to_string())
+            .set_source(err)
+    })
+}
+
+fn new_invalid_error(msg: &'static str) -> Error {
+    Error::new(ErrorKind::ConfigInvalid, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layer_config_from_str() {
+        let cases = vec![
+            (
+                "retry?max_times=3&min_delay=0.5&jitter=true",
+                LayerConfig::Retry(RetryLayerConfig {
+                    max_times: Some(3),
+                    min_delay: Some(0.5),
+                    jitter: true,
+                    ..Default::default()
+                }),
+            ),
+            (
+                "timeout?io_timeout=5",
+                LayerConfig::Timeout(TimeoutLayerConfig {
+                    io_timeout: Some(5.0),
+                    ..Default::default()
+                }),
+            ),
+            (
+                "concurrent_limit?permits=8",
+                LayerConfig::ConcurrentLimit(ConcurrentLimitLayerConfig { permits: 8 }),
+            ),
+            ("logging", LayerConfig::Logging),
+        ];
+
+        for (input, expected) in cases {
+            let cfg: LayerConfig = input.parse().expect("layer config must be valid");
+            assert_eq!(cfg, expected, "{input}");
+        }
+
+        for input in ["unknown", "logging?level=debug", "retry?max_times=x"] {
+            let err = input
+                .parse::<LayerConfig>()
+                .expect_err("layer config must be invalid");
+            assert_eq!(err.kind(), ErrorKind::ConfigInvalid, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_layer_config_deserialize() {
+        let cfgs: Vec<LayerConfig> = serde_json::from_str(
+            r#"[
+                {"type": "retry", "max_times": 3, "factor": 1.5},
+                {"type": "timeout", "timeout": 30},
+                {"type": "logging"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            cfgs,
+            vec![
+                LayerConfig::Retry(RetryLayerConfig {
+                    max_times: Some(3),
+                    factor: Some(1.5),
+                    ..Default::default()
+                }),
+                LayerConfig::Timeout(TimeoutLayerConfig {
+                    timeout: Some(30.0),
+                    ..Default::default()
+                }),
+                LayerConfig::Logging,
+            ]
+        );
+    }
+
+    #[cfg(feature = "services-memory")]
+    #[tokio::test]
+    async fn test_layer_config_apply() {
+        let op = Operator::new(services::Memory::default())
+            .unwrap()
+            .finish()
+            .with_layers([
+                "retry?max_times=3".parse().unwrap(),
+                "timeout?timeout=10".parse().unwrap(),
+                "concurrent_limit?permits=2".parse().unwrap(),
+            ])
+            .unwrap();
+        op.write("test", "hello").await.unwrap();
+        assert_eq!(op.read("test").await.unwrap().to_vec(), b"hello");
+
+        for input in ["retry?factor=0.5", "timeout?timeout=-1", "concurrent_limit"] {
+            let cfg: LayerConfig = input.parse().unwrap();
+            let err = cfg
+                .apply(op.clone())
+                .expect_err("layer config must be rejected");
+            assert_eq!(err.kind(), ErrorKind::ConfigInvalid, "{input}");
+        }
+    }
+}
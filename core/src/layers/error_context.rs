@@ -226,6 +226,183 @@ impl<A: Access> LayeredAccess for ErrorContextAccessor<A> {
         })
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        self.inner
+            .list_multipart_uploads(path, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::ListMultipartUploads)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let upload_id = args.upload_id().to_string();
+        self.inner
+            .abort_multipart_upload(path, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::AbortMultipartUpload)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+                    .with_context("upload_id", upload_id)
+            })
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        let block_id = args.block_id().to_string();
+        let size = args.body().len();
+        self.inner.stage_block(path, args).await.map_err(|err| {
+            err.with_operation(Operation::StageBlock)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+                .with_context("block_id", block_id)
+                .with_context("size", size.to_string())
+        })
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        let blocks = args.block_ids().len();
+        self.inner
+            .commit_block_list(path, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::CommitBlockList)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+                    .with_context("blocks", blocks)
+            })
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        let offset = args.offset();
+        let size = args.body().len();
+        self.inner.write_at(path, args).await.map_err(|err| {
+            err.with_operation(Operation::WriteAt)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+                .with_context("offset", offset)
+                .with_context("size", size)
+        })
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        let size = args.size();
+        self.inner.allocate(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Allocate)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+                .with_context("size", size)
+        })
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let sources = args.sources().len();
+        self.inner.concat(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Concat)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+                .with_context("sources", sources)
+        })
+    }
+
+    async fn locate(&self, path: &str, args: OpLocate) -> Result<RpLocate> {
+        let range = args.range();
+        self.inner.locate(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Locate)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+                .with_context("range", range.to_string())
+        })
+    }
+
+    async fn get_lifecycle(&self, args: OpGetLifecycle) -> Result<RpGetLifecycle> {
+        self.inner.get_lifecycle(args).await.map_err(|err| {
+            err.with_operation(Operation::GetLifecycle)
+                .with_context("service", self.meta.scheme())
+        })
+    }
+
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        let rules = args.rules().len();
+        self.inner.set_lifecycle(args).await.map_err(|err| {
+            err.with_operation(Operation::SetLifecycle)
+                .with_context("service", self.meta.scheme())
+                .with_context("rules", rules)
+        })
+    }
+
+    async fn get_retention(&self, path: &str, args: OpGetRetention) -> Result<RpGetRetention> {
+        self.inner.get_retention(path, args).await.map_err(|err| {
+            err.with_operation(Operation::GetRetention)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        self.inner.set_retention(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetRetention)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn get_acl(&self, path: &str, args: OpGetAcl) -> Result<RpGetAcl> {
+        self.inner.get_acl(path, args).await.map_err(|err| {
+            err.with_operation(Operation::GetAcl)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        let acl = args.acl();
+        self.inner.set_acl(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetAcl)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+                .with_context("acl", acl)
+        })
+    }
+
+    async fn create_bucket(&self, args: OpCreateBucket) -> Result<RpCreateBucket> {
+        self.inner.create_bucket(args).await.map_err(|err| {
+            err.with_operation(Operation::CreateBucket)
+                .with_context("service", self.meta.scheme())
+                .with_context("name", self.meta.name())
+        })
+    }
+
+    async fn delete_bucket(&self, args: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        self.inner.delete_bucket(args).await.map_err(|err| {
+            err.with_operation(Operation::DeleteBucket)
+                .with_context("service", self.meta.scheme())
+                .with_context("name", self.meta.name())
+        })
+    }
+
+    async fn list_buckets(&self, args: OpListBuckets) -> Result<RpListBuckets> {
+        self.inner.list_buckets(args).await.map_err(|err| {
+            err.with_operation(Operation::ListBuckets)
+                .with_context("service", self.meta.scheme())
+                .with_context("name", self.meta.name())
+        })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner.blocking_create_dir(path, args).map_err(|err| {
             err.with_operation(Operation::BlockingCreateDir)
@@ -0,0 +1,441 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use crate::raw::oio::BlockingRead;
+use crate::raw::oio::BlockingWrite;
+use crate::raw::oio::Read;
+use crate::raw::oio::Write;
+use crate::raw::*;
+use crate::*;
+
+/// Read through a secondary operator if the object is not found in the primary one.
+///
+/// This is useful for tiered storage, for example, serving the hot data from a
+/// local cache while the full data set lives in an archive bucket.
+///
+/// # Behavior
+///
+/// - `read` and `stat` that returned [`ErrorKind::NotFound`] will be retried on
+///   the secondary operator.
+/// - If backfill is enabled, objects read from the secondary operator will be
+///   written back to the primary operator, so that the following reads will hit
+///   the primary operator directly. Backfill failures are logged and the content
+///   will be served from the secondary operator instead.
+/// - All other operations only go to the primary operator.
+///
+/// # Notes
+///
+/// - Backfill copies the whole object from the secondary operator in chunks, no
+///   matter which range is requested.
+/// - `stat` never triggers backfill.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::FallbackLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # fn main() -> Result<()> {
+/// let archive = Operator::new(services::Memory::default())?.finish();
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(FallbackLayer::new(archive).with_backfill(true))
+///     .finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FallbackLayer {
+    secondary: Accessor,
+    backfill: bool,
+}
+
+impl Debug for FallbackLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FallbackLayer")
+            .field("secondary", &self.secondary.info().scheme())
+            .field("backfill", &self.backfill)
+            .finish()
+    }
+}
+
+impl FallbackLayer {
+    /// Create a new FallbackLayer that reads through the given secondary operator.
+    pub fn new(secondary: Operator) -> Self {
+        Self {
+            secondary: secondary.into_inner(),
+            backfill: false,
+        }
+    }
+
+    /// Write objects read from the secondary operator back to the primary operator.
+    ///
+    /// Default to `false`.
+    pub fn with_backfill(mut self, enabled: bool) -> Self {
+        self.backfill = enabled;
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for FallbackLayer {
+    type LayeredAccess = FallbackAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        FallbackAccessor {
+            inner,
+            secondary: self.secondary.clone(),
+            backfill: self.backfill,
+        }
+    }
+}
+
+pub struct FallbackAccessor<A: Access> {
+    inner: A,
+    secondary: Accessor,
+    backfill: bool,
+}
+
+impl<A: Access> Debug for FallbackAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FallbackAccessor")
+            .field("inner", &self.inner)
+            .field("backfill", &self.backfill)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> FallbackAccessor<A> {
+    /// Build the args to write the object back with its metadata.
+    fn backfill_args(meta: &Metadata) -> OpWrite {
+        let mut args = OpWrite::default();
+        if let Some(v) = meta.content_type() {
+            args = args.with_content_type(v);
+        }
+        if let Some(v) = meta.content_disposition() {
+            args = args.with_content_disposition(v);
+        }
+        if let Some(v) = meta.cache_control() {
+            args = args.with_cache_control(v);
+        }
+        args
+    }
+
+    /// Build the args to stat the object to read, keeping its version and conditions.
+    fn stat_args(args: &OpRead) -> OpStat {
+        let mut op = OpStat::new().with_context(args.context().clone());
+        if let Some(v) = args.if_match() {
+            op = op.with_if_match(v);
+        }
+        if let Some(v) = args.if_none_match() {
+            op = op.with_if_none_match(v);
+        }
+        if let Some(v) = args.version() {
+            op = op.with_version(v);
+        }
+        op
+    }
+
+    /// Copy the whole object from the secondary operator to the primary one.
+    ///
+    /// The primary writer will be aborted if backfill failed.
+    async fn backfill(&self, path: &str, args: &OpRead, meta: &Metadata) -> Result<()> {
+        let (_, mut r) = self
+            .secondary
+            .read(path, args.clone().with_range(BytesRange::default()))
+            .await?;
+        let (_, mut w) = self.inner.write(path, Self::backfill_args(meta)).await?;
+
+        let res = async {
+            loop {
+                let bs = r.read().await?;
+                if bs.is_empty() {
+                    break;
+                }
+                w.write(bs).await?;
+            }
+            w.close().await
+        }
+        .await;
+        if res.is_err() {
+            if let Err(err) = w.abort().await {
+                log::warn!("fallback abort backfill {path} failed: {err}");
+            }
+        }
+        res
+    }
+
+    fn blocking_backfill(&self, path: &str, args: &OpRead, meta: &Metadata) -> Result<()> {
+        let (_, mut r) = self
+            .secondary
+            .blocking_read(path, args.clone().with_range(BytesRange::default()))?;
+        let (_, mut w) = self.inner.blocking_write(path, Self::backfill_args(meta))?;
+        loop {
+            let bs = r.read()?;
+            if bs.is_empty() {
+                break;
+            }
+            w.write(bs)?;
+        }
+        // `BlockingWrite` can't be aborted, the writer will be dropped on failure.
+        w.close()
+    }
+}
+
+impl<A: Access> LayeredAccess for FallbackAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, oio::Reader>;
+    type BlockingReader = TwoWays<A::BlockingReader, oio::BlockingReader>;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        match self.inner.read(path, args.clone()).await {
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            res => return res.map(|(rp, r)| (rp, TwoWays::One(r))),
+        }
+
+        if self.backfill {
+            // Only the secondary operator decides whether the object exists.
+            let meta = self
+                .secondary
+                .stat(path, Self::stat_args(&args))
+                .await?
+                .into_metadata();
+            match self.backfill(path, &args, &meta).await {
+                Ok(()) => {
+                    return self
+                        .inner
+                        .read(path, args)
+                        .await
+                        .map(|(rp, r)| (rp, TwoWays::One(r)))
+                }
+                Err(err) => log::warn!("fallback backfill {path} failed: {err}"),
+            }
+        }
+
+        self.secondary
+            .read(path, args)
+            .await
+            .map(|(rp, r)| (rp, TwoWays::Two(r)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.inner.stat(path, args.clone()).await {
+            Err(err) if err.kind() == ErrorKind::NotFound => self.secondary.stat(path, args).await,
+            res => res,
+        }
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        match self.inner.blocking_read(path, args.clone()) {
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            res => return res.map(|(rp, r)| (rp, TwoWays::One(r))),
+        }
+
+        if self.backfill {
+            // Only the secondary operator decides whether the object exists.
+            let meta = self
+                .secondary
+                .blocking_stat(path, Self::stat_args(&args))?
+                .into_metadata();
+            match self.blocking_backfill(path, &args, &meta) {
+                Ok(()) => {
+                    return self
+                        .inner
+                        .blocking_read(path, args)
+                        .map(|(rp, r)| (rp, TwoWays::One(r)))
+                }
+                Err(err) => log::warn!("fallback backfill {path} failed: {err}"),
+            }
+        }
+
+        self.secondary
+            .blocking_read(path, args)
+            .map(|(rp, r)| (rp, TwoWays::Two(r)))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.inner.blocking_stat(path, args.clone()) {
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                self.secondary.blocking_stat(path, args)
+            }
+            res => res,
+        }
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::services::Memory;
+
+    fn new_memory() -> Operator {
+        Operator::new(Memory::default()).unwrap().finish()
+    }
+
+    #[tokio::test]
+    async fn test_fallback_read_through() {
+        let primary = new_memory();
+        let secondary = new_memory();
+        secondary.write("file", "hello, world").await.unwrap();
+
+        let op = primary.clone().layer(FallbackLayer::new(secondary.clone()));
+        assert_eq!(op.stat("file").await.unwrap().content_length(), 12);
+        assert_eq!(op.read("file").await.unwrap().to_vec(), b"hello, world");
+        assert!(!primary.is_exist("file").await.unwrap());
+
+        let err = op.read("not_exist").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_backfill() {
+        let primary = new_memory();
+        let secondary = new_memory();
+        secondary
+            .write_with("file", "hello, world")
+            .content_type("text/plain")
+            .await
+            .unwrap();
+
+        let op = primary
+            .clone()
+            .layer(FallbackLayer::new(secondary.clone()).with_backfill(true));
+        assert_eq!(
+            op.read_with("file").range(7..).await.unwrap().to_vec(),
+            b"world"
+        );
+
+        let meta = primary.stat("file").await.unwrap();
+        assert_eq!(meta.content_length(), 12);
+        assert_eq!(meta.content_type(), Some("text/plain"));
+
+        let err = op.read("not_exist").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert!(!primary.is_exist("not_exist").await.unwrap());
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct MockService {
+        aborted: Arc<AtomicBool>,
+    }
+
+    impl Access for MockService {
+        type Reader = ();
+        type Writer = MockWriter;
+        type Lister = ();
+        type BlockingReader = ();
+        type BlockingWriter = ();
+        type BlockingLister = ();
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let mut am = AccessorInfo::default();
+            am.set_native_capability(Capability {
+                read: true,
+                write: true,
+                stat: true,
+                ..Default::default()
+            });
+            am.into()
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            Err(Error::new(ErrorKind::NotFound, "not found in primary"))
+        }
+
+        async fn read(&self, _: &str, _: OpRead) -> Result<(RpRead, Self::Reader)> {
+            Err(Error::new(ErrorKind::NotFound, "not found in primary"))
+        }
+
+        async fn write(&self, _: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            Ok((
+                RpWrite::new(),
+                MockWriter {
+                    aborted: self.aborted.clone(),
+                },
+            ))
+        }
+    }
+
+    struct MockWriter {
+        aborted: Arc<AtomicBool>,
+    }
+
+    impl oio::Write for MockWriter {
+        async fn write(&mut self, _: Buffer) -> Result<()> {
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            Err(Error::new(
+                ErrorKind::NotFound,
+                "parent not found in primary",
+            ))
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            self.aborted.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fallback_backfill_failed() {
+        let secondary = new_memory();
+        secondary.write("file", "hello, world").await.unwrap();
+
+        let srv = MockService::default();
+        let op = OperatorBuilder::new(srv.clone())
+            .finish()
+            .layer(FallbackLayer::new(secondary).with_backfill(true));
+
+        // NotFound from the primary write must not fail the read.
+        assert_eq!(op.read("file").await.unwrap().to_vec(), b"hello, world");
+        assert!(srv.aborted.load(Ordering::SeqCst));
+    }
+}
@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use fastrace::prelude::*;
 use futures::FutureExt;
@@ -108,8 +109,52 @@ use crate::*;
 /// ```
 ///
 /// For real-world usage, please take a look at [`fastrace-datadog`](https://crates.io/crates/fastrace-datadog) or [`fastrace-jaeger`](https://crates.io/crates/fastrace-jaeger) .
+///
+/// The time spent on network can be attached to the spans by [`FastraceHttpObserver`].
+///
+/// # Overhead
+///
+/// Compared to `TracingLayer`, spans are only recorded when
+/// the `enable` feature of `fastrace` is turned on by the application. Otherwise, all spans
+/// are no-op and this layer adds nearly zero overhead, so libraries can always add it and
+/// let the final application decide whether to collect traces.
 pub struct FastraceLayer;
 
+/// Attach the [`HttpPhase`] of http requests to the current span as events.
+///
+/// Every event is named like `http.ttfb` and carries the `host` and `duration_us`
+/// properties. Pass it to [`HttpClient::with_observer`] of the service:
+///
+/// ```no_build
+/// use std::sync::Arc;
+///
+/// use opendal::layers::FastraceHttpObserver;
+/// use opendal::raw::HttpClient;
+///
+/// let client = HttpClient::new()?.with_observer(Arc::new(FastraceHttpObserver));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FastraceHttpObserver;
+
+impl HttpObserve for FastraceHttpObserver {
+    fn observe(&self, host: &str, phase: HttpPhase, duration: Duration) {
+        let name = match phase {
+            HttpPhase::Dns => "http.dns",
+            HttpPhase::Ttfb => "http.ttfb",
+            HttpPhase::Transfer => "http.transfer",
+        };
+        Event::add_to_local_parent(name, || {
+            [
+                ("host".into(), host.to_string().into()),
+                (
+                    "duration_us".into(),
+                    duration.as_micros().to_string().into(),
+                ),
+            ]
+        });
+    }
+}
+
 impl<A: Access> Layer<A> for FastraceLayer {
     type LayeredAccess = FastraceAccessor<A>;
 
@@ -211,6 +256,103 @@ impl<A: Access> LayeredAccess for FastraceAccessor<A> {
             .await
     }
 
+    #[trace(enter_on_poll = true)]
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        self.inner.list_multipart_uploads(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        self.inner.abort_multipart_upload(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        self.inner.stage_block(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        self.inner.commit_block_list(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        self.inner.write_at(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        self.inner.allocate(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.inner.concat(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn locate(&self, path: &str, args: OpLocate) -> Result<RpLocate> {
+        self.inner.locate(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn get_lifecycle(&self, args: OpGetLifecycle) -> Result<RpGetLifecycle> {
+        self.inner.get_lifecycle(args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        self.inner.set_lifecycle(args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn get_retention(&self, path: &str, args: OpGetRetention) -> Result<RpGetRetention> {
+        self.inner.get_retention(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        self.inner.set_retention(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn get_acl(&self, path: &str, args: OpGetAcl) -> Result<RpGetAcl> {
+        self.inner.get_acl(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        self.inner.set_acl(path, args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn create_bucket(&self, args: OpCreateBucket) -> Result<RpCreateBucket> {
+        self.inner.create_bucket(args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn delete_bucket(&self, args: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        self.inner.delete_bucket(args).await
+    }
+
+    #[trace(enter_on_poll = true)]
+    async fn list_buckets(&self, args: OpListBuckets) -> Result<RpListBuckets> {
+        self.inner.list_buckets(args).await
+    }
+
     #[trace(enter_on_poll = true)]
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         self.inner.presign(path, args).await
@@ -305,21 +447,24 @@ impl<R: oio::BlockingRead> oio::BlockingRead for FastraceWrapper<R> {
 
 impl<R: oio::Write> oio::Write for FastraceWrapper<R> {
     fn write(&mut self, bs: Buffer) -> impl Future<Output = Result<()>> + MaybeSend {
-        let _g = self.span.set_local_parent();
-        let _span = LocalSpan::enter_with_local_parent(Operation::WriterWrite.into_static());
-        self.inner.write(bs)
+        self.inner.write(bs).in_span(Span::enter_with_parent(
+            Operation::WriterWrite.into_static(),
+            &self.span,
+        ))
     }
 
     fn abort(&mut self) -> impl Future<Output = Result<()>> + MaybeSend {
-        let _g = self.span.set_local_parent();
-        let _span = LocalSpan::enter_with_local_parent(Operation::WriterAbort.into_static());
-        self.inner.abort()
+        self.inner.abort().in_span(Span::enter_with_parent(
+            Operation::WriterAbort.into_static(),
+            &self.span,
+        ))
     }
 
     fn close(&mut self) -> impl Future<Output = Result<()>> + MaybeSend {
-        let _g = self.span.set_local_parent();
-        let _span = LocalSpan::enter_with_local_parent(Operation::WriterClose.into_static());
-        self.inner.close()
+        self.inner.close().in_span(Span::enter_with_parent(
+            Operation::WriterClose.into_static(),
+            &self.span,
+        ))
     }
 }
 
@@ -15,13 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::fmt::Write;
 use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
 use futures::FutureExt;
 use futures::TryFutureExt;
 use log::log;
+use log::log_enabled;
 use log::Level;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
 
 use crate::raw::*;
 use crate::*;
@@ -39,6 +46,44 @@ use crate::*;
 /// - The default log level while expected error happened is `Warn`.
 /// - The default log level while unexpected failure happened is `Error`.
 ///
+/// Every log entry carries the context in `key=value` fields:
+///
+/// - `path`, `from`, `to`: the paths of the operation.
+/// - `range`: the range to read.
+/// - `read`, `written`, `size`: the bytes of reader and writer.
+/// - `duration`: the time taken by the operation.
+/// - `error_kind`: the [`ErrorKind`] of failed operation.
+///
+/// # Verbosity
+///
+/// Oneshot operations like `stat` are logged at `Debug` while the others like
+/// `ReaderRead` are logged at `Trace`. The levels can be changed per operation:
+///
+/// ```no_run
+/// use log::Level;
+/// use opendal::layers::LoggingLayer;
+/// use opendal::raw::Operation;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         LoggingLayer::default()
+///             .with_operation_level(Operation::Stat, Level::Trace)
+///             .with_operation_level(Operation::List, Level::Info)
+///             .with_unexpected_error_level(Level::Warn),
+///     )
+///     .finish();
+/// ```
+///
+/// # Redaction
+///
+/// Error messages might contain sensitive values like the signatures of presigned
+/// urls. Values of well known sensitive keys like `X-Amz-Signature`, `sig` and
+/// `token` in `key=value` form will be replaced with `***`. More keys can be added
+/// via [`LoggingLayer::with_redacted_key`].
+///
 /// # Examples
 ///
 /// ```no_run
@@ -112,7 +157,7 @@ pub struct LoggingLayer<I = DefaultLoggingInterceptor> {
 impl Default for LoggingLayer {
     fn default() -> Self {
         Self {
-            logger: DefaultLoggingInterceptor,
+            logger: DefaultLoggingInterceptor::default(),
         }
     }
 }
@@ -122,6 +167,34 @@ impl LoggingLayer {
     pub fn new<I: LoggingInterceptor>(logger: I) -> LoggingLayer<I> {
         LoggingLayer { logger }
     }
+
+    /// Set the log level of given operation.
+    ///
+    /// Errors of this operation will still be logged at the error levels.
+    pub fn with_operation_level(mut self, operation: Operation, level: Level) -> Self {
+        Arc::make_mut(&mut self.logger.operation_levels).insert(operation, level);
+        self
+    }
+
+    /// Set the log level while expected error happened, default to `Warn`.
+    pub fn with_error_level(mut self, level: Level) -> Self {
+        self.logger.error_level = level;
+        self
+    }
+
+    /// Set the log level while unexpected failure happened, default to `Error`.
+    pub fn with_unexpected_error_level(mut self, level: Level) -> Self {
+        self.logger.unexpected_error_level = level;
+        self
+    }
+
+    /// Add a key whose value should be redacted in logs.
+    ///
+    /// The key is matched case-insensitively in `key=value` form.
+    pub fn with_redacted_key(mut self, key: &str) -> Self {
+        Arc::make_mut(&mut self.logger.redacted_keys).push(key.to_lowercase());
+        self
+    }
 }
 
 impl<A: Access, I: LoggingInterceptor> Layer<A> for LoggingLayer<I> {
@@ -165,9 +238,61 @@ pub trait LoggingInterceptor: Debug + Clone + Send + Sync + Unpin + 'static {
     );
 }
 
+/// Keys whose values should be redacted by default.
+const DEFAULT_REDACTED_KEYS: &[&str] = &[
+    "x-amz-signature",
+    "x-amz-credential",
+    "x-amz-security-token",
+    "x-goog-signature",
+    "x-goog-credential",
+    "signature",
+    "sig",
+    "ossaccesskeyid",
+    "access_token",
+    "token",
+    "secret",
+    "password",
+];
+
 /// The DefaultLoggingInterceptor will log the message by the standard logging macro.
-#[derive(Debug, Copy, Clone, Default)]
-pub struct DefaultLoggingInterceptor;
+#[derive(Debug, Clone)]
+pub struct DefaultLoggingInterceptor {
+    operation_levels: Arc<HashMap<Operation, Level>>,
+    error_level: Level,
+    unexpected_error_level: Level,
+    redacted_keys: Arc<Vec<String>>,
+}
+
+impl Default for DefaultLoggingInterceptor {
+    fn default() -> Self {
+        Self {
+            operation_levels: Arc::default(),
+            error_level: Level::Warn,
+            unexpected_error_level: Level::Error,
+            redacted_keys: Arc::new(
+                DEFAULT_REDACTED_KEYS
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl DefaultLoggingInterceptor {
+    fn operation_level(&self, operation: Operation) -> Level {
+        if let Some(lvl) = self.operation_levels.get(&operation) {
+            return *lvl;
+        }
+
+        // Print debug message if operation is oneshot, otherwise in trace.
+        if operation.is_oneshot() {
+            Level::Debug
+        } else {
+            Level::Trace
+        }
+    }
+}
 
 impl LoggingInterceptor for DefaultLoggingInterceptor {
     #[inline]
@@ -179,69 +304,81 @@ impl LoggingInterceptor for DefaultLoggingInterceptor {
         message: &str,
         err: Option<&Error>,
     ) {
+        let lvl = match err {
+            Some(err) if err.kind() == ErrorKind::Unexpected => self.unexpected_error_level,
+            Some(_) => self.error_level,
+            None => self.operation_level(operation),
+        };
+        if !log_enabled!(target: LOGGING_TARGET, lvl) {
+            return;
+        }
+
+        let mut line = format!("service={} name={}", info.scheme(), info.name());
+        for (k, v) in context {
+            let _ = write!(line, " {k}={v}");
+        }
+        if let Some(err) = err {
+            let _ = write!(line, " error_kind={}", err.kind());
+        }
+        let _ = write!(line, ": {operation} {message}");
         if let Some(err) = err {
-            // Print error if it's unexpected, otherwise in warn.
-            let lvl = if err.kind() == ErrorKind::Unexpected {
-                Level::Error
+            // Print error message with debug output while unexpected happened.
+            if err.kind() == ErrorKind::Unexpected {
+                let _ = write!(line, " {err:?}");
             } else {
-                Level::Warn
-            };
-
-            log!(
-                target: LOGGING_TARGET,
-                lvl,
-                "service={} name={} {}: {operation} {message} {}",
-                info.scheme(),
-                info.name(),
-                format_args!(
-                    "{}",
-                    context.iter().enumerate().map(|(i, (k, v))| {
-                        if i > 0 {
-                            format!(" {}={}", k, v)
-                        } else {
-                            format!("{}={}", k, v)
-                        }
-                    }).collect::<String>()
-                ),
-                // Print error message with debug output while unexpected happened.
-                //
-                // It's super sad that we can't bind `format_args!()` here.
-                // See: https://github.com/rust-lang/rust/issues/92698
-                if err.kind() != ErrorKind::Unexpected {
-                   format!("{err}")
-                } else {
-                   format!("{err:?}")
-                }
-            );
+                let _ = write!(line, " {err}");
+            }
         }
 
-        // Print debug message if operation is oneshot, otherwise in trace.
-        let lvl = if operation.is_oneshot() {
-            Level::Debug
-        } else {
-            Level::Trace
-        };
-
         log!(
             target: LOGGING_TARGET,
             lvl,
-            "service={} name={} {}: {operation} {message}",
-            info.scheme(),
-            info.name(),
-            format_args!(
-                "{}",
-                context.iter().enumerate().map(|(i, (k, v))| {
-                    if i > 0 {
-                        format!(" {}={}", k, v)
-                    } else {
-                        format!("{}={}", k, v)
-                    }
-                }).collect::<String>()
-            ),
+            "{}",
+            redact(&line, &self.redacted_keys)
         );
     }
 }
 
+/// Replace the values of given keys in `key=value` form with `***`.
+fn redact(line: &str, keys: &[String]) -> String {
+    let lower = line.to_ascii_lowercase();
+    let bs = lower.as_bytes();
+
+    let mut out = String::with_capacity(line.len());
+    let mut last = 0;
+    let mut idx = 0;
+    while let Some(pos) = lower[idx..].find('=').map(|v| v + idx) {
+        idx = pos + 1;
+
+        // Find the key right before `=`.
+        let key_start = lower[..pos]
+            .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+            .map(|v| v + 1)
+            .unwrap_or(0);
+        if !keys.iter().any(|k| k == &lower[key_start..pos]) {
+            continue;
+        }
+
+        // `: ` is the separator between context and message.
+        let value_end = (idx..bs.len())
+            .find(|&i| {
+                b"&;,()[]{}\"' \t\n".contains(&bs[i])
+                    || (bs[i] == b':' && bs.get(i + 1) == Some(&b' '))
+            })
+            .unwrap_or(bs.len());
+        if value_end == idx {
+            continue;
+        }
+
+        out.push_str(&line[last..idx]);
+        out.push_str("***");
+        last = value_end;
+        idx = value_end;
+    }
+    out.push_str(&line[last..]);
+    out
+}
+
 #[derive(Clone, Debug)]
 pub struct LoggingAccessor<A: Access, I: LoggingInterceptor> {
     inner: A,
@@ -252,6 +389,24 @@ pub struct LoggingAccessor<A: Access, I: LoggingInterceptor> {
 
 static LOGGING_TARGET: &str = "opendal::services";
 
+impl<A: Access, I: LoggingInterceptor> LoggingAccessor<A, I> {
+    /// Log the finish of an operation with the duration since `start`.
+    fn log_finished(
+        &self,
+        operation: Operation,
+        context: &[(&str, &str)],
+        start: Instant,
+        message: &str,
+        err: Option<&Error>,
+    ) {
+        let duration = format!("{:?}", start.elapsed());
+        let mut context = context.to_vec();
+        context.push(("duration", &duration));
+        self.logger
+            .log(&self.info, operation, &context, message, err);
+    }
+}
+
 impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
     type Inner = A;
     type Reader = LoggingReader<A::Reader, I>;
@@ -278,279 +433,176 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
     }
 
     async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
-        self.logger.log(
-            &self.info,
-            Operation::CreateDir,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::CreateDir, &ctx, "started", None);
 
         self.inner
             .create_dir(path, args)
             .await
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::CreateDir,
-                    &[("path", path)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::CreateDir, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::CreateDir,
-                    &[("path", path)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::CreateDir, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
-        self.logger.log(
-            &self.info,
-            Operation::Read,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let range = args.range().to_string();
+        let ctx = with_request_id(&[("path", path), ("range", &range)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::Read, &ctx, "started", None);
 
         self.inner
             .read(path, args)
             .await
             .map(|(rp, r)| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Read,
-                    &[("path", path)],
-                    "created reader",
-                    None,
-                );
+                self.log_finished(Operation::Read, &ctx, start, "created reader", None);
                 (
                     rp,
                     LoggingReader::new(self.info.clone(), self.logger.clone(), path, r),
                 )
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Read,
-                    &[("path", path)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::Read, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        self.logger.log(
-            &self.info,
-            Operation::Write,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::Write, &ctx, "started", None);
 
         self.inner
             .write(path, args)
             .await
             .map(|(rp, w)| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Write,
-                    &[("path", path)],
-                    "created writer",
-                    None,
-                );
+                self.log_finished(Operation::Write, &ctx, start, "created writer", None);
                 let w = LoggingWriter::new(self.info.clone(), self.logger.clone(), path, w);
                 (rp, w)
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Write,
-                    &[("path", path)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::Write, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
-        self.logger.log(
-            &self.info,
-            Operation::Copy,
-            &[("from", from), ("to", to)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("from", from), ("to", to)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::Copy, &ctx, "started", None);
 
         self.inner
             .copy(from, to, args)
             .await
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Copy,
-                    &[("from", from), ("to", to)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::Copy, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Copy,
-                    &[("from", from), ("to", to)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::Copy, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
-        self.logger.log(
-            &self.info,
-            Operation::Rename,
-            &[("from", from), ("to", to)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("from", from), ("to", to)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::Rename, &ctx, "started", None);
 
         self.inner
             .rename(from, to, args)
             .await
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Rename,
-                    &[("from", from), ("to", to)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::Rename, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Rename,
-                    &[("from", from), ("to", to)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::Rename, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
-        self.logger.log(
-            &self.info,
-            Operation::Stat,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::Stat, &ctx, "started", None);
 
         self.inner
             .stat(path, args)
             .await
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Stat,
-                    &[("path", path)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::Stat, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::Stat,
-                    &[("path", path)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::Stat, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
-        self.logger.log(
-            &self.info,
-            Operation::Delete,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::Delete, &ctx, "started", None);
 
         self.inner
             .delete(path, args.clone())
             .inspect(|v| match v {
                 Ok(_) => {
-                    self.logger.log(
-                        &self.info,
-                        Operation::Delete,
-                        &[("path", path)],
-                        "finished",
-                        None,
-                    );
+                    self.log_finished(Operation::Delete, &ctx, start, "finished", None);
                 }
                 Err(err) => {
-                    self.logger.log(
-                        &self.info,
-                        Operation::Delete,
-                        &[("path", path)],
-                        "failed",
-                        Some(err),
-                    );
+                    self.log_finished(Operation::Delete, &ctx, start, "failed", Some(err));
                 }
             })
             .await
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
-        self.logger.log(
-            &self.info,
-            Operation::List,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::List, &ctx, "started", None);
 
         self.inner
             .list(path, args)
             .map(|v| match v {
                 Ok((rp, v)) => {
-                    self.logger.log(
-                        &self.info,
-                        Operation::List,
-                        &[("path", path)],
-                        "created lister",
-                        None,
-                    );
+                    self.log_finished(Operation::List, &ctx, start, "created lister", None);
                     let streamer =
                         LoggingLister::new(self.info.clone(), self.logger.clone(), path, v);
                     Ok((rp, streamer))
                 }
                 Err(err) => {
-                    self.logger.log(
-                        &self.info,
-                        Operation::List,
-                        &[("path", path)],
-                        "failed",
-                        Some(&err),
-                    );
+                    self.log_finished(Operation::List, &ctx, start, "failed", Some(&err));
                     Err(err)
                 }
             })
@@ -558,6 +610,7 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let start = Instant::now();
         self.logger.log(
             &self.info,
             Operation::Presign,
@@ -570,20 +623,20 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
             .presign(path, args)
             .await
             .map(|v| {
-                self.logger.log(
-                    &self.info,
+                self.log_finished(
                     Operation::Presign,
                     &[("path", path)],
+                    start,
                     "finished",
                     None,
                 );
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
+                self.log_finished(
                     Operation::Presign,
                     &[("path", path)],
+                    start,
                     "failed",
                     Some(&err),
                 );
@@ -592,6 +645,7 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
     }
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let start = Instant::now();
         let (op, count) = (args.operation()[0].1.operation(), args.operation().len());
 
         self.logger.log(
@@ -605,10 +659,10 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
         self.inner
             .batch(args)
             .map_ok(|v| {
-                self.logger.log(
-                    &self.info,
+                self.log_finished(
                     Operation::Batch,
                     &[("op", op.into_static()), ("count", &count.to_string())],
+                    start,
                     &format!(
                         "finished: {}, succeed: {}, failed: {}",
                         v.results().len(),
@@ -620,10 +674,10 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
+                self.log_finished(
                     Operation::Batch,
                     &[("op", op.into_static()), ("count", &count.to_string())],
+                    start,
                     "failed",
                     Some(&err),
                 );
@@ -633,10 +687,14 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
     }
 
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
         self.logger.log(
             &self.info,
             Operation::BlockingCreateDir,
-            &[("path", path)],
+            &ctx,
             "started",
             None,
         );
@@ -644,20 +702,14 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
         self.inner
             .blocking_create_dir(path, args)
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingCreateDir,
-                    &[("path", path)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::BlockingCreateDir, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
+                self.log_finished(
                     Operation::BlockingCreateDir,
-                    &[("path", path)],
+                    &ctx,
+                    start,
                     "failed",
                     Some(&err),
                 );
@@ -666,55 +718,42 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
-        self.logger.log(
-            &self.info,
-            Operation::BlockingRead,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let range = args.range().to_string();
+        let ctx = with_request_id(&[("path", path), ("range", &range)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::BlockingRead, &ctx, "started", None);
 
         self.inner
             .blocking_read(path, args.clone())
             .map(|(rp, r)| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingRead,
-                    &[("path", path)],
-                    "created reader",
-                    None,
-                );
+                self.log_finished(Operation::BlockingRead, &ctx, start, "created reader", None);
                 let r = LoggingReader::new(self.info.clone(), self.logger.clone(), path, r);
                 (rp, r)
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingRead,
-                    &[("path", path)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::BlockingRead, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
-        self.logger.log(
-            &self.info,
-            Operation::BlockingWrite,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::BlockingWrite, &ctx, "started", None);
 
         self.inner
             .blocking_write(path, args)
             .map(|(rp, w)| {
-                self.logger.log(
-                    &self.info,
+                self.log_finished(
                     Operation::BlockingWrite,
-                    &[("path", path)],
+                    &ctx,
+                    start,
                     "created writer",
                     None,
                 );
@@ -722,184 +761,125 @@ impl<A: Access, I: LoggingInterceptor> LayeredAccess for LoggingAccessor<A, I> {
                 (rp, w)
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingWrite,
-                    &[("path", path)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::BlockingWrite, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
-        self.logger.log(
-            &self.info,
-            Operation::BlockingCopy,
-            &[("from", from), ("to", to)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("from", from), ("to", to)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::BlockingCopy, &ctx, "started", None);
 
         self.inner
             .blocking_copy(from, to, args)
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingCopy,
-                    &[("from", from), ("to", to)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::BlockingCopy, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingCopy,
-                    &[("from", from), ("to", to)],
-                    "",
-                    Some(&err),
-                );
+                self.log_finished(Operation::BlockingCopy, &ctx, start, "", Some(&err));
                 err
             })
     }
 
     fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
-        self.logger.log(
-            &self.info,
-            Operation::BlockingRename,
-            &[("from", from), ("to", to)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("from", from), ("to", to)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::BlockingRename, &ctx, "started", None);
 
         self.inner
             .blocking_rename(from, to, args)
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingRename,
-                    &[("from", from), ("to", to)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::BlockingRename, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingRename,
-                    &[("from", from), ("to", to)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::BlockingRename, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
-        self.logger.log(
-            &self.info,
-            Operation::BlockingStat,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::BlockingStat, &ctx, "started", None);
 
         self.inner
             .blocking_stat(path, args)
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingStat,
-                    &[("path", path)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::BlockingStat, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingStat,
-                    &[("path", path)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::BlockingStat, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
-        self.logger.log(
-            &self.info,
-            Operation::BlockingDelete,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::BlockingDelete, &ctx, "started", None);
 
         self.inner
             .blocking_delete(path, args)
             .map(|v| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingDelete,
-                    &[("path", path)],
-                    "finished",
-                    None,
-                );
+                self.log_finished(Operation::BlockingDelete, &ctx, start, "finished", None);
                 v
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingDelete,
-                    &[("path", path)],
-                    "failed",
-                    Some(&err),
-                );
+                self.log_finished(Operation::BlockingDelete, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
-        self.logger.log(
-            &self.info,
-            Operation::BlockingList,
-            &[("path", path)],
-            "started",
-            None,
-        );
+        let start = Instant::now();
+        let request_id = args.context().request_id().map(|v| v.to_string());
+        let ctx = with_request_id(&[("path", path)], request_id.as_deref());
+
+        self.logger
+            .log(&self.info, Operation::BlockingList, &ctx, "started", None);
 
         self.inner
             .blocking_list(path, args)
             .map(|(rp, v)| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingList,
-                    &[("path", path)],
-                    "created lister",
-                    None,
-                );
+                self.log_finished(Operation::BlockingList, &ctx, start, "created lister", None);
                 let li = LoggingLister::new(self.info.clone(), self.logger.clone(), path, v);
                 (rp, li)
             })
             .map_err(|err| {
-                self.logger.log(
-                    &self.info,
-                    Operation::BlockingList,
-                    &[("path", path)],
-                    "",
-                    Some(&err),
-                );
+                self.log_finished(Operation::BlockingList, &ctx, start, "failed", Some(&err));
                 err
             })
     }
 }
 
+/// Append the request id carried by [`OpContext`] into the log context.
+fn with_request_id<'a>(
+    ctx: &[(&'a str, &'a str)],
+    request_id: Option<&'a str>,
+) -> Vec<(&'a str, &'a str)> {
+    let mut ctx = ctx.to_vec();
+    if let Some(request_id) = request_id {
+        ctx.push(("request_id", request_id));
+    }
+    ctx
+}
+
 /// `LoggingReader` is a wrapper of `BytesReader`, with logging functionality.
 pub struct LoggingReader<R, I: LoggingInterceptor> {
     info: Arc<AccessorInfo>,
@@ -1353,3 +1333,54 @@ impl<P: oio::BlockingList, I: LoggingInterceptor> oio::BlockingList for LoggingL
         res
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        let keys = DEFAULT_REDACTED_KEYS
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+
+        let cases = vec![
+            ("no sensitive", "path=a/b size=1", "path=a/b size=1"),
+            (
+                "presigned s3 url",
+                "url=https://s3/b/k?X-Amz-Credential=ak%2F2024&X-Amz-Signature=abcd",
+                "url=https://s3/b/k?X-Amz-Credential=***&X-Amz-Signature=***",
+            ),
+            (
+                "sas token",
+                "url=https://a.blob/c/k?sv=2022&sig=xyz%3D: read failed",
+                "url=https://a.blob/c/k?sv=2022&sig=***: read failed",
+            ),
+            (
+                "key as suffix",
+                "continuation-token=abc token=def",
+                "continuation-token=abc token=***",
+            ),
+            ("empty value", "token= path=a", "token= path=a"),
+        ];
+
+        for (name, input, expected) in cases {
+            assert_eq!(redact(input, &keys), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_operation_level() {
+        let layer = LoggingLayer::default()
+            .with_operation_level(Operation::List, Level::Info)
+            .with_error_level(Level::Info);
+        let logger = layer.logger;
+
+        assert_eq!(logger.operation_level(Operation::Stat), Level::Debug);
+        assert_eq!(logger.operation_level(Operation::ReaderRead), Level::Trace);
+        assert_eq!(logger.operation_level(Operation::List), Level::Info);
+        assert_eq!(logger.error_level, Level::Info);
+        assert_eq!(logger.unexpected_error_level, Level::Error);
+    }
+}
@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::Instant;
 
 use bytes::Buf;
@@ -28,34 +29,10 @@ use metrics::histogram;
 use metrics::Counter;
 use metrics::Histogram;
 
+use super::observe::*;
 use crate::raw::*;
 use crate::*;
 
-/// requests_total records all successful requests sent via operator.
-static METRIC_REQUESTS_TOTAL: &str = "opendal_requests_total";
-/// requests_duration_seconds records the duration seconds of successful
-/// requests.
-///
-/// # NOTE
-///
-/// this metric will track the whole lifetime of this request:
-///
-/// - Building request
-/// - Sending request
-/// - Receiving response
-/// - Consuming response
-static METRIC_REQUESTS_DURATION_SECONDS: &str = "opendal_requests_duration_seconds";
-static METRICS_ERRORS_TOTAL: &str = "opendal_errors_total";
-/// bytes_total records all bytes processed by operator.
-static METRIC_BYTES_TOTAL: &str = "opendal_bytes_total";
-
-/// The scheme of the service.
-static LABEL_SERVICE: &str = "service";
-/// The operation of this request.
-static LABEL_OPERATION: &str = "operation";
-/// The error kind of this failed request.
-static LABEL_ERROR: &str = "error";
-
 /// Add [metrics](https://docs.rs/metrics/) for every operation.
 ///
 /// # Metrics
@@ -75,6 +52,8 @@ static LABEL_ERROR: &str = "error";
 ///
 /// # Notes
 ///
+/// The time spent on network can be observed by [`MetricsHttpObserver`].
+///
 /// Please make sure the exporter has been pulled in regular time.
 /// Otherwise, the histogram data collected by `requests_duration_seconds`
 /// could result in OOM.
@@ -124,6 +103,75 @@ impl<A: Access> Layer<A> for MetricsLayer {
     }
 }
 
+/// Observe the [`HttpPhase`] of http requests into `opendal_http_phase_duration_seconds`.
+///
+/// The metric carries the `service` and `phase` labels, which helps to tell whether
+/// a slow operation is caused by the network or by the service.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use anyhow::Result;
+/// use opendal::layers::MetricsHttpObserver;
+/// use opendal::raw::HttpClient;
+/// use opendal::Scheme;
+///
+/// # fn main() -> Result<()> {
+/// let client = HttpClient::build_with_observer(
+///     reqwest::ClientBuilder::new(),
+///     Arc::new(MetricsHttpObserver::new(Scheme::S3)),
+/// )?;
+/// // Pass the client to the service builder via `http_client`, for example:
+/// // `services::S3::default().http_client(client)`.
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MetricsHttpObserver {
+    dns: Histogram,
+    ttfb: Histogram,
+    transfer: Histogram,
+}
+
+impl MetricsHttpObserver {
+    /// Create a new observer for the given service.
+    pub fn new(scheme: Scheme) -> Self {
+        let service = scheme.into_static();
+        let phase = |phase: HttpPhase| {
+            histogram!(
+                METRIC_HTTP_PHASE_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_PHASE => phase.into_static(),
+            )
+        };
+
+        Self {
+            dns: phase(HttpPhase::Dns),
+            ttfb: phase(HttpPhase::Ttfb),
+            transfer: phase(HttpPhase::Transfer),
+        }
+    }
+}
+
+impl Debug for MetricsHttpObserver {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetricsHttpObserver")
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpObserve for MetricsHttpObserver {
+    fn observe(&self, _: &str, phase: HttpPhase, duration: Duration) {
+        match phase {
+            HttpPhase::Dns => self.dns.record(duration),
+            HttpPhase::Ttfb => self.ttfb.record(duration),
+            HttpPhase::Transfer => self.transfer.record(duration),
+        }
+    }
+}
+
 /// metrics will hold all metrics handlers in a `RwLock<HashMap>`.
 ///
 /// By holding all metrics handlers we needed, we can reduce the lock
@@ -0,0 +1,831 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use futures::future::join_all;
+
+use crate::raw::*;
+use crate::*;
+
+/// Replicate writes of the operator to mirror operators.
+///
+/// # Behavior
+///
+/// - `create_dir`, `write`, `copy`, `rename`, `delete` and `batch` are applied to the
+///   primary operator first, and then fan out to all mirrors concurrently. So do
+///   `write_at`, `allocate`, `concat`, `stage_block`, `commit_block_list`, `set_acl`,
+///   `set_retention` and `set_lifecycle`.
+/// - Whether the mirrored operation succeeds is decided by [`MirrorWritePolicy`].
+///   The primary operator must always succeed.
+/// - `read` and `stat` go to the primary operator. If fallback is enabled, mirrors
+///   will be tried in order when the primary operator returns an error.
+/// - `list_multipart_uploads` and `abort_multipart_upload` are not supported, since
+///   upload ids are specific to every backend. Please clean up stale uploads on the
+///   primary operator and the mirrors separately.
+/// - Other operations like `list` and `presign` only go to the primary operator.
+///
+/// # Notes
+///
+/// - Mirrors are not rolled back if the operation fails on some of them, so they
+///   could be inconsistent with the primary operator after failures.
+/// - Content written via [`Writer`] is sent to all backends at the same time, so
+///   the write speed is limited by the slowest mirror.
+/// - Errors that happen while reading the content from a mirror will not fall
+///   back again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::MirrorLayer;
+/// use opendal::layers::MirrorWritePolicy;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # fn main() -> Result<()> {
+/// let mirror = Operator::new(services::Memory::default())?.finish();
+///
+/// let _ = Operator::new(services::Memory::default())?
+///     .layer(
+///         MirrorLayer::new()
+///             .with_mirror(mirror)
+///             .with_write_policy(MirrorWritePolicy::Quorum)
+///             .with_read_fallback(true),
+///     )
+///     .finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct MirrorLayer {
+    mirrors: Vec<Accessor>,
+    write_policy: MirrorWritePolicy,
+    read_fallback: bool,
+}
+
+impl Debug for MirrorLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorLayer")
+            .field("mirrors", &self.mirrors.len())
+            .field("write_policy", &self.write_policy)
+            .field("read_fallback", &self.read_fallback)
+            .finish()
+    }
+}
+
+impl MirrorLayer {
+    /// Create a new MirrorLayer without any mirrors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a mirror operator.
+    pub fn with_mirror(mut self, op: Operator) -> Self {
+        self.mirrors.push(op.into_inner());
+        self
+    }
+
+    /// Set the policy to decide whether a mirrored write succeeds.
+    ///
+    /// Default to [`MirrorWritePolicy::AllSuccess`].
+    pub fn with_write_policy(mut self, policy: MirrorWritePolicy) -> Self {
+        self.write_policy = policy;
+        self
+    }
+
+    /// Fall back to mirrors if `read` or `stat` on the primary operator failed.
+    ///
+    /// Default to `false`.
+    pub fn with_read_fallback(mut self, enabled: bool) -> Self {
+        self.read_fallback = enabled;
+        self
+    }
+}
+
+/// MirrorWritePolicy decides whether a mirrored write succeeds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MirrorWritePolicy {
+    /// The write succeeds only if the primary operator and all mirrors succeed.
+    #[default]
+    AllSuccess,
+    /// The write succeeds if the primary operator and a majority of all backends
+    /// succeed, for example, 2 of 3 backends.
+    Quorum,
+}
+
+impl MirrorWritePolicy {
+    /// Check whether the write still succeeds with `failed` of `total` backends failed.
+    ///
+    /// `err` is the error that caused the latest failure.
+    fn check(self, op: Operation, total: usize, failed: usize, err: Option<Error>) -> Result<()> {
+        let tolerated = match self {
+            MirrorWritePolicy::AllSuccess => 0,
+            MirrorWritePolicy::Quorum => total - (total / 2 + 1),
+        };
+
+        match err {
+            None => Ok(()),
+            Some(err) if failed <= tolerated => {
+                log::warn!("mirror {op} failed but tolerated by {self:?}: {err}");
+                Ok(())
+            }
+            Some(err) => Err(err
+                .with_operation(op)
+                .with_context("mirror_policy", format!("{self:?}"))
+                .with_context("mirror_failed", format!("{failed}/{total}"))),
+        }
+    }
+}
+
+impl<A: Access> Layer<A> for MirrorLayer {
+    type LayeredAccess = MirrorAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        MirrorAccessor {
+            inner,
+            mirrors: self.mirrors.clone(),
+            write_policy: self.write_policy,
+            read_fallback: self.read_fallback,
+        }
+    }
+}
+
+pub struct MirrorAccessor<A: Access> {
+    inner: A,
+    mirrors: Vec<Accessor>,
+    write_policy: MirrorWritePolicy,
+    read_fallback: bool,
+}
+
+impl<A: Access> Debug for MirrorAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorAccessor")
+            .field("inner", &self.inner)
+            .field("mirrors", &self.mirrors.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> MirrorAccessor<A> {
+    fn total(&self) -> usize {
+        self.mirrors.len() + 1
+    }
+
+    /// Apply the operation to all mirrors concurrently.
+    async fn mirror<'a, T>(
+        &'a self,
+        op: Operation,
+        f: impl Fn(&'a Accessor) -> BoxedFuture<'a, Result<T>>,
+    ) -> Result<()> {
+        let results = join_all(self.mirrors.iter().map(f)).await;
+        self.settle(op, results.into_iter().map(|v| v.map(|_| ())))
+    }
+
+    /// Apply the operation to all mirrors one by one.
+    fn blocking_mirror<T>(&self, op: Operation, f: impl Fn(&Accessor) -> Result<T>) -> Result<()> {
+        self.settle(op, self.mirrors.iter().map(|v| f(v).map(|_| ())))
+    }
+
+    fn settle(&self, op: Operation, results: impl Iterator<Item = Result<()>>) -> Result<()> {
+        let mut failed = 0;
+        for res in results {
+            if let Err(err) = res {
+                failed += 1;
+                self.write_policy
+                    .check(op, self.total(), failed, Some(err))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn new_writer<W, M>(
+        &self,
+        inner: W,
+        results: Vec<Result<(RpWrite, M)>>,
+    ) -> MirrorWrapper<W, M> {
+        let mut mirrors = Vec::with_capacity(results.len());
+        let mut errors = Vec::new();
+        for res in results {
+            match res {
+                Ok((_, w)) => mirrors.push(w),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        MirrorWrapper {
+            inner,
+            mirrors,
+            total: self.total(),
+            failed: 0,
+            policy: self.write_policy,
+            pending_errors: errors,
+        }
+    }
+}
+
+impl<A: Access> LayeredAccess for MirrorAccessor<A> {
+    type Inner = A;
+    type Reader = TwoWays<A::Reader, oio::Reader>;
+    type BlockingReader = TwoWays<A::BlockingReader, oio::BlockingReader>;
+    type Writer = MirrorWrapper<A::Writer, oio::Writer>;
+    type BlockingWriter = MirrorWrapper<A::BlockingWriter, oio::BlockingWriter>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn metadata(&self) -> Arc<AccessorInfo> {
+        let mut meta = self.inner.info().as_ref().clone();
+        let cap = meta.full_capability_mut();
+        cap.list_multipart_uploads = false;
+        cap.abort_multipart_upload = false;
+        meta.into()
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.inner.create_dir(path, args.clone()).await?;
+        self.mirror(Operation::CreateDir, |m| {
+            m.create_dir_dyn(path, args.clone())
+        })
+        .await?;
+        Ok(rp)
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let err = match self.inner.read(path, args.clone()).await {
+            Ok((rp, r)) => return Ok((rp, TwoWays::One(r))),
+            Err(err) if !self.read_fallback => return Err(err),
+            Err(err) => err,
+        };
+
+        for mirror in &self.mirrors {
+            if let Ok((rp, r)) = mirror.read(path, args.clone()).await {
+                return Ok((rp, TwoWays::Two(r)));
+            }
+        }
+        Err(err)
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let (rp, w) = self.inner.write(path, args.clone()).await?;
+        let results = join_all(self.mirrors.iter().map(|m| m.write_dyn(path, args.clone()))).await;
+        Ok((rp, self.new_writer(w, results)))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let rp = self.inner.copy(from, to, args.clone()).await?;
+        self.mirror(Operation::Copy, |m| m.copy_dyn(from, to, args.clone()))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let rp = self.inner.rename(from, to, args.clone()).await?;
+        self.mirror(Operation::Rename, |m| m.rename_dyn(from, to, args.clone()))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let err = match self.inner.stat(path, args.clone()).await {
+            Ok(rp) => return Ok(rp),
+            Err(err) if !self.read_fallback => return Err(err),
+            Err(err) => err,
+        };
+
+        for mirror in &self.mirrors {
+            if let Ok(rp) = mirror.stat(path, args.clone()).await {
+                return Ok(rp);
+            }
+        }
+        Err(err)
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let rp = self.inner.delete(path, args.clone()).await?;
+        self.mirror(Operation::Delete, |m| m.delete_dyn(path, args.clone()))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let rp = self.inner.batch(args.clone()).await?;
+        self.mirror(Operation::Batch, |m| m.batch_dyn(args.clone()))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        let rp = self.inner.write_at(path, args.clone()).await?;
+        self.mirror(Operation::WriteAt, |m| m.write_at_dyn(path, args.clone()))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        let rp = self.inner.allocate(path, args.clone()).await?;
+        self.mirror(Operation::Allocate, |m| m.allocate_dyn(path, args.clone()))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let rp = self.inner.concat(path, args.clone()).await?;
+        self.mirror(Operation::Concat, |m| m.concat_dyn(path, args.clone()))
+            .await?;
+        Ok(rp)
+    }
+
+    async fn list_multipart_uploads(
+        &self,
+        _: &str,
+        _: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        Err(new_multipart_unsupported_error(
+            Operation::ListMultipartUploads,
+        ))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        _: &str,
+        _: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        Err(new_multipart_unsupported_error(
+            Operation::AbortMultipartUpload,
+        ))
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        let rp = self.inner.stage_block(path, args.clone()).await?;
+        self.mirror(Operation::StageBlock, |m| {
+            m.stage_block_dyn(path, args.clone())
+        })
+        .await?;
+        Ok(rp)
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        let rp = self.inner.commit_block_list(path, args.clone()).await?;
+        self.mirror(Operation::CommitBlockList, |m| {
+            m.commit_block_list_dyn(path, args.clone())
+        })
+        .await?;
+        Ok(rp)
+    }
+
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        let rp = self.inner.set_lifecycle(args.clone()).await?;
+        self.mirror(Operation::SetLifecycle, |m| {
+            m.set_lifecycle_dyn(args.clone())
+        })
+        .await?;
+        Ok(rp)
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        let rp = self.inner.set_retention(path, args.clone()).await?;
+        self.mirror(Operation::SetRetention, |m| {
+            m.set_retention_dyn(path, args.clone())
+        })
+        .await?;
+        Ok(rp)
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        let rp = self.inner.set_acl(path, args.clone()).await?;
+        self.mirror(Operation::SetAcl, |m| m.set_acl_dyn(path, args.clone()))
+            .await?;
+        Ok(rp)
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.inner.blocking_create_dir(path, args.clone())?;
+        self.blocking_mirror(Operation::BlockingCreateDir, |m| {
+            m.blocking_create_dir(path, args.clone())
+        })?;
+        Ok(rp)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let err = match self.inner.blocking_read(path, args.clone()) {
+            Ok((rp, r)) => return Ok((rp, TwoWays::One(r))),
+            Err(err) if !self.read_fallback => return Err(err),
+            Err(err) => err,
+        };
+
+        for mirror in &self.mirrors {
+            if let Ok((rp, r)) = mirror.blocking_read(path, args.clone()) {
+                return Ok((rp, TwoWays::Two(r)));
+            }
+        }
+        Err(err)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let (rp, w) = self.inner.blocking_write(path, args.clone())?;
+        let results = self
+            .mirrors
+            .iter()
+            .map(|m| m.blocking_write(path, args.clone()))
+            .collect();
+        Ok((rp, self.new_writer(w, results)))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let rp = self.inner.blocking_copy(from, to, args.clone())?;
+        self.blocking_mirror(Operation::BlockingCopy, |m| {
+            m.blocking_copy(from, to, args.clone())
+        })?;
+        Ok(rp)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let rp = self.inner.blocking_rename(from, to, args.clone())?;
+        self.blocking_mirror(Operation::BlockingRename, |m| {
+            m.blocking_rename(from, to, args.clone())
+        })?;
+        Ok(rp)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let err = match self.inner.blocking_stat(path, args.clone()) {
+            Ok(rp) => return Ok(rp),
+            Err(err) if !self.read_fallback => return Err(err),
+            Err(err) => err,
+        };
+
+        for mirror in &self.mirrors {
+            if let Ok(rp) = mirror.blocking_stat(path, args.clone()) {
+                return Ok(rp);
+            }
+        }
+        Err(err)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let rp = self.inner.blocking_delete(path, args.clone())?;
+        self.blocking_mirror(Operation::BlockingDelete, |m| {
+            m.blocking_delete(path, args.clone())
+        })?;
+        Ok(rp)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+fn new_multipart_unsupported_error(op: Operation) -> Error {
+    Error::new(
+        ErrorKind::Unsupported,
+        "multipart uploads can't be managed across mirrors",
+    )
+    .with_operation(op)
+}
+
+/// MirrorWrapper writes the same content into the primary writer and all mirror writers.
+///
+/// Mirror writers that failed are dropped, and the following content will only be
+/// written into the remaining ones.
+pub struct MirrorWrapper<W, M> {
+    inner: W,
+    mirrors: Vec<M>,
+    total: usize,
+    failed: usize,
+    policy: MirrorWritePolicy,
+    /// Errors happened while creating mirror writers, will be checked on the first write.
+    pending_errors: Vec<Error>,
+}
+
+impl<W, M> MirrorWrapper<W, M> {
+    /// Drop the mirrors that failed and check the policy.
+    fn settle(&mut self, op: Operation, results: Vec<Result<()>>) -> Result<()> {
+        let mut errors = std::mem::take(&mut self.pending_errors);
+        let mut results = results.into_iter();
+        self.mirrors.retain(|_| match results.next() {
+            Some(Err(err)) => {
+                errors.push(err);
+                false
+            }
+            _ => true,
+        });
+
+        for err in errors {
+            self.failed += 1;
+            self.policy.check(op, self.total, self.failed, Some(err))?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: oio::Write> oio::Write for MirrorWrapper<W, oio::Writer> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.inner.write(bs.clone()).await?;
+        let results = join_all(self.mirrors.iter_mut().map(|w| w.write(bs.clone()))).await;
+        self.settle(Operation::WriterWrite, results)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        if let Err(err) = self.inner.close().await {
+            let _ = join_all(self.mirrors.iter_mut().map(|w| w.abort())).await;
+            return Err(err);
+        }
+        let results = join_all(self.mirrors.iter_mut().map(|w| w.close())).await;
+        self.settle(Operation::WriterClose, results)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let _ = join_all(self.mirrors.iter_mut().map(|w| w.abort())).await;
+        self.inner.abort().await
+    }
+}
+
+impl<W: oio::BlockingWrite> oio::BlockingWrite for MirrorWrapper<W, oio::BlockingWriter> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.inner.write(bs.clone())?;
+        let results = self
+            .mirrors
+            .iter_mut()
+            .map(|w| w.write(bs.clone()))
+            .collect();
+        self.settle(Operation::BlockingWriterWrite, results)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        if let Err(err) = self.inner.close() {
+            // Blocking writers can't be aborted, drop the mirror writers so that
+            // they will never be committed.
+            for _ in self.mirrors.drain(..) {
+                self.pending_errors.push(Error::new(
+                    ErrorKind::Unexpected,
+                    "mirror writer has been dropped since primary writer failed to close",
+                ));
+            }
+            return Err(err);
+        }
+        let results = self.mirrors.iter_mut().map(|w| w.close()).collect();
+        self.settle(Operation::BlockingWriterClose, results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::services::Memory;
+
+    fn new_memory() -> Operator {
+        Operator::new(Memory::default()).unwrap().finish()
+    }
+
+    #[tokio::test]
+    async fn test_mirror_write_and_delete() {
+        let mirror = new_memory();
+        let op = new_memory().layer(MirrorLayer::new().with_mirror(mirror.clone()));
+
+        op.write("file", "hello, world").await.unwrap();
+        op.create_dir("dir/").await.unwrap();
+        assert_eq!(mirror.read("file").await.unwrap().to_vec(), b"hello, world");
+        assert!(mirror.is_exist("dir/").await.unwrap());
+
+        op.delete("file").await.unwrap();
+        assert!(!mirror.is_exist("file").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_read_fallback() {
+        let primary = new_memory();
+        let mirror = new_memory();
+        mirror.write("file", "hello").await.unwrap();
+
+        let op = primary
+            .clone()
+            .layer(MirrorLayer::new().with_mirror(mirror.clone()));
+        let err = op.read("file").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        let op = primary.layer(
+            MirrorLayer::new()
+                .with_mirror(mirror)
+                .with_read_fallback(true),
+        );
+        assert_eq!(op.read("file").await.unwrap().to_vec(), b"hello");
+        assert_eq!(op.stat("file").await.unwrap().content_length(), 5);
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct MockService {
+        calls: Arc<Mutex<Vec<Operation>>>,
+    }
+
+    impl MockService {
+        fn record(&self, op: Operation) {
+            self.calls.lock().unwrap().push(op);
+        }
+    }
+
+    impl Access for MockService {
+        type Reader = ();
+        type Writer = ();
+        type Lister = ();
+        type BlockingReader = ();
+        type BlockingWriter = MockWriter;
+        type BlockingLister = ();
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let mut am = AccessorInfo::default();
+            am.set_native_capability(Capability {
+                write_at: true,
+                allocate: true,
+                concat: true,
+                stage_block: true,
+                commit_block_list: true,
+                lifecycle: true,
+                retention: true,
+                acl: true,
+                ..Default::default()
+            });
+            am.into()
+        }
+
+        fn blocking_write(&self, _: &str, _: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+            Ok((RpWrite::new(), MockWriter::default()))
+        }
+
+        async fn stage_block(&self, _: &str, _: OpStageBlock) -> Result<RpStageBlock> {
+            self.record(Operation::StageBlock);
+            Ok(RpStageBlock::default())
+        }
+
+        async fn commit_block_list(
+            &self,
+            _: &str,
+            _: OpCommitBlockList,
+        ) -> Result<RpCommitBlockList> {
+            self.record(Operation::CommitBlockList);
+            Ok(RpCommitBlockList::default())
+        }
+
+        async fn set_lifecycle(&self, _: OpSetLifecycle) -> Result<RpSetLifecycle> {
+            self.record(Operation::SetLifecycle);
+            Ok(RpSetLifecycle::default())
+        }
+
+        async fn write_at(&self, _: &str, _: OpWriteAt) -> Result<RpWriteAt> {
+            self.record(Operation::WriteAt);
+            Ok(RpWriteAt::default())
+        }
+
+        async fn allocate(&self, _: &str, _: OpAllocate) -> Result<RpAllocate> {
+            self.record(Operation::Allocate);
+            Ok(RpAllocate::default())
+        }
+
+        async fn concat(&self, _: &str, _: OpConcat) -> Result<RpConcat> {
+            self.record(Operation::Concat);
+            Ok(RpConcat::default())
+        }
+
+        async fn set_retention(&self, _: &str, _: OpSetRetention) -> Result<RpSetRetention> {
+            self.record(Operation::SetRetention);
+            Ok(RpSetRetention::default())
+        }
+
+        async fn set_acl(&self, _: &str, _: OpSetAcl) -> Result<RpSetAcl> {
+            self.record(Operation::SetAcl);
+            Ok(RpSetAcl::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mirror_positional_and_metadata_writes() {
+        let primary = MockService::default();
+        let mirror = MockService::default();
+        let acc = MirrorLayer::new()
+            .with_mirror(OperatorBuilder::new(mirror.clone()).finish())
+            .layer(primary.clone());
+
+        Access::write_at(&acc, "file", OpWriteAt::new(0, Buffer::from("hello")))
+            .await
+            .unwrap();
+        Access::allocate(&acc, "file", OpAllocate::new(512))
+            .await
+            .unwrap();
+        Access::concat(&acc, "file", OpConcat::new(vec!["a".to_string()]))
+            .await
+            .unwrap();
+        Access::set_retention(&acc, "file", OpSetRetention::default())
+            .await
+            .unwrap();
+        Access::set_acl(&acc, "file", OpSetAcl::new(Acl::Private))
+            .await
+            .unwrap();
+        Access::stage_block(&acc, "file", OpStageBlock::new("0", Buffer::from("hello")))
+            .await
+            .unwrap();
+        Access::commit_block_list(&acc, "file", OpCommitBlockList::new(vec!["0".into()]))
+            .await
+            .unwrap();
+        Access::set_lifecycle(&acc, OpSetLifecycle::default())
+            .await
+            .unwrap();
+
+        let expected = vec![
+            Operation::WriteAt,
+            Operation::Allocate,
+            Operation::Concat,
+            Operation::SetRetention,
+            Operation::SetAcl,
+            Operation::StageBlock,
+            Operation::CommitBlockList,
+            Operation::SetLifecycle,
+        ];
+        assert_eq!(*primary.calls.lock().unwrap(), expected);
+        assert_eq!(*mirror.calls.lock().unwrap(), expected);
+
+        let err = Access::abort_multipart_upload(&acc, "file", OpAbortMultipartUpload::new("id"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    /// Writer that fails to close at the first time.
+    #[derive(Default)]
+    struct MockWriter {
+        closed: bool,
+    }
+
+    impl oio::BlockingWrite for MockWriter {
+        fn write(&mut self, _: Buffer) -> Result<()> {
+            Ok(())
+        }
+
+        fn close(&mut self) -> Result<()> {
+            if !self.closed {
+                self.closed = true;
+                return Err(Error::new(ErrorKind::Unexpected, "close failed"));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mirror_blocking_close_failed() {
+        let mirror = new_memory();
+        let acc = MirrorLayer::new()
+            .with_mirror(mirror.clone())
+            .layer(MockService::default());
+
+        let (_, mut w) = Access::blocking_write(&acc, "file", OpWrite::new()).unwrap();
+        oio::BlockingWrite::write(&mut w, Buffer::from("hello")).unwrap();
+        assert!(oio::BlockingWrite::close(&mut w).is_err());
+
+        // Mirror writers must be dropped without being committed.
+        assert!(oio::BlockingWrite::close(&mut w).is_err());
+        assert!(!mirror.blocking().is_exist("file").unwrap());
+    }
+
+    #[test]
+    fn test_mirror_write_policy() {
+        let err = || Some(Error::new(ErrorKind::Unexpected, "mirror down"));
+
+        let policy = MirrorWritePolicy::AllSuccess;
+        assert!(policy.check(Operation::Write, 3, 0, None).is_ok());
+        assert!(policy.check(Operation::Write, 3, 1, err()).is_err());
+
+        let policy = MirrorWritePolicy::Quorum;
+        assert!(policy.check(Operation::Write, 3, 1, err()).is_ok());
+        assert!(policy.check(Operation::Write, 3, 2, err()).is_err());
+        assert!(policy.check(Operation::Write, 2, 1, err()).is_err());
+        assert!(policy.check(Operation::Write, 5, 2, err()).is_ok());
+    }
+}
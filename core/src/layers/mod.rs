@@ -26,6 +26,14 @@ pub(crate) use error_context::ErrorContextLayer;
 mod complete;
 pub(crate) use complete::CompleteLayer;
 
+mod cancellation;
+pub(crate) use cancellation::CancellationLayer;
+
+mod audit;
+pub use audit::AuditInterceptor;
+pub use audit::AuditLayer;
+pub use audit::AuditRecord;
+
 mod concurrent_limit;
 pub use concurrent_limit::ConcurrentLimitLayer;
 
@@ -36,9 +44,39 @@ mod logging;
 pub use logging::LoggingInterceptor;
 pub use logging::LoggingLayer;
 
+mod quota;
+pub use quota::QuotaLayer;
+
+mod mirror;
+pub use mirror::MirrorLayer;
+pub use mirror::MirrorWritePolicy;
+
+mod fallback;
+pub use fallback::FallbackLayer;
+
+mod chunked;
+pub use chunked::ChunkedLayer;
+
+mod path_validation;
+pub use path_validation::PathValidationLayer;
+
+mod case_insensitive;
+pub use case_insensitive::CaseInsensitiveLayer;
+
+mod subdir;
+pub use subdir::SubdirLayer;
+
 mod timeout;
 pub use timeout::TimeoutLayer;
 
+mod config;
+pub use config::ConcurrentLimitLayerConfig;
+pub use config::LayerConfig;
+pub use config::RetryLayerConfig;
+#[cfg(feature = "layers-throttle")]
+pub use config::ThrottleLayerConfig;
+pub use config::TimeoutLayerConfig;
+
 #[cfg(feature = "layers-blocking")]
 mod blocking;
 #[cfg(feature = "layers-blocking")]
@@ -52,6 +90,8 @@ pub use chaos::ChaosLayer;
 #[cfg(feature = "layers-metrics")]
 mod metrics;
 #[cfg(feature = "layers-metrics")]
+pub use self::metrics::MetricsHttpObserver;
+#[cfg(feature = "layers-metrics")]
 pub use self::metrics::MetricsLayer;
 
 #[cfg(feature = "layers-mime-guess")]
@@ -59,6 +99,16 @@ mod mime_guess;
 #[cfg(feature = "layers-mime-guess")]
 pub use self::mime_guess::MimeGuessLayer;
 
+#[cfg(feature = "layers-otel-metrics")]
+mod otelmetrics;
+#[cfg(feature = "layers-otel-metrics")]
+pub use self::otelmetrics::OtelMetricsHttpObserver;
+#[cfg(feature = "layers-otel-metrics")]
+pub use self::otelmetrics::OtelMetricsLayer;
+
+#[cfg(any(feature = "layers-metrics", feature = "layers-otel-metrics"))]
+mod observe;
+
 #[cfg(feature = "layers-prometheus")]
 mod prometheus;
 #[cfg(feature = "layers-prometheus")]
@@ -81,6 +131,8 @@ pub use self::tracing::TracingLayer;
 #[cfg(feature = "layers-fastrace")]
 mod fastrace;
 #[cfg(feature = "layers-fastrace")]
+pub use self::fastrace::FastraceHttpObserver;
+#[cfg(feature = "layers-fastrace")]
 pub use self::fastrace::FastraceLayer;
 
 #[cfg(feature = "layers-otel-trace")]
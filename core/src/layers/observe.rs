@@ -0,0 +1,50 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Metric names and labels shared by metrics layers, so that the same
+//! dashboard works with whichever telemetry stack is used.
+
+/// requests_total records all successful requests sent via operator.
+pub static METRIC_REQUESTS_TOTAL: &str = "opendal_requests_total";
+/// requests_duration_seconds records the duration seconds of successful
+/// requests.
+///
+/// # NOTE
+///
+/// this metric will track the whole lifetime of this request:
+///
+/// - Building request
+/// - Sending request
+/// - Receiving response
+/// - Consuming response
+pub static METRIC_REQUESTS_DURATION_SECONDS: &str = "opendal_requests_duration_seconds";
+/// errors_total records all failed requests sent via operator.
+pub static METRICS_ERRORS_TOTAL: &str = "opendal_errors_total";
+/// bytes_total records all bytes processed by operator.
+pub static METRIC_BYTES_TOTAL: &str = "opendal_bytes_total";
+/// http_phase_duration_seconds records the duration seconds of every
+/// [`HttpPhase`](crate::raw::HttpPhase) of http requests.
+pub static METRIC_HTTP_PHASE_DURATION_SECONDS: &str = "opendal_http_phase_duration_seconds";
+
+/// The scheme of the service.
+pub static LABEL_SERVICE: &str = "service";
+/// The operation of this request.
+pub static LABEL_OPERATION: &str = "operation";
+/// The error kind of this failed request.
+pub static LABEL_ERROR: &str = "error";
+/// The phase of this http request.
+pub static LABEL_PHASE: &str = "phase";
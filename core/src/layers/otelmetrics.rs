@@ -0,0 +1,555 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use futures::FutureExt;
+use opentelemetry::global;
+use opentelemetry::metrics::Counter;
+use opentelemetry::metrics::Histogram;
+use opentelemetry::metrics::Meter;
+use opentelemetry::KeyValue;
+
+use super::observe::*;
+use crate::raw::*;
+use crate::*;
+
+/// Add [opentelemetry](https://docs.rs/opentelemetry/) metrics for every operation.
+///
+/// # Metrics
+///
+/// The metrics share the same names and labels with `MetricsLayer`:
+///
+/// - `opendal_requests_total`: Total request numbers.
+/// - `opendal_requests_duration_seconds`: Request duration seconds.
+/// - `opendal_errors_total`: Total error numbers.
+/// - `opendal_bytes_total`: bytes read/write from/to underlying storage.
+///
+/// # Labels
+///
+/// metrics will carry the following labels
+///
+/// - `service`: Service name from [`Scheme`]
+/// - `operation`: Operation name from [`Operation`]
+/// - `error`: [`ErrorKind`] received by requests
+///
+/// # Notes
+///
+/// The time spent on network can be observed by [`OtelMetricsHttpObserver`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::OtelMetricsLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(OtelMetricsLayer::default())
+///     .finish();
+/// ```
+///
+/// # Output
+///
+/// By default, the metrics will be recorded by the global meter provider of
+/// [`opentelemetry`](https://docs.rs/opentelemetry/latest/opentelemetry/). Please
+/// install a meter provider with `opentelemetry::global::set_meter_provider` or
+/// build this layer with a specific meter via [`OtelMetricsLayer::new`].
+#[derive(Clone)]
+pub struct OtelMetricsLayer {
+    meter: Meter,
+}
+
+impl Default for OtelMetricsLayer {
+    fn default() -> Self {
+        Self::new(global::meter("opendal"))
+    }
+}
+
+impl Debug for OtelMetricsLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OtelMetricsLayer").finish_non_exhaustive()
+    }
+}
+
+impl OtelMetricsLayer {
+    /// Create the layer with given meter.
+    pub fn new(meter: Meter) -> Self {
+        Self { meter }
+    }
+}
+
+impl<A: Access> Layer<A> for OtelMetricsLayer {
+    type LayeredAccess = OtelMetricsAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        let meta = inner.info();
+
+        OtelMetricsAccessor {
+            inner,
+            handle: Arc::new(OtelMetricsHandler::new(
+                &self.meter,
+                meta.scheme().into_static(),
+            )),
+        }
+    }
+}
+
+/// Observe the [`HttpPhase`] of http requests into `opendal_http_phase_duration_seconds`.
+///
+/// The metric shares the same name and labels with `MetricsHttpObserver`, carrying
+/// the `service` and `phase` labels.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use anyhow::Result;
+/// use opendal::layers::OtelMetricsHttpObserver;
+/// use opendal::raw::HttpClient;
+/// use opendal::Scheme;
+///
+/// # fn main() -> Result<()> {
+/// let client = HttpClient::build_with_observer(
+///     reqwest::ClientBuilder::new(),
+///     Arc::new(OtelMetricsHttpObserver::new(
+///         &opentelemetry::global::meter("opendal"),
+///         Scheme::S3,
+///     )),
+/// )?;
+/// // Pass the client to the service builder via `http_client`, for example:
+/// // `services::S3::default().http_client(client)`.
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct OtelMetricsHttpObserver {
+    service: &'static str,
+    phase_duration_seconds: Histogram<f64>,
+}
+
+impl OtelMetricsHttpObserver {
+    /// Create a new observer for the given service with given meter.
+    pub fn new(meter: &Meter, scheme: Scheme) -> Self {
+        Self {
+            service: scheme.into_static(),
+            phase_duration_seconds: meter
+                .f64_histogram(METRIC_HTTP_PHASE_DURATION_SECONDS)
+                .with_description("Http request phase duration seconds")
+                .with_unit("s")
+                .init(),
+        }
+    }
+}
+
+impl Debug for OtelMetricsHttpObserver {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OtelMetricsHttpObserver")
+            .field("service", &self.service)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpObserve for OtelMetricsHttpObserver {
+    fn observe(&self, _: &str, phase: HttpPhase, duration: Duration) {
+        self.phase_duration_seconds.record(
+            duration.as_secs_f64(),
+            &[
+                KeyValue::new(LABEL_SERVICE, self.service),
+                KeyValue::new(LABEL_PHASE, phase.into_static()),
+            ],
+        );
+    }
+}
+
+struct OtelMetricsHandler {
+    service: &'static str,
+
+    requests_total: Counter<u64>,
+    requests_duration_seconds: Histogram<f64>,
+    errors_total: Counter<u64>,
+    bytes_total: Counter<u64>,
+}
+
+impl OtelMetricsHandler {
+    fn new(meter: &Meter, service: &'static str) -> Self {
+        Self {
+            service,
+
+            requests_total: meter
+                .u64_counter(METRIC_REQUESTS_TOTAL)
+                .with_description("Total request numbers")
+                .init(),
+            requests_duration_seconds: meter
+                .f64_histogram(METRIC_REQUESTS_DURATION_SECONDS)
+                .with_description("Request duration seconds")
+                .with_unit("s")
+                .init(),
+            errors_total: meter
+                .u64_counter(METRICS_ERRORS_TOTAL)
+                .with_description("Total error numbers")
+                .init(),
+            bytes_total: meter
+                .u64_counter(METRIC_BYTES_TOTAL)
+                .with_description("Bytes read/write from/to underlying storage")
+                .with_unit("By")
+                .init(),
+        }
+    }
+
+    fn attributes(&self, op: Operation) -> [KeyValue; 2] {
+        [
+            KeyValue::new(LABEL_SERVICE, self.service),
+            KeyValue::new(LABEL_OPERATION, op.into_static()),
+        ]
+    }
+
+    fn increment_requests_total(&self, op: Operation) {
+        self.requests_total.add(1, &self.attributes(op));
+    }
+
+    fn increment_bytes_total(&self, op: Operation, bytes: usize) {
+        self.bytes_total.add(bytes as u64, &self.attributes(op));
+    }
+
+    fn increment_errors_total(&self, op: Operation, kind: ErrorKind) {
+        let [service, operation] = self.attributes(op);
+        self.errors_total.add(
+            1,
+            &[
+                service,
+                operation,
+                KeyValue::new(LABEL_ERROR, kind.into_static()),
+            ],
+        );
+    }
+
+    /// Record the duration of successful requests and the errors of failed requests.
+    fn observe<T>(&self, op: Operation, start: Instant, result: Result<T>) -> Result<T> {
+        match &result {
+            Ok(_) => self
+                .requests_duration_seconds
+                .record(start.elapsed().as_secs_f64(), &self.attributes(op)),
+            Err(err) => self.increment_errors_total(op, err.kind()),
+        }
+        result
+    }
+}
+
+#[derive(Clone)]
+pub struct OtelMetricsAccessor<A: Access> {
+    inner: A,
+    handle: Arc<OtelMetricsHandler>,
+}
+
+impl<A: Access> Debug for OtelMetricsAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OtelMetricsAccessor")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> LayeredAccess for OtelMetricsAccessor<A> {
+    type Inner = A;
+    type Reader = OtelMetricsWrapper<A::Reader>;
+    type BlockingReader = OtelMetricsWrapper<A::BlockingReader>;
+    type Writer = OtelMetricsWrapper<A::Writer>;
+    type BlockingWriter = OtelMetricsWrapper<A::BlockingWriter>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn metadata(&self) -> Arc<AccessorInfo> {
+        self.handle.increment_requests_total(Operation::Info);
+
+        let start = Instant::now();
+        let result = self.inner.info();
+        let _ = self.handle.observe(Operation::Info, start, Ok(()));
+
+        result
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.handle.increment_requests_total(Operation::CreateDir);
+
+        let start = Instant::now();
+        self.inner
+            .create_dir(path, args)
+            .map(|v| self.handle.observe(Operation::CreateDir, start, v))
+            .await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.handle.increment_requests_total(Operation::Read);
+
+        self.inner
+            .read(path, args)
+            .map(|v| {
+                v.map(|(rp, r)| {
+                    (
+                        rp,
+                        OtelMetricsWrapper::new(r, Operation::Read, self.handle.clone()),
+                    )
+                })
+                .map_err(|err| {
+                    self.handle
+                        .increment_errors_total(Operation::Read, err.kind());
+                    err
+                })
+            })
+            .await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.handle.increment_requests_total(Operation::Write);
+
+        self.inner
+            .write(path, args)
+            .map(|v| {
+                v.map(|(rp, w)| {
+                    (
+                        rp,
+                        OtelMetricsWrapper::new(w, Operation::Write, self.handle.clone()),
+                    )
+                })
+                .map_err(|err| {
+                    self.handle
+                        .increment_errors_total(Operation::Write, err.kind());
+                    err
+                })
+            })
+            .await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.handle.increment_requests_total(Operation::Stat);
+
+        let start = Instant::now();
+        self.inner
+            .stat(path, args)
+            .map(|v| self.handle.observe(Operation::Stat, start, v))
+            .await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.handle.increment_requests_total(Operation::Delete);
+
+        let start = Instant::now();
+        self.inner
+            .delete(path, args)
+            .map(|v| self.handle.observe(Operation::Delete, start, v))
+            .await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.handle.increment_requests_total(Operation::List);
+
+        let start = Instant::now();
+        self.inner
+            .list(path, args)
+            .map(|v| self.handle.observe(Operation::List, start, v))
+            .await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.handle.increment_requests_total(Operation::Batch);
+
+        let start = Instant::now();
+        self.inner
+            .batch(args)
+            .map(|v| self.handle.observe(Operation::Batch, start, v))
+            .await
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.handle.increment_requests_total(Operation::Presign);
+
+        let start = Instant::now();
+        self.inner
+            .presign(path, args)
+            .map(|v| self.handle.observe(Operation::Presign, start, v))
+            .await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.handle
+            .increment_requests_total(Operation::BlockingCreateDir);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_create_dir(path, args);
+        self.handle
+            .observe(Operation::BlockingCreateDir, start, result)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.handle
+            .increment_requests_total(Operation::BlockingRead);
+
+        self.inner
+            .blocking_read(path, args)
+            .map(|(rp, r)| {
+                (
+                    rp,
+                    OtelMetricsWrapper::new(r, Operation::BlockingRead, self.handle.clone()),
+                )
+            })
+            .map_err(|err| {
+                self.handle
+                    .increment_errors_total(Operation::BlockingRead, err.kind());
+                err
+            })
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.handle
+            .increment_requests_total(Operation::BlockingWrite);
+
+        self.inner
+            .blocking_write(path, args)
+            .map(|(rp, w)| {
+                (
+                    rp,
+                    OtelMetricsWrapper::new(w, Operation::BlockingWrite, self.handle.clone()),
+                )
+            })
+            .map_err(|err| {
+                self.handle
+                    .increment_errors_total(Operation::BlockingWrite, err.kind());
+                err
+            })
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.handle
+            .increment_requests_total(Operation::BlockingStat);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_stat(path, args);
+        self.handle.observe(Operation::BlockingStat, start, result)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.handle
+            .increment_requests_total(Operation::BlockingDelete);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_delete(path, args);
+        self.handle
+            .observe(Operation::BlockingDelete, start, result)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.handle
+            .increment_requests_total(Operation::BlockingList);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_list(path, args);
+        self.handle.observe(Operation::BlockingList, start, result)
+    }
+}
+
+pub struct OtelMetricsWrapper<R> {
+    inner: R,
+
+    op: Operation,
+    handle: Arc<OtelMetricsHandler>,
+}
+
+impl<R> OtelMetricsWrapper<R> {
+    fn new(inner: R, op: Operation, handle: Arc<OtelMetricsHandler>) -> Self {
+        Self { inner, op, handle }
+    }
+}
+
+impl<R: oio::Read> oio::Read for OtelMetricsWrapper<R> {
+    async fn read(&mut self) -> Result<Buffer> {
+        let start = Instant::now();
+
+        let result = self.inner.read().await;
+        if let Ok(bs) = &result {
+            self.handle.increment_bytes_total(self.op, bs.len());
+        }
+        self.handle.observe(self.op, start, result)
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for OtelMetricsWrapper<R> {
+    fn read(&mut self) -> Result<Buffer> {
+        let start = Instant::now();
+
+        let result = self.inner.read();
+        if let Ok(bs) = &result {
+            self.handle.increment_bytes_total(self.op, bs.len());
+        }
+        self.handle.observe(self.op, start, result)
+    }
+}
+
+impl<R: oio::Write> oio::Write for OtelMetricsWrapper<R> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let start = Instant::now();
+        let size = bs.len();
+
+        let result = self.inner.write(bs).await;
+        if result.is_ok() {
+            self.handle.increment_bytes_total(self.op, size);
+        }
+        self.handle.observe(self.op, start, result)
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let start = Instant::now();
+        let result = self.inner.abort().await;
+        self.handle.observe(self.op, start, result)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let start = Instant::now();
+        let result = self.inner.close().await;
+        self.handle.observe(self.op, start, result)
+    }
+}
+
+impl<R: oio::BlockingWrite> oio::BlockingWrite for OtelMetricsWrapper<R> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        let start = Instant::now();
+        let size = bs.len();
+
+        let result = self.inner.write(bs);
+        if result.is_ok() {
+            self.handle.increment_bytes_total(self.op, size);
+        }
+        self.handle.observe(self.op, start, result)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        let start = Instant::now();
+        let result = self.inner.close();
+        self.handle.observe(self.op, start, result)
+    }
+}
@@ -0,0 +1,283 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::raw::*;
+use crate::*;
+
+/// Add path validation for the operator.
+///
+/// Services interpret paths differently: some of them treat `\` as separator, some
+/// of them resolve `..` and some of them compare unicode bytes directly. This layer
+/// makes sure all services receive the same, safe paths.
+///
+/// # Modes
+///
+/// - By default, paths will be sanitized by [`sanitize_path`]: backslashes will be
+///   treated as separators, relative segments will be resolved and unicode will be
+///   normalized into NFC form.
+/// - In strict mode, paths will be validated by [`validate_path_strict`] and dangerous
+///   paths will be rejected instead of being fixed.
+///
+/// Paths contain control characters or traverse beyond the root are always rejected
+/// with [`ErrorKind::Unsupported`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::PathValidationLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+/// use opendal::Scheme;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(PathValidationLayer::new().with_strict(true))
+///     .finish();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PathValidationLayer {
+    strict: bool,
+}
+
+impl PathValidationLayer {
+    /// Create a new PathValidationLayer which sanitizes paths.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject dangerous paths instead of sanitizing them.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for PathValidationLayer {
+    type LayeredAccess = PathValidationAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        PathValidationAccessor {
+            inner,
+            strict: self.strict,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PathValidationAccessor<A: Access> {
+    inner: A,
+    strict: bool,
+}
+
+impl<A: Access> PathValidationAccessor<A> {
+    fn check(&self, op: Operation, path: &str) -> Result<String> {
+        let res = if self.strict {
+            validate_path_strict(path).map(|_| path.to_string())
+        } else {
+            sanitize_path(path)
+        };
+        res.map_err(|err| err.with_operation(op))
+    }
+}
+
+impl<A: Access> LayeredAccess for PathValidationAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let path = self.check(Operation::CreateDir, path)?;
+        self.inner.create_dir(&path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let path = self.check(Operation::Read, path)?;
+        self.inner.read(&path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let path = self.check(Operation::Write, path)?;
+        self.inner.write(&path, args).await
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        let path = self.check(Operation::WriteAt, path)?;
+        self.inner.write_at(&path, args).await
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        let path = self.check(Operation::Allocate, path)?;
+        self.inner.allocate(&path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let from = self.check(Operation::Copy, from)?;
+        let to = self.check(Operation::Copy, to)?;
+        self.inner.copy(&from, &to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let from = self.check(Operation::Rename, from)?;
+        let to = self.check(Operation::Rename, to)?;
+        self.inner.rename(&from, &to, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let path = self.check(Operation::Stat, path)?;
+        self.inner.stat(&path, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let path = self.check(Operation::Delete, path)?;
+        self.inner.delete(&path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let path = self.check(Operation::Concat, path)?;
+        let sources = args
+            .sources()
+            .iter()
+            .map(|v| self.check(Operation::Concat, v))
+            .collect::<Result<Vec<_>>>()?;
+        self.inner.concat(&path, args.with_sources(sources)).await
+    }
+
+    async fn get_retention(&self, path: &str, args: OpGetRetention) -> Result<RpGetRetention> {
+        let path = self.check(Operation::GetRetention, path)?;
+        self.inner.get_retention(&path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        let path = self.check(Operation::SetRetention, path)?;
+        self.inner.set_retention(&path, args).await
+    }
+
+    async fn get_acl(&self, path: &str, args: OpGetAcl) -> Result<RpGetAcl> {
+        let path = self.check(Operation::GetAcl, path)?;
+        self.inner.get_acl(&path, args).await
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        let path = self.check(Operation::SetAcl, path)?;
+        self.inner.set_acl(&path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let path = self.check(Operation::List, path)?;
+        self.inner.list(&path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = args
+            .into_operation()
+            .into_iter()
+            .map(|(path, op)| Ok((self.check(Operation::Batch, &path)?, op)))
+            .collect::<Result<Vec<_>>>()?;
+        self.inner.batch(OpBatch::new(ops)).await
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let path = self.check(Operation::Presign, path)?;
+        self.inner.presign(&path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let path = self.check(Operation::BlockingCreateDir, path)?;
+        self.inner.blocking_create_dir(&path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let path = self.check(Operation::BlockingRead, path)?;
+        self.inner.blocking_read(&path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let path = self.check(Operation::BlockingWrite, path)?;
+        self.inner.blocking_write(&path, args)
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let from = self.check(Operation::BlockingCopy, from)?;
+        let to = self.check(Operation::BlockingCopy, to)?;
+        self.inner.blocking_copy(&from, &to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let from = self.check(Operation::BlockingRename, from)?;
+        let to = self.check(Operation::BlockingRename, to)?;
+        self.inner.blocking_rename(&from, &to, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let path = self.check(Operation::BlockingStat, path)?;
+        self.inner.blocking_stat(&path, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let path = self.check(Operation::BlockingDelete, path)?;
+        self.inner.blocking_delete(&path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        let path = self.check(Operation::BlockingList, path)?;
+        self.inner.blocking_list(&path, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_sanitize() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(PathValidationLayer::new())
+            .finish();
+
+        op.write("abc\\def", "hello").await.unwrap();
+        assert!(op.is_exist("abc/def").await.unwrap());
+        assert!(op.is_exist("abc/./xyz/../def").await.unwrap());
+
+        let err = op.stat("../def").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[tokio::test]
+    async fn test_strict() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(PathValidationLayer::new().with_strict(true))
+            .finish();
+
+        op.write("abc/def", "hello").await.unwrap();
+        for path in ["abc\\def", "abc/./def", "abc/../def", "cafe\u{301}"] {
+            let err = op.write(path, "hello").await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Unsupported, "{path}");
+        }
+    }
+}
@@ -0,0 +1,787 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::raw::*;
+use crate::*;
+
+/// Add quota enforcement for the operator.
+///
+/// # Quota
+///
+/// - `max_object_size`: the max size in bytes of a single written object.
+/// - `max_write_bytes`: the max bytes that can be written in a time window.
+/// - `max_operations`: the max operations that can be issued in a time window.
+///
+/// All limits are disabled by default. The windows are fixed windows which start
+/// at the first operation and are reset once the window duration has passed.
+///
+/// # Notes
+///
+/// - The quota is shared by all clones of the layered operator, but every call to
+///   [`Operator::layer`] will create a new quota.
+/// - Requests that exceed the quota return [`ErrorKind::RateLimited`]. Window based
+///   limits are marked as temporary so they can be retried after the window resets,
+///   while exceeding `max_object_size` is permanent.
+/// - Every call to the service (like `read`, `stat`, `presign` and `set_acl`) will be
+///   counted as an operation, the following calls on readers, writers and listers
+///   are not counted.
+/// - Bytes sent by `write_at` and `stage_block` and the size reserved by `allocate`
+///   are charged as written bytes. For `write_at`, the object size is checked against
+///   the end of the written range.
+/// - The size of `commit_block_list` is the total size of the blocks staged through
+///   this layer, which is only checked against `max_object_size` since the bytes have
+///   been charged while staging.
+/// - The size of `copy` and `concat` is the total size of their sources, which will be
+///   fetched by `stat` if any bytes limit is set.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use anyhow::Result;
+/// use opendal::layers::QuotaLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+/// use opendal::Scheme;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         QuotaLayer::new()
+///             .with_max_object_size(64 * 1024 * 1024)
+///             .with_max_write_bytes(1024 * 1024 * 1024, Duration::from_secs(60))
+///             .with_max_operations(1000, Duration::from_secs(1)),
+///     )
+///     .finish();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QuotaLayer {
+    max_object_size: Option<u64>,
+    max_write_bytes: Option<(u64, Duration)>,
+    max_operations: Option<(u64, Duration)>,
+}
+
+impl QuotaLayer {
+    /// Create a new QuotaLayer without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the max size in bytes of a single written object.
+    pub fn with_max_object_size(mut self, size: u64) -> Self {
+        self.max_object_size = Some(size);
+        self
+    }
+
+    /// Set the max bytes that can be written in every `window`.
+    pub fn with_max_write_bytes(mut self, bytes: u64, window: Duration) -> Self {
+        self.max_write_bytes = Some((bytes, window));
+        self
+    }
+
+    /// Set the max operations that can be issued in every `window`.
+    pub fn with_max_operations(mut self, count: u64, window: Duration) -> Self {
+        self.max_operations = Some((count, window));
+        self
+    }
+}
+
+impl<A: Access> Layer<A> for QuotaLayer {
+    type LayeredAccess = QuotaAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        QuotaAccessor {
+            inner,
+            quota: Arc::new(Quota {
+                max_object_size: self.max_object_size,
+                staged_blocks: Mutex::default(),
+                write_bytes: self
+                    .max_write_bytes
+                    .map(|(limit, window)| Mutex::new(FixedWindow::new(limit, window))),
+                operations: self
+                    .max_operations
+                    .map(|(limit, window)| Mutex::new(FixedWindow::new(limit, window))),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Quota {
+    max_object_size: Option<u64>,
+    /// The size of staged blocks keyed by path and block id, only tracked while
+    /// `max_object_size` is set.
+    staged_blocks: Mutex<HashMap<(String, String), u64>>,
+    write_bytes: Option<Mutex<FixedWindow>>,
+    operations: Option<Mutex<FixedWindow>>,
+}
+
+impl Quota {
+    fn acquire_operation(&self, op: Operation) -> Result<()> {
+        let Some(window) = &self.operations else {
+            return Ok(());
+        };
+        let mut window = window.lock().expect("lock must succeed");
+        window.acquire(1).map_err(|err| {
+            err.with_operation(op)
+                .with_context("quota", "max_operations")
+        })
+    }
+
+    /// Whether bytes need to be charged.
+    fn has_bytes_limit(&self) -> bool {
+        self.max_object_size.is_some() || self.write_bytes.is_some()
+    }
+
+    fn check_object_size(&self, op: Operation, size: u64) -> Result<()> {
+        match self.max_object_size {
+            Some(max) if size > max => Err(Error::new(
+                ErrorKind::RateLimited,
+                "object size exceeds quota",
+            )
+            .with_operation(op)
+            .with_context("quota", "max_object_size")
+            .with_context("limit", max.to_string())
+            .with_context("size", size.to_string())),
+            _ => Ok(()),
+        }
+    }
+
+    fn acquire_write(&self, op: Operation, written: u64, size: u64) -> Result<()> {
+        self.check_object_size(op, written.saturating_add(size))?;
+
+        let Some(window) = &self.write_bytes else {
+            return Ok(());
+        };
+        let mut window = window.lock().expect("lock must succeed");
+        window.acquire(size).map_err(|err| {
+            err.with_operation(op)
+                .with_context("quota", "max_write_bytes")
+        })
+    }
+
+    fn acquire_stage_block(&self, path: &str, block_id: &str, size: u64) -> Result<()> {
+        self.acquire_write(Operation::StageBlock, 0, size)?;
+        if self.max_object_size.is_some() {
+            let mut staged = self.staged_blocks.lock().expect("lock must succeed");
+            staged.insert((path.to_string(), block_id.to_string()), size);
+        }
+        Ok(())
+    }
+
+    fn acquire_commit_block_list(&self, path: &str, block_ids: &[String]) -> Result<()> {
+        if self.max_object_size.is_none() {
+            return Ok(());
+        }
+
+        let size = {
+            let staged = self.staged_blocks.lock().expect("lock must succeed");
+            block_ids.iter().fold(0u64, |size, id| {
+                let key = (path.to_string(), id.to_string());
+                size.saturating_add(staged.get(&key).copied().unwrap_or_default())
+            })
+        };
+        self.check_object_size(Operation::CommitBlockList, size)?;
+
+        let mut staged = self.staged_blocks.lock().expect("lock must succeed");
+        staged.retain(|(p, _), _| p != path);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct FixedWindow {
+    limit: u64,
+    window: Duration,
+
+    start: Option<Instant>,
+    used: u64,
+}
+
+impl FixedWindow {
+    fn new(limit: u64, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            start: None,
+            used: 0,
+        }
+    }
+
+    fn acquire(&mut self, n: u64) -> Result<()> {
+        let now = Instant::now();
+        match self.start {
+            Some(start) if now.duration_since(start) < self.window => {}
+            _ => {
+                self.start = Some(now);
+                self.used = 0;
+            }
+        }
+
+        if self.used.saturating_add(n) > self.limit {
+            return Err(Error::new(ErrorKind::RateLimited, "quota exceeded")
+                .with_context("limit", self.limit.to_string())
+                .with_context("window", format!("{:?}", self.window))
+                .set_temporary());
+        }
+
+        self.used = self.used.saturating_add(n);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuotaAccessor<A: Access> {
+    inner: A,
+    quota: Arc<Quota>,
+}
+
+impl<A: Access> LayeredAccess for QuotaAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = QuotaWrapper<A::Writer>;
+    type BlockingWriter = QuotaWrapper<A::BlockingWriter>;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.quota.acquire_operation(Operation::CreateDir)?;
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.quota.acquire_operation(Operation::Read)?;
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.quota.acquire_operation(Operation::Write)?;
+        self.inner
+            .write(path, args)
+            .await
+            .map(|(rp, w)| (rp, QuotaWrapper::new(w, self.quota.clone())))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.quota.acquire_operation(Operation::Copy)?;
+        if self.quota.has_bytes_limit() {
+            let rp = self.inner.stat(from, OpStat::new()).await?;
+            let size = rp.into_metadata().content_length();
+            self.quota.acquire_write(Operation::Copy, 0, size)?;
+        }
+        self.inner.copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.quota.acquire_operation(Operation::Rename)?;
+        self.inner.rename(from, to, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.quota.acquire_operation(Operation::Stat)?;
+        self.inner.stat(path, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.quota.acquire_operation(Operation::Delete)?;
+        self.inner.delete(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.quota.acquire_operation(Operation::List)?;
+        self.inner.list(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.quota.acquire_operation(Operation::Batch)?;
+        self.inner.batch(args).await
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        self.quota.acquire_operation(Operation::WriteAt)?;
+        self.quota
+            .acquire_write(Operation::WriteAt, args.offset(), args.body().len() as u64)?;
+        self.inner.write_at(path, args).await
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        self.quota.acquire_operation(Operation::Allocate)?;
+        self.quota
+            .acquire_write(Operation::Allocate, 0, args.size())?;
+        self.inner.allocate(path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.quota.acquire_operation(Operation::Concat)?;
+        if self.quota.has_bytes_limit() {
+            let mut size = 0;
+            for source in args.sources() {
+                let rp = self.inner.stat(source, OpStat::new()).await?;
+                size = rp.into_metadata().content_length().saturating_add(size);
+            }
+            self.quota.acquire_write(Operation::Concat, 0, size)?;
+        }
+        self.inner.concat(path, args).await
+    }
+
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        self.quota
+            .acquire_operation(Operation::ListMultipartUploads)?;
+        self.inner.list_multipart_uploads(path, args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        self.quota
+            .acquire_operation(Operation::AbortMultipartUpload)?;
+        self.inner.abort_multipart_upload(path, args).await
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        self.quota.acquire_operation(Operation::StageBlock)?;
+        self.quota
+            .acquire_stage_block(path, args.block_id(), args.body().len() as u64)?;
+        self.inner.stage_block(path, args).await
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        self.quota.acquire_operation(Operation::CommitBlockList)?;
+        self.quota
+            .acquire_commit_block_list(path, args.block_ids())?;
+        self.inner.commit_block_list(path, args).await
+    }
+
+    async fn locate(&self, path: &str, args: OpLocate) -> Result<RpLocate> {
+        self.quota.acquire_operation(Operation::Locate)?;
+        self.inner.locate(path, args).await
+    }
+
+    async fn get_lifecycle(&self, args: OpGetLifecycle) -> Result<RpGetLifecycle> {
+        self.quota.acquire_operation(Operation::GetLifecycle)?;
+        self.inner.get_lifecycle(args).await
+    }
+
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        self.quota.acquire_operation(Operation::SetLifecycle)?;
+        self.inner.set_lifecycle(args).await
+    }
+
+    async fn get_retention(&self, path: &str, args: OpGetRetention) -> Result<RpGetRetention> {
+        self.quota.acquire_operation(Operation::GetRetention)?;
+        self.inner.get_retention(path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        self.quota.acquire_operation(Operation::SetRetention)?;
+        self.inner.set_retention(path, args).await
+    }
+
+    async fn get_acl(&self, path: &str, args: OpGetAcl) -> Result<RpGetAcl> {
+        self.quota.acquire_operation(Operation::GetAcl)?;
+        self.inner.get_acl(path, args).await
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        self.quota.acquire_operation(Operation::SetAcl)?;
+        self.inner.set_acl(path, args).await
+    }
+
+    async fn create_bucket(&self, args: OpCreateBucket) -> Result<RpCreateBucket> {
+        self.quota.acquire_operation(Operation::CreateBucket)?;
+        self.inner.create_bucket(args).await
+    }
+
+    async fn delete_bucket(&self, args: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        self.quota.acquire_operation(Operation::DeleteBucket)?;
+        self.inner.delete_bucket(args).await
+    }
+
+    async fn list_buckets(&self, args: OpListBuckets) -> Result<RpListBuckets> {
+        self.quota.acquire_operation(Operation::ListBuckets)?;
+        self.inner.list_buckets(args).await
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.quota.acquire_operation(Operation::Presign)?;
+        self.inner.presign(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.quota.acquire_operation(Operation::BlockingCreateDir)?;
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.quota.acquire_operation(Operation::BlockingRead)?;
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.quota.acquire_operation(Operation::BlockingWrite)?;
+        self.inner
+            .blocking_write(path, args)
+            .map(|(rp, w)| (rp, QuotaWrapper::new(w, self.quota.clone())))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.quota.acquire_operation(Operation::BlockingCopy)?;
+        if self.quota.has_bytes_limit() {
+            let rp = self.inner.blocking_stat(from, OpStat::new())?;
+            let size = rp.into_metadata().content_length();
+            self.quota.acquire_write(Operation::BlockingCopy, 0, size)?;
+        }
+        self.inner.blocking_copy(from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.quota.acquire_operation(Operation::BlockingRename)?;
+        self.inner.blocking_rename(from, to, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.quota.acquire_operation(Operation::BlockingStat)?;
+        self.inner.blocking_stat(path, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.quota.acquire_operation(Operation::BlockingDelete)?;
+        self.inner.blocking_delete(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.quota.acquire_operation(Operation::BlockingList)?;
+        self.inner.blocking_list(path, args)
+    }
+}
+
+pub struct QuotaWrapper<R> {
+    inner: R,
+    quota: Arc<Quota>,
+    written: u64,
+}
+
+impl<R> QuotaWrapper<R> {
+    fn new(inner: R, quota: Arc<Quota>) -> Self {
+        Self {
+            inner,
+            quota,
+            written: 0,
+        }
+    }
+}
+
+impl<R: oio::Write> oio::Write for QuotaWrapper<R> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let size = bs.len() as u64;
+        self.quota
+            .acquire_write(Operation::WriterWrite, self.written, size)?;
+        self.inner.write(bs).await?;
+        self.written = self.written.saturating_add(size);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+impl<R: oio::BlockingWrite> oio::BlockingWrite for QuotaWrapper<R> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        let size = bs.len() as u64;
+        self.quota
+            .acquire_write(Operation::BlockingWriterWrite, self.written, size)?;
+        self.inner.write(bs)?;
+        self.written = self.written.saturating_add(size);
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_max_object_size() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(QuotaLayer::new().with_max_object_size(8))
+            .finish();
+
+        op.write("small", "hello").await.unwrap();
+        let err = op.write("large", "hello, world").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(!err.is_temporary());
+        assert!(!op.is_exist("large").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_max_write_bytes() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(QuotaLayer::new().with_max_write_bytes(8, Duration::from_secs(3600)))
+            .finish();
+
+        op.write("a", "hello").await.unwrap();
+        let err = op.write("b", "hello").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+    }
+
+    #[tokio::test]
+    async fn test_max_operations() {
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(QuotaLayer::new().with_max_operations(2, Duration::from_millis(100)))
+            .finish();
+
+        op.write("a", "hello").await.unwrap();
+        op.stat("a").await.unwrap();
+        let err = op.stat("a").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        op.stat("a").await.unwrap();
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct MockService;
+
+    impl Access for MockService {
+        type Reader = ();
+        type Writer = ();
+        type Lister = ();
+        type BlockingReader = ();
+        type BlockingWriter = ();
+        type BlockingLister = ();
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            AccessorInfo::default().into()
+        }
+
+        async fn write_at(&self, _: &str, _: OpWriteAt) -> Result<RpWriteAt> {
+            Ok(RpWriteAt::default())
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE).with_content_length(3),
+            ))
+        }
+
+        async fn concat(&self, _: &str, _: OpConcat) -> Result<RpConcat> {
+            Ok(RpConcat::default())
+        }
+
+        async fn allocate(&self, _: &str, _: OpAllocate) -> Result<RpAllocate> {
+            Ok(RpAllocate::default())
+        }
+
+        async fn copy(&self, _: &str, _: &str, _: OpCopy) -> Result<RpCopy> {
+            Ok(RpCopy::default())
+        }
+
+        async fn stage_block(&self, _: &str, _: OpStageBlock) -> Result<RpStageBlock> {
+            Ok(RpStageBlock::default())
+        }
+
+        async fn commit_block_list(
+            &self,
+            _: &str,
+            _: OpCommitBlockList,
+        ) -> Result<RpCommitBlockList> {
+            Ok(RpCommitBlockList::default())
+        }
+
+        async fn get_retention(&self, _: &str, _: OpGetRetention) -> Result<RpGetRetention> {
+            Ok(RpGetRetention::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_at() {
+        let acc = QuotaLayer::new()
+            .with_max_object_size(8)
+            .with_max_write_bytes(6, Duration::from_secs(3600))
+            .layer(MockService);
+
+        // The object size is checked against the end of the written range.
+        let err = Access::write_at(&acc, "a", OpWriteAt::new(4, Buffer::from("hello")))
+            .await
+            .unwrap_err();
+        assert!(!err.is_temporary());
+
+        Access::write_at(&acc, "a", OpWriteAt::new(0, Buffer::from("hello")))
+            .await
+            .unwrap();
+        let err = Access::write_at(&acc, "a", OpWriteAt::new(5, Buffer::from("ab")))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+    }
+
+    #[tokio::test]
+    async fn test_concat() {
+        let acc = QuotaLayer::new()
+            .with_max_object_size(8)
+            .with_max_operations(2, Duration::from_secs(3600))
+            .layer(MockService);
+
+        let sources = |n: usize| (0..n).map(|i| i.to_string()).collect::<Vec<_>>();
+        Access::concat(&acc, "a", OpConcat::new(sources(2)))
+            .await
+            .unwrap();
+        // Every source is 3 bytes.
+        let err = Access::concat(&acc, "a", OpConcat::new(sources(3)))
+            .await
+            .unwrap_err();
+        assert!(!err.is_temporary());
+
+        // Sources stat are not counted as operations.
+        let err = Access::concat(&acc, "a", OpConcat::new(sources(1)))
+            .await
+            .unwrap_err();
+        assert!(err.is_temporary());
+    }
+
+    #[tokio::test]
+    async fn test_allocate() {
+        let acc = QuotaLayer::new()
+            .with_max_object_size(8)
+            .with_max_write_bytes(10, Duration::from_secs(3600))
+            .layer(MockService);
+
+        let err = Access::allocate(&acc, "a", OpAllocate::new(9))
+            .await
+            .unwrap_err();
+        assert!(!err.is_temporary());
+
+        Access::allocate(&acc, "a", OpAllocate::new(8))
+            .await
+            .unwrap();
+        let err = Access::allocate(&acc, "b", OpAllocate::new(8))
+            .await
+            .unwrap_err();
+        assert!(err.is_temporary());
+    }
+
+    #[tokio::test]
+    async fn test_copy() {
+        let acc = QuotaLayer::new()
+            .with_max_write_bytes(5, Duration::from_secs(3600))
+            .layer(MockService);
+
+        // The source is 3 bytes.
+        Access::copy(&acc, "a", "b", OpCopy::new()).await.unwrap();
+        let err = Access::copy(&acc, "a", "c", OpCopy::new())
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+    }
+
+    #[tokio::test]
+    async fn test_block_list() {
+        let acc = QuotaLayer::new()
+            .with_max_object_size(8)
+            .with_max_write_bytes(12, Duration::from_secs(3600))
+            .layer(MockService);
+
+        for id in ["0", "1"] {
+            Access::stage_block(&acc, "a", OpStageBlock::new(id, Buffer::from("hello")))
+                .await
+                .unwrap();
+        }
+        // Staged blocks are 10 bytes which exceeds the max object size.
+        let ids = vec!["0".to_string(), "1".to_string()];
+        let err = Access::commit_block_list(&acc, "a", OpCommitBlockList::new(ids))
+            .await
+            .unwrap_err();
+        assert!(!err.is_temporary());
+        Access::commit_block_list(&acc, "a", OpCommitBlockList::new(vec!["0".to_string()]))
+            .await
+            .unwrap();
+
+        // Staged bytes are charged as written bytes.
+        let err = Access::stage_block(&acc, "b", OpStageBlock::new("0", Buffer::from("hello")))
+            .await
+            .unwrap_err();
+        assert!(err.is_temporary());
+    }
+
+    #[tokio::test]
+    async fn test_other_operations() {
+        let acc = QuotaLayer::new()
+            .with_max_operations(1, Duration::from_secs(3600))
+            .layer(MockService);
+
+        Access::get_retention(&acc, "a", OpGetRetention::default())
+            .await
+            .unwrap();
+        let err = Access::get_retention(&acc, "a", OpGetRetention::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+    }
+
+    #[test]
+    fn test_fixed_window() {
+        let mut window = FixedWindow::new(10, Duration::from_secs(3600));
+        window.acquire(4).unwrap();
+        window.acquire(6).unwrap();
+        assert!(window.acquire(1).is_err());
+        assert!(window.acquire(u64::MAX).is_err());
+    }
+}
@@ -17,13 +17,12 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 
-use backon::BlockingRetryable;
+use backon::BackoffBuilder;
 use backon::ExponentialBuilder;
-use backon::Retryable;
-use futures::FutureExt;
 use log::warn;
 
 use crate::raw::*;
@@ -37,6 +36,12 @@ use crate::*;
 /// returns true. If operation still failed, this layer will set error to
 /// `Persistent` which means error has been retried.
 ///
+/// If the service asks us to wait via [`Error::retry_after`], the backoff won't be shorter than it.
+///
+/// The backoff of async operations sleeps via [`Execute::sleep`] of the executor set by
+/// [`RetryLayer::with_executor`], users running on runtimes other than tokio (for example,
+/// `wasm32-unknown-unknown`) should set their own executor.
+///
 /// # Panics
 ///
 /// While retrying `Reader` or `Writer` operations, please make sure either:
@@ -106,6 +111,7 @@ use crate::*;
 pub struct RetryLayer<I = DefaultRetryInterceptor> {
     builder: ExponentialBuilder,
     notify: Arc<I>,
+    executor: Executor,
 }
 
 impl<I> Clone for RetryLayer<I> {
@@ -113,6 +119,7 @@ impl<I> Clone for RetryLayer<I> {
         Self {
             builder: self.builder.clone(),
             notify: self.notify.clone(),
+            executor: self.executor.clone(),
         }
     }
 }
@@ -122,6 +129,7 @@ impl Default for RetryLayer {
         Self {
             builder: ExponentialBuilder::default(),
             notify: Arc::new(DefaultRetryInterceptor),
+            executor: Executor::new(),
         }
     }
 }
@@ -175,8 +183,19 @@ impl RetryLayer {
         RetryLayer {
             builder: self.builder,
             notify: Arc::new(notify),
+            executor: self.executor,
         }
     }
+}
+
+impl<I> RetryLayer<I> {
+    /// Set the executor whose [`Execute::sleep`] is used to wait between retries.
+    ///
+    /// Users running on runtimes other than tokio should set this.
+    pub fn with_executor(mut self, executor: Executor) -> Self {
+        self.executor = executor;
+        self
+    }
 
     /// Set jitter of current backoff.
     ///
@@ -228,6 +247,7 @@ impl<A: Access, I: RetryInterceptor> Layer<A> for RetryLayer<I> {
             inner: Arc::new(inner),
             builder: self.builder.clone(),
             notify: self.notify.clone(),
+            executor: self.executor.clone(),
         }
     }
 }
@@ -264,10 +284,104 @@ impl RetryInterceptor for DefaultRetryInterceptor {
     }
 }
 
+/// Retry the future built by `f` while it fails with temporary errors.
+///
+/// We don't use `backon::Retryable` here since it sleeps on tokio's timer.
+async fn retry<T, F, Fut, I>(
+    builder: &ExponentialBuilder,
+    notify: &I,
+    executor: &Executor,
+    mut f: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+    I: RetryInterceptor,
+{
+    let mut backoff = builder.build();
+    loop {
+        let err = match f().await {
+            Ok(v) => return Ok(v),
+            Err(err) if err.is_temporary() => err,
+            Err(err) => return Err(err.set_persistent()),
+        };
+        let Some(dur) = backoff.next() else {
+            return Err(err.set_persistent());
+        };
+        let dur = backoff_delay(&err, dur);
+        notify.intercept(&err, dur);
+        executor.sleep(dur).await;
+    }
+}
+
+/// Retry the future built by `f` with given context while it fails with temporary errors.
+///
+/// The context is passed into `f` and returned back by every attempt.
+async fn retry_with_context<C, T, F, Fut, I>(
+    builder: &ExponentialBuilder,
+    notify: &I,
+    executor: &Executor,
+    mut ctx: C,
+    mut f: F,
+) -> (C, Result<T>)
+where
+    F: FnMut(C) -> Fut,
+    Fut: Future<Output = (C, Result<T>)>,
+    I: RetryInterceptor,
+{
+    let mut backoff = builder.build();
+    loop {
+        let (c, res) = f(ctx).await;
+        ctx = c;
+        let err = match res {
+            Ok(v) => return (ctx, Ok(v)),
+            Err(err) if err.is_temporary() => err,
+            Err(err) => return (ctx, Err(err.set_persistent())),
+        };
+        let Some(dur) = backoff.next() else {
+            return (ctx, Err(err.set_persistent()));
+        };
+        let dur = backoff_delay(&err, dur);
+        notify.intercept(&err, dur);
+        executor.sleep(dur).await;
+    }
+}
+
+/// Retry `f` while it fails with temporary errors, blocking the current thread while backoff.
+fn blocking_retry<T, F, I>(builder: &ExponentialBuilder, notify: &I, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+    I: RetryInterceptor,
+{
+    let mut backoff = builder.build();
+    loop {
+        let err = match f() {
+            Ok(v) => return Ok(v),
+            Err(err) if err.is_temporary() => err,
+            Err(err) => return Err(err.set_persistent()),
+        };
+        let Some(dur) = backoff.next() else {
+            return Err(err.set_persistent());
+        };
+        let dur = backoff_delay(&err, dur);
+        notify.intercept(&err, dur);
+        std::thread::sleep(dur);
+    }
+}
+
+/// Use the `Retry-After` returned by service as the floor of backoff.
+fn backoff_delay(err: &Error, dur: Duration) -> Duration {
+    match err.retry_after() {
+        Some(v) => v.max(dur),
+        None => dur,
+    }
+}
+
 pub struct RetryAccessor<A: Access, I: RetryInterceptor> {
     inner: Arc<A>,
     builder: ExponentialBuilder,
     notify: Arc<I>,
+    executor: Executor,
 }
 
 impl<A: Access, I: RetryInterceptor> Debug for RetryAccessor<A, I> {
@@ -278,6 +392,17 @@ impl<A: Access, I: RetryInterceptor> Debug for RetryAccessor<A, I> {
     }
 }
 
+impl<A: Access, I: RetryInterceptor> RetryAccessor<A, I> {
+    fn wrap<R>(&self, inner: R) -> RetryWrapper<R, I> {
+        RetryWrapper::new(
+            inner,
+            self.notify.clone(),
+            self.builder.clone(),
+            self.executor.clone(),
+        )
+    }
+}
+
 impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
     type Inner = A;
     type Reader = RetryWrapper<RetryReader<A, A::Reader>, I>;
@@ -292,202 +417,134 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
     }
 
     async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
-        { || self.inner.create_dir(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur: Duration| self.notify.intercept(err, dur))
-            .map(|v| v.map_err(|e| e.set_persistent()))
-            .await
+        retry(&self.builder, &*self.notify, &self.executor, || {
+            self.inner.create_dir(path, args.clone())
+        })
+        .await
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
-        let (rp, reader) = { || self.inner.read(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .await
-            .map_err(|e| e.set_persistent())?;
+        let (rp, reader) = retry(&self.builder, &*self.notify, &self.executor, || {
+            self.inner.read(path, args.clone())
+        })
+        .await?;
 
         let retry_reader = RetryReader::new(self.inner.clone(), path.to_string(), args, reader);
-        let retry_wrapper =
-            RetryWrapper::new(retry_reader, self.notify.clone(), self.builder.clone());
+        let retry_wrapper = self.wrap(retry_reader);
 
         Ok((rp, retry_wrapper))
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        { || self.inner.write(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .map(|v| {
-                v.map(|(rp, r)| {
-                    (
-                        rp,
-                        RetryWrapper::new(r, self.notify.clone(), self.builder.clone()),
-                    )
-                })
-                .map_err(|e| e.set_persistent())
-            })
-            .await
+        let (rp, w) = retry(&self.builder, &*self.notify, &self.executor, || {
+            self.inner.write(path, args.clone())
+        })
+        .await?;
+        Ok((rp, self.wrap(w)))
     }
 
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
-        { || self.inner.stat(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .map(|v| v.map_err(|e| e.set_persistent()))
-            .await
+        retry(&self.builder, &*self.notify, &self.executor, || {
+            self.inner.stat(path, args.clone())
+        })
+        .await
     }
 
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
-        { || self.inner.delete(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .map(|v| v.map_err(|e| e.set_persistent()))
-            .await
+        retry(&self.builder, &*self.notify, &self.executor, || {
+            self.inner.delete(path, args.clone())
+        })
+        .await
     }
 
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
-        { || self.inner.copy(from, to, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .map(|v| v.map_err(|e| e.set_persistent()))
-            .await
+        retry(&self.builder, &*self.notify, &self.executor, || {
+            self.inner.copy(from, to, args.clone())
+        })
+        .await
     }
 
     async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
-        { || self.inner.rename(from, to, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .map(|v| v.map_err(|e| e.set_persistent()))
-            .await
+        retry(&self.builder, &*self.notify, &self.executor, || {
+            self.inner.rename(from, to, args.clone())
+        })
+        .await
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
-        { || self.inner.list(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .map(|v| {
-                v.map(|(l, p)| {
-                    let lister = RetryWrapper::new(p, self.notify.clone(), self.builder.clone());
-                    (l, lister)
-                })
-                .map_err(|e| e.set_persistent())
-            })
-            .await
+        let (rp, l) = retry(&self.builder, &*self.notify, &self.executor, || {
+            self.inner.list(path, args.clone())
+        })
+        .await?;
+        Ok((rp, self.wrap(l)))
     }
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
-        {
-            || async {
-                let rp = self.inner.batch(args.clone()).await?;
-                let mut nrp = Vec::with_capacity(rp.results().len());
-                for (path, result) in rp.into_results() {
-                    let result = result?;
-                    nrp.push((path, Ok(result)))
-                }
-                Ok(RpBatch::new(nrp))
+        retry(&self.builder, &*self.notify, &self.executor, || async {
+            let rp = self.inner.batch(args.clone()).await?;
+            let mut nrp = Vec::with_capacity(rp.results().len());
+            for (path, result) in rp.into_results() {
+                let result = result?;
+                nrp.push((path, Ok(result)))
             }
-        }
-        .retry(&self.builder)
-        .when(|e: &Error| e.is_temporary())
-        .notify(|err, dur| self.notify.intercept(err, dur))
+            Ok(RpBatch::new(nrp))
+        })
         .await
-        .map_err(|e| e.set_persistent())
     }
 
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
-        { || self.inner.blocking_create_dir(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .call()
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.blocking_create_dir(path, args.clone())
+        })
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
-        let (rp, reader) = { || self.inner.blocking_read(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .call()
-            .map_err(|e| e.set_persistent())?;
+        let (rp, reader) = blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.blocking_read(path, args.clone())
+        })?;
 
         let retry_reader = RetryReader::new(self.inner.clone(), path.to_string(), args, reader);
-        let retry_wrapper =
-            RetryWrapper::new(retry_reader, self.notify.clone(), self.builder.clone());
+        let retry_wrapper = self.wrap(retry_reader);
 
         Ok((rp, retry_wrapper))
     }
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
-        { || self.inner.blocking_write(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .call()
-            .map(|(rp, r)| {
-                (
-                    rp,
-                    RetryWrapper::new(r, self.notify.clone(), self.builder.clone()),
-                )
-            })
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.blocking_write(path, args.clone())
+        })
+        .map(|(rp, r)| (rp, self.wrap(r)))
     }
 
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
-        { || self.inner.blocking_stat(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .call()
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.blocking_stat(path, args.clone())
+        })
     }
 
     fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
-        { || self.inner.blocking_delete(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .call()
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.blocking_delete(path, args.clone())
+        })
     }
 
     fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
-        { || self.inner.blocking_copy(from, to, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .call()
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.blocking_copy(from, to, args.clone())
+        })
     }
 
     fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
-        { || self.inner.blocking_rename(from, to, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .call()
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.blocking_rename(from, to, args.clone())
+        })
     }
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
-        { || self.inner.blocking_list(path, args.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
-            .call()
-            .map(|(rp, p)| {
-                let p = RetryWrapper::new(p, self.notify.clone(), self.builder.clone());
-                (rp, p)
-            })
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.blocking_list(path, args.clone())
+        })
+        .map(|(rp, p)| (rp, self.wrap(p)))
     }
 }
 
@@ -556,14 +613,16 @@ pub struct RetryWrapper<R, I> {
     notify: Arc<I>,
 
     builder: ExponentialBuilder,
+    executor: Executor,
 }
 
 impl<R, I> RetryWrapper<R, I> {
-    fn new(inner: R, notify: Arc<I>, backoff: ExponentialBuilder) -> Self {
+    fn new(inner: R, notify: Arc<I>, backoff: ExponentialBuilder, executor: Executor) -> Self {
         Self {
             inner: Some(inner),
             notify,
             builder: backoff,
+            executor,
         }
     }
 
@@ -579,178 +638,140 @@ impl<R, I> RetryWrapper<R, I> {
 
 impl<R: oio::Read, I: RetryInterceptor> oio::Read for RetryWrapper<R, I> {
     async fn read(&mut self) -> Result<Buffer> {
-        use backon::RetryableWithContext;
-
         let inner = self.take_inner()?;
 
-        let (inner, res) = {
+        let (inner, res) = retry_with_context(
+            &self.builder,
+            &*self.notify,
+            &self.executor,
+            inner,
             |mut r: R| async move {
                 let res = r.read().await;
 
                 (r, res)
-            }
-        }
-        .retry(&self.builder)
-        .when(|e| e.is_temporary())
-        .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+            },
+        )
         .await;
 
         self.inner = Some(inner);
-        res.map_err(|err| err.set_persistent())
+        res
     }
 }
 
 impl<R: oio::BlockingRead, I: RetryInterceptor> oio::BlockingRead for RetryWrapper<R, I> {
     fn read(&mut self) -> Result<Buffer> {
-        use backon::BlockingRetryableWithContext;
+        let mut inner = self.take_inner()?;
 
-        let inner = self.take_inner()?;
-
-        let (inner, res) = {
-            |mut r: R| {
-                let res = r.read();
-
-                (r, res)
-            }
-        }
-        .retry(&self.builder)
-        .when(|e| e.is_temporary())
-        .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
-        .call();
+        let res = blocking_retry(&self.builder, &*self.notify, || inner.read());
 
         self.inner = Some(inner);
-        res.map_err(|err| err.set_persistent())
+        res
     }
 }
 
 impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
-        use backon::RetryableWithContext;
-
         let inner = self.take_inner()?;
 
-        let ((inner, _), res) = {
+        let ((inner, _), res) = retry_with_context(
+            &self.builder,
+            &*self.notify,
+            &self.executor,
+            (inner, bs),
             |(mut r, bs): (R, Buffer)| async move {
                 let res = r.write(bs.clone()).await;
 
                 ((r, bs), res)
-            }
-        }
-        .retry(&self.builder)
-        .when(|e| e.is_temporary())
-        .context((inner, bs))
-        .notify(|err, dur| self.notify.intercept(err, dur))
+            },
+        )
         .await;
 
         self.inner = Some(inner);
-        res.map_err(|err| err.set_persistent())
+        res
     }
 
     async fn abort(&mut self) -> Result<()> {
-        use backon::RetryableWithContext;
-
         let inner = self.take_inner()?;
 
-        let (inner, res) = {
+        let (inner, res) = retry_with_context(
+            &self.builder,
+            &*self.notify,
+            &self.executor,
+            inner,
             |mut r: R| async move {
                 let res = r.abort().await;
 
                 (r, res)
-            }
-        }
-        .retry(&self.builder)
-        .when(|e| e.is_temporary())
-        .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+            },
+        )
         .await;
 
         self.inner = Some(inner);
-        res.map_err(|err| err.set_persistent())
+        res
     }
 
     async fn close(&mut self) -> Result<()> {
-        use backon::RetryableWithContext;
-
         let inner = self.take_inner()?;
 
-        let (inner, res) = {
+        let (inner, res) = retry_with_context(
+            &self.builder,
+            &*self.notify,
+            &self.executor,
+            inner,
             |mut r: R| async move {
                 let res = r.close().await;
 
                 (r, res)
-            }
-        }
-        .retry(&self.builder)
-        .when(|e| e.is_temporary())
-        .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+            },
+        )
         .await;
 
         self.inner = Some(inner);
-        res.map_err(|err| err.set_persistent())
+        res
     }
 }
 
 impl<R: oio::BlockingWrite, I: RetryInterceptor> oio::BlockingWrite for RetryWrapper<R, I> {
     fn write(&mut self, bs: Buffer) -> Result<()> {
-        { || self.inner.as_mut().unwrap().write(bs.clone()) }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| {
-                self.notify.intercept(err, dur);
-            })
-            .call()
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.as_mut().unwrap().write(bs.clone())
+        })
     }
 
     fn close(&mut self) -> Result<()> {
-        { || self.inner.as_mut().unwrap().close() }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| {
-                self.notify.intercept(err, dur);
-            })
-            .call()
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.as_mut().unwrap().close()
+        })
     }
 }
 
 impl<P: oio::List, I: RetryInterceptor> oio::List for RetryWrapper<P, I> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
-        use backon::RetryableWithContext;
-
         let inner = self.take_inner()?;
 
-        let (inner, res) = {
+        let (inner, res) = retry_with_context(
+            &self.builder,
+            &*self.notify,
+            &self.executor,
+            inner,
             |mut p: P| async move {
                 let res = p.next().await;
 
                 (p, res)
-            }
-        }
-        .retry(&self.builder)
-        .when(|e| e.is_temporary())
-        .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+            },
+        )
         .await;
 
         self.inner = Some(inner);
-        res.map_err(|err| err.set_persistent())
+        res
     }
 }
 
 impl<P: oio::BlockingList, I: RetryInterceptor> oio::BlockingList for RetryWrapper<P, I> {
     fn next(&mut self) -> Result<Option<oio::Entry>> {
-        { || self.inner.as_mut().unwrap().next() }
-            .retry(&self.builder)
-            .when(|e| e.is_temporary())
-            .notify(|err, dur| {
-                self.notify.intercept(err, dur);
-            })
-            .call()
-            .map_err(|e| e.set_persistent())
+        blocking_retry(&self.builder, &*self.notify, || {
+            self.inner.as_mut().unwrap().next()
+        })
     }
 }
 
@@ -1095,4 +1116,73 @@ mod tests {
         op.remove(paths).await.expect("batch must succeed");
         assert_eq!(*builder.attempt.lock().unwrap(), 5);
     }
+
+    /// Executor that records all sleeps and wakes them up immediately.
+    #[derive(Default)]
+    struct RecordingExecutor {
+        sleeps: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl Execute for RecordingExecutor {
+        fn execute(&self, _: BoxedStaticFuture<()>) {
+            unreachable!("no task should be executed")
+        }
+
+        fn sleep(&self, dur: Duration) -> BoxedStaticFuture<()> {
+            self.sleeps.lock().unwrap().push(dur);
+            Box::pin(futures::future::ready(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_executor() {
+        let executor = RecordingExecutor::default();
+        let sleeps = executor.sleeps.clone();
+
+        let op = Operator::new(MockBuilder::default())
+            .unwrap()
+            .layer(
+                RetryLayer::new()
+                    .with_min_delay(Duration::from_secs(3600))
+                    .with_executor(Executor::with(executor)),
+            )
+            .finish();
+
+        let mut lister = op
+            .lister("retryable_error/")
+            .await
+            .expect("service must support list");
+        while lister.try_next().await.expect("must success").is_some() {}
+
+        // The lister fails twice, both backoffs must sleep via the executor.
+        assert_eq!(sleeps.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_as_backoff_floor() {
+        let executor = RecordingExecutor::default();
+        let sleeps = executor.sleeps.clone();
+        let executor = Executor::with(executor);
+
+        let builder = ExponentialBuilder::default().with_min_delay(Duration::from_millis(1));
+        let mut attempt = 0;
+        let res = retry(&builder, &DefaultRetryInterceptor, &executor, || {
+            attempt += 1;
+            let res = match attempt {
+                1 => Err(Error::new(ErrorKind::RateLimited, "slow down")
+                    .with_retry_after(Duration::from_secs(10))
+                    .set_temporary()),
+                2 => Err(Error::new(ErrorKind::Unexpected, "retryable").set_temporary()),
+                _ => Ok(()),
+            };
+            async move { res }
+        })
+        .await;
+
+        assert!(res.is_ok());
+        let sleeps = sleeps.lock().unwrap();
+        assert_eq!(sleeps.len(), 2);
+        assert_eq!(sleeps[0], Duration::from_secs(10));
+        assert!(sleeps[1] < Duration::from_secs(10));
+    }
 }
@@ -0,0 +1,549 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use crate::raw::*;
+use crate::*;
+
+/// Scope all operations into a sub dir of the operator's root.
+///
+/// All paths will be prefixed with the given dir transparently, and the dir
+/// will be stripped from the paths of list results. It's useful to hand out
+/// operators for different tenants that share the same backend.
+///
+/// Users can also use [`Operator::subdir`] for short.
+///
+/// # Notes
+///
+/// The dir will be normalized like `root`, so both `a/b` and `/a/b/` are the
+/// same dir. The [`OperatorInfo::root`] will be the joined root.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::SubdirLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(SubdirLayer::new("tenant/a"))
+///     .finish();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SubdirLayer {
+    dir: String,
+}
+
+impl SubdirLayer {
+    /// Create a new `SubdirLayer` with given dir.
+    pub fn new(dir: &str) -> Self {
+        Self {
+            dir: normalize_root(dir),
+        }
+    }
+}
+
+impl<A: Access> Layer<A> for SubdirLayer {
+    type LayeredAccess = SubdirAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        let mut info = (*inner.info()).clone();
+        let root = format!("{}{}", info.root(), &self.dir[1..]);
+        info.set_root(&root);
+
+        SubdirAccessor {
+            dir: self.dir.clone(),
+            info: Arc::new(info),
+            inner,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubdirAccessor<A: Access> {
+    /// The normalized dir like `/path/to/dir/`.
+    dir: String,
+    info: Arc<AccessorInfo>,
+    inner: A,
+}
+
+impl<A: Access> SubdirAccessor<A> {
+    /// Prefix the path with the dir.
+    ///
+    /// Paths containing `..` are rejected, otherwise they could escape from the dir.
+    fn abs_path(&self, path: &str) -> Result<String> {
+        if normalize_path(path).split('/').any(|v| v == "..") {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "path with `..` is not allowed in subdir",
+            )
+            .with_context("path", path));
+        }
+
+        if self.dir == "/" {
+            return Ok(path.to_string());
+        }
+        Ok(build_abs_path(&self.dir, path))
+    }
+
+    /// `start_after` is a path too, prefix it with the dir as well.
+    fn abs_list_args(&self, args: OpList) -> Result<OpList> {
+        match args.start_after().map(|v| self.abs_path(v)).transpose()? {
+            Some(start_after) => Ok(args.with_start_after(&start_after)),
+            None => Ok(args),
+        }
+    }
+}
+
+/// Strip the dir from paths returned by inner accessor.
+fn rel_path(dir: &str, path: &str) -> String {
+    if dir == "/" {
+        return path.to_string();
+    }
+    match path.strip_prefix(&dir[1..]) {
+        Some("") => "/".to_string(),
+        Some(p) => p.to_string(),
+        // Should not happen, keep the path as is.
+        None => path.to_string(),
+    }
+}
+
+impl<A: Access> LayeredAccess for SubdirAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = SubdirLister<A::Lister>;
+    type BlockingLister = SubdirLister<A::BlockingLister>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn metadata(&self) -> Arc<AccessorInfo> {
+        self.info.clone()
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.inner.create_dir(&self.abs_path(path)?, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(&self.abs_path(path)?, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(&self.abs_path(path)?, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.inner
+            .copy(&self.abs_path(from)?, &self.abs_path(to)?, args)
+            .await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.inner
+            .rename(&self.abs_path(from)?, &self.abs_path(to)?, args)
+            .await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(&self.abs_path(path)?, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.inner.delete(&self.abs_path(path)?, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let args = self.abs_list_args(args)?;
+        let (rp, lister) = self.inner.list(&self.abs_path(path)?, args).await?;
+        Ok((rp, SubdirLister::new(&self.dir, lister)))
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = args
+            .into_operation()
+            .into_iter()
+            .map(|(path, op)| Ok((self.abs_path(&path)?, op)))
+            .collect::<Result<_>>()?;
+        let rp = self.inner.batch(OpBatch::new(ops)).await?;
+
+        let results = rp
+            .into_results()
+            .into_iter()
+            .map(|(path, res)| (rel_path(&self.dir, &path), res))
+            .collect();
+        Ok(RpBatch::new(results))
+    }
+
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let rp = self
+            .inner
+            .list_multipart_uploads(&self.abs_path(path)?, args)
+            .await?;
+
+        let uploads = rp
+            .into_uploads()
+            .into_iter()
+            .map(|upload| {
+                let mut v =
+                    MultipartUpload::new(&rel_path(&self.dir, upload.path()), upload.upload_id());
+                if let Some(initiated) = upload.initiated() {
+                    v = v.with_initiated(initiated);
+                }
+                v
+            })
+            .collect();
+        Ok(RpListMultipartUploads::new(uploads))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        self.inner
+            .abort_multipart_upload(&self.abs_path(path)?, args)
+            .await
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        self.inner.stage_block(&self.abs_path(path)?, args).await
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        self.inner
+            .commit_block_list(&self.abs_path(path)?, args)
+            .await
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        self.inner.write_at(&self.abs_path(path)?, args).await
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        self.inner.allocate(&self.abs_path(path)?, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let sources = args
+            .sources()
+            .iter()
+            .map(|v| self.abs_path(v))
+            .collect::<Result<_>>()?;
+        let args = args.with_sources(sources);
+        self.inner.concat(&self.abs_path(path)?, args).await
+    }
+
+    async fn locate(&self, path: &str, args: OpLocate) -> Result<RpLocate> {
+        self.inner.locate(&self.abs_path(path)?, args).await
+    }
+
+    /// Lifecycle rules are shared by the whole bucket, don't allow a sub dir
+    /// to replace rules of others.
+    async fn get_lifecycle(&self, _: OpGetLifecycle) -> Result<RpGetLifecycle> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "lifecycle is not supported in subdir",
+        )
+        .with_operation(Operation::GetLifecycle))
+    }
+
+    async fn set_lifecycle(&self, _: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "lifecycle is not supported in subdir",
+        )
+        .with_operation(Operation::SetLifecycle))
+    }
+
+    async fn get_retention(&self, path: &str, args: OpGetRetention) -> Result<RpGetRetention> {
+        self.inner.get_retention(&self.abs_path(path)?, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        self.inner.set_retention(&self.abs_path(path)?, args).await
+    }
+
+    async fn get_acl(&self, path: &str, args: OpGetAcl) -> Result<RpGetAcl> {
+        self.inner.get_acl(&self.abs_path(path)?, args).await
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        self.inner.set_acl(&self.abs_path(path)?, args).await
+    }
+
+    /// Bucket is shared by all sub dirs, don't allow a sub dir to manage it.
+    async fn create_bucket(&self, _: OpCreateBucket) -> Result<RpCreateBucket> {
+        Err(
+            Error::new(ErrorKind::Unsupported, "bucket is not supported in subdir")
+                .with_operation(Operation::CreateBucket),
+        )
+    }
+
+    async fn delete_bucket(&self, _: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        Err(
+            Error::new(ErrorKind::Unsupported, "bucket is not supported in subdir")
+                .with_operation(Operation::DeleteBucket),
+        )
+    }
+
+    async fn list_buckets(&self, _: OpListBuckets) -> Result<RpListBuckets> {
+        Err(
+            Error::new(ErrorKind::Unsupported, "bucket is not supported in subdir")
+                .with_operation(Operation::ListBuckets),
+        )
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.inner.presign(&self.abs_path(path)?, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.inner.blocking_create_dir(&self.abs_path(path)?, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(&self.abs_path(path)?, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(&self.abs_path(path)?, args)
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.inner
+            .blocking_copy(&self.abs_path(from)?, &self.abs_path(to)?, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.inner
+            .blocking_rename(&self.abs_path(from)?, &self.abs_path(to)?, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.blocking_stat(&self.abs_path(path)?, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.inner.blocking_delete(&self.abs_path(path)?, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        let args = self.abs_list_args(args)?;
+        let (rp, lister) = self.inner.blocking_list(&self.abs_path(path)?, args)?;
+        Ok((rp, SubdirLister::new(&self.dir, lister)))
+    }
+}
+
+pub struct SubdirLister<L> {
+    dir: String,
+    inner: L,
+}
+
+impl<L> SubdirLister<L> {
+    fn new(dir: &str, inner: L) -> Self {
+        Self {
+            dir: dir.to_string(),
+            inner,
+        }
+    }
+
+    fn map_entry(&self, mut entry: oio::Entry) -> oio::Entry {
+        let path = rel_path(&self.dir, entry.path());
+        entry.set_path(&path);
+        entry
+    }
+}
+
+impl<L: oio::List> oio::List for SubdirLister<L> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let entry = self.inner.next().await?;
+        Ok(entry.map(|v| self.map_entry(v)))
+    }
+}
+
+impl<L: oio::BlockingList> oio::BlockingList for SubdirLister<L> {
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let entry = self.inner.next()?;
+        Ok(entry.map(|v| self.map_entry(v)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    #[test]
+    fn test_rel_path() {
+        assert_eq!(rel_path("/", "abc"), "abc");
+        assert_eq!(rel_path("/a/b/", "a/b/"), "/");
+        assert_eq!(rel_path("/a/b/", "a/b/c/d"), "c/d");
+    }
+
+    #[tokio::test]
+    async fn test_subdir() {
+        let op = Operator::new(Memory::default()).unwrap().finish();
+        let sub = op.subdir("tenant/a");
+        assert_eq!(sub.info().root(), "/tenant/a/");
+
+        sub.write("dir/hello.txt", "hello").await.unwrap();
+        assert_eq!(
+            op.read("tenant/a/dir/hello.txt").await.unwrap().to_vec(),
+            b"hello"
+        );
+        assert_eq!(sub.read("dir/hello.txt").await.unwrap().to_vec(), b"hello");
+
+        sub.write("world.txt", "world").await.unwrap();
+        assert!(op.is_exist("tenant/a/world.txt").await.unwrap());
+
+        let mut paths: Vec<String> = sub
+            .lister_with("/")
+            .recursive(true)
+            .await
+            .unwrap()
+            .map_ok(|v| v.path().to_string())
+            .try_collect()
+            .await
+            .unwrap();
+        paths.sort();
+        assert_eq!(paths, vec!["dir/hello.txt", "world.txt"]);
+
+        let mut paths: Vec<String> = sub
+            .list("/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|v| v.path().to_string())
+            .collect();
+        paths.sort();
+        assert_eq!(paths, vec!["dir/", "world.txt"]);
+
+        // Subdir of subdir should be joined.
+        let nested = sub.subdir("dir");
+        assert_eq!(nested.info().root(), "/tenant/a/dir/");
+        assert_eq!(nested.read("hello.txt").await.unwrap().to_vec(), b"hello");
+
+        sub.remove_all("/").await.unwrap();
+        assert!(!op.is_exist("tenant/a/dir/hello.txt").await.unwrap());
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct MockService {
+        listed: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Access for MockService {
+        type Reader = ();
+        type Writer = ();
+        type Lister = ();
+        type BlockingReader = ();
+        type BlockingWriter = ();
+        type BlockingLister = ();
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let mut am = AccessorInfo::default();
+            am.set_native_capability(Capability {
+                list: true,
+                list_with_start_after: true,
+                ..Default::default()
+            });
+            am.into()
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+            self.listed
+                .lock()
+                .unwrap()
+                .push(format!("{path}:{}", args.start_after().unwrap_or_default()));
+            Ok((RpList::default(), ()))
+        }
+
+        fn blocking_list(
+            &self,
+            path: &str,
+            args: OpList,
+        ) -> Result<(RpList, Self::BlockingLister)> {
+            self.listed
+                .lock()
+                .unwrap()
+                .push(format!("{path}:{}", args.start_after().unwrap_or_default()));
+            Ok((RpList::default(), ()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subdir_reject_parent_dir() {
+        let op = Operator::new(Memory::default()).unwrap().finish();
+        op.write("tenant/b/secret.txt", "secret").await.unwrap();
+        let sub = op.subdir("tenant/a");
+
+        for path in ["../b/secret.txt", "dir/../../b/secret.txt", ".."] {
+            let err = sub.read(path).await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        }
+        let err = sub
+            .write("../b/secret.txt", "overwritten")
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(
+            op.read("tenant/b/secret.txt").await.unwrap().to_vec(),
+            b"secret"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subdir_list_with_start_after() {
+        let srv = MockService::default();
+        let acc = SubdirLayer::new("tenant/a").layer(srv.clone());
+
+        Access::list(&acc, "dir/", OpList::new().with_start_after("dir/b"))
+            .await
+            .unwrap();
+        Access::blocking_list(&acc, "dir/", OpList::new().with_start_after("dir/c")).unwrap();
+        Access::list(&acc, "dir/", OpList::new()).await.unwrap();
+
+        assert_eq!(
+            *srv.listed.lock().unwrap(),
+            vec![
+                "tenant/a/dir/:tenant/a/dir/b",
+                "tenant/a/dir/:tenant/a/dir/c",
+                "tenant/a/dir/:",
+            ]
+        );
+    }
+}
@@ -16,9 +16,13 @@
 // under the License.
 
 use std::future::Future;
+use std::pin::pin;
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::future::select;
+use futures::future::Either;
+
 use crate::raw::*;
 use crate::*;
 
@@ -37,6 +41,10 @@ use crate::*;
 /// - IO Operation like `read`, `Reader::read` and `Writer::write`, they operate on data directly, we
 ///   control them by setting `io_timeout`.
 ///
+/// Timeouts are tracked by [`Execute::sleep`] of the executor set by [`TimeoutLayer::with_executor`],
+/// users running on runtimes other than tokio (for example, `wasm32-unknown-unknown`) should set
+/// their own executor.
+///
 /// # Default
 ///
 /// - timeout: 60 seconds
@@ -88,8 +96,8 @@ use crate::*;
 ///
 /// # Implementation Notes
 ///
-/// TimeoutLayer is using [`tokio::time::timeout`] to implement timeout for operations. And IO
-/// Operations insides `reader`, `writer` will use `Pin<Box<tokio::time::Sleep>>` to track the
+/// TimeoutLayer races every operation with the sleep future returned by [`Execute::sleep`]. And IO
+/// Operations insides `reader`, `writer` will create a new sleep future for every call to track the
 /// timeout.
 ///
 /// This might introduce a bit overhead for IO operations, but it's the only way to implement
@@ -104,6 +112,7 @@ use crate::*;
 pub struct TimeoutLayer {
     timeout: Duration,
     io_timeout: Duration,
+    executor: Executor,
 }
 
 impl Default for TimeoutLayer {
@@ -111,6 +120,7 @@ impl Default for TimeoutLayer {
         Self {
             timeout: Duration::from_secs(60),
             io_timeout: Duration::from_secs(10),
+            executor: Executor::new(),
         }
     }
 }
@@ -137,6 +147,14 @@ impl TimeoutLayer {
         self
     }
 
+    /// Set the executor whose [`Execute::sleep`] is used to track timeouts.
+    ///
+    /// Users running on runtimes other than tokio should set this.
+    pub fn with_executor(mut self, executor: Executor) -> Self {
+        self.executor = executor;
+        self
+    }
+
     /// Set speed for TimeoutLayer with given value.
     ///
     /// # Notes
@@ -162,6 +180,7 @@ impl<A: Access> Layer<A> for TimeoutLayer {
 
             timeout: self.timeout,
             io_timeout: self.io_timeout,
+            executor: self.executor.clone(),
         }
     }
 }
@@ -172,16 +191,27 @@ pub struct TimeoutAccessor<A: Access> {
 
     timeout: Duration,
     io_timeout: Duration,
+    executor: Executor,
+}
+
+/// Wait for given future until the timeout reached, returns `None` if timeout.
+async fn timeout<F: Future>(executor: &Executor, timeout: Duration, fut: F) -> Option<F::Output> {
+    match select(pin!(fut), executor.sleep(timeout)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
 }
 
 impl<A: Access> TimeoutAccessor<A> {
     async fn timeout<F: Future<Output = Result<T>>, T>(&self, op: Operation, fut: F) -> Result<T> {
-        tokio::time::timeout(self.timeout, fut).await.map_err(|_| {
-            Error::new(ErrorKind::Unexpected, "operation timeout reached")
-                .with_operation(op)
-                .with_context("timeout", self.timeout.as_secs_f64().to_string())
-                .set_temporary()
-        })?
+        timeout(&self.executor, self.timeout, fut)
+            .await
+            .ok_or_else(|| {
+                Error::new(ErrorKind::Unexpected, "operation timeout reached")
+                    .with_operation(op)
+                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                    .set_temporary()
+            })?
     }
 
     async fn io_timeout<F: Future<Output = Result<T>>, T>(
@@ -189,15 +219,19 @@ impl<A: Access> TimeoutAccessor<A> {
         op: Operation,
         fut: F,
     ) -> Result<T> {
-        tokio::time::timeout(self.io_timeout, fut)
+        timeout(&self.executor, self.io_timeout, fut)
             .await
-            .map_err(|_| {
+            .ok_or_else(|| {
                 Error::new(ErrorKind::Unexpected, "io timeout reached")
                     .with_operation(op)
                     .with_context("timeout", self.io_timeout.as_secs_f64().to_string())
                     .set_temporary()
             })?
     }
+
+    fn wrap<R>(&self, inner: R) -> TimeoutWrapper<R> {
+        TimeoutWrapper::new(inner, self.io_timeout, self.executor.clone())
+    }
 }
 
 impl<A: Access> LayeredAccess for TimeoutAccessor<A> {
@@ -228,7 +262,7 @@ impl<A: Access> LayeredAccess for TimeoutAccessor<A> {
 
         self.io_timeout(Operation::Read, self.inner.read(path, args))
             .await
-            .map(|(rp, r)| (rp, TimeoutWrapper::new(r, self.io_timeout)))
+            .map(|(rp, r)| (rp, self.wrap(r)))
     }
 
     async fn write(&self, path: &str, mut args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
@@ -241,7 +275,7 @@ impl<A: Access> LayeredAccess for TimeoutAccessor<A> {
 
         self.io_timeout(Operation::Write, self.inner.write(path, args))
             .await
-            .map(|(rp, r)| (rp, TimeoutWrapper::new(r, self.io_timeout)))
+            .map(|(rp, r)| (rp, self.wrap(r)))
     }
 
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
@@ -267,7 +301,7 @@ impl<A: Access> LayeredAccess for TimeoutAccessor<A> {
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         self.io_timeout(Operation::List, self.inner.list(path, args))
             .await
-            .map(|(rp, r)| (rp, TimeoutWrapper::new(r, self.io_timeout)))
+            .map(|(rp, r)| (rp, self.wrap(r)))
     }
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
@@ -309,7 +343,11 @@ impl Execute for TimeoutExecutor {
     }
 
     fn timeout(&self) -> Option<BoxedStaticFuture<()>> {
-        Some(Box::pin(tokio::time::sleep(self.timeout)))
+        Some(self.exec.sleep(self.timeout))
+    }
+
+    fn sleep(&self, dur: Duration) -> BoxedStaticFuture<()> {
+        self.exec.sleep(dur)
     }
 }
 
@@ -317,23 +355,29 @@ pub struct TimeoutWrapper<R> {
     inner: R,
 
     timeout: Duration,
+    executor: Executor,
 }
 
 impl<R> TimeoutWrapper<R> {
-    fn new(inner: R, timeout: Duration) -> Self {
-        Self { inner, timeout }
+    fn new(inner: R, timeout: Duration, executor: Executor) -> Self {
+        Self {
+            inner,
+            timeout,
+            executor,
+        }
     }
 
     #[inline]
     async fn io_timeout<F: Future<Output = Result<T>>, T>(
-        timeout: Duration,
+        executor: &Executor,
+        dur: Duration,
         op: &'static str,
         fut: F,
     ) -> Result<T> {
-        tokio::time::timeout(timeout, fut).await.map_err(|_| {
+        timeout(executor, dur, fut).await.ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "io operation timeout reached")
                 .with_operation(op)
-                .with_context("timeout", timeout.as_secs_f64().to_string())
+                .with_context("timeout", dur.as_secs_f64().to_string())
                 .set_temporary()
         })?
     }
@@ -342,31 +386,61 @@ impl<R> TimeoutWrapper<R> {
 impl<R: oio::Read> oio::Read for TimeoutWrapper<R> {
     async fn read(&mut self) -> Result<Buffer> {
         let fut = self.inner.read();
-        Self::io_timeout(self.timeout, Operation::ReaderRead.into_static(), fut).await
+        Self::io_timeout(
+            &self.executor,
+            self.timeout,
+            Operation::ReaderRead.into_static(),
+            fut,
+        )
+        .await
     }
 }
 
 impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
         let fut = self.inner.write(bs);
-        Self::io_timeout(self.timeout, Operation::WriterWrite.into_static(), fut).await
+        Self::io_timeout(
+            &self.executor,
+            self.timeout,
+            Operation::WriterWrite.into_static(),
+            fut,
+        )
+        .await
     }
 
     async fn close(&mut self) -> Result<()> {
         let fut = self.inner.close();
-        Self::io_timeout(self.timeout, Operation::WriterClose.into_static(), fut).await
+        Self::io_timeout(
+            &self.executor,
+            self.timeout,
+            Operation::WriterClose.into_static(),
+            fut,
+        )
+        .await
     }
 
     async fn abort(&mut self) -> Result<()> {
         let fut = self.inner.abort();
-        Self::io_timeout(self.timeout, Operation::WriterAbort.into_static(), fut).await
+        Self::io_timeout(
+            &self.executor,
+            self.timeout,
+            Operation::WriterAbort.into_static(),
+            fut,
+        )
+        .await
     }
 }
 
 impl<R: oio::List> oio::List for TimeoutWrapper<R> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         let fut = self.inner.next();
-        Self::io_timeout(self.timeout, Operation::ListerNext.into_static(), fut).await
+        Self::io_timeout(
+            &self.executor,
+            self.timeout,
+            Operation::ListerNext.into_static(),
+            fut,
+        )
+        .await
     }
 }
 
@@ -515,4 +589,35 @@ mod tests {
         assert_eq!(err.kind(), ErrorKind::Unexpected);
         assert!(err.to_string().contains("timeout"));
     }
+
+    /// Executor that never spawns and wakes up all sleeps immediately.
+    struct ImmediateExecutor;
+
+    impl Execute for ImmediateExecutor {
+        fn execute(&self, _: BoxedStaticFuture<()>) {
+            unreachable!("no task should be executed")
+        }
+
+        fn sleep(&self, _: Duration) -> BoxedStaticFuture<()> {
+            Box::pin(futures::future::ready(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_timeout_with_executor() {
+        let acc = Arc::new(TypeEraseLayer.layer(MockService)) as Accessor;
+        let op = Operator::from_inner(acc).layer(
+            TimeoutLayer::new()
+                .with_timeout(Duration::from_secs(3600))
+                .with_executor(Executor::with(ImmediateExecutor)),
+        );
+
+        // Timeout must be reached at once since the timer comes from the executor.
+        let res = timeout(Duration::from_secs(1), op.delete("test"))
+            .await
+            .expect("timer of executor must be used");
+        let err = res.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(err.to_string().contains("timeout"))
+    }
 }
@@ -278,6 +278,348 @@ pub trait Access: Send + Sync + Debug + Unpin + 'static {
         )))
     }
 
+    /// Invoke the `list_multipart_uploads` operation on the specified path prefix.
+    ///
+    /// Require [`Capability::list_multipart_uploads`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should return all in-progress uploads whose path starts with `path`.
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> impl Future<Output = Result<RpListMultipartUploads>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `abort_multipart_upload` operation on the specified path.
+    ///
+    /// Require [`Capability::abort_multipart_upload`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> impl Future<Output = Result<RpAbortMultipartUpload>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `stage_block` operation on the specified path.
+    ///
+    /// Require [`Capability::stage_block`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should upload the block as uncommitted, it will be invisible until committed.
+    /// - Staging the same block id again will replace the previous one.
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    fn stage_block(
+        &self,
+        path: &str,
+        args: OpStageBlock,
+    ) -> impl Future<Output = Result<RpStageBlock>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `commit_block_list` operation on the specified path.
+    ///
+    /// Require [`Capability::commit_block_list`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should write the staged blocks in order into the file at given path.
+    /// - Blocks that are staged but not committed will be discarded.
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> impl Future<Output = Result<RpCommitBlockList>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `write_at` operation on the specified path.
+    ///
+    /// Require [`Capability::write_at`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should create the file if it doesn't exist, and extend it if
+    ///   the write goes beyond the end.
+    /// - Data outside of the written range should be kept as is, regions that
+    ///   never be written should be read as zeros.
+    /// - Services should allow writing different ranges of the same file concurrently.
+    fn write_at(
+        &self,
+        path: &str,
+        args: OpWriteAt,
+    ) -> impl Future<Output = Result<RpWriteAt>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `allocate` operation on the specified path.
+    ///
+    /// Require [`Capability::allocate`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should create a file of given size which reads as zeros.
+    /// - Existing file on the path should be replaced.
+    fn allocate(
+        &self,
+        path: &str,
+        args: OpAllocate,
+    ) -> impl Future<Output = Result<RpAllocate>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `concat` operation on the specified path.
+    ///
+    /// Require [`Capability::concat`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should concat all sources in order into the file at given path.
+    /// - The target will be overwritten if exists.
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `locate` operation on the specified path.
+    ///
+    /// Require [`Capability::locate`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should return the locations of all blocks that overlap with the range.
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    fn locate(
+        &self,
+        path: &str,
+        args: OpLocate,
+    ) -> impl Future<Output = Result<RpLocate>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `get_lifecycle` operation to get the lifecycle rules.
+    ///
+    /// Require [`Capability::lifecycle`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should return an empty list if no lifecycle rules are set.
+    /// - Rules outside of root should not be returned.
+    fn get_lifecycle(
+        &self,
+        args: OpGetLifecycle,
+    ) -> impl Future<Output = Result<RpGetLifecycle>> + MaybeSend {
+        let _ = args;
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_lifecycle` operation to replace the lifecycle rules.
+    ///
+    /// Require [`Capability::lifecycle`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should remove all lifecycle rules if the given rules are empty.
+    fn set_lifecycle(
+        &self,
+        args: OpSetLifecycle,
+    ) -> impl Future<Output = Result<RpSetLifecycle>> + MaybeSend {
+        let _ = args;
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `get_retention` operation on the specified path.
+    ///
+    /// Require [`Capability::retention`]
+    fn get_retention(
+        &self,
+        path: &str,
+        args: OpGetRetention,
+    ) -> impl Future<Output = Result<RpGetRetention>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_retention` operation on the specified path.
+    ///
+    /// Require [`Capability::retention`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should keep the retention or legal hold as is if it's not set in args.
+    fn set_retention(
+        &self,
+        path: &str,
+        args: OpSetRetention,
+    ) -> impl Future<Output = Result<RpSetRetention>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `get_acl` operation on the specified path.
+    ///
+    /// Require [`Capability::acl`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should return the closest canned acl if the real acl can't be
+    ///   represented by [`Acl`].
+    fn get_acl(
+        &self,
+        path: &str,
+        args: OpGetAcl,
+    ) -> impl Future<Output = Result<RpGetAcl>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_acl` operation on the specified path.
+    ///
+    /// Require [`Capability::acl`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should return `Unsupported` if the given acl can't be mapped.
+    fn set_acl(
+        &self,
+        path: &str,
+        args: OpSetAcl,
+    ) -> impl Future<Output = Result<RpSetAcl>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `create_bucket` operation to create the configured bucket.
+    ///
+    /// Require [`Capability::create_bucket`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services should return [`ErrorKind::AlreadyExists`] if the bucket exists.
+    fn create_bucket(
+        &self,
+        args: OpCreateBucket,
+    ) -> impl Future<Output = Result<RpCreateBucket>> + MaybeSend {
+        let _ = args;
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `delete_bucket` operation to delete the configured bucket.
+    ///
+    /// Require [`Capability::delete_bucket`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services usually refuse to delete a bucket that is not empty.
+    fn delete_bucket(
+        &self,
+        args: OpDeleteBucket,
+    ) -> impl Future<Output = Result<RpDeleteBucket>> + MaybeSend {
+        let _ = args;
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `list_buckets` operation to list all buckets of the account.
+    ///
+    /// Require [`Capability::list_buckets`]
+    fn list_buckets(
+        &self,
+        args: OpListBuckets,
+    ) -> impl Future<Output = Result<RpListBuckets>> + MaybeSend {
+        let _ = args;
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
     /// Invoke the `blocking_create` operation on the specified path.
     ///
     /// This operation is the blocking version of [`Accessor::create_dir`]
@@ -451,6 +793,82 @@ pub trait AccessDyn: Send + Sync + Debug + Unpin {
     ) -> BoxedFuture<'a, Result<RpPresign>>;
     /// Dyn version of [`Accessor::batch`]
     fn batch_dyn(&self, args: OpBatch) -> BoxedFuture<'_, Result<RpBatch>>;
+    /// Dyn version of [`Accessor::list_multipart_uploads`]
+    fn list_multipart_uploads_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpListMultipartUploads,
+    ) -> BoxedFuture<'a, Result<RpListMultipartUploads>>;
+    /// Dyn version of [`Accessor::abort_multipart_upload`]
+    fn abort_multipart_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpAbortMultipartUpload,
+    ) -> BoxedFuture<'a, Result<RpAbortMultipartUpload>>;
+    /// Dyn version of [`Accessor::stage_block`]
+    fn stage_block_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpStageBlock,
+    ) -> BoxedFuture<'a, Result<RpStageBlock>>;
+    /// Dyn version of [`Accessor::commit_block_list`]
+    fn commit_block_list_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpCommitBlockList,
+    ) -> BoxedFuture<'a, Result<RpCommitBlockList>>;
+    /// Dyn version of [`Accessor::write_at`]
+    fn write_at_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpWriteAt,
+    ) -> BoxedFuture<'a, Result<RpWriteAt>>;
+    /// Dyn version of [`Accessor::allocate`]
+    fn allocate_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpAllocate,
+    ) -> BoxedFuture<'a, Result<RpAllocate>>;
+    /// Dyn version of [`Accessor::concat`]
+    fn concat_dyn<'a>(&'a self, path: &'a str, args: OpConcat)
+        -> BoxedFuture<'a, Result<RpConcat>>;
+    /// Dyn version of [`Accessor::locate`]
+    fn locate_dyn<'a>(&'a self, path: &'a str, args: OpLocate)
+        -> BoxedFuture<'a, Result<RpLocate>>;
+    /// Dyn version of [`Accessor::get_lifecycle`]
+    fn get_lifecycle_dyn(&self, args: OpGetLifecycle) -> BoxedFuture<'_, Result<RpGetLifecycle>>;
+    /// Dyn version of [`Accessor::set_lifecycle`]
+    fn set_lifecycle_dyn(&self, args: OpSetLifecycle) -> BoxedFuture<'_, Result<RpSetLifecycle>>;
+    /// Dyn version of [`Accessor::get_retention`]
+    fn get_retention_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpGetRetention,
+    ) -> BoxedFuture<'a, Result<RpGetRetention>>;
+    /// Dyn version of [`Accessor::set_retention`]
+    fn set_retention_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetRetention,
+    ) -> BoxedFuture<'a, Result<RpSetRetention>>;
+    /// Dyn version of [`Accessor::get_acl`]
+    fn get_acl_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpGetAcl,
+    ) -> BoxedFuture<'a, Result<RpGetAcl>>;
+    /// Dyn version of [`Accessor::set_acl`]
+    fn set_acl_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetAcl,
+    ) -> BoxedFuture<'a, Result<RpSetAcl>>;
+    /// Dyn version of [`Accessor::create_bucket`]
+    fn create_bucket_dyn(&self, args: OpCreateBucket) -> BoxedFuture<'_, Result<RpCreateBucket>>;
+    /// Dyn version of [`Accessor::delete_bucket`]
+    fn delete_bucket_dyn(&self, args: OpDeleteBucket) -> BoxedFuture<'_, Result<RpDeleteBucket>>;
+    /// Dyn version of [`Accessor::list_buckets`]
+    fn list_buckets_dyn(&self, args: OpListBuckets) -> BoxedFuture<'_, Result<RpListBuckets>>;
     /// Dyn version of [`Accessor::blocking_create_dir`]
     fn blocking_create_dir_dyn(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir>;
     /// Dyn version of [`Accessor::blocking_stat`]
@@ -562,6 +980,122 @@ where
         Box::pin(self.batch(args))
     }
 
+    fn list_multipart_uploads_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpListMultipartUploads,
+    ) -> BoxedFuture<'a, Result<RpListMultipartUploads>> {
+        Box::pin(self.list_multipart_uploads(path, args))
+    }
+
+    fn abort_multipart_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpAbortMultipartUpload,
+    ) -> BoxedFuture<'a, Result<RpAbortMultipartUpload>> {
+        Box::pin(self.abort_multipart_upload(path, args))
+    }
+
+    fn stage_block_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpStageBlock,
+    ) -> BoxedFuture<'a, Result<RpStageBlock>> {
+        Box::pin(self.stage_block(path, args))
+    }
+
+    fn commit_block_list_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpCommitBlockList,
+    ) -> BoxedFuture<'a, Result<RpCommitBlockList>> {
+        Box::pin(self.commit_block_list(path, args))
+    }
+
+    fn write_at_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpWriteAt,
+    ) -> BoxedFuture<'a, Result<RpWriteAt>> {
+        Box::pin(self.write_at(path, args))
+    }
+
+    fn allocate_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpAllocate,
+    ) -> BoxedFuture<'a, Result<RpAllocate>> {
+        Box::pin(self.allocate(path, args))
+    }
+
+    fn concat_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpConcat,
+    ) -> BoxedFuture<'a, Result<RpConcat>> {
+        Box::pin(self.concat(path, args))
+    }
+
+    fn locate_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpLocate,
+    ) -> BoxedFuture<'a, Result<RpLocate>> {
+        Box::pin(self.locate(path, args))
+    }
+
+    fn get_lifecycle_dyn(&self, args: OpGetLifecycle) -> BoxedFuture<'_, Result<RpGetLifecycle>> {
+        Box::pin(self.get_lifecycle(args))
+    }
+
+    fn set_lifecycle_dyn(&self, args: OpSetLifecycle) -> BoxedFuture<'_, Result<RpSetLifecycle>> {
+        Box::pin(self.set_lifecycle(args))
+    }
+
+    fn get_retention_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpGetRetention,
+    ) -> BoxedFuture<'a, Result<RpGetRetention>> {
+        Box::pin(self.get_retention(path, args))
+    }
+
+    fn set_retention_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetRetention,
+    ) -> BoxedFuture<'a, Result<RpSetRetention>> {
+        Box::pin(self.set_retention(path, args))
+    }
+
+    fn get_acl_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpGetAcl,
+    ) -> BoxedFuture<'a, Result<RpGetAcl>> {
+        Box::pin(self.get_acl(path, args))
+    }
+
+    fn set_acl_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetAcl,
+    ) -> BoxedFuture<'a, Result<RpSetAcl>> {
+        Box::pin(self.set_acl(path, args))
+    }
+
+    fn create_bucket_dyn(&self, args: OpCreateBucket) -> BoxedFuture<'_, Result<RpCreateBucket>> {
+        Box::pin(self.create_bucket(args))
+    }
+
+    fn delete_bucket_dyn(&self, args: OpDeleteBucket) -> BoxedFuture<'_, Result<RpDeleteBucket>> {
+        Box::pin(self.delete_bucket(args))
+    }
+
+    fn list_buckets_dyn(&self, args: OpListBuckets) -> BoxedFuture<'_, Result<RpListBuckets>> {
+        Box::pin(self.list_buckets(args))
+    }
+
     fn blocking_create_dir_dyn(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.blocking_create_dir(path, args)
     }
@@ -651,6 +1185,86 @@ impl Access for dyn AccessDyn {
         self.batch_dyn(args)
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        self.list_multipart_uploads_dyn(path, args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        self.abort_multipart_upload_dyn(path, args).await
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        self.stage_block_dyn(path, args).await
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        self.commit_block_list_dyn(path, args).await
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        self.write_at_dyn(path, args).await
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        self.allocate_dyn(path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.concat_dyn(path, args).await
+    }
+
+    async fn locate(&self, path: &str, args: OpLocate) -> Result<RpLocate> {
+        self.locate_dyn(path, args).await
+    }
+
+    async fn get_lifecycle(&self, args: OpGetLifecycle) -> Result<RpGetLifecycle> {
+        self.get_lifecycle_dyn(args).await
+    }
+
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        self.set_lifecycle_dyn(args).await
+    }
+
+    async fn get_retention(&self, path: &str, args: OpGetRetention) -> Result<RpGetRetention> {
+        self.get_retention_dyn(path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        self.set_retention_dyn(path, args).await
+    }
+
+    async fn get_acl(&self, path: &str, args: OpGetAcl) -> Result<RpGetAcl> {
+        self.get_acl_dyn(path, args).await
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        self.set_acl_dyn(path, args).await
+    }
+
+    async fn create_bucket(&self, args: OpCreateBucket) -> Result<RpCreateBucket> {
+        self.create_bucket_dyn(args).await
+    }
+
+    async fn delete_bucket(&self, args: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        self.delete_bucket_dyn(args).await
+    }
+
+    async fn list_buckets(&self, args: OpListBuckets) -> Result<RpListBuckets> {
+        self.list_buckets_dyn(args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.blocking_create_dir_dyn(path, args)
     }
@@ -700,6 +1314,7 @@ impl Access for () {
             name: "dummy".to_string(),
             native_capability: Capability::default(),
             full_capability: Capability::default(),
+            http_client: None,
         }
         .into()
     }
@@ -796,6 +1411,137 @@ impl<T: Access + ?Sized> Access for Arc<T> {
         async move { self.as_ref().batch(args).await }
     }
 
+    fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> impl Future<Output = Result<RpListMultipartUploads>> + MaybeSend {
+        async move { self.as_ref().list_multipart_uploads(path, args).await }
+    }
+
+    fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> impl Future<Output = Result<RpAbortMultipartUpload>> + MaybeSend {
+        async move { self.as_ref().abort_multipart_upload(path, args).await }
+    }
+
+    fn stage_block(
+        &self,
+        path: &str,
+        args: OpStageBlock,
+    ) -> impl Future<Output = Result<RpStageBlock>> + MaybeSend {
+        async move { self.as_ref().stage_block(path, args).await }
+    }
+
+    fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> impl Future<Output = Result<RpCommitBlockList>> + MaybeSend {
+        async move { self.as_ref().commit_block_list(path, args).await }
+    }
+
+    fn write_at(
+        &self,
+        path: &str,
+        args: OpWriteAt,
+    ) -> impl Future<Output = Result<RpWriteAt>> + MaybeSend {
+        async move { self.as_ref().write_at(path, args).await }
+    }
+
+    fn allocate(
+        &self,
+        path: &str,
+        args: OpAllocate,
+    ) -> impl Future<Output = Result<RpAllocate>> + MaybeSend {
+        async move { self.as_ref().allocate(path, args).await }
+    }
+
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        async move { self.as_ref().concat(path, args).await }
+    }
+
+    fn locate(
+        &self,
+        path: &str,
+        args: OpLocate,
+    ) -> impl Future<Output = Result<RpLocate>> + MaybeSend {
+        async move { self.as_ref().locate(path, args).await }
+    }
+
+    fn get_lifecycle(
+        &self,
+        args: OpGetLifecycle,
+    ) -> impl Future<Output = Result<RpGetLifecycle>> + MaybeSend {
+        async move { self.as_ref().get_lifecycle(args).await }
+    }
+
+    fn set_lifecycle(
+        &self,
+        args: OpSetLifecycle,
+    ) -> impl Future<Output = Result<RpSetLifecycle>> + MaybeSend {
+        async move { self.as_ref().set_lifecycle(args).await }
+    }
+
+    fn get_retention(
+        &self,
+        path: &str,
+        args: OpGetRetention,
+    ) -> impl Future<Output = Result<RpGetRetention>> + MaybeSend {
+        async move { self.as_ref().get_retention(path, args).await }
+    }
+
+    fn set_retention(
+        &self,
+        path: &str,
+        args: OpSetRetention,
+    ) -> impl Future<Output = Result<RpSetRetention>> + MaybeSend {
+        async move { self.as_ref().set_retention(path, args).await }
+    }
+
+    fn get_acl(
+        &self,
+        path: &str,
+        args: OpGetAcl,
+    ) -> impl Future<Output = Result<RpGetAcl>> + MaybeSend {
+        async move { self.as_ref().get_acl(path, args).await }
+    }
+
+    fn set_acl(
+        &self,
+        path: &str,
+        args: OpSetAcl,
+    ) -> impl Future<Output = Result<RpSetAcl>> + MaybeSend {
+        async move { self.as_ref().set_acl(path, args).await }
+    }
+
+    fn create_bucket(
+        &self,
+        args: OpCreateBucket,
+    ) -> impl Future<Output = Result<RpCreateBucket>> + MaybeSend {
+        async move { self.as_ref().create_bucket(args).await }
+    }
+
+    fn delete_bucket(
+        &self,
+        args: OpDeleteBucket,
+    ) -> impl Future<Output = Result<RpDeleteBucket>> + MaybeSend {
+        async move { self.as_ref().delete_bucket(args).await }
+    }
+
+    fn list_buckets(
+        &self,
+        args: OpListBuckets,
+    ) -> impl Future<Output = Result<RpListBuckets>> + MaybeSend {
+        async move { self.as_ref().list_buckets(args).await }
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.as_ref().blocking_create_dir(path, args)
     }
@@ -841,6 +1587,8 @@ pub struct AccessorInfo {
 
     native_capability: Capability,
     full_capability: Capability,
+
+    http_client: Option<HttpClient>,
 }
 
 impl AccessorInfo {
@@ -910,4 +1658,20 @@ impl AccessorInfo {
     pub fn full_capability_mut(&mut self) -> &mut Capability {
         &mut self.full_capability
     }
+
+    /// Get the http client used by service.
+    ///
+    /// Returns `None` if service doesn't send requests via [`HttpClient`].
+    pub fn http_client(&self) -> Option<HttpClient> {
+        self.http_client.clone()
+    }
+
+    /// Set the http client used by service.
+    ///
+    /// Services that support presign should set it so that the presigned requests
+    /// could be sent via the same client.
+    pub fn set_http_client(&mut self, client: HttpClient) -> &mut Self {
+        self.http_client = Some(client);
+        self
+    }
 }
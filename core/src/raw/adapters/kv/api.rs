@@ -17,6 +17,7 @@
 
 use std::fmt::Debug;
 use std::future::ready;
+use std::time::Duration;
 
 use futures::Future;
 
@@ -48,6 +49,30 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         .with_operation("kv::Adapter::blocking_get"))
     }
 
+    /// Get a range of the value of key from service, returns the data in range
+    /// and the total size of the value.
+    ///
+    /// - return `Ok(None)` if this key is not exist.
+    ///
+    /// The default implementation gets the whole value, services that can read
+    /// part of the value should override it to avoid loading large values.
+    fn get_range(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> impl Future<Output = Result<Option<(Buffer, u64)>>> + MaybeSend {
+        async move {
+            let bs = self.get(path).await?;
+            Ok(bs.map(|bs| slice_range(bs, range)))
+        }
+    }
+
+    /// The blocking version of get_range.
+    fn blocking_get_range(&self, path: &str, range: BytesRange) -> Result<Option<(Buffer, u64)>> {
+        let bs = self.blocking_get(path)?;
+        Ok(bs.map(|bs| slice_range(bs, range)))
+    }
+
     /// Set a key into service.
     fn set(&self, path: &str, value: Buffer) -> impl Future<Output = Result<()>> + MaybeSend;
 
@@ -62,6 +87,36 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         .with_operation("kv::Adapter::blocking_set"))
     }
 
+    /// Set a key into service which will be expired after given ttl.
+    ///
+    /// Services that support this should also enable `write_with_ttl` in
+    /// their capability.
+    fn set_with_ttl(
+        &self,
+        path: &str,
+        value: Buffer,
+        ttl: Duration,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        let _ = (path, value, ttl);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::set_with_ttl")))
+    }
+
+    /// The blocking version of set_with_ttl.
+    fn blocking_set_with_ttl(&self, path: &str, value: Buffer, ttl: Duration) -> Result<()> {
+        let _ = (path, value, ttl);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::blocking_set_with_ttl"))
+    }
+
     /// Delete a key from service.
     ///
     /// - return `Ok(())` even if this key is not exist.
@@ -129,6 +184,16 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
     }
 }
 
+/// Slice the value by range, returns the data in range and the total size.
+fn slice_range(bs: Buffer, range: BytesRange) -> (Buffer, u64) {
+    let size = bs.len() as u64;
+    let bs = match BytesContentRange::from_bytes_range(range, size).range_inclusive() {
+        Some(r) => bs.slice(*r.start() as usize..=*r.end() as usize),
+        None => Buffer::new(),
+    };
+    (bs, size)
+}
+
 /// Metadata for this key value accessor.
 pub struct Metadata {
     scheme: Scheme,
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 use std::vec::IntoIter;
 
 use super::Adapter;
@@ -89,32 +90,40 @@ impl<S: Adapter> Access for Backend<S> {
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let p = build_abs_path(&self.root, path);
-        let bs = match self.kv.get(&p).await? {
-            Some(bs) => bs,
+        let (bs, size) = match self.kv.get_range(&p, args.range()).await? {
+            Some(v) => v,
             None => return Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
         };
-        Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
+        let range = BytesContentRange::from_bytes_range(args.range(), size);
+        Ok((RpRead::new().with_range(Some(range)), bs))
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
         let p = build_abs_path(&self.root, path);
-        let bs = match self.kv.blocking_get(&p)? {
-            Some(bs) => bs,
+        let (bs, size) = match self.kv.blocking_get_range(&p, args.range())? {
+            Some(v) => v,
             None => return Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
         };
-        Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
+        let range = BytesContentRange::from_bytes_range(args.range(), size);
+        Ok((RpRead::new().with_range(Some(range)), bs))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            KvWriter::new(self.kv.clone(), p, args.ttl()),
+        ))
     }
 
-    fn blocking_write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
         let p = build_abs_path(&self.root, path);
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            KvWriter::new(self.kv.clone(), p, args.ttl()),
+        ))
     }
 
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
@@ -123,10 +132,11 @@ impl<S: Adapter> Access for Backend<S> {
         if p == build_abs_path(&self.root, "") {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            let bs = self.kv.get(&p).await?;
+            // Only the size is needed, read nothing.
+            let bs = self.kv.get_range(&p, BytesRange::new(0, Some(0))).await?;
             match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
+                Some((_, size)) => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(size),
                 )),
                 None => Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
             }
@@ -139,10 +149,13 @@ impl<S: Adapter> Access for Backend<S> {
         if p == build_abs_path(&self.root, "") {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            let bs = self.kv.blocking_get(&p)?;
+            // Only the size is needed, read nothing.
+            let bs = self
+                .kv
+                .blocking_get_range(&p, BytesRange::new(0, Some(0)))?;
             match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
+                Some((_, size)) => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(size),
                 )),
                 None => Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
             }
@@ -223,14 +236,16 @@ impl oio::BlockingList for KvLister {
 pub struct KvWriter<S> {
     kv: Arc<S>,
     path: String,
+    ttl: Option<Duration>,
     buffer: QueueBuf,
 }
 
 impl<S> KvWriter<S> {
-    fn new(kv: Arc<S>, path: String) -> Self {
+    fn new(kv: Arc<S>, path: String, ttl: Option<Duration>) -> Self {
         KvWriter {
             kv,
             path,
+            ttl,
             buffer: QueueBuf::new(),
         }
     }
@@ -249,7 +264,10 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
 
     async fn close(&mut self) -> Result<()> {
         let buf = self.buffer.clone().collect();
-        self.kv.set(&self.path, buf).await
+        match self.ttl {
+            Some(ttl) => self.kv.set_with_ttl(&self.path, buf, ttl).await,
+            None => self.kv.set(&self.path, buf).await,
+        }
     }
 
     async fn abort(&mut self) -> Result<()> {
@@ -266,7 +284,10 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
 
     fn close(&mut self) -> Result<()> {
         let buf = self.buffer.clone().collect();
-        self.kv.blocking_set(&self.path, buf)?;
+        match self.ttl {
+            Some(ttl) => self.kv.blocking_set_with_ttl(&self.path, buf, ttl)?,
+            None => self.kv.blocking_set(&self.path, buf)?,
+        }
         Ok(())
     }
 }
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use std::future::ready;
 use std::future::Future;
 use std::mem::size_of;
+use std::time::Duration;
 
 use chrono::Utc;
 
@@ -60,6 +61,33 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
     /// Set a value into adapter.
     fn blocking_set(&self, path: &str, value: Value) -> Result<()>;
 
+    /// Set a value into adapter which will be expired after given ttl.
+    fn set_with_ttl(
+        &self,
+        path: &str,
+        value: Value,
+        ttl: Duration,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        let _ = (path, value, ttl);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "typed_kv adapter doesn't support this operation",
+        )
+        .with_operation("typed_kv::Adapter::set_with_ttl")))
+    }
+
+    /// Set a value into adapter which will be expired after given ttl.
+    fn blocking_set_with_ttl(&self, path: &str, value: Value, ttl: Duration) -> Result<()> {
+        let _ = (path, value, ttl);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "typed_kv adapter doesn't support this operation",
+        )
+        .with_operation("typed_kv::Adapter::blocking_set_with_ttl"))
+    }
+
     /// Delete a value from adapter.
     fn delete(&self, path: &str) -> impl Future<Output = Result<()>> + MaybeSend;
 
@@ -130,6 +158,10 @@ pub struct Capability {
     pub delete: bool,
     /// If typed_kv operator supports scan natively.
     pub scan: bool,
+    /// If keys returned by scan are sorted in lexicographical order.
+    pub scan_sorted: bool,
+    /// If typed_kv operator supports set with ttl natively.
+    pub set_with_ttl: bool,
 }
 
 impl Debug for Capability {
@@ -148,6 +180,12 @@ impl Debug for Capability {
         if self.scan {
             s.push("Scan");
         }
+        if self.scan_sorted {
+            s.push("ScanSorted");
+        }
+        if self.set_with_ttl {
+            s.push("SetWithTtl");
+        }
 
         write!(f, "{{ {} }}", s.join(" | "))
     }
@@ -85,6 +85,11 @@ impl<S: Adapter> Access for Backend<S> {
         if kv_cap.scan {
             cap.list = true;
             cap.list_with_recursive = true;
+            cap.list_sorted = kv_cap.scan_sorted;
+        }
+
+        if kv_cap.set_with_ttl {
+            cap.write_with_ttl = true;
         }
 
         cap.blocking = true;
@@ -98,24 +103,30 @@ impl<S: Adapter> Access for Backend<S> {
         let p = build_abs_path(&self.root, path);
 
         let bs = match self.kv.get(&p).await? {
-            // TODO: we can reuse the metadata in value to build content range.
             Some(bs) => bs.value,
             None => return Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
         };
 
-        Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
+        let range = BytesContentRange::from_bytes_range(args.range(), bs.len() as u64);
+        Ok((
+            RpRead::new().with_range(Some(range)),
+            bs.slice(args.range().to_range_as_usize()),
+        ))
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
         let p = build_abs_path(&self.root, path);
 
         let bs = match self.kv.blocking_get(&p)? {
-            // TODO: we can reuse the metadata in value to build content range.
             Some(bs) => bs.value,
             None => return Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
         };
 
-        Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
+        let range = BytesContentRange::from_bytes_range(args.range(), bs.len() as u64);
+        Ok((
+            RpRead::new().with_range(Some(range)),
+            bs.slice(args.range().to_range_as_usize()),
+        ))
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
@@ -291,7 +302,10 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
                 value
             }
         };
-        self.kv.set(&self.path, value).await?;
+        match self.op.ttl() {
+            Some(ttl) => self.kv.set_with_ttl(&self.path, value, ttl).await?,
+            None => self.kv.set(&self.path, value).await?,
+        }
         Ok(())
     }
 
@@ -320,7 +334,10 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
             }
         };
 
-        kv.blocking_set(&self.path, value)?;
+        match self.op.ttl() {
+            Some(ttl) => kv.blocking_set_with_ttl(&self.path, value, ttl)?,
+            None => kv.blocking_set(&self.path, value)?,
+        }
         Ok(())
     }
 }
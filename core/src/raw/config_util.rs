@@ -0,0 +1,225 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Macros to reduce the boilerplate of services' config and builder.
+//!
+//! A service built with them looks like:
+//!
+//! ```ignore
+//! #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+//! #[serde(default)]
+//! #[non_exhaustive]
+//! pub struct DemoConfig {
+//!     pub root: Option<String>,
+//!     pub endpoint: Option<String>,
+//!     pub token: Option<String>,
+//! }
+//!
+//! impl_config_debug!(DemoConfig { root, endpoint }, redacted { token });
+//!
+//! #[derive(Default)]
+//! pub struct DemoBuilder {
+//!     config: DemoConfig,
+//! }
+//!
+//! impl_configurator!(DemoConfig => DemoBuilder);
+//!
+//! builder_setters!(DemoBuilder {
+//!     /// Set root of this backend.
+//!     root: &str,
+//!     /// Set endpoint of this backend.
+//!     endpoint: &str,
+//!     /// Set token of this backend.
+//!     token: &str,
+//! });
+//! ```
+
+/// IntoConfigValue assigns the value passed to a builder setter into the
+/// config field.
+///
+/// This is used by [`builder_setters!`] to decide how to fill the field:
+///
+/// - `&str` into `Option<String>`: empty string will be ignored.
+/// - `T` into `Option<T>`: the field will be set to `Some(T)`.
+/// - `T` into `T`: the field will be overwritten.
+pub trait IntoConfigValue<T> {
+    /// Assign self into the config field.
+    fn assign_to(self, field: &mut T);
+}
+
+impl IntoConfigValue<Option<String>> for &str {
+    fn assign_to(self, field: &mut Option<String>) {
+        if !self.is_empty() {
+            *field = Some(self.to_string());
+        }
+    }
+}
+
+impl<T> IntoConfigValue<Option<T>> for T {
+    fn assign_to(self, field: &mut Option<T>) {
+        *field = Some(self);
+    }
+}
+
+impl<T> IntoConfigValue<T> for T {
+    fn assign_to(self, field: &mut T) {
+        *field = self;
+    }
+}
+
+/// Implement `Debug` for services' config.
+///
+/// Fields listed in `redacted` must be `Option`s and will be printed as
+/// `<redacted>` if set, so secrets will never be leaked into logs.
+#[macro_export]
+macro_rules! impl_config_debug {
+    ($config:ident { $($field:ident),* $(,)? } $(, redacted { $($secret:ident),* $(,)? })? $(,)?) => {
+        impl std::fmt::Debug for $config {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let mut d = f.debug_struct(stringify!($config));
+
+                $(d.field(stringify!($field), &self.$field);)*
+                $($(d.field(
+                    stringify!($secret),
+                    &self.$secret.as_ref().map(|_| "<redacted>"),
+                );)*)?
+
+                d.finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+/// Implement `Configurator` for services' config and `Debug` for its builder.
+///
+/// The builder must implement `Default` and hold the config in the `config` field.
+#[macro_export]
+macro_rules! impl_configurator {
+    ($config:ident => $builder:ident) => {
+        impl $crate::Configurator for $config {
+            type Builder = $builder;
+
+            #[allow(clippy::needless_update)]
+            fn into_builder(self) -> Self::Builder {
+                $builder {
+                    config: self,
+                    ..Default::default()
+                }
+            }
+        }
+
+        impl std::fmt::Debug for $builder {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($builder))
+                    .field("config", &self.config)
+                    .finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+/// Generate setters for services' builder which assign the argument into the
+/// config field of the same name via [`IntoConfigValue`].
+///
+/// Doc comments and attributes of every setter will be kept.
+#[macro_export]
+macro_rules! builder_setters {
+    ($builder:ident { $($(#[$meta:meta])* $name:ident: $ty:ty),* $(,)? }) => {
+        impl $builder {
+            $(
+                $(#[$meta])*
+                pub fn $name(mut self, $name: $ty) -> Self {
+                    $crate::raw::IntoConfigValue::assign_to($name, &mut self.config.$name);
+                    self
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::raw::*;
+    use crate::*;
+
+    #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+    #[serde(default)]
+    struct DemoConfig {
+        root: Option<String>,
+        token: Option<String>,
+        db: i64,
+        ttl: Option<Duration>,
+    }
+
+    impl_config_debug!(DemoConfig { root, db }, redacted { token });
+
+    #[derive(Default)]
+    struct DemoBuilder {
+        config: DemoConfig,
+    }
+
+    impl_configurator!(DemoConfig => DemoBuilder);
+
+    impl Builder for DemoBuilder {
+        const SCHEME: Scheme = Scheme::Custom("demo");
+        type Config = DemoConfig;
+
+        fn build(self) -> Result<impl Access> {
+            Ok(())
+        }
+    }
+
+    builder_setters!(DemoBuilder {
+        root: &str,
+        token: &str,
+        db: i64,
+        ttl: Duration,
+    });
+
+    #[test]
+    fn test_builder_setters() {
+        let builder = DemoBuilder::default()
+            .root("/tmp")
+            .token("")
+            .db(1)
+            .ttl(Duration::from_secs(1));
+
+        assert_eq!(builder.config.root.as_deref(), Some("/tmp"));
+        assert_eq!(builder.config.token, None, "empty str should be ignored");
+        assert_eq!(builder.config.db, 1);
+        assert_eq!(builder.config.ttl, Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_config_debug_redacted() {
+        let builder = DemoConfig::default()
+            .into_builder()
+            .root("/tmp")
+            .token("secret");
+
+        let debug = format!("{builder:?}");
+        assert!(debug.contains("DemoBuilder"));
+        assert!(debug.contains("/tmp"));
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("secret"));
+    }
+}
@@ -0,0 +1,182 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::raw::*;
+use crate::*;
+
+/// SigningCredential is the credential used by services to sign requests.
+///
+/// Credentials like STS tokens or OAuth access tokens will expire after a
+/// while, services should return `false` in [`SigningCredential::is_valid`]
+/// with some buffer before the real expiration so that [`CredentialLoader`]
+/// could refresh it in time.
+pub trait SigningCredential: Clone + Send + Sync + 'static {
+    /// Check if this credential is still valid for signing.
+    fn is_valid(&self) -> bool;
+}
+
+/// ProvideCredential is used to load credential from a source like env,
+/// config file, instance metadata or a remote token service.
+///
+/// Implementations should always load a fresh credential instead of
+/// returning a cached one, the caching is handled by [`CredentialLoader`].
+///
+/// - If succeed, return `Ok(Some(cred))`
+/// - If not found, return `Ok(None)`
+/// - If unexpected errors happened, return `Err(err)`
+pub trait ProvideCredential: Debug + Send + Sync + 'static {
+    /// The credential that provided by this provider.
+    type Credential: SigningCredential;
+
+    /// Load a credential from this provider.
+    fn provide_credential(&self) -> BoxedFuture<'_, Result<Option<Self::Credential>>>;
+}
+
+/// CredentialLoader will cache the credential loaded from [`ProvideCredential`]
+/// and refresh it when it's expired.
+///
+/// Services could call [`CredentialLoader::expire`] while the server reports
+/// that the credential has been expired, so that the next request will load
+/// a new credential instead of failing with the same one.
+pub struct CredentialLoader<C: SigningCredential> {
+    provider: Arc<dyn ProvideCredential<Credential = C>>,
+    credential: Arc<Mutex<Option<C>>>,
+}
+
+impl<C: SigningCredential> Clone for CredentialLoader<C> {
+    fn clone(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            credential: self.credential.clone(),
+        }
+    }
+}
+
+impl<C: SigningCredential> Debug for CredentialLoader<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CredentialLoader")
+            .field("provider", &self.provider)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: SigningCredential> CredentialLoader<C> {
+    /// Create a new CredentialLoader via given provider.
+    pub fn new(provider: impl ProvideCredential<Credential = C>) -> Self {
+        Self::from_arc(Arc::new(provider))
+    }
+
+    /// Create a new CredentialLoader via given dyn provider.
+    pub fn from_arc(provider: Arc<dyn ProvideCredential<Credential = C>>) -> Self {
+        Self {
+            provider,
+            credential: Arc::default(),
+        }
+    }
+
+    /// Load credential.
+    ///
+    /// Return the cached credential if it's still valid, otherwise load a new
+    /// one from the provider.
+    pub async fn load(&self) -> Result<Option<C>> {
+        if let Some(cred) = self.credential.lock().expect("lock poisoned").as_ref() {
+            if cred.is_valid() {
+                return Ok(Some(cred.clone()));
+            }
+        }
+
+        let cred = self.provider.provide_credential().await?;
+
+        let mut lock = self.credential.lock().expect("lock poisoned");
+        lock.clone_from(&cred);
+
+        Ok(cred)
+    }
+
+    /// Expire the cached credential so that the next load will fetch a new one.
+    pub fn expire(&self) {
+        self.credential.lock().expect("lock poisoned").take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct TestCredential {
+        valid: bool,
+    }
+
+    impl SigningCredential for TestCredential {
+        fn is_valid(&self) -> bool {
+            self.valid
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct TestProvider {
+        valid: bool,
+        count: AtomicUsize,
+    }
+
+    impl ProvideCredential for TestProvider {
+        type Credential = TestCredential;
+
+        fn provide_credential(&self) -> BoxedFuture<'_, Result<Option<Self::Credential>>> {
+            Box::pin(async move {
+                self.count.fetch_add(1, Ordering::SeqCst);
+                Ok(Some(TestCredential { valid: self.valid }))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_credential_loader_cache() {
+        let provider = Arc::new(TestProvider {
+            valid: true,
+            ..Default::default()
+        });
+        let loader = CredentialLoader::from_arc(provider.clone());
+
+        loader.load().await.unwrap();
+        loader.load().await.unwrap();
+        assert_eq!(provider.count.load(Ordering::SeqCst), 1);
+
+        loader.expire();
+        loader.load().await.unwrap();
+        assert_eq!(provider.count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_credential_loader_refresh_expired() {
+        let provider = Arc::new(TestProvider::default());
+        let loader = CredentialLoader::from_arc(provider.clone());
+
+        loader.load().await.unwrap();
+        loader.load().await.unwrap();
+        assert_eq!(provider.count.load(Ordering::SeqCst), 2);
+    }
+}
@@ -92,6 +92,40 @@ impl<ONE: oio::Write, TWO: oio::Write> oio::Write for TwoWays<ONE, TWO> {
     }
 }
 
+impl<ONE: oio::BlockingWrite, TWO: oio::BlockingWrite> oio::BlockingWrite for TwoWays<ONE, TWO> {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        match self {
+            Self::One(v) => v.write(bs),
+            Self::Two(v) => v.write(bs),
+        }
+    }
+
+    fn close(&mut self) -> Result<()> {
+        match self {
+            Self::One(v) => v.close(),
+            Self::Two(v) => v.close(),
+        }
+    }
+}
+
+impl<ONE: oio::List, TWO: oio::List> oio::List for TwoWays<ONE, TWO> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        match self {
+            Self::One(v) => v.next().await,
+            Self::Two(v) => v.next().await,
+        }
+    }
+}
+
+impl<ONE: oio::BlockingList, TWO: oio::BlockingList> oio::BlockingList for TwoWays<ONE, TWO> {
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        match self {
+            Self::One(v) => v.next(),
+            Self::Two(v) => v.next(),
+        }
+    }
+}
+
 /// ThreeWays is used to implement traits that based on three ways.
 ///
 /// Users can wrap three different trait types together.
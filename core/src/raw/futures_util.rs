@@ -44,6 +44,14 @@ pub type BoxedStaticFuture<T> = futures::future::BoxFuture<'static, T>;
 #[cfg(target_arch = "wasm32")]
 pub type BoxedStaticFuture<T> = futures::future::LocalBoxFuture<'static, T>;
 
+/// BoxedStaticStream is the type alias of [`futures::stream::BoxStream`].
+///
+/// We will switch to [`futures::stream::LocalBoxStream`] on wasm32 target.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxedStaticStream<T> = futures::stream::BoxStream<'static, T>;
+#[cfg(target_arch = "wasm32")]
+pub type BoxedStaticStream<T> = futures::stream::LocalBoxStream<'static, T>;
+
 /// MaybeSend is a marker to determine whether a type is `Send` or not.
 /// We use this trait to wrap the `Send` requirement for wasm32 target.
 ///
@@ -16,6 +16,10 @@
 // under the License.
 
 use std::cmp::Ordering;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
 
 use futures::Stream;
 use futures::StreamExt;
@@ -74,26 +78,34 @@ impl HttpBody {
         }
     }
 
-    /// Check if the consumed data is equal to the expected content length.
+    /// Check the consumed data against the expected content length.
+    ///
+    /// Getting too much data is an error as soon as it happens, while getting too
+    /// little data is only an error after the stream reaches its end (`eof`), which
+    /// happens if the connection is closed in the middle of the body.
+    ///
+    /// Both errors are temporary so that the caller can retry them.
     #[inline]
-    fn check(&self) -> Result<()> {
+    fn check(&self, eof: bool) -> Result<()> {
         let Some(expect) = self.size else {
             return Ok(());
         };
 
         let actual = self.consumed;
         match actual.cmp(&expect) {
-            Ordering::Equal => Ok(()),
-            Ordering::Less => Err(Error::new(
+            Ordering::Less if eof => Err(Error::new(
                 ErrorKind::Unexpected,
                 format!("http response got too little data, expect: {expect}, actual: {actual}"),
             )
+            .with_operation("http_util::HttpBody::read")
             .set_temporary()),
             Ordering::Greater => Err(Error::new(
                 ErrorKind::Unexpected,
                 format!("http response got too much data, expect: {expect}, actual: {actual}"),
             )
+            .with_operation("http_util::HttpBody::read")
             .set_temporary()),
+            _ => Ok(()),
         }
     }
 
@@ -103,17 +115,73 @@ impl HttpBody {
     }
 }
 
-impl oio::Read for HttpBody {
-    async fn read(&mut self) -> Result<Buffer> {
-        match self.stream.next().await.transpose()? {
-            Some(buf) => {
+impl Stream for HttpBody {
+    type Item = Result<Buffer>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let res = match ready!(self.stream.poll_next_unpin(cx)) {
+            Some(Ok(buf)) => {
                 self.consumed += buf.len() as u64;
-                Ok(buf)
-            }
-            None => {
-                self.check()?;
-                Ok(Buffer::new())
+                self.check(false).map(|_| Some(buf))
             }
-        }
+            Some(Err(err)) => Err(err),
+            None => self.check(true).map(|_| None),
+        };
+        Poll::Ready(res.transpose())
+    }
+}
+
+impl oio::Read for HttpBody {
+    async fn read(&mut self) -> Result<Buffer> {
+        Ok(self.next().await.transpose()?.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+
+    fn new_body(chunks: Vec<&'static str>, size: Option<u64>) -> HttpBody {
+        HttpBody::new(
+            stream::iter(chunks.into_iter().map(|v| Ok(Buffer::from(v)))),
+            size,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_http_body_exact() {
+        let mut body = new_body(vec!["hello", "world"], Some(10));
+        let bs = body.to_buffer().await.expect("read must succeed");
+        assert_eq!(bs.to_bytes(), "helloworld");
+    }
+
+    #[tokio::test]
+    async fn test_http_body_unknown_size() {
+        let mut body = new_body(vec!["hello", "world"], None);
+        let bs = body.to_buffer().await.expect("read must succeed");
+        assert_eq!(bs.to_bytes(), "helloworld");
+    }
+
+    #[tokio::test]
+    async fn test_http_body_truncated() {
+        let mut body = new_body(vec!["hello"], Some(10));
+        assert_eq!(body.read().await.expect("read must succeed").len(), 5);
+
+        let err = body.read().await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(err.is_temporary());
+    }
+
+    #[tokio::test]
+    async fn test_http_body_too_long() {
+        let mut body = new_body(vec!["hello", "world"], Some(8));
+        assert_eq!(body.read().await.expect("read must succeed").len(), 5);
+
+        // The extra data should not be returned to the caller.
+        let err = body.read().await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(err.is_temporary());
     }
 }
@@ -21,6 +21,7 @@ use std::ops::Range;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
+use crate::raw::*;
 use crate::*;
 
 /// BytesContentRange is the content range of bytes.
@@ -108,6 +109,25 @@ impl BytesContentRange {
     pub fn to_header(&self) -> String {
         format!("bytes {self}")
     }
+
+    /// Build the content range returned by reading `range` from content of `size`.
+    ///
+    /// The range is clamped into the content, only size will be set if nothing
+    /// is left to read.
+    pub fn from_bytes_range(range: BytesRange, size: u64) -> Self {
+        let start = range.offset().min(size);
+        let end = match range.size() {
+            Some(n) => start.saturating_add(n).min(size),
+            None => size,
+        };
+
+        let bcr = BytesContentRange::default().with_size(size);
+        if start < end {
+            bcr.with_range(start, end - 1)
+        } else {
+            bcr
+        }
+    }
 }
 
 impl Display for BytesContentRange {
@@ -236,4 +256,42 @@ mod tests {
             .with_size(1024);
         assert_eq!(h.to_header(), "bytes 0-1023/1024");
     }
+
+    #[test]
+    fn test_bytes_content_range_from_bytes_range() {
+        let cases = vec![
+            (
+                "full range",
+                BytesRange::default(),
+                BytesContentRange::default()
+                    .with_range(0, 1023)
+                    .with_size(1024),
+            ),
+            (
+                "open-ended range",
+                BytesRange::from(1000..),
+                BytesContentRange::default()
+                    .with_range(1000, 1023)
+                    .with_size(1024),
+            ),
+            (
+                "range beyond size",
+                BytesRange::from(1000..2000),
+                BytesContentRange::default()
+                    .with_range(1000, 1023)
+                    .with_size(1024),
+            ),
+            (
+                "range after end",
+                BytesRange::from(2000..),
+                BytesContentRange::default().with_size(1024),
+            ),
+        ];
+
+        for (name, input, expected) in cases {
+            let actual = BytesContentRange::from_bytes_range(input, 1024);
+
+            assert_eq!(expected, actual, "{name}")
+        }
+    }
 }
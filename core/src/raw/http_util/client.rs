@@ -17,24 +17,54 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+#[cfg(feature = "reqwest")]
 use std::future;
+use std::future::Future;
+#[cfg(feature = "reqwest")]
 use std::mem;
+use std::ops::Deref;
+#[cfg(feature = "reqwest")]
 use std::str::FromStr;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
+use bytes::BufMut;
+#[cfg(feature = "reqwest")]
 use futures::TryStreamExt;
 use http::Request;
 use http::Response;
+use raw::oio;
 use raw::oio::Read;
+use raw::BoxedFuture;
+use raw::MaybeSend;
+use raw::OpContext;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
 
+use super::insert_op_context_headers;
+use super::observe::ObservedStream;
+#[cfg(feature = "reqwest")]
 use super::parse_content_encoding;
 use super::parse_content_length;
 use super::HttpBody;
+use super::HttpObserve;
+use super::HttpPhase;
 use crate::*;
 
 /// HttpClient that used across opendal.
+///
+/// HttpClient sends requests via a [`HttpFetch`], which is [`reqwest::Client`] by
+/// default. Users who don't want to depend on reqwest, for example, on embedded
+/// targets or with their own TLS stack, can build opendal without the `reqwest`
+/// feature and provide their own transport via [`HttpClient::with_fetcher`].
 #[derive(Clone)]
 pub struct HttpClient {
-    client: reqwest::Client,
+    fetcher: HttpFetcher,
+    #[cfg(feature = "reqwest")]
+    client: Option<reqwest::Client>,
+    buffer_pool: Option<Arc<oio::PooledBuf>>,
+    observer: Option<Arc<dyn HttpObserve>>,
 }
 
 /// We don't want users to know details about our clients.
@@ -46,38 +76,194 @@ impl Debug for HttpClient {
 
 impl HttpClient {
     /// Create a new http client in async context.
+    #[cfg(feature = "reqwest")]
     pub fn new() -> Result<Self> {
         Self::build(reqwest::ClientBuilder::new())
     }
 
+    /// Create a new http client in async context.
+    ///
+    /// opendal is built without the `reqwest` feature, so there is no default
+    /// transport. Please provide one via [`HttpClient::with_fetcher`].
+    #[cfg(not(feature = "reqwest"))]
+    pub fn new() -> Result<Self> {
+        Err(Error::new(
+            ErrorKind::ConfigInvalid,
+            "no default http client available without reqwest, please set one via HttpClient::with_fetcher",
+        ))
+    }
+
     /// Construct `Self` with given [`reqwest::Client`]
+    #[cfg(feature = "reqwest")]
     pub fn with(client: reqwest::Client) -> Self {
-        Self { client }
+        Self {
+            fetcher: Arc::new(client.clone()),
+            client: Some(client),
+            buffer_pool: None,
+            observer: None,
+        }
+    }
+
+    /// Construct `Self` with given [`HttpFetch`] as the transport.
+    ///
+    /// Credential loaders of services like s3 and gcs send requests via
+    /// [`reqwest::Client`] directly instead of this transport, so building them
+    /// with this client returns [`ErrorKind::ConfigInvalid`].
+    pub fn with_fetcher(fetcher: impl HttpFetch) -> Self {
+        Self {
+            fetcher: Arc::new(fetcher),
+            #[cfg(feature = "reqwest")]
+            client: None,
+            buffer_pool: None,
+            observer: None,
+        }
     }
 
     /// Build a new http client in async context.
+    #[cfg(feature = "reqwest")]
     pub fn build(builder: reqwest::ClientBuilder) -> Result<Self> {
-        Ok(Self {
-            client: builder.build().map_err(|err| {
-                Error::new(ErrorKind::Unexpected, "http client build failed").set_source(err)
-            })?,
-        })
+        let client = builder.build().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "http client build failed").set_source(err)
+        })?;
+        Ok(Self::with(client))
+    }
+
+    /// Build a new http client with given observer in async context.
+    ///
+    /// Besides the phases reported by [`HttpClient::with_observer`], the client
+    /// resolves host names by itself so that [`HttpPhase::Dns`] can be observed
+    /// too. The `dns_resolver` set on the `builder` will be overridden.
+    #[cfg(feature = "reqwest")]
+    pub fn build_with_observer(
+        builder: reqwest::ClientBuilder,
+        observer: Arc<dyn HttpObserve>,
+    ) -> Result<Self> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let builder = builder.dns_resolver(Arc::new(super::observe::ObservedResolver {
+            observer: observer.clone(),
+        }));
+
+        Ok(Self::build(builder)?.with_observer(observer))
+    }
+
+    /// Set the buffer pool used by [`HttpClient::send`].
+    ///
+    /// By default, the response returned by `send` holds the chunks allocated by the
+    /// underlying http client. With a buffer pool, the chunks will be collected into a
+    /// contiguous buffer from the pool instead. The memory will be reused by the following
+    /// responses once the previous response has been dropped, which reduces allocator
+    /// pressure in high QPS services.
+    ///
+    /// The same pool can be shared by multiple clients.
+    pub fn with_buffer_pool(mut self, pool: Arc<oio::PooledBuf>) -> Self {
+        self.buffer_pool = Some(pool);
+        self
+    }
+
+    /// Set the observer that receives the timing of every request sent by this client.
+    ///
+    /// [`HttpPhase::Ttfb`] and [`HttpPhase::Transfer`] will be observed, which helps
+    /// to tell the network latency apart from the service latency. Use
+    /// [`HttpClient::build_with_observer`] to observe [`HttpPhase::Dns`] as well.
+    pub fn with_observer(mut self, observer: Arc<dyn HttpObserve>) -> Self {
+        self.observer = Some(observer);
+        self
     }
 
     /// Get the async client from http client.
-    pub fn client(&self) -> reqwest::Client {
-        self.client.clone()
+    ///
+    /// Returns an error if this client is built with a custom [`HttpFetch`], since
+    /// there is no [`reqwest::Client`] that would send requests via it.
+    #[cfg(feature = "reqwest")]
+    pub fn client(&self) -> Result<reqwest::Client> {
+        self.client.clone().ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "http client with custom fetcher doesn't have a reqwest client to load credentials",
+            )
+        })
     }
 
     /// Send a request in async way.
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
         let (parts, mut body) = self.fetch(req).await?.into_parts();
-        let buffer = body.read_all().await?;
+        let buffer = match &self.buffer_pool {
+            Some(pool) => {
+                let size = parse_content_length(&parts.headers)?.unwrap_or_default();
+                read_all_pooled(&mut body, pool, size as usize).await?
+            }
+            None => body.read_all().await?,
+        };
         Ok(Response::from_parts(parts, buffer))
     }
 
     /// Fetch a request in async way.
-    pub async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+    ///
+    /// The headers carried by the [`OpContext`] of the running operation will be
+    /// inserted into the request.
+    pub async fn fetch(&self, mut req: Request<Buffer>) -> Result<Response<HttpBody>> {
+        insert_op_context_headers(req.headers_mut(), &OpContext::current())?;
+
+        let Some(observer) = &self.observer else {
+            return self.fetcher.fetch(req).await;
+        };
+
+        let host = req.uri().host().unwrap_or_default().to_string();
+        let start = Instant::now();
+        let resp = self.fetcher.fetch(req).await;
+        observer.observe(&host, HttpPhase::Ttfb, start.elapsed());
+
+        // The size has been checked by the inner body already.
+        let (parts, body) = resp?.into_parts();
+        let body = HttpBody::new(ObservedStream::new(body, observer.clone(), host), None);
+        Ok(Response::from_parts(parts, body))
+    }
+}
+
+/// HttpFetch is the trait to fetch a request in async way.
+///
+/// Users can implement this trait to provide their own transport for [`HttpClient`].
+///
+/// # Behavior
+///
+/// - The response body should be returned as a stream without being buffered.
+/// - The uri of the request should be inserted into the extensions of response.
+/// - Errors that are worth retrying, like connection reset, should be marked as temporary.
+pub trait HttpFetch: Send + Sync + Unpin + 'static {
+    /// Fetch a request in async way.
+    fn fetch(
+        &self,
+        req: Request<Buffer>,
+    ) -> impl Future<Output = Result<Response<HttpBody>>> + MaybeSend;
+}
+
+/// HttpFetchDyn is the dyn version of [`HttpFetch`] which makes it possible to
+/// use as `Arc<dyn HttpFetchDyn>`.
+///
+/// Users should never implement this trait, but use [`HttpFetch`] instead.
+pub trait HttpFetchDyn: Send + Sync + Unpin + 'static {
+    /// The dyn version of [`HttpFetch::fetch`].
+    fn fetch_dyn(&self, req: Request<Buffer>) -> BoxedFuture<'_, Result<Response<HttpBody>>>;
+}
+
+impl<T: HttpFetch + ?Sized> HttpFetchDyn for T {
+    fn fetch_dyn(&self, req: Request<Buffer>) -> BoxedFuture<'_, Result<Response<HttpBody>>> {
+        Box::pin(self.fetch(req))
+    }
+}
+
+impl<T: HttpFetchDyn + ?Sized> HttpFetch for Arc<T> {
+    async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+        self.deref().fetch_dyn(req).await
+    }
+}
+
+/// HttpFetcher is a type erased [`HttpFetch`].
+pub type HttpFetcher = Arc<dyn HttpFetchDyn>;
+
+#[cfg(feature = "reqwest")]
+impl HttpFetch for reqwest::Client {
+    async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
         // Uri stores all string alike data in `Bytes` which means
         // the clone here is cheap.
         let uri = req.uri().clone();
@@ -86,7 +272,6 @@ impl HttpClient {
         let (parts, body) = req.into_parts();
 
         let mut req_builder = self
-            .client
             .request(
                 parts.method,
                 reqwest::Url::from_str(&uri.to_string()).expect("input request url must be valid"),
@@ -144,25 +329,49 @@ impl HttpClient {
         // Swap headers directly instead of copy the entire map.
         mem::swap(hr.headers_mut().unwrap(), resp.headers_mut());
 
-        let bs = HttpBody::new(
-            resp.bytes_stream()
-                .try_filter(|v| future::ready(!v.is_empty()))
-                .map_ok(Buffer::from)
-                .map_err(move |err| {
-                    Error::new(ErrorKind::Unexpected, "read data from http response")
-                        .with_operation("http_util::Client::send")
-                        .with_context("url", uri.to_string())
-                        .with_temporary(is_temporary_error(&err))
-                        .set_source(err)
-                }),
-            content_length,
-        );
-
-        let resp = hr.body(bs).expect("response must build succeed");
+        let stream = resp
+            .bytes_stream()
+            .try_filter(|v| future::ready(!v.is_empty()))
+            .map_ok(Buffer::from)
+            .map_err(move |err| {
+                Error::new(ErrorKind::Unexpected, "read data from http response")
+                    .with_operation("http_util::Client::send")
+                    .with_context("url", uri.to_string())
+                    .with_temporary(is_temporary_error(&err))
+                    .set_source(err)
+            });
+
+        let resp = hr
+            .body(HttpBody::new(stream, content_length))
+            .expect("response must build succeed");
         Ok(resp)
     }
 }
 
+/// Read all data from the body into a contiguous buffer from the pool.
+async fn read_all_pooled(
+    body: &mut HttpBody,
+    pool: &oio::PooledBuf,
+    size: usize,
+) -> Result<Buffer> {
+    let mut buf = pool.get();
+    buf.reserve(size);
+    loop {
+        let bs = body.read().await?;
+        if bs.is_empty() {
+            break;
+        }
+        buf.put(bs);
+    }
+
+    // Split the filled part out and put the remaining back to the pool. The memory
+    // will be reclaimed by the next `reserve` after the returned buffer is dropped.
+    let bs = buf.split().freeze();
+    pool.put(buf);
+    Ok(Buffer::from(bs))
+}
+
+#[cfg(feature = "reqwest")]
 #[inline]
 fn is_temporary_error(err: &reqwest::Error) -> bool {
     // error sending request
@@ -172,3 +381,67 @@ fn is_temporary_error(err: &reqwest::Error) -> bool {
     // error decoding response body, for example, connection reset.
     err.is_decode()
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+    use crate::raw::X_REQUEST_ID;
+
+    /// MockFetcher echoes the headers and body of request back.
+    struct MockFetcher;
+
+    impl HttpFetch for MockFetcher {
+        async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+            let (parts, body) = req.into_parts();
+            let body = HttpBody::new(stream::iter(vec![Ok(body)]), None);
+            let mut resp = Response::builder()
+                .status(http::StatusCode::OK)
+                .body(body)
+                .expect("response must build succeed");
+            *resp.headers_mut() = parts.headers;
+            Ok(resp)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_client_with_fetcher() {
+        let client = HttpClient::with_fetcher(MockFetcher);
+        let req = Request::put("https://example.com/path")
+            .body(Buffer::from("hello"))
+            .expect("request must build succeed");
+
+        let resp = client.send(req).await.expect("send must succeed");
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(resp.into_body().to_bytes(), "hello");
+    }
+
+    #[tokio::test]
+    async fn test_http_client_send_op_context_headers() {
+        let client = HttpClient::with_fetcher(MockFetcher);
+        let new_req = || {
+            Request::get("https://example.com/path")
+                .body(Buffer::new())
+                .expect("request must build succeed")
+        };
+
+        let resp = client.send(new_req()).await.expect("send must succeed");
+        assert!(resp.headers().get(X_REQUEST_ID).is_none());
+
+        let ctx = OpContext::new().with_request_id("req-1");
+        let resp = ctx
+            .scope(client.send(new_req()))
+            .await
+            .expect("send must succeed");
+        assert_eq!(resp.headers()[X_REQUEST_ID], "req-1");
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_http_client_with_fetcher_has_no_reqwest_client() {
+        let client = HttpClient::with_fetcher(MockFetcher);
+        let err = client.client().expect_err("client must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
@@ -18,6 +18,8 @@
 use http::response::Parts;
 use http::Uri;
 
+use super::parse_request_id;
+use super::parse_retry_after;
 use crate::Error;
 use crate::ErrorKind;
 
@@ -52,11 +54,22 @@ pub fn new_request_sign_error(err: anyhow::Error) -> Error {
 ///
 /// - remove sensitive or useless headers from parts.
 /// - fetch uri if parts extensions contains `Uri`.
+/// - fetch request id if services returned it.
+/// - record http status and `Retry-After` into error.
 pub fn with_error_response_context(mut err: Error, mut parts: Parts) -> Error {
     if let Some(uri) = parts.extensions.get::<Uri>() {
         err = err.with_context("uri", uri.to_string());
     }
 
+    err = err.with_http_status(parts.status);
+    if let Some(retry_after) = parse_retry_after(&parts.headers) {
+        err = err.with_retry_after(retry_after);
+    }
+
+    if let Some(request_id) = parse_request_id(&parts.headers) {
+        err = err.with_context("request_id", request_id);
+    }
+
     // The following headers may contains sensitive information.
     parts.headers.remove("Set-Cookie");
     parts.headers.remove("WWW-Authenticate");
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::time::Duration;
+
 use base64::engine::general_purpose;
 use base64::Engine;
 use chrono::DateTime;
@@ -28,6 +30,8 @@ use http::header::CONTENT_TYPE;
 use http::header::ETAG;
 use http::header::LAST_MODIFIED;
 use http::header::LOCATION;
+use http::header::RETRY_AFTER;
+use http::header::USER_AGENT;
 use http::HeaderMap;
 use http::HeaderName;
 use http::HeaderValue;
@@ -139,6 +143,53 @@ where
     })?))
 }
 
+/// Parse the `Retry-After` header into a duration.
+///
+/// Both delay seconds and http date are supported, http date in the past
+/// will be treated as zero.
+pub fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let v = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(secs) = v.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = DateTime::parse_from_rfc2822(v).ok()?.with_timezone(&Utc);
+    Some((date - Utc::now()).to_std().unwrap_or_default())
+}
+
+/// The headers that services use to return their request id.
+///
+/// - `x-amz-request-id`: s3 and s3 compatible services.
+/// - `x-ms-request-id`: azure services.
+/// - `x-guploader-uploadid`: gcs.
+const REQUEST_ID_HEADERS: &[&str] = &[
+    "x-amz-request-id",
+    "x-ms-request-id",
+    "x-guploader-uploadid",
+];
+
+/// Parse the request id returned by services from header map.
+pub fn parse_request_id(headers: &HeaderMap) -> Option<&str> {
+    REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|name| headers.get(*name).and_then(|v| v.to_str().ok()))
+}
+
+/// Parse the headers of a read response into [`RpRead`].
+///
+/// `Content-Range` will be used if returned (`206 Partial Content`), otherwise
+/// the whole content has been returned and `Content-Length` is its size.
+pub fn parse_into_rp_read(headers: &HeaderMap) -> Result<RpRead> {
+    let length = parse_content_length(headers)?;
+    let range = match parse_content_range(headers)? {
+        Some(range) => Some(range),
+        None => length.map(|size| BytesContentRange::from_bytes_range(BytesRange::default(), size)),
+    };
+
+    Ok(RpRead::new().with_size(length).with_range(range))
+}
+
 /// parse_into_metadata will parse standards http headers into Metadata.
 ///
 /// # Notes
@@ -186,6 +237,10 @@ pub fn parse_into_metadata(path: &str, headers: &HeaderMap) -> Result<Metadata>
         m.set_content_disposition(v);
     }
 
+    if let Some(v) = parse_request_id(headers) {
+        m.set_request_id(v);
+    }
+
     Ok(m)
 }
 
@@ -243,10 +298,122 @@ pub fn build_header_value(v: &str) -> Result<HeaderValue> {
     })
 }
 
+/// The header that carries the request id of [`OpContext`].
+pub const X_REQUEST_ID: &str = "x-request-id";
+
+/// Insert the headers carried by [`OpContext`] into request.
+///
+/// - `request_id` will be sent as `x-request-id`.
+/// - `user_agent` will override the `User-Agent`.
+///
+/// Tags are only visible to layers and won't be sent.
+///
+/// [`HttpClient`] calls this for every request with the context of the running
+/// operation, services don't need to call it by themselves.
+pub fn insert_op_context_headers(headers: &mut HeaderMap, ctx: &OpContext) -> Result<()> {
+    if let Some(request_id) = ctx.request_id() {
+        headers.insert(X_REQUEST_ID, build_header_value(request_id)?);
+    }
+    if let Some(user_agent) = ctx.user_agent() {
+        headers.insert(USER_AGENT, build_header_value(user_agent)?);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(120)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(parse_retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_parse_into_rp_read() -> Result<()> {
+        let mut headers = HeaderMap::new();
+        let rp = parse_into_rp_read(&headers)?;
+        assert_eq!(rp.size(), None);
+        assert_eq!(rp.range(), None);
+
+        headers.insert(CONTENT_LENGTH, HeaderValue::from_static("1024"));
+        let rp = parse_into_rp_read(&headers)?;
+        assert_eq!(rp.size(), Some(1024));
+        assert_eq!(
+            rp.range(),
+            Some(
+                BytesContentRange::default()
+                    .with_range(0, 1023)
+                    .with_size(1024)
+            )
+        );
+
+        headers.insert(CONTENT_LENGTH, HeaderValue::from_static("24"));
+        headers.insert(
+            CONTENT_RANGE,
+            HeaderValue::from_static("bytes 1000-1023/1024"),
+        );
+        let rp = parse_into_rp_read(&headers)?;
+        assert_eq!(rp.size(), Some(24));
+        assert_eq!(
+            rp.range(),
+            Some(
+                BytesContentRange::default()
+                    .with_range(1000, 1023)
+                    .with_size(1024)
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_request_id() {
+        let cases = vec![
+            ("x-amz-request-id", "4442587FB7D0A2F9"),
+            ("x-ms-request-id", "9b5e1b3a-e01e-0042-3f4d-7a2a1e000000"),
+            ("x-guploader-uploadid", "ADPycdtQ2zGsyPZ9F1aC"),
+        ];
+
+        for (name, value) in cases {
+            let mut headers = HeaderMap::new();
+            headers.insert(name, HeaderValue::from_static(value));
+            assert_eq!(parse_request_id(&headers), Some(value), "{name}");
+        }
+
+        assert_eq!(parse_request_id(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_insert_op_context_headers() {
+        let mut headers = HeaderMap::new();
+        insert_op_context_headers(&mut headers, &OpContext::new()).unwrap();
+        assert!(headers.is_empty());
+
+        let ctx = OpContext::new()
+            .with_request_id("req-1")
+            .with_user_agent("my-app/1.0")
+            .with_tag("tenant", "alice");
+        headers.insert(USER_AGENT, HeaderValue::from_static("opendal"));
+        insert_op_context_headers(&mut headers, &ctx).unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers[X_REQUEST_ID], "req-1");
+        assert_eq!(headers[USER_AGENT], "my-app/1.0");
+    }
+
     /// Test cases is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html
     #[test]
     fn test_format_content_md5() {
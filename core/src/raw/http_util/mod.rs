@@ -24,15 +24,23 @@
 
 mod client;
 pub use client::HttpClient;
+pub use client::HttpFetch;
+pub use client::HttpFetchDyn;
+pub use client::HttpFetcher;
 
 mod body;
 pub use body::HttpBody;
 
+mod observe;
+pub use observe::HttpObserve;
+pub use observe::HttpPhase;
+
 mod header;
 pub use header::build_header_value;
 pub use header::format_authorization_by_basic;
 pub use header::format_authorization_by_bearer;
 pub use header::format_content_md5;
+pub use header::insert_op_context_headers;
 pub use header::parse_content_disposition;
 pub use header::parse_content_encoding;
 pub use header::parse_content_length;
@@ -42,8 +50,12 @@ pub use header::parse_content_type;
 pub use header::parse_etag;
 pub use header::parse_header_to_str;
 pub use header::parse_into_metadata;
+pub use header::parse_into_rp_read;
 pub use header::parse_last_modified;
 pub use header::parse_location;
+pub use header::parse_request_id;
+pub use header::parse_retry_after;
+pub use header::X_REQUEST_ID;
 
 mod uri;
 pub use uri::percent_decode_path;
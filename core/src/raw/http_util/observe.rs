@@ -0,0 +1,178 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use futures::Stream;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::*;
+
+/// HttpPhase is a phase in the lifecycle of a http request.
+///
+/// `reqwest` doesn't expose the time spent on establishing connections, so the
+/// connect time (including tls handshake) of a newly created connection is
+/// counted in [`HttpPhase::Ttfb`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HttpPhase {
+    /// Resolving the host name into addresses.
+    ///
+    /// Only observed for clients built by [`HttpClient::build_with_observer`].
+    Dns,
+    /// From sending the request to receiving the response headers.
+    Ttfb,
+    /// From receiving the response headers to consuming the whole body.
+    Transfer,
+}
+
+impl HttpPhase {
+    /// Convert self into static str.
+    pub fn into_static(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl From<HttpPhase> for &'static str {
+    fn from(v: HttpPhase) -> &'static str {
+        match v {
+            HttpPhase::Dns => "dns",
+            HttpPhase::Ttfb => "ttfb",
+            HttpPhase::Transfer => "transfer",
+        }
+    }
+}
+
+/// HttpObserve observes the duration of every [`HttpPhase`] of the requests sent
+/// by [`HttpClient`].
+///
+/// `observe` is called inside the future that sends the request or reads the
+/// response, so implementations can attach the timing to the current span.
+/// It's called on the hot path and should return quickly.
+pub trait HttpObserve: Send + Sync + 'static {
+    /// Observe that `phase` of a request to `host` took `duration`.
+    fn observe(&self, host: &str, phase: HttpPhase, duration: Duration);
+}
+
+/// ObservedStream reports [`HttpPhase::Transfer`] once the body has been
+/// consumed or failed.
+pub(super) struct ObservedStream<S> {
+    inner: S,
+    observer: Option<Arc<dyn HttpObserve>>,
+    host: String,
+    start: Instant,
+}
+
+impl<S> ObservedStream<S> {
+    pub(super) fn new(inner: S, observer: Arc<dyn HttpObserve>, host: String) -> Self {
+        Self {
+            inner,
+            observer: Some(observer),
+            host,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl<S> Stream for ObservedStream<S>
+where
+    S: Stream<Item = Result<Buffer>> + Unpin,
+{
+    type Item = Result<Buffer>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let item = ready!(Pin::new(&mut self.inner).poll_next(cx));
+        if matches!(item, None | Some(Err(_))) {
+            if let Some(observer) = self.observer.take() {
+                observer.observe(&self.host, HttpPhase::Transfer, self.start.elapsed());
+            }
+        }
+        Poll::Ready(item)
+    }
+}
+
+/// ObservedResolver resolves host names with the system resolver and reports
+/// [`HttpPhase::Dns`].
+#[cfg(all(not(target_arch = "wasm32"), feature = "reqwest"))]
+pub(super) struct ObservedResolver {
+    pub(super) observer: Arc<dyn HttpObserve>,
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "reqwest"))]
+impl reqwest::dns::Resolve for ObservedResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let observer = self.observer.clone();
+        Box::pin(async move {
+            let host = name.as_str();
+            let start = Instant::now();
+            // The port will be replaced by reqwest, use 0 here.
+            let addrs = tokio::net::lookup_host(format!("{host}:0")).await;
+            observer.observe(host, HttpPhase::Dns, start.elapsed());
+
+            let addrs: reqwest::dns::Addrs = Box::new(addrs?);
+            Ok(addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures::stream;
+    use futures::StreamExt;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockObserver {
+        phases: Mutex<Vec<(String, HttpPhase)>>,
+    }
+
+    impl HttpObserve for MockObserver {
+        fn observe(&self, host: &str, phase: HttpPhase, _: Duration) {
+            self.phases.lock().unwrap().push((host.to_string(), phase));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observed_stream() {
+        let observer = Arc::new(MockObserver::default());
+        let inner = stream::iter(vec![Ok(Buffer::from("hello")), Ok(Buffer::from("world"))]);
+        let mut s = ObservedStream::new(inner, observer.clone(), "example.com".to_string());
+
+        assert!(s.next().await.is_some());
+        assert!(observer.phases.lock().unwrap().is_empty());
+        assert!(s.next().await.is_some());
+        assert!(s.next().await.is_none());
+        assert!(s.next().await.is_none());
+
+        // Transfer should only be observed once.
+        assert_eq!(
+            *observer.phases.lock().unwrap(),
+            vec![("example.com".to_string(), HttpPhase::Transfer)]
+        );
+    }
+}
@@ -204,6 +204,137 @@ pub trait LayeredAccess: Send + Sync + Debug + Unpin + 'static {
         self.inner().batch(args)
     }
 
+    fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> impl Future<Output = Result<RpListMultipartUploads>> + MaybeSend {
+        self.inner().list_multipart_uploads(path, args)
+    }
+
+    fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> impl Future<Output = Result<RpAbortMultipartUpload>> + MaybeSend {
+        self.inner().abort_multipart_upload(path, args)
+    }
+
+    fn stage_block(
+        &self,
+        path: &str,
+        args: OpStageBlock,
+    ) -> impl Future<Output = Result<RpStageBlock>> + MaybeSend {
+        self.inner().stage_block(path, args)
+    }
+
+    fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> impl Future<Output = Result<RpCommitBlockList>> + MaybeSend {
+        self.inner().commit_block_list(path, args)
+    }
+
+    fn write_at(
+        &self,
+        path: &str,
+        args: OpWriteAt,
+    ) -> impl Future<Output = Result<RpWriteAt>> + MaybeSend {
+        self.inner().write_at(path, args)
+    }
+
+    fn allocate(
+        &self,
+        path: &str,
+        args: OpAllocate,
+    ) -> impl Future<Output = Result<RpAllocate>> + MaybeSend {
+        self.inner().allocate(path, args)
+    }
+
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        self.inner().concat(path, args)
+    }
+
+    fn locate(
+        &self,
+        path: &str,
+        args: OpLocate,
+    ) -> impl Future<Output = Result<RpLocate>> + MaybeSend {
+        self.inner().locate(path, args)
+    }
+
+    fn get_lifecycle(
+        &self,
+        args: OpGetLifecycle,
+    ) -> impl Future<Output = Result<RpGetLifecycle>> + MaybeSend {
+        self.inner().get_lifecycle(args)
+    }
+
+    fn set_lifecycle(
+        &self,
+        args: OpSetLifecycle,
+    ) -> impl Future<Output = Result<RpSetLifecycle>> + MaybeSend {
+        self.inner().set_lifecycle(args)
+    }
+
+    fn get_retention(
+        &self,
+        path: &str,
+        args: OpGetRetention,
+    ) -> impl Future<Output = Result<RpGetRetention>> + MaybeSend {
+        self.inner().get_retention(path, args)
+    }
+
+    fn set_retention(
+        &self,
+        path: &str,
+        args: OpSetRetention,
+    ) -> impl Future<Output = Result<RpSetRetention>> + MaybeSend {
+        self.inner().set_retention(path, args)
+    }
+
+    fn get_acl(
+        &self,
+        path: &str,
+        args: OpGetAcl,
+    ) -> impl Future<Output = Result<RpGetAcl>> + MaybeSend {
+        self.inner().get_acl(path, args)
+    }
+
+    fn set_acl(
+        &self,
+        path: &str,
+        args: OpSetAcl,
+    ) -> impl Future<Output = Result<RpSetAcl>> + MaybeSend {
+        self.inner().set_acl(path, args)
+    }
+
+    fn create_bucket(
+        &self,
+        args: OpCreateBucket,
+    ) -> impl Future<Output = Result<RpCreateBucket>> + MaybeSend {
+        self.inner().create_bucket(args)
+    }
+
+    fn delete_bucket(
+        &self,
+        args: OpDeleteBucket,
+    ) -> impl Future<Output = Result<RpDeleteBucket>> + MaybeSend {
+        self.inner().delete_bucket(args)
+    }
+
+    fn list_buckets(
+        &self,
+        args: OpListBuckets,
+    ) -> impl Future<Output = Result<RpListBuckets>> + MaybeSend {
+        self.inner().list_buckets(args)
+    }
+
     fn presign(
         &self,
         path: &str,
@@ -287,6 +418,86 @@ impl<L: LayeredAccess> Access for L {
         (self as &L).batch(args).await
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        (self as &L).list_multipart_uploads(path, args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        (self as &L).abort_multipart_upload(path, args).await
+    }
+
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        (self as &L).stage_block(path, args).await
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        (self as &L).commit_block_list(path, args).await
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        (self as &L).write_at(path, args).await
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        (self as &L).allocate(path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        (self as &L).concat(path, args).await
+    }
+
+    async fn locate(&self, path: &str, args: OpLocate) -> Result<RpLocate> {
+        (self as &L).locate(path, args).await
+    }
+
+    async fn get_lifecycle(&self, args: OpGetLifecycle) -> Result<RpGetLifecycle> {
+        (self as &L).get_lifecycle(args).await
+    }
+
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        (self as &L).set_lifecycle(args).await
+    }
+
+    async fn get_retention(&self, path: &str, args: OpGetRetention) -> Result<RpGetRetention> {
+        (self as &L).get_retention(path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        (self as &L).set_retention(path, args).await
+    }
+
+    async fn get_acl(&self, path: &str, args: OpGetAcl) -> Result<RpGetAcl> {
+        (self as &L).get_acl(path, args).await
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        (self as &L).set_acl(path, args).await
+    }
+
+    async fn create_bucket(&self, args: OpCreateBucket) -> Result<RpCreateBucket> {
+        (self as &L).create_bucket(args).await
+    }
+
+    async fn delete_bucket(&self, args: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        (self as &L).delete_bucket(args).await
+    }
+
+    async fn list_buckets(&self, args: OpListBuckets) -> Result<RpListBuckets> {
+        (self as &L).list_buckets(args).await
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         (self as &L).presign(path, args).await
     }
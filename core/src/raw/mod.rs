@@ -25,6 +25,8 @@
 //!   API should never expose raw API directly.
 //! - Raw APIs are far less stable than public API, please don't rely on
 //!   them whenever possible.
+//! - Items in [`stable`] follow semver like public API, external layers and
+//!   services should prefer them.
 
 mod accessor;
 pub use accessor::*;
@@ -58,6 +60,12 @@ pub use http_util::*;
 mod serde_util;
 pub use serde_util::*;
 
+mod config_util;
+pub use crate::builder_setters;
+pub use crate::impl_config_debug;
+pub use crate::impl_configurator;
+pub use config_util::IntoConfigValue;
+
 mod chrono_util;
 pub use chrono_util::*;
 
@@ -72,6 +80,7 @@ pub use std_io_util::*;
 mod futures_util;
 pub use futures_util::BoxedFuture;
 pub use futures_util::BoxedStaticFuture;
+pub use futures_util::BoxedStaticStream;
 pub use futures_util::ConcurrentFutures;
 pub use futures_util::ConcurrentTasks;
 pub use futures_util::MaybeSend;
@@ -82,8 +91,12 @@ pub use enum_utils::*;
 mod atomic_util;
 pub use atomic_util::*;
 
+mod credential;
+pub use credential::*;
+
 // Expose as a pub mod to avoid confusing.
 pub mod adapters;
 pub mod oio;
+pub mod stable;
 #[cfg(feature = "tests")]
 pub mod tests;
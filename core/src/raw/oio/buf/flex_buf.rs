@@ -15,14 +15,23 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::mem;
+use std::sync::Arc;
+
 use bytes::Buf;
 use bytes::BufMut;
 use bytes::Bytes;
 use bytes::BytesMut;
 
+use super::PooledBuf;
+
 /// FlexBuf is a buffer that support frozen bytes and reuse existing allocated memory.
 ///
 /// It's useful when we want to freeze the buffer and reuse the memory for the next buffer.
+///
+/// FlexBuf can be created from a [`PooledBuf`] by [`FlexBuf::with_pool`], the buffer will
+/// be returned back to the pool while dropping so that the allocated memory can be
+/// reused by the next FlexBuf.
 pub struct FlexBuf {
     /// Already allocated memory size of `buf`.
     cap: usize,
@@ -30,6 +39,7 @@ pub struct FlexBuf {
     len: usize,
     buf: BytesMut,
     frozen: Option<Bytes>,
+    pool: Option<Arc<PooledBuf>>,
 }
 
 impl FlexBuf {
@@ -41,6 +51,22 @@ impl FlexBuf {
 
             buf: BytesMut::with_capacity(cap),
             frozen: None,
+            pool: None,
+        }
+    }
+
+    /// Initializes a new `FlexBuf` with the given capacity from the pool.
+    pub fn with_pool(cap: usize, pool: Arc<PooledBuf>) -> Self {
+        let mut buf = pool.get();
+        buf.reserve(cap);
+
+        FlexBuf {
+            cap,
+            len: 0,
+
+            buf,
+            frozen: None,
+            pool: Some(pool),
         }
     }
 
@@ -116,3 +142,35 @@ impl FlexBuf {
         self.buf.reserve(self.cap);
     }
 }
+
+impl Drop for FlexBuf {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.take() {
+            pool.put(mem::take(&mut self.buf));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flex_buf_with_pool() {
+        let pool = Arc::new(PooledBuf::new(1));
+
+        let mut buf = FlexBuf::with_pool(4, pool.clone());
+        assert_eq!(buf.put(b"hello"), 4);
+        assert_eq!(buf.get().unwrap().as_ref(), b"hell");
+        buf.advance(4);
+        drop(buf);
+
+        // The allocated memory should be reused.
+        let buf = FlexBuf::with_pool(4, pool.clone());
+        assert!(buf.buf.capacity() >= 4);
+        let ptr = buf.buf.as_ptr();
+        drop(buf);
+        let buf = FlexBuf::with_pool(4, pool);
+        assert_eq!(buf.buf.as_ptr(), ptr);
+    }
+}
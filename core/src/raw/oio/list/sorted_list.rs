@@ -0,0 +1,350 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs;
+use std::io;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::vec;
+
+use crate::raw::*;
+use crate::*;
+
+/// The default number of entries kept in memory before spilling to disk.
+const DEFAULT_SPILL_THRESHOLD: usize = 100_000;
+
+/// SortedLister is used to return entries in lexicographical order of their
+/// paths for services that can't guarantee it.
+///
+/// All entries will be fetched from the inner lister at the first `next` call.
+/// Once there are more than `spill_threshold` entries in memory, they will be
+/// sorted and spilled into a run file under [`std::env::temp_dir`], and all
+/// runs will be merged while returning entries.
+///
+/// # Notes
+///
+/// - Entries spilled to disk only keep their path and mode, other metadata
+///   will be fetched again by [`Lister`][crate::Lister] if required by `metakey`.
+/// - Spill files are written via blocking IO, so spilling is only enabled for
+///   [`oio::BlockingList`]. [`oio::List`] will keep all entries in memory to
+///   avoid blocking the async runtime.
+/// - Spill files are removed when the lister is dropped.
+/// - Spilling is disabled on `wasm32`, all entries will be kept in memory.
+pub struct SortedLister<L> {
+    lister: Option<L>,
+    spill_threshold: usize,
+
+    buffer: Vec<oio::Entry>,
+    runs: Vec<PathBuf>,
+    state: State,
+}
+
+enum State {
+    Idle,
+    Memory(vec::IntoIter<oio::Entry>),
+    Merge {
+        readers: Vec<BufReader<fs::File>>,
+        heap: BinaryHeap<Reverse<(String, usize, u8)>>,
+    },
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for SortedLister.
+unsafe impl<L> Sync for SortedLister<L> {}
+
+impl<L> SortedLister<L> {
+    /// Create a new sorted lister.
+    pub fn new(lister: L) -> SortedLister<L> {
+        SortedLister {
+            lister: Some(lister),
+            spill_threshold: DEFAULT_SPILL_THRESHOLD,
+
+            buffer: Vec::new(),
+            runs: Vec::new(),
+            state: State::Idle,
+        }
+    }
+
+    /// Set the number of entries kept in memory before spilling to disk.
+    pub fn with_spill_threshold(mut self, threshold: usize) -> Self {
+        self.spill_threshold = threshold.max(1);
+        self
+    }
+
+    /// Buffer the entry, spill the buffer to disk if `spill` is allowed and
+    /// the threshold has been reached.
+    fn push(&mut self, entry: oio::Entry, spill: bool) -> Result<()> {
+        self.buffer.push(entry);
+        if spill && cfg!(not(target_arch = "wasm32")) && self.buffer.len() >= self.spill_threshold {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// Sort the buffered entries and write them into a new run file.
+    fn spill(&mut self) -> Result<()> {
+        self.buffer.sort_unstable_by(|a, b| a.path().cmp(b.path()));
+
+        let path =
+            std::env::temp_dir().join(format!("opendal-sorted-list-{}", uuid::Uuid::new_v4()));
+        // Record the run before writing so that it will be removed even if failed.
+        self.runs.push(path.clone());
+
+        let f = fs::File::create(&path).map_err(new_std_io_error)?;
+        let mut w = BufWriter::new(f);
+        for entry in self.buffer.drain(..) {
+            write_entry(&mut w, &entry).map_err(new_std_io_error)?;
+        }
+        w.flush().map_err(new_std_io_error)?;
+        Ok(())
+    }
+
+    /// All entries have been fetched, prepare the state to return them in order.
+    fn finish(&mut self) -> Result<()> {
+        if self.runs.is_empty() {
+            let mut buffer = std::mem::take(&mut self.buffer);
+            buffer.sort_unstable_by(|a, b| a.path().cmp(b.path()));
+            self.state = State::Memory(buffer.into_iter());
+            return Ok(());
+        }
+
+        if !self.buffer.is_empty() {
+            self.spill()?;
+        }
+
+        let mut readers = Vec::with_capacity(self.runs.len());
+        let mut heap = BinaryHeap::with_capacity(self.runs.len());
+        for (idx, path) in self.runs.iter().enumerate() {
+            let f = fs::File::open(path).map_err(new_std_io_error)?;
+            let mut r = BufReader::new(f);
+            if let Some((path, mode)) = read_entry(&mut r).map_err(new_std_io_error)? {
+                heap.push(Reverse((path, idx, mode)));
+            }
+            readers.push(r);
+        }
+        self.state = State::Merge { readers, heap };
+        Ok(())
+    }
+
+    fn next_sorted(&mut self) -> Result<Option<oio::Entry>> {
+        match &mut self.state {
+            State::Idle => Err(Error::new(
+                ErrorKind::Unexpected,
+                "sorted lister has failed while fetching entries",
+            )),
+            State::Memory(iter) => Ok(iter.next()),
+            State::Merge { readers, heap } => {
+                let Some(Reverse((path, idx, mode))) = heap.pop() else {
+                    return Ok(None);
+                };
+                if let Some((path, mode)) =
+                    read_entry(&mut readers[idx]).map_err(new_std_io_error)?
+                {
+                    heap.push(Reverse((path, idx, mode)));
+                }
+
+                let mode = match mode {
+                    MODE_FILE => EntryMode::FILE,
+                    MODE_DIR => EntryMode::DIR,
+                    _ => EntryMode::Unknown,
+                };
+                Ok(Some(oio::Entry::with(path, Metadata::new(mode))))
+            }
+        }
+    }
+}
+
+impl<L> Drop for SortedLister<L> {
+    fn drop(&mut self) {
+        for path in &self.runs {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+const MODE_FILE: u8 = 0;
+const MODE_DIR: u8 = 1;
+const MODE_UNKNOWN: u8 = 2;
+
+/// Write entry into run file in `[path_len: u32][path][mode: u8]`.
+fn write_entry(w: &mut impl Write, entry: &oio::Entry) -> io::Result<()> {
+    let path = entry.path().as_bytes();
+    let mode = match entry.mode() {
+        EntryMode::FILE => MODE_FILE,
+        EntryMode::DIR => MODE_DIR,
+        EntryMode::Unknown => MODE_UNKNOWN,
+    };
+
+    w.write_all(&(path.len() as u32).to_le_bytes())?;
+    w.write_all(path)?;
+    w.write_all(&[mode])
+}
+
+/// Read entry from run file, returns `None` if the run is exhausted.
+fn read_entry(r: &mut impl Read) -> io::Result<Option<(String, u8)>> {
+    let mut len = [0u8; 4];
+    match r.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+
+    let mut path = vec![0u8; u32::from_le_bytes(len) as usize];
+    r.read_exact(&mut path)?;
+    let path =
+        String::from_utf8(path).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    let mut mode = [0u8; 1];
+    r.read_exact(&mut mode)?;
+    Ok(Some((path, mode[0])))
+}
+
+impl<L> oio::List for SortedLister<L>
+where
+    L: oio::List,
+{
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        if let Some(mut lister) = self.lister.take() {
+            while let Some(entry) = lister.next().await? {
+                self.push(entry, false)?;
+            }
+            self.finish()?;
+        }
+
+        self.next_sorted()
+    }
+}
+
+impl<L> oio::BlockingList for SortedLister<L>
+where
+    L: oio::BlockingList,
+{
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        if let Some(mut lister) = self.lister.take() {
+            while let Some(entry) = lister.next()? {
+                self.push(entry, true)?;
+            }
+            self.finish()?;
+        }
+
+        self.next_sorted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockLister {
+        entries: vec::IntoIter<oio::Entry>,
+    }
+
+    impl MockLister {
+        fn new(paths: &[&str]) -> Self {
+            let entries: Vec<_> = paths
+                .iter()
+                .map(|p| {
+                    let mode = if p.ends_with('/') {
+                        EntryMode::DIR
+                    } else {
+                        EntryMode::FILE
+                    };
+                    oio::Entry::new(p, Metadata::new(mode))
+                })
+                .collect();
+            Self {
+                entries: entries.into_iter(),
+            }
+        }
+    }
+
+    impl oio::List for MockLister {
+        async fn next(&mut self) -> Result<Option<oio::Entry>> {
+            Ok(self.entries.next())
+        }
+    }
+
+    impl oio::BlockingList for MockLister {
+        fn next(&mut self) -> Result<Option<oio::Entry>> {
+            Ok(self.entries.next())
+        }
+    }
+
+    fn collect(mut lister: SortedLister<MockLister>) -> Vec<(String, EntryMode)> {
+        let mut entries = Vec::new();
+        while let Some(e) = oio::BlockingList::next(&mut lister).unwrap() {
+            entries.push((e.path().to_string(), e.mode()));
+        }
+        entries
+    }
+
+    #[test]
+    fn test_sorted_list() {
+        let paths = ["b", "a/", "c", "a/b", "aa"];
+
+        let lister = SortedLister::new(MockLister::new(&paths));
+        let entries = collect(lister);
+        assert_eq!(
+            entries.iter().map(|(p, _)| p.as_str()).collect::<Vec<_>>(),
+            vec!["a/", "a/b", "aa", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn test_sorted_list_spill() {
+        let paths: Vec<String> = (0..100).rev().map(|i| format!("dir/{i:03}")).collect();
+        let mut paths: Vec<&str> = paths.iter().map(|p| p.as_str()).collect();
+        paths.push("dir/");
+
+        let lister = SortedLister::new(MockLister::new(&paths)).with_spill_threshold(7);
+        let entries = collect(lister);
+
+        assert_eq!(entries.len(), 101);
+        assert_eq!(entries[0], ("dir/".to_string(), EntryMode::DIR));
+        for (i, (path, mode)) in entries[1..].iter().enumerate() {
+            assert_eq!(path, &format!("dir/{i:03}"));
+            assert_eq!(*mode, EntryMode::FILE);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sorted_list_async_no_spill() {
+        let paths: Vec<String> = (0..100).rev().map(|i| format!("dir/{i:03}")).collect();
+        let paths: Vec<&str> = paths.iter().map(|p| p.as_str()).collect();
+
+        let mut lister = SortedLister::new(MockLister::new(&paths)).with_spill_threshold(7);
+        let mut entries = Vec::new();
+        while let Some(e) = oio::List::next(&mut lister).await.unwrap() {
+            entries.push(e.path().to_string());
+        }
+
+        assert!(
+            lister.runs.is_empty(),
+            "async lister must not spill to disk"
+        );
+        assert_eq!(entries.len(), 100);
+        for (i, path) in entries.iter().enumerate() {
+            assert_eq!(path, &format!("dir/{i:03}"));
+        }
+    }
+}
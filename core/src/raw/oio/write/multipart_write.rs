@@ -416,6 +416,10 @@ mod tests {
             let time = thread_rng().gen_range(0..100);
             Some(Box::pin(tokio::time::sleep(Duration::from_nanos(time))))
         }
+
+        fn sleep(&self, dur: Duration) -> BoxedStaticFuture<()> {
+            self.exec.sleep(dur)
+        }
     }
 
     #[tokio::test]
@@ -55,6 +55,40 @@ pub enum Operation {
     Batch,
     /// Operation for [`crate::raw::Access::presign`]
     Presign,
+    /// Operation for [`crate::raw::Access::list_multipart_uploads`]
+    ListMultipartUploads,
+    /// Operation for [`crate::raw::Access::abort_multipart_upload`]
+    AbortMultipartUpload,
+    /// Operation for [`crate::raw::Access::stage_block`]
+    StageBlock,
+    /// Operation for [`crate::raw::Access::commit_block_list`]
+    CommitBlockList,
+    /// Operation for [`crate::raw::Access::write_at`]
+    WriteAt,
+    /// Operation for [`crate::raw::Access::allocate`]
+    Allocate,
+    /// Operation for [`crate::raw::Access::concat`]
+    Concat,
+    /// Operation for [`crate::raw::Access::locate`]
+    Locate,
+    /// Operation for [`crate::raw::Access::get_lifecycle`]
+    GetLifecycle,
+    /// Operation for [`crate::raw::Access::set_lifecycle`]
+    SetLifecycle,
+    /// Operation for [`crate::raw::Access::get_retention`]
+    GetRetention,
+    /// Operation for [`crate::raw::Access::set_retention`]
+    SetRetention,
+    /// Operation for [`crate::raw::Access::get_acl`]
+    GetAcl,
+    /// Operation for [`crate::raw::Access::set_acl`]
+    SetAcl,
+    /// Operation for [`crate::raw::Access::create_bucket`]
+    CreateBucket,
+    /// Operation for [`crate::raw::Access::delete_bucket`]
+    DeleteBucket,
+    /// Operation for [`crate::raw::Access::list_buckets`]
+    ListBuckets,
     /// Operation for [`crate::raw::Access::blocking_create_dir`]
     BlockingCreateDir,
     /// Operation for [`crate::raw::Access::blocking_read`]
@@ -130,6 +164,23 @@ impl From<Operation> for &'static str {
             Operation::ListerNext => "List::next",
             Operation::Presign => "presign",
             Operation::Batch => "batch",
+            Operation::ListMultipartUploads => "list_multipart_uploads",
+            Operation::AbortMultipartUpload => "abort_multipart_upload",
+            Operation::StageBlock => "stage_block",
+            Operation::CommitBlockList => "commit_block_list",
+            Operation::WriteAt => "write_at",
+            Operation::Allocate => "allocate",
+            Operation::Concat => "concat",
+            Operation::Locate => "locate",
+            Operation::GetLifecycle => "get_lifecycle",
+            Operation::SetLifecycle => "set_lifecycle",
+            Operation::GetRetention => "get_retention",
+            Operation::SetRetention => "set_retention",
+            Operation::GetAcl => "get_acl",
+            Operation::SetAcl => "set_acl",
+            Operation::CreateBucket => "create_bucket",
+            Operation::DeleteBucket => "delete_bucket",
+            Operation::ListBuckets => "list_buckets",
             Operation::BlockingCreateDir => "blocking_create_dir",
             Operation::BlockingRead => "blocking_read",
             Operation::BlockingReaderRead => "BlockingReader::read",
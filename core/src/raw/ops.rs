@@ -19,25 +19,220 @@
 //!
 //! By using ops, users can add more context for operation.
 
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::future::poll_fn;
+use std::future::Future;
+use std::path::Path;
+use std::path::PathBuf;
+use std::pin::pin;
+use std::sync::Arc;
 use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
 use flagset::FlagSet;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
 
 use crate::raw::*;
 use crate::*;
 
+/// Context carried by operations.
+///
+/// OpContext is supplied by callers and passed through all layers untouched, so that
+/// layers like logging can correlate their output with callers' requests.
+///
+/// The request id and user agent will be sent to the server side by [`HttpClient`]
+/// for all requests of the operation, including the requests sent by its readers,
+/// writers and listers.
+///
+/// The deadline and cancellation token in context will be checked by every call of
+/// the operation, including the calls of returned readers, writers and listers.
+///
+/// OpContext is cheap to clone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpContext {
+    inner: Option<Arc<OpContextInner>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct OpContextInner {
+    request_id: Option<String>,
+    user_agent: Option<String>,
+    tags: HashMap<String, String>,
+    deadline: Option<Instant>,
+    cancellation: Option<CancellationToken>,
+    executor: Option<Executor>,
+}
+
+impl OpContext {
+    /// Create a new empty `OpContext`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn inner_mut(&mut self) -> &mut OpContextInner {
+        Arc::make_mut(self.inner.get_or_insert_with(Default::default))
+    }
+
+    /// Set the request id of this context.
+    ///
+    /// It's visible to all layers, and sent as `x-request-id` by http services.
+    pub fn with_request_id(mut self, request_id: &str) -> Self {
+        self.inner_mut().request_id = Some(request_id.to_string());
+        self
+    }
+
+    /// Get the request id of this context.
+    pub fn request_id(&self) -> Option<&str> {
+        self.inner.as_ref()?.request_id.as_deref()
+    }
+
+    /// Set the user agent of this context.
+    ///
+    /// Http services will use it to override their `User-Agent` header.
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.inner_mut().user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Get the user agent of this context.
+    pub fn user_agent(&self) -> Option<&str> {
+        self.inner.as_ref()?.user_agent.as_deref()
+    }
+
+    /// Add a custom tag like tenant into this context.
+    pub fn with_tag(mut self, key: &str, value: &str) -> Self {
+        self.inner_mut()
+            .tags
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Get the tag of given key.
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.inner.as_ref()?.tags.get(key).map(|v| v.as_str())
+    }
+
+    /// Iterate all tags of this context.
+    pub fn tags(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.inner
+            .iter()
+            .flat_map(|v| v.tags.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+    }
+
+    /// Set the deadline of this context.
+    ///
+    /// Operations will fail once the deadline exceeded, in-flight requests will be
+    /// dropped.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.inner_mut().deadline = Some(deadline);
+        self
+    }
+
+    /// Get the deadline of this context.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.inner.as_ref()?.deadline
+    }
+
+    /// Set the cancellation token of this context.
+    ///
+    /// Operations will fail once the token cancelled, in-flight requests will be
+    /// dropped.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.inner_mut().cancellation = Some(token);
+        self
+    }
+
+    /// Get the cancellation token of this context.
+    pub fn cancellation(&self) -> Option<&CancellationToken> {
+        self.inner.as_ref()?.cancellation.as_ref()
+    }
+
+    /// Set the executor used to track the deadline.
+    ///
+    /// [`Operator`] will set it to the default executor.
+    pub(crate) fn with_executor(mut self, executor: Executor) -> Self {
+        self.inner_mut().executor = Some(executor);
+        self
+    }
+
+    /// Get the executor used to track the deadline.
+    pub(crate) fn executor(&self) -> Option<&Executor> {
+        self.inner.as_ref()?.executor.as_ref()
+    }
+
+    /// Check if this context is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_none()
+    }
+
+    /// Get the context of the operation running on current thread.
+    ///
+    /// Returns an empty context if no operation is running.
+    pub(crate) fn current() -> OpContext {
+        CURRENT_OP_CONTEXT.with(|v| v.borrow().clone())
+    }
+
+    /// Call `f` with this context as the current context.
+    pub(crate) fn enter<T>(&self, f: impl FnOnce() -> T) -> T {
+        struct Guard(Option<OpContext>);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                if let Some(ctx) = self.0.take() {
+                    CURRENT_OP_CONTEXT.with(|v| *v.borrow_mut() = ctx);
+                }
+            }
+        }
+
+        let prev = CURRENT_OP_CONTEXT.with(|v| v.replace(self.clone()));
+        let _guard = Guard(Some(prev));
+        f()
+    }
+
+    /// Poll `fut` with this context as the current context.
+    pub(crate) async fn scope<F: Future>(self, fut: F) -> F::Output {
+        let mut fut = pin!(fut);
+        poll_fn(|cx| self.enter(|| fut.as_mut().poll(cx))).await
+    }
+}
+
+thread_local! {
+    /// The context of the operation that is running on current thread.
+    ///
+    /// It's set by [`OpContext::enter`] and [`OpContext::scope`] so that the http client
+    /// can send it without every service passing it through.
+    static CURRENT_OP_CONTEXT: RefCell<OpContext> = RefCell::new(OpContext::default());
+}
+
 /// Args for `create` operation.
 ///
 /// The path must be normalized.
 #[derive(Debug, Clone, Default)]
-pub struct OpCreateDir {}
+pub struct OpCreateDir {
+    context: OpContext,
+}
 
 impl OpCreateDir {
     /// Create a new `OpCreateDir`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
 }
 
 /// Args for `delete` operation.
@@ -46,6 +241,8 @@ impl OpCreateDir {
 #[derive(Debug, Clone, Default)]
 pub struct OpDelete {
     version: Option<String>,
+    if_generation_match: Option<u64>,
+    context: OpContext,
 }
 
 impl OpDelete {
@@ -53,6 +250,17 @@ impl OpDelete {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
 }
 
 impl OpDelete {
@@ -66,6 +274,159 @@ impl OpDelete {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set the if generation match of this delete operation.
+    ///
+    /// The delete will only succeed if the generation of existing object matches.
+    pub fn with_if_generation_match(mut self, generation: u64) -> Self {
+        self.if_generation_match = Some(generation);
+        self
+    }
+
+    /// Get the if generation match of this delete operation.
+    pub fn if_generation_match(&self) -> Option<u64> {
+        self.if_generation_match
+    }
+}
+
+/// Args for deleter operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpDeleter {
+    /// The concurrent delete tasks that deleter could run.
+    concurrent: usize,
+    /// The max paths that will be sent in one batch delete request.
+    batch_size: Option<usize>,
+    /// The context that carried by the operations.
+    context: OpContext,
+}
+
+impl OpDeleter {
+    /// Create a new `OpDeleter`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the concurrent of the option
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent;
+        self
+    }
+
+    /// Get concurrent from option
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Set the batch size of the option
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Get batch size from option
+    pub fn batch_size(&self) -> Option<usize> {
+        self.batch_size
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// The callback to report the progress of `remove_all` operation.
+pub type RemoveAllProgressFn = Arc<dyn Fn(&RemoveAllReport) + Send + Sync>;
+
+/// Args for `remove_all` operation.
+#[derive(Clone, Default)]
+pub struct OpRemoveAll {
+    /// The concurrent delete tasks that remove_all could run.
+    concurrent: usize,
+    /// The max paths that will be sent in one batch delete request.
+    batch_size: Option<usize>,
+    /// The callback that will be called after every path has been handled.
+    progress: Option<RemoveAllProgressFn>,
+    /// The context that carried by the operations.
+    context: OpContext,
+}
+
+impl Debug for OpRemoveAll {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpRemoveAll")
+            .field("concurrent", &self.concurrent)
+            .field("batch_size", &self.batch_size)
+            .field("progress", &self.progress.is_some())
+            .field("context", &self.context)
+            .finish()
+    }
+}
+
+impl OpRemoveAll {
+    /// Create a new `OpRemoveAll`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the concurrent of the option
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent;
+        self
+    }
+
+    /// Get concurrent from option
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Set the batch size of the option
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Get batch size from option
+    pub fn batch_size(&self) -> Option<usize> {
+        self.batch_size
+    }
+
+    /// Set the progress callback of the option
+    pub fn with_progress(mut self, progress: RemoveAllProgressFn) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Get progress callback from option
+    pub fn progress(&self) -> Option<&RemoveAllProgressFn> {
+        self.progress.as_ref()
+    }
+
+    /// Build the [`OpDeleter`] used by this `remove_all`.
+    pub fn deleter(&self) -> OpDeleter {
+        let op = OpDeleter::new()
+            .with_concurrent(self.concurrent)
+            .with_context(self.context.clone());
+        match self.batch_size {
+            Some(size) => op.with_batch_size(size),
+            None => op,
+        }
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
 }
 
 /// Args for `list` operation.
@@ -100,6 +461,12 @@ pub struct OpList {
     /// - If this is set to > 1, the list operation will be concurrent,
     ///   and the maximum number of concurrent operations will be determined by this value.
     concurrent: usize,
+    /// The sorted is used to control whether entries must be returned in
+    /// lexicographical order of their paths.
+    ///
+    /// Default to `false`.
+    sorted: bool,
+    context: OpContext,
 }
 
 impl Default for OpList {
@@ -111,6 +478,8 @@ impl Default for OpList {
             // By default, we want to know what's the mode of this entry.
             metakey: Metakey::Mode.into(),
             concurrent: 1,
+            sorted: false,
+            context: OpContext::default(),
         }
     }
 }
@@ -172,105 +541,564 @@ impl OpList {
         self.metakey
     }
 
-    /// Change the concurrent of this list operation.
-    ///
-    /// The default concurrent is 1.
-    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
-        self.concurrent = concurrent;
-        self
+    /// Change the concurrent of this list operation.
+    ///
+    /// The default concurrent is 1.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent;
+        self
+    }
+
+    /// Get the concurrent of list operation.
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Change the sorted of this list operation.
+    ///
+    /// Entries will be sorted client-side if services can't guarantee it.
+    pub fn with_sorted(mut self, sorted: bool) -> Self {
+        self.sorted = sorted;
+        self
+    }
+
+    /// Get the sorted of list operation.
+    pub fn sorted(&self) -> bool {
+        self.sorted
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// Args for `presign` operation.
+///
+/// The path must be normalized.
+#[derive(Debug, Clone)]
+pub struct OpPresign {
+    expire: Duration,
+
+    op: PresignOperation,
+}
+
+impl OpPresign {
+    /// Create a new `OpPresign`.
+    pub fn new(op: impl Into<PresignOperation>, expire: Duration) -> Self {
+        Self {
+            op: op.into(),
+            expire,
+        }
+    }
+
+    /// Get operation from op.
+    pub fn operation(&self) -> &PresignOperation {
+        &self.op
+    }
+
+    /// Get expire from op.
+    pub fn expire(&self) -> Duration {
+        self.expire
+    }
+
+    /// Consume OpPresign into (Duration, PresignOperation)
+    pub fn into_parts(self) -> (Duration, PresignOperation) {
+        (self.expire, self.op)
+    }
+}
+
+/// Presign operation used for presign.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum PresignOperation {
+    /// Presign a stat(head) operation.
+    Stat(OpStat),
+    /// Presign a read operation.
+    Read(OpRead),
+    /// Presign a write operation.
+    Write(OpWrite),
+}
+
+impl From<OpStat> for PresignOperation {
+    fn from(op: OpStat) -> Self {
+        Self::Stat(op)
+    }
+}
+
+impl From<OpRead> for PresignOperation {
+    fn from(v: OpRead) -> Self {
+        Self::Read(v)
+    }
+}
+
+impl From<OpWrite> for PresignOperation {
+    fn from(v: OpWrite) -> Self {
+        Self::Write(v)
+    }
+}
+
+/// Args for `batch` operation.
+#[derive(Debug, Clone)]
+pub struct OpBatch {
+    ops: Vec<(String, BatchOperation)>,
+}
+
+impl OpBatch {
+    /// Create a new batch options.
+    pub fn new(ops: Vec<(String, BatchOperation)>) -> Self {
+        Self { ops }
+    }
+
+    /// Get operation from op.
+    pub fn operation(&self) -> &[(String, BatchOperation)] {
+        &self.ops
+    }
+
+    /// Consume OpBatch into BatchOperation
+    pub fn into_operation(self) -> Vec<(String, BatchOperation)> {
+        self.ops
+    }
+}
+
+/// Args for `list_multipart_uploads` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpListMultipartUploads {
+    context: OpContext,
+}
+
+impl OpListMultipartUploads {
+    /// Create a new `OpListMultipartUploads`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// Args for `abort_multipart_upload` operation.
+#[derive(Debug, Clone)]
+pub struct OpAbortMultipartUpload {
+    upload_id: String,
+    context: OpContext,
+}
+
+impl OpAbortMultipartUpload {
+    /// Create a new `OpAbortMultipartUpload` for the given upload id.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            context: OpContext::default(),
+        }
+    }
+
+    /// Get the upload id of this abort.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// Args for `stage_block` operation.
+#[derive(Debug, Clone)]
+pub struct OpStageBlock {
+    block_id: String,
+    body: Buffer,
+    context: OpContext,
+}
+
+impl OpStageBlock {
+    /// Create a new `OpStageBlock` to stage the body as given block id.
+    pub fn new(block_id: &str, body: Buffer) -> Self {
+        Self {
+            block_id: block_id.to_string(),
+            body,
+            context: OpContext::default(),
+        }
+    }
+
+    /// Get the block id of this block.
+    pub fn block_id(&self) -> &str {
+        &self.block_id
+    }
+
+    /// Get the body of this block.
+    pub fn body(&self) -> &Buffer {
+        &self.body
+    }
+
+    /// Consume the option to get the body of this block.
+    pub fn into_body(self) -> Buffer {
+        self.body
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// Args for `commit_block_list` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpCommitBlockList {
+    block_ids: Vec<String>,
+    write: OpWrite,
+}
+
+impl OpCommitBlockList {
+    /// Create a new `OpCommitBlockList` to commit given block ids in order.
+    pub fn new(block_ids: Vec<String>) -> Self {
+        Self {
+            block_ids,
+            write: OpWrite::default(),
+        }
+    }
+
+    /// Get the block ids to commit in order.
+    pub fn block_ids(&self) -> &[String] {
+        &self.block_ids
+    }
+
+    /// Set the write options like content type of the committed file.
+    pub fn with_write(mut self, write: OpWrite) -> Self {
+        self.write = write;
+        self
+    }
+
+    /// Get the write options of the committed file.
+    pub fn write(&self) -> &OpWrite {
+        &self.write
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        self.write.context()
+    }
+}
+
+/// Args for `concat` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpConcat {
+    sources: Vec<String>,
+    if_generation_match: Option<u64>,
+    context: OpContext,
+}
+
+impl OpConcat {
+    /// Create a new `OpConcat` with given sources.
+    pub fn new(sources: Vec<String>) -> Self {
+        Self {
+            sources,
+            ..Default::default()
+        }
+    }
+
+    /// Set the sources to concat in order.
+    pub fn with_sources(mut self, sources: Vec<String>) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Get the sources to concat in order.
+    pub fn sources(&self) -> &[String] {
+        &self.sources
+    }
+
+    /// Set the if generation match of the target.
+    ///
+    /// The concat will only succeed if the generation of existing target matches.
+    pub fn with_if_generation_match(mut self, generation: u64) -> Self {
+        self.if_generation_match = Some(generation);
+        self
+    }
+
+    /// Get the if generation match of the target.
+    pub fn if_generation_match(&self) -> Option<u64> {
+        self.if_generation_match
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// Args for `locate` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpLocate {
+    range: BytesRange,
+}
+
+impl OpLocate {
+    /// Create a new `OpLocate`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the range of the file to locate.
+    pub fn with_range(mut self, range: BytesRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the range of the file to locate.
+    pub fn range(&self) -> BytesRange {
+        self.range
+    }
+}
+
+/// Args for `write_at` operation.
+#[derive(Debug, Clone)]
+pub struct OpWriteAt {
+    offset: u64,
+    body: Buffer,
+    context: OpContext,
+}
+
+impl OpWriteAt {
+    /// Create a new `OpWriteAt` to write the body at given offset.
+    pub fn new(offset: u64, body: Buffer) -> Self {
+        Self {
+            offset,
+            body,
+            context: OpContext::default(),
+        }
+    }
+
+    /// Get the offset to write at.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Get the body to write.
+    pub fn body(&self) -> &Buffer {
+        &self.body
+    }
+
+    /// Consume the option to get the body to write.
+    pub fn into_body(self) -> Buffer {
+        self.body
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// Args for `allocate` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpAllocate {
+    size: u64,
+    context: OpContext,
+}
+
+impl OpAllocate {
+    /// Create a new `OpAllocate` to allocate a file of given size.
+    pub fn new(size: u64) -> Self {
+        Self {
+            size,
+            context: OpContext::default(),
+        }
+    }
+
+    /// Get the size of the file to allocate.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// Args for `get_lifecycle` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetLifecycle {}
+
+impl OpGetLifecycle {
+    /// Create a new `OpGetLifecycle`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_lifecycle` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetLifecycle {
+    rules: Vec<LifecycleRule>,
+}
+
+impl OpSetLifecycle {
+    /// Create a new `OpSetLifecycle`.
+    pub fn new(rules: Vec<LifecycleRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Get the rules to set.
+    pub fn rules(&self) -> &[LifecycleRule] {
+        &self.rules
     }
+}
 
-    /// Get the concurrent of list operation.
-    pub fn concurrent(&self) -> usize {
-        self.concurrent
+/// Args for `get_retention` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetRetention {}
+
+impl OpGetRetention {
+    /// Create a new `OpGetRetention`.
+    pub fn new() -> Self {
+        Self::default()
     }
 }
 
-/// Args for `presign` operation.
+/// Args for `set_retention` operation.
 ///
-/// The path must be normalized.
-#[derive(Debug, Clone)]
-pub struct OpPresign {
-    expire: Duration,
-
-    op: PresignOperation,
+/// Fields that are not set will be kept as is.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetRetention {
+    retention: Option<Retention>,
+    legal_hold: Option<bool>,
 }
 
-impl OpPresign {
-    /// Create a new `OpPresign`.
-    pub fn new(op: impl Into<PresignOperation>, expire: Duration) -> Self {
-        Self {
-            op: op.into(),
-            expire,
-        }
+impl OpSetRetention {
+    /// Create a new `OpSetRetention`.
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Get operation from op.
-    pub fn operation(&self) -> &PresignOperation {
-        &self.op
+    /// Set the retention of the object.
+    pub fn with_retention(mut self, retention: Retention) -> Self {
+        self.retention = Some(retention);
+        self
     }
 
-    /// Get expire from op.
-    pub fn expire(&self) -> Duration {
-        self.expire
+    /// Get the retention to set.
+    pub fn retention(&self) -> Option<Retention> {
+        self.retention
     }
 
-    /// Consume OpPresign into (Duration, PresignOperation)
-    pub fn into_parts(self) -> (Duration, PresignOperation) {
-        (self.expire, self.op)
+    /// Set whether the object is under legal hold.
+    pub fn with_legal_hold(mut self, legal_hold: bool) -> Self {
+        self.legal_hold = Some(legal_hold);
+        self
+    }
+
+    /// Get the legal hold to set.
+    pub fn legal_hold(&self) -> Option<bool> {
+        self.legal_hold
     }
 }
 
-/// Presign operation used for presign.
+/// Args for `get_acl` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetAcl {}
+
+impl OpGetAcl {
+    /// Create a new `OpGetAcl`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_acl` operation.
 #[derive(Debug, Clone)]
-#[non_exhaustive]
-pub enum PresignOperation {
-    /// Presign a stat(head) operation.
-    Stat(OpStat),
-    /// Presign a read operation.
-    Read(OpRead),
-    /// Presign a write operation.
-    Write(OpWrite),
+pub struct OpSetAcl {
+    acl: Acl,
 }
 
-impl From<OpStat> for PresignOperation {
-    fn from(op: OpStat) -> Self {
-        Self::Stat(op)
+impl OpSetAcl {
+    /// Create a new `OpSetAcl`.
+    pub fn new(acl: Acl) -> Self {
+        Self { acl }
     }
-}
 
-impl From<OpRead> for PresignOperation {
-    fn from(v: OpRead) -> Self {
-        Self::Read(v)
+    /// Get the acl to set.
+    pub fn acl(&self) -> Acl {
+        self.acl
     }
 }
 
-impl From<OpWrite> for PresignOperation {
-    fn from(v: OpWrite) -> Self {
-        Self::Write(v)
+/// Args for `create_bucket` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpCreateBucket {}
+
+impl OpCreateBucket {
+    /// Create a new `OpCreateBucket`.
+    pub fn new() -> Self {
+        Self::default()
     }
 }
 
-/// Args for `batch` operation.
-#[derive(Debug, Clone)]
-pub struct OpBatch {
-    ops: Vec<(String, BatchOperation)>,
-}
+/// Args for `delete_bucket` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpDeleteBucket {}
 
-impl OpBatch {
-    /// Create a new batch options.
-    pub fn new(ops: Vec<(String, BatchOperation)>) -> Self {
-        Self { ops }
+impl OpDeleteBucket {
+    /// Create a new `OpDeleteBucket`.
+    pub fn new() -> Self {
+        Self::default()
     }
+}
 
-    /// Get operation from op.
-    pub fn operation(&self) -> &[(String, BatchOperation)] {
-        &self.ops
-    }
+/// Args for `list_buckets` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpListBuckets {}
 
-    /// Consume OpBatch into BatchOperation
-    pub fn into_operation(self) -> Vec<(String, BatchOperation)> {
-        self.ops
+impl OpListBuckets {
+    /// Create a new `OpListBuckets`.
+    pub fn new() -> Self {
+        Self::default()
     }
 }
 
@@ -280,6 +1108,8 @@ impl OpBatch {
 pub enum BatchOperation {
     /// Batch delete operation.
     Delete(OpDelete),
+    /// Batch stat operation.
+    Stat(OpStat),
 }
 
 impl From<OpDelete> for BatchOperation {
@@ -288,12 +1118,19 @@ impl From<OpDelete> for BatchOperation {
     }
 }
 
+impl From<OpStat> for BatchOperation {
+    fn from(op: OpStat) -> Self {
+        Self::Stat(op)
+    }
+}
+
 impl BatchOperation {
     /// Return the operation of this batch.
     pub fn operation(&self) -> Operation {
         use BatchOperation::*;
         match self {
             Delete(_) => Operation::Delete,
+            Stat(_) => Operation::Stat,
         }
     }
 }
@@ -308,7 +1145,9 @@ pub struct OpRead {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    if_generation_match: Option<u64>,
     executor: Option<Executor>,
+    context: OpContext,
 }
 
 impl OpRead {
@@ -400,6 +1239,19 @@ impl OpRead {
         self.version.as_deref()
     }
 
+    /// Set the if generation match of the option
+    ///
+    /// The read will only succeed if the generation of existing object matches.
+    pub fn with_if_generation_match(mut self, generation: u64) -> Self {
+        self.if_generation_match = Some(generation);
+        self
+    }
+
+    /// Get if generation match from option
+    pub fn if_generation_match(&self) -> Option<u64> {
+        self.if_generation_match
+    }
+
     /// Set the executor of the option
     pub fn with_executor(mut self, executor: Executor) -> Self {
         self.executor = Some(executor);
@@ -424,6 +1276,17 @@ impl OpRead {
     pub fn executor(&self) -> Option<&Executor> {
         self.executor.as_ref()
     }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
 }
 
 /// Args for reader operation.
@@ -435,6 +1298,10 @@ pub struct OpReader {
     chunk: Option<usize>,
     /// The gap size of each request.
     gap: Option<usize>,
+    /// The access pattern hint of the reader.
+    access: AccessPattern,
+    /// The number of chunks to prefetch.
+    prefetch: usize,
 }
 
 impl Default for OpReader {
@@ -443,6 +1310,8 @@ impl Default for OpReader {
             concurrent: 1,
             chunk: None,
             gap: None,
+            access: AccessPattern::Normal,
+            prefetch: 0,
         }
     }
 }
@@ -485,6 +1354,76 @@ impl OpReader {
     pub fn gap(&self) -> Option<usize> {
         self.gap
     }
+
+    /// Set the access pattern of the option
+    pub fn with_access(mut self, access: AccessPattern) -> Self {
+        self.access = access;
+        self
+    }
+
+    /// Get access pattern from option
+    pub fn access(&self) -> AccessPattern {
+        self.access
+    }
+
+    /// Set the prefetch of the option
+    pub fn with_prefetch(mut self, prefetch: usize) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+
+    /// Get prefetch from option
+    pub fn prefetch(&self) -> usize {
+        self.prefetch
+    }
+}
+
+/// Args for read to file operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpReadToFile {
+    /// The local path to write into.
+    local_path: PathBuf,
+    /// Resume the download from previous progress.
+    resume: bool,
+    /// Verify the checksum after download.
+    checksum: bool,
+}
+
+impl OpReadToFile {
+    /// Create a new `OpReadToFile`.
+    pub fn new(local_path: impl AsRef<Path>) -> Self {
+        Self {
+            local_path: local_path.as_ref().to_path_buf(),
+            ..Default::default()
+        }
+    }
+
+    /// Get local path from option
+    pub fn local_path(&self) -> &Path {
+        &self.local_path
+    }
+
+    /// Set the resume of the option
+    pub fn with_resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// Get resume from option
+    pub fn resume(&self) -> bool {
+        self.resume
+    }
+
+    /// Set the checksum of the option
+    pub fn with_checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Get checksum from option
+    pub fn checksum(&self) -> bool {
+        self.checksum
+    }
 }
 
 /// Args for `stat` operation.
@@ -496,6 +1435,7 @@ pub struct OpStat {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    context: OpContext,
 }
 
 impl OpStat {
@@ -570,6 +1510,17 @@ impl OpStat {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
 }
 
 /// Args for `write` operation.
@@ -582,6 +1533,15 @@ pub struct OpWrite {
     cache_control: Option<String>,
     executor: Option<Executor>,
     user_metadata: Option<HashMap<String, String>>,
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+    if_generation_match: Option<u64>,
+    block_size: Option<u64>,
+    replication: Option<u16>,
+    permission: Option<u32>,
+    ttl: Option<Duration>,
+    acl: Option<Acl>,
+    context: OpContext,
 }
 
 impl OpWrite {
@@ -690,6 +1650,115 @@ impl OpWrite {
     pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
         self.user_metadata.as_ref()
     }
+
+    /// Set the If-Match of the option
+    ///
+    /// The write will only succeed if the etag of existing object matches.
+    pub fn with_if_match(mut self, if_match: &str) -> Self {
+        self.if_match = Some(if_match.to_string());
+        self
+    }
+
+    /// Get If-Match from option
+    pub fn if_match(&self) -> Option<&str> {
+        self.if_match.as_deref()
+    }
+
+    /// Set the If-None-Match of the option
+    ///
+    /// Use `*` to make sure the write will only succeed if the object
+    /// doesn't exist.
+    pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
+        self.if_none_match = Some(if_none_match.to_string());
+        self
+    }
+
+    /// Get If-None-Match from option
+    pub fn if_none_match(&self) -> Option<&str> {
+        self.if_none_match.as_deref()
+    }
+
+    /// Set the if generation match of the option
+    ///
+    /// The write will only succeed if the generation of existing object matches.
+    /// Use `0` to make sure the write will only succeed if the object doesn't exist.
+    pub fn with_if_generation_match(mut self, generation: u64) -> Self {
+        self.if_generation_match = Some(generation);
+        self
+    }
+
+    /// Get if generation match from option
+    pub fn if_generation_match(&self) -> Option<u64> {
+        self.if_generation_match
+    }
+
+    /// Set the block size of the file to create.
+    pub fn with_block_size(mut self, block_size: u64) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
+    /// Get the block size from option
+    pub fn block_size(&self) -> Option<u64> {
+        self.block_size
+    }
+
+    /// Set the replication factor of the file to create.
+    pub fn with_replication(mut self, replication: u16) -> Self {
+        self.replication = Some(replication);
+        self
+    }
+
+    /// Get the replication factor from option
+    pub fn replication(&self) -> Option<u16> {
+        self.replication
+    }
+
+    /// Set the permission of the file to create, like `0o644`.
+    pub fn with_permission(mut self, permission: u32) -> Self {
+        self.permission = Some(permission);
+        self
+    }
+
+    /// Get the permission from option
+    pub fn permission(&self) -> Option<u32> {
+        self.permission
+    }
+
+    /// Set the time to live of the object to create.
+    ///
+    /// The object will be expired by the service after the given duration.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Get the time to live from option
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Set the canned acl of the object to create.
+    pub fn with_acl(mut self, acl: Acl) -> Self {
+        self.acl = Some(acl);
+        self
+    }
+
+    /// Get the acl from option
+    pub fn acl(&self) -> Option<Acl> {
+        self.acl
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
 }
 
 /// Args for `writer` operation.
@@ -728,22 +1797,109 @@ impl OpWriter {
 
 /// Args for `copy` operation.
 #[derive(Debug, Clone, Default)]
-pub struct OpCopy {}
+pub struct OpCopy {
+    context: OpContext,
+}
 
 impl OpCopy {
     /// Create a new `OpCopy`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
+}
+
+/// Args for `copy_dir` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpCopyDir {
+    /// The dir that all entries will be copied into.
+    to: String,
+    /// The concurrent copy tasks that copy_dir could run.
+    concurrent: usize,
+    /// The policy for the entries that already exist in target.
+    conflict: CopyConflict,
+    /// The context that carried by the operations.
+    context: OpContext,
+}
+
+impl OpCopyDir {
+    /// Create a new `OpCopyDir`.
+    pub fn new(to: &str) -> Self {
+        Self {
+            to: to.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Get the target dir from option
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+
+    /// Set the concurrent of the option
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent;
+        self
+    }
+
+    /// Get concurrent from option
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Set the conflict policy of the option
+    pub fn with_conflict(mut self, conflict: CopyConflict) -> Self {
+        self.conflict = conflict;
+        self
+    }
+
+    /// Get conflict policy from option
+    pub fn conflict(&self) -> CopyConflict {
+        self.conflict
+    }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
 }
 
 /// Args for `rename` operation.
 #[derive(Debug, Clone, Default)]
-pub struct OpRename {}
+pub struct OpRename {
+    context: OpContext,
+}
 
 impl OpRename {
     /// Create a new `OpMove`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set the context of the option
+    pub fn with_context(mut self, context: OpContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Get context from option
+    pub fn context(&self) -> &OpContext {
+        &self.context
+    }
 }
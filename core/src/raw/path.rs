@@ -15,6 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use unicode_normalization::is_nfc;
+use unicode_normalization::UnicodeNormalization;
+
 use crate::*;
 
 /// build_abs_path will build an absolute path with root.
@@ -221,6 +224,99 @@ pub fn validate_path(path: &str, mode: EntryMode) -> bool {
     }
 }
 
+/// Validate given normalized path in strict mode.
+///
+/// Paths which could be interpreted differently by services will be rejected:
+///
+/// - Contains control characters like `\0`: `abc\0def`
+/// - Contains backslashes which are separators on windows: `abc\def`
+/// - Contains relative segments: `abc/./def`, `abc/../def`
+/// - Not in unicode NFC form: `e\u{301}` (should be `\u{e9}`)
+///
+/// Returns [`ErrorKind::Unsupported`] with the reason if the path is rejected.
+pub fn validate_path_strict(path: &str) -> Result<()> {
+    let reject = |reason: &'static str| {
+        Err(
+            Error::new(ErrorKind::Unsupported, "path is rejected by strict mode")
+                .with_context("path", path)
+                .with_context("reason", reason),
+        )
+    };
+
+    if path.chars().any(char::is_control) {
+        return reject("path contains control characters");
+    }
+    if path.contains('\\') {
+        return reject("path contains backslashes");
+    }
+    if path.split('/').any(|v| v == "." || v == "..") {
+        return reject("path contains relative segments");
+    }
+    if !is_nfc(path) {
+        return reject("path is not in unicode NFC form");
+    }
+
+    Ok(())
+}
+
+/// Sanitize given path into the form that accepted by [`validate_path_strict`].
+///
+/// # Sanitize Rules
+///
+/// - Backslashes will be treated as separators: `abc\def` => `abc/def`
+/// - Unicode will be normalized into NFC form: `e\u{301}` => `\u{e9}`
+/// - `.` segments will be removed: `abc/./def` => `abc/def`
+/// - `..` segments will be resolved: `abc/../def` => `def`
+/// - Path ends with relative segments will be a dir path: `abc/def/..` => `abc/`
+/// - All rules of [`normalize_path`] will be applied.
+///
+/// Paths contain control characters or traverse beyond the root will be rejected
+/// with [`ErrorKind::Unsupported`].
+pub fn sanitize_path(path: &str) -> Result<String> {
+    let reject = |reason: &'static str| {
+        Err(Error::new(ErrorKind::Unsupported, "path is rejected")
+            .with_context("path", path)
+            .with_context("reason", reason))
+    };
+
+    if path.chars().any(char::is_control) {
+        return reject("path contains control characters");
+    }
+
+    let p = path.trim().replace('\\', "/").nfc().collect::<String>();
+
+    let mut segments = Vec::new();
+    let mut has_trailing = p.ends_with('/');
+    for v in p.split('/') {
+        match v {
+            "" => {}
+            "." => has_trailing = true,
+            ".." => {
+                if segments.pop().is_none() {
+                    return reject("path traverses beyond the root");
+                }
+                has_trailing = true;
+            }
+            v => {
+                segments.push(v);
+                has_trailing = false;
+            }
+        }
+    }
+    // Keep the trailing `/` of input path.
+    has_trailing |= p.ends_with('/');
+
+    if segments.is_empty() {
+        return Ok("/".to_string());
+    }
+
+    let mut p = segments.join("/");
+    if has_trailing {
+        p.push('/');
+    }
+    Ok(p)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,4 +473,58 @@ mod tests {
             assert_eq!(actual, expect, "{name}")
         }
     }
+
+    #[test]
+    fn test_validate_path_strict() {
+        let cases = vec![
+            ("file path", "abc/def", true),
+            ("dir path", "abc/def/", true),
+            ("root path", "/", true),
+            ("dotted name", "abc/.def..", true),
+            ("nfc unicode", "caf\u{e9}", true),
+            ("control characters", "abc\0def", false),
+            ("backslashes", "abc\\def", false),
+            ("current segment", "abc/./def", false),
+            ("parent segment", "abc/../def", false),
+            ("parent segment at end", "abc/..", false),
+            ("nfd unicode", "cafe\u{301}", false),
+        ];
+
+        for (name, path, expect) in cases {
+            let actual = validate_path_strict(path);
+            assert_eq!(actual.is_ok(), expect, "{name}");
+            if let Err(err) = actual {
+                assert_eq!(err.kind(), ErrorKind::Unsupported, "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sanitize_path() {
+        let cases = vec![
+            ("file path", "abc/def", Some("abc/def")),
+            ("dir path", "abc/def/", Some("abc/def/")),
+            ("empty path", "", Some("/")),
+            ("root path", "/", Some("/")),
+            ("backslashes", "abc\\def", Some("abc/def")),
+            ("backslashes dir", "abc\\def\\", Some("abc/def/")),
+            ("current segment", "abc/./def", Some("abc/def")),
+            ("current segment at end", "abc/.", Some("abc/")),
+            ("parent segment", "abc/../def", Some("def")),
+            ("parent segment at end", "abc/def/..", Some("abc/")),
+            ("parent segment to root", "abc/..", Some("/")),
+            ("nfd unicode", "cafe\u{301}", Some("caf\u{e9}")),
+            ("traverse beyond root", "../abc", None),
+            ("traverse beyond root later", "abc/../../def", None),
+            ("control characters", "abc\0def", None),
+        ];
+
+        for (name, path, expect) in cases {
+            let actual = sanitize_path(path);
+            assert_eq!(actual.as_deref().ok(), expect, "{name}");
+            if let Some(p) = expect {
+                assert!(validate_path_strict(p).is_ok(), "{name}");
+            }
+        }
+    }
 }
@@ -15,7 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use http::header::CONTENT_LENGTH;
 use http::Request;
+use http::Response;
 
 use crate::raw::*;
 use crate::*;
@@ -82,6 +84,30 @@ impl PresignedRequest {
     pub fn header(&self) -> &http::HeaderMap {
         &self.headers
     }
+
+    /// Send this presigned request with given body via the http client.
+    ///
+    /// The response will be returned as is, callers should check the status code by themselves.
+    pub async fn send(self, client: &HttpClient, body: Buffer) -> Result<Response<Buffer>> {
+        client.send(self.into_request(body)).await
+    }
+
+    /// Fetch this presigned request with given body via the http client.
+    ///
+    /// Unlike [`PresignedRequest::send`], the response body will be returned as a stream.
+    pub async fn fetch(self, client: &HttpClient, body: Buffer) -> Result<Response<HttpBody>> {
+        client.fetch(self.into_request(body)).await
+    }
+
+    fn into_request(self, body: Buffer) -> Request<Buffer> {
+        let mut req: Request<Buffer> = self.into();
+        // Services like s3 don't accept chunked upload for presigned write.
+        if !body.is_empty() && !req.headers().contains_key(CONTENT_LENGTH) {
+            req.headers_mut().insert(CONTENT_LENGTH, body.len().into());
+        }
+        *req.body_mut() = body;
+        req
+    }
 }
 
 impl<T: Default> From<PresignedRequest> for Request<T> {
@@ -176,9 +202,12 @@ impl RpBatch {
 }
 
 /// Batch results of `batch` operations.
+#[non_exhaustive]
 pub enum BatchedReply {
     /// results of `delete batch` operation
     Delete(RpDelete),
+    /// results of `stat batch` operation
+    Stat(Box<RpStat>),
 }
 
 impl From<RpDelete> for BatchedReply {
@@ -187,6 +216,164 @@ impl From<RpDelete> for BatchedReply {
     }
 }
 
+impl From<RpStat> for BatchedReply {
+    fn from(rp: RpStat) -> Self {
+        Self::Stat(Box::new(rp))
+    }
+}
+
+/// Reply for `list_multipart_uploads` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpListMultipartUploads {
+    uploads: Vec<MultipartUpload>,
+}
+
+impl RpListMultipartUploads {
+    /// Create a new reply for `list_multipart_uploads`.
+    pub fn new(uploads: Vec<MultipartUpload>) -> Self {
+        Self { uploads }
+    }
+
+    /// Consume reply to get the listed uploads.
+    pub fn into_uploads(self) -> Vec<MultipartUpload> {
+        self.uploads
+    }
+}
+
+/// Reply for `abort_multipart_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpAbortMultipartUpload {}
+
+/// Reply for `stage_block` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpStageBlock {}
+
+/// Reply for `commit_block_list` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpCommitBlockList {}
+
+/// Reply for `write_at` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpWriteAt {}
+
+/// Reply for `allocate` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpAllocate {}
+
+/// Reply for `concat` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpConcat {}
+
+/// Reply for `locate` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpLocate {
+    locations: Vec<BlockLocation>,
+}
+
+impl RpLocate {
+    /// Create a new reply for `locate`.
+    pub fn new(locations: Vec<BlockLocation>) -> Self {
+        Self { locations }
+    }
+
+    /// Consume reply to get the block locations.
+    pub fn into_locations(self) -> Vec<BlockLocation> {
+        self.locations
+    }
+}
+
+/// Reply for `get_lifecycle` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetLifecycle {
+    rules: Vec<LifecycleRule>,
+}
+
+impl RpGetLifecycle {
+    /// Create a new reply for `get_lifecycle`.
+    pub fn new(rules: Vec<LifecycleRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Consume reply to get the lifecycle rules.
+    pub fn into_rules(self) -> Vec<LifecycleRule> {
+        self.rules
+    }
+}
+
+/// Reply for `set_lifecycle` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetLifecycle {}
+
+/// Reply for `get_retention` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetRetention {
+    retention: ObjectRetention,
+}
+
+impl RpGetRetention {
+    /// Create a new reply for `get_retention`.
+    pub fn new(retention: ObjectRetention) -> Self {
+        Self { retention }
+    }
+
+    /// Consume reply to get the object retention.
+    pub fn into_retention(self) -> ObjectRetention {
+        self.retention
+    }
+}
+
+/// Reply for `set_retention` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetRetention {}
+
+/// Reply for `get_acl` operation.
+#[derive(Debug, Clone)]
+pub struct RpGetAcl {
+    acl: Acl,
+}
+
+impl RpGetAcl {
+    /// Create a new reply for `get_acl`.
+    pub fn new(acl: Acl) -> Self {
+        Self { acl }
+    }
+
+    /// Consume reply to get the acl.
+    pub fn into_acl(self) -> Acl {
+        self.acl
+    }
+}
+
+/// Reply for `set_acl` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetAcl {}
+
+/// Reply for `create_bucket` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpCreateBucket {}
+
+/// Reply for `delete_bucket` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpDeleteBucket {}
+
+/// Reply for `list_buckets` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpListBuckets {
+    buckets: Vec<String>,
+}
+
+impl RpListBuckets {
+    /// Create a new reply for `list_buckets`.
+    pub fn new(buckets: Vec<String>) -> Self {
+        Self { buckets }
+    }
+
+    /// Consume reply to get the bucket names.
+    pub fn into_buckets(self) -> Vec<String> {
+        self.buckets
+    }
+}
+
 /// Reply for `stat` operation.
 #[derive(Debug, Clone)]
 pub struct RpStat {
@@ -280,4 +467,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_presigned_request_into_request() {
+        let pr = PresignedRequest::new(
+            Method::PUT,
+            Uri::from_static("https://opendal.apache.org/path/to/file"),
+            HeaderMap::new(),
+        );
+
+        let req = pr.clone().into_request(Buffer::from("Hello, World!"));
+        assert_eq!("13", req.headers().get(CONTENT_LENGTH).unwrap());
+        assert_eq!(13, req.body().len());
+
+        let req = pr.into_request(Buffer::new());
+        assert!(req.headers().get(CONTENT_LENGTH).is_none());
+    }
 }
@@ -29,6 +29,7 @@ use serde::de::{self};
 use crate::*;
 
 /// Parse xml deserialize error into opendal::Error.
+#[cfg(feature = "internal-xml")]
 pub fn new_xml_deserialize_error(e: quick_xml::DeError) -> Error {
     Error::new(ErrorKind::Unexpected, "deserialize xml").set_source(e)
 }
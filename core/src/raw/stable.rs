@@ -0,0 +1,257 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `stable` is the subset of raw APIs that external layers and services can
+//! depend on.
+//!
+//! # Stability
+//!
+//! Items re-exported here follow the same semver rules as OpenDAL's public API:
+//!
+//! - They will never be removed or changed in an incompatible way in a patch release.
+//! - Before an incompatible change in a minor release, the old item will be kept and
+//!   marked as `#[deprecated]` for at least one minor release, and an adapter shim
+//!   will be provided whenever possible, for example, a default implementation
+//!   for a new trait method or a type alias for a renamed type.
+//! - New methods with default implementations and new fields of `#[non_exhaustive]`
+//!   structs may be added in any minor release.
+//! - Migration steps for every deprecation will be recorded in
+//!   [`upgrade`][crate::docs::upgrade].
+//!
+//! Other items in [`raw`][crate::raw] are helpers for OpenDAL's built-in services
+//! and may change in any minor release without notice.
+//!
+//! # Usage
+//!
+//! ```
+//! use opendal::raw::stable::*;
+//! use opendal::*;
+//!
+//! #[derive(Debug)]
+//! struct NoopAccessor<A: Access> {
+//!     inner: A,
+//! }
+//!
+//! impl<A: Access> LayeredAccess for NoopAccessor<A> {
+//!     type Inner = A;
+//!     type Reader = A::Reader;
+//!     type BlockingReader = A::BlockingReader;
+//!     type Writer = A::Writer;
+//!     type BlockingWriter = A::BlockingWriter;
+//!     type Lister = A::Lister;
+//!     type BlockingLister = A::BlockingLister;
+//!
+//!     fn inner(&self) -> &Self::Inner {
+//!         &self.inner
+//!     }
+//!
+//!     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+//!         self.inner.read(path, args).await
+//!     }
+//!
+//!     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+//!         self.inner.write(path, args).await
+//!     }
+//!
+//!     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+//!         self.inner.list(path, args).await
+//!     }
+//!
+//!     fn blocking_read(
+//!         &self,
+//!         path: &str,
+//!         args: OpRead,
+//!     ) -> Result<(RpRead, Self::BlockingReader)> {
+//!         self.inner.blocking_read(path, args)
+//!     }
+//!
+//!     fn blocking_write(
+//!         &self,
+//!         path: &str,
+//!         args: OpWrite,
+//!     ) -> Result<(RpWrite, Self::BlockingWriter)> {
+//!         self.inner.blocking_write(path, args)
+//!     }
+//!
+//!     fn blocking_list(
+//!         &self,
+//!         path: &str,
+//!         args: OpList,
+//!     ) -> Result<(RpList, Self::BlockingLister)> {
+//!         self.inner.blocking_list(path, args)
+//!     }
+//! }
+//! ```
+
+pub use super::Access;
+pub use super::AccessDyn;
+pub use super::Accessor;
+pub use super::AccessorInfo;
+pub use super::Layer;
+pub use super::LayeredAccess;
+pub use super::Operation;
+
+pub use super::BoxedFuture;
+pub use super::BoxedStaticFuture;
+pub use super::MaybeSend;
+
+pub use super::OpCopy;
+pub use super::OpCreateDir;
+pub use super::OpDelete;
+pub use super::OpList;
+pub use super::OpPresign;
+pub use super::OpRead;
+pub use super::OpRename;
+pub use super::OpStat;
+pub use super::OpWrite;
+
+pub use super::RpCopy;
+pub use super::RpCreateDir;
+pub use super::RpDelete;
+pub use super::RpList;
+pub use super::RpPresign;
+pub use super::RpRead;
+pub use super::RpRename;
+pub use super::RpStat;
+pub use super::RpWrite;
+
+pub use super::build_abs_path;
+pub use super::build_rel_path;
+pub use super::normalize_path;
+pub use super::normalize_root;
+
+pub use super::BytesRange;
+pub use super::HttpBody;
+pub use super::HttpClient;
+pub use super::HttpFetch;
+
+/// The stable subset of [`oio`][crate::raw::oio].
+pub mod oio {
+    pub use crate::raw::oio::BlockingList;
+    pub use crate::raw::oio::BlockingLister;
+    pub use crate::raw::oio::BlockingRead;
+    pub use crate::raw::oio::BlockingReader;
+    pub use crate::raw::oio::BlockingWrite;
+    pub use crate::raw::oio::BlockingWriter;
+    pub use crate::raw::oio::Entry;
+    pub use crate::raw::oio::List;
+    pub use crate::raw::oio::Lister;
+    pub use crate::raw::oio::Read;
+    pub use crate::raw::oio::Reader;
+    pub use crate::raw::oio::Write;
+    pub use crate::raw::oio::Writer;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::services;
+    use crate::*;
+
+    /// CountLayer counts the reads with the stable APIs only, so that breaking
+    /// changes to them will be caught at compile time.
+    #[derive(Debug, Clone, Default)]
+    struct CountLayer {
+        reads: Arc<AtomicUsize>,
+    }
+
+    impl<A: Access> Layer<A> for CountLayer {
+        type LayeredAccess = CountAccessor<A>;
+
+        fn layer(&self, inner: A) -> Self::LayeredAccess {
+            CountAccessor {
+                inner,
+                reads: self.reads.clone(),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct CountAccessor<A: Access> {
+        inner: A,
+        reads: Arc<AtomicUsize>,
+    }
+
+    impl<A: Access> LayeredAccess for CountAccessor<A> {
+        type Inner = A;
+        type Reader = A::Reader;
+        type BlockingReader = A::BlockingReader;
+        type Writer = A::Writer;
+        type BlockingWriter = A::BlockingWriter;
+        type Lister = A::Lister;
+        type BlockingLister = A::BlockingLister;
+
+        fn inner(&self) -> &Self::Inner {
+            &self.inner
+        }
+
+        async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            self.inner.read(path, args).await
+        }
+
+        async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            self.inner.write(path, args).await
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+            self.inner.list(path, args).await
+        }
+
+        fn blocking_read(
+            &self,
+            path: &str,
+            args: OpRead,
+        ) -> Result<(RpRead, Self::BlockingReader)> {
+            self.inner.blocking_read(path, args)
+        }
+
+        fn blocking_write(
+            &self,
+            path: &str,
+            args: OpWrite,
+        ) -> Result<(RpWrite, Self::BlockingWriter)> {
+            self.inner.blocking_write(path, args)
+        }
+
+        fn blocking_list(
+            &self,
+            path: &str,
+            args: OpList,
+        ) -> Result<(RpList, Self::BlockingLister)> {
+            self.inner.blocking_list(path, args)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_layer_with_stable_api() {
+        let layer = CountLayer::default();
+        let op = Operator::new(services::Memory::default())
+            .expect("must init")
+            .layer(layer.clone())
+            .finish();
+
+        op.write("test", "hello").await.expect("write must succeed");
+        let bs = op.read("test").await.expect("read must succeed");
+        assert_eq!(bs.to_bytes(), "hello");
+        assert_eq!(layer.reads.load(Ordering::Relaxed), 1);
+    }
+}
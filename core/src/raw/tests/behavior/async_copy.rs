@@ -19,7 +19,7 @@ use anyhow::Result;
 use sha2::Digest;
 use sha2::Sha256;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
@@ -35,7 +35,14 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_copy_self,
             test_copy_nested,
             test_copy_overwrite
-        ))
+        ));
+        if cap.list_with_recursive {
+            tests.extend(async_trials!(
+                op,
+                test_copy_dir,
+                test_copy_dir_with_conflict
+            ))
+        }
     }
 }
 
@@ -229,3 +236,59 @@ pub async fn test_copy_overwrite(op: Operator) -> Result<()> {
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Copy a dir should copy all nested files.
+pub async fn test_copy_dir(op: Operator) -> Result<()> {
+    let source = format!("{}/", uuid::Uuid::new_v4());
+    let target = format!("{}/", uuid::Uuid::new_v4());
+
+    let files = ["a", "b/c", "b/d/e"];
+    for file in files {
+        op.write(&format!("{source}{file}"), file).await?;
+    }
+
+    let report = op.copy_dir_with(&source, &target).concurrent(4).await?;
+    assert_eq!(report.copied(), files.len());
+
+    for file in files {
+        let content = op.read(&format!("{target}{file}")).await?.to_vec();
+        assert_eq!(content, file.as_bytes());
+    }
+
+    op.remove_all(&source).await?;
+    op.remove_all(&target).await?;
+    Ok(())
+}
+
+/// Copy a dir with conflict policy should respect existing files.
+pub async fn test_copy_dir_with_conflict(op: Operator) -> Result<()> {
+    let source = format!("{}/", uuid::Uuid::new_v4());
+    let target = format!("{}/", uuid::Uuid::new_v4());
+
+    op.write(&format!("{source}x"), "source").await?;
+    op.write(&format!("{source}y"), "source").await?;
+    op.write(&format!("{target}x"), "target").await?;
+
+    let err = op
+        .copy_dir_with(&source, &target)
+        .conflict(CopyConflict::Fail)
+        .await
+        .expect_err("copy to existing file must fail");
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+    let report = op
+        .copy_dir_with(&source, &target)
+        .conflict(CopyConflict::Skip)
+        .await?;
+    assert_eq!(report.skipped(), 1);
+    assert_eq!(op.read(&format!("{target}x")).await?.to_vec(), b"target");
+    assert_eq!(op.read(&format!("{target}y")).await?.to_vec(), b"source");
+
+    let report = op.copy_dir_with(&source, &target).await?;
+    assert_eq!(report.copied(), 2);
+    assert_eq!(op.read(&format!("{target}x")).await?.to_vec(), b"source");
+
+    op.remove_all(&source).await?;
+    op.remove_all(&target).await?;
+    Ok(())
+}
@@ -15,12 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
 use anyhow::Result;
 use futures::StreamExt;
 use futures::TryStreamExt;
 use log::warn;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
@@ -33,10 +37,15 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_delete_with_special_chars,
             test_delete_not_existing,
             test_delete_stream,
+            test_deleter,
             test_remove_one_file
         ));
         if cap.list_with_recursive {
-            tests.extend(async_trials!(op, test_remove_all_basic));
+            tests.extend(async_trials!(
+                op,
+                test_remove_all_basic,
+                test_remove_all_with_concurrent
+            ));
             if !cap.create_dir {
                 tests.extend(async_trials!(op, test_remove_all_with_prefix_exists));
             }
@@ -76,12 +85,12 @@ pub async fn test_delete_empty_dir(op: Operator) -> Result<()> {
 /// Delete file with special chars should succeed.
 pub async fn test_delete_with_special_chars(op: Operator) -> Result<()> {
     // Ignore test for supabase until https://github.com/apache/opendal/issues/2194 addressed.
-    if op.info().scheme() == opendal::Scheme::Supabase {
+    if op.info().scheme() == crate::Scheme::Supabase {
         warn!("ignore test for supabase until https://github.com/apache/opendal/issues/2194 is resolved");
         return Ok(());
     }
     // Ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 addressed.
-    if op.info().scheme() == opendal::Scheme::Atomicserver {
+    if op.info().scheme() == crate::Scheme::Atomicserver {
         warn!("ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 is resolved");
         return Ok(());
     }
@@ -203,6 +212,69 @@ pub async fn test_remove_all_basic(op: Operator) -> Result<()> {
     test_blocking_remove_all_with_objects(op, parent, ["a/b", "a/c", "a/d/e"]).await
 }
 
+/// Remove all with concurrent should report progress of every path.
+pub async fn test_remove_all_with_concurrent(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    for path in (0..20).map(|v| format!("{parent}/x/{v}")) {
+        op.write(&path, "remove_all").await?;
+    }
+
+    let handled = Arc::new(AtomicUsize::new(0));
+    let counter = handled.clone();
+    let report = op
+        .remove_all_with(&format!("{parent}/"))
+        .concurrent(4)
+        .batch_size(3)
+        .progress(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        })
+        .await?;
+
+    assert!(
+        report.is_ok(),
+        "remove all must succeed: {:?}",
+        report.failed()
+    );
+    assert!(report.deleted() >= 20);
+    assert_eq!(handled.load(Ordering::Relaxed), report.deleted());
+
+    let found = op
+        .lister_with(&format!("{parent}/"))
+        .recursive(true)
+        .await
+        .expect("list must succeed")
+        .try_next()
+        .await
+        .expect("list must succeed")
+        .is_some();
+    assert!(!found, "all objects should be removed");
+
+    Ok(())
+}
+
+/// Deleter should report the result of every path.
+pub async fn test_deleter(op: Operator) -> Result<()> {
+    let dir = uuid::Uuid::new_v4().to_string();
+
+    let expected: Vec<_> = (0..50).map(|v| format!("{dir}/{v}")).collect();
+    for path in expected.iter() {
+        op.write(path, "deleter").await?;
+    }
+
+    let deleter = op.deleter_with().concurrent(4).batch_size(7).await?;
+    let results: Vec<_> = deleter.delete_iter(expected.clone()).collect().await;
+
+    assert_eq!(results.len(), expected.len());
+    for (path, res) in results {
+        assert!(res.is_ok(), "{path} should be deleted: {res:?}");
+    }
+    for path in expected.iter() {
+        assert!(!op.is_exist(path).await?, "{path} should be removed")
+    }
+
+    Ok(())
+}
+
 /// Remove all under a prefix, while the prefix itself is also an object
 pub async fn test_remove_all_with_prefix_exists(op: Operator) -> Result<()> {
     let parent = uuid::Uuid::new_v4().to_string();
@@ -19,11 +19,11 @@ use std::io::SeekFrom;
 use std::vec;
 
 use anyhow::Result;
-use opendal::raw::tests::ReadAction;
-use opendal::raw::tests::ReadChecker;
-use opendal::raw::BytesRange;
+use crate::raw::tests::ReadAction;
+use crate::raw::tests::ReadChecker;
+use crate::raw::BytesRange;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     tests.extend(async_trials!(op))
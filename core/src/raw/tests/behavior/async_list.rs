@@ -24,7 +24,7 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 use log::debug;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
@@ -33,6 +33,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
         tests.extend(async_trials!(
             op,
             test_check,
+            test_check_with_strategy,
             test_list_dir,
             test_list_dir_with_metakey,
             test_list_dir_with_metakey_complete,
@@ -44,6 +45,10 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_list_nested_dir,
             test_list_dir_with_file_path,
             test_list_with_start_after,
+            test_list_with_sorted,
+            test_list_with_continuation_token,
+            test_list_partitions,
+            test_list_with_pages,
             test_list_dir_with_recursive,
             test_list_dir_with_recursive_no_trailing_slash,
             test_list_file_with_recursive,
@@ -64,6 +69,23 @@ pub async fn test_check(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Check with all strategies should be OK.
+pub async fn test_check_with_strategy(op: Operator) -> Result<()> {
+    for strategy in [
+        CheckStrategy::Auto,
+        CheckStrategy::List,
+        CheckStrategy::Stat,
+        CheckStrategy::Write,
+    ] {
+        let report = op.check_with(strategy).await.expect("operator check is ok");
+        assert_eq!(report.scheme(), op.info().scheme());
+        assert!(!report.steps().is_empty());
+        assert_ne!(report.strategy(), CheckStrategy::Auto);
+    }
+
+    Ok(())
+}
+
 /// List dir should return newly created file.
 pub async fn test_list_dir(op: Operator) -> Result<()> {
     let parent = uuid::Uuid::new_v4().to_string();
@@ -469,6 +491,157 @@ pub async fn test_list_with_start_after(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List with sorted should return entries in lexicographical order.
+pub async fn test_list_with_sorted(op: Operator) -> Result<()> {
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+    op.create_dir(dir).await?;
+
+    let mut expected = vec![];
+    for name in ["file-c", "file-a", "file-b/", "file-a0", "file-"] {
+        let path = format!("{dir}{name}");
+        if name.ends_with('/') {
+            op.create_dir(&path).await?;
+        } else {
+            op.write(&path, "content").await?;
+        }
+        expected.push(path);
+    }
+    expected.sort();
+
+    let mut objects = op.lister_with(dir).sorted(true).await?;
+    let mut actual = vec![];
+    while let Some(o) = objects.try_next().await? {
+        if o.path() != dir {
+            actual.push(o.path().to_string())
+        }
+    }
+
+    assert_eq!(expected, actual);
+
+    op.remove_all(dir).await?;
+
+    Ok(())
+}
+
+/// List continued from token should return the remaining entries.
+pub async fn test_list_with_continuation_token(op: Operator) -> Result<()> {
+    if !op.info().full_capability().list_with_start_after {
+        return Ok(());
+    }
+
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+    op.create_dir(dir).await?;
+
+    let mut expected = vec![];
+    for idx in 0..5 {
+        let path = format!("{dir}file-{idx}");
+        op.write(&path, "content").await?;
+        expected.push(path);
+    }
+
+    let mut lister = op.lister(dir).await?;
+    let mut actual = vec![];
+    while actual.len() < 2 {
+        let o = lister.try_next().await?.expect("entry must exist");
+        if o.path() != dir {
+            actual.push(o.path().to_string())
+        }
+    }
+    let token = lister.continuation_token().to_string();
+    drop(lister);
+
+    let token: ListToken = token.parse()?;
+    assert_eq!(token.path(), dir);
+    assert_eq!(token.start_after(), Some(actual[1].as_str()));
+
+    let mut lister = op.lister_from(&token).await?;
+    while let Some(o) = lister.try_next().await? {
+        if o.path() != dir {
+            actual.push(o.path().to_string())
+        }
+    }
+
+    assert_eq!(expected, actual);
+
+    op.remove_all(dir).await?;
+
+    Ok(())
+}
+
+/// List partitions should return all entries without overlap.
+pub async fn test_list_partitions(op: Operator) -> Result<()> {
+    if !op.info().full_capability().list_with_start_after {
+        return Ok(());
+    }
+
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+    op.create_dir(dir).await?;
+
+    let mut expected = vec![];
+    for name in [
+        "0-file", "A-file", "M-file", "Z-file", "a-file", "m-file", "z-file",
+    ] {
+        let path = format!("{dir}{name}");
+        op.write(&path, "content").await?;
+        expected.push(path);
+    }
+
+    let mut actual = vec![];
+    for mut lister in op.list_partitions(dir, 4).await? {
+        while let Some(o) = lister.try_next().await? {
+            if o.path() != dir {
+                actual.push(o.path().to_string())
+            }
+        }
+    }
+
+    assert_eq!(expected, actual);
+
+    op.remove_all(dir).await?;
+
+    Ok(())
+}
+
+/// List dir page by page should return all entries.
+pub async fn test_list_with_pages(op: Operator) -> Result<()> {
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+    op.create_dir(dir).await?;
+
+    for idx in 0..5 {
+        op.write(&format!("{dir}file-{idx}"), "content").await?;
+    }
+
+    let mut expected = op
+        .list(dir)
+        .await?
+        .into_iter()
+        .map(|e| e.path().to_string())
+        .collect::<Vec<_>>();
+    expected.sort();
+
+    let mut lister = op.lister_with(dir).limit(2).await?;
+    let mut actual = vec![];
+    while let Some(page) = lister.next_page().await? {
+        assert!(page.entries().len() <= 2, "page must not exceed limit");
+        assert_eq!(
+            page.token(),
+            page.entries().last().unwrap().path(),
+            "token must be the last entry"
+        );
+        actual.extend(
+            page.into_entries()
+                .into_iter()
+                .map(|e| e.path().to_string()),
+        );
+    }
+    actual.sort();
+
+    assert_eq!(expected, actual);
+
+    op.remove_all(dir).await?;
+    Ok(())
+}
+
 pub async fn test_list_root_with_recursive(op: Operator) -> Result<()> {
     let w = op.lister_with("").recursive(true).await?;
     let actual = w
@@ -0,0 +1,42 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+use rand::prelude::*;
+
+use super::*;
+use crate::raw::tests::ModelChecker;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.delete {
+        tests.extend(async_trials!(op, test_model_check))
+    }
+}
+
+/// Random interleaved actions should be consistent with the in-memory model.
+pub async fn test_model_check(op: Operator) -> Result<()> {
+    let dir = TEST_FIXTURE.new_dir_path();
+
+    let mut checker = ModelChecker::new(thread_rng().gen());
+    let actions = checker.actions(op.info().full_capability(), 256);
+    checker.check(&op, &dir, &actions).await;
+
+    op.remove_all(&dir).await?;
+    Ok(())
+}
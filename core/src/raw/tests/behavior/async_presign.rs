@@ -18,15 +18,15 @@
 use std::str::FromStr;
 use std::time::Duration;
 
+use crate::raw;
 use anyhow::Result;
 use http::header;
 use log::debug;
-use opendal::raw;
 use reqwest::Url;
 use sha2::Digest;
 use sha2::Sha256;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
@@ -36,7 +36,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             op,
             test_presign_write,
             test_presign_read,
-            test_presign_stat
+            test_presign_stat,
+            test_send_presigned
         ))
     }
 }
@@ -138,3 +139,33 @@ pub async fn test_presign_read(op: Operator) -> Result<()> {
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Presigned requests should be sent via operator successfully.
+pub async fn test_send_presigned(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    let signed_req = op.presign_write(&path, Duration::from_secs(3600)).await?;
+    let resp = op.send_presigned(signed_req, content.clone()).await?;
+    assert!(
+        resp.status().is_success(),
+        "write status: {}",
+        resp.status()
+    );
+
+    let signed_req = op.presign_read(&path, Duration::from_secs(3600)).await?;
+    let resp = op.send_presigned(signed_req, Buffer::new()).await?;
+    assert!(resp.status().is_success(), "read status: {}", resp.status());
+
+    let bs = resp.into_body().to_bytes();
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::env;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -26,7 +27,7 @@ use reqwest::Url;
 use sha2::Digest;
 use sha2::Sha256;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
@@ -36,6 +37,8 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             op,
             test_read_full,
             test_read_range,
+            test_read_partial,
+            test_locate,
             test_reader,
             test_read_not_exist,
             test_read_with_if_match,
@@ -44,7 +47,9 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_read_with_special_chars,
             test_read_with_override_cache_control,
             test_read_with_override_content_disposition,
-            test_read_with_override_content_type
+            test_read_with_override_content_type,
+            test_read_to_file,
+            test_read_to_file_with_resume
         ))
     }
 
@@ -108,6 +113,51 @@ pub async fn test_read_range(op: Operator) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Read partial should return the effective range of an open-ended read.
+pub async fn test_read_partial(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+    let (offset, _) = gen_offset_length(size);
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let res = op.read_partial(&path, offset..).await?;
+    assert_eq!(res.range(), offset..size as u64, "read range");
+    if let Some(total_size) = res.total_size() {
+        assert_eq!(total_size, size as u64, "total size");
+        assert_eq!(res.is_end(), Some(true));
+    }
+    assert_eq!(
+        format!("{:x}", Sha256::digest(res.buffer().to_bytes())),
+        format!("{:x}", Sha256::digest(&content[offset as usize..])),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Locate should return blocks that cover the file.
+pub async fn test_locate(op: Operator) -> anyhow::Result<()> {
+    if !op.info().full_capability().locate {
+        return Ok(());
+    }
+
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let blocks = op.locate(&path, ..).await?;
+    assert!(!blocks.is_empty(), "blocks must not be empty");
+    assert_eq!(blocks[0].offset(), 0, "first block offset");
+    let end = blocks.iter().map(|v| v.offset() + v.length()).max();
+    assert!(end >= Some(size as u64), "blocks must cover the file");
+
+    Ok(())
+}
+
 /// Read full content should match.
 pub async fn test_reader(op: Operator) -> anyhow::Result<()> {
     let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
@@ -161,6 +211,74 @@ pub async fn test_reader(op: Operator) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Read to local file should match.
+pub async fn test_read_to_file(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let local_path = env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    let res = op
+        .read_to_file_with(&path, &local_path)
+        .concurrent(4)
+        .chunk(1024)
+        .checksum(true)
+        .await;
+    let bs = tokio::fs::read(&local_path).await;
+    tokio::fs::remove_file(&local_path).await?;
+    res.expect("read to file must succeed");
+
+    let bs = bs?;
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Read to local file with resume should continue from recorded progress.
+pub async fn test_read_to_file_with_resume(op: Operator) -> anyhow::Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+    let meta = op.stat(&path).await?;
+    let etag = meta.etag().unwrap_or_default();
+
+    // Simulate a download that was interrupted at the half of the file.
+    let local_path = env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    let progress_path = local_path.with_extension("opendal-progress");
+    let half = size / 2;
+    let mut partial = content[..half].to_vec();
+    partial.resize(size, 0);
+    tokio::fs::write(&local_path, &partial).await?;
+    tokio::fs::write(&progress_path, format!("{etag}\n{half}")).await?;
+
+    let res = op.read_to_file_with(&path, &local_path).resume(true).await;
+    let bs = tokio::fs::read(&local_path).await;
+    let progress_exist = tokio::fs::try_exists(&progress_path).await?;
+    tokio::fs::remove_file(&local_path).await?;
+    let _ = tokio::fs::remove_file(&progress_path).await;
+    res.expect("read to file must succeed");
+
+    let bs = bs?;
+    assert!(!progress_exist, "progress must be removed after succeed");
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
 /// Read not exist file should return NotFound
 pub async fn test_read_not_exist(op: Operator) -> anyhow::Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -253,12 +371,12 @@ pub async fn test_read_with_dir_path(op: Operator) -> anyhow::Result<()> {
 /// Read file with special chars should succeed.
 pub async fn test_read_with_special_chars(op: Operator) -> anyhow::Result<()> {
     // Ignore test for supabase until https://github.com/apache/opendal/issues/2194 addressed.
-    if op.info().scheme() == opendal::Scheme::Supabase {
+    if op.info().scheme() == crate::Scheme::Supabase {
         warn!("ignore test for supabase until https://github.com/apache/opendal/issues/2194 is resolved");
         return Ok(());
     }
     // Ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 addressed.
-    if op.info().scheme() == opendal::Scheme::Atomicserver {
+    if op.info().scheme() == crate::Scheme::Atomicserver {
         warn!("ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 is resolved");
         return Ok(());
     }
@@ -23,7 +23,7 @@ use http::StatusCode;
 use log::warn;
 use reqwest::Url;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
@@ -127,12 +127,12 @@ pub async fn test_stat_nested_parent_dir(op: Operator) -> Result<()> {
 /// Stat existing file with special chars should return metadata
 pub async fn test_stat_with_special_chars(op: Operator) -> Result<()> {
     // Ignore test for supabase until https://github.com/apache/opendal/issues/2194 addressed.
-    if op.info().scheme() == opendal::Scheme::Supabase {
+    if op.info().scheme() == crate::Scheme::Supabase {
         warn!("ignore test for supabase until https://github.com/apache/opendal/issues/2194 is resolved");
         return Ok(());
     }
     // Ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 addressed.
-    if op.info().scheme() == opendal::Scheme::Atomicserver {
+    if op.info().scheme() == crate::Scheme::Atomicserver {
         warn!("ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 is resolved");
         return Ok(());
     }
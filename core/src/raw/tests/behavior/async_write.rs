@@ -16,6 +16,8 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
 
 use anyhow::Result;
 use bytes::Bytes;
@@ -29,7 +31,9 @@ use log::warn;
 use sha2::Digest;
 use sha2::Sha256;
 
-use crate::*;
+use super::*;
+use crate::raw::tests::WriteAction;
+use crate::raw::tests::WriteChecker;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
@@ -45,6 +49,15 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_write_with_content_type,
             test_write_with_content_disposition,
             test_write_with_user_metadata,
+            test_write_with_if_none_match,
+            test_write_with_if_match,
+            test_write_with_if_generation_match,
+            test_write_with_hadoop_options,
+            test_write_with_ttl,
+            test_write_with_acl,
+            test_write_at,
+            test_allocate,
+            test_write_from_file,
             test_writer_write,
             test_writer_write_with_overwrite,
             test_writer_write_with_concurrent,
@@ -52,11 +65,17 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_writer_sink_with_concurrent,
             test_writer_abort,
             test_writer_abort_with_concurrent,
+            test_writer_drop_without_close,
             test_writer_futures_copy,
-            test_writer_futures_copy_with_concurrent
+            test_writer_futures_copy_with_concurrent,
+            test_writer_with_actions
         ))
     }
 
+    if cap.read && cap.write && cap.concat {
+        tests.extend(async_trials!(op, test_concat))
+    }
+
     if cap.read && cap.write && cap.write_can_append && cap.stat {
         tests.extend(async_trials!(
             op,
@@ -108,17 +127,17 @@ pub async fn test_write_with_dir_path(op: Operator) -> Result<()> {
 /// Write a single file with special chars should succeed.
 pub async fn test_write_with_special_chars(op: Operator) -> Result<()> {
     // Ignore test for supabase until https://github.com/apache/opendal/issues/2194 addressed.
-    if op.info().scheme() == opendal::Scheme::Supabase {
+    if op.info().scheme() == crate::Scheme::Supabase {
         warn!("ignore test for supabase until https://github.com/apache/opendal/issues/2194 is resolved");
         return Ok(());
     }
     // Ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 addressed.
-    if op.info().scheme() == opendal::Scheme::Atomicserver {
+    if op.info().scheme() == crate::Scheme::Atomicserver {
         warn!("ignore test for atomicserver until https://github.com/atomicdata-dev/atomic-server/issues/663 is resolved");
         return Ok(());
     }
     // Ignore test for vercel blob https://github.com/apache/opendal/pull/4103.
-    if op.info().scheme() == opendal::Scheme::VercelBlob {
+    if op.info().scheme() == crate::Scheme::VercelBlob {
         warn!("ignore test for vercel blob https://github.com/apache/opendal/pull/4103");
         return Ok(());
     }
@@ -160,6 +179,141 @@ pub async fn test_write_with_cache_control(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a single file with block size, replication and permission should succeed.
+pub async fn test_write_with_hadoop_options(op: Operator) -> Result<()> {
+    let cap = op.info().full_capability();
+    if !(cap.write_with_block_size || cap.write_with_replication || cap.write_with_permission) {
+        return Ok(());
+    }
+
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    let mut fut = op.write_with(&path, content.clone());
+    if cap.write_with_block_size {
+        fut = fut.block_size(128 * 1024 * 1024);
+    }
+    if cap.write_with_replication {
+        fut = fut.replication(1);
+    }
+    if cap.write_with_permission {
+        fut = fut.permission(0o644);
+    }
+    fut.await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs, content);
+
+    Ok(())
+}
+
+/// Write a single file with ttl should succeed and be readable before expired.
+pub async fn test_write_with_ttl(op: Operator) -> Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    let res = op
+        .write_with(&path, content.clone())
+        .ttl(Duration::from_secs(3600))
+        .await;
+    if !op.info().full_capability().write_with_ttl {
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+        return Ok(());
+    }
+    res?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs, content);
+
+    Ok(())
+}
+
+/// Write a single file with acl should succeed.
+///
+/// Use `Acl::Private` here since public access is usually blocked by default.
+pub async fn test_write_with_acl(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    let res = op.write_with(&path, content).acl(Acl::Private).await;
+    if !op.info().full_capability().write_with_acl {
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+        return Ok(());
+    }
+    res?;
+
+    if op.info().full_capability().acl {
+        assert_eq!(op.acl(&path).await?, Acl::Private);
+    }
+
+    Ok(())
+}
+
+/// Write ranges of a file out of order should succeed, the gap should be read as zeros.
+pub async fn test_write_at(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_at {
+        return Ok(());
+    }
+
+    // 512 fits the align size of all services that support write_at.
+    let path = TEST_FIXTURE.new_file_path();
+    let head = vec![1u8; 512];
+    let tail = vec![2u8; 512];
+
+    op.write_at(&path, 1024, tail.clone())
+        .await
+        .expect("write at must succeed");
+    op.write_at(&path, 0, head.clone())
+        .await
+        .expect("write at must succeed");
+
+    let bs = op.read(&path).await?.to_vec();
+    assert_eq!(bs.len(), 1536);
+    assert_eq!(bs[..512], head);
+    assert_eq!(bs[512..1024], vec![0u8; 512]);
+    assert_eq!(bs[1024..], tail);
+
+    Ok(())
+}
+
+/// Allocate a file should succeed, the file should be read as zeros.
+pub async fn test_allocate(op: Operator) -> Result<()> {
+    if !op.info().full_capability().allocate {
+        return Ok(());
+    }
+
+    let path = TEST_FIXTURE.new_file_path();
+    op.allocate(&path, 2048)
+        .await
+        .expect("allocate must succeed");
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.content_length(), 2048);
+    let bs = op.read(&path).await?.to_vec();
+    assert_eq!(bs, vec![0u8; 2048]);
+
+    if op.info().full_capability().write_at {
+        let page = vec![1u8; 512];
+        op.write_at(&path, 512, page.clone())
+            .await
+            .expect("write at must succeed");
+
+        let bs = op.read(&path).await?.to_vec();
+        assert_eq!(
+            bs.len(),
+            2048,
+            "write inside allocated file must not grow it"
+        );
+        assert_eq!(bs[512..1024], page);
+        assert_eq!(bs[1024..], vec![0u8; 1024]);
+    }
+
+    Ok(())
+}
+
 /// Write a single file with content type should succeed.
 pub async fn test_write_with_content_type(op: Operator) -> Result<()> {
     if !op.info().full_capability().write_with_content_type {
@@ -223,14 +377,132 @@ pub async fn test_write_with_user_metadata(op: Operator) -> Result<()> {
     let meta = op.stat(&path).await.expect("stat must succeed");
     let resp_meta = meta.user_metadata().expect("meta data must exist");
 
+    // Services could return extra user metadata, like posix attributes of fs.
+    let resp_meta = resp_meta
+        .iter()
+        .filter(|(k, _)| target_user_metadata.iter().any(|(tk, _)| tk == *k))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<HashMap<_, _>>();
     assert_eq!(
-        *resp_meta,
+        resp_meta,
         target_user_metadata.into_iter().collect::<HashMap<_, _>>()
     );
 
     Ok(())
 }
 
+/// Write a file with if_none_match will fail if the file already exists.
+pub async fn test_write_with_if_none_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_if_none_match {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write_with(&path, content.clone())
+        .if_none_match("*")
+        .await
+        .expect("write must succeed");
+
+    let res = op.write_with(&path, content).if_none_match("*").await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    Ok(())
+}
+
+/// Write a file with if_generation_match `0` will only succeed if the file doesn't exist.
+pub async fn test_write_with_if_generation_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_if_generation_match {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write_with(&path, content.clone())
+        .if_generation_match(0)
+        .await
+        .expect("write must succeed");
+
+    let res = op.write_with(&path, content).if_generation_match(0).await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    Ok(())
+}
+
+/// Concat files should produce the content of all sources in order.
+pub async fn test_concat(op: Operator) -> Result<()> {
+    let (path_a, content_a, _) = TEST_FIXTURE.new_file(op.clone());
+    let (path_b, content_b, _) = TEST_FIXTURE.new_file(op.clone());
+    let (path, _, _) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path_a, content_a.clone()).await?;
+    op.write(&path_b, content_b.clone()).await?;
+
+    op.concat(&path, &[&path_a, &path_b])
+        .await
+        .expect("concat must succeed");
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs, [content_a, content_b].concat());
+
+    Ok(())
+}
+
+/// Write a file with if_match will only succeed if the etag matches.
+pub async fn test_write_with_if_match(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_if_match {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    let etag = meta.etag().expect("etag must exist");
+
+    op.write_with(&path, content.clone())
+        .if_match(etag)
+        .await
+        .expect("write with matched etag must succeed");
+
+    let res = op
+        .write_with(&path, content)
+        .if_match("\"invalid_etag\"")
+        .await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    Ok(())
+}
+
+/// Write a file from local file should succeed.
+pub async fn test_write_from_file(op: Operator) -> Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    let local_path = env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    tokio::fs::write(&local_path, &content).await?;
+
+    let res = op.write_from_file(&path, &local_path).await;
+    tokio::fs::remove_file(&local_path).await?;
+    res.expect("write from file must succeed");
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    Ok(())
+}
+
 /// Delete existing file should succeed.
 pub async fn test_writer_abort(op: Operator) -> Result<()> {
     let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
@@ -285,6 +557,32 @@ pub async fn test_writer_abort_with_concurrent(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Dropped writer should be aborted and not write actual file.
+pub async fn test_writer_drop_without_close(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    // Skip if abort is not supported.
+    let mut writer = op.writer_with(&path).concurrent(2).await?;
+    writer.write(content.clone()).await?;
+    if let Err(e) = writer.abort().await {
+        assert_eq!(e.kind(), ErrorKind::Unsupported);
+        return Ok(());
+    }
+
+    let mut writer = op.writer_with(&path).concurrent(2).await?;
+    writer.write(content).await?;
+    drop(writer);
+
+    // Abort is running in background, wait for a while.
+    for _ in 0..10 {
+        if !op.is_exist(&path).await? {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("dropped writer must not write actual file")
+}
+
 /// Append data into writer
 pub async fn test_writer_write(op: Operator) -> Result<()> {
     if !(op.info().full_capability().write_can_multi) {
@@ -525,6 +823,42 @@ pub async fn test_writer_futures_copy_with_concurrent(op: Operator) -> Result<()
     Ok(())
 }
 
+/// Writer driven by a sequence of actions should write expected content.
+pub async fn test_writer_with_actions(op: Operator) -> Result<()> {
+    if !(op.info().full_capability().write_can_multi) {
+        return Ok(());
+    }
+
+    let size = 5 * 1024 * 1024;
+    let cases = [
+        vec![
+            WriteAction::Write(size),
+            WriteAction::Flush,
+            WriteAction::Write(size),
+            WriteAction::Close,
+        ],
+        vec![WriteAction::Write(size), WriteAction::Abort],
+    ];
+
+    for actions in cases.iter() {
+        let checker = WriteChecker::with_actions(actions);
+
+        let path = TEST_FIXTURE.new_file_path();
+        let w = op.writer_with(&path).concurrent(2).await?;
+        checker.check_writer(&op, &path, w, actions).await;
+
+        if !actions.contains(&WriteAction::Abort) {
+            let path = TEST_FIXTURE.new_file_path();
+            let w = op.writer_with(&path).concurrent(2).await?;
+            checker
+                .check_futures_async_writer(&op, &path, w.into_futures_async_write(), actions)
+                .await;
+        }
+    }
+
+    Ok(())
+}
+
 /// Test append to a file must success.
 pub async fn test_write_with_append(op: Operator) -> Result<()> {
     let path = TEST_FIXTURE.new_file_path();
@@ -18,7 +18,7 @@
 use anyhow::Result;
 use log::debug;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
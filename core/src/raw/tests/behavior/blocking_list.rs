@@ -20,7 +20,7 @@ use std::collections::HashMap;
 use anyhow::Result;
 use log::debug;
 
-use crate::*;
+use super::*;
 
 pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
     let cap = op.info().full_capability();
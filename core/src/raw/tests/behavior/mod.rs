@@ -0,0 +1,117 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Behavior tests that every service should pass.
+//!
+//! The tests are driven by the [`Capability`] of given operator, so services
+//! outside opendal can certify themselves against the same checks:
+//!
+//! ```no_run
+//! use opendal::raw::tests::behavior;
+//! use opendal::services::Memory;
+//! use opendal::Operator;
+//!
+//! fn main() -> anyhow::Result<()> {
+//!     let op = Operator::new(Memory::default())?.finish();
+//!     behavior::run(op).exit()
+//! }
+//! ```
+//!
+//! The test binary should be declared with `harness = false`, and accepts the
+//! same arguments as libtest like `cargo test -- behavior::test_write`.
+
+#[macro_use]
+mod utils;
+pub use utils::Fixture;
+
+mod async_copy;
+mod async_create_dir;
+mod async_delete;
+// mod async_fuzz;
+mod async_list;
+mod async_model;
+mod async_presign;
+mod async_read;
+mod async_rename;
+mod async_stat;
+mod async_write;
+
+// Blocking test cases
+mod blocking_copy;
+mod blocking_create_dir;
+mod blocking_delete;
+mod blocking_list;
+mod blocking_read;
+mod blocking_rename;
+mod blocking_stat;
+mod blocking_write;
+
+use libtest_mimic::Arguments;
+use libtest_mimic::Conclusion;
+use libtest_mimic::Trial;
+use utils::*;
+
+use super::TEST_RUNTIME;
+use crate::*;
+
+/// TEST_FIXTURE tracks all paths created by behavior tests so that they can
+/// be cleaned up after tests.
+pub static TEST_FIXTURE: Fixture = Fixture::new();
+
+/// Build all behavior tests that given operator's capability supports.
+///
+/// The blocking tests require `op` to support blocking, please add
+/// [`BlockingLayer`][crate::layers::BlockingLayer] before if needed.
+pub fn tests(op: &Operator) -> Vec<Trial> {
+    let mut tests = Vec::new();
+
+    async_copy::tests(op, &mut tests);
+    async_create_dir::tests(op, &mut tests);
+    async_delete::tests(op, &mut tests);
+    async_list::tests(op, &mut tests);
+    async_model::tests(op, &mut tests);
+    async_presign::tests(op, &mut tests);
+    async_read::tests(op, &mut tests);
+    async_rename::tests(op, &mut tests);
+    async_stat::tests(op, &mut tests);
+    async_write::tests(op, &mut tests);
+
+    blocking_copy::tests(op, &mut tests);
+    blocking_create_dir::tests(op, &mut tests);
+    blocking_delete::tests(op, &mut tests);
+    blocking_list::tests(op, &mut tests);
+    blocking_read::tests(op, &mut tests);
+    blocking_rename::tests(op, &mut tests);
+    blocking_stat::tests(op, &mut tests);
+    blocking_write::tests(op, &mut tests);
+
+    tests
+}
+
+/// Run all behavior tests on given operator with the arguments from command line.
+///
+/// Files created during tests will be cleaned up after all tests finished.
+pub fn run(op: Operator) -> Conclusion {
+    let args = Arguments::from_args();
+
+    let conclusion = libtest_mimic::run(&args, tests(&op));
+
+    // Cleanup the fixtures.
+    TEST_RUNTIME.block_on(TEST_FIXTURE.cleanup(op));
+
+    conclusion
+}
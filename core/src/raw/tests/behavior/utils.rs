@@ -18,12 +18,12 @@
 use std::mem;
 use std::sync::Mutex;
 
+use crate::raw::tests::TEST_RUNTIME;
+use crate::raw::*;
+use crate::*;
 use futures::Future;
 use libtest_mimic::Failed;
 use libtest_mimic::Trial;
-use opendal::raw::tests::TEST_RUNTIME;
-use opendal::raw::*;
-use opendal::*;
 use rand::distributions::uniform::SampleRange;
 use rand::prelude::*;
 
@@ -38,7 +38,9 @@ pub fn gen_bytes_with_range(range: impl SampleRange<usize>) -> (Vec<u8>, usize)
 }
 
 pub fn gen_bytes(cap: Capability) -> (Vec<u8>, usize) {
-    let max_size = cap.write_total_max_size.unwrap_or(4 * 1024 * 1024);
+    let max_size = cap
+        .write_total_max_size
+        .map_or(4 * 1024 * 1024, |v| v.min(4 * 1024 * 1024));
     gen_bytes_with_range(1..max_size)
 }
 
@@ -74,7 +76,6 @@ where
     })
 }
 
-#[macro_export]
 macro_rules! async_trials {
     ($op:ident, $($test:ident),*) => {
         vec![$(
@@ -95,7 +96,6 @@ where
     })
 }
 
-#[macro_export]
 macro_rules! blocking_trials {
     ($op:ident, $($test:ident),*) => {
         vec![$(
@@ -104,8 +104,9 @@ macro_rules! blocking_trials {
     };
 }
 
+/// Fixture records the paths used by tests, and removes them in [`Fixture::cleanup`].
 pub struct Fixture {
-    pub paths: Mutex<Vec<String>>,
+    paths: Mutex<Vec<String>>,
 }
 
 impl Default for Fixture {
@@ -145,11 +146,12 @@ impl Fixture {
             .info()
             .full_capability()
             .write_total_max_size
-            .unwrap_or(4 * 1024 * 1024);
+            .map_or(4 * 1024 * 1024, |v| v.min(4 * 1024 * 1024));
 
         self.new_file_with_range(uuid::Uuid::new_v4().to_string(), 1..max_size)
     }
 
+    /// Create a new file with random content at given path
     pub fn new_file_with_path(
         &self,
         op: impl Into<Operator>,
@@ -160,7 +162,7 @@ impl Fixture {
             .info()
             .full_capability()
             .write_total_max_size
-            .unwrap_or(4 * 1024 * 1024);
+            .map_or(4 * 1024 * 1024, |v| v.min(4 * 1024 * 1024));
 
         self.new_file_with_range(path, 1..max_size)
     }
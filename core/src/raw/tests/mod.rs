@@ -25,6 +25,12 @@ mod write;
 pub use write::WriteAction;
 pub use write::WriteChecker;
 
+mod model;
+pub use model::ModelAction;
+pub use model::ModelChecker;
+
 mod utils;
 pub use utils::init_test_service;
 pub use utils::TEST_RUNTIME;
+
+pub mod behavior;
@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use bytes::Bytes;
+use futures::TryStreamExt;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::RngCore;
+use rand::SeedableRng;
+
+use crate::*;
+
+/// Paths that model actions will be applied on.
+///
+/// The pool is small by design so that actions will conflict with each other
+/// frequently. None of the files is the parent dir of another file.
+const MODEL_PATHS: &[&str] = &["a", "b", "d/a", "d/b", "d/e/a"];
+
+/// Max size of content written by [`ModelAction::Write`].
+const MODEL_MAX_SIZE: usize = 4 * 1024;
+
+/// ModelAction represents an action applied on both service and model.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ModelAction {
+    /// Write represents writing random content with given size into path.
+    Write(String, usize),
+    /// Read represents reading the whole content of path.
+    Read(String),
+    /// Delete represents deleting path.
+    Delete(String),
+    /// Rename represents renaming a path from the first to the second.
+    Rename(String, String),
+    /// List represents listing all files recursively.
+    List,
+}
+
+/// ModelChecker is used to check the consistency of a service by comparing it
+/// with an in-memory model.
+///
+/// All randomness comes from the given seed, please use the seed in panic
+/// message to reproduce a failed check.
+pub struct ModelChecker {
+    seed: u64,
+    rng: StdRng,
+    files: BTreeMap<String, Bytes>,
+}
+
+impl ModelChecker {
+    /// Create a new model checker with given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Return the seed of this model checker.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generate given count of random actions that supported by capability.
+    pub fn actions(&mut self, cap: Capability, count: usize) -> Vec<ModelAction> {
+        let mut kinds = vec![0, 1, 2];
+        if cap.rename {
+            kinds.push(3);
+        }
+        if cap.list && cap.list_with_recursive {
+            kinds.push(4);
+        }
+
+        let mut actions = Vec::with_capacity(count);
+        for _ in 0..count {
+            let path = self.random_path();
+            let action = match kinds[self.rng.gen_range(0..kinds.len())] {
+                0 => ModelAction::Write(path, self.rng.gen_range(0..=MODEL_MAX_SIZE)),
+                1 => ModelAction::Read(path),
+                2 => ModelAction::Delete(path),
+                3 => {
+                    let mut to = self.random_path();
+                    while to == path {
+                        to = self.random_path();
+                    }
+                    ModelAction::Rename(path, to)
+                }
+                _ => ModelAction::List,
+            };
+            actions.push(action);
+        }
+        actions
+    }
+
+    fn random_path(&mut self) -> String {
+        MODEL_PATHS[self.rng.gen_range(0..MODEL_PATHS.len())].to_string()
+    }
+
+    /// Check will apply given actions on both `op` and the model under `dir`,
+    /// and assert that they are observable equivalent after every action.
+    ///
+    /// `dir` must be an empty dir like `abc/`. Check will panic if any check failed.
+    pub async fn check(&mut self, op: &Operator, dir: &str, actions: &[ModelAction]) {
+        for (idx, action) in actions.iter().enumerate() {
+            let ctx = format!("seed: {}, action {idx}: {action:?}", self.seed);
+
+            match action {
+                ModelAction::Write(path, size) => {
+                    let mut bs = vec![0; *size];
+                    self.rng.fill_bytes(&mut bs);
+                    let bs = Bytes::from(bs);
+
+                    op.write(&format!("{dir}{path}"), bs.clone())
+                        .await
+                        .unwrap_or_else(|err| panic!("write must succeed, {ctx}: {err:?}"));
+                    self.files.insert(path.clone(), bs);
+                }
+                ModelAction::Read(path) => {
+                    let result = op.read(&format!("{dir}{path}")).await;
+                    match self.files.get(path) {
+                        Some(expected) => {
+                            let actual = result
+                                .unwrap_or_else(|err| panic!("read must succeed, {ctx}: {err:?}"));
+                            assert_eq!(actual.to_bytes(), expected, "read content mismatch, {ctx}");
+                        }
+                        None => {
+                            let err = result.expect_err(&format!("read must fail, {ctx}"));
+                            assert_eq!(err.kind(), ErrorKind::NotFound, "{ctx}");
+                        }
+                    }
+                }
+                ModelAction::Delete(path) => {
+                    op.delete(&format!("{dir}{path}"))
+                        .await
+                        .unwrap_or_else(|err| panic!("delete must succeed, {ctx}: {err:?}"));
+                    self.files.remove(path);
+                }
+                ModelAction::Rename(from, to) => {
+                    let result = op
+                        .rename(&format!("{dir}{from}"), &format!("{dir}{to}"))
+                        .await;
+                    match self.files.remove(from) {
+                        Some(bs) => {
+                            result.unwrap_or_else(|err| {
+                                panic!("rename must succeed, {ctx}: {err:?}")
+                            });
+                            self.files.insert(to.clone(), bs);
+                        }
+                        None => {
+                            let err = result.expect_err(&format!("rename must fail, {ctx}"));
+                            assert_eq!(err.kind(), ErrorKind::NotFound, "{ctx}");
+                        }
+                    }
+                }
+                ModelAction::List => {
+                    let actual = op
+                        .lister_with(dir)
+                        .recursive(true)
+                        .await
+                        .unwrap_or_else(|err| panic!("list must succeed, {ctx}: {err:?}"))
+                        .try_filter(|entry| futures::future::ready(!entry.path().ends_with('/')))
+                        .map_ok(|entry| entry.path()[dir.len()..].to_string())
+                        .try_collect::<BTreeSet<_>>()
+                        .await
+                        .unwrap_or_else(|err| panic!("list must succeed, {ctx}: {err:?}"));
+                    let expected = self.files.keys().cloned().collect::<BTreeSet<_>>();
+                    assert_eq!(actual, expected, "list result mismatch, {ctx}");
+                }
+            }
+        }
+    }
+}
@@ -17,12 +17,15 @@
 
 use bytes::Bytes;
 use bytes::BytesMut;
+use futures::AsyncWriteExt;
 use rand::thread_rng;
 use rand::RngCore;
 use sha2::Digest;
 use sha2::Sha256;
 
-/// WriteAction represents a read action.
+use crate::*;
+
+/// WriteAction represents a write action.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum WriteAction {
     /// Write represents a write action with given input buf size.
@@ -31,6 +34,16 @@ pub enum WriteAction {
     ///
     /// The size is the input buf size, it's possible that the actual write size is smaller.
     Write(usize),
+    /// Flush represents a flush action.
+    ///
+    /// Only [`FuturesAsyncWriter`] supports flush, it will be ignored by [`Writer`].
+    Flush,
+    /// Abort represents an abort action, all actions after it will be ignored.
+    ///
+    /// Only [`Writer`] supports abort.
+    Abort,
+    /// Close represents a close action, all actions after it will be ignored.
+    Close,
 }
 
 /// WriteAction is used to check the correctness of the write process.
@@ -63,6 +76,19 @@ impl WriteChecker {
         }
     }
 
+    /// Create a new WriteChecker with a chunk for every [`WriteAction::Write`] in actions.
+    pub fn with_actions(actions: &[WriteAction]) -> Self {
+        Self::new(
+            actions
+                .iter()
+                .filter_map(|action| match action {
+                    WriteAction::Write(size) => Some(*size),
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
+
     /// Get the check's chunks.
     pub fn chunks(&self) -> &[Bytes] {
         &self.chunks
@@ -76,4 +102,97 @@ impl WriteChecker {
             "check failed: result is not expected"
         )
     }
+
+    /// Check the content of `path` after given count of chunks written.
+    async fn check_written(&self, op: &Operator, path: &str, written: usize) {
+        let actual = op.read(path).await.expect("read must succeed").to_bytes();
+        let expected = self.chunks[..written]
+            .iter()
+            .fold(BytesMut::new(), |mut acc, x| {
+                acc.extend_from_slice(x);
+                acc
+            });
+
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&actual)),
+            format!("{:x}", Sha256::digest(&expected)),
+            "check failed: result is not expected"
+        )
+    }
+
+    /// Check will check the correctness of the write process on `path` via given actions.
+    ///
+    /// The writer will be closed if actions doesn't end with abort or close. Aborted
+    /// writer must not leave any object at `path`.
+    ///
+    /// Check will panic if any check failed.
+    pub async fn check_writer(
+        &self,
+        op: &Operator,
+        path: &str,
+        mut w: Writer,
+        actions: &[WriteAction],
+    ) {
+        let mut written = 0;
+        for action in actions {
+            match action {
+                WriteAction::Write(_) => {
+                    w.write(self.chunks[written].clone())
+                        .await
+                        .expect("write must succeed");
+                    written += 1;
+                }
+                WriteAction::Flush => {}
+                WriteAction::Abort => {
+                    match w.abort().await {
+                        Ok(()) => assert!(
+                            !op.is_exist(path).await.expect("stat must succeed"),
+                            "check failed: aborted writer must not leave object"
+                        ),
+                        Err(err) => assert_eq!(
+                            err.kind(),
+                            ErrorKind::Unsupported,
+                            "abort must succeed: {err:?}"
+                        ),
+                    }
+                    return;
+                }
+                WriteAction::Close => break,
+            }
+        }
+
+        w.close().await.expect("close must succeed");
+        self.check_written(op, path, written).await;
+    }
+
+    /// Check will check the correctness of the write process on `path` via given actions.
+    ///
+    /// The writer will be closed if actions doesn't end with close.
+    ///
+    /// Check will panic if any check failed or abort found in actions.
+    pub async fn check_futures_async_writer(
+        &self,
+        op: &Operator,
+        path: &str,
+        mut w: FuturesAsyncWriter,
+        actions: &[WriteAction],
+    ) {
+        let mut written = 0;
+        for action in actions {
+            match action {
+                WriteAction::Write(_) => {
+                    w.write_all(&self.chunks[written])
+                        .await
+                        .expect("write must succeed");
+                    written += 1;
+                }
+                WriteAction::Flush => w.flush().await.expect("flush must succeed"),
+                WriteAction::Abort => panic!("FuturesAsyncWriter doesn't support abort"),
+                WriteAction::Close => break,
+            }
+        }
+
+        w.close().await.expect("close must succeed");
+        self.check_written(op, path, written).await;
+    }
 }
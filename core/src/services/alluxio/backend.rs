@@ -27,6 +27,7 @@ use serde::Serialize;
 use super::core::AlluxioCore;
 use super::error::parse_error;
 use super::lister::AlluxioLister;
+use super::reader::AlluxioReader;
 use super::writer::AlluxioWriter;
 use super::writer::AlluxioWriters;
 use crate::raw::*;
@@ -170,7 +171,7 @@ pub struct AlluxioBackend {
 }
 
 impl Access for AlluxioBackend {
-    type Reader = HttpBody;
+    type Reader = AlluxioReader;
     type Writer = AlluxioWriters;
     type Lister = oio::PageLister<AlluxioLister>;
     type BlockingReader = ();
@@ -184,11 +185,7 @@ impl Access for AlluxioBackend {
             .set_native_capability(Capability {
                 stat: true,
 
-                // FIXME:
-                //
-                // alluxio's read support is not implemented correctly
-                // We need to refactor by use [page_read](https://github.com/Alluxio/alluxio-py/blob/main/alluxio/const.py#L18)
-                read: false,
+                read: true,
 
                 write: true,
                 write_can_multi: true,
@@ -218,13 +215,15 @@ impl Access for AlluxioBackend {
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let stream_id = self.core.open_file(path).await?;
 
-        let resp = self.core.read(stream_id, args.range()).await?;
+        let resp = self.core.read(stream_id).await?;
         if !resp.status().is_success() {
             let (part, mut body) = resp.into_parts();
             let buf = body.to_buffer().await?;
             return Err(parse_error(Response::from_parts(part, buf)).await?);
         }
-        Ok((RpRead::new(), resp.into_body()))
+
+        let r = AlluxioReader::new(self.core.clone(), stream_id, resp.into_body(), args.range());
+        Ok((RpRead::new(), r))
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
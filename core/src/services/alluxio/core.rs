@@ -288,10 +288,11 @@ impl AlluxioCore {
         }
     }
 
-    /// TODO: we should implement range support correctly.
+    /// Read the opened stream from the beginning.
     ///
-    /// Please refer to [alluxio-py](https://github.com/Alluxio/alluxio-py/blob/main/alluxio/const.py#L18)
-    pub async fn read(&self, stream_id: u64, _: BytesRange) -> Result<Response<HttpBody>> {
+    /// The proxy doesn't support range, see [`AlluxioReader`](super::reader::AlluxioReader)
+    /// for how ranges are served.
+    pub async fn read(&self, stream_id: u64) -> Result<Response<HttpBody>> {
         let req = Request::post(format!(
             "{}/api/v1/streams/{}/read",
             self.endpoint, stream_id,
@@ -0,0 +1,94 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::sync::Arc;
+
+use bytes::Buf;
+
+use super::core::AlluxioCore;
+use crate::raw::*;
+use crate::*;
+
+/// AlluxioReader reads the range out of an opened stream.
+///
+/// The stream read API of alluxio proxy always starts from the beginning of
+/// the file, so we skip the data before the range and stop at the end of it.
+pub struct AlluxioReader {
+    core: Arc<AlluxioCore>,
+
+    stream_id: u64,
+    body: HttpBody,
+    /// The bytes should be skipped before returning data.
+    skip: u64,
+    /// The bytes left to return, `None` means read until the end.
+    remaining: Option<u64>,
+    closed: bool,
+}
+
+impl AlluxioReader {
+    pub fn new(core: Arc<AlluxioCore>, stream_id: u64, body: HttpBody, range: BytesRange) -> Self {
+        AlluxioReader {
+            core,
+            stream_id,
+            body,
+            skip: range.offset(),
+            remaining: range.size(),
+            closed: false,
+        }
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        if !self.closed {
+            self.closed = true;
+            self.core.close(self.stream_id).await?;
+        }
+        Ok(())
+    }
+}
+
+impl oio::Read for AlluxioReader {
+    async fn read(&mut self) -> Result<Buffer> {
+        loop {
+            if self.remaining == Some(0) {
+                self.close().await?;
+                return Ok(Buffer::new());
+            }
+
+            let mut bs = self.body.read().await?;
+            if bs.is_empty() {
+                self.close().await?;
+                return Ok(bs);
+            }
+
+            if self.skip > 0 {
+                let n = self.skip.min(bs.len() as u64);
+                self.skip -= n;
+                bs.advance(n as usize);
+                if bs.is_empty() {
+                    continue;
+                }
+            }
+
+            if let Some(remaining) = self.remaining.as_mut() {
+                let n = (*remaining).min(bs.len() as u64);
+                *remaining -= n;
+                bs.truncate(n as usize);
+            }
+
+            return Ok(bs);
+        }
+    }
+}
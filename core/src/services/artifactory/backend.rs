@@ -0,0 +1,413 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use bytes::Buf;
+use http::Response;
+use http::StatusCode;
+use log::debug;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::core::*;
+use super::error::parse_error;
+use super::lister::ArtifactoryLister;
+use super::writer::ArtifactoryWriter;
+use super::writer::ArtifactoryWriters;
+use crate::raw::*;
+use crate::*;
+
+/// Config for JFrog Artifactory services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ArtifactoryConfig {
+    /// endpoint of this backend, e.g. `https://example.jfrog.io/artifactory`.
+    pub endpoint: Option<String>,
+    /// repository of this backend.
+    ///
+    /// All operations will happen under this repository.
+    pub repository: Option<String>,
+    /// root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub root: Option<String>,
+    /// username of this backend.
+    pub username: Option<String>,
+    /// password of this backend.
+    pub password: Option<String>,
+    /// access token of this backend.
+    ///
+    /// If access token is set, username and password will be ignored.
+    pub access_token: Option<String>,
+}
+
+impl Debug for ArtifactoryConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("ArtifactoryConfig");
+
+        ds.field("endpoint", &self.endpoint);
+        ds.field("repository", &self.repository);
+        ds.field("root", &self.root);
+        ds.field("username", &self.username);
+
+        if self.password.is_some() {
+            ds.field("password", &"<redacted>");
+        }
+        if self.access_token.is_some() {
+            ds.field("access_token", &"<redacted>");
+        }
+
+        ds.finish()
+    }
+}
+
+impl Configurator for ArtifactoryConfig {
+    type Builder = ArtifactoryBuilder;
+    fn into_builder(self) -> Self::Builder {
+        ArtifactoryBuilder {
+            config: self,
+            http_client: None,
+        }
+    }
+}
+
+/// [JFrog Artifactory](https://jfrog.com/artifactory/)'s REST API support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct ArtifactoryBuilder {
+    config: ArtifactoryConfig,
+
+    http_client: Option<HttpClient>,
+}
+
+impl Debug for ArtifactoryBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("ArtifactoryBuilder");
+
+        d.field("config", &self.config);
+        d.finish_non_exhaustive()
+    }
+}
+
+impl ArtifactoryBuilder {
+    /// Set the endpoint of this backend.
+    ///
+    /// Endpoint should be the full uri of artifactory, e.g.
+    ///
+    /// - `https://example.jfrog.io/artifactory`
+    /// - `http://127.0.0.1:8081/artifactory`
+    ///
+    /// If user inputs endpoint without scheme, we will prepend `https://` to it.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.config.endpoint = if endpoint.is_empty() {
+            None
+        } else {
+            Some(endpoint.trim_end_matches('/').to_string())
+        };
+        self
+    }
+
+    /// Set the repository of this backend.
+    ///
+    /// All operations will happen under this repository. It is required. e.g. `generic-local`
+    pub fn repository(mut self, repository: &str) -> Self {
+        self.config.repository = if repository.is_empty() {
+            None
+        } else {
+            Some(repository.trim_matches('/').to_string())
+        };
+        self
+    }
+
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set the username of this backend.
+    pub fn username(mut self, username: &str) -> Self {
+        self.config.username = if username.is_empty() {
+            None
+        } else {
+            Some(username.to_string())
+        };
+        self
+    }
+
+    /// Set the password of this backend.
+    pub fn password(mut self, password: &str) -> Self {
+        self.config.password = if password.is_empty() {
+            None
+        } else {
+            Some(password.to_string())
+        };
+        self
+    }
+
+    /// Set the access token of this backend.
+    ///
+    /// Access token will be sent via `Authorization: Bearer <token>`, and takes
+    /// precedence over username and password.
+    pub fn access_token(mut self, access_token: &str) -> Self {
+        self.config.access_token = if access_token.is_empty() {
+            None
+        } else {
+            Some(access_token.to_string())
+        };
+        self
+    }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+}
+
+impl Builder for ArtifactoryBuilder {
+    const SCHEME: Scheme = Scheme::Artifactory;
+    type Config = ArtifactoryConfig;
+
+    /// Builds the backend and returns the result of ArtifactoryBackend.
+    fn build(self) -> Result<impl Access> {
+        debug!("backend build started: {:?}", &self);
+
+        let root = normalize_root(&self.config.root.clone().unwrap_or_default());
+        debug!("backend use root {}", &root);
+
+        let endpoint = match &self.config.endpoint {
+            Some(endpoint) => {
+                if endpoint.starts_with("http") {
+                    endpoint.to_string()
+                } else {
+                    format!("https://{endpoint}")
+                }
+            }
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "endpoint is empty")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Artifactory))
+            }
+        };
+        debug!("backend use endpoint {}", &endpoint);
+
+        let repository = match &self.config.repository {
+            Some(repository) => repository.to_string(),
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "repository is empty")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Artifactory))
+            }
+        };
+        debug!("backend use repository {}", &repository);
+
+        let authorization = match (
+            &self.config.access_token,
+            &self.config.username,
+            &self.config.password,
+        ) {
+            (Some(token), _, _) => Some(format_authorization_by_bearer(token)?),
+            (None, Some(username), password) => Some(format_authorization_by_basic(
+                username,
+                password.as_deref().unwrap_or_default(),
+            )?),
+            (None, None, _) => None,
+        };
+
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Artifactory)
+            })?
+        };
+
+        Ok(ArtifactoryBackend {
+            core: Arc::new(ArtifactoryCore {
+                root,
+                endpoint,
+                repository,
+                authorization,
+                client,
+            }),
+        })
+    }
+}
+
+/// Backend for Artifactory services.
+#[derive(Debug, Clone)]
+pub struct ArtifactoryBackend {
+    core: Arc<ArtifactoryCore>,
+}
+
+impl Access for ArtifactoryBackend {
+    type Reader = HttpBody;
+    type Writer = ArtifactoryWriters;
+    type Lister = oio::PageLister<ArtifactoryLister>;
+    type BlockingReader = ();
+    type BlockingWriter = ();
+    type BlockingLister = ();
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        let mut am = AccessorInfo::default();
+        am.set_scheme(Scheme::Artifactory)
+            .set_root(&self.core.root)
+            .set_native_capability(Capability {
+                stat: true,
+
+                create_dir: true,
+
+                read: true,
+
+                write: true,
+                write_can_empty: true,
+                write_with_content_type: true,
+
+                delete: true,
+                copy: true,
+                rename: true,
+
+                list: true,
+                list_with_limit: true,
+                list_with_recursive: true,
+
+                ..Default::default()
+            });
+
+        am.into()
+    }
+
+    async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
+        let resp = self.core.artifactory_create_folder(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::CREATED => Ok(RpCreateDir::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        let resp = self.core.artifactory_get_file_info(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body();
+
+                let info: FileInfo =
+                    serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+
+                // Artifactory returns folder info for both `dir` and `dir/`, but
+                // a file must not be returned for a dir path.
+                if path.ends_with('/') && !info.is_folder() {
+                    return Err(Error::new(ErrorKind::NotFound, "dir not found"));
+                }
+
+                parse_file_info(info).map(RpStat::new)
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let resp = self.core.artifactory_download(path, args.range()).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
+            }
+            _ => {
+                let (part, mut body) = resp.into_parts();
+                let buf = body.to_buffer().await?;
+                Err(parse_error(Response::from_parts(part, buf)).await?)
+            }
+        }
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let writer = ArtifactoryWriter::new(self.core.clone(), path, args);
+
+        let w = oio::OneShotWriter::new(writer);
+
+        Ok((RpWrite::default(), w))
+    }
+
+    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let resp = self.core.artifactory_delete(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(RpDelete::default()),
+            // Allow 404 when deleting a non-existing object
+            StatusCode::NOT_FOUND => Ok(RpDelete::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        let resp = self.core.artifactory_copy(from, to).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => Ok(RpCopy::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let resp = self.core.artifactory_move(from, to).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => Ok(RpRename::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let l = ArtifactoryLister::new(self.core.clone(), path, args.recursive(), args.limit());
+
+        Ok((RpList::default(), oio::PageLister::new(l)))
+    }
+}
@@ -0,0 +1,458 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use http::header;
+use http::Request;
+use http::Response;
+use md5::Digest;
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::raw::*;
+use crate::*;
+
+/// Header used by artifactory to verify the md5 checksum of deployed content.
+const X_CHECKSUM_MD5: &str = "X-Checksum-Md5";
+
+pub struct ArtifactoryCore {
+    pub root: String,
+    pub endpoint: String,
+    pub repository: String,
+    pub authorization: Option<String>,
+
+    pub client: HttpClient,
+}
+
+impl Debug for ArtifactoryCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArtifactoryCore")
+            .field("root", &self.root)
+            .field("endpoint", &self.endpoint)
+            .field("repository", &self.repository)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ArtifactoryCore {
+    #[inline]
+    pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
+        self.client.send(req).await
+    }
+
+    fn sign(&self, mut req: http::request::Builder) -> http::request::Builder {
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+        req
+    }
+
+    fn item_url(&self, path: &str) -> String {
+        let p = build_abs_path(&self.root, path);
+
+        format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.repository,
+            percent_encode_path(&p)
+        )
+    }
+}
+
+impl ArtifactoryCore {
+    pub async fn artifactory_get_file_info(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/api/storage/{}/{}",
+            self.endpoint,
+            self.repository,
+            percent_encode_path(p.trim_end_matches('/'))
+        );
+
+        let req = self.sign(Request::get(&url));
+
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
+    pub async fn artifactory_download(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<HttpBody>> {
+        let url = self.item_url(path);
+
+        let mut req = self.sign(Request::get(&url));
+
+        if !range.is_full() {
+            req = req.header(header::RANGE, range.to_header());
+        }
+
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.client.fetch(req).await
+    }
+
+    pub async fn artifactory_deploy(
+        &self,
+        path: &str,
+        size: u64,
+        args: &OpWrite,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let url = self.item_url(path);
+
+        let mut req = self.sign(Request::put(&url));
+
+        req = req.header(header::CONTENT_LENGTH, size);
+
+        if let Some(mime) = args.content_type() {
+            req = req.header(header::CONTENT_TYPE, mime);
+        }
+
+        // Let artifactory verify the integrity of the deployed content.
+        req = req.header(X_CHECKSUM_MD5, format_md5_hex(&body));
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
+    pub async fn artifactory_create_folder(&self, path: &str) -> Result<Response<Buffer>> {
+        // Artifactory creates a folder while deploying to a path ends with `/`.
+        let url = self.item_url(path);
+
+        let req = self.sign(Request::put(&url));
+
+        let req = req
+            .header(header::CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
+    pub async fn artifactory_delete(&self, path: &str) -> Result<Response<Buffer>> {
+        let url = self.item_url(path);
+
+        let req = self.sign(Request::delete(&url));
+
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
+    pub async fn artifactory_copy(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
+        self.artifactory_copy_or_move("copy", from, to).await
+    }
+
+    pub async fn artifactory_move(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
+        self.artifactory_copy_or_move("move", from, to).await
+    }
+
+    async fn artifactory_copy_or_move(
+        &self,
+        action: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Response<Buffer>> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+
+        let url = format!(
+            "{}/api/{}/{}/{}?to=/{}/{}",
+            self.endpoint,
+            action,
+            self.repository,
+            percent_encode_path(&from),
+            self.repository,
+            percent_encode_path(&to),
+        );
+
+        let req = self.sign(Request::post(&url));
+
+        let req = req
+            .header(header::CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
+    /// Search items via [AQL](https://jfrog.com/help/r/jfrog-rest-apis/artifactory-query-language).
+    ///
+    /// `path` is the abs dir path that ends with `/`, items under this path will be returned.
+    pub async fn artifactory_search_items(
+        &self,
+        path: &str,
+        recursive: bool,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}/api/search/aql", self.endpoint);
+
+        let query = build_aql_items_query(&self.repository, path, recursive, offset, limit);
+
+        let req = self.sign(Request::post(&url));
+
+        let req = req
+            .header(header::CONTENT_TYPE, "text/plain")
+            .header(header::CONTENT_LENGTH, query.len())
+            .body(Buffer::from(query))
+            .map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+}
+
+/// Build the AQL query to find items under given dir.
+///
+/// AQL stores the parent path of an item in `path` (`.` for repository root)
+/// and its basename in `name`.
+pub(super) fn build_aql_items_query(
+    repository: &str,
+    path: &str,
+    recursive: bool,
+    offset: usize,
+    limit: usize,
+) -> String {
+    let dir = path.trim_end_matches('/');
+
+    let criteria = match (dir.is_empty(), recursive) {
+        (true, false) => json!({"repo": repository, "path": ".", "type": "any"}),
+        (true, true) => json!({"repo": repository, "type": "any"}),
+        (false, false) => json!({"repo": repository, "path": dir, "type": "any"}),
+        (false, true) => json!({
+            "repo": repository,
+            "$or": [{"path": dir}, {"path": {"$match": format!("{dir}/*")}}],
+            "type": "any",
+        }),
+    };
+
+    format!(
+        r#"items.find({criteria}).include("repo","path","name","type","size","modified","actual_md5").sort({{"$asc":["path","name"]}}).offset({offset}).limit({limit})"#
+    )
+}
+
+pub(super) fn format_md5_hex(bs: &Buffer) -> String {
+    let mut hasher = md5::Md5::new();
+    for b in bs.clone() {
+        hasher.update(&b);
+    }
+
+    format!("{:x}", hasher.finalize())
+}
+
+/// Response of [File Info](https://jfrog.com/help/r/jfrog-rest-apis/file-info)
+/// and [Folder Info](https://jfrog.com/help/r/jfrog-rest-apis/folder-info).
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub(super) struct FileInfo {
+    pub size: Option<String>,
+    pub last_modified: Option<String>,
+    pub mime_type: Option<String>,
+    pub checksums: Option<Checksums>,
+    /// Only folder info contains children.
+    pub children: Option<IgnoredAny>,
+}
+
+impl FileInfo {
+    pub fn is_folder(&self) -> bool {
+        self.children.is_some()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub(super) struct Checksums {
+    pub md5: Option<String>,
+}
+
+pub(super) fn parse_file_info(info: FileInfo) -> Result<Metadata> {
+    if info.is_folder() {
+        let mut md = Metadata::new(EntryMode::DIR);
+        if let Some(v) = info.last_modified {
+            md.set_last_modified(parse_datetime_from_rfc3339(&v)?);
+        }
+        return Ok(md);
+    }
+
+    let mut md = Metadata::new(EntryMode::FILE);
+
+    if let Some(v) = info.size {
+        let size = v
+            .parse::<u64>()
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "parse content length").set_source(e))?;
+        md.set_content_length(size);
+    }
+    if let Some(v) = info.last_modified {
+        md.set_last_modified(parse_datetime_from_rfc3339(&v)?);
+    }
+    if let Some(v) = info.mime_type {
+        md.set_content_type(&v);
+    }
+    if let Some(v) = info.checksums.and_then(|v| v.md5) {
+        md.set_content_md5(&v);
+    }
+
+    Ok(md)
+}
+
+/// Response of AQL search.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub(super) struct AqlSearchResponse {
+    pub results: Vec<AqlItem>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub(super) struct AqlItem {
+    pub path: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub size: u64,
+    pub modified: Option<String>,
+    pub actual_md5: Option<String>,
+}
+
+impl AqlItem {
+    /// Returns the abs path of this item, dir will end with `/`.
+    pub fn abs_path(&self) -> String {
+        let mut p = if self.path == "." || self.path.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}/{}", self.path, self.name)
+        };
+        if self.type_field == "folder" {
+            p.push('/');
+        }
+        p
+    }
+}
+
+pub(super) fn parse_aql_item(item: &AqlItem) -> Result<Metadata> {
+    let mut md = if item.type_field == "folder" {
+        Metadata::new(EntryMode::DIR)
+    } else {
+        let mut md = Metadata::new(EntryMode::FILE);
+        md.set_content_length(item.size);
+        if let Some(v) = &item.actual_md5 {
+            md.set_content_md5(v);
+        }
+        md
+    };
+
+    if let Some(v) = &item.modified {
+        md.set_last_modified(parse_datetime_from_rfc3339(v)?);
+    }
+
+    Ok(md)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_aql_items_query() {
+        let cases = vec![
+            (
+                "",
+                false,
+                r#"items.find({"path":".","repo":"generic-local","type":"any"})"#,
+            ),
+            (
+                "",
+                true,
+                r#"items.find({"repo":"generic-local","type":"any"})"#,
+            ),
+            (
+                "a/b/",
+                false,
+                r#"items.find({"path":"a/b","repo":"generic-local","type":"any"})"#,
+            ),
+            (
+                "a/b/",
+                true,
+                r#"items.find({"$or":[{"path":"a/b"},{"path":{"$match":"a/b/*"}}],"repo":"generic-local","type":"any"})"#,
+            ),
+        ];
+
+        for (path, recursive, expected) in cases {
+            let query = build_aql_items_query("generic-local", path, recursive, 0, 10);
+            assert!(
+                query.starts_with(expected),
+                "query {query} for {path} is not expected"
+            );
+            assert!(query.ends_with(".offset(0).limit(10)"));
+        }
+    }
+
+    #[test]
+    fn test_parse_aql_search_response() -> Result<()> {
+        let bs = bytes::Bytes::from(
+            r#"{
+                "results": [
+                    {
+                        "repo": "generic-local",
+                        "path": ".",
+                        "name": "dir",
+                        "type": "folder",
+                        "size": 0,
+                        "modified": "2024-08-05T10:21:37.878Z"
+                    },
+                    {
+                        "repo": "generic-local",
+                        "path": "dir",
+                        "name": "file.txt",
+                        "type": "file",
+                        "size": 1024,
+                        "modified": "2024-08-05T10:21:37.878+08:00",
+                        "actual_md5": "0cc175b9c0f1b6a831c399e269772661"
+                    }
+                ],
+                "range": {
+                    "start_pos": 0,
+                    "end_pos": 2,
+                    "total": 2
+                }
+            }"#,
+        );
+
+        let resp: AqlSearchResponse =
+            serde_json::from_slice(&bs).map_err(new_json_deserialize_error)?;
+        assert_eq!(resp.results.len(), 2);
+
+        assert_eq!(resp.results[0].abs_path(), "dir/");
+        assert!(parse_aql_item(&resp.results[0])?.is_dir());
+
+        assert_eq!(resp.results[1].abs_path(), "dir/file.txt");
+        let md = parse_aql_item(&resp.results[1])?;
+        assert!(md.is_file());
+        assert_eq!(md.content_length(), 1024);
+        assert_eq!(md.content_md5(), Some("0cc175b9c0f1b6a831c399e269772661"));
+
+        Ok(())
+    }
+}
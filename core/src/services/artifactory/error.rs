@@ -0,0 +1,111 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Buf;
+use http::Response;
+use serde::Deserialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// ArtifactoryError is the error returned by Artifactory service.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct ArtifactoryError {
+    errors: Vec<ArtifactoryErrorDetail>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+#[allow(dead_code)]
+struct ArtifactoryErrorDetail {
+    status: u16,
+    message: String,
+}
+
+/// Parse error response into Error.
+pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
+    let (parts, mut body) = resp.into_parts();
+    let bs = body.copy_to_bytes(body.remaining());
+
+    let (kind, retryable) = match parts.status.as_u16() {
+        401 | 403 => (ErrorKind::PermissionDenied, false),
+        404 => (ErrorKind::NotFound, false),
+        // Artifactory returns 409 while conflicting with repository layout or
+        // checksum policy.
+        409 => (ErrorKind::ConditionNotMatch, false),
+        429 => (ErrorKind::RateLimited, true),
+        500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let message = match serde_json::from_slice::<ArtifactoryError>(&bs) {
+        Ok(artifactory_err) if !artifactory_err.errors.is_empty() => {
+            format!("{:?}", artifactory_err.errors)
+        }
+        _ => String::from_utf8_lossy(&bs).into_owned(),
+    };
+
+    let mut err = Error::new(kind, message);
+
+    err = with_error_response_context(err, parts);
+
+    if retryable {
+        err = err.set_temporary();
+    }
+
+    Ok(err)
+}
+
+#[cfg(test)]
+mod test {
+    use http::StatusCode;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_parse_error() {
+        let err_res = vec![
+            (
+                r#"{"errors": [{"status": 404, "message": "File not found."}]}"#,
+                ErrorKind::NotFound,
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                r#"{"errors": [{"status": 401, "message": "Bad credentials"}]}"#,
+                ErrorKind::PermissionDenied,
+                StatusCode::UNAUTHORIZED,
+            ),
+            (
+                "Service Unavailable",
+                ErrorKind::Unexpected,
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+        ];
+
+        for res in err_res {
+            let bs = bytes::Bytes::from(res.0);
+            let body = Buffer::from(bs);
+            let resp = Response::builder().status(res.2).body(body).unwrap();
+
+            let err = parse_error(resp).await;
+
+            assert!(err.is_ok());
+            assert_eq!(err.unwrap().kind(), res.1);
+        }
+    }
+}
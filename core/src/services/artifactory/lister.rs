@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use bytes::Buf;
+
+use super::core::parse_aql_item;
+use super::core::AqlSearchResponse;
+use super::core::ArtifactoryCore;
+use super::error::parse_error;
+use crate::raw::oio::Entry;
+use crate::raw::*;
+use crate::Result;
+
+/// The default page size of AQL search.
+const DEFAULT_LIST_LIMIT: usize = 1000;
+
+pub struct ArtifactoryLister {
+    core: Arc<ArtifactoryCore>,
+
+    path: String,
+    recursive: bool,
+    limit: usize,
+}
+
+impl ArtifactoryLister {
+    pub(super) fn new(
+        core: Arc<ArtifactoryCore>,
+        path: &str,
+        recursive: bool,
+        limit: Option<usize>,
+    ) -> Self {
+        ArtifactoryLister {
+            core,
+            path: path.to_string(),
+            recursive,
+            limit: limit.unwrap_or(DEFAULT_LIST_LIMIT),
+        }
+    }
+}
+
+impl oio::PageList for ArtifactoryLister {
+    async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let offset = ctx.token.parse::<usize>().unwrap_or(0);
+
+        let abs_path = build_abs_path(&self.core.root, &self.path);
+
+        let resp = self
+            .core
+            .artifactory_search_items(&abs_path, self.recursive, offset, self.limit)
+            .await?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body();
+        let output: AqlSearchResponse =
+            serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+
+        let n = output.results.len();
+
+        for item in output.results {
+            // Skip the repository root itself.
+            if item.name == "." {
+                continue;
+            }
+
+            let path = build_rel_path(&self.core.root, &item.abs_path());
+            let md = parse_aql_item(&item)?;
+
+            ctx.entries.push_back(Entry::new(&path, md));
+        }
+
+        if n < self.limit {
+            ctx.done = true;
+        }
+        ctx.token = (offset + n).to_string();
+
+        Ok(())
+    }
+}
@@ -19,6 +19,7 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
@@ -28,18 +29,23 @@ use http::Response;
 use http::StatusCode;
 use log::debug;
 use reqsign::AzureStorageConfig;
-use reqsign::AzureStorageLoader;
+use reqsign::AzureStorageCredential;
 use reqsign::AzureStorageSigner;
 use serde::Deserialize;
 use serde::Serialize;
 use sha2::Digest;
 use sha2::Sha256;
 
+use super::core::check_block_ids;
+use super::core::parse_container_acl;
+use super::core::parse_object_retention;
+use super::core::ListContainersOutput;
 use super::error::parse_error;
 use super::lister::AzblobLister;
 use super::writer::AzblobWriter;
 use crate::raw::*;
 use crate::services::azblob::core::AzblobCore;
+use crate::services::azblob::core::AzblobCredentialProvider;
 use crate::services::azblob::writer::AzblobWriters;
 use crate::*;
 
@@ -54,9 +60,13 @@ const KNOWN_AZBLOB_ENDPOINT_SUFFIX: &[&str] = &[
 ];
 
 const AZBLOB_BATCH_LIMIT: usize = 256;
+/// The max size of pages that can be written in a single Put Page request.
+const AZBLOB_PUT_PAGE_LIMIT: usize = 4 * 1024 * 1024;
 
 /// Azure Storage Blob services support.
 #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
 pub struct AzblobConfig {
     /// The root of Azblob service backend.
     ///
@@ -120,9 +130,15 @@ impl Debug for AzblobConfig {
 
 impl Configurator for AzblobConfig {
     type Builder = AzblobBuilder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("container"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         AzblobBuilder {
             config: self,
+            customized_credential_load: None,
             http_client: None,
         }
     }
@@ -132,6 +148,9 @@ impl Configurator for AzblobConfig {
 #[derive(Default, Clone)]
 pub struct AzblobBuilder {
     config: AzblobConfig,
+
+    customized_credential_load:
+        Option<Arc<dyn ProvideCredential<Credential = AzureStorageCredential>>>,
     http_client: Option<HttpClient>,
 }
 
@@ -300,6 +319,19 @@ impl AzblobBuilder {
         self
     }
 
+    /// Adding a customized credential load for service.
+    ///
+    /// If customized_credential_load has been set, we will ignore all other
+    /// credential load methods. The loaded credential will be cached and the
+    /// provider will be called again once the credential is expired.
+    pub fn customized_credential_load(
+        mut self,
+        cred: Arc<dyn ProvideCredential<Credential = AzureStorageCredential>>,
+    ) -> Self {
+        self.customized_credential_load = Some(cred);
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -490,7 +522,11 @@ impl Builder for AzblobBuilder {
             }
         };
 
-        let cred_loader = AzureStorageLoader::new(config_loader);
+        let account_name = config_loader.account_name.clone();
+        let cred_loader = match self.customized_credential_load {
+            Some(v) => CredentialLoader::from_arc(v),
+            None => CredentialLoader::new(AzblobCredentialProvider::new(config_loader)),
+        };
 
         let signer = AzureStorageSigner::new();
 
@@ -512,8 +548,9 @@ impl Builder for AzblobBuilder {
                 loader: cred_loader,
                 signer,
                 batch_max_operations,
+                account_name,
+                user_delegation_key: Mutex::new(None),
             }),
-            has_sas_token: self.config.sas_token.is_some(),
         })
     }
 }
@@ -546,7 +583,6 @@ fn infer_storage_name_from_endpoint(endpoint: &str) -> Option<String> {
 #[derive(Debug, Clone)]
 pub struct AzblobBackend {
     core: Arc<AzblobCore>,
-    has_sas_token: bool,
 }
 
 impl Access for AzblobBackend {
@@ -561,6 +597,7 @@ impl Access for AzblobBackend {
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::Azblob)
             .set_root(&self.core.root)
+            .set_http_client(self.core.client.clone())
             .set_name(&self.core.container)
             .set_native_capability(Capability {
                 stat: true,
@@ -580,16 +617,35 @@ impl Access for AzblobBackend {
                 write_with_cache_control: true,
                 write_with_content_type: true,
 
+                stage_block: true,
+                commit_block_list: true,
+
+                retention: true,
+                acl: true,
+
+                write_at: true,
+                write_at_align_size: Some(512),
+                allocate: true,
+                allocate_align_size: Some(512),
+
+                create_bucket: true,
+                delete_bucket: true,
+                list_buckets: true,
+
                 delete: true,
                 copy: true,
 
                 list: true,
                 list_with_recursive: true,
+                list_has_content_length: true,
+                list_has_content_type: true,
+                list_has_etag: true,
+                list_has_last_modified: true,
 
-                presign: self.has_sas_token,
-                presign_stat: self.has_sas_token,
-                presign_read: self.has_sas_token,
-                presign_write: self.has_sas_token,
+                presign: true,
+                presign_stat: true,
+                presign_read: true,
+                presign_write: true,
 
                 batch: true,
                 batch_delete: true,
@@ -617,7 +673,10 @@ impl Access for AzblobBackend {
 
         let status = resp.status();
         match status {
-            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok((RpRead::new(), resp.into_body())),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
+            }
             _ => {
                 let (part, mut body) = resp.into_parts();
                 let buf = body.to_buffer().await?;
@@ -641,6 +700,128 @@ impl Access for AzblobBackend {
         Ok((RpWrite::default(), w))
     }
 
+    async fn stage_block(&self, path: &str, args: OpStageBlock) -> Result<RpStageBlock> {
+        check_block_ids([args.block_id()])?;
+
+        let block_id = args.block_id().to_string();
+        let size = args.body().len() as u64;
+        let resp = self
+            .core
+            .azblob_put_block(
+                path,
+                &block_id,
+                Some(size),
+                &OpWrite::default(),
+                args.into_body(),
+            )
+            .await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::CREATED | StatusCode::OK => Ok(RpStageBlock::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn commit_block_list(
+        &self,
+        path: &str,
+        args: OpCommitBlockList,
+    ) -> Result<RpCommitBlockList> {
+        check_block_ids(args.block_ids().iter().map(|v| v.as_str()))?;
+
+        let resp = self
+            .core
+            .azblob_complete_put_block_list(path, args.block_ids(), args.write())
+            .await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::CREATED | StatusCode::OK => Ok(RpCommitBlockList::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        let offset = args.offset();
+        let body = args.into_body();
+        let end = offset + body.len() as u64;
+
+        let mut written = 0;
+        let mut retried = false;
+        while written < body.len() {
+            let n = (body.len() - written).min(AZBLOB_PUT_PAGE_LIMIT);
+            let pages = body.slice(written..written + n);
+
+            let resp = self
+                .core
+                .azblob_put_page(path, offset + written as u64, pages)
+                .await?;
+            match resp.status() {
+                StatusCode::CREATED => {
+                    written += n;
+                    retried = false;
+                }
+                // Create the page blob or grow it to fit the whole write, then retry.
+                StatusCode::NOT_FOUND if !retried => {
+                    self.create_page_blob(path, end).await?;
+                    retried = true;
+                }
+                StatusCode::RANGE_NOT_SATISFIABLE if !retried => {
+                    self.grow_page_blob(path, end).await?;
+                    retried = true;
+                }
+                _ => return Err(parse_error(resp).await?),
+            }
+        }
+
+        Ok(RpWriteAt::default())
+    }
+
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        let resp = self
+            .core
+            .azblob_create_page_blob(path, args.size(), false)
+            .await?;
+        match resp.status() {
+            StatusCode::CREATED => Ok(RpAllocate::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn get_retention(&self, path: &str, _: OpGetRetention) -> Result<RpGetRetention> {
+        let resp = self
+            .core
+            .azblob_get_blob_properties(path, &OpStat::new())
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpGetRetention::new(parse_object_retention(resp.headers())?)),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        if let Some(retention) = args.retention() {
+            let resp = self
+                .core
+                .azblob_set_immutability_policy(path, retention)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+        }
+
+        if let Some(legal_hold) = args.legal_hold() {
+            let resp = self.core.azblob_set_legal_hold(path, legal_hold).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+        }
+
+        Ok(RpSetRetention::default())
+    }
+
     async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         let resp = self.core.azblob_delete_blob(path).await?;
 
@@ -675,21 +856,35 @@ impl Access for AzblobBackend {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
-        let mut req = match args.operation() {
-            PresignOperation::Stat(v) => self.core.azblob_head_blob_request(path, v)?,
-            PresignOperation::Read(v) => {
+        let (req, permissions) = match args.operation() {
+            PresignOperation::Stat(v) => (self.core.azblob_head_blob_request(path, v)?, "r"),
+            PresignOperation::Read(v) => (
                 self.core
-                    .azblob_get_blob_request(path, BytesRange::default(), v)?
-            }
-            PresignOperation::Write(_) => {
-                self.core
-                    .azblob_put_blob_request(path, None, &OpWrite::default(), Buffer::new())?
-            }
+                    .azblob_get_blob_request(path, BytesRange::default(), v)?,
+                "r",
+            ),
+            PresignOperation::Write(_) => (
+                self.core.azblob_put_blob_request(
+                    path,
+                    None,
+                    &OpWrite::default(),
+                    Buffer::new(),
+                )?,
+                "cw",
+            ),
         };
 
-        self.core.sign_query(&mut req).await?;
+        let query = self
+            .core
+            .presign_query(path, permissions, args.expire())
+            .await?;
 
-        let (parts, _) = req.into_parts();
+        let (mut parts, _) = req.into_parts();
+        let uri = parts.uri.to_string();
+        let sep = if uri.contains('?') { '&' } else { '?' };
+        parts.uri = format!("{uri}{sep}{query}").parse().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "failed to build presigned uri").set_source(err)
+        })?;
 
         Ok(RpPresign::new(PresignedRequest::new(
             parts.method,
@@ -768,12 +963,138 @@ impl Access for AzblobBackend {
         }
         Ok(RpBatch::new(results))
     }
+
+    async fn get_acl(&self, path: &str, _: OpGetAcl) -> Result<RpGetAcl> {
+        check_container_acl_path(path)?;
+
+        let resp = self.core.azblob_get_container_acl().await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpGetAcl::new(parse_container_acl(resp.headers())?)),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        check_container_acl_path(path)?;
+
+        // Fetch the stored access policies first, or they will be removed
+        // by setting acl.
+        let resp = self.core.azblob_get_container_acl().await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let resp = self
+            .core
+            .azblob_set_container_acl(args.acl(), resp.into_body())
+            .await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetAcl::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn create_bucket(&self, _: OpCreateBucket) -> Result<RpCreateBucket> {
+        let resp = self.core.azblob_create_container().await?;
+        match resp.status() {
+            StatusCode::CREATED => Ok(RpCreateBucket::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn delete_bucket(&self, _: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        let resp = self.core.azblob_delete_container().await?;
+        match resp.status() {
+            StatusCode::ACCEPTED => Ok(RpDeleteBucket::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn list_buckets(&self, _: OpListBuckets) -> Result<RpListBuckets> {
+        let mut buckets = vec![];
+        let mut marker = String::new();
+
+        loop {
+            let resp = self.core.azblob_list_containers(&marker).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let output: ListContainersOutput =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+            buckets.extend(output.containers.container.into_iter().map(|v| v.name));
+
+            match output.next_marker {
+                Some(v) if !v.is_empty() && v != marker => marker = v,
+                _ => break,
+            }
+        }
+
+        Ok(RpListBuckets::new(buckets))
+    }
+}
+
+impl AzblobBackend {
+    /// Create the page blob with given size, it's fine if the blob has been
+    /// created by others concurrently.
+    async fn create_page_blob(&self, path: &str, size: u64) -> Result<()> {
+        let resp = self.core.azblob_create_page_blob(path, size, true).await?;
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::CONFLICT | StatusCode::PRECONDITION_FAILED => Ok(()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    /// Grow the page blob to at least given size.
+    ///
+    /// Page blobs can only be resized as a whole, we resize it with `If-Match`
+    /// so that concurrent writers will never shrink the blob grown by others.
+    async fn grow_page_blob(&self, path: &str, size: u64) -> Result<()> {
+        loop {
+            let resp = self
+                .core
+                .azblob_get_blob_properties(path, &OpStat::new())
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let current = parse_content_length(resp.headers())?.unwrap_or_default();
+            if current >= size {
+                return Ok(());
+            }
+            let etag = parse_etag(resp.headers())?.unwrap_or_default().to_string();
+
+            let resp = self.core.azblob_resize_page_blob(path, size, &etag).await?;
+            match resp.status() {
+                StatusCode::OK => return Ok(()),
+                // The blob has been changed by others, check the size again.
+                StatusCode::PRECONDITION_FAILED => continue,
+                _ => return Err(parse_error(resp).await?),
+            }
+        }
+    }
+}
+
+/// Azure only supports public access level of the whole container, which is
+/// represented by the root path.
+fn check_container_acl_path(path: &str) -> Result<()> {
+    if path != "/" {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "azblob only supports acl of the whole container, use `/` as path",
+        )
+        .with_context("path", path));
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::infer_storage_name_from_endpoint;
     use super::AzblobBuilder;
+    use super::AzblobConfig;
 
     #[test]
     fn test_infer_storage_name_from_endpoint() {
@@ -866,4 +1187,13 @@ SharedAccessSignature=sv=2021-01-01&ss=b&srt=c&sp=rwdlaciytfx&se=2022-01-01T11:0
         assert_eq!(builder.config.account_name, None);
         assert_eq!(builder.config.account_key, None);
     }
+
+    #[test]
+    fn test_config_deserialize_with_missing_fields() {
+        let cfg: AzblobConfig =
+            serde_json::from_str(r#"{"root": "/path/to/dir"}"#).expect("config must be valid");
+
+        assert_eq!(cfg.root.as_deref(), Some("/path/to/dir"));
+        assert_eq!(cfg.container, "");
+    }
 }
@@ -19,26 +19,35 @@ use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::fmt::Write;
+use std::sync::Mutex;
 use std::time::Duration;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use bytes::Buf;
 use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
 use http::header::HeaderName;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
+use http::HeaderMap;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::StatusCode;
+use reqsign::AzureStorageConfig;
 use reqsign::AzureStorageCredential;
 use reqsign::AzureStorageLoader;
 use reqsign::AzureStorageSigner;
 use serde::Deserialize;
 use serde::Serialize;
-use uuid::Uuid;
 
+use super::error::is_credential_expired;
+use super::error::parse_error;
+use super::sas::*;
 use crate::raw::*;
 use crate::*;
 
@@ -49,6 +58,13 @@ mod constants {
     pub const X_MS_COPY_SOURCE: &str = "x-ms-copy-source";
     pub const X_MS_BLOB_CACHE_CONTROL: &str = "x-ms-blob-cache-control";
     pub const X_MS_BLOB_CONDITION_APPENDPOS: &str = "x-ms-blob-condition-appendpos";
+    pub const X_MS_IMMUTABILITY_POLICY_UNTIL_DATE: &str = "x-ms-immutability-policy-until-date";
+    pub const X_MS_IMMUTABILITY_POLICY_MODE: &str = "x-ms-immutability-policy-mode";
+    pub const X_MS_LEGAL_HOLD: &str = "x-ms-legal-hold";
+    pub const X_MS_BLOB_PUBLIC_ACCESS: &str = "x-ms-blob-public-access";
+    pub const X_MS_BLOB_CONTENT_LENGTH: &str = "x-ms-blob-content-length";
+    pub const X_MS_PAGE_WRITE: &str = "x-ms-page-write";
+    pub const X_MS_RANGE: &str = "x-ms-range";
 
     // Server-side encryption with customer-provided headers
     pub const X_MS_ENCRYPTION_KEY: &str = "x-ms-encryption-key";
@@ -64,9 +80,14 @@ pub struct AzblobCore {
     pub encryption_key_sha256: Option<HeaderValue>,
     pub encryption_algorithm: Option<HeaderValue>,
     pub client: HttpClient,
-    pub loader: AzureStorageLoader,
+    pub loader: CredentialLoader<AzureStorageCredential>,
     pub signer: AzureStorageSigner,
     pub batch_max_operations: usize,
+
+    /// The account name used to build the canonicalized resource of SAS.
+    pub account_name: Option<String>,
+    /// The cached user delegation key used to sign user delegation SAS.
+    pub user_delegation_key: Mutex<Option<UserDelegationKey>>,
 }
 
 impl Debug for AzblobCore {
@@ -79,13 +100,49 @@ impl Debug for AzblobCore {
     }
 }
 
+impl SigningCredential for AzureStorageCredential {
+    fn is_valid(&self) -> bool {
+        AzureStorageCredential::is_valid(self)
+    }
+}
+
+/// AzblobCredentialProvider will load credential via reqsign.
+pub struct AzblobCredentialProvider {
+    config: AzureStorageConfig,
+}
+
+impl Debug for AzblobCredentialProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AzblobCredentialProvider")
+            .finish_non_exhaustive()
+    }
+}
+
+impl AzblobCredentialProvider {
+    /// Create a new provider via given config.
+    pub fn new(config: AzureStorageConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl ProvideCredential for AzblobCredentialProvider {
+    type Credential = AzureStorageCredential;
+
+    fn provide_credential(&self) -> BoxedFuture<'_, Result<Option<Self::Credential>>> {
+        Box::pin(async move {
+            // Always build a new loader so that the expired credential cached
+            // by reqsign will not be returned again.
+            AzureStorageLoader::new(self.config.clone())
+                .load()
+                .await
+                .map_err(new_request_credential_error)
+        })
+    }
+}
+
 impl AzblobCore {
     async fn load_credential(&self) -> Result<AzureStorageCredential> {
-        let cred = self
-            .loader
-            .load()
-            .await
-            .map_err(new_request_credential_error)?;
+        let cred = self.loader.load().await?;
 
         if let Some(cred) = cred {
             Ok(cred)
@@ -97,12 +154,77 @@ impl AzblobCore {
         }
     }
 
-    pub async fn sign_query<T>(&self, req: &mut Request<T>) -> Result<()> {
+    /// Build the SAS query of given path which will be expired after `expire`.
+    ///
+    /// - Sas token will be used directly if provided.
+    /// - Service SAS will be signed if account key is provided.
+    /// - User delegation SAS will be signed if bearer token is provided.
+    pub async fn presign_query(
+        &self,
+        path: &str,
+        permissions: &str,
+        expire: Duration,
+    ) -> Result<String> {
         let cred = self.load_credential().await?;
 
-        self.signer
-            .sign_query(req, Duration::from_secs(3600), &cred)
-            .map_err(new_request_sign_error)
+        let now = Utc::now();
+        // Allow some clock skew between client and azure.
+        let start = now - chrono::Duration::minutes(15);
+        let expiry = now
+            + chrono::Duration::from_std(expire).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "presign expire is out of range").set_source(err)
+            })?;
+        let p = build_abs_path(&self.root, path);
+
+        match cred {
+            AzureStorageCredential::SharedAccessSignature(token) => Ok(token),
+            AzureStorageCredential::SharedKey(account_name, account_key) => {
+                let resource = canonicalized_resource(&account_name, &self.container, &p);
+                build_service_sas(&account_key, &resource, permissions, start, expiry)
+            }
+            AzureStorageCredential::BearerToken(_, _) => {
+                let account_name = self.account_name.as_deref().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "account_name is required to sign user delegation sas",
+                    )
+                })?;
+                let key = self.load_user_delegation_key(start, expiry).await?;
+                let resource = canonicalized_resource(account_name, &self.container, &p);
+                build_user_delegation_sas(&key, &resource, permissions, start, expiry)
+            }
+        }
+    }
+
+    /// Load the cached user delegation key or request a new one if it will be
+    /// expired before `expiry`.
+    async fn load_user_delegation_key(
+        &self,
+        start: DateTime<Utc>,
+        expiry: DateTime<Utc>,
+    ) -> Result<UserDelegationKey> {
+        if let Some(key) = self.user_delegation_key.lock().unwrap().as_ref() {
+            if key.is_valid_until(expiry) {
+                return Ok(key.clone());
+            }
+        }
+
+        // Request key for longer so that it could be reused by later presign,
+        // azure allows user delegation key to be valid for at most 7 days.
+        let key_expiry = expiry
+            .max(start + chrono::Duration::days(1))
+            .min(Utc::now() + chrono::Duration::days(7));
+        let resp = self
+            .azblob_get_user_delegation_key(start, key_expiry)
+            .await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let key: UserDelegationKey = quick_xml::de::from_reader(resp.into_body().reader())
+            .map_err(new_xml_deserialize_error)?;
+        *self.user_delegation_key.lock().unwrap() = Some(key.clone());
+        Ok(key)
     }
 
     pub async fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
@@ -125,9 +247,16 @@ impl AzblobCore {
         self.signer.sign(req, &cred).map_err(new_request_sign_error)
     }
 
-    #[inline]
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
-        self.client.send(req).await
+        let resp = self.client.send(req).await?;
+
+        // Expire the cached credential if server reports that it has been
+        // expired, so that the retried request will sign with a new one.
+        if is_credential_expired(&resp) {
+            self.loader.expire();
+        }
+
+        Ok(resp)
     }
 
     pub fn insert_sse_headers(&self, mut req: http::request::Builder) -> http::request::Builder {
@@ -163,6 +292,31 @@ impl AzblobCore {
 }
 
 impl AzblobCore {
+    /// Get the user delegation key to sign user delegation SAS.
+    ///
+    /// ref: <https://learn.microsoft.com/en-us/rest/api/storageservices/get-user-delegation-key>
+    pub async fn azblob_get_user_delegation_key(
+        &self,
+        start: DateTime<Utc>,
+        expiry: DateTime<Utc>,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}/?restype=service&comp=userdelegationkey", self.endpoint);
+
+        let content = quick_xml::se::to_string(&KeyInfo {
+            start: format_sas_time(start),
+            expiry: format_sas_time(expiry),
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub fn azblob_get_blob_request(
         &self,
         path: &str,
@@ -366,10 +520,130 @@ impl AzblobCore {
         Ok(req)
     }
 
+    /// Create an empty page blob with given size, the existing blob will be
+    /// replaced unless `if_not_exists` is set.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/put-blob
+    pub async fn azblob_create_page_blob(
+        &self,
+        path: &str,
+        size: u64,
+        if_not_exists: bool,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url);
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        req = req
+            .header(CONTENT_LENGTH, 0)
+            .header(
+                HeaderName::from_static(constants::X_MS_BLOB_TYPE),
+                "PageBlob",
+            )
+            .header(constants::X_MS_BLOB_CONTENT_LENGTH, size);
+
+        if if_not_exists {
+            req = req.header(IF_NONE_MATCH, "*");
+        }
+
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Resize the page blob to given size if it's not changed since `etag`.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/set-blob-properties
+    pub async fn azblob_resize_page_blob(
+        &self,
+        path: &str,
+        size: u64,
+        etag: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=properties",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, 0)
+            .header(constants::X_MS_BLOB_CONTENT_LENGTH, size)
+            .header(IF_MATCH, etag)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Write pages into the page blob at given offset.
+    ///
+    /// # Notes
+    ///
+    /// - Offset and size must be aligned to 512 bytes.
+    /// - The maximum size of pages could be written is 4MB.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/put-page
+    pub async fn azblob_put_page(
+        &self,
+        path: &str,
+        offset: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=page",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url);
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        let size = body.len() as u64;
+        req = req
+            .header(CONTENT_LENGTH, size)
+            .header(constants::X_MS_PAGE_WRITE, "update")
+            .header(
+                constants::X_MS_RANGE,
+                BytesRange::new(offset, Some(size)).to_header(),
+            );
+
+        let mut req = req.body(body).map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub fn azblob_put_block_request(
         &self,
         path: &str,
-        block_id: Uuid,
+        block_id: &str,
         size: Option<u64>,
         args: &OpWrite,
         body: Buffer,
@@ -378,8 +652,7 @@ impl AzblobCore {
         // refer to https://learn.microsoft.com/en-us/rest/api/storageservices/put-block?tabs=microsoft-entra-id
         let p = build_abs_path(&self.root, path);
 
-        let encoded_block_id: String =
-            percent_encode_path(&BASE64_STANDARD.encode(block_id.as_bytes()));
+        let encoded_block_id: String = percent_encode_path(&BASE64_STANDARD.encode(block_id));
         let url = format!(
             "{}/{}/{}?comp=block&blockid={}",
             self.endpoint,
@@ -410,7 +683,7 @@ impl AzblobCore {
     pub async fn azblob_put_block(
         &self,
         path: &str,
-        block_id: Uuid,
+        block_id: &str,
         size: Option<u64>,
         args: &OpWrite,
         body: Buffer,
@@ -424,7 +697,7 @@ impl AzblobCore {
     pub async fn azblob_complete_put_block_list_request(
         &self,
         path: &str,
-        block_ids: Vec<Uuid>,
+        block_ids: &[String],
         args: &OpWrite,
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
@@ -445,11 +718,8 @@ impl AzblobCore {
 
         let content = quick_xml::se::to_string(&PutBlockListRequest {
             latest: block_ids
-                .into_iter()
-                .map(|block_id| {
-                    let encoded_block_id: String = BASE64_STANDARD.encode(block_id.as_bytes());
-                    encoded_block_id
-                })
+                .iter()
+                .map(|block_id| BASE64_STANDARD.encode(block_id))
                 .collect(),
         })
         .map_err(new_xml_deserialize_error)?;
@@ -466,7 +736,7 @@ impl AzblobCore {
     pub async fn azblob_complete_put_block_list(
         &self,
         path: &str,
-        block_ids: Vec<Uuid>,
+        block_ids: &[String],
         args: &OpWrite,
     ) -> Result<Response<Buffer>> {
         let mut req = self
@@ -541,6 +811,64 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    pub async fn azblob_set_immutability_policy(
+        &self,
+        path: &str,
+        retention: Retention,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=immutabilityPolicies",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mode = match retention.mode() {
+            RetentionMode::Governance => "Unlocked",
+            RetentionMode::Compliance => "Locked",
+        };
+        let until = retention
+            .retain_until()
+            .format("%a, %d %b %Y %H:%M:%S GMT")
+            .to_string();
+
+        let mut req = Request::put(&url)
+            .header(constants::X_MS_IMMUTABILITY_POLICY_UNTIL_DATE, until)
+            .header(constants::X_MS_IMMUTABILITY_POLICY_MODE, mode)
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn azblob_set_legal_hold(
+        &self,
+        path: &str,
+        legal_hold: bool,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=legalhold",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url)
+            .header(constants::X_MS_LEGAL_HOLD, legal_hold.to_string())
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn azblob_copy_blob(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
         let source = build_abs_path(&self.root, from);
         let target = build_abs_path(&self.root, to);
@@ -603,6 +931,97 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    pub async fn azblob_get_container_acl(&self) -> Result<Response<Buffer>> {
+        let url = format!(
+            "{}/{}?restype=container&comp=acl",
+            self.endpoint, self.container
+        );
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Set the public access level of container.
+    ///
+    /// `signed_identifiers` is the stored access policies returned by
+    /// [`AzblobCore::azblob_get_container_acl`], Azure will remove all
+    /// policies that are not in it.
+    pub async fn azblob_set_container_acl(
+        &self,
+        acl: Acl,
+        signed_identifiers: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let url = format!(
+            "{}/{}?restype=container&comp=acl",
+            self.endpoint, self.container
+        );
+
+        let mut req = Request::put(&url);
+        match acl {
+            Acl::Private => {}
+            Acl::PublicRead => {
+                req = req.header(constants::X_MS_BLOB_PUBLIC_ACCESS, "blob");
+            }
+            acl => {
+                return Err(
+                    Error::new(ErrorKind::Unsupported, "acl is not supported by azblob")
+                        .with_context("acl", acl),
+                )
+            }
+        }
+
+        let mut req = req
+            .header(CONTENT_LENGTH, signed_identifiers.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(signed_identifiers)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn azblob_create_container(&self) -> Result<Response<Buffer>> {
+        let url = format!("{}/{}?restype=container", self.endpoint, self.container);
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn azblob_delete_container(&self) -> Result<Response<Buffer>> {
+        let url = format!("{}/{}?restype=container", self.endpoint, self.container);
+
+        let mut req = Request::delete(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn azblob_list_containers(&self, next_marker: &str) -> Result<Response<Buffer>> {
+        let mut url = format!("{}/?comp=list", self.endpoint);
+        if !next_marker.is_empty() {
+            write!(url, "&marker={next_marker}").expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn azblob_batch_delete(&self, paths: &[String]) -> Result<Response<Buffer>> {
         let url = format!(
             "{}/{}?restype=container&comp=batch",
@@ -628,6 +1047,73 @@ impl AzblobCore {
     }
 }
 
+/// Check the user-controlled block ids before sending to azblob.
+///
+/// Block ids must be non-empty and at most 64 bytes before base64 encoded, all blocks
+/// of the same blob must have ids in the same length.
+///
+/// ref: <https://learn.microsoft.com/en-us/rest/api/storageservices/put-block>
+/// Parse the immutability policy and legal hold from the blob properties.
+pub fn parse_object_retention(headers: &HeaderMap) -> Result<ObjectRetention> {
+    let mode = parse_header_to_str(headers, constants::X_MS_IMMUTABILITY_POLICY_MODE)?;
+    let until = parse_header_to_str(headers, constants::X_MS_IMMUTABILITY_POLICY_UNTIL_DATE)?;
+    let retention = match (mode, until) {
+        (Some(mode), Some(until)) => {
+            let mode = if mode.eq_ignore_ascii_case("unlocked") {
+                RetentionMode::Governance
+            } else if mode.eq_ignore_ascii_case("locked") {
+                RetentionMode::Compliance
+            } else {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "immutability policy mode is not recognized",
+                )
+                .with_context("mode", mode));
+            };
+            Some(Retention::new(mode, parse_datetime_from_rfc2822(until)?))
+        }
+        _ => None,
+    };
+    let legal_hold = parse_header_to_str(headers, constants::X_MS_LEGAL_HOLD)?
+        .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+
+    Ok(ObjectRetention::new(retention, legal_hold))
+}
+
+/// Parse the public access level of container into acl.
+///
+/// Both `blob` and `container` allow everyone to read blobs, `container` allows
+/// listing blobs in addition.
+pub fn parse_container_acl(headers: &HeaderMap) -> Result<Acl> {
+    let acl = match parse_header_to_str(headers, constants::X_MS_BLOB_PUBLIC_ACCESS)? {
+        Some("blob") | Some("container") => Acl::PublicRead,
+        _ => Acl::Private,
+    };
+    Ok(acl)
+}
+
+pub fn check_block_ids<'a>(block_ids: impl IntoIterator<Item = &'a str>) -> Result<()> {
+    let mut len = None;
+    for id in block_ids {
+        if id.is_empty() || id.len() > 64 {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "block id must be non-empty and at most 64 bytes",
+            )
+            .with_context("block_id", id));
+        }
+        if *len.get_or_insert(id.len()) != id.len() {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "block ids of the same blob must be in the same length",
+            )
+            .with_context("block_id", id));
+        }
+    }
+
+    Ok(())
+}
+
 /// Request of PutBlockListRequest
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(default, rename = "BlockList", rename_all = "PascalCase")]
@@ -677,6 +1163,26 @@ pub struct Properties {
     pub etag: String,
 }
 
+/// Output of [List Containers](https://learn.microsoft.com/en-us/rest/api/storageservices/list-containers2).
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListContainersOutput {
+    pub containers: Containers,
+    pub next_marker: Option<String>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Containers {
+    pub container: Vec<Container>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Container {
+    pub name: String,
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
@@ -685,6 +1191,33 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_parse_object_retention() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            constants::X_MS_IMMUTABILITY_POLICY_MODE,
+            HeaderValue::from_static("unlocked"),
+        );
+        headers.insert(
+            constants::X_MS_IMMUTABILITY_POLICY_UNTIL_DATE,
+            HeaderValue::from_static("Fri, 13 Nov 2026 08:00:00 GMT"),
+        );
+        headers.insert(
+            constants::X_MS_LEGAL_HOLD,
+            HeaderValue::from_static("false"),
+        );
+
+        let retention = parse_object_retention(&headers).unwrap();
+        assert!(!retention.legal_hold());
+        assert_eq!(
+            retention.retention(),
+            Some(Retention::new(
+                RetentionMode::Governance,
+                parse_datetime_from_rfc3339("2026-11-13T08:00:00Z").unwrap()
+            ))
+        );
+    }
+
     #[test]
     fn test_parse_xml() {
         let bs = bytes::Bytes::from(
@@ -864,6 +1397,41 @@ mod tests {
         de::from_reader(Bytes::from(bs).reader()).expect("must success")
     }
 
+    #[test]
+    fn test_parse_container_acl() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_container_acl(&headers).unwrap(), Acl::Private);
+
+        headers.insert(
+            constants::X_MS_BLOB_PUBLIC_ACCESS,
+            HeaderValue::from_static("container"),
+        );
+        assert_eq!(parse_container_acl(&headers).unwrap(), Acl::PublicRead);
+    }
+
+    #[test]
+    fn test_parse_list_containers() {
+        let bs = "<?xml version=\"1.0\" encoding=\"utf-8\"?><EnumerationResults ServiceEndpoint=\"https://test.blob.core.windows.net/\"><Containers><Container><Name>a</Name><Properties><Last-Modified>Thu, 01 Sep 2022 07:26:49 GMT</Last-Modified><Etag>0x8DA8BEB55D0EA35</Etag></Properties></Container><Container><Name>b</Name></Container></Containers><NextMarker>/test/c</NextMarker></EnumerationResults>";
+
+        let out: ListContainersOutput = de::from_str(bs).expect("must success");
+        let names: Vec<_> = out
+            .containers
+            .container
+            .into_iter()
+            .map(|v| v.name)
+            .collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(out.next_marker.as_deref(), Some("/test/c"));
+    }
+
+    #[test]
+    fn test_check_block_ids() {
+        assert!(check_block_ids(["0001", "0002"]).is_ok());
+        assert!(check_block_ids([""]).is_err());
+        assert!(check_block_ids([&*"a".repeat(65)]).is_err());
+        assert!(check_block_ids(["0001", "02"]).is_err());
+    }
+
     /// This example is from https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-list?tabs=microsoft-entra-id
     #[test]
     fn test_serialize_put_block_list_request() {
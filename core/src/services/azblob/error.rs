@@ -76,11 +76,21 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
         _ => (ErrorKind::Unexpected, false),
     };
 
-    let mut message = match de::from_reader::<_, AzblobError>(bs.clone().reader()) {
-        Ok(azblob_err) => format!("{azblob_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let (mut message, mut code) = match de::from_reader::<_, AzblobError>(bs.clone().reader()) {
+        Ok(azblob_err) => (format!("{azblob_err:?}"), azblob_err.code),
+        Err(_) => (String::from_utf8_lossy(&bs).into_owned(), String::new()),
     };
 
+    if code.is_empty() {
+        if let Some(v) = parts
+            .headers
+            .get("x-ms-error-code")
+            .and_then(|v| v.to_str().ok())
+        {
+            code = v.to_string();
+        }
+    }
+
     // If there is no body here, fill with error code.
     if message.is_empty() {
         if let Some(v) = parts.headers.get("x-ms-error-code") {
@@ -96,8 +106,22 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
         }
     }
 
+    // The cached credential will be expired while seeing this error, it's
+    // Ok to retry with a new one.
+    let (kind, retryable) = if is_token_expired(parts.status, &bs) {
+        (ErrorKind::PermissionDenied, true)
+    } else if code == "ContainerAlreadyExists" {
+        (ErrorKind::AlreadyExists, false)
+    } else {
+        (kind, retryable)
+    };
+
     let mut err = Error::new(kind, &message);
 
+    if !code.is_empty() {
+        err = err.with_service_code(code);
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
@@ -107,6 +131,18 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
     Ok(err)
 }
 
+/// Check if the response reports that the credential has been expired.
+pub(crate) fn is_credential_expired(resp: &Response<Buffer>) -> bool {
+    is_token_expired(resp.status(), &resp.body().to_bytes())
+}
+
+/// Azure storage will return `InvalidAuthenticationInfo` with detail like
+/// `Lifetime validation failed. The token is expired.` for expired tokens.
+fn is_token_expired(status: StatusCode, body: &[u8]) -> bool {
+    matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+        && String::from_utf8_lossy(body).contains("The token is expired")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,4 +196,26 @@ mod tests {
         );
         assert_eq!(out.reason, "invalid receipt format");
     }
+
+    #[test]
+    fn test_is_credential_expired() {
+        let body = r#"<?xml version="1.0" encoding="utf-8"?>
+<Error>
+  <Code>InvalidAuthenticationInfo</Code>
+  <Message>Authentication information is not given in the correct format.</Message>
+  <AuthenticationErrorDetail>Lifetime validation failed. The token is expired.</AuthenticationErrorDetail>
+</Error>"#;
+
+        let resp = Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Buffer::from(body))
+            .unwrap();
+        assert!(is_credential_expired(&resp));
+
+        let resp = Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Buffer::from(body))
+            .unwrap();
+        assert!(!is_credential_expired(&resp));
+    }
 }
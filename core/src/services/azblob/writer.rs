@@ -125,7 +125,13 @@ impl oio::BlockWrite for AzblobWriter {
     async fn write_block(&self, block_id: Uuid, size: u64, body: Buffer) -> Result<()> {
         let resp = self
             .core
-            .azblob_put_block(&self.path, block_id, Some(size), &self.op, body)
+            .azblob_put_block(
+                &self.path,
+                &block_id.to_string(),
+                Some(size),
+                &self.op,
+                body,
+            )
             .await?;
 
         let status = resp.status();
@@ -136,9 +142,10 @@ impl oio::BlockWrite for AzblobWriter {
     }
 
     async fn complete_block(&self, block_ids: Vec<Uuid>) -> Result<()> {
+        let block_ids: Vec<String> = block_ids.iter().map(|v| v.to_string()).collect();
         let resp = self
             .core
-            .azblob_complete_put_block_list(&self.path, block_ids, &self.op)
+            .azblob_complete_put_block_list(&self.path, &block_ids, &self.op)
             .await?;
 
         let status = resp.status();
@@ -48,6 +48,8 @@ const KNOWN_AZDLS_ENDPOINT_SUFFIX: &[&str] = &[
 
 /// Azure Data Lake Storage Gen2 Support.
 #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
 pub struct AzdlsConfig {
     /// Root of this backend.
     pub root: Option<String>,
@@ -82,6 +84,11 @@ impl Debug for AzdlsConfig {
 
 impl Configurator for AzdlsConfig {
     type Builder = AzdlsBuilder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("filesystem"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         AzdlsBuilder {
             config: self,
@@ -349,7 +356,10 @@ impl Access for AzdlsBackend {
 
         let status = resp.status();
         match status {
-            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok((RpRead::new(), resp.into_body())),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
+            }
             _ => {
                 let (part, mut body) = resp.into_parts();
                 let buf = body.to_buffer().await?;
@@ -41,6 +41,8 @@ const DEFAULT_AZFILE_ENDPOINT_SUFFIX: &str = "file.core.windows.net";
 
 /// Azure File services support.
 #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
 pub struct AzfileConfig {
     /// The root path for azfile.
     pub root: Option<String>,
@@ -80,6 +82,11 @@ impl Debug for AzfileConfig {
 
 impl Configurator for AzfileConfig {
     type Builder = AzfileBuilder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("share_name"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         AzfileBuilder {
             config: self,
@@ -285,6 +292,7 @@ impl Access for AzfileBackend {
                 read: true,
 
                 write: true,
+                write_can_append: true,
                 create_dir: true,
                 delete: true,
                 rename: true,
@@ -347,7 +355,10 @@ impl Access for AzfileBackend {
 
         let status = resp.status();
         match status {
-            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok((RpRead::new(), resp.into_body())),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
+            }
             _ => {
                 let (part, mut body) = resp.into_parts();
                 let buf = body.to_buffer().await?;
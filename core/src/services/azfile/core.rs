@@ -192,6 +192,31 @@ impl AzfileCore {
         self.send(req).await
     }
 
+    /// Resize the file to given size, new bytes will be filled with zero.
+    ///
+    /// ref: https://learn.microsoft.com/en-us/rest/api/storageservices/set-file-properties
+    pub async fn azfile_set_file_size(&self, path: &str, size: u64) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path)
+            .trim_start_matches('/')
+            .to_string();
+        let url = format!(
+            "{}/{}/{}?comp=properties",
+            self.endpoint,
+            self.share_name,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url);
+
+        req = req.header(X_MS_CONTENT_LENGTH, size);
+
+        req = req.header(CONTENT_LENGTH, 0);
+
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn azfile_get_file_properties(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let url = format!(
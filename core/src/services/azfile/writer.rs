@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::ops::Range;
 use std::sync::Arc;
 
 use http::StatusCode;
@@ -26,6 +27,11 @@ use crate::*;
 
 pub type AzfileWriters = TwoWays<oio::OneShotWriter<AzfileWriter>, oio::AppendWriter<AzfileWriter>>;
 
+/// The max size of a single `Put Range` request.
+///
+/// ref: https://learn.microsoft.com/en-us/rest/api/storageservices/put-range
+const AZFILE_MAX_RANGE_SIZE: usize = 4 * 1024 * 1024;
+
 pub struct AzfileWriter {
     core: Arc<AzfileCore>,
     op: OpWrite,
@@ -36,6 +42,37 @@ impl AzfileWriter {
     pub fn new(core: Arc<AzfileCore>, op: OpWrite, path: String) -> Self {
         AzfileWriter { core, op, path }
     }
+
+    /// Write data at given offset, split into multiple ranges if it's larger
+    /// than what a single `Put Range` accepts.
+    async fn write_ranges(&self, offset: u64, bs: Buffer) -> Result<()> {
+        for range in split_ranges(bs.len()) {
+            let size = range.len() as u64;
+            let pos = offset + range.start as u64;
+            let resp = self
+                .core
+                .azfile_update(&self.path, size, pos, bs.slice(range))
+                .await?;
+            let status = resp.status();
+            match status {
+                StatusCode::OK | StatusCode::CREATED => {}
+                _ => {
+                    return Err(parse_error(resp)
+                        .await?
+                        .with_operation("Backend::azfile_update"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Split `len` bytes into ranges that fit in a single `Put Range` request.
+fn split_ranges(len: usize) -> impl Iterator<Item = Range<usize>> {
+    (0..len)
+        .step_by(AZFILE_MAX_RANGE_SIZE)
+        .map(move |start| start..len.min(start + AZFILE_MAX_RANGE_SIZE))
 }
 
 impl oio::OneShotWrite for AzfileWriter {
@@ -55,17 +92,7 @@ impl oio::OneShotWrite for AzfileWriter {
             }
         }
 
-        let resp = self
-            .core
-            .azfile_update(&self.path, bs.len() as u64, 0, bs)
-            .await?;
-        let status = resp.status();
-        match status {
-            StatusCode::OK | StatusCode::CREATED => Ok(()),
-            _ => Err(parse_error(resp)
-                .await?
-                .with_operation("Backend::azfile_update")),
-        }
+        self.write_ranges(0, bs).await
     }
 }
 
@@ -77,22 +104,65 @@ impl oio::AppendWrite for AzfileWriter {
 
         match status {
             StatusCode::OK => Ok(parse_content_length(resp.headers())?.unwrap_or_default()),
+            StatusCode::NOT_FOUND => {
+                let resp = self
+                    .core
+                    .azfile_create_file(&self.path, 0, &self.op)
+                    .await?;
+                match resp.status() {
+                    StatusCode::OK | StatusCode::CREATED => Ok(0),
+                    _ => Err(parse_error(resp)
+                        .await?
+                        .with_operation("Backend::azfile_create_file")),
+                }
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
 
     async fn append(&self, offset: u64, size: u64, body: Buffer) -> Result<()> {
+        // Azure Files requires ranges to be inside the file, grow the file first.
         let resp = self
             .core
-            .azfile_update(&self.path, size, offset, body)
+            .azfile_set_file_size(&self.path, offset + size)
             .await?;
-
         let status = resp.status();
         match status {
-            StatusCode::OK | StatusCode::CREATED => Ok(()),
-            _ => Err(parse_error(resp)
-                .await?
-                .with_operation("Backend::azfile_update")),
+            StatusCode::OK => {}
+            _ => {
+                return Err(parse_error(resp)
+                    .await?
+                    .with_operation("Backend::azfile_set_file_size"));
+            }
+        }
+
+        self.write_ranges(offset, body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_ranges() {
+        const MAX: usize = AZFILE_MAX_RANGE_SIZE;
+
+        let cases = vec![
+            ("empty", 0, vec![]),
+            ("small", 1, vec![(0, 1)]),
+            ("exactly max", MAX, vec![(0, MAX)]),
+            ("max plus one", MAX + 1, vec![(0, MAX), (MAX, MAX + 1)]),
+            (
+                "several ranges",
+                2 * MAX + 10,
+                vec![(0, MAX), (MAX, 2 * MAX), (2 * MAX, 2 * MAX + 10)],
+            ),
+        ];
+
+        for (name, len, expected) in cases {
+            let actual: Vec<_> = split_ranges(len).map(|r| (r.start, r.end)).collect();
+            assert_eq!(actual, expected, "{name}");
         }
     }
 }
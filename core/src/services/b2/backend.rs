@@ -84,6 +84,11 @@ impl Debug for B2Config {
 
 impl Configurator for B2Config {
     type Builder = B2Builder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("bucket"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         B2Builder {
             config: self,
@@ -243,6 +248,7 @@ impl Builder for B2Builder {
 
                 bucket: self.config.bucket.clone(),
                 bucket_id: self.config.bucket_id.clone(),
+                upload_urls: Arc::default(),
                 client,
             }),
         })
@@ -267,6 +273,7 @@ impl Access for B2Backend {
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::B2)
             .set_root(&self.core.root)
+            .set_http_client(self.core.client.clone())
             .set_native_capability(Capability {
                 stat: true,
 
@@ -288,6 +295,18 @@ impl Access for B2Backend {
                 } else {
                     Some(usize::MAX)
                 },
+                // The max multipart count of B2 is 10000.
+                //
+                // ref: <https://www.backblaze.com/docs/cloud-storage-large-files>
+                write_multi_max_parts: Some(10000),
+                // The max object size of B2 is 10 TB.
+                //
+                // ref: <https://www.backblaze.com/docs/cloud-storage-large-files>
+                write_total_max_size: if cfg!(target_pointer_width = "64") {
+                    Some(10 * 1000 * 1000 * 1000 * 1000)
+                } else {
+                    Some(usize::MAX)
+                },
 
                 delete: true,
                 copy: true,
@@ -308,18 +327,21 @@ impl Access for B2Backend {
         am.into()
     }
 
-    /// B2 have a get_file_info api required a file_id field, but field_id need call list api, list api also return file info
-    /// So we call list api to get file info
     async fn stat(&self, path: &str, _args: OpStat) -> Result<RpStat> {
         // Stat root always returns a DIR.
         if path == "/" {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
         }
 
-        let delimiter = if path.ends_with('/') { Some("/") } else { None };
+        if !path.ends_with('/') {
+            let file = self.core.get_file_info_by_name(path).await?;
+            return Ok(RpStat::new(parse_file_info(&file)));
+        }
+
+        // B2 doesn't have real dirs, a dir exists if there are any files under it.
         let resp = self
             .core
-            .list_file_names(Some(path), delimiter, None, None)
+            .list_file_names(Some(path), Some("/"), Some(1), None)
             .await?;
 
         let status = resp.status();
@@ -333,8 +355,7 @@ impl Access for B2Backend {
                 if resp.files.is_empty() {
                     return Err(Error::new(ErrorKind::NotFound, "no such file or directory"));
                 }
-                let meta = parse_file_info(&resp.files[0]);
-                Ok(RpStat::new(meta))
+                Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -349,7 +370,8 @@ impl Access for B2Backend {
         let status = resp.status();
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -402,28 +424,7 @@ impl Access for B2Backend {
     }
 
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        let resp = self
-            .core
-            .list_file_names(Some(from), None, None, None)
-            .await?;
-
-        let status = resp.status();
-
-        let source_file_id = match status {
-            StatusCode::OK => {
-                let bs = resp.into_body();
-
-                let resp: ListFileNamesResponse =
-                    serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
-                if resp.files.is_empty() {
-                    return Err(Error::new(ErrorKind::NotFound, "no such file or directory"));
-                }
-
-                let file_id = resp.files[0].clone().file_id;
-                Ok(file_id)
-            }
-            _ => Err(parse_error(resp).await?),
-        }?;
+        let source_file_id = self.core.get_file_info_by_name(from).await?.file_id;
 
         let Some(source_file_id) = source_file_id else {
             return Err(Error::new(ErrorKind::IsADirectory, "is a directory"));
@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::Duration;
 
 use bytes::Buf;
@@ -56,6 +57,12 @@ pub struct B2Core {
     /// The bucket id of this backend.
     pub bucket_id: String,
 
+    /// Upload urls that can be reused by later uploads.
+    ///
+    /// B2 allows reusing an upload url until it rejects an upload, so we keep
+    /// them here to avoid calling `b2_get_upload_url` for every upload.
+    pub upload_urls: Arc<Mutex<Vec<GetUploadUrlResponse>>>,
+
     pub client: HttpClient,
 }
 
@@ -72,7 +79,20 @@ impl Debug for B2Core {
 impl B2Core {
     #[inline]
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
-        self.client.send(req).await
+        let resp = self.client.send(req).await?;
+        if resp.status() == StatusCode::UNAUTHORIZED {
+            self.expire_auth_info().await;
+        }
+        Ok(resp)
+    }
+
+    /// Mark current auth info as expired so that next call will authorize again.
+    ///
+    /// B2 returns `401` with `expired_auth_token` or `bad_auth_token` while the
+    /// token is no longer valid, retrying with the same token will never succeed.
+    pub async fn expire_auth_info(&self) {
+        let mut signer = self.signer.write().await;
+        signer.auth_info.expires_in = DateTime::<Utc>::MIN_UTC;
     }
 
     /// [b2_authorize_account](https://www.backblaze.com/apidocs/b2-authorize-account)
@@ -157,7 +177,36 @@ impl B2Core {
 
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
-        self.client.fetch(req).await
+        let resp = self.client.fetch(req).await?;
+        if resp.status() == StatusCode::UNAUTHORIZED {
+            self.expire_auth_info().await;
+        }
+        Ok(resp)
+    }
+
+    /// Get the file info of given path via [b2_list_file_names](https://www.backblaze.com/apidocs/b2-list-file-names).
+    ///
+    /// B2 have a get_file_info api required a file_id field, but field_id need call list api,
+    /// so we list only one file starting from given path and check whether it's exactly matched.
+    pub async fn get_file_info_by_name(&self, path: &str) -> Result<File> {
+        let p = build_abs_path(&self.root, path);
+
+        let resp = self
+            .list_file_names(Some(path), None, Some(1), Some(p.clone()))
+            .await?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body();
+        let resp: ListFileNamesResponse =
+            serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+
+        match resp.files.into_iter().next() {
+            Some(file) if file.file_name == p => Ok(file),
+            _ => Err(Error::new(ErrorKind::NotFound, "no such file or directory")),
+        }
     }
 
     pub(super) async fn get_upload_url(&self) -> Result<GetUploadUrlResponse> {
@@ -239,15 +288,23 @@ impl B2Core {
         args: &OpWrite,
         body: Buffer,
     ) -> Result<Response<Buffer>> {
-        let resp = self.get_upload_url().await?;
+        let cached = self
+            .upload_urls
+            .lock()
+            .expect("lock must be acquired")
+            .pop();
+        let upload = match cached {
+            Some(upload) => upload,
+            None => self.get_upload_url().await?,
+        };
 
         let p = build_abs_path(&self.root, path);
 
-        let mut req = Request::post(resp.upload_url);
+        let mut req = Request::post(&upload.upload_url);
 
         req = req.header(X_BZ_FILE_NAME, percent_encode_path(&p));
 
-        req = req.header(header::AUTHORIZATION, resp.authorization_token);
+        req = req.header(header::AUTHORIZATION, &upload.authorization_token);
 
         req = req.header(X_BZ_CONTENT_SHA1, "do_not_verify");
 
@@ -268,7 +325,16 @@ impl B2Core {
         // Set body
         let req = req.body(body).map_err(new_request_build_error)?;
 
-        self.send(req).await
+        // Upload url must not be reused once it rejects an upload, we should
+        // get a new one instead.
+        let resp = self.client.send(req).await?;
+        if resp.status() == StatusCode::OK {
+            self.upload_urls
+                .lock()
+                .expect("lock must be acquired")
+                .push(upload);
+        }
+        Ok(resp)
     }
 
     pub async fn start_large_file(&self, path: &str, args: &OpWrite) -> Result<Response<Buffer>> {
@@ -629,6 +695,10 @@ pub struct File {
     pub content_md5: Option<String>,
     pub content_type: Option<String>,
     pub file_name: String,
+    /// UTC time in milliseconds since midnight, January 1, 1970.
+    ///
+    /// Always `0` for folders.
+    pub upload_timestamp: Option<i64>,
 }
 
 pub(super) fn parse_file_info(file: &File) -> Metadata {
@@ -648,6 +718,12 @@ pub(super) fn parse_file_info(file: &File) -> Metadata {
         metadata.set_content_type(content_type);
     }
 
+    if let Some(ts) = file.upload_timestamp {
+        if let Ok(t) = parse_datetime_from_from_timestamp_millis(ts) {
+            metadata.set_last_modified(t);
+        }
+    }
+
     metadata
 }
 
@@ -70,7 +70,11 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
 pub fn parse_b2_error_code(code: &str) -> Option<(ErrorKind, bool)> {
     match code {
         "already_hidden" => Some((ErrorKind::AlreadyExists, false)),
-        "no_such_file" => Some((ErrorKind::NotFound, false)),
+        "no_such_file" | "file_not_present" => Some((ErrorKind::NotFound, false)),
+        // Auth token will be refreshed by core, so it's safe to retry.
+        "expired_auth_token" | "bad_auth_token" => Some((ErrorKind::PermissionDenied, true)),
+        "too_many_requests" => Some((ErrorKind::RateLimited, true)),
+        "service_unavailable" => Some((ErrorKind::Unexpected, true)),
         _ => None,
     }
 }
@@ -95,6 +99,12 @@ mod test {
             Some((crate::ErrorKind::NotFound, false))
         );
 
+        let code = "expired_auth_token";
+        assert_eq!(
+            parse_b2_error_code(code),
+            Some((crate::ErrorKind::PermissionDenied, true))
+        );
+
         let code = "not_found";
         assert_eq!(parse_b2_error_code(code), None);
     }
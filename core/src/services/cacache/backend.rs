@@ -33,6 +33,8 @@ use crate::*;
 
 /// cacache service support.
 #[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
 pub struct CacacheConfig {
     /// That path to the cacache data directory.
     pub datadir: Option<String>,
@@ -33,6 +33,8 @@ use crate::*;
 
 /// Cloudflare KV Service Support.
 #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
 pub struct CloudflareKvConfig {
     /// The token used to authenticate with CloudFlare.
     pub token: Option<String>,
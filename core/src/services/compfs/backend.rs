@@ -32,6 +32,8 @@ use crate::*;
 
 /// compio-based file system support.
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
 pub struct CompfsConfig {
     /// root of this backend.
     ///
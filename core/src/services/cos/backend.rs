@@ -23,7 +23,7 @@ use http::StatusCode;
 use http::Uri;
 use log::debug;
 use reqsign::TencentCosConfig;
-use reqsign::TencentCosCredentialLoader;
+use reqsign::TencentCosCredential;
 use reqsign::TencentCosSigner;
 use serde::Deserialize;
 use serde::Serialize;
@@ -39,6 +39,7 @@ use crate::*;
 /// Tencent-Cloud COS services support.
 #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(default)]
+#[non_exhaustive]
 pub struct CosConfig {
     /// Root of this backend.
     pub root: Option<String>,
@@ -68,9 +69,15 @@ impl Debug for CosConfig {
 
 impl Configurator for CosConfig {
     type Builder = CosBuilder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("bucket"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         CosBuilder {
             config: self,
+            customized_credential_load: None,
             http_client: None,
         }
     }
@@ -81,6 +88,9 @@ impl Configurator for CosConfig {
 #[derive(Default, Clone)]
 pub struct CosBuilder {
     config: CosConfig,
+
+    customized_credential_load:
+        Option<Arc<dyn ProvideCredential<Credential = TencentCosCredential>>>,
     http_client: Option<HttpClient>,
 }
 
@@ -162,6 +172,19 @@ impl CosBuilder {
         self
     }
 
+    /// Adding a customized credential load for service.
+    ///
+    /// If customized_credential_load has been set, we will ignore all other
+    /// credential load methods. The loaded credential will be cached and the
+    /// provider will be called again once the credential is expired.
+    pub fn customized_credential_load(
+        mut self,
+        cred: Arc<dyn ProvideCredential<Credential = TencentCosCredential>>,
+    ) -> Self {
+        self.customized_credential_load = Some(cred);
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -234,7 +257,10 @@ impl Builder for CosBuilder {
             cfg.secret_key = Some(v);
         }
 
-        let cred_loader = TencentCosCredentialLoader::new(client.client(), cfg);
+        let cred_loader = match self.customized_credential_load {
+            Some(v) => CredentialLoader::from_arc(v),
+            None => CredentialLoader::new(CosCredentialProvider::new(client.client()?, cfg)),
+        };
 
         let signer = TencentCosSigner::new();
 
@@ -269,6 +295,7 @@ impl Access for CosBackend {
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::Cos)
             .set_root(&self.core.root)
+            .set_http_client(self.core.client.clone())
             .set_name(&self.core.bucket)
             .set_native_capability(Capability {
                 stat: true,
@@ -299,12 +326,17 @@ impl Access for CosBackend {
                 } else {
                     Some(usize::MAX)
                 },
+                // The max multipart count of COS is 10000.
+                //
+                // ref: <https://www.tencentcloud.com/document/product/436/14112>
+                write_multi_max_parts: Some(10000),
 
                 delete: true,
                 copy: true,
 
                 list: true,
                 list_with_recursive: true,
+                list_has_content_length: true,
 
                 presign: true,
                 presign_stat: true,
@@ -335,7 +367,8 @@ impl Access for CosBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
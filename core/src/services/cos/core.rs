@@ -20,6 +20,7 @@ use std::fmt::Formatter;
 use std::time::Duration;
 
 use bytes::Bytes;
+use chrono::Utc;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
 use http::header::CONTENT_LENGTH;
@@ -28,12 +29,14 @@ use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
 use http::Request;
 use http::Response;
+use reqsign::TencentCosConfig;
 use reqsign::TencentCosCredential;
 use reqsign::TencentCosCredentialLoader;
 use reqsign::TencentCosSigner;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::error::is_credential_expired;
 use crate::raw::*;
 use crate::*;
 
@@ -43,7 +46,7 @@ pub struct CosCore {
     pub endpoint: String,
 
     pub signer: TencentCosSigner,
-    pub loader: TencentCosCredentialLoader,
+    pub loader: CredentialLoader<TencentCosCredential>,
     pub client: HttpClient,
 }
 
@@ -57,13 +60,59 @@ impl Debug for CosCore {
     }
 }
 
+impl SigningCredential for TencentCosCredential {
+    fn is_valid(&self) -> bool {
+        if (self.secret_id.is_empty() || self.secret_key.is_empty())
+            && self.security_token.is_none()
+        {
+            return false;
+        }
+
+        // Take 120s as buffer to avoid edge cases.
+        self.expires_in.map_or(true, |v| {
+            v > Utc::now() + chrono::TimeDelta::try_minutes(2).expect("2 minutes must be valid")
+        })
+    }
+}
+
+/// CosCredentialProvider will load credential via reqsign.
+pub struct CosCredentialProvider {
+    client: reqwest::Client,
+    config: TencentCosConfig,
+}
+
+impl Debug for CosCredentialProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CosCredentialProvider")
+            .finish_non_exhaustive()
+    }
+}
+
+impl CosCredentialProvider {
+    /// Create a new provider via given client and config.
+    pub fn new(client: reqwest::Client, config: TencentCosConfig) -> Self {
+        Self { client, config }
+    }
+}
+
+impl ProvideCredential for CosCredentialProvider {
+    type Credential = TencentCosCredential;
+
+    fn provide_credential(&self) -> BoxedFuture<'_, Result<Option<Self::Credential>>> {
+        Box::pin(async move {
+            // Always build a new loader so that the expired credential cached
+            // by reqsign will not be returned again.
+            TencentCosCredentialLoader::new(self.client.clone(), self.config.clone())
+                .load()
+                .await
+                .map_err(new_request_credential_error)
+        })
+    }
+}
+
 impl CosCore {
     async fn load_credential(&self) -> Result<Option<TencentCosCredential>> {
-        let cred = self
-            .loader
-            .load()
-            .await
-            .map_err(new_request_credential_error)?;
+        let cred = self.loader.load().await?;
 
         if let Some(cred) = cred {
             return Ok(Some(cred));
@@ -97,9 +146,16 @@ impl CosCore {
             .map_err(new_request_sign_error)
     }
 
-    #[inline]
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
-        self.client.send(req).await
+        let resp = self.client.send(req).await?;
+
+        // Expire the cached credential if server reports that it has been
+        // expired, so that the retried request will sign with a new one.
+        if is_credential_expired(&resp) {
+            self.loader.expire();
+        }
+
+        Ok(resp)
     }
 }
 
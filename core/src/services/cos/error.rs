@@ -56,9 +56,16 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
         _ => (ErrorKind::Unexpected, false),
     };
 
-    let message = match de::from_reader::<_, CosError>(bs.clone().reader()) {
-        Ok(cos_error) => format!("{cos_error:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let (message, cos_error) = match de::from_reader::<_, CosError>(bs.clone().reader()) {
+        Ok(cos_error) => (format!("{cos_error:?}"), Some(cos_error)),
+        Err(_) => (String::from_utf8_lossy(&bs).into_owned(), None),
+    };
+
+    // The cached credential will be expired while seeing this error, it's
+    // Ok to retry with a new one.
+    let (kind, retryable) = match cos_error {
+        Some(cos_error) if cos_error.code == "ExpiredToken" => (ErrorKind::PermissionDenied, true),
+        _ => (kind, retryable),
     };
 
     let mut err = Error::new(kind, message);
@@ -71,6 +78,17 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
     Ok(err)
 }
 
+/// Check if the response reports that the credential has been expired.
+pub(crate) fn is_credential_expired(resp: &Response<Buffer>) -> bool {
+    if !matches!(resp.status().as_u16(), 400 | 403) {
+        return false;
+    }
+
+    de::from_reader::<_, CosError>(resp.body().clone().reader())
+        .map(|err| err.code == "ExpiredToken")
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +120,26 @@ mod tests {
             "RkRCRDJENDc5MzdGQkQ4OUY3MTI4NTQ3NDk2Mjg0M0FBQUFBQUFBYmJiYmJiYmJD"
         );
     }
+
+    #[test]
+    fn test_is_credential_expired() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+<Code>ExpiredToken</Code>
+<Message>The provided token has expired.</Message>
+<RequestId>001B21A61C6C0000013402C4616D5285</RequestId>
+</Error>"#;
+
+        let resp = Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Buffer::from(body))
+            .unwrap();
+        assert!(is_credential_expired(&resp));
+
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Buffer::from(body.replace("ExpiredToken", "AccessDenied")))
+            .unwrap();
+        assert!(!is_credential_expired(&resp));
+    }
 }
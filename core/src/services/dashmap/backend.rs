@@ -28,6 +28,8 @@ use crate::*;
 
 /// [dashmap](https://github.com/xacrimon/dashmap) backend support.
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
 pub struct DashmapConfig {
     /// The root path for dashmap.
     pub root: Option<String>,
@@ -101,6 +103,8 @@ impl typed_kv::Adapter for Adapter {
                 get: true,
                 set: true,
                 scan: true,
+                scan_sorted: false,
+                set_with_ttl: false,
                 delete: true,
             },
         )
@@ -148,7 +148,8 @@ impl Access for DropboxBackend {
         let status = resp.status();
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -0,0 +1,737 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Buf;
+use bytes::Bytes;
+use http::header;
+use http::Request;
+use http::StatusCode;
+use log::debug;
+use reqsign::AwsConfig;
+use reqsign::AwsDefaultLoader;
+use reqsign::AwsV4Signer;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::error::parse_error;
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::*;
+
+const X_AMZ_TARGET: &str = "x-amz-target";
+const X_AMZ_CONTENT_SHA_256: &str = "x-amz-content-sha256";
+
+/// The attribute that stores the size of the value.
+const SIZE_FIELD: &str = "size";
+/// The attribute that stores the count of chunks, `0` means the value is
+/// stored in the item itself.
+const CHUNKS_FIELD: &str = "chunks";
+/// DynamoDB limits the size of an item to 400KB including the attribute names
+/// and the key, so values larger than this are split into chunk items.
+const CHUNK_SIZE: u64 = 384 * 1024;
+
+/// Config for DynamoDB services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct DynamodbConfig {
+    /// The table to store objects, required.
+    pub table: Option<String>,
+    /// The region of the table, loaded from env or profile if not set.
+    pub region: Option<String>,
+    /// The endpoint of DynamoDB, default to `https://dynamodb.{region}.amazonaws.com`.
+    pub endpoint: Option<String>,
+    /// The access key id, loaded from env or profile if not set.
+    pub access_key_id: Option<String>,
+    /// The secret access key, loaded from env or profile if not set.
+    pub secret_access_key: Option<String>,
+    /// The session token, loaded from env or profile if not set.
+    pub session_token: Option<String>,
+    /// The partition key of the table, default to `key`.
+    ///
+    /// The key must be a string attribute.
+    pub key_field: Option<String>,
+    /// The attribute to store the value, default to `value`.
+    pub value_field: Option<String>,
+    /// The root for DynamoDB.
+    pub root: Option<String>,
+}
+
+impl Debug for DynamodbConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamodbConfig")
+            .field("table", &self.table)
+            .field("region", &self.region)
+            .field("endpoint", &self.endpoint)
+            .field("key_field", &self.key_field)
+            .field("value_field", &self.value_field)
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Configurator for DynamodbConfig {
+    type Builder = DynamodbBuilder;
+    fn into_builder(self) -> Self::Builder {
+        DynamodbBuilder {
+            config: self,
+            http_client: None,
+        }
+    }
+}
+
+/// [DynamoDB](https://aws.amazon.com/dynamodb/) services support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct DynamodbBuilder {
+    config: DynamodbConfig,
+
+    http_client: Option<HttpClient>,
+}
+
+impl Debug for DynamodbBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamodbBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DynamodbBuilder {
+    /// Set the table to store objects.
+    pub fn table(mut self, table: &str) -> Self {
+        if !table.is_empty() {
+            self.config.table = Some(table.to_string());
+        }
+        self
+    }
+
+    /// Set the region of the table, like `us-east-1`.
+    pub fn region(mut self, region: &str) -> Self {
+        if !region.is_empty() {
+            self.config.region = Some(region.to_string());
+        }
+        self
+    }
+
+    /// Set the endpoint of DynamoDB.
+    ///
+    /// Default to `https://dynamodb.{region}.amazonaws.com`, set it to use
+    /// services like DynamoDB local.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+        self
+    }
+
+    /// Set the access key id.
+    pub fn access_key_id(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.access_key_id = Some(v.to_string());
+        }
+        self
+    }
+
+    /// Set the secret access key.
+    pub fn secret_access_key(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.secret_access_key = Some(v.to_string());
+        }
+        self
+    }
+
+    /// Set the session token.
+    pub fn session_token(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.session_token = Some(v.to_string());
+        }
+        self
+    }
+
+    /// Set the partition key of the table, default to `key`.
+    pub fn key_field(mut self, key_field: &str) -> Self {
+        if !key_field.is_empty() {
+            self.config.key_field = Some(key_field.to_string());
+        }
+        self
+    }
+
+    /// Set the attribute to store the value, default to `value`.
+    pub fn value_field(mut self, value_field: &str) -> Self {
+        if !value_field.is_empty() {
+            self.config.value_field = Some(value_field.to_string());
+        }
+        self
+    }
+
+    /// Set the root for DynamoDB.
+    pub fn root(mut self, root: &str) -> Self {
+        if !root.is_empty() {
+            self.config.root = Some(root.to_string());
+        }
+        self
+    }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+}
+
+impl Builder for DynamodbBuilder {
+    const SCHEME: Scheme = Scheme::Dynamodb;
+    type Config = DynamodbConfig;
+
+    fn build(self) -> Result<impl Access> {
+        debug!("backend build started: {:?}", &self);
+
+        let Some(table) = self.config.table.clone() else {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "table is required")
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Dynamodb));
+        };
+
+        let mut cfg = AwsConfig::default();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            cfg = cfg.from_profile();
+            cfg = cfg.from_env();
+        }
+        if let Some(v) = self.config.region.clone() {
+            cfg.region = Some(v);
+        }
+        let Some(region) = cfg.region.clone() else {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "region is missing")
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Dynamodb));
+        };
+        if let Some(v) = self.config.access_key_id.clone() {
+            cfg.access_key_id = Some(v);
+        }
+        if let Some(v) = self.config.secret_access_key.clone() {
+            cfg.secret_access_key = Some(v);
+        }
+        if let Some(v) = self.config.session_token.clone() {
+            cfg.session_token = Some(v);
+        }
+
+        let endpoint = self
+            .config
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| format!("https://dynamodb.{region}.amazonaws.com"));
+        debug!("backend use endpoint: {endpoint}");
+
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Dynamodb)
+            })?
+        };
+
+        let loader = AwsDefaultLoader::new(client.client()?, cfg);
+        let signer = AwsV4Signer::new("dynamodb", &region);
+
+        Ok(DynamodbBackend::new(Adapter {
+            endpoint,
+            table,
+            key_field: self
+                .config
+                .key_field
+                .clone()
+                .unwrap_or_else(|| "key".to_string()),
+            value_field: self
+                .config
+                .value_field
+                .clone()
+                .unwrap_or_else(|| "value".to_string()),
+            client,
+            loader,
+            signer,
+        })
+        .with_root(self.config.root.as_deref().unwrap_or("/")))
+    }
+}
+
+/// Backend for DynamoDB services.
+pub type DynamodbBackend = kv::Backend<Adapter>;
+
+pub struct Adapter {
+    endpoint: String,
+    table: String,
+    key_field: String,
+    value_field: String,
+
+    client: HttpClient,
+    loader: AwsDefaultLoader,
+    signer: AwsV4Signer,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Adapter")
+            .field("endpoint", &self.endpoint)
+            .field("table", &self.table)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Adapter {
+    /// Call the DynamoDB API like `GetItem` with a json body.
+    async fn request<T: DeserializeOwned>(&self, target: &str, body: Value) -> Result<T> {
+        let body = serde_json::to_vec(&body).map_err(new_json_serialize_error)?;
+
+        let mut req = Request::post(&self.endpoint)
+            .header(header::CONTENT_TYPE, "application/x-amz-json-1.0")
+            .header(X_AMZ_TARGET, format!("DynamoDB_20120810.{target}"))
+            // DynamoDB doesn't accept unsigned payload.
+            .header(
+                X_AMZ_CONTENT_SHA_256,
+                format!("{:x}", Sha256::digest(&body)),
+            )
+            .body(Buffer::from(body))
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+
+        let resp = self.client.send(req).await?;
+        match resp.status() {
+            StatusCode::OK => serde_json::from_reader(resp.into_body().reader())
+                .map_err(new_json_deserialize_error),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
+        let Some(cred) = self
+            .loader
+            .load()
+            .await
+            .map_err(new_request_credential_error)?
+        else {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "no valid credential found",
+            ));
+        };
+
+        self.signer
+            .sign(req, &cred)
+            .map_err(new_request_sign_error)?;
+
+        // Always remove host header, let users' client to set it based on HTTP
+        // version.
+        req.headers_mut().remove(header::HOST);
+
+        Ok(())
+    }
+
+    /// Get the item of the key, the value will be skipped if `with_value` is false.
+    async fn get_item(&self, key: &str, with_value: bool) -> Result<Option<Item>> {
+        let mut names = Map::new();
+        names.insert("#s".to_string(), json!(SIZE_FIELD));
+        names.insert("#c".to_string(), json!(CHUNKS_FIELD));
+        let mut projection = "#s, #c".to_string();
+        if with_value {
+            names.insert("#v".to_string(), json!(self.value_field));
+            projection.push_str(", #v");
+        }
+
+        let resp: GetItemResponse = self
+            .request(
+                "GetItem",
+                json!({
+                    "TableName": self.table,
+                    "Key": { (self.key_field.as_str()): { "S": key } },
+                    "ConsistentRead": true,
+                    "ProjectionExpression": projection,
+                    "ExpressionAttributeNames": names,
+                }),
+            )
+            .await?;
+        Ok(resp.item)
+    }
+
+    async fn put_item(&self, key: &str, attrs: Map<String, Value>) -> Result<()> {
+        let mut item = attrs;
+        item.insert(self.key_field.clone(), json!({ "S": key }));
+
+        let _: Value = self
+            .request(
+                "PutItem",
+                json!({
+                    "TableName": self.table,
+                    "Item": item,
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_item(&self, key: &str) -> Result<()> {
+        let _: Value = self
+            .request(
+                "DeleteItem",
+                json!({
+                    "TableName": self.table,
+                    "Key": { (self.key_field.as_str()): { "S": key } },
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    fn value_attrs(&self, value: &[u8]) -> Map<String, Value> {
+        let mut attrs = Map::new();
+        if !value.is_empty() {
+            attrs.insert(
+                self.value_field.clone(),
+                json!({ "B": BASE64_STANDARD.encode(value) }),
+            );
+        }
+        attrs
+    }
+}
+
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::Dynamodb,
+            &self.table,
+            Capability {
+                read: true,
+                write: true,
+                list: true,
+
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Buffer>> {
+        Ok(self
+            .get_range(path, BytesRange::default())
+            .await?
+            .map(|(bs, _)| bs))
+    }
+
+    /// Only fetch the chunks that overlap with the range.
+    async fn get_range(&self, path: &str, range: BytesRange) -> Result<Option<(Buffer, u64)>> {
+        let with_value = range.size() != Some(0);
+        let Some(item) = self.get_item(path, with_value).await? else {
+            return Ok(None);
+        };
+
+        let chunks = item_number(&item, CHUNKS_FIELD)?.unwrap_or(0);
+        if chunks == 0 {
+            let value = Bytes::from(item_binary(&item, &self.value_field)?.unwrap_or_default());
+            let size = item_number(&item, SIZE_FIELD)?.unwrap_or(value.len() as u64);
+            let (start, end) = clamp_range(range, value.len() as u64);
+            return Ok(Some((
+                Buffer::from(value.slice(start as usize..end as usize)),
+                size,
+            )));
+        }
+
+        let size = item_number(&item, SIZE_FIELD)?
+            .ok_or_else(|| Error::new(ErrorKind::Unexpected, "chunked item doesn't have size"))?;
+        let (start, end) = clamp_range(range, size);
+
+        let mut bufs = vec![];
+        for (idx, chunk_start, chunk_end) in chunk_ranges(start, end) {
+            let chunk = self
+                .get_item_value(&chunk_key(path, idx))
+                .await?
+                .ok_or_else(|| {
+                    Error::new(ErrorKind::Unexpected, "chunk of the value is missing")
+                        .with_context("chunk", idx.to_string())
+                })?;
+            let chunk = Bytes::from(chunk);
+            let chunk_end = (chunk_end as usize).min(chunk.len());
+            let chunk_start = (chunk_start as usize).min(chunk_end);
+            bufs.push(chunk.slice(chunk_start..chunk_end));
+        }
+
+        Ok(Some((Buffer::from(bufs), size)))
+    }
+
+    async fn set(&self, path: &str, value: Buffer) -> Result<()> {
+        let old_chunks = match self.get_item(path, false).await? {
+            Some(item) => item_number(&item, CHUNKS_FIELD)?.unwrap_or(0),
+            None => 0,
+        };
+
+        let value = value.to_bytes();
+        let size = value.len() as u64;
+        let chunks = if size <= CHUNK_SIZE {
+            let mut attrs = self.value_attrs(&value);
+            attrs.insert(SIZE_FIELD.to_string(), json!({ "N": size.to_string() }));
+            attrs.insert(CHUNKS_FIELD.to_string(), json!({ "N": "0" }));
+            self.put_item(path, attrs).await?;
+            0
+        } else {
+            // Write chunks before the item so that the item never refers to
+            // chunks that are not written.
+            let mut chunks = 0;
+            for chunk in value.chunks(CHUNK_SIZE as usize) {
+                self.put_item(&chunk_key(path, chunks), self.value_attrs(chunk))
+                    .await?;
+                chunks += 1;
+            }
+
+            let mut attrs = Map::new();
+            attrs.insert(SIZE_FIELD.to_string(), json!({ "N": size.to_string() }));
+            attrs.insert(CHUNKS_FIELD.to_string(), json!({ "N": chunks.to_string() }));
+            self.put_item(path, attrs).await?;
+            chunks
+        };
+
+        // Remove the chunks left by the old value.
+        for idx in chunks..old_chunks {
+            self.delete_item(&chunk_key(path, idx)).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let chunks = match self.get_item(path, false).await? {
+            Some(item) => item_number(&item, CHUNKS_FIELD)?.unwrap_or(0),
+            None => return Ok(()),
+        };
+
+        self.delete_item(path).await?;
+        for idx in 0..chunks {
+            self.delete_item(&chunk_key(path, idx)).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        let mut keys = vec![];
+        let mut start_key: Option<Value> = None;
+        loop {
+            let mut body = json!({
+                "TableName": self.table,
+                "ProjectionExpression": "#k",
+                "ExpressionAttributeNames": { "#k": self.key_field },
+                "ConsistentRead": true,
+            });
+            if !path.is_empty() {
+                body["FilterExpression"] = json!("begins_with(#k, :p)");
+                body["ExpressionAttributeValues"] = json!({ ":p": { "S": path } });
+            }
+            if let Some(v) = start_key.take() {
+                body["ExclusiveStartKey"] = v;
+            }
+
+            let resp: ScanResponse = self.request("Scan", body).await?;
+            for item in resp.items {
+                let Some(key) = item.get(&self.key_field).and_then(|v| v.s.clone()) else {
+                    continue;
+                };
+                // Skip chunks of values and the path itself.
+                if key.starts_with('/') || key == path {
+                    continue;
+                }
+                keys.push(key);
+            }
+
+            match resp.last_evaluated_key {
+                Some(v) => start_key = Some(v),
+                None => break,
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+impl Adapter {
+    async fn get_item_value(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let resp: GetItemResponse = self
+            .request(
+                "GetItem",
+                json!({
+                    "TableName": self.table,
+                    "Key": { (self.key_field.as_str()): { "S": key } },
+                    "ConsistentRead": true,
+                    "ProjectionExpression": "#v",
+                    "ExpressionAttributeNames": { "#v": self.value_field },
+                }),
+            )
+            .await?;
+
+        match resp.item {
+            Some(item) => Ok(Some(
+                item_binary(&item, &self.value_field)?.unwrap_or_default(),
+            )),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The key of the chunk item.
+///
+/// Paths of kv backend never start with `/`, so chunks won't conflict with
+/// user's keys.
+fn chunk_key(path: &str, idx: u64) -> String {
+    format!("/chunk/{idx}/{path}")
+}
+
+/// Clamp the range into `[start, end)` within the size.
+fn clamp_range(range: BytesRange, size: u64) -> (u64, u64) {
+    let start = range.offset().min(size);
+    let end = match range.size() {
+        Some(v) => (start + v).min(size),
+        None => size,
+    };
+    (start, end)
+}
+
+/// Split `[start, end)` into `(chunk index, start in chunk, end in chunk)`.
+fn chunk_ranges(start: u64, end: u64) -> Vec<(u64, u64, u64)> {
+    if start >= end {
+        return vec![];
+    }
+
+    (start / CHUNK_SIZE..=(end - 1) / CHUNK_SIZE)
+        .map(|idx| {
+            let base = idx * CHUNK_SIZE;
+            (
+                idx,
+                start.saturating_sub(base),
+                (end - base).min(CHUNK_SIZE),
+            )
+        })
+        .collect()
+}
+
+type Item = HashMap<String, AttributeValue>;
+
+/// The value of an attribute, only the types used by OpenDAL are listed.
+#[derive(Default, Debug, Deserialize)]
+struct AttributeValue {
+    #[serde(rename = "S")]
+    s: Option<String>,
+    #[serde(rename = "N")]
+    n: Option<String>,
+    #[serde(rename = "B")]
+    b: Option<String>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct GetItemResponse {
+    item: Option<Item>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct ScanResponse {
+    items: Vec<Item>,
+    last_evaluated_key: Option<Value>,
+}
+
+fn item_number(item: &Item, name: &str) -> Result<Option<u64>> {
+    let Some(v) = item.get(name).and_then(|v| v.n.as_deref()) else {
+        return Ok(None);
+    };
+    v.parse().map(Some).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "dynamodb returns invalid number")
+            .with_context("attribute", name)
+            .set_source(err)
+    })
+}
+
+fn item_binary(item: &Item, name: &str) -> Result<Option<Vec<u8>>> {
+    let Some(v) = item.get(name).and_then(|v| v.b.as_deref()) else {
+        return Ok(None);
+    };
+    BASE64_STANDARD.decode(v).map(Some).map_err(|err| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "dynamodb returns invalid base64 data",
+        )
+        .with_context("attribute", name)
+        .set_source(err)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_ranges() {
+        let c = CHUNK_SIZE;
+        assert!(chunk_ranges(0, 0).is_empty());
+        assert_eq!(chunk_ranges(0, 10), vec![(0, 0, 10)]);
+        assert_eq!(chunk_ranges(0, c), vec![(0, 0, c)]);
+        assert_eq!(chunk_ranges(c, c + 1), vec![(1, 0, 1)]);
+        assert_eq!(
+            chunk_ranges(10, 2 * c + 5),
+            vec![(0, 10, c), (1, 0, c), (2, 0, 5)]
+        );
+    }
+
+    #[test]
+    fn test_clamp_range() {
+        assert_eq!(clamp_range(BytesRange::default(), 10), (0, 10));
+        assert_eq!(clamp_range(BytesRange::new(2, Some(3)), 10), (2, 5));
+        assert_eq!(clamp_range(BytesRange::new(8, Some(5)), 10), (8, 10));
+        assert_eq!(clamp_range(BytesRange::new(12, None), 10), (10, 10));
+    }
+
+    #[test]
+    fn test_parse_get_item_response() {
+        let resp: GetItemResponse = serde_json::from_str(
+            r#"{"Item":{"size":{"N":"5"},"chunks":{"N":"0"},"value":{"B":"aGVsbG8="}}}"#,
+        )
+        .unwrap();
+        let item = resp.item.unwrap();
+        assert_eq!(item_number(&item, SIZE_FIELD).unwrap(), Some(5));
+        assert_eq!(item_number(&item, CHUNKS_FIELD).unwrap(), Some(0));
+        assert_eq!(
+            item_binary(&item, "value").unwrap(),
+            Some(b"hello".to_vec())
+        );
+
+        let resp: GetItemResponse = serde_json::from_str("{}").unwrap();
+        assert!(resp.item.is_none());
+    }
+}
@@ -0,0 +1,118 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use bytes::Buf;
+use http::Response;
+use http::StatusCode;
+use serde::Deserialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// The error response returned by DynamoDB, like:
+///
+/// ```json
+/// {"__type":"com.amazonaws.dynamodb.v20120810#ResourceNotFoundException","message":"Requested resource not found"}
+/// ```
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct DynamodbError {
+    #[serde(rename = "__type")]
+    ty: String,
+    #[serde(alias = "Message")]
+    message: String,
+}
+
+/// Parse error response into Error.
+pub(super) async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
+    let (parts, mut body) = resp.into_parts();
+    let bs = body.copy_to_bytes(body.remaining());
+
+    let (mut kind, mut retryable) = match parts.status {
+        StatusCode::INTERNAL_SERVER_ERROR
+        | StatusCode::BAD_GATEWAY
+        | StatusCode::SERVICE_UNAVAILABLE
+        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let message = match serde_json::from_slice::<DynamodbError>(&bs) {
+        Ok(err) => {
+            (kind, retryable) = parse_dynamodb_error_type(&err.ty).unwrap_or((kind, retryable));
+            format!("{err:?}")
+        }
+        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    };
+
+    let mut err = Error::new(kind, message);
+
+    err = with_error_response_context(err, parts);
+
+    if retryable {
+        err = err.set_temporary();
+    }
+
+    Ok(err)
+}
+
+/// Map the error type like `com.amazonaws.dynamodb.v20120810#ThrottlingException`.
+fn parse_dynamodb_error_type(ty: &str) -> Option<(ErrorKind, bool)> {
+    let ty = ty.rsplit('#').next()?;
+    let v = match ty {
+        "ResourceNotFoundException" => (ErrorKind::ConfigInvalid, false),
+        "AccessDeniedException"
+        | "UnrecognizedClientException"
+        | "MissingAuthenticationTokenException"
+        | "InvalidSignatureException" => (ErrorKind::PermissionDenied, false),
+        "ProvisionedThroughputExceededException"
+        | "ThrottlingException"
+        | "RequestLimitExceeded" => (ErrorKind::RateLimited, true),
+        "ItemCollectionSizeLimitExceededException" => (ErrorKind::Unexpected, false),
+        "InternalServerError" | "ServiceUnavailable" => (ErrorKind::Unexpected, true),
+        _ => return None,
+    };
+    Some(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_parse_error() {
+        let resp = Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Buffer::from(
+                r#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException","message":"Rate of requests exceeds the allowed throughput."}"#,
+            ))
+            .unwrap();
+
+        let err = parse_error(resp).await.unwrap();
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+
+        let resp = Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Buffer::from(
+                r#"{"__type":"com.amazonaws.dynamodb.v20120810#ResourceNotFoundException","message":"Requested resource not found"}"#,
+            ))
+            .unwrap();
+
+        let err = parse_error(resp).await.unwrap();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        assert!(!err.is_temporary());
+    }
+}
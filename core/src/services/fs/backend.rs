@@ -18,16 +18,23 @@
 use std::io::SeekFrom;
 use std::path::Path;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
 
-use chrono::DateTime;
 use log::debug;
+use log::warn;
 use serde::Deserialize;
 use serde::Serialize;
 
 use super::core::*;
+use super::direct;
+use super::direct::FsDirectReader;
+use super::direct::FsDirectWriter;
 use super::lister::FsLister;
 use super::reader::FsReader;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+use super::uring::*;
+use super::writer::write_all_at;
 use super::writer::FsWriter;
 use super::writer::FsWriters;
 use crate::raw::*;
@@ -43,6 +50,21 @@ pub struct FsConfig {
 
     /// tmp dir for atomic write
     pub atomic_write_dir: Option<String>,
+
+    /// write into a tmp file in the same dir and rename it while closing
+    pub atomic_write: bool,
+
+    /// how to handle symlinks in stat and list: `follow`, `skip` or `surface`
+    pub symlink_policy: Option<String>,
+
+    /// preserve and expose posix attributes via user metadata
+    pub enable_posix_metadata: bool,
+
+    /// open files with O_DIRECT to bypass the page cache
+    pub enable_direct_io: bool,
+
+    /// use io_uring for read, write and stat if available
+    pub enable_io_uring: bool,
 }
 
 impl Configurator for FsConfig {
@@ -82,6 +104,88 @@ impl FsBuilder {
 
         self
     }
+
+    /// Enable atomic write in place.
+    ///
+    /// Data will be written into a hidden tmp file in the same directory of the target
+    /// file, and renamed to the target while closing. So readers will never observe
+    /// partially written files. Rename in the same directory won't cross file systems,
+    /// and will replace the existing file on both unix and windows.
+    ///
+    /// # Notes
+    ///
+    /// - [`FsBuilder::atomic_write_dir`] takes precedence over this option if set.
+    /// - When append is enabled and the target file exists, we will not use atomic
+    ///   write to avoid data loss and performance issue.
+    pub fn atomic_write(mut self) -> Self {
+        self.config.atomic_write = true;
+        self
+    }
+
+    /// Set how to handle symlinks in stat and list.
+    ///
+    /// - `follow_stat` (default): follow symlinks in stat, but list symlinks as
+    ///   [`EntryMode::Unknown`] without following them.
+    /// - `follow`: follow symlinks and return the metadata of their targets.
+    ///   Broken symlinks will be listed as [`EntryMode::Unknown`].
+    /// - `skip`: skip symlinks in list, and return `NotFound` error in stat.
+    /// - `surface`: return symlinks themselves as [`EntryMode::Unknown`], and their
+    ///   targets can be visited by [`Metadata::symlink_target`].
+    ///
+    /// Symlinks to directories will be listed as directories with `follow`, so recursive
+    /// list will never end on cyclic symlinks.
+    pub fn symlink_policy(mut self, policy: &str) -> Self {
+        if !policy.is_empty() {
+            self.config.symlink_policy = Some(policy.to_string());
+        }
+
+        self
+    }
+
+    /// Preserve and expose POSIX attributes via user metadata.
+    ///
+    /// - `mode`: the permission bits of file in octal, like `644`.
+    /// - `uid` and `gid`: the owner of file.
+    /// - all other keys are extended attributes under `user.` namespace.
+    ///
+    /// `stat` will return them in user metadata, and `write` will apply them
+    /// from user metadata.
+    ///
+    /// # Notes
+    ///
+    /// - This only works on unix, and extended attributes only work on linux.
+    /// - Changing owner requires the privilege of the process.
+    pub fn enable_posix_metadata(mut self) -> Self {
+        self.config.enable_posix_metadata = true;
+        self
+    }
+
+    /// Open files with `O_DIRECT` to bypass the page cache.
+    ///
+    /// # Notes
+    ///
+    /// - This only works on linux, the buffered io will be used otherwise.
+    /// - The underlying file system must support direct io, for example, tmpfs doesn't.
+    /// - Reads and writes will be aligned to 4 KiB blocks by the reader and writer.
+    /// - Append will always use buffered io.
+    /// - This takes precedence over [`FsBuilder::enable_io_uring`].
+    pub fn enable_direct_io(mut self) -> Self {
+        self.config.enable_direct_io = true;
+        self
+    }
+
+    /// Use io_uring for read, write and stat.
+    ///
+    /// # Notes
+    ///
+    /// - This requires the `services-fs-io-uring` feature and only works on linux,
+    ///   the default implementation will be used otherwise.
+    /// - If io_uring is not supported by the kernel, we will fall back to polling.
+    /// - Append will always use the default implementation.
+    pub fn enable_io_uring(mut self) -> Self {
+        self.config.enable_io_uring = true;
+        self
+    }
 }
 
 impl Builder for FsBuilder {
@@ -156,16 +260,69 @@ impl Builder for FsBuilder {
             })
             .unwrap_or(Ok(None))?;
 
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        let uring = if self.config.enable_io_uring {
+            match FsUring::new() {
+                Ok(uring) => Some(Arc::new(uring)),
+                Err(err) => {
+                    warn!("io_uring is not available, fall back to tokio: {err}");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        #[cfg(not(all(target_os = "linux", feature = "services-fs-io-uring")))]
+        if self.config.enable_io_uring {
+            warn!("io_uring requires services-fs-io-uring feature on linux, fall back to tokio");
+        }
+
+        let symlink_policy = self
+            .config
+            .symlink_policy
+            .as_deref()
+            .map(SymlinkPolicy::from_str)
+            .transpose()?
+            .unwrap_or_default();
+
+        let posix_metadata = self.config.enable_posix_metadata && cfg!(unix);
+        if self.config.enable_posix_metadata && !posix_metadata {
+            warn!("posix metadata is only supported on unix, ignored");
+        }
+
+        let direct_io = self.config.enable_direct_io && cfg!(target_os = "linux");
+        if self.config.enable_direct_io && !direct_io {
+            warn!("direct io is only supported on linux, fall back to buffered io");
+        }
+
         Ok(FsBackend {
             core: Arc::new(FsCore {
                 root,
                 atomic_write_dir,
+                atomic_write: self.config.atomic_write,
+                symlink_policy,
                 buf_pool: oio::PooledBuf::new(16).with_initial_capacity(256 * 1024),
+                posix_metadata,
+                direct_io,
+                #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+                uring,
             }),
         })
     }
 }
 
+type FsBaseReader = TwoWays<FsReader<tokio::fs::File>, FsDirectReader>;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+type FsAsyncReader = TwoWays<FsBaseReader, FsUringReader>;
+#[cfg(not(all(target_os = "linux", feature = "services-fs-io-uring")))]
+type FsAsyncReader = FsBaseReader;
+
+type FsBaseWriter = TwoWays<FsWriters, FsDirectWriter>;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+type FsAsyncWriter = TwoWays<FsBaseWriter, FsUringWriter>;
+#[cfg(not(all(target_os = "linux", feature = "services-fs-io-uring")))]
+type FsAsyncWriter = FsBaseWriter;
+
 /// Backend is used to serve `Accessor` support for posix-like fs.
 #[derive(Debug, Clone)]
 pub struct FsBackend {
@@ -173,11 +330,11 @@ pub struct FsBackend {
 }
 
 impl Access for FsBackend {
-    type Reader = FsReader<tokio::fs::File>;
-    type Writer = FsWriters;
+    type Reader = FsAsyncReader;
+    type Writer = FsAsyncWriter;
     type Lister = Option<FsLister<tokio::fs::ReadDir>>;
-    type BlockingReader = FsReader<std::fs::File>;
-    type BlockingWriter = FsWriter<std::fs::File>;
+    type BlockingReader = TwoWays<FsReader<std::fs::File>, FsDirectReader>;
+    type BlockingWriter = TwoWays<FsWriter<std::fs::File>, FsDirectWriter>;
     type BlockingLister = Option<FsLister<std::fs::ReadDir>>;
 
     fn info(&self) -> Arc<AccessorInfo> {
@@ -193,6 +350,9 @@ impl Access for FsBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_with_user_metadata: self.core.posix_metadata,
+                write_at: true,
+                allocate: true,
                 create_dir: true,
                 delete: true,
 
@@ -221,22 +381,34 @@ impl Access for FsBackend {
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
-        let meta = tokio::fs::metadata(&p).await.map_err(new_std_io_error)?;
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        if let Some(uring) = self
+            .core
+            .uring
+            .as_ref()
+            .filter(|_| self.core.symlink_policy.follow_in_stat())
+        {
+            let m = uring.stat(p.clone()).await?;
+            let m = self.core.with_posix_metadata(p, m).await?;
+            return Ok(RpStat::new(m));
+        }
 
-        let mode = if meta.is_dir() {
-            EntryMode::DIR
-        } else if meta.is_file() {
-            EntryMode::FILE
-        } else {
-            EntryMode::Unknown
-        };
-        let m = Metadata::new(mode)
-            .with_content_length(meta.len())
-            .with_last_modified(
-                meta.modified()
-                    .map(DateTime::from)
-                    .map_err(new_std_io_error)?,
-            );
+        let meta = match self.core.symlink_policy.follow_in_stat() {
+            true => tokio::fs::metadata(&p).await,
+            false => tokio::fs::symlink_metadata(&p).await,
+        }
+        .map_err(new_std_io_error)?;
+
+        let mut m = parse_metadata(&meta)?;
+        if meta.is_symlink() {
+            if self.core.symlink_policy == SymlinkPolicy::Skip {
+                return Err(new_symlink_skipped_error(&p));
+            }
+
+            let target = tokio::fs::read_link(&p).await.map_err(new_std_io_error)?;
+            m.set_symlink_target(&target.to_string_lossy());
+        }
+        let m = self.core.with_posix_metadata(p, m).await?;
 
         Ok(RpStat::new(m))
     }
@@ -253,6 +425,21 @@ impl Access for FsBackend {
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
+        if self.core.direct_io {
+            let f = direct::open(p, false).await?;
+            let r = TwoWays::Two(FsDirectReader::new(f, args.range()));
+            #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+            let r = TwoWays::One(r);
+            return Ok((RpRead::new(), r));
+        }
+
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        if let Some(uring) = &self.core.uring {
+            let f = uring.open_read(p).await?;
+            let r = FsUringReader::new(uring.clone(), f, args.range());
+            return Ok((RpRead::new(), TwoWays::Two(r)));
+        }
+
         let mut f = tokio::fs::OpenOptions::new()
             .read(true)
             .open(&p)
@@ -267,11 +454,13 @@ impl Access for FsBackend {
                 .map_err(new_std_io_error)?;
         }
 
-        let r = FsReader::new(
+        let r = TwoWays::One(FsReader::new(
             self.core.clone(),
             f,
             args.range().size().unwrap_or(u64::MAX) as _,
-        );
+        ));
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        let r = TwoWays::One(r);
         Ok((RpRead::new(), r))
     }
 
@@ -302,9 +491,43 @@ impl Access for FsBackend {
                 .ensure_write_abs_path(&self.core.root, path)
                 .await?;
 
-            (p, None)
+            if self.core.atomic_write
+                && !(op.append() && tokio::fs::try_exists(&p).await.map_err(new_std_io_error)?)
+            {
+                let tmp_path = tmp_path_next_to(&p, path);
+                (p, Some(tmp_path))
+            } else {
+                (p, None)
+            }
         };
 
+        if self.core.direct_io && !op.append() {
+            let f = direct::open(
+                tmp_path.clone().unwrap_or_else(|| target_path.clone()),
+                true,
+            )
+            .await?;
+            self.core
+                .apply_posix_metadata(tmp_path.as_ref().unwrap_or(&target_path), &op)
+                .await?;
+            let w = TwoWays::Two(FsDirectWriter::new(target_path, tmp_path, f));
+            #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+            let w = TwoWays::One(w);
+            return Ok((RpWrite::default(), w));
+        }
+
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        if let Some(uring) = self.core.uring.as_ref().filter(|_| !op.append()) {
+            let f = uring
+                .open_write(tmp_path.clone().unwrap_or_else(|| target_path.clone()))
+                .await?;
+            self.core
+                .apply_posix_metadata(tmp_path.as_ref().unwrap_or(&target_path), &op)
+                .await?;
+            let w = FsUringWriter::new(uring.clone(), target_path, tmp_path, f);
+            return Ok((RpWrite::default(), TwoWays::Two(w)));
+        }
+
         let mut open_options = tokio::fs::OpenOptions::new();
         open_options.create(true).write(true);
         if op.append() {
@@ -317,6 +540,9 @@ impl Access for FsBackend {
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .await
             .map_err(new_std_io_error)?;
+        self.core
+            .apply_posix_metadata(tmp_path.as_ref().unwrap_or(&target_path), &op)
+            .await?;
 
         let w = FsWriter::new(target_path, tmp_path, f);
 
@@ -329,10 +555,56 @@ impl Access for FsBackend {
                 op.concurrent(),
             ))
         };
+        let w = TwoWays::One(w);
+        #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+        let w = TwoWays::One(w);
 
         Ok((RpWrite::default(), w))
     }
 
+    /// Write in place with `pwrite`, `atomic_write` and `direct_io` are not
+    /// applied here since the file is expected to be written by multiple calls.
+    async fn write_at(&self, path: &str, args: OpWriteAt) -> Result<RpWriteAt> {
+        let p = self
+            .core
+            .ensure_write_abs_path(&self.core.root, path)
+            .await?;
+
+        let f = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(&p)
+            .await
+            .map_err(new_std_io_error)?
+            .into_std()
+            .await;
+
+        let offset = args.offset();
+        let buf = args.into_body();
+        tokio::task::spawn_blocking(move || write_all_at(&f, buf, offset))
+            .await
+            .map_err(new_task_join_error)??;
+
+        Ok(RpWriteAt::default())
+    }
+
+    /// Allocate a sparse file with `set_len`, so no disk space will be used
+    /// until the file is written on file systems that support sparse files.
+    async fn allocate(&self, path: &str, args: OpAllocate) -> Result<RpAllocate> {
+        let p = self
+            .core
+            .ensure_write_abs_path(&self.core.root, path)
+            .await?;
+
+        let f = tokio::fs::File::create(&p)
+            .await
+            .map_err(new_std_io_error)?;
+        f.set_len(args.size()).await.map_err(new_std_io_error)?;
+
+        Ok(RpAllocate::default())
+    }
+
     async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
@@ -367,7 +639,7 @@ impl Access for FsBackend {
             }
         };
 
-        let rd = FsLister::new(&self.core.root, f, arg);
+        let rd = FsLister::new(&self.core.root, f, arg, self.core.symlink_policy);
 
         Ok((RpList::default(), Some(rd)))
     }
@@ -417,22 +689,22 @@ impl Access for FsBackend {
     fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
-        let meta = std::fs::metadata(p).map_err(new_std_io_error)?;
+        let meta = match self.core.symlink_policy.follow_in_stat() {
+            true => std::fs::metadata(&p),
+            false => std::fs::symlink_metadata(&p),
+        }
+        .map_err(new_std_io_error)?;
 
-        let mode = if meta.is_dir() {
-            EntryMode::DIR
-        } else if meta.is_file() {
-            EntryMode::FILE
-        } else {
-            EntryMode::Unknown
-        };
-        let m = Metadata::new(mode)
-            .with_content_length(meta.len())
-            .with_last_modified(
-                meta.modified()
-                    .map(DateTime::from)
-                    .map_err(new_std_io_error)?,
-            );
+        let mut m = parse_metadata(&meta)?;
+        if meta.is_symlink() {
+            if self.core.symlink_policy == SymlinkPolicy::Skip {
+                return Err(new_symlink_skipped_error(&p));
+            }
+
+            let target = std::fs::read_link(&p).map_err(new_std_io_error)?;
+            m.set_symlink_target(&target.to_string_lossy());
+        }
+        let m = self.core.blocking_with_posix_metadata(&p, m)?;
 
         Ok(RpStat::new(m))
     }
@@ -440,6 +712,12 @@ impl Access for FsBackend {
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
+        if self.core.direct_io {
+            let f = direct::blocking_open(&p, false)?;
+            let r = FsDirectReader::new(f, args.range());
+            return Ok((RpRead::new(), TwoWays::Two(r)));
+        }
+
         let mut f = std::fs::OpenOptions::new()
             .read(true)
             .open(p)
@@ -457,7 +735,7 @@ impl Access for FsBackend {
             f,
             args.range().size().unwrap_or(u64::MAX) as _,
         );
-        Ok((RpRead::new(), r))
+        Ok((RpRead::new(), TwoWays::One(r)))
     }
 
     fn blocking_write(&self, path: &str, op: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
@@ -484,9 +762,24 @@ impl Access for FsBackend {
                 .core
                 .blocking_ensure_write_abs_path(&self.core.root, path)?;
 
-            (p, None)
+            if self.core.atomic_write
+                && !(op.append() && p.try_exists().map_err(new_std_io_error)?)
+            {
+                let tmp_path = tmp_path_next_to(&p, path);
+                (p, Some(tmp_path))
+            } else {
+                (p, None)
+            }
         };
 
+        if self.core.direct_io && !op.append() {
+            let f = direct::blocking_open(tmp_path.as_ref().unwrap_or(&target_path), true)?;
+            self.core
+                .blocking_apply_posix_metadata(tmp_path.as_ref().unwrap_or(&target_path), &op)?;
+            let w = FsDirectWriter::new(target_path, tmp_path, f);
+            return Ok((RpWrite::new(), TwoWays::Two(w)));
+        }
+
         let mut f = std::fs::OpenOptions::new();
         f.create(true).write(true);
 
@@ -499,8 +792,11 @@ impl Access for FsBackend {
         let f = f
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .map_err(new_std_io_error)?;
+        self.core
+            .blocking_apply_posix_metadata(tmp_path.as_ref().unwrap_or(&target_path), &op)?;
 
-        Ok((RpWrite::new(), FsWriter::new(target_path, tmp_path, f)))
+        let w = FsWriter::new(target_path, tmp_path, f);
+        Ok((RpWrite::new(), TwoWays::One(w)))
     }
 
     fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
@@ -537,7 +833,7 @@ impl Access for FsBackend {
             }
         };
 
-        let rd = FsLister::new(&self.core.root, f, arg);
+        let rd = FsLister::new(&self.core.root, f, arg, self.core.symlink_policy);
 
         Ok((RpList::default(), Some(rd)))
     }
@@ -577,6 +873,87 @@ impl Access for FsBackend {
 mod tests {
     use super::*;
 
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_policy() {
+        let root = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("file"), b"hello").unwrap();
+        std::fs::create_dir_all(root.join("dir")).unwrap();
+        std::os::unix::fs::symlink(root.join("file"), root.join("link")).unwrap();
+        std::os::unix::fs::symlink(root.join("dir"), root.join("dirlink")).unwrap();
+
+        let new_op = |policy: &str| {
+            Operator::new(
+                FsBuilder::default()
+                    .root(&root.to_string_lossy())
+                    .symlink_policy(policy),
+            )
+            .unwrap()
+            .finish()
+            .blocking()
+        };
+        let list = |op: &BlockingOperator| {
+            let mut entries = op
+                .list("/")
+                .unwrap()
+                .into_iter()
+                .map(|e| e.path().to_string())
+                .collect::<Vec<_>>();
+            entries.sort();
+            entries
+        };
+
+        for policy in ["", "follow_stat"] {
+            let op = new_op(policy);
+            let meta = op.stat("link").unwrap();
+            assert!(meta.is_file());
+            assert_eq!(meta.content_length(), 5);
+            assert_eq!(list(&op), vec!["dir/", "dirlink", "file", "link"]);
+        }
+
+        let op = new_op("follow");
+        let meta = op.stat("link").unwrap();
+        assert!(meta.is_file());
+        assert_eq!(meta.content_length(), 5);
+        assert_eq!(list(&op), vec!["dir/", "dirlink/", "file", "link"]);
+
+        let op = new_op("skip");
+        let err = op.stat("link").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert_eq!(list(&op), vec!["dir/", "file"]);
+
+        let op = new_op("surface");
+        let meta = op.stat("link").unwrap();
+        assert_eq!(meta.mode(), EntryMode::Unknown);
+        assert!(meta.is_symlink());
+        assert_eq!(
+            meta.symlink_target(),
+            Some(root.join("file").to_string_lossy().as_ref())
+        );
+
+        let err = FsBuilder::default()
+            .root("/tmp")
+            .symlink_policy("ignore")
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_tmp_path_next_to() {
+        let target = PathBuf::from("/tmp/abc/hello.txt");
+        let tmp_path = tmp_path_next_to(&target, "abc/hello.txt");
+
+        assert_eq!(tmp_path.parent(), target.parent());
+        let name = tmp_path.file_name().unwrap().to_string_lossy();
+        assert!(name.starts_with(".hello.txt."));
+        assert!(name.ends_with(".tmp"));
+    }
+
     #[test]
     fn test_tmp_file_of() {
         let cases = vec![
@@ -17,17 +17,70 @@
 
 use std::path::Path;
 use std::path::PathBuf;
+use std::str::FromStr;
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+use std::sync::Arc;
 
 use uuid::Uuid;
 
+use super::posix;
+
 use crate::raw::*;
 use crate::*;
 
+/// SymlinkPolicy decides how symlinks are handled in stat and list.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Follow symlinks in stat, but list symlinks themselves without following them.
+    #[default]
+    FollowStat,
+    /// Follow symlinks and return the metadata of their targets.
+    Follow,
+    /// Skip symlinks in list, and return `NotFound` in stat.
+    Skip,
+    /// Return symlinks themselves with their targets in metadata.
+    Surface,
+}
+
+impl SymlinkPolicy {
+    /// Returns true if stat should return the metadata of symlink targets.
+    pub fn follow_in_stat(self) -> bool {
+        matches!(self, SymlinkPolicy::FollowStat | SymlinkPolicy::Follow)
+    }
+}
+
+impl FromStr for SymlinkPolicy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "follow_stat" => Ok(SymlinkPolicy::FollowStat),
+            "follow" => Ok(SymlinkPolicy::Follow),
+            "skip" => Ok(SymlinkPolicy::Skip),
+            "surface" => Ok(SymlinkPolicy::Surface),
+            v => Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "symlink policy must be one of follow_stat, follow, skip or surface",
+            )
+            .with_context("symlink_policy", v)),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct FsCore {
     pub root: PathBuf,
     pub atomic_write_dir: Option<PathBuf>,
+    pub atomic_write: bool,
+    pub symlink_policy: SymlinkPolicy,
     pub buf_pool: oio::PooledBuf,
+    /// Expose and apply POSIX attributes via user metadata.
+    pub posix_metadata: bool,
+    /// Open files with `O_DIRECT` to bypass the page cache.
+    pub direct_io: bool,
+    /// The io_uring dispatcher, only set while io_uring is enabled and available.
+    #[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+    pub uring: Option<Arc<super::uring::FsUring>>,
 }
 
 impl FsCore {
@@ -84,6 +137,63 @@ impl FsCore {
 
         Ok(p)
     }
+
+    /// Fill POSIX attributes into metadata if enabled.
+    pub async fn with_posix_metadata(&self, path: PathBuf, mut m: Metadata) -> Result<Metadata> {
+        if self.posix_metadata {
+            m.with_user_metadata(posix::read(path).await?);
+        }
+        Ok(m)
+    }
+
+    /// Synchronously fill POSIX attributes into metadata if enabled.
+    pub fn blocking_with_posix_metadata(&self, path: &Path, mut m: Metadata) -> Result<Metadata> {
+        if self.posix_metadata {
+            m.with_user_metadata(posix::blocking_read(path)?);
+        }
+        Ok(m)
+    }
+
+    /// Apply POSIX attributes from user metadata of write if enabled.
+    pub async fn apply_posix_metadata(&self, path: &Path, op: &OpWrite) -> Result<()> {
+        match op.user_metadata() {
+            Some(attrs) if self.posix_metadata => {
+                posix::apply(path.to_path_buf(), attrs.clone()).await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Synchronously apply POSIX attributes from user metadata of write if enabled.
+    pub fn blocking_apply_posix_metadata(&self, path: &Path, op: &OpWrite) -> Result<()> {
+        match op.user_metadata() {
+            Some(attrs) if self.posix_metadata => posix::blocking_apply(path, attrs),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Build metadata from the metadata of file system.
+///
+/// Symlinks will be returned as [`EntryMode::Unknown`].
+pub fn parse_metadata(meta: &std::fs::Metadata) -> Result<Metadata> {
+    let mode = if meta.is_dir() {
+        EntryMode::DIR
+    } else if meta.is_file() {
+        EntryMode::FILE
+    } else {
+        EntryMode::Unknown
+    };
+
+    Ok(Metadata::new(mode)
+        .with_content_length(meta.len())
+        .with_last_modified(meta.modified().map_err(new_std_io_error)?.into()))
+}
+
+/// Build the error for symlinks that skipped by [`SymlinkPolicy::Skip`].
+pub fn new_symlink_skipped_error(path: &Path) -> Error {
+    Error::new(ErrorKind::NotFound, "symlink is skipped by symlink policy")
+        .with_context("path", path.to_string_lossy())
 }
 
 #[inline]
@@ -93,3 +203,9 @@ pub fn tmp_file_of(path: &str) -> String {
 
     format!("{name}.{uuid}")
 }
+
+/// Build the hidden tmp file path next to the target file for atomic write.
+#[inline]
+pub fn tmp_path_next_to(target: &Path, path: &str) -> PathBuf {
+    target.with_file_name(format!(".{}.tmp", tmp_file_of(path)))
+}
@@ -0,0 +1,459 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Direct I/O support for fs service.
+//!
+//! Files opened with `O_DIRECT` bypass the page cache, but require the memory
+//! address, file offset and length of every io to be aligned with the block size
+//! of the underlying storage. Readers and writers here take care of the alignment
+//! so that users can still read arbitrary ranges and write arbitrary sizes.
+
+use std::alloc::Layout;
+use std::fs::File;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::path::Path;
+use std::path::PathBuf;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// The alignment used for direct io.
+///
+/// 4 KiB is the logical block size for most of the storage devices, and a
+/// multiple of the 512 bytes sector size of the rest.
+const DIRECT_IO_ALIGN: usize = 4096;
+
+/// The size of the aligned buffer used by reader and writer.
+///
+/// Use 2 MiB as default value, the same as [`super::reader::FsReader`].
+const DIRECT_IO_BUF_SIZE: usize = 2 * 1024 * 1024;
+
+#[inline]
+fn align_down(v: u64) -> u64 {
+    v & !(DIRECT_IO_ALIGN as u64 - 1)
+}
+
+#[inline]
+fn align_up(v: usize) -> usize {
+    (v + DIRECT_IO_ALIGN - 1) & !(DIRECT_IO_ALIGN - 1)
+}
+
+/// Open file with `O_DIRECT`.
+///
+/// The flag is only available on linux, other platforms will open the file
+/// as normal.
+pub fn blocking_open(path: &Path, write: bool) -> Result<File> {
+    let mut opts = std::fs::OpenOptions::new();
+    if write {
+        opts.create(true).write(true).truncate(true);
+    } else {
+        opts.read(true);
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.custom_flags(libc::O_DIRECT);
+    }
+
+    opts.open(path).map_err(new_std_io_error)
+}
+
+/// Open file with `O_DIRECT` without blocking the async runtime.
+pub async fn open(path: PathBuf, write: bool) -> Result<File> {
+    tokio::task::spawn_blocking(move || blocking_open(&path, write))
+        .await
+        .map_err(new_task_join_error)?
+}
+
+/// AlignedBuf is a fixed capacity buffer whose memory address is aligned to
+/// [`DIRECT_IO_ALIGN`].
+struct AlignedBuf {
+    ptr: NonNull<u8>,
+    cap: usize,
+    len: usize,
+}
+
+/// # Safety
+///
+/// AlignedBuf owns its memory exclusively, just like `Vec<u8>`.
+unsafe impl Send for AlignedBuf {}
+unsafe impl Sync for AlignedBuf {}
+
+impl AlignedBuf {
+    fn new(cap: usize) -> Self {
+        let cap = align_up(cap.max(1));
+        let layout = Self::layout(cap);
+        // Safety: layout has non-zero size.
+        let ptr = unsafe { std::alloc::alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+
+        Self { ptr, cap, len: 0 }
+    }
+
+    fn layout(cap: usize) -> Layout {
+        Layout::from_size_align(cap, DIRECT_IO_ALIGN).expect("direct io buffer layout is valid")
+    }
+
+    fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    fn remaining(&self) -> usize {
+        self.cap - self.len
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == self.cap
+    }
+
+    /// Copy as many bytes as possible from `src`, returns the copied size.
+    fn extend_from_slice(&mut self, src: &[u8]) -> usize {
+        let (len, n) = (self.len, src.len().min(self.remaining()));
+        self.as_full_mut()[len..len + n].copy_from_slice(&src[..n]);
+        self.len += n;
+        n
+    }
+
+    /// The whole buffer including the unfilled part.
+    fn as_full_mut(&mut self) -> &mut [u8] {
+        // Safety: ptr is valid for `cap` bytes and initialized by `alloc_zeroed`.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.cap) }
+    }
+}
+
+impl Deref for AlignedBuf {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // Safety: ptr is valid for `len` bytes.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl DerefMut for AlignedBuf {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        let len = self.len;
+        &mut self.as_full_mut()[..len]
+    }
+}
+
+impl Drop for AlignedBuf {
+    fn drop(&mut self) {
+        // Safety: ptr is allocated with the same layout.
+        unsafe { std::alloc::dealloc(self.ptr.as_ptr(), Self::layout(self.cap)) }
+    }
+}
+
+pub struct FsDirectReader {
+    f: Arc<File>,
+    buf: Option<AlignedBuf>,
+    offset: u64,
+    end: u64,
+}
+
+impl FsDirectReader {
+    pub fn new(f: File, range: BytesRange) -> Self {
+        Self {
+            f: Arc::new(f),
+            buf: Some(AlignedBuf::new(DIRECT_IO_BUF_SIZE)),
+            offset: range.offset(),
+            end: range
+                .size()
+                .map_or(u64::MAX, |v| range.offset().saturating_add(v)),
+        }
+    }
+
+    /// Read the next chunk starting from `offset` but no further than `end`.
+    ///
+    /// The read will be started at the aligned position before `offset`, and the
+    /// leading bytes will be skipped.
+    fn read_at(f: &File, buf: &mut AlignedBuf, offset: u64, end: u64) -> Result<Bytes> {
+        if offset >= end {
+            return Ok(Bytes::new());
+        }
+
+        let start = align_down(offset);
+        let want = (end - start).min(buf.capacity() as u64) as usize;
+        let size = align_up(want);
+
+        let mut filled = 0;
+        let full = buf.as_full_mut();
+        while filled < size {
+            let n = read_at(f, &mut full[filled..size], start + filled as u64)?;
+            filled += n;
+            // We reach the end of file, or the next read will be unaligned.
+            if n == 0 || filled % DIRECT_IO_ALIGN != 0 {
+                break;
+            }
+        }
+
+        let skip = (offset - start) as usize;
+        let stop = filled.min(want);
+        if stop <= skip {
+            return Ok(Bytes::new());
+        }
+        Ok(Bytes::copy_from_slice(&full[skip..stop]))
+    }
+}
+
+impl oio::Read for FsDirectReader {
+    async fn read(&mut self) -> Result<Buffer> {
+        let f = self.f.clone();
+        let mut buf = self.buf.take().expect("FsDirectReader must be initialized");
+        let (offset, end) = (self.offset, self.end);
+
+        let (buf, res) = tokio::task::spawn_blocking(move || {
+            let res = Self::read_at(&f, &mut buf, offset, end);
+            (buf, res)
+        })
+        .await
+        .map_err(new_task_join_error)?;
+        self.buf = Some(buf);
+
+        let bs = res?;
+        self.offset += bs.len() as u64;
+        Ok(Buffer::from(bs))
+    }
+}
+
+impl oio::BlockingRead for FsDirectReader {
+    fn read(&mut self) -> Result<Buffer> {
+        let buf = self
+            .buf
+            .as_mut()
+            .expect("FsDirectReader must be initialized");
+
+        let bs = Self::read_at(&self.f, buf, self.offset, self.end)?;
+        self.offset += bs.len() as u64;
+        Ok(Buffer::from(bs))
+    }
+}
+
+pub struct FsDirectWriter {
+    target_path: PathBuf,
+    tmp_path: Option<PathBuf>,
+
+    f: Arc<File>,
+    buf: Option<AlignedBuf>,
+    /// The position of the buffered data, always aligned.
+    pos: u64,
+}
+
+impl FsDirectWriter {
+    pub fn new(target_path: PathBuf, tmp_path: Option<PathBuf>, f: File) -> Self {
+        Self {
+            target_path,
+            tmp_path,
+            f: Arc::new(f),
+            buf: Some(AlignedBuf::new(DIRECT_IO_BUF_SIZE)),
+            pos: 0,
+        }
+    }
+
+    /// Copy data into the aligned buffer, and flush it while full.
+    fn write_buf(f: &File, buf: &mut AlignedBuf, pos: &mut u64, bs: Buffer) -> Result<()> {
+        for chunk in bs {
+            let mut chunk = chunk.as_ref();
+            while !chunk.is_empty() {
+                let n = buf.extend_from_slice(chunk);
+                chunk = &chunk[n..];
+
+                if buf.is_full() {
+                    write_all_at(f, buf, *pos)?;
+                    *pos += buf.len() as u64;
+                    buf.clear();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Flush the rest data and sync the file.
+    ///
+    /// The tail will be padded with zeros to keep aligned, and then truncated
+    /// to the real size.
+    fn finish(f: &File, buf: &mut AlignedBuf, pos: u64) -> Result<()> {
+        let len = buf.len();
+        if len > 0 {
+            let size = align_up(len);
+            let full = buf.as_full_mut();
+            full[len..size].fill(0);
+            write_all_at(f, &full[..size], pos)?;
+            f.set_len(pos + len as u64).map_err(new_std_io_error)?;
+            buf.clear();
+        }
+
+        f.sync_all().map_err(new_std_io_error)
+    }
+}
+
+impl oio::Write for FsDirectWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let f = self.f.clone();
+        let mut buf = self.buf.take().expect("FsDirectWriter must be initialized");
+        let mut pos = self.pos;
+
+        let (buf, pos, res) = tokio::task::spawn_blocking(move || {
+            let res = Self::write_buf(&f, &mut buf, &mut pos, bs);
+            (buf, pos, res)
+        })
+        .await
+        .map_err(new_task_join_error)?;
+        self.buf = Some(buf);
+        self.pos = pos;
+
+        res
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let f = self.f.clone();
+        let mut buf = self.buf.take().expect("FsDirectWriter must be initialized");
+        let pos = self.pos;
+
+        let (buf, res) = tokio::task::spawn_blocking(move || {
+            let res = Self::finish(&f, &mut buf, pos);
+            (buf, res)
+        })
+        .await
+        .map_err(new_task_join_error)?;
+        self.buf = Some(buf);
+        res?;
+
+        if let Some(tmp_path) = &self.tmp_path {
+            tokio::fs::rename(tmp_path, &self.target_path)
+                .await
+                .map_err(new_std_io_error)?;
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        if let Some(tmp_path) = &self.tmp_path {
+            tokio::fs::remove_file(tmp_path)
+                .await
+                .map_err(new_std_io_error)
+        } else {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "Fs doesn't support abort if atomic_write_dir is not set",
+            ))
+        }
+    }
+}
+
+impl oio::BlockingWrite for FsDirectWriter {
+    fn write(&mut self, bs: Buffer) -> Result<()> {
+        let buf = self
+            .buf
+            .as_mut()
+            .expect("FsDirectWriter must be initialized");
+        Self::write_buf(&self.f, buf, &mut self.pos, bs)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        let buf = self
+            .buf
+            .as_mut()
+            .expect("FsDirectWriter must be initialized");
+        Self::finish(&self.f, buf, self.pos)?;
+
+        if let Some(tmp_path) = &self.tmp_path {
+            std::fs::rename(tmp_path, &self.target_path).map_err(new_std_io_error)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_all_at(f: &File, mut buf: &[u8], mut offset: u64) -> Result<()> {
+    while !buf.is_empty() {
+        let n = write_at(f, buf, offset)?;
+        if n == 0 {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "failed to write whole buffer with direct io",
+            ));
+        }
+        buf = &buf[n..];
+        offset += n as u64;
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn read_at(f: &File, buf: &mut [u8], offset: u64) -> Result<usize> {
+    use std::os::windows::fs::FileExt;
+    f.seek_read(buf, offset).map_err(new_std_io_error)
+}
+
+#[cfg(unix)]
+fn read_at(f: &File, buf: &mut [u8], offset: u64) -> Result<usize> {
+    use std::os::unix::fs::FileExt;
+    f.read_at(buf, offset).map_err(new_std_io_error)
+}
+
+#[cfg(windows)]
+fn write_at(f: &File, buf: &[u8], offset: u64) -> Result<usize> {
+    use std::os::windows::fs::FileExt;
+    f.seek_write(buf, offset).map_err(new_std_io_error)
+}
+
+#[cfg(unix)]
+fn write_at(f: &File, buf: &[u8], offset: u64) -> Result<usize> {
+    use std::os::unix::fs::FileExt;
+    f.write_at(buf, offset).map_err(new_std_io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align() {
+        assert_eq!(align_down(0), 0);
+        assert_eq!(align_down(4095), 0);
+        assert_eq!(align_down(4097), 4096);
+        assert_eq!(align_up(0), 0);
+        assert_eq!(align_up(1), 4096);
+        assert_eq!(align_up(8192), 8192);
+    }
+
+    #[test]
+    fn test_aligned_buf() {
+        let mut buf = AlignedBuf::new(100);
+        assert_eq!(buf.capacity(), DIRECT_IO_ALIGN);
+        assert_eq!(buf.ptr.as_ptr() as usize % DIRECT_IO_ALIGN, 0);
+
+        let data = vec![1; 5000];
+        assert_eq!(buf.extend_from_slice(&data), DIRECT_IO_ALIGN);
+        assert!(buf.is_full());
+        assert_eq!(buf.extend_from_slice(&data), 0);
+
+        buf.clear();
+        assert_eq!(buf.extend_from_slice(&data[..10]), 10);
+        assert_eq!(&buf[..], &data[..10]);
+    }
+}
@@ -15,12 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::core::*;
 use crate::raw::*;
 use crate::Metadata;
 use crate::Result;
 use crate::{EntryMode, Metakey};
-use std::path::Path;
-use std::path::PathBuf;
 
 pub struct FsLister<P> {
     root: PathBuf,
@@ -28,16 +30,28 @@ pub struct FsLister<P> {
     rd: P,
 
     op: OpList,
+    symlink_policy: SymlinkPolicy,
 }
 
 impl<P> FsLister<P> {
-    pub fn new(root: &Path, rd: P, arg: OpList) -> Self {
+    pub fn new(root: &Path, rd: P, arg: OpList, symlink_policy: SymlinkPolicy) -> Self {
         Self {
             root: root.to_owned(),
             rd,
             op: arg,
+            symlink_policy,
         }
     }
+
+    fn rel_path(&self, entry_path: &Path) -> String {
+        normalize_path(
+            &entry_path
+                .strip_prefix(&self.root)
+                .expect("cannot fail because the prefix is iterated")
+                .to_string_lossy()
+                .replace('\\', "/"),
+        )
+    }
 }
 
 /// # Safety
@@ -47,103 +61,117 @@ unsafe impl<P> Sync for FsLister<P> {}
 
 impl oio::List for FsLister<tokio::fs::ReadDir> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
-        let Some(de) = self.rd.next_entry().await.map_err(new_std_io_error)? else {
-            return Ok(None);
-        };
+        loop {
+            let Some(de) = self.rd.next_entry().await.map_err(new_std_io_error)? else {
+                return Ok(None);
+            };
 
-        let entry_path = de.path();
-        let rel_path = normalize_path(
-            &entry_path
-                .strip_prefix(&self.root)
-                .expect("cannot fail because the prefix is iterated")
-                .to_string_lossy()
-                .replace('\\', "/"),
-        );
+            let ft = de.file_type().await.map_err(new_std_io_error)?;
+            if ft.is_symlink() && self.symlink_policy == SymlinkPolicy::Skip {
+                continue;
+            }
 
-        let default_meta = self.op.metakey() == Metakey::Mode;
+            let entry_path = de.path();
+            let rel_path = self.rel_path(&entry_path);
 
-        let metadata = if default_meta {
-            let ft = de.file_type().await.map_err(new_std_io_error)?;
-            if ft.is_file() {
-                Metadata::new(EntryMode::FILE)
-            } else if ft.is_dir() {
-                Metadata::new(EntryMode::DIR)
+            let default_meta = self.op.metakey() == Metakey::Mode;
+            let follow = ft.is_symlink() && self.symlink_policy == SymlinkPolicy::Follow;
+
+            let mut metadata = if default_meta && !follow {
+                if ft.is_file() {
+                    Metadata::new(EntryMode::FILE)
+                } else if ft.is_dir() {
+                    Metadata::new(EntryMode::DIR)
+                } else {
+                    Metadata::new(EntryMode::Unknown)
+                }
             } else {
-                Metadata::new(EntryMode::Unknown)
+                let fs_meta = match follow {
+                    // Broken symlinks will fall back to the metadata of themselves.
+                    true => match tokio::fs::metadata(&entry_path).await {
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                            de.metadata().await
+                        }
+                        v => v,
+                    },
+                    false => de.metadata().await,
+                }
+                .map_err(new_std_io_error)?;
+                parse_metadata(&fs_meta)?
+            };
+
+            if ft.is_symlink() && self.symlink_policy == SymlinkPolicy::Surface {
+                let target = tokio::fs::read_link(&entry_path)
+                    .await
+                    .map_err(new_std_io_error)?;
+                metadata.set_symlink_target(&target.to_string_lossy());
             }
-        } else {
-            let fs_meta = de.metadata().await.map_err(new_std_io_error)?;
-            let mut meta = if fs_meta.file_type().is_file() {
-                Metadata::new(EntryMode::FILE)
-            } else if fs_meta.file_type().is_dir() {
-                Metadata::new(EntryMode::DIR)
+
+            let p = if metadata.is_dir() {
+                // Make sure we are returning the correct path.
+                &format!("{rel_path}/")
             } else {
-                Metadata::new(EntryMode::Unknown)
+                &rel_path
             };
-            meta.set_content_length(fs_meta.len());
-            meta.set_last_modified(fs_meta.modified().map_err(new_std_io_error)?.into());
-            meta
-        };
-
-        let p = if metadata.is_dir() {
-            // Make sure we are returning the correct path.
-            &format!("{rel_path}/")
-        } else {
-            &rel_path
-        };
-
-        Ok(Some(oio::Entry::new(p, metadata)))
+
+            return Ok(Some(oio::Entry::new(p, metadata)));
+        }
     }
 }
 
 impl oio::BlockingList for FsLister<std::fs::ReadDir> {
     fn next(&mut self) -> Result<Option<oio::Entry>> {
-        let de = match self.rd.next() {
-            Some(de) => de.map_err(new_std_io_error)?,
-            None => return Ok(None),
-        };
+        loop {
+            let de = match self.rd.next() {
+                Some(de) => de.map_err(new_std_io_error)?,
+                None => return Ok(None),
+            };
 
-        let entry_path = de.path();
-        let rel_path = normalize_path(
-            &entry_path
-                .strip_prefix(&self.root)
-                .expect("cannot fail because the prefix is iterated")
-                .to_string_lossy()
-                .replace('\\', "/"),
-        );
+            let ft = de.file_type().map_err(new_std_io_error)?;
+            if ft.is_symlink() && self.symlink_policy == SymlinkPolicy::Skip {
+                continue;
+            }
 
-        let default_meta = self.op.metakey() == Metakey::Mode;
+            let entry_path = de.path();
+            let rel_path = self.rel_path(&entry_path);
 
-        let metadata = if default_meta {
-            let ft = de.file_type().map_err(new_std_io_error)?;
-            if ft.is_file() {
-                Metadata::new(EntryMode::FILE)
-            } else if ft.is_dir() {
-                Metadata::new(EntryMode::DIR)
+            let default_meta = self.op.metakey() == Metakey::Mode;
+            let follow = ft.is_symlink() && self.symlink_policy == SymlinkPolicy::Follow;
+
+            let mut metadata = if default_meta && !follow {
+                if ft.is_file() {
+                    Metadata::new(EntryMode::FILE)
+                } else if ft.is_dir() {
+                    Metadata::new(EntryMode::DIR)
+                } else {
+                    Metadata::new(EntryMode::Unknown)
+                }
             } else {
-                Metadata::new(EntryMode::Unknown)
+                let fs_meta = match follow {
+                    // Broken symlinks will fall back to the metadata of themselves.
+                    true => match std::fs::metadata(&entry_path) {
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => de.metadata(),
+                        v => v,
+                    },
+                    false => de.metadata(),
+                }
+                .map_err(new_std_io_error)?;
+                parse_metadata(&fs_meta)?
+            };
+
+            if ft.is_symlink() && self.symlink_policy == SymlinkPolicy::Surface {
+                let target = std::fs::read_link(&entry_path).map_err(new_std_io_error)?;
+                metadata.set_symlink_target(&target.to_string_lossy());
             }
-        } else {
-            let fs_meta = de.metadata().map_err(new_std_io_error)?;
-            let mut meta = if fs_meta.file_type().is_file() {
-                Metadata::new(EntryMode::FILE)
-            } else if fs_meta.file_type().is_dir() {
-                Metadata::new(EntryMode::DIR)
+
+            let p = if metadata.is_dir() {
+                // Make sure we are returning the correct path.
+                &format!("{rel_path}/")
             } else {
-                Metadata::new(EntryMode::Unknown)
+                &rel_path
             };
-            meta.set_content_length(fs_meta.len());
-            meta.set_last_modified(fs_meta.modified().map_err(new_std_io_error)?.into());
-            meta
-        };
-
-        let p = if metadata.is_dir() {
-            // Make sure we are returning the correct path.
-            &format!("{rel_path}/")
-        } else {
-            &rel_path
-        };
-
-        Ok(Some(oio::Entry::new(p, metadata)))
+
+            return Ok(Some(oio::Entry::new(p, metadata)));
+        }
     }
 }
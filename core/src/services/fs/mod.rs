@@ -20,6 +20,11 @@ pub use backend::FsBuilder as Fs;
 pub use backend::FsConfig;
 
 mod core;
+mod direct;
 mod lister;
+mod posix;
 mod reader;
 mod writer;
+
+#[cfg(all(target_os = "linux", feature = "services-fs-io-uring"))]
+mod uring;
@@ -0,0 +1,253 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! POSIX attributes support for fs service.
+//!
+//! POSIX attributes are exposed via user metadata:
+//!
+//! - `mode`: the permission bits of file in octal, like `644`.
+//! - `uid`: the user id of file owner.
+//! - `gid`: the group id of file owner.
+//! - all other keys are mapped to extended attributes under `user.` namespace.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::raw::*;
+use crate::*;
+
+pub const MODE_KEY: &str = "mode";
+pub const UID_KEY: &str = "uid";
+pub const GID_KEY: &str = "gid";
+/// The namespace of extended attributes that user metadata mapped to.
+pub const XATTR_NAMESPACE: &str = "user.";
+
+/// Read POSIX attributes of given path as user metadata.
+pub async fn read(path: PathBuf) -> Result<HashMap<String, String>> {
+    tokio::task::spawn_blocking(move || blocking_read(&path))
+        .await
+        .map_err(new_task_join_error)?
+}
+
+/// Apply user metadata as POSIX attributes to given path.
+pub async fn apply(path: PathBuf, attrs: HashMap<String, String>) -> Result<()> {
+    tokio::task::spawn_blocking(move || blocking_apply(&path, &attrs))
+        .await
+        .map_err(new_task_join_error)?
+}
+
+#[cfg(unix)]
+pub fn blocking_read(path: &Path) -> Result<HashMap<String, String>> {
+    use std::os::unix::fs::MetadataExt;
+
+    let meta = std::fs::metadata(path).map_err(new_std_io_error)?;
+
+    let mut attrs = xattr::list(path)?;
+    attrs.insert(MODE_KEY.to_string(), format!("{:o}", meta.mode() & 0o7777));
+    attrs.insert(UID_KEY.to_string(), meta.uid().to_string());
+    attrs.insert(GID_KEY.to_string(), meta.gid().to_string());
+    Ok(attrs)
+}
+
+#[cfg(not(unix))]
+pub fn blocking_read(_: &Path) -> Result<HashMap<String, String>> {
+    Ok(HashMap::new())
+}
+
+#[cfg(unix)]
+pub fn blocking_apply(path: &Path, attrs: &HashMap<String, String>) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let parse_err = |key: &str, value: &str| {
+        Error::new(
+            ErrorKind::Unsupported,
+            "user metadata is not a valid posix attribute",
+        )
+        .with_context("key", key)
+        .with_context("value", value)
+    };
+
+    let (mut uid, mut gid) = (None, None);
+    for (key, value) in attrs {
+        match key.as_str() {
+            MODE_KEY => {
+                let mode = u32::from_str_radix(value, 8).map_err(|_| parse_err(key, value))?;
+                std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))
+                    .map_err(new_std_io_error)?;
+            }
+            UID_KEY => uid = Some(value.parse().map_err(|_| parse_err(key, value))?),
+            GID_KEY => gid = Some(value.parse().map_err(|_| parse_err(key, value))?),
+            _ => xattr::set(path, key, value)?,
+        }
+    }
+
+    if uid.is_some() || gid.is_some() {
+        std::os::unix::fs::chown(path, uid, gid).map_err(new_std_io_error)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn blocking_apply(_: &Path, attrs: &HashMap<String, String>) -> Result<()> {
+    if attrs.is_empty() {
+        return Ok(());
+    }
+
+    Err(Error::new(
+        ErrorKind::Unsupported,
+        "posix attributes are not supported on this platform",
+    ))
+}
+
+#[cfg(target_os = "linux")]
+mod xattr {
+    use std::collections::HashMap;
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    use super::XATTR_NAMESPACE;
+    use crate::raw::*;
+    use crate::*;
+
+    fn path_to_cstring(path: &Path) -> Result<CString> {
+        CString::new(path.as_os_str().as_bytes()).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "path contains nul byte")
+                .with_context("path", path.to_string_lossy())
+                .set_source(err)
+        })
+    }
+
+    /// Call xattr syscalls which follow the size probing convention: call with
+    /// empty buffer to get the size first, and then fill the buffer.
+    fn probe(f: impl Fn(*mut libc::c_void, usize) -> libc::ssize_t) -> Result<Vec<u8>> {
+        loop {
+            let size = f(std::ptr::null_mut(), 0);
+            if size < 0 {
+                return Err(new_std_io_error(std::io::Error::last_os_error()));
+            }
+
+            let mut buf = vec![0u8; size as usize];
+            let n = f(buf.as_mut_ptr() as *mut libc::c_void, buf.len());
+            if n >= 0 {
+                buf.truncate(n as usize);
+                return Ok(buf);
+            }
+
+            let err = std::io::Error::last_os_error();
+            // The attributes changed between two calls, try again.
+            if err.raw_os_error() != Some(libc::ERANGE) {
+                return Err(new_std_io_error(err));
+            }
+        }
+    }
+
+    pub fn list(path: &Path) -> Result<HashMap<String, String>> {
+        let p = path_to_cstring(path)?;
+
+        // Safety: the buffer is valid for given size.
+        let names =
+            match probe(|buf, size| unsafe { libc::listxattr(p.as_ptr(), buf.cast(), size) }) {
+                Ok(names) => names,
+                // The file system doesn't support xattrs.
+                Err(err) if err.kind() == ErrorKind::Unsupported => return Ok(HashMap::new()),
+                Err(err) => return Err(err),
+            };
+
+        let mut attrs = HashMap::new();
+        for name in names.split(|b| *b == 0).filter(|v| !v.is_empty()) {
+            let Some(key) = name.strip_prefix(XATTR_NAMESPACE.as_bytes()) else {
+                continue;
+            };
+            let name = CString::new(name).expect("xattr name must not contain nul byte");
+            // Safety: the buffer is valid for given size.
+            let value =
+                probe(|buf, size| unsafe { libc::getxattr(p.as_ptr(), name.as_ptr(), buf, size) })?;
+
+            attrs.insert(
+                String::from_utf8_lossy(key).to_string(),
+                String::from_utf8_lossy(&value).to_string(),
+            );
+        }
+        Ok(attrs)
+    }
+
+    pub fn set(path: &Path, key: &str, value: &str) -> Result<()> {
+        let p = path_to_cstring(path)?;
+        let name = CString::new(format!("{XATTR_NAMESPACE}{key}")).map_err(|err| {
+            Error::new(ErrorKind::Unsupported, "xattr name contains nul byte")
+                .with_context("key", key)
+                .set_source(err)
+        })?;
+
+        // Safety: all pointers are valid during the call.
+        let ret = unsafe {
+            libc::setxattr(
+                p.as_ptr(),
+                name.as_ptr(),
+                value.as_ptr().cast(),
+                value.len(),
+                0,
+            )
+        };
+        if ret < 0 {
+            return Err(new_std_io_error(std::io::Error::last_os_error()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+mod xattr {
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    use crate::*;
+
+    pub fn list(_: &Path) -> Result<HashMap<String, String>> {
+        Ok(HashMap::new())
+    }
+
+    pub fn set(_: &Path, key: &str, _: &str) -> Result<()> {
+        Err(
+            Error::new(ErrorKind::Unsupported, "xattrs are only supported on linux")
+                .with_context("key", key),
+        )
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        std::fs::write(&path, b"hello").unwrap();
+
+        let attrs = HashMap::from([(MODE_KEY.to_string(), "600".to_string())]);
+        blocking_apply(&path, &attrs).unwrap();
+
+        let got = blocking_read(&path).unwrap();
+        assert_eq!(got.get(MODE_KEY).map(String::as_str), Some("600"));
+        assert!(got.contains_key(UID_KEY));
+        assert!(got.contains_key(GID_KEY));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
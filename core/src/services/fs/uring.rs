@@ -0,0 +1,238 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! io_uring based implementation for fs service's read, write and stat.
+//!
+//! The io tasks are executed by a [`compio`] dispatcher, which will pick io_uring
+//! at runtime if the kernel supports it, and fall back to polling otherwise.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use compio::buf::buf_try;
+use compio::dispatcher::Dispatcher;
+use compio::io::AsyncReadAt;
+use compio::io::AsyncWriteAtExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// The max size of each read request.
+const READ_SIZE: usize = 256 * 1024;
+
+#[derive(Debug)]
+pub struct FsUring {
+    dispatcher: Dispatcher,
+    buf_pool: oio::PooledBuf,
+}
+
+impl FsUring {
+    /// Create a new io_uring dispatcher.
+    ///
+    /// Returns error if the dispatcher can't be initiated, callers should fall back
+    /// to the default implementation in this case.
+    pub fn new() -> Result<Self> {
+        let dispatcher = Dispatcher::new().map_err(|err| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "failed to initiate io_uring dispatcher",
+            )
+            .set_source(err)
+        })?;
+
+        Ok(Self {
+            dispatcher,
+            buf_pool: oio::PooledBuf::new(16).with_initial_capacity(READ_SIZE),
+        })
+    }
+
+    async fn exec<Fn, Fut, R>(&self, f: Fn) -> Result<R>
+    where
+        Fn: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = std::io::Result<R>> + 'static,
+        R: Send + 'static,
+    {
+        self.dispatcher
+            .dispatch(f)
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "io_uring spawn io task failed"))?
+            .await
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "io_uring task cancelled"))?
+            .map_err(new_std_io_error)
+    }
+
+    pub async fn stat(&self, path: PathBuf) -> Result<Metadata> {
+        let meta = self
+            .exec(move || async move { compio::fs::metadata(path).await })
+            .await?;
+
+        let mode = if meta.is_dir() {
+            EntryMode::DIR
+        } else if meta.is_file() {
+            EntryMode::FILE
+        } else {
+            EntryMode::Unknown
+        };
+        Ok(Metadata::new(mode)
+            .with_content_length(meta.len())
+            .with_last_modified(meta.modified().map_err(new_std_io_error)?.into()))
+    }
+
+    pub async fn open_read(&self, path: PathBuf) -> Result<compio::fs::File> {
+        self.exec(move || async move { compio::fs::OpenOptions::new().read(true).open(path).await })
+            .await
+    }
+
+    pub async fn open_write(&self, path: PathBuf) -> Result<compio::fs::File> {
+        self.exec(move || async move {
+            compio::fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(path)
+                .await
+        })
+        .await
+    }
+}
+
+pub struct FsUringReader {
+    uring: Arc<FsUring>,
+    file: compio::fs::File,
+    offset: u64,
+    end: u64,
+}
+
+impl FsUringReader {
+    pub fn new(uring: Arc<FsUring>, file: compio::fs::File, range: BytesRange) -> Self {
+        Self {
+            uring,
+            file,
+            offset: range.offset(),
+            end: range
+                .size()
+                .map_or(u64::MAX, |v| range.offset().saturating_add(v)),
+        }
+    }
+}
+
+impl oio::Read for FsUringReader {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.offset >= self.end {
+            return Ok(Buffer::new());
+        }
+
+        let size = (self.end - self.offset).min(READ_SIZE as u64) as usize;
+        let mut bs = self.uring.buf_pool.get();
+        bs.reserve(size);
+
+        let (f, pos) = (self.file.clone(), self.offset);
+        let (n, mut bs) = self
+            .uring
+            .exec(move || async move {
+                let (n, bs) = buf_try!(@try f.read_at(bs, pos).await);
+                Ok((n, bs))
+            })
+            .await?;
+
+        // The buffer could have more capacity than we want to read.
+        let frozen = bs.split_to(n.min(size)).freeze();
+        self.offset += frozen.len() as u64;
+        self.uring.buf_pool.put(bs);
+        Ok(Buffer::from(frozen))
+    }
+}
+
+pub struct FsUringWriter {
+    uring: Arc<FsUring>,
+    target_path: PathBuf,
+    tmp_path: Option<PathBuf>,
+
+    file: compio::fs::File,
+    offset: u64,
+}
+
+impl FsUringWriter {
+    pub fn new(
+        uring: Arc<FsUring>,
+        target_path: PathBuf,
+        tmp_path: Option<PathBuf>,
+        file: compio::fs::File,
+    ) -> Self {
+        Self {
+            uring,
+            target_path,
+            tmp_path,
+            file,
+            offset: 0,
+        }
+    }
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for FsUringWriter.
+unsafe impl Sync for FsUringWriter {}
+
+impl oio::Write for FsUringWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let size = bs.len() as u64;
+        let (mut f, pos) = (self.file.clone(), self.offset);
+
+        self.uring
+            .exec(move || async move {
+                let mut pos = pos;
+                for bs in bs {
+                    let len = bs.len() as u64;
+                    buf_try!(@try f.write_all_at(bs, pos).await);
+                    pos += len;
+                }
+                Ok(())
+            })
+            .await?;
+
+        self.offset += size;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let f = self.file.clone();
+        self.uring
+            .exec(move || async move { f.sync_all().await })
+            .await?;
+
+        if let Some(tmp_path) = &self.tmp_path {
+            tokio::fs::rename(tmp_path, &self.target_path)
+                .await
+                .map_err(new_std_io_error)?;
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        if let Some(tmp_path) = &self.tmp_path {
+            tokio::fs::remove_file(tmp_path)
+                .await
+                .map_err(new_std_io_error)
+        } else {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "Fs doesn't support abort if atomic_write_dir is not set",
+            ))
+        }
+    }
+}
@@ -126,22 +126,9 @@ impl oio::PositionWrite for FsWriter<tokio::fs::File> {
             .into_std()
             .await;
 
-        tokio::task::spawn_blocking(move || {
-            let mut buf = buf;
-            let mut offset = offset;
-            while !buf.is_empty() {
-                match write_at(&f, buf.chunk(), offset) {
-                    Ok(n) => {
-                        buf.advance(n);
-                        offset += n as u64
-                    }
-                    Err(e) => return Err(e),
-                }
-            }
-            Ok(())
-        })
-        .await
-        .map_err(new_task_join_error)?
+        tokio::task::spawn_blocking(move || write_all_at(&f, buf, offset))
+            .await
+            .map_err(new_task_join_error)?
     }
 
     async fn close(&self) -> Result<()> {
@@ -179,6 +166,16 @@ impl oio::PositionWrite for FsWriter<tokio::fs::File> {
     }
 }
 
+/// Write the whole buffer at given offset of file.
+pub fn write_all_at(f: &File, mut buf: Buffer, mut offset: u64) -> Result<()> {
+    while !buf.is_empty() {
+        let n = write_at(f, buf.chunk(), offset)?;
+        buf.advance(n);
+        offset += n as u64;
+    }
+    Ok(())
+}
+
 #[cfg(windows)]
 fn write_at(f: &File, buf: &[u8], offset: u64) -> Result<usize> {
     use std::os::windows::fs::FileExt;
@@ -30,10 +30,12 @@ use reqsign::GoogleTokenLoader;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json;
+use tokio::sync::OnceCell;
 
 use super::core::*;
 use super::error::parse_error;
 use super::lister::GcsLister;
+use super::writer::GcsSessionStore;
 use super::writer::GcsWriter;
 use super::writer::GcsWriters;
 use crate::raw::*;
@@ -41,6 +43,7 @@ use crate::*;
 
 const DEFAULT_GCS_ENDPOINT: &str = "https://storage.googleapis.com";
 const DEFAULT_GCS_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+const DEFAULT_RESUMABLE_CHUNK_MAX_RETRIES: usize = 3;
 
 /// [Google Cloud Storage](https://cloud.google.com/storage) services support.
 #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -78,6 +81,25 @@ pub struct GcsConfig {
     ///
     /// Takes precedence over `credential` and `credential_path`.
     pub token: Option<String>,
+    /// Checksum Algorithm to use when uploading parts.
+    ///
+    /// Available options:
+    /// - "crc32c"
+    pub checksum_algorithm: Option<String>,
+    /// Disable resumable upload and use XML multipart upload for writes instead.
+    pub disable_resumable_upload: bool,
+    /// The access id of HMAC key used to sign presigned requests.
+    pub hmac_access_id: Option<String>,
+    /// The secret of HMAC key used to sign presigned requests.
+    pub hmac_secret: Option<String>,
+    /// The max retry times of every chunk in resumable upload.
+    ///
+    /// Default to 3 if not set.
+    pub resumable_chunk_max_retries: Option<usize>,
+    /// The project id that buckets belong to.
+    ///
+    /// Required by `create_bucket` and `list_buckets`.
+    pub project: Option<String>,
 }
 
 impl Debug for GcsConfig {
@@ -93,11 +115,17 @@ impl Debug for GcsConfig {
 
 impl Configurator for GcsConfig {
     type Builder = GcsBuilder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("bucket"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         GcsBuilder {
             config: self,
             http_client: None,
             customized_token_loader: None,
+            session_store: None,
         }
     }
 }
@@ -110,6 +138,7 @@ pub struct GcsBuilder {
 
     http_client: Option<HttpClient>,
     customized_token_loader: Option<Box<dyn GoogleTokenLoad>>,
+    session_store: Option<Arc<dyn GcsSessionStore>>,
 }
 
 impl Debug for GcsBuilder {
@@ -224,6 +253,18 @@ impl GcsBuilder {
         self
     }
 
+    /// Set the HMAC key used to sign presigned requests.
+    ///
+    /// Presigned requests will be signed with service account private key or
+    /// IAM signBlob API if HMAC key is not set.
+    pub fn hmac_key(mut self, access_id: &str, secret: &str) -> Self {
+        if !access_id.is_empty() && !secret.is_empty() {
+            self.config.hmac_access_id = Some(access_id.to_string());
+            self.config.hmac_secret = Some(secret.to_string());
+        }
+        self
+    }
+
     /// Disable attempting to load credentials from the GCE metadata server.
     pub fn disable_vm_metadata(mut self) -> Self {
         self.config.disable_vm_metadata = true;
@@ -252,6 +293,51 @@ impl GcsBuilder {
         self
     }
 
+    /// Set checksum algorithm of this backend.
+    ///
+    /// If set, opendal will send the checksum of every part in multipart uploads and
+    /// verify the checksum of the whole object after the upload is completed.
+    ///
+    /// Available options:
+    /// - "crc32c"
+    pub fn checksum_algorithm(mut self, checksum_algorithm: &str) -> Self {
+        self.config.checksum_algorithm = Some(checksum_algorithm.to_string());
+        self
+    }
+
+    /// Disable resumable upload and use XML multipart upload for writes instead.
+    pub fn disable_resumable_upload(mut self) -> Self {
+        self.config.disable_resumable_upload = true;
+        self
+    }
+
+    /// Set the max retry times of every chunk in resumable upload.
+    ///
+    /// The chunk will be retried from the offset that gcs has persisted. Default to 3.
+    pub fn resumable_chunk_max_retries(mut self, max_retries: usize) -> Self {
+        self.config.resumable_chunk_max_retries = Some(max_retries);
+        self
+    }
+
+    /// Set the project id that buckets belong to.
+    ///
+    /// `create_bucket` and `list_buckets` are only available when project is set.
+    pub fn project(mut self, project: &str) -> Self {
+        if !project.is_empty() {
+            self.config.project = Some(project.to_string())
+        }
+        self
+    }
+
+    /// Specify the store used to persist session uri of resumable uploads.
+    ///
+    /// With a session store, writing the same path again after a crash will resume the
+    /// previous upload instead of starting over.
+    pub fn session_store(mut self, store: impl GcsSessionStore) -> Self {
+        self.session_store = Some(Arc::new(store));
+        self
+    }
+
     /// Set the default storage class for GCS.
     ///
     /// Available values are:
@@ -298,6 +384,19 @@ impl Builder for GcsBuilder {
 
         // TODO: server side encryption
 
+        let checksum_algorithm = match self.config.checksum_algorithm.as_deref() {
+            Some("crc32c") => Some(ChecksumAlgorithm::Crc32c),
+            None => None,
+            Some(v) => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    format!("{v} is not a supported checksum_algorithm."),
+                )
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Gcs))
+            }
+        };
+
         let client = if let Some(client) = self.http_client {
             client
         } else {
@@ -339,7 +438,7 @@ impl Builder for GcsBuilder {
             DEFAULT_GCS_SCOPE
         };
 
-        let mut token_loader = GoogleTokenLoader::new(scope, client.client());
+        let mut token_loader = GoogleTokenLoader::new(scope, client.client()?);
         if let Some(account) = &self.config.service_account {
             token_loader = token_loader.with_service_account(account);
         }
@@ -367,9 +466,22 @@ impl Builder for GcsBuilder {
                 token: self.config.token,
                 scope: scope.to_string(),
                 credential_loader: cred_loader,
+                service_account: self.config.service_account.clone(),
+                disable_vm_metadata: self.config.disable_vm_metadata,
+                hmac_access_id: self.config.hmac_access_id.clone(),
+                hmac_secret: self.config.hmac_secret.clone(),
+                signer_email: OnceCell::new(),
                 predefined_acl: self.config.predefined_acl.clone(),
                 default_storage_class: self.config.default_storage_class.clone(),
                 allow_anonymous: self.config.allow_anonymous,
+                checksum_algorithm,
+                disable_resumable_upload: self.config.disable_resumable_upload,
+                resumable_chunk_max_retries: self
+                    .config
+                    .resumable_chunk_max_retries
+                    .unwrap_or(DEFAULT_RESUMABLE_CHUNK_MAX_RETRIES),
+                session_store: self.session_store,
+                project: self.config.project.clone(),
             }),
         };
 
@@ -395,6 +507,7 @@ impl Access for GcsBackend {
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::Gcs)
             .set_root(&self.core.root)
+            .set_http_client(self.core.client.clone())
             .set_name(&self.core.bucket)
             .set_native_capability(Capability {
                 stat: true,
@@ -405,11 +518,14 @@ impl Access for GcsBackend {
 
                 read_with_if_match: true,
                 read_with_if_none_match: true,
+                read_with_if_generation_match: true,
 
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
                 write_with_content_type: true,
+                write_with_if_generation_match: true,
+                write_with_acl: true,
                 // The min multipart size of Gcs is 5 MiB.
                 //
                 // ref: <https://cloud.google.com/storage/docs/xml-api/put-object-multipart>
@@ -422,22 +538,55 @@ impl Access for GcsBackend {
                 } else {
                     Some(usize::MAX)
                 },
+                // Chunks of resumable upload must be multiple of 256 KiB except the last one.
+                //
+                // ref: <https://cloud.google.com/storage/docs/performing-resumable-uploads#chunked-upload>
+                write_multi_align_size: if self.core.disable_resumable_upload {
+                    None
+                } else {
+                    Some(256 * 1024)
+                },
+                // The max object size of GCS is 5 TiB.
+                //
+                // ref: <https://cloud.google.com/storage/quotas#objects>
+                write_total_max_size: if cfg!(target_pointer_width = "64") {
+                    Some(5 * 1024 * 1024 * 1024 * 1024)
+                } else {
+                    Some(usize::MAX)
+                },
 
                 delete: true,
+                delete_with_if_generation_match: true,
                 copy: true,
+                concat: true,
+                // GCS supports composing at most 32 objects in a single request.
+                //
+                // ref: <https://cloud.google.com/storage/docs/composing-objects>
+                concat_max_sources: Some(32),
 
                 list: true,
                 list_with_limit: true,
                 list_with_start_after: true,
                 list_with_recursive: true,
+                list_has_content_length: true,
+                list_has_content_type: true,
+                list_has_etag: true,
+                list_has_last_modified: true,
 
                 batch: true,
+                batch_stat: true,
                 batch_max_operations: Some(100),
                 presign: true,
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
 
+                acl: true,
+
+                create_bucket: self.core.project.is_some(),
+                delete_bucket: true,
+                list_buckets: self.core.project.is_some(),
+
                 ..Default::default()
             });
         am.into()
@@ -446,32 +595,7 @@ impl Access for GcsBackend {
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let resp = self.core.gcs_get_object_metadata(path, &args).await?;
 
-        if !resp.status().is_success() {
-            return Err(parse_error(resp));
-        }
-
-        let slc = resp.into_body();
-
-        let meta: GetObjectJsonResponse =
-            serde_json::from_reader(slc.reader()).map_err(new_json_deserialize_error)?;
-
-        let mut m = Metadata::new(EntryMode::FILE);
-
-        m.set_etag(&meta.etag);
-        m.set_content_md5(&meta.md5_hash);
-
-        let size = meta
-            .size
-            .parse::<u64>()
-            .map_err(|e| Error::new(ErrorKind::Unexpected, "parse u64").set_source(e))?;
-        m.set_content_length(size);
-        if !meta.content_type.is_empty() {
-            m.set_content_type(&meta.content_type);
-        }
-
-        m.set_last_modified(parse_datetime_from_rfc3339(&meta.updated)?);
-
-        Ok(RpStat::new(m))
+        parse_object_metadata(resp).map(RpStat::new)
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
@@ -481,7 +605,8 @@ impl Access for GcsBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -495,13 +620,18 @@ impl Access for GcsBackend {
         let concurrent = args.concurrent();
         let executor = args.executor().cloned();
         let w = GcsWriter::new(self.core.clone(), path, args);
-        let w = oio::MultipartWriter::new(w, executor, concurrent);
+        let w = if self.core.disable_resumable_upload {
+            GcsWriters::One(oio::MultipartWriter::new(w, executor, concurrent))
+        } else {
+            // Chunks of resumable upload must be uploaded in order.
+            GcsWriters::Two(oio::RangeWriter::new(w, executor, 1))
+        };
 
         Ok((RpWrite::default(), w))
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let resp = self.core.gcs_delete_object(path).await?;
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let resp = self.core.gcs_delete_object(path, &args).await?;
 
         // deleting not existing objects is ok
         if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
@@ -511,6 +641,16 @@ impl Access for GcsBackend {
         }
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let resp = self.core.gcs_compose_object(path, &args).await?;
+
+        if resp.status().is_success() {
+            Ok(RpConcat::default())
+        } else {
+            Err(parse_error(resp))
+        }
+    }
+
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let l = GcsLister::new(
             self.core.clone(),
@@ -561,58 +701,128 @@ impl Access for GcsBackend {
         if ops.len() > 100 {
             return Err(Error::new(
                 ErrorKind::Unsupported,
-                "gcs services only allow delete less than 100 keys at once",
+                "gcs services only allow batch less than 100 operations at once",
             )
             .with_context("length", ops.len().to_string()));
         }
 
+        if ops
+            .iter()
+            .all(|(_, op)| matches!(op, BatchOperation::Stat(_)))
+        {
+            return self.batch_stat(ops).await;
+        }
+        if ops
+            .iter()
+            .any(|(_, op)| !matches!(op, BatchOperation::Delete(_)))
+        {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "gcs services don't support mixed operations in one batch",
+            ));
+        }
+
         let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
         let resp = self.core.gcs_delete_objects(paths.clone()).await?;
+        let parts = parse_batch_response(resp)?;
 
-        let status = resp.status();
+        let mut batched_result = Vec::with_capacity(parts.len());
 
-        if let StatusCode::OK = status {
-            let content_type = parse_content_type(resp.headers())?.ok_or_else(|| {
-                Error::new(
-                    ErrorKind::Unexpected,
-                    "gcs batch delete response content type is empty",
-                )
-            })?;
-            let boundary = content_type
-                .strip_prefix("multipart/mixed; boundary=")
-                .ok_or_else(|| {
-                    Error::new(
-                        ErrorKind::Unexpected,
-                        "gcs batch delete response content type is not multipart/mixed",
-                    )
-                })?
-                .trim_matches('"');
-            let multipart: Multipart<MixedPart> = Multipart::new()
-                .with_boundary(boundary)
-                .parse(resp.into_body().to_bytes())?;
-            let parts = multipart.into_parts();
-
-            let mut batched_result = Vec::with_capacity(parts.len());
-
-            for (i, part) in parts.into_iter().enumerate() {
-                let resp = part.into_response();
-                // TODO: maybe we can take it directly?
-                let path = paths[i].clone();
-
-                // deleting not existing objects is ok
-                if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
-                    batched_result.push((path, Ok(RpDelete::default().into())));
-                } else {
-                    batched_result.push((path, Err(parse_error(resp))));
-                }
+        for (i, part) in parts.into_iter().enumerate() {
+            let resp = part.into_response();
+            // TODO: maybe we can take it directly?
+            let path = paths[i].clone();
+
+            // deleting not existing objects is ok
+            if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
+                batched_result.push((path, Ok(RpDelete::default().into())));
+            } else {
+                batched_result.push((path, Err(parse_error(resp))));
             }
+        }
 
-            Ok(RpBatch::new(batched_result))
-        } else {
-            // If the overall request isn't formatted correctly and Cloud Storage is unable to parse it into sub-requests, you receive a 400 error.
-            // Otherwise, Cloud Storage returns a 200 status code, even if some or all of the sub-requests fail.
-            Err(parse_error(resp))
+        Ok(RpBatch::new(batched_result))
+    }
+
+    async fn get_acl(&self, path: &str, _: OpGetAcl) -> Result<RpGetAcl> {
+        let resp = self.core.gcs_get_object_acl(path).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
         }
+
+        let acl: ObjectAccessControls = serde_json::from_reader(resp.into_body().reader())
+            .map_err(new_json_deserialize_error)?;
+        Ok(RpGetAcl::new(parse_object_acl(&acl)))
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        let resp = self.core.gcs_set_object_acl(path, args.acl()).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetAcl::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn create_bucket(&self, _: OpCreateBucket) -> Result<RpCreateBucket> {
+        let project = self.core.project.as_deref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "project is required to create bucket",
+            )
+            .with_operation("Backend::create_bucket")
+            .with_context("service", Scheme::Gcs)
+        })?;
+
+        let resp = self.core.gcs_create_bucket(project).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpCreateBucket::default()),
+            // GCS returns `409 Conflict` if the bucket name is already taken.
+            StatusCode::CONFLICT => {
+                let err = parse_error(resp);
+                Err(Error::new(ErrorKind::AlreadyExists, "bucket already exists").set_source(err))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn delete_bucket(&self, _: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        let resp = self.core.gcs_delete_bucket().await?;
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(RpDeleteBucket::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn list_buckets(&self, _: OpListBuckets) -> Result<RpListBuckets> {
+        let project = self.core.project.as_deref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "project is required to list buckets",
+            )
+            .with_operation("Backend::list_buckets")
+            .with_context("service", Scheme::Gcs)
+        })?;
+
+        let mut buckets = vec![];
+        let mut token = String::new();
+
+        loop {
+            let resp = self.core.gcs_list_buckets(project, &token).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListBucketsResponse = serde_json::from_reader(resp.into_body().reader())
+                .map_err(new_json_deserialize_error)?;
+            buckets.extend(output.items.into_iter().map(|v| v.name));
+
+            match output.next_page_token {
+                Some(v) if !v.is_empty() && v != token => token = v,
+                _ => break,
+            }
+        }
+
+        Ok(RpListBuckets::new(buckets))
     }
 }
 
@@ -640,6 +850,116 @@ struct GetObjectJsonResponse {
     ///
     /// For example: `"contentType": "image/png",`
     content_type: String,
+    /// The generation of this object, GCS will return it in string.
+    ///
+    /// For example: `"generation": "1660563214863653"`
+    generation: String,
+}
+
+impl GcsBackend {
+    /// Stat objects in one batch request.
+    async fn batch_stat(&self, ops: Vec<(String, BatchOperation)>) -> Result<RpBatch> {
+        let ops: Vec<(String, OpStat)> = ops
+            .into_iter()
+            .filter_map(|(path, op)| match op {
+                BatchOperation::Stat(args) => Some((path, args)),
+                _ => None,
+            })
+            .collect();
+
+        let resp = self.core.gcs_get_objects_metadata(&ops).await?;
+        let paths = ops.into_iter().map(|(path, _)| path).collect();
+        parse_batch_stat_response(paths, resp)
+    }
+}
+
+/// Parse the response of batch stat into results of given paths.
+fn parse_batch_stat_response(paths: Vec<String>, resp: Response<Buffer>) -> Result<RpBatch> {
+    let parts = parse_batch_response(resp)?;
+    if parts.len() != paths.len() {
+        return Err(Error::new(
+            ErrorKind::Unexpected,
+            "invalid batch response, paths and response parts don't match",
+        ));
+    }
+
+    let batched_result = paths
+        .into_iter()
+        .zip(parts)
+        .map(|(path, part)| {
+            let res = parse_object_metadata(part.into_response()).map(|m| RpStat::new(m).into());
+            (path, res)
+        })
+        .collect();
+
+    Ok(RpBatch::new(batched_result))
+}
+
+/// Parse the response of batch request into parts.
+fn parse_batch_response(resp: Response<Buffer>) -> Result<Vec<MixedPart>> {
+    // If the overall request isn't formatted correctly and Cloud Storage is unable to parse it into sub-requests, you receive a 400 error.
+    // Otherwise, Cloud Storage returns a 200 status code, even if some or all of the sub-requests fail.
+    if resp.status() != StatusCode::OK {
+        return Err(parse_error(resp));
+    }
+
+    let content_type = parse_content_type(resp.headers())?.ok_or_else(|| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "gcs batch response content type is empty",
+        )
+    })?;
+    let boundary = content_type
+        .strip_prefix("multipart/mixed; boundary=")
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "gcs batch response content type is not multipart/mixed",
+            )
+        })?
+        .trim_matches('"');
+    let multipart: Multipart<MixedPart> = Multipart::new()
+        .with_boundary(boundary)
+        .parse(resp.into_body().to_bytes())?;
+
+    Ok(multipart.into_parts())
+}
+
+/// Parse the response of get object metadata into [`Metadata`].
+fn parse_object_metadata(resp: Response<Buffer>) -> Result<Metadata> {
+    if !resp.status().is_success() {
+        return Err(parse_error(resp));
+    }
+
+    let request_id = parse_request_id(resp.headers()).map(|v| v.to_string());
+    let slc = resp.into_body();
+
+    let meta: GetObjectJsonResponse =
+        serde_json::from_reader(slc.reader()).map_err(new_json_deserialize_error)?;
+
+    let mut m = Metadata::new(EntryMode::FILE);
+
+    m.set_etag(&meta.etag);
+    m.set_content_md5(&meta.md5_hash);
+    if !meta.generation.is_empty() {
+        m.set_version(&meta.generation);
+    }
+
+    let size = meta
+        .size
+        .parse::<u64>()
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "parse u64").set_source(e))?;
+    m.set_content_length(size);
+    if !meta.content_type.is_empty() {
+        m.set_content_type(&meta.content_type);
+    }
+
+    m.set_last_modified(parse_datetime_from_rfc3339(&meta.updated)?);
+    if let Some(v) = request_id {
+        m.set_request_id(&v);
+    }
+
+    Ok(m)
 }
 
 #[cfg(test)]
@@ -677,4 +997,52 @@ mod tests {
         assert_eq!(meta.etag, "CKWasoTgyPkCEAE=");
         assert_eq!(meta.content_type, "image/png");
     }
+
+    #[test]
+    fn test_parse_batch_stat_response() {
+        let body = r#"--batch_test
+Content-Type: application/http
+Content-ID: <response-0>
+
+HTTP/1.1 200 OK
+Content-Type: application/json; charset=UTF-8
+
+{"name": "a", "size": "5", "etag": "CKWasoTgyPkCEAE=", "updated": "2022-08-15T11:33:34.866Z"}
+
+--batch_test
+Content-Type: application/http
+Content-ID: <response-1>
+
+HTTP/1.1 404 Not Found
+Content-Type: application/json; charset=UTF-8
+
+{"error": {"code": 404, "message": "No such object: example/b"}}
+
+--batch_test--"#
+            .replace('\n', "\r\n");
+        let resp = Response::builder()
+            .status(StatusCode::OK)
+            .header(
+                http::header::CONTENT_TYPE,
+                "multipart/mixed; boundary=batch_test",
+            )
+            .body(Buffer::from(body))
+            .unwrap();
+
+        let rp = parse_batch_stat_response(vec!["a".to_string(), "b".to_string()], resp).unwrap();
+        let mut results = rp.into_results().into_iter();
+
+        let (path, res) = results.next().unwrap();
+        assert_eq!(path, "a");
+        let BatchedReply::Stat(rp) = res.unwrap() else {
+            panic!("reply must be stat")
+        };
+        let meta = rp.into_metadata();
+        assert_eq!(meta.content_length(), 5);
+        assert_eq!(meta.etag(), Some("CKWasoTgyPkCEAE="));
+
+        let (path, res) = results.next().unwrap();
+        assert_eq!(path, "b");
+        assert_eq!(res.err().unwrap().kind(), ErrorKind::NotFound);
+    }
 }
@@ -19,16 +19,24 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::fmt::Write;
+use std::sync::Arc;
 use std::time::Duration;
 
 use backon::ExponentialBuilder;
 use backon::Retryable;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Buf;
 use bytes::Bytes;
+use chrono::Utc;
 use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_RANGE;
 use http::header::CONTENT_TYPE;
 use http::header::HOST;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
+use http::header::RANGE;
+use http::HeaderMap;
 use http::Request;
 use http::Response;
 use once_cell::sync::Lazy;
@@ -40,11 +48,21 @@ use reqsign::GoogleTokenLoader;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::json;
+use tokio::sync::OnceCell;
 
+use super::error::parse_error;
+use super::presign::*;
 use super::uri::percent_encode_path;
+use super::writer::GcsSessionStore;
 use crate::raw::*;
 use crate::*;
 
+pub mod constants {
+    pub const X_GOOG_HASH: &str = "x-goog-hash";
+    pub const X_UPLOAD_CONTENT_TYPE: &str = "x-upload-content-type";
+    pub const X_GOOG_IF_GENERATION_MATCH: &str = "x-goog-if-generation-match";
+}
+
 pub struct GcsCore {
     pub endpoint: String,
     pub bucket: String,
@@ -56,11 +74,29 @@ pub struct GcsCore {
     pub token: Option<String>,
     pub scope: String,
     pub credential_loader: GoogleCredentialLoader,
+    pub service_account: Option<String>,
+    pub disable_vm_metadata: bool,
+    /// The HMAC key used to sign presigned requests.
+    pub hmac_access_id: Option<String>,
+    pub hmac_secret: Option<String>,
+    /// The email of service account used to sign presigned requests via signBlob API.
+    pub signer_email: OnceCell<String>,
 
     pub predefined_acl: Option<String>,
     pub default_storage_class: Option<String>,
 
     pub allow_anonymous: bool,
+
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+
+    /// Use XML multipart upload instead of resumable upload.
+    pub disable_resumable_upload: bool,
+    /// The max retry times of every chunk in resumable upload.
+    pub resumable_chunk_max_retries: usize,
+    /// The store to persist session uri of resumable uploads.
+    pub session_store: Option<Arc<dyn GcsSessionStore>>,
+    /// The project id that buckets belong to.
+    pub project: Option<String>,
 }
 
 impl Debug for GcsCore {
@@ -101,24 +137,103 @@ impl GcsCore {
         ))
     }
 
-    fn load_credential(&self) -> Result<Option<GoogleCredential>> {
-        let cred = self
-            .credential_loader
+    /// Load the credential for presign, returns `None` if we can only sign with token.
+    fn load_presign_credential(&self) -> Result<Option<GoogleCredential>> {
+        if self.token.is_some() {
+            return Ok(None);
+        }
+
+        self.credential_loader
             .load()
-            .map_err(new_request_credential_error)?;
+            .map_err(new_request_credential_error)
+    }
 
-        if let Some(cred) = cred {
-            return Ok(Some(cred));
-        }
+    /// Load the email of service account used by signBlob API.
+    ///
+    /// The configured service account will be used if it's an email, otherwise
+    /// we will query it from the GCE metadata server.
+    async fn load_signer_email(&self) -> Result<&str> {
+        let email = self
+            .signer_email
+            .get_or_try_init(|| async {
+                if let Some(account) = &self.service_account {
+                    if account.contains('@') {
+                        return Ok(account.clone());
+                    }
+                }
+                if self.disable_vm_metadata {
+                    return Err(Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "service_account email is required to presign without credential",
+                    ));
+                }
+
+                let url = format!(
+                    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/{}/email",
+                    self.service_account.as_deref().unwrap_or("default")
+                );
+                let req = Request::get(&url)
+                    .header("Metadata-Flavor", "Google")
+                    .body(Buffer::new())
+                    .map_err(new_request_build_error)?;
+                let resp = self.client.send(req).await?;
+                if !resp.status().is_success() {
+                    return Err(parse_error(resp));
+                }
+
+                let bs = resp.into_body().to_bytes();
+                Ok(String::from_utf8_lossy(&bs).trim().to_string())
+            })
+            .await?;
+
+        Ok(email)
+    }
 
-        if self.allow_anonymous {
-            return Ok(None);
+    /// Sign the string via IAM signBlob API and return the hex encoded signature.
+    ///
+    /// ref: <https://cloud.google.com/iam/docs/reference/credentials/rest/v1/projects.serviceAccounts/signBlob>
+    async fn gcs_sign_blob(&self, email: &str, string_to_sign: &str) -> Result<String> {
+        let url = format!(
+            "https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/{}:signBlob",
+            percent_encode_path(email)
+        );
+
+        let content = serde_json::to_vec(&SignBlobRequest {
+            payload: BASE64_STANDARD.encode(string_to_sign),
+        })
+        .map_err(new_json_serialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(CONTENT_LENGTH, content.len())
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        let resp = self.send(req).await?;
+        if !resp.status().is_success() {
+            return Err(parse_error(resp));
         }
 
-        Err(Error::new(
-            ErrorKind::ConfigInvalid,
-            "no valid credential found",
-        ))
+        let out: SignBlobResponse = serde_json::from_reader(resp.into_body().reader())
+            .map_err(new_json_deserialize_error)?;
+        let signature = BASE64_STANDARD.decode(out.signed_blob).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "signBlob returns invalid signature").set_source(err)
+        })?;
+        Ok(hex_encode(&signature))
+    }
+
+    /// Calculate the base64 encoded checksum of the body.
+    pub fn calculate_checksum(&self, body: &Buffer) -> Option<String> {
+        match self.checksum_algorithm {
+            None => None,
+            Some(ChecksumAlgorithm::Crc32c) => {
+                let mut crc = 0u32;
+                body.clone()
+                    .for_each(|b| crc = crc32c::crc32c_append(crc, &b));
+                Some(BASE64_STANDARD.encode(crc.to_be_bytes()))
+            }
+        }
     }
 
     pub async fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
@@ -141,11 +256,31 @@ impl GcsCore {
         Ok(())
     }
 
-    pub async fn sign_query<T>(&self, req: &mut Request<T>, duration: Duration) -> Result<()> {
-        if let Some(cred) = self.load_credential()? {
+    /// Sign the request via V4 signed url.
+    ///
+    /// - HMAC keys will be used if provided.
+    /// - Service account private keys will be used if credential is loaded.
+    /// - IAM signBlob API will be used if there is only a token.
+    pub async fn sign_query(&self, req: &mut Request<Buffer>, duration: Duration) -> Result<()> {
+        if let (Some(access_id), Some(secret)) = (&self.hmac_access_id, &self.hmac_secret) {
+            let now = Utc::now();
+            let (mut parts, body) = std::mem::take(req).into_parts();
+            let signing = build_v4_signing(&parts, GOOG4_HMAC_SHA256, access_id, now, duration)?;
+            let signature = sign_hmac(secret, now, &signing.string_to_sign);
+            apply_v4_signature(&mut parts, signing, &signature)?;
+            *req = Request::from_parts(parts, body);
+        } else if let Some(cred) = self.load_presign_credential()? {
             self.signer
                 .sign_query(req, duration, &cred)
                 .map_err(new_request_sign_error)?;
+        } else if self.load_token().await?.is_some() {
+            let now = Utc::now();
+            let email = self.load_signer_email().await?;
+            let (mut parts, body) = std::mem::take(req).into_parts();
+            let signing = build_v4_signing(&parts, GOOG4_RSA_SHA256, email, now, duration)?;
+            let signature = self.gcs_sign_blob(email, &signing.string_to_sign).await?;
+            apply_v4_signature(&mut parts, signing, &signature)?;
+            *req = Request::from_parts(parts, body);
         } else {
             return Ok(());
         }
@@ -165,6 +300,23 @@ impl GcsCore {
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
         self.client.send(req).await
     }
+
+    /// The predefined acl used by JSON API, acl of the write takes precedence
+    /// over the configured `predefined_acl`.
+    fn predefined_acl<'a>(&'a self, args: &OpWrite) -> Result<Option<&'a str>> {
+        match args.acl() {
+            Some(acl) => format_predefined_acl(acl).map(Some),
+            None => Ok(self.predefined_acl.as_deref()),
+        }
+    }
+
+    /// The predefined acl used by XML API, which shares the canned acl names of S3.
+    fn xml_predefined_acl(&self, args: &OpWrite) -> Result<Option<String>> {
+        match args.acl() {
+            Some(acl) => format_predefined_acl(acl).map(|_| Some(acl.to_string())),
+            None => Ok(self.predefined_acl.clone()),
+        }
+    }
 }
 
 impl GcsCore {
@@ -176,12 +328,18 @@ impl GcsCore {
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/storage/v1/b/{}/o/{}?alt=media",
             self.endpoint,
             self.bucket,
             percent_encode_path(&p)
         );
+        if let Some(generation) = args.version() {
+            write!(url, "&generation={generation}").expect("write into string must succeed");
+        }
+        if let Some(generation) = args.if_generation_match() {
+            write!(url, "&ifGenerationMatch={generation}").expect("write into string must succeed");
+        }
 
         let mut req = Request::get(&url);
 
@@ -261,9 +419,12 @@ impl GcsCore {
             percent_encode_path(&p)
         );
 
-        if let Some(acl) = &self.predefined_acl {
+        if let Some(acl) = self.predefined_acl(op)? {
             write!(&mut url, "&predefinedAcl={}", acl).unwrap();
         }
+        if let Some(generation) = op.if_generation_match() {
+            write!(&mut url, "&ifGenerationMatch={generation}").unwrap();
+        }
 
         let mut req = Request::post(&url);
 
@@ -322,7 +483,7 @@ impl GcsCore {
             req = req.header(CONTENT_TYPE, content_type);
         }
 
-        if let Some(acl) = &self.predefined_acl {
+        if let Some(acl) = self.xml_predefined_acl(args)? {
             req = req.header("x-goog-acl", acl);
         }
 
@@ -338,12 +499,15 @@ impl GcsCore {
     pub fn gcs_head_object_request(&self, path: &str, args: &OpStat) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/storage/v1/b/{}/o/{}",
             self.endpoint,
             self.bucket,
             percent_encode_path(&p)
         );
+        if let Some(generation) = args.version() {
+            write!(url, "?generation={generation}").expect("write into string must succeed");
+        }
 
         let mut req = Request::get(&url);
 
@@ -397,22 +561,35 @@ impl GcsCore {
         self.send(req).await
     }
 
-    pub async fn gcs_delete_object(&self, path: &str) -> Result<Response<Buffer>> {
-        let mut req = self.gcs_delete_object_request(path)?;
+    pub async fn gcs_delete_object(&self, path: &str, args: &OpDelete) -> Result<Response<Buffer>> {
+        let mut req = self.gcs_delete_object_request(path, args)?;
 
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
-    pub fn gcs_delete_object_request(&self, path: &str) -> Result<Request<Buffer>> {
+    pub fn gcs_delete_object_request(
+        &self,
+        path: &str,
+        args: &OpDelete,
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/storage/v1/b/{}/o/{}",
             self.endpoint,
             self.bucket,
             percent_encode_path(&p)
         );
+        let mut sep = '?';
+        if let Some(generation) = args.version() {
+            write!(url, "{sep}generation={generation}").expect("write into string must succeed");
+            sep = '&';
+        }
+        if let Some(generation) = args.if_generation_match() {
+            write!(url, "{sep}ifGenerationMatch={generation}")
+                .expect("write into string must succeed");
+        }
 
         Request::delete(&url)
             .body(Buffer::new())
@@ -420,13 +597,35 @@ impl GcsCore {
     }
 
     pub async fn gcs_delete_objects(&self, paths: Vec<String>) -> Result<Response<Buffer>> {
+        let reqs = paths
+            .iter()
+            .map(|path| self.gcs_delete_object_request(path, &OpDelete::default()))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.gcs_batch(reqs).await
+    }
+
+    pub async fn gcs_get_objects_metadata(
+        &self,
+        ops: &[(String, OpStat)],
+    ) -> Result<Response<Buffer>> {
+        let reqs = ops
+            .iter()
+            .map(|(path, args)| self.gcs_head_object_request(path, args))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.gcs_batch(reqs).await
+    }
+
+    /// Send the requests in one batch request.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/batch>
+    async fn gcs_batch(&self, reqs: Vec<Request<Buffer>>) -> Result<Response<Buffer>> {
         let uri = format!("{}/batch/storage/v1", self.endpoint);
 
         let mut multipart = Multipart::new();
 
-        for (idx, path) in paths.iter().enumerate() {
-            let req = self.gcs_delete_object_request(path)?;
-
+        for (idx, req) in reqs.into_iter().enumerate() {
             multipart = multipart.part(
                 MixedPart::from_request(req).part_header("content-id".parse().unwrap(), idx.into()),
             );
@@ -461,6 +660,141 @@ impl GcsCore {
         self.send(req).await
     }
 
+    /// Compose the sources into the object at given path.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/json_api/v1/objects/compose>
+    pub async fn gcs_compose_object(
+        &self,
+        path: &str,
+        args: &OpConcat,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/storage/v1/b/{}/o/{}/compose",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+        if let Some(generation) = args.if_generation_match() {
+            write!(url, "?ifGenerationMatch={generation}").expect("write into string must succeed");
+        }
+
+        let request = ComposeObjectRequest {
+            source_objects: args
+                .sources()
+                .iter()
+                .map(|v| ComposeSourceObject {
+                    name: build_abs_path(&self.root, v),
+                })
+                .collect(),
+        };
+        let content = serde_json::to_vec(&request).map_err(new_json_serialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/json")
+            .body(Buffer::from(content))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn gcs_get_object_acl(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/storage/v1/b/{}/o/{}/acl",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn gcs_set_object_acl(&self, path: &str, acl: Acl) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/storage/v1/b/{}/o/{}?predefinedAcl={}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p),
+            format_predefined_acl(acl)?
+        );
+
+        let content = b"{}".to_vec();
+        let mut req = Request::patch(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/json")
+            .body(Buffer::from(content))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn gcs_create_bucket(&self, project: &str) -> Result<Response<Buffer>> {
+        let url = format!(
+            "{}/storage/v1/b?project={}",
+            self.endpoint,
+            percent_encode_path(project)
+        );
+
+        let content = serde_json::to_vec(&json!({ "name": self.bucket }))
+            .map_err(new_json_serialize_error)?;
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/json")
+            .body(Buffer::from(content))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn gcs_delete_bucket(&self) -> Result<Response<Buffer>> {
+        let url = format!("{}/storage/v1/b/{}", self.endpoint, self.bucket);
+
+        let mut req = Request::delete(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn gcs_list_buckets(
+        &self,
+        project: &str,
+        page_token: &str,
+    ) -> Result<Response<Buffer>> {
+        let mut url = format!(
+            "{}/storage/v1/b?project={}",
+            self.endpoint,
+            percent_encode_path(project)
+        );
+        if !page_token.is_empty() {
+            write!(url, "&pageToken={}", percent_encode_path(page_token))
+                .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn gcs_list_objects(
         &self,
         path: &str,
@@ -509,12 +843,24 @@ impl GcsCore {
         self.send(req).await
     }
 
-    pub async fn gcs_initiate_multipart_upload(&self, path: &str) -> Result<Response<Buffer>> {
+    pub async fn gcs_initiate_multipart_upload(
+        &self,
+        path: &str,
+        op: &OpWrite,
+    ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}/{}?uploads", self.endpoint, self.bucket, p);
 
-        let mut req = Request::post(&url)
+        let mut req = Request::post(&url);
+        if let Some(acl) = self.xml_predefined_acl(op)? {
+            req = req.header("x-goog-acl", acl);
+        }
+        if let Some(generation) = op.if_generation_match() {
+            req = req.header(constants::X_GOOG_IF_GENERATION_MATCH, generation);
+        }
+
+        let mut req = req
             .header(CONTENT_LENGTH, 0)
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
@@ -530,6 +876,7 @@ impl GcsCore {
         part_number: usize,
         size: u64,
         body: Buffer,
+        checksum: Option<String>,
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
@@ -546,6 +893,11 @@ impl GcsCore {
 
         req = req.header(CONTENT_LENGTH, size);
 
+        if let Some(checksum) = checksum {
+            // Set checksum header, gcs will reject the part if checksum is mismatch.
+            req = req.header(constants::X_GOOG_HASH, format!("crc32c={checksum}"));
+        }
+
         let mut req = req.body(body).map_err(new_request_build_error)?;
 
         self.sign(&mut req).await?;
@@ -585,6 +937,116 @@ impl GcsCore {
         self.send(req).await
     }
 
+    /// Initiate a resumable upload session, the session uri will be returned in `Location`.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/performing-resumable-uploads#initiate-session>
+    pub async fn gcs_initiate_resumable_upload(
+        &self,
+        path: &str,
+        op: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/upload/storage/v1/b/{}/o?uploadType=resumable&name={}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+        if let Some(acl) = self.predefined_acl(op)? {
+            write!(&mut url, "&predefinedAcl={}", acl).unwrap();
+        }
+        if let Some(generation) = op.if_generation_match() {
+            write!(&mut url, "&ifGenerationMatch={generation}").unwrap();
+        }
+
+        let mut metadata = HashMap::new();
+        if let Some(storage_class) = &self.default_storage_class {
+            metadata.insert("storageClass", storage_class.as_str());
+        }
+        if let Some(cache_control) = op.cache_control() {
+            metadata.insert("cacheControl", cache_control);
+        }
+
+        let mut req = Request::post(&url);
+        if let Some(content_type) = op.content_type() {
+            req = req.header(constants::X_UPLOAD_CONTENT_TYPE, content_type);
+        }
+
+        let mut req = if metadata.is_empty() {
+            req.header(CONTENT_LENGTH, 0)
+                .body(Buffer::new())
+                .map_err(new_request_build_error)?
+        } else {
+            let content = json!(metadata).to_string();
+            req.header(CONTENT_LENGTH, content.len())
+                .header(CONTENT_TYPE, "application/json; charset=UTF-8")
+                .body(Buffer::from(Bytes::from(content)))
+                .map_err(new_request_build_error)?
+        };
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Upload a chunk into the resumable upload session.
+    ///
+    /// - `total` should be set only while uploading the last chunk.
+    /// - GCS will return `308 Resume Incomplete` for chunks except the last one.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/performing-resumable-uploads#chunked-upload>
+    pub async fn gcs_upload_resumable_chunk(
+        &self,
+        location: &str,
+        offset: u64,
+        total: Option<u64>,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let size = body.len() as u64;
+        let total = total.map_or("*".to_string(), |v| v.to_string());
+        let range = if size == 0 {
+            format!("bytes */{total}")
+        } else {
+            format!("bytes {}-{}/{total}", offset, offset + size - 1)
+        };
+
+        let mut req = Request::put(location)
+            .header(CONTENT_LENGTH, size)
+            .header(CONTENT_RANGE, range)
+            .body(body)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Query the status of the resumable upload session.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/performing-resumable-uploads#status-check>
+    pub async fn gcs_query_resumable_upload(&self, location: &str) -> Result<Response<Buffer>> {
+        let mut req = Request::put(location)
+            .header(CONTENT_LENGTH, 0)
+            .header(CONTENT_RANGE, "bytes */*")
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Cancel the resumable upload session, gcs will return `499` if succeeded.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/performing-resumable-uploads#cancel-upload>
+    pub async fn gcs_cancel_resumable_upload(&self, location: &str) -> Result<Response<Buffer>> {
+        let mut req = Request::delete(location)
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn gcs_abort_multipart_upload(
         &self,
         path: &str,
@@ -608,6 +1070,49 @@ impl GcsCore {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    Crc32c,
+}
+
+/// Parse the base64 encoded crc32c from `x-goog-hash` headers.
+///
+/// GCS returns hashes like `x-goog-hash: crc32c=n03x6A==,md5=Ojk9c3dhfxgoKVVHYwFbHQ==`,
+/// the hashes could be returned in multiple headers too.
+pub fn parse_crc32c(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(constants::X_GOOG_HASH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .find_map(|v| v.trim().strip_prefix("crc32c="))
+        .map(|v| v.to_string())
+}
+
+/// Parse the size of persisted data from the `Range` header of `308 Resume Incomplete`.
+///
+/// GCS returns `Range: bytes=0-42` if the first 43 bytes have been persisted, and no
+/// `Range` header if nothing has been persisted.
+pub fn parse_persisted_size(headers: &HeaderMap) -> Result<u64> {
+    let Some(v) = headers.get(RANGE) else {
+        return Ok(0);
+    };
+
+    let v = v.to_str().map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "header value is not valid utf-8").set_source(err)
+    })?;
+    v.strip_prefix("bytes=0-")
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(|end| end + 1)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "range of resumable upload is invalid",
+            )
+            .with_context("range", v)
+        })
+}
+
 /// Response JSON from GCS list objects API.
 ///
 /// refer to https://cloud.google.com/storage/docs/json_api/v1/objects/list for details
@@ -637,6 +1142,90 @@ pub struct ListResponseItem {
     pub content_type: String,
 }
 
+/// Map acl into the predefined acl of GCS JSON API.
+///
+/// GCS doesn't allow everyone to write objects, so `PublicReadWrite` is not supported.
+pub fn format_predefined_acl(acl: Acl) -> Result<&'static str> {
+    let v = match acl {
+        Acl::Private => "private",
+        Acl::PublicRead => "publicRead",
+        Acl::AuthenticatedRead => "authenticatedRead",
+        Acl::BucketOwnerRead => "bucketOwnerRead",
+        Acl::BucketOwnerFullControl => "bucketOwnerFullControl",
+        acl => {
+            return Err(
+                Error::new(ErrorKind::Unsupported, "acl is not supported by gcs")
+                    .with_context("acl", acl),
+            )
+        }
+    };
+    Ok(v)
+}
+
+/// Convert the access control entries of an object into the closest canned acl.
+pub fn parse_object_acl(acl: &ObjectAccessControls) -> Acl {
+    let granted = |entity: &str| acl.items.iter().any(|v| v.entity == entity);
+
+    if granted("allUsers") {
+        Acl::PublicRead
+    } else if granted("allAuthenticatedUsers") {
+        Acl::AuthenticatedRead
+    } else {
+        Acl::Private
+    }
+}
+
+/// Response JSON from GCS [list object access controls](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls/list) API.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct ObjectAccessControls {
+    pub items: Vec<ObjectAccessControl>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct ObjectAccessControl {
+    pub entity: String,
+    pub role: String,
+}
+
+/// Response JSON from GCS list buckets API.
+///
+/// refer to https://cloud.google.com/storage/docs/json_api/v1/buckets/list for details
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ListBucketsResponse {
+    pub next_page_token: Option<String>,
+    pub items: Vec<ListBucketsResponseItem>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct ListBucketsResponseItem {
+    pub name: String,
+}
+
+/// Request body of [compose](https://cloud.google.com/storage/docs/json_api/v1/objects/compose).
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposeObjectRequest {
+    pub source_objects: Vec<ComposeSourceObject>,
+}
+
+#[derive(Default, Debug, Serialize)]
+pub struct ComposeSourceObject {
+    pub name: String,
+}
+
+/// Object resource returned after the resumable upload is completed.
+///
+/// Only the fields we need are listed here.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResumableUploadResult {
+    pub crc32c: String,
+}
+
 /// Result of CreateMultipartUpload
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
@@ -664,6 +1253,73 @@ pub struct CompleteMultipartUploadRequestPart {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_object_acl() {
+        let bs = r#"{
+  "kind": "storage#objectAccessControls",
+  "items": [
+    {"kind": "storage#objectAccessControl", "entity": "project-owners-123", "role": "OWNER"},
+    {"kind": "storage#objectAccessControl", "entity": "allUsers", "role": "READER"}
+  ]
+}"#;
+        let out: ObjectAccessControls = serde_json::from_str(bs).expect("must success");
+        assert_eq!(parse_object_acl(&out), Acl::PublicRead);
+        assert_eq!(
+            parse_object_acl(&ObjectAccessControls::default()),
+            Acl::Private
+        );
+    }
+
+    #[test]
+    fn test_format_predefined_acl() {
+        assert_eq!(
+            format_predefined_acl(Acl::PublicRead).unwrap(),
+            "publicRead"
+        );
+        assert_eq!(
+            format_predefined_acl(Acl::PublicReadWrite)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Unsupported
+        );
+    }
+
+    #[test]
+    fn test_parse_persisted_size() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_persisted_size(&headers).unwrap(), 0);
+
+        headers.insert(RANGE, "bytes=0-42".parse().unwrap());
+        assert_eq!(parse_persisted_size(&headers).unwrap(), 43);
+
+        headers.insert(RANGE, "bytes=1-42".parse().unwrap());
+        assert!(parse_persisted_size(&headers).is_err());
+    }
+
+    #[test]
+    fn test_parse_crc32c() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_crc32c(&headers), None);
+
+        headers.append(
+            constants::X_GOOG_HASH,
+            "md5=Ojk9c3dhfxgoKVVHYwFbHQ==".parse().unwrap(),
+        );
+        assert_eq!(parse_crc32c(&headers), None);
+
+        headers.append(constants::X_GOOG_HASH, "crc32c=n03x6A==".parse().unwrap());
+        assert_eq!(parse_crc32c(&headers).as_deref(), Some("n03x6A=="));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            constants::X_GOOG_HASH,
+            "crc32c=n03x6A==,md5=Ojk9c3dhfxgoKVVHYwFbHQ=="
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(parse_crc32c(&headers).as_deref(), Some("n03x6A=="));
+    }
+
     #[test]
     fn test_deserialize_list_response() {
         let content = r#"
@@ -65,13 +65,20 @@ pub fn parse_error(resp: Response<Buffer>) -> Error {
         _ => (ErrorKind::Unexpected, false),
     };
 
-    let message = match de::from_slice::<GcsErrorResponse>(&bs) {
-        Ok(gcs_err) => format!("{gcs_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let (message, code) = match de::from_slice::<GcsErrorResponse>(&bs) {
+        Ok(gcs_err) => {
+            let code = gcs_err.error.errors.first().map(|v| v.reason.clone());
+            (format!("{gcs_err:?}"), code)
+        }
+        Err(_) => (String::from_utf8_lossy(&bs).into_owned(), None),
     };
 
     let mut err = Error::new(kind, message);
 
+    if let Some(code) = code.filter(|v| !v.is_empty()) {
+        err = err.with_service_code(code);
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
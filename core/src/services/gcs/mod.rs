@@ -22,5 +22,7 @@ pub use backend::GcsConfig;
 mod core;
 mod error;
 mod lister;
+mod presign;
 mod uri;
 mod writer;
+pub use writer::GcsSessionStore;
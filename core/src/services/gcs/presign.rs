@@ -0,0 +1,311 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
+use http::header::HOST;
+use http::request::Parts;
+use http::Uri;
+use percent_encoding::percent_decode_str;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::NON_ALPHANUMERIC;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::*;
+
+/// Algorithm used while signing with service account keys or signBlob API.
+pub const GOOG4_RSA_SHA256: &str = "GOOG4-RSA-SHA256";
+/// Algorithm used while signing with HMAC keys.
+pub const GOOG4_HMAC_SHA256: &str = "GOOG4-HMAC-SHA256";
+
+/// GCS allows signed urls to be valid for at most 7 days.
+const MAX_EXPIRE: Duration = Duration::from_secs(7 * 24 * 3600);
+
+/// Characters that don't need to be encoded in the canonical request.
+static CANONICAL_ENCODE_SET: AsciiSet = NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+static CANONICAL_PATH_ENCODE_SET: AsciiSet = CANONICAL_ENCODE_SET.remove(b'/');
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The V4 signing context of a request that waiting for the signature.
+pub struct V4Signing {
+    /// Canonical query string without `X-Goog-Signature`.
+    pub query: String,
+    /// The string to sign by RSA or HMAC keys.
+    pub string_to_sign: String,
+}
+
+/// Build the V4 signing context for query signing.
+///
+/// ref: <https://cloud.google.com/storage/docs/authentication/signatures>
+pub fn build_v4_signing(
+    parts: &Parts,
+    algorithm: &str,
+    access_id: &str,
+    now: DateTime<Utc>,
+    expire: Duration,
+) -> Result<V4Signing> {
+    if expire > MAX_EXPIRE {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "gcs presign expire must not be longer than 7 days",
+        ));
+    }
+
+    let host = parts
+        .uri
+        .authority()
+        .ok_or_else(|| Error::new(ErrorKind::Unexpected, "presign request must have host"))?;
+
+    let mut headers = vec![("host".to_string(), host.as_str().to_string())];
+    for name in parts.headers.keys() {
+        if name == HOST {
+            continue;
+        }
+        let value = parts
+            .headers
+            .get_all(name)
+            .iter()
+            .map(|v| String::from_utf8_lossy(v.as_bytes()).trim().to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        headers.push((name.as_str().to_lowercase(), value));
+    }
+    headers.sort();
+    let signed_headers = headers
+        .iter()
+        .map(|(k, _)| k.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let scope = format!("{}/auto/storage/goog4_request", now.format("%Y%m%d"));
+    let datetime = now.format("%Y%m%dT%H%M%SZ").to_string();
+
+    let mut query = parts
+        .uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            let (k, v) = v.split_once('=').unwrap_or((v, ""));
+            (canonical_encode(k), canonical_encode(v))
+        })
+        .collect::<Vec<_>>();
+    for (k, v) in [
+        ("X-Goog-Algorithm", algorithm.to_string()),
+        ("X-Goog-Credential", format!("{access_id}/{scope}")),
+        ("X-Goog-Date", datetime.clone()),
+        ("X-Goog-Expires", expire.as_secs().to_string()),
+        ("X-Goog-SignedHeaders", signed_headers.clone()),
+    ] {
+        query.push((
+            k.to_string(),
+            utf8_percent_encode(&v, &CANONICAL_ENCODE_SET).to_string(),
+        ));
+    }
+    query.sort();
+    let query = query
+        .into_iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let path = percent_decode_str(parts.uri.path()).decode_utf8_lossy();
+    let path = utf8_percent_encode(&path, &CANONICAL_PATH_ENCODE_SET);
+
+    let mut canonical_request = format!("{}\n{path}\n{query}\n", parts.method);
+    for (k, v) in &headers {
+        let _ = writeln!(canonical_request, "{k}:{v}");
+    }
+    let _ = write!(canonical_request, "\n{signed_headers}\nUNSIGNED-PAYLOAD");
+
+    let string_to_sign = format!(
+        "{algorithm}\n{datetime}\n{scope}\n{}",
+        hex_encode(&Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    Ok(V4Signing {
+        query,
+        string_to_sign,
+    })
+}
+
+/// Sign the string to sign with HMAC keys and return the hex encoded signature.
+pub fn sign_hmac(secret: &str, now: DateTime<Utc>, string_to_sign: &str) -> String {
+    let key = [
+        now.format("%Y%m%d").to_string().as_str(),
+        "auto",
+        "storage",
+        "goog4_request",
+    ]
+    .iter()
+    .fold(format!("GOOG4{secret}").into_bytes(), |key, v| {
+        hmac_sha256(&key, v.as_bytes())
+    });
+
+    hex_encode(&hmac_sha256(&key, string_to_sign.as_bytes()))
+}
+
+/// Apply the signature into the uri of request.
+pub fn apply_v4_signature(parts: &mut Parts, signing: V4Signing, signature: &str) -> Result<()> {
+    let uri = format!(
+        "{}://{}{}?{}&X-Goog-Signature={signature}",
+        parts.uri.scheme_str().unwrap_or("https"),
+        parts
+            .uri
+            .authority()
+            .map(|v| v.as_str())
+            .unwrap_or_default(),
+        parts.uri.path(),
+        signing.query,
+    );
+
+    parts.uri = uri.parse::<Uri>().map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "failed to build presigned uri").set_source(err)
+    })?;
+    Ok(())
+}
+
+pub fn hex_encode(bs: &[u8]) -> String {
+    bs.iter()
+        .fold(String::with_capacity(bs.len() * 2), |mut s, v| {
+            let _ = write!(s, "{v:02x}");
+            s
+        })
+}
+
+fn canonical_encode(v: &str) -> String {
+    let v = percent_decode_str(v).decode_utf8_lossy();
+    utf8_percent_encode(&v, &CANONICAL_ENCODE_SET).to_string()
+}
+
+fn hmac_sha256(key: &[u8], content: &[u8]) -> Vec<u8> {
+    let mut h = HmacSha256::new_from_slice(key).expect("hmac accepts key in any size");
+    h.update(content);
+    h.finalize().into_bytes().to_vec()
+}
+
+/// Request of [signBlob](https://cloud.google.com/iam/docs/reference/credentials/rest/v1/projects.serviceAccounts/signBlob).
+#[derive(Serialize)]
+pub struct SignBlobRequest {
+    /// Base64 encoded bytes to be signed.
+    pub payload: String,
+}
+
+/// Response of [signBlob](https://cloud.google.com/iam/docs/reference/credentials/rest/v1/projects.serviceAccounts/signBlob).
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignBlobResponse {
+    /// Base64 encoded signature.
+    pub signed_blob: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use http::Method;
+    use http::Request;
+
+    use super::*;
+
+    #[test]
+    fn test_build_v4_signing() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let (parts, _) = Request::builder()
+            .method(Method::GET)
+            .uri("https://storage.googleapis.com/bucket/dir/hello%20world?generation=1")
+            .header("x-goog-custom", " value ")
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        let signing = build_v4_signing(
+            &parts,
+            GOOG4_HMAC_SHA256,
+            "GOOG1ID",
+            now,
+            Duration::from_secs(3600),
+        )
+        .unwrap();
+
+        assert_eq!(
+            signing.query,
+            "X-Goog-Algorithm=GOOG4-HMAC-SHA256\
+             &X-Goog-Credential=GOOG1ID%2F20240101%2Fauto%2Fstorage%2Fgoog4_request\
+             &X-Goog-Date=20240101T000000Z\
+             &X-Goog-Expires=3600\
+             &X-Goog-SignedHeaders=host%3Bx-goog-custom\
+             &generation=1"
+        );
+
+        let lines = signing.string_to_sign.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[..3],
+            [
+                "GOOG4-HMAC-SHA256",
+                "20240101T000000Z",
+                "20240101/auto/storage/goog4_request"
+            ]
+        );
+        assert_eq!(lines[3].len(), 64);
+
+        let mut parts = parts;
+        apply_v4_signature(&mut parts, signing, "abcd").unwrap();
+        assert_eq!(
+            parts.uri.path_and_query().unwrap().as_str(),
+            "/bucket/dir/hello%20world?X-Goog-Algorithm=GOOG4-HMAC-SHA256\
+             &X-Goog-Credential=GOOG1ID%2F20240101%2Fauto%2Fstorage%2Fgoog4_request\
+             &X-Goog-Date=20240101T000000Z\
+             &X-Goog-Expires=3600\
+             &X-Goog-SignedHeaders=host%3Bx-goog-custom\
+             &generation=1&X-Goog-Signature=abcd"
+        );
+    }
+
+    #[test]
+    fn test_build_v4_signing_expire_too_long() {
+        let (parts, _) = Request::get("https://storage.googleapis.com/bucket/file")
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        let err = build_v4_signing(
+            &parts,
+            GOOG4_RSA_SHA256,
+            "id",
+            Utc::now(),
+            Duration::from_secs(8 * 24 * 3600),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+}
@@ -15,24 +15,62 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Mutex;
 
+use backon::BackoffBuilder;
+use backon::ExponentialBuilder;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use bytes::Buf;
 use http::StatusCode;
 
+use super::core::parse_crc32c;
+use super::core::parse_persisted_size;
 use super::core::CompleteMultipartUploadRequestPart;
 use super::core::GcsCore;
 use super::core::InitiateMultipartUploadResult;
+use super::core::ResumableUploadResult;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
-pub type GcsWriters = oio::MultipartWriter<GcsWriter>;
+pub type GcsWriters = TwoWays<oio::MultipartWriter<GcsWriter>, oio::RangeWriter<GcsWriter>>;
+
+/// GcsSessionStore persists the session uri of resumable uploads.
+///
+/// The session uri will be saved after the resumable upload is initiated and removed after
+/// it's completed or aborted. If the process crashed during uploading, writing the same
+/// path again will resume the upload from the persisted data instead of starting over.
+///
+/// Users need to write exactly the same content again while resuming, the data that has
+/// been persisted by gcs will be skipped.
+pub trait GcsSessionStore: Debug + Send + Sync + 'static {
+    /// Load the session uri of given path, returns `None` if not found.
+    fn load(&self, path: &str) -> Option<String>;
+    /// Save the session uri of given path.
+    fn save(&self, path: &str, session_uri: &str);
+    /// Remove the session uri of given path.
+    fn remove(&self, path: &str);
+}
 
 pub struct GcsWriter {
     core: Arc<GcsCore>,
     path: String,
     op: OpWrite,
+
+    /// The size of uploaded parts, used to combine the checksum of the whole object.
+    part_sizes: Mutex<HashMap<usize, u64>>,
+
+    /// The size of data that has been persisted in the resumed session.
+    resumed_size: AtomicU64,
+    /// The running crc32c of all data written in resumable upload.
+    crc32c: AtomicU32,
 }
 
 impl GcsWriter {
@@ -41,8 +79,159 @@ impl GcsWriter {
             core,
             path: path.to_string(),
             op,
+            part_sizes: Mutex::default(),
+            resumed_size: AtomicU64::new(0),
+            crc32c: AtomicU32::new(0),
+        }
+    }
+
+    /// Update the running crc32c with given body if checksum is enabled.
+    ///
+    /// Chunks of resumable upload are written one by one, so it's safe to update in order.
+    fn update_checksum(&self, body: &Buffer) {
+        if self.core.checksum_algorithm.is_none() {
+            return;
         }
+
+        let mut crc = self.crc32c.load(Ordering::Relaxed);
+        body.clone()
+            .for_each(|b| crc = crc32c::crc32c_append(crc, &b));
+        self.crc32c.store(crc, Ordering::Relaxed);
     }
+
+    /// Query the size of persisted data of the resumable upload.
+    ///
+    /// Returns `None` if the session has been completed or expired.
+    async fn query_persisted_size(&self, location: &str) -> Result<Option<u64>> {
+        let resp = self.core.gcs_query_resumable_upload(location).await?;
+
+        match resp.status() {
+            StatusCode::PERMANENT_REDIRECT => Ok(Some(parse_persisted_size(resp.headers())?)),
+            StatusCode::OK | StatusCode::CREATED | StatusCode::NOT_FOUND | StatusCode::GONE => {
+                Ok(None)
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    /// Send the chunk to gcs, returns the response body if this is the last chunk.
+    async fn send_chunk(
+        &self,
+        location: &str,
+        offset: u64,
+        total: Option<u64>,
+        body: Buffer,
+    ) -> Result<Buffer> {
+        let end = offset + body.len() as u64;
+        let resp = self
+            .core
+            .gcs_upload_resumable_chunk(location, offset, total, body)
+            .await?;
+
+        match resp.status() {
+            // GCS returns `308 Resume Incomplete` for chunks except the last one.
+            StatusCode::PERMANENT_REDIRECT if total.is_none() => {
+                let persisted = parse_persisted_size(resp.headers())?;
+                if persisted < end {
+                    // GCS could persist only part of the chunk, retry from the persisted offset.
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "chunk of resumable upload is partially persisted",
+                    )
+                    .with_context("persisted", persisted)
+                    .with_context("expected", end)
+                    .set_temporary());
+                }
+                Ok(Buffer::new())
+            }
+            StatusCode::OK | StatusCode::CREATED if total.is_some() => Ok(resp.into_body()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    /// Upload the chunk at given offset, the chunk will be retried from the persisted
+    /// offset if failed with temporary errors.
+    async fn upload_chunk(
+        &self,
+        location: &str,
+        mut offset: u64,
+        total: Option<u64>,
+        mut body: Buffer,
+    ) -> Result<Buffer> {
+        let end = offset + body.len() as u64;
+        let mut persisted = self.resumed_size.load(Ordering::Relaxed);
+        let mut backoff = ExponentialBuilder::default()
+            .with_jitter()
+            .with_max_times(self.core.resumable_chunk_max_retries)
+            .build();
+
+        loop {
+            // Skip the data that has been persisted.
+            if persisted > offset {
+                let skip = persisted.min(end) - offset;
+                body.advance(skip as usize);
+                offset += skip;
+            }
+            // The last chunk must always be sent to finalize the upload.
+            if body.is_empty() && total.is_none() {
+                return Ok(Buffer::new());
+            }
+
+            let err = match self.send_chunk(location, offset, total, body.clone()).await {
+                Ok(bs) => return Ok(bs),
+                Err(err) if err.is_temporary() => err,
+                Err(err) => return Err(err),
+            };
+            let Some(dur) = backoff.next() else {
+                return Err(err);
+            };
+
+            // Quiesce before checking the upload status, so that gcs could settle
+            // the data that has been received.
+            self.op
+                .executor()
+                .cloned()
+                .unwrap_or_default()
+                .sleep(dur)
+                .await;
+            persisted = match self.query_persisted_size(location).await? {
+                Some(v) => v,
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "resumable upload session has been completed or expired",
+                    )
+                    .with_context("location", location)
+                    .set_source(err))
+                }
+            };
+        }
+    }
+
+    /// Combine the crc32c of the whole object from the crc32c of parts.
+    ///
+    /// Returns `None` if checksum is not enabled.
+    fn combine_checksum(&self, parts: &[oio::MultipartPart]) -> Option<String> {
+        self.core.checksum_algorithm?;
+
+        let sizes = self.part_sizes.lock().expect("lock must be valid");
+        combine_crc32c(parts, &sizes)
+    }
+}
+
+/// Combine the base64 encoded crc32c of parts into the crc32c of the whole object.
+fn combine_crc32c(parts: &[oio::MultipartPart], sizes: &HashMap<usize, u64>) -> Option<String> {
+    let mut parts = parts.iter().collect::<Vec<_>>();
+    parts.sort_by_key(|p| p.part_number);
+
+    let mut crc = 0u32;
+    for p in parts {
+        let bs = BASE64_STANDARD.decode(p.checksum.as_deref()?).ok()?;
+        let part_crc = u32::from_be_bytes(bs.try_into().ok()?);
+        let size = *sizes.get(&p.part_number)?;
+        crc = crc32c::crc32c_combine(crc, part_crc, size as usize);
+    }
+    Some(BASE64_STANDARD.encode(crc.to_be_bytes()))
 }
 
 impl oio::MultipartWrite for GcsWriter {
@@ -70,7 +259,7 @@ impl oio::MultipartWrite for GcsWriter {
     async fn initiate_part(&self) -> Result<String> {
         let resp = self
             .core
-            .gcs_initiate_multipart_upload(&percent_encode_path(&self.path))
+            .gcs_initiate_multipart_upload(&percent_encode_path(&self.path), &self.op)
             .await?;
 
         if !resp.status().is_success() {
@@ -93,9 +282,18 @@ impl oio::MultipartWrite for GcsWriter {
         // Gcs requires part number must between [1..=10000]
         let part_number = part_number + 1;
 
+        let checksum = self.core.calculate_checksum(&body);
+
         let resp = self
             .core
-            .gcs_upload_part(&self.path, upload_id, part_number, size, body)
+            .gcs_upload_part(
+                &self.path,
+                upload_id,
+                part_number,
+                size,
+                body,
+                checksum.clone(),
+            )
             .await?;
 
         if !resp.status().is_success() {
@@ -111,15 +309,22 @@ impl oio::MultipartWrite for GcsWriter {
             })?
             .to_string();
 
+        if checksum.is_some() {
+            self.part_sizes
+                .lock()
+                .expect("lock must be valid")
+                .insert(part_number, size);
+        }
+
         Ok(oio::MultipartPart {
             part_number,
             etag,
-            checksum: None,
+            checksum,
         })
     }
 
     async fn complete_part(&self, upload_id: &str, parts: &[oio::MultipartPart]) -> Result<()> {
-        let parts = parts
+        let req_parts = parts
             .iter()
             .map(|p| CompleteMultipartUploadRequestPart {
                 part_number: p.part_number,
@@ -129,13 +334,26 @@ impl oio::MultipartWrite for GcsWriter {
 
         let resp = self
             .core
-            .gcs_complete_multipart_upload(&self.path, upload_id, parts)
+            .gcs_complete_multipart_upload(&self.path, upload_id, req_parts)
             .await?;
 
         if !resp.status().is_success() {
             return Err(parse_error(resp));
         }
-        Ok(())
+
+        // Verify the crc32c returned by gcs with the combined crc32c of parts.
+        let Some(expected) = self.combine_checksum(parts) else {
+            return Ok(());
+        };
+        match parse_crc32c(resp.headers()) {
+            Some(actual) if actual != expected => Err(Error::new(
+                ErrorKind::Unexpected,
+                "uploaded checksum is mismatch",
+            )
+            .with_context("expected", expected)
+            .with_context("actual", actual)),
+            _ => Ok(()),
+        }
     }
 
     async fn abort_part(&self, upload_id: &str) -> Result<()> {
@@ -150,3 +368,112 @@ impl oio::MultipartWrite for GcsWriter {
         }
     }
 }
+
+impl oio::RangeWrite for GcsWriter {
+    async fn write_once(&self, body: Buffer) -> Result<()> {
+        oio::MultipartWrite::write_once(self, body.len() as u64, body).await
+    }
+
+    async fn initiate_range(&self) -> Result<String> {
+        // Resume the session that persisted before.
+        if let Some(store) = &self.core.session_store {
+            if let Some(location) = store.load(&self.path) {
+                if let Some(size) = self.query_persisted_size(&location).await? {
+                    self.resumed_size.store(size, Ordering::Relaxed);
+                    return Ok(location);
+                }
+                store.remove(&self.path);
+            }
+        }
+
+        let resp = self
+            .core
+            .gcs_initiate_resumable_upload(&self.path, &self.op)
+            .await?;
+        if !resp.status().is_success() {
+            return Err(parse_error(resp));
+        }
+
+        let location = parse_location(resp.headers())?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "location not present in returning response",
+                )
+            })?
+            .to_string();
+        if let Some(store) = &self.core.session_store {
+            store.save(&self.path, &location);
+        }
+        Ok(location)
+    }
+
+    async fn write_range(&self, location: &str, offset: u64, body: Buffer) -> Result<()> {
+        self.update_checksum(&body);
+        self.upload_chunk(location, offset, None, body).await?;
+        Ok(())
+    }
+
+    async fn complete_range(&self, location: &str, offset: u64, body: Buffer) -> Result<()> {
+        self.update_checksum(&body);
+        let total = offset + body.len() as u64;
+        let bs = self
+            .upload_chunk(location, offset, Some(total), body)
+            .await?;
+        if let Some(store) = &self.core.session_store {
+            store.remove(&self.path);
+        }
+
+        if self.core.checksum_algorithm.is_none() {
+            return Ok(());
+        }
+        let result: ResumableUploadResult =
+            serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+        let expected = BASE64_STANDARD.encode(self.crc32c.load(Ordering::Relaxed).to_be_bytes());
+        if result.crc32c != expected {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "uploaded checksum is mismatch")
+                    .with_context("expected", expected)
+                    .with_context("actual", result.crc32c),
+            );
+        }
+        Ok(())
+    }
+
+    async fn abort_range(&self, location: &str) -> Result<()> {
+        let resp = self.core.gcs_cancel_resumable_upload(location).await?;
+        if let Some(store) = &self.core.session_store {
+            store.remove(&self.path);
+        }
+
+        match resp.status().as_u16() {
+            // gcs returns code 499 if cancel succeeds.
+            499 => Ok(()),
+            _ if resp.status().is_success() => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combine_crc32c() {
+        let part = |part_number, checksum: &str| oio::MultipartPart {
+            part_number,
+            etag: String::new(),
+            checksum: Some(checksum.to_string()),
+        };
+
+        // crc32c of "hello " and "world".
+        let parts = vec![part(2, "MaqBTg=="), part(1, "fmJ+WA==")];
+        let sizes = HashMap::from([(1, 6), (2, 5)]);
+        // crc32c of "hello world".
+        assert_eq!(combine_crc32c(&parts, &sizes).as_deref(), Some("yZRlqg=="));
+
+        let sizes = HashMap::from([(1, 6)]);
+        assert_eq!(combine_crc32c(&parts, &sizes), None);
+    }
+}
@@ -260,6 +260,8 @@ impl Access for HdfsBackend {
 
                 write: true,
                 write_can_append: self.enable_append,
+                write_with_block_size: true,
+                write_with_replication: true,
 
                 create_dir: true,
                 delete: true,
@@ -371,6 +373,12 @@ impl Access for HdfsBackend {
         } else {
             open_options.write(true);
         }
+        if let Some(block_size) = op.block_size() {
+            open_options.with_blocksize(block_size as usize);
+        }
+        if let Some(replication) = op.replication() {
+            open_options.with_replication(replication as usize);
+        }
 
         let f = open_options
             .async_open(tmp_path.as_ref().unwrap_or(&target_path))
@@ -568,6 +576,12 @@ impl Access for HdfsBackend {
         } else {
             open_options.write(true);
         }
+        if let Some(block_size) = op.block_size() {
+            open_options.with_blocksize(block_size as usize);
+        }
+        if let Some(replication) = op.replication() {
+            open_options.with_replication(replication as usize);
+        }
 
         let f = open_options
             .open(tmp_path.as_ref().unwrap_or(&target_path))
@@ -22,6 +22,10 @@ use std::sync::Arc;
 use http::header;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
+use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
+use http::Method;
 use http::Request;
 use http::Response;
 use http::StatusCode;
@@ -29,6 +33,7 @@ use log::debug;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::decode::*;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
@@ -48,6 +53,21 @@ pub struct HttpConfig {
     pub token: Option<String>,
     /// root of this backend
     pub root: Option<String>,
+    /// Enable transparent decompression of responses with `Content-Encoding`.
+    ///
+    /// Requires feature `services-http-compression`.
+    pub enable_decompression: bool,
+    /// The max number of redirects to follow.
+    ///
+    /// - `None`: use the default policy of the underlying http client (follow up to 10 redirects).
+    /// - `Some(0)`: don't follow redirects.
+    ///
+    /// Only takes effect when the http client is built by OpenDAL.
+    pub max_redirects: Option<usize>,
+    /// Stat files with a ranged `GET` (`Range: bytes=0-0`) instead of `HEAD`.
+    ///
+    /// This is useful for servers that don't allow `HEAD` requests.
+    pub enable_stat_with_get: bool,
 }
 
 impl Debug for HttpConfig {
@@ -55,6 +75,9 @@ impl Debug for HttpConfig {
         let mut de = f.debug_struct("HttpConfig");
         de.field("endpoint", &self.endpoint);
         de.field("root", &self.root);
+        de.field("enable_decompression", &self.enable_decompression);
+        de.field("max_redirects", &self.max_redirects);
+        de.field("enable_stat_with_get", &self.enable_stat_with_get);
 
         de.finish_non_exhaustive()
     }
@@ -65,6 +88,7 @@ impl Configurator for HttpConfig {
     fn into_builder(self) -> Self::Builder {
         HttpBuilder {
             config: self,
+            headers: Vec::new(),
             http_client: None,
         }
     }
@@ -75,6 +99,7 @@ impl Configurator for HttpConfig {
 #[derive(Default)]
 pub struct HttpBuilder {
     config: HttpConfig,
+    headers: Vec<(String, String)>,
     http_client: Option<HttpClient>,
 }
 
@@ -82,7 +107,11 @@ impl Debug for HttpBuilder {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut de = f.debug_struct("HttpBuilder");
 
-        de.field("config", &self.config).finish()
+        // Header values could carry credentials, only print their names.
+        let headers: Vec<_> = self.headers.iter().map(|(k, _)| k).collect();
+        de.field("config", &self.config)
+            .field("headers", &headers)
+            .finish()
     }
 }
 
@@ -141,6 +170,57 @@ impl HttpBuilder {
         self
     }
 
+    /// Enable transparent decompression so that responses served with
+    /// `Content-Encoding` of `gzip`, `deflate` or `zstd` will be decoded while reading.
+    ///
+    /// # Notes
+    ///
+    /// - Requires feature `services-http-compression`.
+    /// - The decoded size is unknown before reading, so `stat` won't return
+    ///   `Content-Length` for encoded content.
+    /// - Range reads on encoded content are not supported since the range applies to
+    ///   the encoded data.
+    pub fn enable_decompression(mut self) -> Self {
+        self.config.enable_decompression = true;
+        self
+    }
+
+    /// Add a static header that will be sent with every request.
+    ///
+    /// Can be called multiple times, headers with the same name will all be sent.
+    /// Invalid header names or values will be reported while building.
+    ///
+    /// default: no extra headers
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        if !name.is_empty() {
+            self.headers.push((name.to_string(), value.to_string()));
+        }
+        self
+    }
+
+    /// Set the max number of redirects to follow, `0` means don't follow redirects.
+    ///
+    /// default: follow up to 10 redirects
+    ///
+    /// # Notes
+    ///
+    /// This setting can't be used together with [`HttpBuilder::http_client`],
+    /// please configure the redirect policy on the client instead.
+    pub fn max_redirects(mut self, max_redirects: usize) -> Self {
+        self.config.max_redirects = Some(max_redirects);
+        self
+    }
+
+    /// Stat files with a ranged `GET` request instead of `HEAD`.
+    ///
+    /// Some servers reject `HEAD` requests with `405 Method Not Allowed`;
+    /// enable this to send `GET` with `Range: bytes=0-0` and take the
+    /// size from `Content-Range` instead.
+    pub fn enable_stat_with_get(mut self) -> Self {
+        self.config.enable_stat_with_get = true;
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -171,8 +251,46 @@ impl Builder for HttpBuilder {
         let root = normalize_root(&self.config.root.unwrap_or_default());
         debug!("backend use root {}", root);
 
+        if self.config.enable_decompression {
+            check_decompression_supported()?;
+        }
+
+        let mut headers = HeaderMap::with_capacity(self.headers.len());
+        for (name, value) in &self.headers {
+            let name = HeaderName::try_from(name.as_str()).map_err(|err| {
+                Error::new(ErrorKind::ConfigInvalid, "header name is invalid")
+                    .with_context("service", Scheme::Http)
+                    .with_context("header", name)
+                    .set_source(err)
+            })?;
+            let value = HeaderValue::try_from(value.as_str()).map_err(|err| {
+                Error::new(ErrorKind::ConfigInvalid, "header value is invalid")
+                    .with_context("service", Scheme::Http)
+                    .with_context("header", name.as_str())
+                    .set_source(err)
+            })?;
+            headers.append(name, value);
+        }
+
         let client = if let Some(client) = self.http_client {
+            if self.config.max_redirects.is_some() {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "max_redirects can't be used with a custom http client",
+                )
+                .with_context("service", Scheme::Http));
+            }
             client
+        } else if let Some(max_redirects) = self.config.max_redirects {
+            let policy = if max_redirects == 0 {
+                reqwest::redirect::Policy::none()
+            } else {
+                reqwest::redirect::Policy::limited(max_redirects)
+            };
+            HttpClient::build(reqwest::ClientBuilder::new().redirect(policy)).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Http)
+            })?
         } else {
             HttpClient::new().map_err(|err| {
                 err.with_operation("Builder::build")
@@ -194,8 +312,11 @@ impl Builder for HttpBuilder {
         Ok(HttpBackend {
             endpoint: endpoint.to_string(),
             authorization: auth,
+            headers,
             root,
             client,
+            enable_decompression: self.config.enable_decompression,
+            enable_stat_with_get: self.config.enable_stat_with_get,
         })
     }
 }
@@ -208,6 +329,9 @@ pub struct HttpBackend {
     client: HttpClient,
 
     authorization: Option<String>,
+    headers: HeaderMap,
+    enable_decompression: bool,
+    enable_stat_with_get: bool,
 }
 
 impl Debug for HttpBackend {
@@ -216,6 +340,8 @@ impl Debug for HttpBackend {
             .field("endpoint", &self.endpoint)
             .field("root", &self.root)
             .field("client", &self.client)
+            .field("enable_decompression", &self.enable_decompression)
+            .field("enable_stat_with_get", &self.enable_stat_with_get)
             .finish()
     }
 }
@@ -232,6 +358,7 @@ impl Access for HttpBackend {
         let mut ma = AccessorInfo::default();
         ma.set_scheme(Scheme::Http)
             .set_root(&self.root)
+            .set_http_client(self.client.clone())
             .set_native_capability(Capability {
                 stat: true,
                 stat_with_if_match: true,
@@ -258,10 +385,19 @@ impl Access for HttpBackend {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
         }
 
-        let resp = self.http_head(path, &args).await?;
+        let mut resp = if self.enable_stat_with_get {
+            self.http_stat_with_get(path, &args).await?
+        } else {
+            self.http_head(path, &args).await?
+        };
 
         let status = resp.status();
 
+        // The content length of encoded content is not the size we will read.
+        if self.enable_decompression && ContentEncoding::parse(resp.headers())?.is_some() {
+            resp.headers_mut().remove(header::CONTENT_LENGTH);
+        }
+
         match status {
             StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
             // HTTP Server like nginx could return FORBIDDEN if auto-index
@@ -280,7 +416,25 @@ impl Access for HttpBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let encoding = if self.enable_decompression {
+                    ContentEncoding::parse(resp.headers())?
+                } else {
+                    None
+                };
+                let Some(encoding) = encoding else {
+                    let rp = parse_into_rp_read(resp.headers())?;
+                    return Ok((rp, resp.into_body()));
+                };
+
+                if !args.range().is_full() {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "range read on encoded content is not supported while decompression is enabled",
+                    )
+                    .with_context("service", Scheme::Http)
+                    .with_context("range", args.range().to_string()));
+                }
+                Ok((RpRead::new(), decode(resp.into_body(), encoding)))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -352,7 +506,9 @@ impl HttpBackend {
             req = req.header(header::RANGE, range.to_header());
         }
 
-        req.body(Buffer::new()).map_err(new_request_build_error)
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        self.apply_headers(&mut req);
+        Ok(req)
     }
 
     pub async fn http_get(
@@ -361,7 +517,13 @@ impl HttpBackend {
         range: BytesRange,
         args: &OpRead,
     ) -> Result<Response<HttpBody>> {
-        let req = self.http_get_request(path, range, args)?;
+        let mut req = self.http_get_request(path, range, args)?;
+        if self.enable_decompression {
+            req.headers_mut().insert(
+                header::ACCEPT_ENCODING,
+                HeaderValue::from_static(ACCEPT_ENCODING),
+            );
+        }
         self.client.fetch(req).await
     }
 
@@ -384,11 +546,115 @@ impl HttpBackend {
             req = req.header(header::AUTHORIZATION, auth.clone())
         }
 
-        req.body(Buffer::new()).map_err(new_request_build_error)
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        self.apply_headers(&mut req);
+        Ok(req)
     }
 
     async fn http_head(&self, path: &str, args: &OpStat) -> Result<Response<Buffer>> {
-        let req = self.http_head_request(path, args)?;
+        let mut req = self.http_head_request(path, args)?;
+        if self.enable_decompression {
+            req.headers_mut().insert(
+                header::ACCEPT_ENCODING,
+                HeaderValue::from_static(ACCEPT_ENCODING),
+            );
+        }
         self.client.send(req).await
     }
+
+    /// Stat via `GET` with `Range: bytes=0-0` and rewrite the response so that
+    /// it looks like a `HEAD` response.
+    async fn http_stat_with_get(&self, path: &str, args: &OpStat) -> Result<Response<Buffer>> {
+        let mut req = self.http_head_request(path, args)?;
+        *req.method_mut() = Method::GET;
+        req.headers_mut()
+            .insert(header::RANGE, HeaderValue::from_static("bytes=0-0"));
+        if self.enable_decompression {
+            req.headers_mut().insert(
+                header::ACCEPT_ENCODING,
+                HeaderValue::from_static(ACCEPT_ENCODING),
+            );
+        }
+
+        let resp = self.client.fetch(req).await?;
+        let (mut parts, mut body) = resp.into_parts();
+
+        match parts.status {
+            // The server ignores our range, `Content-Length` is the full size.
+            // The body will be dropped without reading.
+            StatusCode::OK => Ok(Response::from_parts(parts, Buffer::new())),
+            // The size is carried by `Content-Range`, and `416` will be returned
+            // for empty files.
+            StatusCode::PARTIAL_CONTENT | StatusCode::RANGE_NOT_SATISFIABLE => {
+                let size = parse_content_range(&parts.headers)?.and_then(|v| v.size());
+                parts.headers.remove(header::CONTENT_RANGE);
+                parts.headers.remove(header::CONTENT_LENGTH);
+                if let Some(size) = size {
+                    parts.headers.insert(header::CONTENT_LENGTH, size.into());
+                }
+                parts.status = StatusCode::OK;
+                Ok(Response::from_parts(parts, Buffer::new()))
+            }
+            _ => {
+                let buf = body.to_buffer().await?;
+                Ok(Response::from_parts(parts, buf))
+            }
+        }
+    }
+
+    fn apply_headers(&self, req: &mut Request<Buffer>) {
+        for (name, value) in &self.headers {
+            req.headers_mut().append(name, value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_build_with_headers() {
+        let builder = HttpBuilder::default()
+            .endpoint("http://127.0.0.1")
+            .header("x-api-key", "secret")
+            .header("x-api-key", "another");
+        assert!(!format!("{builder:?}").contains("secret"));
+
+        let backend = builder.build().expect("build must succeed");
+        let rp = backend
+            .presign(
+                "abc",
+                OpPresign::new(OpStat::new(), std::time::Duration::from_secs(60)),
+            )
+            .await
+            .expect("presign must succeed");
+        let req = rp.into_presigned_request();
+        let values: Vec<_> = req.header().get_all("x-api-key").iter().collect();
+        assert_eq!(values, vec!["secret", "another"]);
+
+        let err = HttpBuilder::default()
+            .endpoint("http://127.0.0.1")
+            .header("invalid header", "value")
+            .build()
+            .expect_err("invalid header name must be rejected");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[test]
+    fn test_build_with_max_redirects() {
+        assert!(HttpBuilder::default()
+            .endpoint("http://127.0.0.1")
+            .max_redirects(0)
+            .build()
+            .is_ok());
+
+        let err = HttpBuilder::default()
+            .endpoint("http://127.0.0.1")
+            .max_redirects(3)
+            .http_client(HttpClient::new().unwrap())
+            .build()
+            .expect_err("max_redirects conflicts with a custom http client");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
 }
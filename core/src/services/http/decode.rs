@@ -0,0 +1,234 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use http::HeaderMap;
+
+use crate::raw::*;
+use crate::*;
+
+/// The `Accept-Encoding` we send while decompression is enabled.
+pub const ACCEPT_ENCODING: &str = "gzip, deflate, zstd";
+
+/// ContentEncoding is the `Content-Encoding` that we know how to decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentEncoding {
+    Gzip,
+    Deflate,
+    Zstd,
+}
+
+impl ContentEncoding {
+    /// Parse the content encoding from headers.
+    ///
+    /// Returns `None` if the content is not encoded or encoded in a way we don't
+    /// support (for example, `br` or multiple encodings), such content will be
+    /// returned as is.
+    pub fn parse(headers: &HeaderMap) -> Result<Option<Self>> {
+        let Some(v) = parse_content_encoding(headers)? else {
+            return Ok(None);
+        };
+
+        let encoding = match v.trim().to_ascii_lowercase().as_str() {
+            "gzip" | "x-gzip" => Some(ContentEncoding::Gzip),
+            // `deflate` in http is the zlib format instead of raw deflate.
+            "deflate" => Some(ContentEncoding::Deflate),
+            "zstd" => Some(ContentEncoding::Zstd),
+            _ => None,
+        };
+        Ok(encoding)
+    }
+}
+
+/// Check if decompression is supported by current build.
+pub fn check_decompression_supported() -> Result<()> {
+    if cfg!(feature = "services-http-compression") {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorKind::ConfigInvalid,
+            "decompression requires feature services-http-compression",
+        )
+        .with_context("service", Scheme::Http))
+    }
+}
+
+/// Decode the body with given content encoding.
+///
+/// The decoded size is unknown until the whole body has been read, so the
+/// returned body doesn't carry a size to check against.
+#[cfg(feature = "services-http-compression")]
+pub fn decode(body: HttpBody, encoding: ContentEncoding) -> HttpBody {
+    use std::io;
+
+    use async_compression::futures::bufread::GzipDecoder;
+    use async_compression::futures::bufread::ZlibDecoder;
+    use async_compression::futures::bufread::ZstdDecoder;
+    use bytes::Bytes;
+    use futures::stream;
+    use futures::AsyncRead;
+    use futures::AsyncReadExt;
+    use futures::TryStreamExt;
+
+    /// The size of the buffer we decode into for every read.
+    const DECODE_BUFFER_SIZE: usize = 64 * 1024;
+
+    let reader = body
+        .map_ok(|buf| buf.to_bytes())
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+        .into_async_read();
+    let decoder: Box<dyn AsyncRead + Send + Sync + Unpin> = match encoding {
+        ContentEncoding::Gzip => {
+            let mut r = GzipDecoder::new(reader);
+            // Datasets are usually concatenated gzip members.
+            r.multiple_members(true);
+            Box::new(r)
+        }
+        ContentEncoding::Deflate => Box::new(ZlibDecoder::new(reader)),
+        ContentEncoding::Zstd => {
+            let mut r = ZstdDecoder::new(reader);
+            r.multiple_members(true);
+            Box::new(r)
+        }
+    };
+
+    let stream = stream::try_unfold(decoder, |mut r| async move {
+        let mut buf = vec![0; DECODE_BUFFER_SIZE];
+        let n = r.read(&mut buf).await.map_err(new_decode_error)?;
+        if n == 0 {
+            return Ok(None);
+        }
+        buf.truncate(n);
+        Ok(Some((Buffer::from(Bytes::from(buf)), r)))
+    });
+
+    HttpBody::new(Box::pin(stream), None)
+}
+
+/// Keep the errors returned by the body as is so that they can still be
+/// retried, other errors are returned by the decoder.
+#[cfg(feature = "services-http-compression")]
+fn new_decode_error(err: std::io::Error) -> Error {
+    if err.get_ref().is_some_and(|v| v.is::<Error>()) {
+        let inner = err.into_inner().expect("inner error must exist");
+        return *inner
+            .downcast::<Error>()
+            .expect("inner error must be Error");
+    }
+
+    Error::new(ErrorKind::Unexpected, "decode http response body")
+        .with_operation("http::decode")
+        .set_source(err)
+}
+
+#[cfg(not(feature = "services-http-compression"))]
+pub fn decode(body: HttpBody, _: ContentEncoding) -> HttpBody {
+    debug_assert!(false, "decompression must be checked while building");
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::CONTENT_ENCODING;
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_content_encoding() {
+        let cases = vec![
+            (None, None),
+            (Some("gzip"), Some(ContentEncoding::Gzip)),
+            (Some("x-gzip"), Some(ContentEncoding::Gzip)),
+            (Some("Deflate"), Some(ContentEncoding::Deflate)),
+            (Some("zstd"), Some(ContentEncoding::Zstd)),
+            (Some("br"), None),
+            (Some("gzip, br"), None),
+        ];
+
+        for (input, expected) in cases {
+            let mut headers = HeaderMap::new();
+            if let Some(v) = input {
+                headers.insert(CONTENT_ENCODING, HeaderValue::from_static(v));
+            }
+
+            let actual = ContentEncoding::parse(&headers).expect("parse must succeed");
+            assert_eq!(actual, expected, "{input:?}");
+        }
+    }
+
+    #[cfg(feature = "services-http-compression")]
+    mod compression {
+        use async_compression::futures::bufread::GzipEncoder;
+        use async_compression::futures::bufread::ZlibEncoder;
+        use async_compression::futures::bufread::ZstdEncoder;
+        use futures::stream;
+        use futures::AsyncRead;
+        use futures::AsyncReadExt;
+
+        use super::*;
+
+        async fn encode(mut r: impl AsyncRead + Unpin) -> Vec<u8> {
+            let mut buf = vec![];
+            r.read_to_end(&mut buf).await.expect("encode must succeed");
+            buf
+        }
+
+        /// Split encoded data into small chunks to make sure decoder handles
+        /// chunk boundaries.
+        fn new_body(bs: Vec<u8>) -> HttpBody {
+            let chunks: Vec<_> = bs.chunks(7).map(|v| Ok(Buffer::from(v.to_vec()))).collect();
+            HttpBody::new(stream::iter(chunks), None)
+        }
+
+        #[tokio::test]
+        async fn test_decode() {
+            let content = "Hello, World!".repeat(1024).into_bytes();
+
+            let cases = vec![
+                (
+                    ContentEncoding::Gzip,
+                    encode(GzipEncoder::new(content.as_slice())).await,
+                ),
+                (
+                    ContentEncoding::Deflate,
+                    encode(ZlibEncoder::new(content.as_slice())).await,
+                ),
+                (
+                    ContentEncoding::Zstd,
+                    encode(ZstdEncoder::new(content.as_slice())).await,
+                ),
+            ];
+
+            for (encoding, encoded) in cases {
+                let mut body = decode(new_body(encoded), encoding);
+                let bs = body.to_buffer().await.expect("decode must succeed");
+                assert_eq!(bs.to_vec(), content, "{encoding:?}");
+            }
+        }
+
+        #[tokio::test]
+        async fn test_decode_truncated() {
+            let content = "Hello, World!".repeat(1024).into_bytes();
+            let mut encoded = encode(GzipEncoder::new(content.as_slice())).await;
+            encoded.truncate(encoded.len() / 2);
+
+            let mut body = decode(new_body(encoded), ContentEncoding::Gzip);
+            let err = body.to_buffer().await.expect_err("decode must fail");
+            assert_eq!(err.kind(), ErrorKind::Unexpected);
+        }
+    }
+}
@@ -19,4 +19,5 @@ mod backend;
 pub use backend::HttpBuilder as Http;
 pub use backend::HttpConfig;
 
+mod decode;
 mod error;
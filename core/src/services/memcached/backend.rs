@@ -223,6 +223,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                write_with_ttl: true,
 
                 ..Default::default()
             },
@@ -249,6 +250,13 @@ impl kv::Adapter for Adapter {
         .await
     }
 
+    async fn set_with_ttl(&self, key: &str, value: Buffer, ttl: Duration) -> Result<()> {
+        let mut conn = self.conn().await?;
+
+        conn.set(&percent_encode_path(key), &value.to_vec(), exptime(ttl))
+            .await
+    }
+
     async fn delete(&self, key: &str) -> Result<()> {
         let mut conn = self.conn().await?;
 
@@ -256,6 +264,26 @@ impl kv::Adapter for Adapter {
     }
 }
 
+/// Max exptime that memcached will treat as relative seconds.
+///
+/// Larger values are treated as an absolute unix timestamp by memcached.
+const MAX_RELATIVE_EXPTIME: u64 = 60 * 60 * 24 * 30;
+
+/// Convert ttl into memcached's exptime.
+///
+/// `0` means never expire in memcached, so sub-second ttl will be rounded
+/// up to one second instead.
+fn exptime(ttl: Duration) -> u32 {
+    let secs = ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0);
+    let secs = secs.max(1);
+    if secs <= MAX_RELATIVE_EXPTIME {
+        return secs as u32;
+    }
+
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    (now + secs).min(u32::MAX as u64) as u32
+}
+
 /// A `bb8::ManageConnection` for `memcache_async::ascii::Protocol`.
 #[derive(Clone, Debug)]
 struct MemcacheConnectionManager {
@@ -300,3 +328,19 @@ impl bb8::ManageConnection for MemcacheConnectionManager {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exptime() {
+        assert_eq!(exptime(Duration::from_millis(10)), 1);
+        assert_eq!(exptime(Duration::from_millis(1500)), 2);
+        assert_eq!(exptime(Duration::from_secs(60)), 60);
+
+        let now = chrono::Utc::now().timestamp() as u32;
+        let ts = exptime(Duration::from_secs(MAX_RELATIVE_EXPTIME + 1));
+        assert!(ts > now + MAX_RELATIVE_EXPTIME as u32);
+    }
+}
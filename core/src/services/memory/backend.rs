@@ -95,6 +95,8 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                scan_sorted: true,
+                set_with_ttl: false,
             },
         )
     }
@@ -166,6 +166,8 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                scan_sorted: false,
+                set_with_ttl: false,
             },
         )
     }
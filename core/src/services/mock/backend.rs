@@ -0,0 +1,414 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::services::Memory;
+use crate::*;
+
+/// Config for mock.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct MockConfig {
+    /// root of the backend.
+    pub root: Option<String>,
+}
+
+impl Configurator for MockConfig {
+    type Builder = MockBuilder;
+    fn into_builder(self) -> Self::Builder {
+        MockBuilder {
+            config: self,
+            rules: vec![],
+        }
+    }
+}
+
+/// MockRule describes how mock service should respond to the matched calls.
+///
+/// Rules without any action can be used to count the calls for `nth` of
+/// other rules, and won't change the result.
+#[derive(Debug, Clone)]
+pub struct MockRule {
+    operation: Operation,
+    path: Option<String>,
+    nth: Option<usize>,
+
+    delay: Option<Duration>,
+    error: Option<(ErrorKind, String)>,
+    temporary: bool,
+    metadata: Option<Metadata>,
+}
+
+impl MockRule {
+    /// Create a new rule that matches given operation.
+    ///
+    /// Blocking operations should use their own operation like [`Operation::BlockingStat`].
+    pub fn new(operation: Operation) -> Self {
+        Self {
+            operation,
+            path: None,
+            nth: None,
+            delay: None,
+            error: None,
+            temporary: false,
+            metadata: None,
+        }
+    }
+
+    /// Only match calls on given path.
+    ///
+    /// The path is relative to the operator's root, like `dir/file`. For `copy`
+    /// and `rename`, the path is the `from` path.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    /// Only trigger on the nth (starts from 1) matched call.
+    pub fn nth(mut self, n: usize) -> Self {
+        self.nth = Some(n);
+        self
+    }
+
+    /// Delay the matched call by given duration.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Fail the matched call with given error kind and message.
+    pub fn fail(mut self, kind: ErrorKind, message: &str) -> Self {
+        self.error = Some((kind, message.to_string()));
+        self
+    }
+
+    /// Mark the error returned by this rule as temporary so that it could be retried.
+    pub fn temporary(mut self) -> Self {
+        self.temporary = true;
+        self
+    }
+
+    /// Return given metadata for the matched `stat`.
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    fn matches(&self, operation: Operation, path: &str) -> bool {
+        self.operation == operation && self.path.as_deref().map_or(true, |v| v == path)
+    }
+
+    fn new_error(&self, operation: Operation, path: &str) -> Option<Error> {
+        let (kind, message) = self.error.as_ref()?;
+
+        let err = Error::new(*kind, message)
+            .with_operation(operation)
+            .with_context("service", Scheme::Mock)
+            .with_context("path", path);
+        Some(if self.temporary {
+            err.set_temporary()
+        } else {
+            err
+        })
+    }
+}
+
+/// Mock service support.
+#[doc = include_str!("docs.md")]
+#[derive(Default, Debug)]
+pub struct MockBuilder {
+    config: MockConfig,
+    rules: Vec<MockRule>,
+}
+
+impl MockBuilder {
+    /// Set the root for mock.
+    pub fn root(mut self, path: &str) -> Self {
+        self.config.root = Some(path.into());
+        self
+    }
+
+    /// Add a rule into mock.
+    ///
+    /// Rules will be checked in the order they are added.
+    pub fn rule(mut self, rule: MockRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+impl Builder for MockBuilder {
+    const SCHEME: Scheme = Scheme::Mock;
+    type Config = MockConfig;
+
+    fn build(self) -> Result<impl Access> {
+        let mut memory = Memory::default();
+        if let Some(root) = &self.config.root {
+            memory = memory.root(root);
+        }
+        let inner = Operator::new(memory)?.finish().into_inner();
+
+        let mut info = (*inner.info()).clone();
+        info.set_scheme(Scheme::Mock);
+
+        Ok(MockBackend {
+            info: Arc::new(info),
+            inner,
+            rules: Arc::new(
+                self.rules
+                    .into_iter()
+                    .map(|rule| (rule, AtomicUsize::new(0)))
+                    .collect(),
+            ),
+        })
+    }
+}
+
+/// Backend for mock service.
+#[derive(Debug, Clone)]
+pub struct MockBackend {
+    info: Arc<AccessorInfo>,
+    inner: Accessor,
+    /// rules along with the count of matched calls.
+    rules: Arc<Vec<(MockRule, AtomicUsize)>>,
+}
+
+impl MockBackend {
+    /// Find the rule that triggered by this call.
+    ///
+    /// All matched rules will be counted even if there is already a triggered rule.
+    fn trigger(&self, operation: Operation, path: &str) -> Option<&MockRule> {
+        let mut triggered = None;
+        for (rule, hits) in self.rules.iter() {
+            if !rule.matches(operation, path) {
+                continue;
+            }
+            let n = hits.fetch_add(1, Ordering::SeqCst) + 1;
+            if triggered.is_none() && rule.nth.map_or(true, |v| v == n) {
+                triggered = Some(rule);
+            }
+        }
+        triggered
+    }
+
+    /// Apply the triggered rule, returns the scripted metadata if exists.
+    async fn apply(&self, operation: Operation, path: &str) -> Result<Option<Metadata>> {
+        let Some(rule) = self.trigger(operation, path) else {
+            return Ok(None);
+        };
+
+        if let Some(delay) = rule.delay {
+            tokio::time::sleep(delay).await;
+        }
+        match rule.new_error(operation, path) {
+            Some(err) => Err(err),
+            None => Ok(rule.metadata.clone()),
+        }
+    }
+
+    fn blocking_apply(&self, operation: Operation, path: &str) -> Result<Option<Metadata>> {
+        let Some(rule) = self.trigger(operation, path) else {
+            return Ok(None);
+        };
+
+        if let Some(delay) = rule.delay {
+            thread::sleep(delay);
+        }
+        match rule.new_error(operation, path) {
+            Some(err) => Err(err),
+            None => Ok(rule.metadata.clone()),
+        }
+    }
+}
+
+impl Access for MockBackend {
+    type Reader = oio::Reader;
+    type Writer = oio::Writer;
+    type Lister = oio::Lister;
+    type BlockingReader = oio::BlockingReader;
+    type BlockingWriter = oio::BlockingWriter;
+    type BlockingLister = oio::BlockingLister;
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        self.info.clone()
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.apply(Operation::CreateDir, path).await?;
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.apply(Operation::Stat, path).await? {
+            Some(meta) => Ok(RpStat::new(meta)),
+            None => self.inner.stat(path, args).await,
+        }
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.apply(Operation::Read, path).await?;
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.apply(Operation::Write, path).await?;
+        self.inner.write(path, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.apply(Operation::Delete, path).await?;
+        self.inner.delete(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.apply(Operation::List, path).await?;
+        self.inner.list(path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.apply(Operation::Copy, from).await?;
+        self.inner.copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.apply(Operation::Rename, from).await?;
+        self.inner.rename(from, to, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.blocking_apply(Operation::BlockingCreateDir, path)?;
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.blocking_apply(Operation::BlockingStat, path)? {
+            Some(meta) => Ok(RpStat::new(meta)),
+            None => self.inner.blocking_stat(path, args),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.blocking_apply(Operation::BlockingRead, path)?;
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.blocking_apply(Operation::BlockingWrite, path)?;
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.blocking_apply(Operation::BlockingDelete, path)?;
+        self.inner.blocking_delete(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.blocking_apply(Operation::BlockingList, path)?;
+        self.inner.blocking_list(path, args)
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.blocking_apply(Operation::BlockingCopy, from)?;
+        self.inner.blocking_copy(from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.blocking_apply(Operation::BlockingRename, from)?;
+        self.inner.blocking_rename(from, to, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fail_nth_write() -> Result<()> {
+        let op = Operator::new(
+            MockBuilder::default().rule(
+                MockRule::new(Operation::Write)
+                    .nth(3)
+                    .fail(ErrorKind::Unexpected, "disk is full"),
+            ),
+        )?
+        .finish();
+
+        op.write("a", "1").await?;
+        op.write("b", "2").await?;
+        let err = op.write("c", "3").await.expect_err("3rd write must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        op.write("d", "4").await?;
+
+        assert!(!op.is_exist("c").await?);
+        assert_eq!(op.read("d").await?.to_vec(), b"4");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delay_and_metadata() -> Result<()> {
+        let op = Operator::new(
+            MockBuilder::default().rule(
+                MockRule::new(Operation::Stat)
+                    .path("file")
+                    .delay(Duration::from_millis(100))
+                    .metadata(Metadata::new(EntryMode::FILE).with_content_length(42)),
+            ),
+        )?
+        .finish();
+
+        let now = Instant::now();
+        let meta = op.stat("file").await?;
+        assert!(now.elapsed() >= Duration::from_millis(100));
+        assert_eq!(meta.content_length(), 42);
+
+        // Other paths are not affected.
+        let err = op.stat("other").await.expect_err("must not exist");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking_temporary_error() -> Result<()> {
+        let op = Operator::new(
+            MockBuilder::default().rule(
+                MockRule::new(Operation::BlockingDelete)
+                    .fail(ErrorKind::RateLimited, "slow down")
+                    .temporary(),
+            ),
+        )?
+        .finish()
+        .blocking();
+
+        let err = op.delete("file").expect_err("delete must fail");
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+        Ok(())
+    }
+}
@@ -88,6 +88,8 @@ mod gcs;
 pub use gcs::Gcs;
 #[cfg(feature = "services-gcs")]
 pub use gcs::GcsConfig;
+#[cfg(feature = "services-gcs")]
+pub use gcs::GcsSessionStore;
 
 #[cfg(feature = "services-ghac")]
 mod ghac;
@@ -292,6 +294,13 @@ pub use dropbox::Dropbox;
 #[cfg(feature = "services-dropbox")]
 pub use dropbox::DropboxConfig;
 
+#[cfg(feature = "services-dynamodb")]
+mod dynamodb;
+#[cfg(feature = "services-dynamodb")]
+pub use dynamodb::Dynamodb;
+#[cfg(feature = "services-dynamodb")]
+pub use dynamodb::DynamodbConfig;
+
 #[cfg(feature = "services-vercel-artifacts")]
 mod vercel_artifacts;
 #[cfg(feature = "services-vercel-artifacts")]
@@ -299,6 +308,13 @@ pub use vercel_artifacts::VercelArtifacts;
 #[cfg(feature = "services-vercel-artifacts")]
 pub use vercel_artifacts::VercelArtifactsConfig;
 
+#[cfg(feature = "services-rados")]
+mod rados;
+#[cfg(feature = "services-rados")]
+pub use rados::Rados;
+#[cfg(feature = "services-rados")]
+pub use rados::RadosConfig;
+
 #[cfg(feature = "services-redb")]
 mod redb;
 #[cfg(feature = "services-redb")]
@@ -362,6 +378,15 @@ pub use self::azfile::Azfile;
 #[cfg(feature = "services-azfile")]
 pub use self::azfile::AzfileConfig;
 
+#[cfg(feature = "services-mock")]
+mod mock;
+#[cfg(feature = "services-mock")]
+pub use self::mock::Mock;
+#[cfg(feature = "services-mock")]
+pub use self::mock::MockConfig;
+#[cfg(feature = "services-mock")]
+pub use self::mock::MockRule;
+
 #[cfg(feature = "services-mongodb")]
 mod mongodb;
 #[cfg(feature = "services-mongodb")]
@@ -383,6 +408,13 @@ pub use self::swift::Swift;
 #[cfg(feature = "services-swift")]
 pub use self::swift::SwiftConfig;
 
+#[cfg(feature = "services-artifactory")]
+mod artifactory;
+#[cfg(feature = "services-artifactory")]
+pub use artifactory::Artifactory;
+#[cfg(feature = "services-artifactory")]
+pub use artifactory::ArtifactoryConfig;
+
 #[cfg(feature = "services-alluxio")]
 mod alluxio;
 #[cfg(feature = "services-alluxio")]
@@ -404,6 +436,13 @@ pub use seafile::Seafile;
 #[cfg(feature = "services-seafile")]
 pub use seafile::SeafileConfig;
 
+#[cfg(feature = "services-upstash")]
+mod upstash;
+#[cfg(feature = "services-upstash")]
+pub use upstash::Upstash;
+#[cfg(feature = "services-upstash")]
+pub use upstash::UpstashConfig;
+
 #[cfg(feature = "services-upyun")]
 mod upyun;
 #[cfg(feature = "services-upyun")]
@@ -18,10 +18,12 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::time::Duration;
+use std::time::Instant;
 
 use log::debug;
 use moka::sync::CacheBuilder;
 use moka::sync::SegmentedCache;
+use moka::Expiry;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -151,10 +153,12 @@ impl Builder for MokaBuilder {
     fn build(self) -> Result<impl Access> {
         debug!("backend build started: {:?}", &self);
 
-        let mut builder: CacheBuilder<String, typed_kv::Value, _> =
+        let mut builder: CacheBuilder<String, Entry, _> =
             SegmentedCache::builder(self.config.num_segments.unwrap_or(1));
         // Use entries' bytes as capacity weigher.
-        builder = builder.weigher(|k, v| (k.len() + v.size()) as u32);
+        builder = builder.weigher(|k, v| (k.len() + v.value.size()) as u32);
+        // Apply the ttl of every entry, `time_to_live` and `time_to_idle` still work as upper bound.
+        builder = builder.expire_after(EntryExpiry);
         if let Some(v) = &self.config.name {
             builder = builder.name(v);
         }
@@ -184,9 +188,36 @@ impl Builder for MokaBuilder {
 /// Backend is used to serve `Accessor` support in moka.
 pub type MokaBackend = typed_kv::Backend<Adapter>;
 
+/// Entry is the value stored in moka along with its ttl.
+#[derive(Debug, Clone)]
+struct Entry {
+    value: typed_kv::Value,
+    ttl: Option<Duration>,
+}
+
+/// EntryExpiry expires entries based on their own ttl.
+struct EntryExpiry;
+
+impl Expiry<String, Entry> for EntryExpiry {
+    fn expire_after_create(&self, _: &String, value: &Entry, _: Instant) -> Option<Duration> {
+        value.ttl
+    }
+
+    fn expire_after_update(
+        &self,
+        _: &String,
+        value: &Entry,
+        _: Instant,
+        _: Option<Duration>,
+    ) -> Option<Duration> {
+        // The ttl will be reset (or removed) while entry is overwritten.
+        value.ttl
+    }
+}
+
 #[derive(Clone)]
 pub struct Adapter {
-    inner: SegmentedCache<String, typed_kv::Value>,
+    inner: SegmentedCache<String, Entry>,
 }
 
 impl Debug for Adapter {
@@ -208,6 +239,8 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                scan_sorted: false,
+                set_with_ttl: true,
             },
         )
     }
@@ -219,7 +252,7 @@ impl typed_kv::Adapter for Adapter {
     fn blocking_get(&self, path: &str) -> Result<Option<typed_kv::Value>> {
         match self.inner.get(path) {
             None => Ok(None),
-            Some(bs) => Ok(Some(bs)),
+            Some(entry) => Ok(Some(entry.value)),
         }
     }
 
@@ -228,7 +261,29 @@ impl typed_kv::Adapter for Adapter {
     }
 
     fn blocking_set(&self, path: &str, value: typed_kv::Value) -> Result<()> {
-        self.inner.insert(path.to_string(), value);
+        self.inner
+            .insert(path.to_string(), Entry { value, ttl: None });
+
+        Ok(())
+    }
+
+    async fn set_with_ttl(&self, path: &str, value: typed_kv::Value, ttl: Duration) -> Result<()> {
+        self.blocking_set_with_ttl(path, value, ttl)
+    }
+
+    fn blocking_set_with_ttl(
+        &self,
+        path: &str,
+        value: typed_kv::Value,
+        ttl: Duration,
+    ) -> Result<()> {
+        self.inner.insert(
+            path.to_string(),
+            Entry {
+                value,
+                ttl: Some(ttl),
+            },
+        );
 
         Ok(())
     }
@@ -256,3 +311,35 @@ impl typed_kv::Adapter for Adapter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_with_ttl() {
+        let op = Operator::new(MokaBuilder::default()).unwrap().finish();
+        assert!(op.info().full_capability().write_with_ttl);
+
+        op.write_with("expired", "hello")
+            .ttl(Duration::from_millis(50))
+            .await
+            .unwrap();
+        op.write("kept", "world").await.unwrap();
+        assert_eq!(op.read("expired").await.unwrap().to_vec(), b"hello");
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let err = op.read("expired").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert_eq!(op.read("kept").await.unwrap().to_vec(), b"world");
+
+        // Overwrite without ttl should remove the expiration.
+        op.write_with("renewed", "hello")
+            .ttl(Duration::from_millis(50))
+            .await
+            .unwrap();
+        op.write("renewed", "hello").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(op.read("renewed").await.is_ok());
+    }
+}
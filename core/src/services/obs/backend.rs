@@ -24,12 +24,13 @@ use http::StatusCode;
 use http::Uri;
 use log::debug;
 use reqsign::HuaweicloudObsConfig;
-use reqsign::HuaweicloudObsCredentialLoader;
+use reqsign::HuaweicloudObsCredential;
 use reqsign::HuaweicloudObsSigner;
 use serde::Deserialize;
 use serde::Serialize;
 
 use super::core::ObsCore;
+use super::core::ObsCredentialProvider;
 use super::error::parse_error;
 use super::lister::ObsLister;
 use super::writer::ObsWriter;
@@ -68,9 +69,15 @@ impl Debug for ObsConfig {
 
 impl Configurator for ObsConfig {
     type Builder = ObsBuilder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("bucket"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         ObsBuilder {
             config: self,
+            customized_credential_load: None,
             http_client: None,
         }
     }
@@ -81,6 +88,9 @@ impl Configurator for ObsConfig {
 #[derive(Default, Clone)]
 pub struct ObsBuilder {
     config: ObsConfig,
+
+    customized_credential_load:
+        Option<Arc<dyn ProvideCredential<Credential = HuaweicloudObsCredential>>>,
     http_client: Option<HttpClient>,
 }
 
@@ -152,6 +162,19 @@ impl ObsBuilder {
         self
     }
 
+    /// Adding a customized credential load for service.
+    ///
+    /// If customized_credential_load has been set, we will ignore all other
+    /// credential load methods. The loaded credential will be cached and the
+    /// provider will be called again once the credential is expired.
+    pub fn customized_credential_load(
+        mut self,
+        cred: Arc<dyn ProvideCredential<Credential = HuaweicloudObsCredential>>,
+    ) -> Self {
+        self.customized_credential_load = Some(cred);
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -229,7 +252,10 @@ impl Builder for ObsBuilder {
             cfg.secret_access_key = Some(v);
         }
 
-        let loader = HuaweicloudObsCredentialLoader::new(cfg);
+        let loader = match self.customized_credential_load {
+            Some(v) => CredentialLoader::from_arc(v),
+            None => CredentialLoader::new(ObsCredentialProvider::new(cfg)),
+        };
 
         // Set the bucket name in CanonicalizedResource.
         // 1. If the bucket is bound to a user domain name, use the user domain name as the bucket name,
@@ -278,6 +304,7 @@ impl Access for ObsBackend {
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::Obs)
             .set_root(&self.core.root)
+            .set_http_client(self.core.client.clone())
             .set_name(&self.core.bucket)
             .set_native_capability(Capability {
                 stat: true,
@@ -307,12 +334,17 @@ impl Access for ObsBackend {
                 } else {
                     Some(usize::MAX)
                 },
+                // The max multipart count of OBS is 10000.
+                //
+                // ref: <https://support.huaweicloud.com/intl/en-us/ugobs-obs/obs_41_0021.html>
+                write_multi_max_parts: Some(10000),
 
                 delete: true,
                 copy: true,
 
                 list: true,
                 list_with_recursive: true,
+                list_has_content_length: true,
 
                 presign: true,
                 presign_stat: true,
@@ -347,7 +379,8 @@ impl Access for ObsBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -28,6 +28,7 @@ use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
 use http::Request;
 use http::Response;
+use reqsign::HuaweicloudObsConfig;
 use reqsign::HuaweicloudObsCredential;
 use reqsign::HuaweicloudObsCredentialLoader;
 use reqsign::HuaweicloudObsSigner;
@@ -43,7 +44,7 @@ pub struct ObsCore {
     pub endpoint: String,
 
     pub signer: HuaweicloudObsSigner,
-    pub loader: HuaweicloudObsCredentialLoader,
+    pub loader: CredentialLoader<HuaweicloudObsCredential>,
     pub client: HttpClient,
 }
 
@@ -57,13 +58,47 @@ impl Debug for ObsCore {
     }
 }
 
+impl SigningCredential for HuaweicloudObsCredential {
+    fn is_valid(&self) -> bool {
+        !self.access_key_id.is_empty() && !self.secret_access_key.is_empty()
+    }
+}
+
+/// ObsCredentialProvider will load credential via reqsign.
+pub struct ObsCredentialProvider {
+    config: HuaweicloudObsConfig,
+}
+
+impl Debug for ObsCredentialProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObsCredentialProvider")
+            .finish_non_exhaustive()
+    }
+}
+
+impl ObsCredentialProvider {
+    /// Create a new provider via given config.
+    pub fn new(config: HuaweicloudObsConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl ProvideCredential for ObsCredentialProvider {
+    type Credential = HuaweicloudObsCredential;
+
+    fn provide_credential(&self) -> BoxedFuture<'_, Result<Option<Self::Credential>>> {
+        Box::pin(async move {
+            HuaweicloudObsCredentialLoader::new(self.config.clone())
+                .load()
+                .await
+                .map_err(new_request_credential_error)
+        })
+    }
+}
+
 impl ObsCore {
     async fn load_credential(&self) -> Result<Option<HuaweicloudObsCredential>> {
-        let cred = self
-            .loader
-            .load()
-            .await
-            .map_err(new_request_credential_error)?;
+        let cred = self.loader.load().await?;
 
         if let Some(cred) = cred {
             Ok(Some(cred))
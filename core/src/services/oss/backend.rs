@@ -26,7 +26,7 @@ use http::StatusCode;
 use http::Uri;
 use log::debug;
 use reqsign::AliyunConfig;
-use reqsign::AliyunLoader;
+use reqsign::AliyunCredential;
 use reqsign::AliyunOssSigner;
 use serde::Deserialize;
 use serde::Serialize;
@@ -40,6 +40,7 @@ use crate::services::oss::writer::OssWriters;
 use crate::*;
 
 const DEFAULT_BATCH_MAX_OPERATIONS: usize = 1000;
+const OSS_ACCELERATE_ENDPOINT: &str = "oss-accelerate.aliyuncs.com";
 
 /// Config for Aliyun Object Storage Service (OSS) support.
 #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -63,12 +64,26 @@ pub struct OssConfig {
     pub server_side_encryption_key_id: Option<String>,
     /// Allow anonymous for oss.
     pub allow_anonymous: bool,
+    /// Enable transfer acceleration for oss.
+    pub enable_accelerate: bool,
 
     // authenticate options
     /// Access key id for oss.
     pub access_key_id: Option<String>,
     /// Access key secret for oss.
     pub access_key_secret: Option<String>,
+    /// Security token for oss.
+    pub security_token: Option<String>,
+    /// Role arn for oss.
+    pub role_arn: Option<String>,
+    /// Role session name for oss.
+    pub role_session_name: Option<String>,
+    /// OIDC provider arn for oss.
+    pub oidc_provider_arn: Option<String>,
+    /// OIDC token file for oss.
+    pub oidc_token_file: Option<String>,
+    /// STS endpoint for oss.
+    pub sts_endpoint: Option<String>,
     /// batch_max_operations
     pub batch_max_operations: Option<usize>,
 }
@@ -79,7 +94,13 @@ impl Debug for OssConfig {
         d.field("root", &self.root)
             .field("bucket", &self.bucket)
             .field("endpoint", &self.endpoint)
-            .field("allow_anonymous", &self.allow_anonymous);
+            .field("allow_anonymous", &self.allow_anonymous)
+            .field("enable_accelerate", &self.enable_accelerate)
+            .field("role_arn", &self.role_arn)
+            .field("role_session_name", &self.role_session_name)
+            .field("oidc_provider_arn", &self.oidc_provider_arn)
+            .field("oidc_token_file", &self.oidc_token_file)
+            .field("sts_endpoint", &self.sts_endpoint);
 
         d.finish_non_exhaustive()
     }
@@ -87,9 +108,15 @@ impl Debug for OssConfig {
 
 impl Configurator for OssConfig {
     type Builder = OssBuilder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("bucket"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         OssBuilder {
             config: self,
+            customized_credential_load: None,
             http_client: None,
         }
     }
@@ -100,6 +127,8 @@ impl Configurator for OssConfig {
 #[derive(Default)]
 pub struct OssBuilder {
     config: OssConfig,
+
+    customized_credential_load: Option<Arc<dyn ProvideCredential<Credential = AliyunCredential>>>,
     http_client: Option<HttpClient>,
 }
 
@@ -184,6 +213,100 @@ impl OssBuilder {
         self
     }
 
+    /// Set security_token of this backend.
+    ///
+    /// The security token is required while using temporary credentials
+    /// issued by Aliyun STS. Please note that static security tokens can't
+    /// be refreshed, use `role_arn` along with `oidc_provider_arn` and
+    /// `oidc_token_file` for long-running jobs instead.
+    pub fn security_token(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.security_token = Some(v.to_string())
+        }
+
+        self
+    }
+
+    /// Set role_arn of this backend.
+    ///
+    /// If role_arn is set, we will assume this role via
+    /// `AssumeRoleWithOIDC` and refresh the temporary credential
+    /// automatically before it expires.
+    pub fn role_arn(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.role_arn = Some(v.to_string())
+        }
+
+        self
+    }
+
+    /// Set role_session_name of this backend.
+    pub fn role_session_name(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.role_session_name = Some(v.to_string())
+        }
+
+        self
+    }
+
+    /// Set oidc_provider_arn of this backend.
+    pub fn oidc_provider_arn(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.oidc_provider_arn = Some(v.to_string())
+        }
+
+        self
+    }
+
+    /// Set oidc_token_file of this backend.
+    ///
+    /// The token file will be re-read every time the credential is refreshed,
+    /// so it's safe to rotate it on the fly.
+    pub fn oidc_token_file(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.oidc_token_file = Some(v.to_string())
+        }
+
+        self
+    }
+
+    /// Set sts_endpoint of this backend.
+    ///
+    /// The endpoint should be host only like `sts.cn-hangzhou.aliyuncs.com`,
+    /// default to `sts.aliyuncs.com` if not set.
+    pub fn sts_endpoint(mut self, v: &str) -> Self {
+        if !v.is_empty() {
+            self.config.sts_endpoint = Some(v.to_string())
+        }
+
+        self
+    }
+
+    /// Enable transfer acceleration for this backend.
+    ///
+    /// If enabled, requests will be sent to the global acceleration endpoint
+    /// `oss-accelerate.aliyuncs.com` instead of the regional endpoint. The
+    /// bucket must have transfer acceleration enabled.
+    ///
+    /// Reference: <https://www.alibabacloud.com/help/en/oss/user-guide/enable-transfer-acceleration>
+    pub fn enable_accelerate(mut self) -> Self {
+        self.config.enable_accelerate = true;
+        self
+    }
+
+    /// Adding a customized credential load for service.
+    ///
+    /// If customized_credential_load has been set, we will ignore all other
+    /// credential load methods. The loaded credential will be cached and the
+    /// provider will be called again once the credential is expired.
+    pub fn customized_credential_load(
+        mut self,
+        cred: Arc<dyn ProvideCredential<Credential = AliyunCredential>>,
+    ) -> Self {
+        self.customized_credential_load = Some(cred);
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -309,7 +432,12 @@ impl Builder for OssBuilder {
 
         // Retrieve endpoint and host by parsing the endpoint option and bucket. If presign_endpoint is not
         // set, take endpoint as default presign_endpoint.
-        let (endpoint, host) = self.parse_endpoint(&self.config.endpoint, bucket)?;
+        let endpoint = if self.config.enable_accelerate {
+            Some(accelerate_endpoint(&self.config.endpoint))
+        } else {
+            self.config.endpoint.clone()
+        };
+        let (endpoint, host) = self.parse_endpoint(&endpoint, bucket)?;
         debug!("backend use bucket {}, endpoint: {}", &bucket, &endpoint);
 
         let presign_endpoint = if self.config.presign_endpoint.is_some() {
@@ -348,6 +476,30 @@ impl Builder for OssBuilder {
             cfg.access_key_secret = Some(v);
         }
 
+        if let Some(v) = self.config.security_token {
+            cfg.security_token = Some(v);
+        }
+
+        if let Some(v) = self.config.role_arn {
+            cfg.role_arn = Some(v);
+        }
+
+        if let Some(v) = self.config.role_session_name {
+            cfg.role_session_name = v;
+        }
+
+        if let Some(v) = self.config.oidc_provider_arn {
+            cfg.oidc_provider_arn = Some(v);
+        }
+
+        if let Some(v) = self.config.oidc_token_file {
+            cfg.oidc_token_file = Some(v);
+        }
+
+        if let Some(v) = self.config.sts_endpoint {
+            cfg.sts_endpoint = Some(v);
+        }
+
         let client = if let Some(client) = self.http_client {
             client
         } else {
@@ -357,7 +509,10 @@ impl Builder for OssBuilder {
             })?
         };
 
-        let loader = AliyunLoader::new(client.client(), cfg);
+        let loader = match self.customized_credential_load {
+            Some(v) => CredentialLoader::from_arc(v),
+            None => CredentialLoader::new(OssCredentialProvider::new(client.client()?, cfg)),
+        };
 
         let signer = AliyunOssSigner::new(bucket);
 
@@ -385,6 +540,16 @@ impl Builder for OssBuilder {
     }
 }
 
+/// Build the transfer acceleration endpoint while keeping the scheme of
+/// user input.
+fn accelerate_endpoint(endpoint: &Option<String>) -> String {
+    let scheme = match endpoint.as_deref() {
+        Some(ep) if ep.starts_with("http://") => "http",
+        _ => "https",
+    };
+    format!("{scheme}://{OSS_ACCELERATE_ENDPOINT}")
+}
+
 #[derive(Debug, Clone)]
 /// Aliyun Object Storage Service backend
 pub struct OssBackend {
@@ -403,6 +568,7 @@ impl Access for OssBackend {
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::Oss)
             .set_root(&self.core.root)
+            .set_http_client(self.core.client.clone())
             .set_name(&self.core.bucket)
             .set_native_capability(Capability {
                 stat: true,
@@ -433,6 +599,10 @@ impl Access for OssBackend {
                 } else {
                     Some(usize::MAX)
                 },
+                // The max multipart count of OSS is 10000.
+                //
+                // ref: <https://www.alibabacloud.com/help/en/oss/user-guide/multipart-upload-12>
+                write_multi_max_parts: Some(10000),
                 write_with_user_metadata: true,
 
                 delete: true,
@@ -442,6 +612,9 @@ impl Access for OssBackend {
                 list_with_limit: true,
                 list_with_start_after: true,
                 list_with_recursive: true,
+                list_has_content_length: true,
+                list_has_etag: true,
+                list_has_last_modified: true,
 
                 presign: true,
                 presign_stat: true,
@@ -451,6 +624,9 @@ impl Access for OssBackend {
                 batch: true,
                 batch_max_operations: Some(self.core.batch_max_operations),
 
+                create_bucket: true,
+                delete_bucket: true,
+
                 ..Default::default()
             });
 
@@ -486,7 +662,8 @@ impl Access for OssBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -620,4 +797,41 @@ impl Access for OssBackend {
             Err(parse_error(resp).await?)
         }
     }
+
+    async fn create_bucket(&self, _: OpCreateBucket) -> Result<RpCreateBucket> {
+        let resp = self.core.oss_create_bucket().await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpCreateBucket::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn delete_bucket(&self, _: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        let resp = self.core.oss_delete_bucket().await?;
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(RpDeleteBucket::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accelerate_endpoint() {
+        assert_eq!(
+            accelerate_endpoint(&None),
+            "https://oss-accelerate.aliyuncs.com"
+        );
+        assert_eq!(
+            accelerate_endpoint(&Some("https://oss-cn-beijing.aliyuncs.com".to_string())),
+            "https://oss-accelerate.aliyuncs.com"
+        );
+        assert_eq!(
+            accelerate_endpoint(&Some("http://oss-cn-beijing.aliyuncs.com".to_string())),
+            "http://oss-accelerate.aliyuncs.com"
+        );
+    }
 }
@@ -34,12 +34,14 @@ use http::HeaderName;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use reqsign::AliyunConfig;
 use reqsign::AliyunCredential;
 use reqsign::AliyunLoader;
 use reqsign::AliyunOssSigner;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::error::is_credential_expired;
 use crate::raw::*;
 use crate::*;
 
@@ -70,7 +72,7 @@ pub struct OssCore {
     pub server_side_encryption_key_id: Option<HeaderValue>,
 
     pub client: HttpClient,
-    pub loader: AliyunLoader,
+    pub loader: CredentialLoader<AliyunCredential>,
     pub signer: AliyunOssSigner,
     pub batch_max_operations: usize,
 }
@@ -86,13 +88,50 @@ impl Debug for OssCore {
     }
 }
 
+impl SigningCredential for AliyunCredential {
+    fn is_valid(&self) -> bool {
+        AliyunCredential::is_valid(self)
+    }
+}
+
+/// OssCredentialProvider will load credential via reqsign.
+pub struct OssCredentialProvider {
+    client: reqwest::Client,
+    config: AliyunConfig,
+}
+
+impl Debug for OssCredentialProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OssCredentialProvider")
+            .finish_non_exhaustive()
+    }
+}
+
+impl OssCredentialProvider {
+    /// Create a new provider via given client and config.
+    pub fn new(client: reqwest::Client, config: AliyunConfig) -> Self {
+        Self { client, config }
+    }
+}
+
+impl ProvideCredential for OssCredentialProvider {
+    type Credential = AliyunCredential;
+
+    fn provide_credential(&self) -> BoxedFuture<'_, Result<Option<Self::Credential>>> {
+        Box::pin(async move {
+            // Always build a new loader so that the expired credential cached
+            // by reqsign will not be returned again.
+            AliyunLoader::new(self.client.clone(), self.config.clone())
+                .load()
+                .await
+                .map_err(new_request_credential_error)
+        })
+    }
+}
+
 impl OssCore {
     async fn load_credential(&self) -> Result<Option<AliyunCredential>> {
-        let cred = self
-            .loader
-            .load()
-            .await
-            .map_err(new_request_credential_error)?;
+        let cred = self.loader.load().await?;
 
         if let Some(cred) = cred {
             Ok(Some(cred))
@@ -131,9 +170,16 @@ impl OssCore {
             .map_err(new_request_sign_error)
     }
 
-    #[inline]
     pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
-        self.client.send(req).await
+        let resp = self.client.send(req).await?;
+
+        // Expire the cached credential if server reports that it has been
+        // expired, so that the retried request will sign with a new one.
+        if is_credential_expired(&resp) {
+            self.loader.expire();
+        }
+
+        Ok(resp)
     }
 
     /// Set sse headers
@@ -513,6 +559,29 @@ impl OssCore {
         self.send(req).await
     }
 
+    pub async fn oss_create_bucket(&self) -> Result<Response<Buffer>> {
+        let url = format!("{}/", self.endpoint);
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn oss_delete_bucket(&self) -> Result<Response<Buffer>> {
+        let url = format!("{}/", self.endpoint);
+
+        let mut req = Request::delete(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn oss_delete_objects(&self, paths: Vec<String>) -> Result<Response<Buffer>> {
         let url = format!("{}/?delete", self.endpoint);
 
@@ -52,11 +52,15 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
         _ => (ErrorKind::Unexpected, false),
     };
 
-    let message = match de::from_reader::<_, OssError>(bs.clone().reader()) {
-        Ok(oss_err) => format!("{oss_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let (message, oss_err) = match de::from_reader::<_, OssError>(bs.clone().reader()) {
+        Ok(oss_err) => (format!("{oss_err:?}"), Some(oss_err)),
+        Err(_) => (String::from_utf8_lossy(&bs).into_owned(), None),
     };
 
+    let (kind, retryable) = oss_err
+        .and_then(|err| parse_oss_error_code(err.code.trim()))
+        .unwrap_or((kind, retryable));
+
     let mut err = Error::new(kind, message);
 
     err = with_error_response_context(err, parts);
@@ -68,6 +72,33 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
     Ok(err)
 }
 
+/// Check if the response reports that the credential has been expired.
+pub(crate) fn is_credential_expired(resp: &Response<Buffer>) -> bool {
+    if resp.status() != StatusCode::FORBIDDEN {
+        return false;
+    }
+
+    de::from_reader::<_, OssError>(resp.body().clone().reader())
+        .map(|err| err.code.trim() == "SecurityTokenExpired")
+        .unwrap_or_default()
+}
+
+/// Returns the `ErrorKind` of this code and whether the error is retryable.
+///
+/// Reference: <https://www.alibabacloud.com/help/en/oss/support/http-status-code-403>
+fn parse_oss_error_code(code: &str) -> Option<(ErrorKind, bool)> {
+    match code {
+        // The temporary credential has expired, the loader will refresh it
+        // on the next attempt.
+        "SecurityTokenExpired" => Some((ErrorKind::PermissionDenied, true)),
+        // The position of append doesn't match the current object length.
+        "PositionNotEqualToLength" => Some((ErrorKind::ConditionNotMatch, false)),
+        // The bucket to create already exists.
+        "BucketAlreadyExists" => Some((ErrorKind::AlreadyExists, false)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +134,42 @@ mod tests {
         assert_eq!(out.request_id, "1D842BC54255****");
         assert_eq!(out.host_id, "oss-cn-hangzhou.aliyuncs.com");
     }
+
+    #[test]
+    fn test_parse_oss_error_code() {
+        assert_eq!(
+            parse_oss_error_code("SecurityTokenExpired"),
+            Some((ErrorKind::PermissionDenied, true))
+        );
+        assert_eq!(
+            parse_oss_error_code("PositionNotEqualToLength"),
+            Some((ErrorKind::ConditionNotMatch, false))
+        );
+        assert_eq!(parse_oss_error_code("AccessDenied"), None);
+    }
+
+    #[test]
+    fn test_is_credential_expired() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>SecurityTokenExpired</Code>
+  <Message>The security token you provided has expired.</Message>
+  <RequestId>1D842BC54255****</RequestId>
+  <HostId>oss-cn-hangzhou.aliyuncs.com</HostId>
+</Error>"#;
+
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Buffer::from(body))
+            .unwrap();
+        assert!(is_credential_expired(&resp));
+
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Buffer::from(
+                body.replace("SecurityTokenExpired", "AccessDenied"),
+            ))
+            .unwrap();
+        assert!(!is_credential_expired(&resp));
+    }
 }
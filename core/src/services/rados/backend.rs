@@ -0,0 +1,281 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::task;
+
+use super::core::RadosCore;
+use super::core::MAX_WRITE_SIZE;
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::*;
+
+/// Config for ceph rados service support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct RadosConfig {
+    /// The path of ceph config file.
+    ///
+    /// Default locations like `/etc/ceph/ceph.conf` will be searched if not set.
+    pub config_file: Option<String>,
+    /// The user id to connect as, like `admin` for `client.admin`.
+    pub user: Option<String>,
+    /// The monitor addresses, overrides `mon_host` in config file.
+    pub mon_host: Option<String>,
+    /// The secret key of the user, overrides `key` in config file.
+    pub key: Option<String>,
+    /// The pool to store objects, required.
+    pub pool: String,
+    /// The namespace inside the pool.
+    pub namespace: Option<String>,
+    /// The root for rados.
+    pub root: Option<String>,
+}
+
+impl Debug for RadosConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadosConfig")
+            .field("config_file", &self.config_file)
+            .field("user", &self.user)
+            .field("mon_host", &self.mon_host)
+            .field("pool", &self.pool)
+            .field("namespace", &self.namespace)
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Configurator for RadosConfig {
+    type Builder = RadosBuilder;
+    fn into_builder(self) -> Self::Builder {
+        RadosBuilder { config: self }
+    }
+}
+
+/// [Ceph RADOS](https://docs.ceph.com/en/latest/rados/api/librados/) service support.
+#[doc = include_str!("docs.md")]
+#[derive(Default, Debug)]
+pub struct RadosBuilder {
+    config: RadosConfig,
+}
+
+impl RadosBuilder {
+    /// Set the path of ceph config file.
+    ///
+    /// Default locations like `/etc/ceph/ceph.conf` will be searched if not set.
+    pub fn config_file(mut self, path: &str) -> Self {
+        if !path.is_empty() {
+            self.config.config_file = Some(path.to_string());
+        }
+        self
+    }
+
+    /// Set the user id to connect as, like `admin` for `client.admin`.
+    pub fn user(mut self, user: &str) -> Self {
+        if !user.is_empty() {
+            self.config.user = Some(user.to_string());
+        }
+        self
+    }
+
+    /// Set the monitor addresses, like `10.0.0.1,10.0.0.2`.
+    pub fn mon_host(mut self, mon_host: &str) -> Self {
+        if !mon_host.is_empty() {
+            self.config.mon_host = Some(mon_host.to_string());
+        }
+        self
+    }
+
+    /// Set the secret key of the user.
+    pub fn key(mut self, key: &str) -> Self {
+        if !key.is_empty() {
+            self.config.key = Some(key.to_string());
+        }
+        self
+    }
+
+    /// Set the pool to store objects.
+    pub fn pool(mut self, pool: &str) -> Self {
+        self.config.pool = pool.to_string();
+        self
+    }
+
+    /// Set the namespace inside the pool.
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        if !namespace.is_empty() {
+            self.config.namespace = Some(namespace.to_string());
+        }
+        self
+    }
+
+    /// Set the root for rados.
+    pub fn root(mut self, root: &str) -> Self {
+        if !root.is_empty() {
+            self.config.root = Some(root.to_string());
+        }
+        self
+    }
+}
+
+impl Builder for RadosBuilder {
+    const SCHEME: Scheme = Scheme::Rados;
+    type Config = RadosConfig;
+
+    fn build(self) -> Result<impl Access> {
+        if self.config.pool.is_empty() {
+            return Err(
+                Error::new(ErrorKind::ConfigInvalid, "pool is required but not set")
+                    .with_context("service", Scheme::Rados),
+            );
+        }
+
+        let mut options = vec![];
+        if let Some(v) = &self.config.mon_host {
+            options.push(("mon_host", v.as_str()));
+        }
+        if let Some(v) = &self.config.key {
+            options.push(("key", v.as_str()));
+        }
+
+        let core = RadosCore::connect(
+            self.config.user.as_deref(),
+            self.config.config_file.as_deref(),
+            &options,
+            &self.config.pool,
+            self.config.namespace.as_deref(),
+        )
+        .map_err(|err| {
+            err.with_operation("Builder::build")
+                .with_context("service", Scheme::Rados)
+                .with_context("pool", &self.config.pool)
+        })?;
+
+        Ok(RadosBackend::new(Adapter {
+            core: Arc::new(core),
+        })
+        .with_root(self.config.root.as_deref().unwrap_or_default()))
+    }
+}
+
+/// Backend for rados services.
+pub type RadosBackend = kv::Backend<Adapter>;
+
+#[derive(Clone, Debug)]
+pub struct Adapter {
+    core: Arc<RadosCore>,
+}
+
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::Rados,
+            &self.core.pool,
+            Capability {
+                read: true,
+                write: true,
+                list: true,
+                blocking: true,
+                // Larger values can't be written atomically in one call.
+                write_total_max_size: Some(MAX_WRITE_SIZE),
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Buffer>> {
+        let this = self.clone();
+        let path = path.to_string();
+
+        task::spawn_blocking(move || this.blocking_get(&path))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    fn blocking_get(&self, path: &str) -> Result<Option<Buffer>> {
+        Ok(self
+            .blocking_get_range(path, BytesRange::default())?
+            .map(|(bs, _)| bs))
+    }
+
+    async fn get_range(&self, path: &str, range: BytesRange) -> Result<Option<(Buffer, u64)>> {
+        let this = self.clone();
+        let path = path.to_string();
+
+        task::spawn_blocking(move || this.blocking_get_range(&path, range))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    /// Read only the range of the object instead of the whole object.
+    fn blocking_get_range(&self, path: &str, range: BytesRange) -> Result<Option<(Buffer, u64)>> {
+        let Some(total) = self.core.stat(path)? else {
+            return Ok(None);
+        };
+
+        let offset = range.offset().min(total);
+        let size = range.size().unwrap_or(total).min(total - offset);
+        let bs = self.core.read(path, offset, size as usize)?;
+
+        Ok(Some((Buffer::from(bs), total)))
+    }
+
+    async fn set(&self, path: &str, value: Buffer) -> Result<()> {
+        let this = self.clone();
+        let path = path.to_string();
+
+        task::spawn_blocking(move || this.blocking_set(&path, value))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    fn blocking_set(&self, path: &str, value: Buffer) -> Result<()> {
+        self.core.write_full(path, &value.to_bytes())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let this = self.clone();
+        let path = path.to_string();
+
+        task::spawn_blocking(move || this.blocking_delete(&path))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    fn blocking_delete(&self, path: &str) -> Result<()> {
+        self.core.remove(path)
+    }
+
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        let this = self.clone();
+        let path = path.to_string();
+
+        task::spawn_blocking(move || this.blocking_scan(&path))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    fn blocking_scan(&self, path: &str) -> Result<Vec<String>> {
+        let mut res = self.core.list(path)?;
+        res.retain(|oid| oid != path);
+        Ok(res)
+    }
+}
@@ -0,0 +1,408 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io;
+use std::ptr;
+
+use super::ffi::*;
+use crate::*;
+
+/// The max size of a single write, keep it below the default `osd_max_write_size`
+/// (90MiB) so every object can be written atomically in one call.
+pub const MAX_WRITE_SIZE: usize = 64 * 1024 * 1024;
+/// The max size of a single read, the return value of `rados_read` is `int`.
+const MAX_READ_SIZE: usize = 64 * 1024 * 1024;
+/// The max number of keys to fetch from the index in one call.
+const MAX_LIST_KEYS: u64 = 1000;
+/// The object whose omap keys are all objects written by us.
+///
+/// Paths passed to kv adapters never start with `/`, so it will not conflict
+/// with the objects written by users.
+const INDEX_OID: &str = "/.opendal_index";
+
+/// RadosCore holds the connected cluster and the io context of the pool.
+pub struct RadosCore {
+    pub pool: String,
+    pub namespace: Option<String>,
+
+    cluster: rados_t,
+    ioctx: rados_ioctx_t,
+}
+
+/// # Safety
+///
+/// librados handles are thread safe, the io context settings are only changed
+/// while connecting.
+unsafe impl Send for RadosCore {}
+unsafe impl Sync for RadosCore {}
+
+impl Debug for RadosCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadosCore")
+            .field("pool", &self.pool)
+            .field("namespace", &self.namespace)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for RadosCore {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.ioctx.is_null() {
+                rados_ioctx_destroy(self.ioctx);
+            }
+            rados_shutdown(self.cluster);
+        }
+    }
+}
+
+impl RadosCore {
+    /// Connect to the cluster and create the io context for the pool.
+    pub fn connect(
+        user: Option<&str>,
+        config_file: Option<&str>,
+        options: &[(&str, &str)],
+        pool: &str,
+        namespace: Option<&str>,
+    ) -> Result<Self> {
+        let user = user.map(to_c_string).transpose()?;
+        let config_file = config_file.map(to_c_string).transpose()?;
+
+        let mut cluster: rados_t = ptr::null_mut();
+        let ret = unsafe {
+            rados_create(
+                &mut cluster,
+                user.as_ref().map_or(ptr::null(), |v| v.as_ptr()),
+            )
+        };
+        check(ret, "rados_create")?;
+
+        // Make sure the cluster will be shutdown if anything goes wrong.
+        let mut core = RadosCore {
+            pool: pool.to_string(),
+            namespace: namespace.map(|v| v.to_string()),
+            cluster,
+            ioctx: ptr::null_mut(),
+        };
+
+        // `NULL` means searching the default locations like `/etc/ceph/ceph.conf`.
+        let ret = unsafe {
+            rados_conf_read_file(
+                core.cluster,
+                config_file.as_ref().map_or(ptr::null(), |v| v.as_ptr()),
+            )
+        };
+        check(ret, "rados_conf_read_file")?;
+
+        for (k, v) in options {
+            let (k, v) = (to_c_string(k)?, to_c_string(v)?);
+            let ret = unsafe { rados_conf_set(core.cluster, k.as_ptr(), v.as_ptr()) };
+            check(ret, "rados_conf_set")?;
+        }
+
+        let ret = unsafe { rados_connect(core.cluster) };
+        check(ret, "rados_connect")?;
+
+        let pool = to_c_string(pool)?;
+        let ret = unsafe { rados_ioctx_create(core.cluster, pool.as_ptr(), &mut core.ioctx) };
+        check(ret, "rados_ioctx_create")?;
+
+        if let Some(ns) = namespace {
+            let ns = to_c_string(ns)?;
+            unsafe { rados_ioctx_set_namespace(core.ioctx, ns.as_ptr()) };
+        }
+
+        Ok(core)
+    }
+
+    /// Get the size of the object, returns `None` if not exist.
+    pub fn stat(&self, oid: &str) -> Result<Option<u64>> {
+        let oid = to_c_string(oid)?;
+
+        let mut size = 0;
+        let mut mtime = 0;
+        let ret = unsafe { rados_stat(self.ioctx, oid.as_ptr(), &mut size, &mut mtime) };
+        if ret == -libc::ENOENT {
+            return Ok(None);
+        }
+        check(ret, "rados_stat")?;
+
+        Ok(Some(size))
+    }
+
+    /// Read `size` bytes starting from `offset`, the returned data will be
+    /// shorter if the object ends earlier.
+    pub fn read(&self, oid: &str, offset: u64, size: usize) -> Result<Vec<u8>> {
+        let oid = to_c_string(oid)?;
+
+        let mut buf = vec![0; size];
+        let mut filled = 0;
+        while filled < size {
+            let len = (size - filled).min(MAX_READ_SIZE);
+            let ret = unsafe {
+                rados_read(
+                    self.ioctx,
+                    oid.as_ptr(),
+                    buf[filled..].as_mut_ptr() as *mut _,
+                    len,
+                    offset + filled as u64,
+                )
+            };
+            check(ret, "rados_read")?;
+            if ret == 0 {
+                break;
+            }
+            filled += ret as usize;
+        }
+        buf.truncate(filled);
+
+        Ok(buf)
+    }
+
+    /// Replace the content of the object and add it into the index.
+    ///
+    /// The content is written by a single `rados_write_full` so readers never
+    /// observe partial content, values larger than [`MAX_WRITE_SIZE`] will be
+    /// rejected.
+    pub fn write_full(&self, oid: &str, bs: &[u8]) -> Result<()> {
+        if bs.len() > MAX_WRITE_SIZE {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "value is larger than the max write size of rados",
+            )
+            .with_operation("rados_write_full")
+            .with_context("size", bs.len())
+            .with_context("max_size", MAX_WRITE_SIZE));
+        }
+
+        let c_oid = to_c_string(oid)?;
+        let ret = unsafe {
+            rados_write_full(
+                self.ioctx,
+                c_oid.as_ptr(),
+                bs.as_ptr() as *const _,
+                bs.len(),
+            )
+        };
+        check(ret, "rados_write_full")?;
+
+        // Update the index after the object has been written, so listing will
+        // only return complete objects.
+        self.index_set(&c_oid)
+    }
+
+    /// Remove the object and its index, it's ok if the object doesn't exist.
+    pub fn remove(&self, oid: &str) -> Result<()> {
+        let oid = to_c_string(oid)?;
+
+        let ret = unsafe { rados_remove(self.ioctx, oid.as_ptr()) };
+        if ret != -libc::ENOENT {
+            check(ret, "rados_remove")?;
+        }
+
+        self.index_remove(&oid)
+    }
+
+    /// List all objects starts with the prefix in current namespace.
+    ///
+    /// Objects are looked up in the omap of [`INDEX_OID`] which is sorted by
+    /// key, so we don't need to iterate over the whole pool.
+    pub fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let index = to_c_string(INDEX_OID)?;
+        let prefix = to_c_string(prefix)?;
+
+        let mut res = vec![];
+        let mut start_after = CString::default();
+        loop {
+            let (keys, more) = self.index_list(&index, &start_after, &prefix)?;
+            let Some(last) = keys.last() else {
+                break;
+            };
+            start_after = to_c_string(last)?;
+            res.extend(keys);
+
+            if !more {
+                break;
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn index_set(&self, oid: &CStr) -> Result<()> {
+        let index = to_c_string(INDEX_OID)?;
+        let val = CString::default();
+        let keys = [oid.as_ptr()];
+        let vals = [val.as_ptr()];
+        let lens = [0];
+
+        let ret = unsafe {
+            let op = rados_create_write_op();
+            rados_write_op_omap_set(op, keys.as_ptr(), vals.as_ptr(), lens.as_ptr(), 1);
+            let ret = rados_write_op_operate(op, self.ioctx, index.as_ptr(), ptr::null_mut(), 0);
+            rados_release_write_op(op);
+            ret
+        };
+        check(ret, "rados_write_op_omap_set")
+    }
+
+    fn index_remove(&self, oid: &CStr) -> Result<()> {
+        let index = to_c_string(INDEX_OID)?;
+        let keys = [oid.as_ptr()];
+
+        let ret = unsafe {
+            let op = rados_create_write_op();
+            rados_write_op_omap_rm_keys(op, keys.as_ptr(), 1);
+            let ret = rados_write_op_operate(op, self.ioctx, index.as_ptr(), ptr::null_mut(), 0);
+            rados_release_write_op(op);
+            ret
+        };
+        // The index object will not exist if nothing has been written.
+        if ret == -libc::ENOENT {
+            return Ok(());
+        }
+        check(ret, "rados_write_op_omap_rm_keys")
+    }
+
+    /// List at most [`MAX_LIST_KEYS`] keys after `start_after` from the index,
+    /// returns the keys and whether there are more keys.
+    fn index_list(
+        &self,
+        index: &CStr,
+        start_after: &CStr,
+        prefix: &CStr,
+    ) -> Result<(Vec<String>, bool)> {
+        let mut iter: rados_omap_iter_t = ptr::null_mut();
+        let mut more = 0;
+        let mut rval = 0;
+
+        let op = unsafe { rados_create_read_op() };
+        unsafe {
+            rados_read_op_omap_get_vals2(
+                op,
+                start_after.as_ptr(),
+                prefix.as_ptr(),
+                MAX_LIST_KEYS,
+                &mut iter,
+                &mut more,
+                &mut rval,
+            )
+        };
+        let ret = unsafe { rados_read_op_operate(op, self.ioctx, index.as_ptr(), 0) };
+
+        let result = if ret == -libc::ENOENT {
+            // The index object will not exist if nothing has been written.
+            Ok((vec![], false))
+        } else {
+            check(ret, "rados_read_op_operate")
+                .and_then(|_| check(rval, "rados_read_op_omap_get_vals2"))
+                .map(|_| {
+                    let mut keys = vec![];
+                    loop {
+                        let mut key = ptr::null_mut();
+                        let mut val = ptr::null_mut();
+                        let mut len = 0;
+                        let ret =
+                            unsafe { rados_omap_get_next(iter, &mut key, &mut val, &mut len) };
+                        if ret < 0 || key.is_null() {
+                            break;
+                        }
+                        // The key is owned by the iterator and only valid until next call.
+                        keys.push(
+                            unsafe { CStr::from_ptr(key) }
+                                .to_string_lossy()
+                                .into_owned(),
+                        );
+                    }
+                    (keys, more != 0)
+                })
+        };
+
+        unsafe {
+            if !iter.is_null() {
+                rados_omap_get_end(iter);
+            }
+            rados_release_read_op(op);
+        }
+
+        result
+    }
+}
+
+fn to_c_string(v: &str) -> Result<CString> {
+    CString::new(v).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "string contains nul byte").set_source(err)
+    })
+}
+
+/// Check the return value of librados, negative value is `-errno`.
+fn check(ret: i32, operation: &'static str) -> Result<()> {
+    if ret >= 0 {
+        return Ok(());
+    }
+
+    let (kind, retryable) = match -ret {
+        libc::ENOENT => (ErrorKind::NotFound, false),
+        libc::EACCES | libc::EPERM => (ErrorKind::PermissionDenied, false),
+        libc::EAGAIN | libc::ETIMEDOUT => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let mut err = Error::new(kind, "error from rados")
+        .with_operation(operation)
+        .set_source(io::Error::from_raw_os_error(-ret));
+    if retryable {
+        err = err.set_temporary();
+    }
+
+    Err(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check(0, "test").is_ok());
+        assert!(check(42, "test").is_ok());
+
+        let err = check(-libc::ENOENT, "test").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert!(!err.is_temporary());
+
+        let err = check(-libc::EACCES, "test").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+
+        let err = check(-libc::ETIMEDOUT, "test").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(err.is_temporary());
+
+        let err = check(-libc::EIO, "test").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(!err.is_temporary());
+    }
+
+    #[test]
+    fn test_to_c_string() {
+        assert_eq!(to_c_string("a/b").unwrap().as_bytes(), b"a/b");
+        assert!(to_c_string("a\0b").is_err());
+    }
+}
@@ -0,0 +1,118 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Minimal bindings of the librados C API used by this service.
+//!
+//! Please refer to [librados.h](https://github.com/ceph/ceph/blob/main/src/include/rados/librados.h)
+//! for the details of every function.
+
+#![allow(non_camel_case_types)]
+
+use std::ffi::c_char;
+use std::ffi::c_int;
+use std::ffi::c_uchar;
+use std::ffi::c_void;
+
+pub type rados_t = *mut c_void;
+pub type rados_ioctx_t = *mut c_void;
+pub type rados_write_op_t = *mut c_void;
+pub type rados_read_op_t = *mut c_void;
+pub type rados_omap_iter_t = *mut c_void;
+
+#[link(name = "rados")]
+extern "C" {
+    pub fn rados_create(cluster: *mut rados_t, id: *const c_char) -> c_int;
+    pub fn rados_conf_read_file(cluster: rados_t, path: *const c_char) -> c_int;
+    pub fn rados_conf_set(cluster: rados_t, option: *const c_char, value: *const c_char) -> c_int;
+    pub fn rados_connect(cluster: rados_t) -> c_int;
+    pub fn rados_shutdown(cluster: rados_t);
+
+    pub fn rados_ioctx_create(
+        cluster: rados_t,
+        pool_name: *const c_char,
+        ioctx: *mut rados_ioctx_t,
+    ) -> c_int;
+    pub fn rados_ioctx_destroy(io: rados_ioctx_t);
+    pub fn rados_ioctx_set_namespace(io: rados_ioctx_t, nspace: *const c_char);
+
+    pub fn rados_stat(
+        io: rados_ioctx_t,
+        oid: *const c_char,
+        psize: *mut u64,
+        pmtime: *mut libc::time_t,
+    ) -> c_int;
+    pub fn rados_read(
+        io: rados_ioctx_t,
+        oid: *const c_char,
+        buf: *mut c_char,
+        len: libc::size_t,
+        off: u64,
+    ) -> c_int;
+    pub fn rados_write_full(
+        io: rados_ioctx_t,
+        oid: *const c_char,
+        buf: *const c_char,
+        len: libc::size_t,
+    ) -> c_int;
+    pub fn rados_remove(io: rados_ioctx_t, oid: *const c_char) -> c_int;
+
+    pub fn rados_create_write_op() -> rados_write_op_t;
+    pub fn rados_release_write_op(write_op: rados_write_op_t);
+    pub fn rados_write_op_omap_set(
+        write_op: rados_write_op_t,
+        keys: *const *const c_char,
+        vals: *const *const c_char,
+        lens: *const libc::size_t,
+        num: libc::size_t,
+    );
+    pub fn rados_write_op_omap_rm_keys(
+        write_op: rados_write_op_t,
+        keys: *const *const c_char,
+        keys_len: libc::size_t,
+    );
+    pub fn rados_write_op_operate(
+        write_op: rados_write_op_t,
+        io: rados_ioctx_t,
+        oid: *const c_char,
+        mtime: *mut libc::time_t,
+        flags: c_int,
+    ) -> c_int;
+
+    pub fn rados_create_read_op() -> rados_read_op_t;
+    pub fn rados_release_read_op(read_op: rados_read_op_t);
+    pub fn rados_read_op_omap_get_vals2(
+        read_op: rados_read_op_t,
+        start_after: *const c_char,
+        filter_prefix: *const c_char,
+        max_return: u64,
+        iter: *mut rados_omap_iter_t,
+        pmore: *mut c_uchar,
+        prval: *mut c_int,
+    );
+    pub fn rados_read_op_operate(
+        read_op: rados_read_op_t,
+        io: rados_ioctx_t,
+        oid: *const c_char,
+        flags: c_int,
+    ) -> c_int;
+    pub fn rados_omap_get_next(
+        iter: rados_omap_iter_t,
+        key: *mut *mut c_char,
+        val: *mut *mut c_char,
+        len: *mut libc::size_t,
+    ) -> c_int;
+    pub fn rados_omap_get_end(iter: rados_omap_iter_t);
+}
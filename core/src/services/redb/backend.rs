@@ -132,6 +132,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 blocking: true,
                 ..Default::default()
             },
@@ -223,6 +224,48 @@ impl kv::Adapter for Adapter {
         write_txn.commit().map_err(parse_commit_error)?;
         Ok(())
     }
+
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        let cloned_self = self.clone();
+        let cloned_path = path.to_string();
+
+        task::spawn_blocking(move || cloned_self.blocking_scan(cloned_path.as_str()))
+            .await
+            .map_err(new_task_join_error)
+            .and_then(|inner_result| inner_result)
+    }
+
+    fn blocking_scan(&self, path: &str) -> Result<Vec<String>> {
+        let read_txn = self.db.begin_read().map_err(parse_transaction_error)?;
+
+        let table_define: redb::TableDefinition<&str, &[u8]> =
+            redb::TableDefinition::new(&self.table);
+
+        let table = match read_txn.open_table(table_define) {
+            Ok(table) => table,
+            // Nothing has been written yet.
+            Err(redb::TableError::TableDoesNotExist(_)) => return Ok(vec![]),
+            Err(e) => return Err(parse_table_error(e)),
+        };
+
+        // Keys are sorted, so all keys with the given prefix are
+        // located in one continuous range starting from `path`.
+        let mut res = Vec::default();
+        for entry in table.range(path..).map_err(parse_storage_error)? {
+            let (key, _) = entry.map_err(parse_storage_error)?;
+            let key = key.value();
+            if !key.starts_with(path) {
+                break;
+            }
+            if key == path {
+                continue;
+            }
+
+            res.push(key.to_string());
+        }
+
+        Ok(res)
+    }
 }
 
 fn parse_transaction_error(e: redb::TransactionError) -> Error {
@@ -249,3 +292,47 @@ fn parse_database_error(e: redb::DatabaseError) -> Error {
 fn parse_commit_error(e: redb::CommitError) -> Error {
     Error::new(ErrorKind::Unexpected, "error from redb").set_source(e)
 }
+
+#[cfg(test)]
+mod tests {
+    use redb::backends::InMemoryBackend;
+
+    use super::*;
+    use crate::raw::adapters::kv::Adapter as _;
+
+    fn new_adapter() -> Adapter {
+        let db = redb::Database::builder()
+            .create_with_backend(InMemoryBackend::new())
+            .unwrap();
+
+        Adapter {
+            datadir: "memory".to_string(),
+            table: "test".to_string(),
+            db: Arc::new(db),
+        }
+    }
+
+    #[test]
+    fn test_blocking_scan() {
+        let adapter = new_adapter();
+        // Table doesn't exist before the first write.
+        assert!(adapter.blocking_scan("").unwrap().is_empty());
+
+        // `a.` sorts before `a/` and `a0` sorts after all keys starting with `a/`.
+        for key in ["a", "a.", "a/", "a/b", "a/c/d", "a0", "b"] {
+            adapter.blocking_set(key, Buffer::new()).unwrap();
+        }
+
+        assert_eq!(adapter.blocking_scan("a/").unwrap(), vec!["a/b", "a/c/d"]);
+        assert_eq!(adapter.blocking_scan("a/c/").unwrap(), vec!["a/c/d"]);
+        assert_eq!(
+            adapter.blocking_scan("a").unwrap(),
+            vec!["a.", "a/", "a/b", "a/c/d", "a0"]
+        );
+        assert_eq!(
+            adapter.blocking_scan("").unwrap(),
+            vec!["a", "a.", "a/", "a/b", "a/c/d", "a0", "b"]
+        );
+        assert!(adapter.blocking_scan("c").unwrap().is_empty());
+    }
+}
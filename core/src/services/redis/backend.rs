@@ -75,35 +75,16 @@ pub struct RedisConfig {
     pub default_ttl: Option<Duration>,
 }
 
-impl Debug for RedisConfig {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut d = f.debug_struct("RedisConfig");
-
-        d.field("db", &self.db.to_string());
-        d.field("root", &self.root);
-        if let Some(endpoint) = self.endpoint.clone() {
-            d.field("endpoint", &endpoint);
-        }
-        if let Some(cluster_endpoints) = self.cluster_endpoints.clone() {
-            d.field("cluster_endpoints", &cluster_endpoints);
-        }
-        if let Some(username) = self.username.clone() {
-            d.field("username", &username);
-        }
-        if self.password.is_some() {
-            d.field("password", &"<redacted>");
-        }
-
-        d.finish_non_exhaustive()
-    }
-}
-
-impl Configurator for RedisConfig {
-    type Builder = RedisBuilder;
-    fn into_builder(self) -> Self::Builder {
-        RedisBuilder { config: self }
-    }
-}
+impl_config_debug!(
+    RedisConfig {
+        db,
+        root,
+        endpoint,
+        cluster_endpoints,
+        username,
+    },
+    redacted { password },
+);
 
 /// [Redis](https://redis.io/) services support.
 #[doc = include_str!("docs.md")]
@@ -112,16 +93,9 @@ pub struct RedisBuilder {
     config: RedisConfig,
 }
 
-impl Debug for RedisBuilder {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut d = f.debug_struct("RedisBuilder");
+impl_configurator!(RedisConfig => RedisBuilder);
 
-        d.field("config", &self.config);
-        d.finish_non_exhaustive()
-    }
-}
-
-impl RedisBuilder {
+builder_setters!(RedisBuilder {
     /// set the network address of redis service.
     ///
     /// currently supported schemes:
@@ -129,13 +103,7 @@ impl RedisBuilder {
     /// - "tcp" or "redis": unsecured redis connections
     /// - "rediss": secured redis connections
     /// - "unix" or "redis+unix": unix socket connection
-    pub fn endpoint(mut self, endpoint: &str) -> Self {
-        if !endpoint.is_empty() {
-            self.config.endpoint = Some(endpoint.to_owned());
-        }
-        self
-    }
-
+    endpoint: &str,
     /// set the network address of redis cluster service.
     /// This parameter is mutually exclusive with the endpoint parameter.
     ///
@@ -144,59 +112,28 @@ impl RedisBuilder {
     /// - "tcp" or "redis": unsecured redis connections
     /// - "rediss": secured redis connections
     /// - "unix" or "redis+unix": unix socket connection
-    pub fn cluster_endpoints(mut self, cluster_endpoints: &str) -> Self {
-        if !cluster_endpoints.is_empty() {
-            self.config.cluster_endpoints = Some(cluster_endpoints.to_owned());
-        }
-        self
-    }
-
+    cluster_endpoints: &str,
     /// set the username for redis
     ///
     /// default: no username
-    pub fn username(mut self, username: &str) -> Self {
-        if !username.is_empty() {
-            self.config.username = Some(username.to_owned());
-        }
-        self
-    }
-
+    username: &str,
     /// set the password for redis
     ///
     /// default: no password
-    pub fn password(mut self, password: &str) -> Self {
-        if !password.is_empty() {
-            self.config.password = Some(password.to_owned());
-        }
-        self
-    }
-
+    password: &str,
     /// set the db used in redis
     ///
     /// default: 0
-    pub fn db(mut self, db: i64) -> Self {
-        self.config.db = db;
-        self
-    }
-
+    db: i64,
     /// Set the default ttl for redis services.
     ///
     /// If set, we will specify `EX` for write operations.
-    pub fn default_ttl(mut self, ttl: Duration) -> Self {
-        self.config.default_ttl = Some(ttl);
-        self
-    }
-
+    default_ttl: Duration,
     /// set the working directory, all operations will be performed under it.
     ///
     /// default: "/"
-    pub fn root(mut self, root: &str) -> Self {
-        if !root.is_empty() {
-            self.config.root = Some(root.to_owned());
-        }
-        self
-    }
-}
+    root: &str,
+});
 
 impl Builder for RedisBuilder {
     const SCHEME: Scheme = Scheme::Redis;
@@ -383,6 +320,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                write_with_ttl: true,
 
                 ..Default::default()
             },
@@ -399,27 +337,37 @@ impl kv::Adapter for Adapter {
     }
 
     async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+        if let Some(ttl) = self.default_ttl {
+            return self.set_with_ttl(key, value, ttl).await;
+        }
+
         let conn = self.conn().await?;
         let value = value.to_vec();
-        match self.default_ttl {
-            Some(ttl) => match conn {
-                RedisConnection::Normal(mut conn) => conn
-                    .set_ex(key, value, ttl.as_secs())
-                    .await
-                    .map_err(format_redis_error)?,
-                RedisConnection::Cluster(mut conn) => conn
-                    .set_ex(key, value, ttl.as_secs())
-                    .await
-                    .map_err(format_redis_error)?,
-            },
-            None => match conn {
-                RedisConnection::Normal(mut conn) => {
-                    conn.set(key, value).await.map_err(format_redis_error)?
-                }
-                RedisConnection::Cluster(mut conn) => {
-                    conn.set(key, value).await.map_err(format_redis_error)?
-                }
-            },
+        match conn {
+            RedisConnection::Normal(mut conn) => {
+                conn.set(key, value).await.map_err(format_redis_error)?
+            }
+            RedisConnection::Cluster(mut conn) => {
+                conn.set(key, value).await.map_err(format_redis_error)?
+            }
+        }
+        Ok(())
+    }
+
+    async fn set_with_ttl(&self, key: &str, value: Buffer, ttl: Duration) -> Result<()> {
+        let conn = self.conn().await?;
+        let value = value.to_vec();
+        // Use `SET key value PX ttl` so that sub-second ttl will be kept.
+        let ttl = ttl.as_millis() as u64;
+        match conn {
+            RedisConnection::Normal(mut conn) => conn
+                .pset_ex(key, value, ttl)
+                .await
+                .map_err(format_redis_error)?,
+            RedisConnection::Cluster(mut conn) => conn
+                .pset_ex(key, value, ttl)
+                .await
+                .map_err(format_redis_error)?,
         }
         Ok(())
     }
@@ -26,6 +26,7 @@ use std::sync::Arc;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use bytes::Buf;
+use chrono::SecondsFormat;
 use http::Response;
 use http::StatusCode;
 use log::debug;
@@ -94,6 +95,15 @@ pub struct S3Config {
     ///
     /// default to `https://s3.amazonaws.com` if not set.
     pub endpoint: Option<String>,
+    /// Replica endpoints serving the same bucket as `endpoint`, for example the
+    /// other gateways of a MinIO cluster.
+    ///
+    /// Requests will fail over to replicas while `endpoint` is unhealthy. Please
+    /// enable `RetryLayer` so that the failed request is retried on a replica.
+    pub replica_endpoints: Option<Vec<String>>,
+    /// Send read requests to replica endpoints first and use `endpoint` as
+    /// the fallback.
+    pub read_from_replica: bool,
     /// Region represent the signing region of this endpoint. This is required
     /// if you are using the default AWS S3 endpoint.
     ///
@@ -212,6 +222,13 @@ pub struct S3Config {
     /// Available options:
     /// - "crc32c"
     pub checksum_algorithm: Option<String>,
+    /// Enable S3 Express One Zone support for directory buckets.
+    ///
+    /// - Requests will be signed with the session credentials created by `CreateSession`.
+    /// - Virtual host style will always be used.
+    /// - If endpoint is not set, it will be built from the availability zone id in bucket
+    ///   name like `bucket-base-name--usw2-az1--x-s3`.
+    pub enable_s3_express: bool,
 }
 
 impl Debug for S3Config {
@@ -229,6 +246,11 @@ impl Debug for S3Config {
 
 impl Configurator for S3Config {
     type Builder = S3Builder;
+
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(Some("bucket"))?)
+    }
+
     fn into_builder(self) -> Self::Builder {
         S3Builder {
             config: self,
@@ -301,6 +323,32 @@ impl S3Builder {
         self
     }
 
+    /// Set replica endpoints serving the same bucket as `endpoint`.
+    ///
+    /// Replicas are checked passively: an endpoint that fails to connect or returns
+    /// `502`/`504` will be skipped for a while, and requests will fail over to the
+    /// next healthy one. Please enable `RetryLayer` so that the failed request is
+    /// retried on a replica.
+    pub fn replica_endpoints(mut self, endpoints: Vec<String>) -> Self {
+        let endpoints: Vec<String> = endpoints
+            .into_iter()
+            .filter(|v| !v.is_empty())
+            .map(|v| v.trim_end_matches('/').to_string())
+            .collect();
+        if !endpoints.is_empty() {
+            self.config.replica_endpoints = Some(endpoints)
+        }
+
+        self
+    }
+
+    /// Send read requests to replica endpoints first and use `endpoint` as the
+    /// fallback, which spreads the read load across all gateways.
+    pub fn read_from_replica(mut self) -> Self {
+        self.config.read_from_replica = true;
+        self
+    }
+
     /// Region represent the signing region of this endpoint. This is required
     /// if you are using the default AWS S3 endpoint.
     ///
@@ -631,21 +679,31 @@ impl S3Builder {
         // If enable virtual host style, `bucket` will reside in domain part,
         // for example `https://bucket_name.s3.us-east-1.amazonaws.com`,
         // so `bucket` with dot can't be recognized correctly for this format.
-        if self.config.enable_virtual_host_style && self.config.bucket.contains('.') {
+        if self.is_virtual_host_style() && self.config.bucket.contains('.') {
             return false;
         }
         true
     }
 
+    /// S3 Express One Zone only supports virtual host style.
+    fn is_virtual_host_style(&self) -> bool {
+        self.config.enable_virtual_host_style || self.config.enable_s3_express
+    }
+
     /// Build endpoint with given region.
     fn build_endpoint(&self, region: &str) -> String {
+        self.format_endpoint(self.config.endpoint.as_deref(), region)
+    }
+
+    /// Format the user input endpoint into the one used to build requests.
+    fn format_endpoint(&self, endpoint: Option<&str>, region: &str) -> String {
         let bucket = {
             debug_assert!(self.is_bucket_valid(), "bucket must be valid");
 
             self.config.bucket.as_str()
         };
 
-        let mut endpoint = match &self.config.endpoint {
+        let mut endpoint = match endpoint {
             Some(endpoint) => {
                 if endpoint.starts_with("http") {
                     endpoint.to_string()
@@ -654,7 +712,12 @@ impl S3Builder {
                     format!("https://{endpoint}")
                 }
             }
-            None => "https://s3.amazonaws.com".to_string(),
+            None => match parse_s3_express_az_id(bucket) {
+                Some(az_id) if self.config.enable_s3_express => {
+                    format!("https://s3express-{az_id}.{region}.amazonaws.com")
+                }
+                _ => "https://s3.amazonaws.com".to_string(),
+            },
         };
 
         // If endpoint contains bucket name, we should trim them.
@@ -676,7 +739,7 @@ impl S3Builder {
         };
 
         // Apply virtual host style.
-        if self.config.enable_virtual_host_style {
+        if self.is_virtual_host_style() {
             endpoint = endpoint.replace("//", &format!("//{bucket}."))
         } else {
             write!(endpoint, "/{bucket}").expect("write into string must succeed");
@@ -703,6 +766,19 @@ impl S3Builder {
         self
     }
 
+    /// Enable S3 Express One Zone support for directory buckets.
+    ///
+    /// OpenDAL will create sessions via `CreateSession` API and sign requests with the
+    /// session credentials, the sessions will be cached and refreshed before expiration.
+    ///
+    /// Directory buckets don't return objects in lexicographical order and don't support
+    /// `start_after` in list.
+    pub fn enable_s3_express(mut self) -> Self {
+        self.config.enable_s3_express = true;
+
+        self
+    }
+
     /// Detect region of S3 bucket.
     ///
     /// # Args
@@ -840,6 +916,20 @@ impl Builder for S3Builder {
         }?;
         debug!("backend use bucket {}", &bucket);
 
+        // Directory buckets must be named like `bucket-base-name--azid--x-s3`.
+        if self.config.enable_s3_express
+            && self.config.endpoint.is_none()
+            && parse_s3_express_az_id(bucket).is_none()
+        {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "The bucket is not a valid directory bucket name for s3 express",
+            )
+            .with_operation("Builder::build")
+            .with_context("service", Scheme::S3)
+            .with_context("bucket", bucket));
+        }
+
         let default_storage_class = match &self.config.default_storage_class {
             None => None,
             Some(v) => Some(
@@ -926,6 +1016,16 @@ impl Builder for S3Builder {
         // Building endpoint.
         let endpoint = self.build_endpoint(&region);
         debug!("backend use endpoint: {endpoint}");
+        let replica_endpoints: Vec<String> = self
+            .config
+            .replica_endpoints
+            .iter()
+            .flatten()
+            .map(|v| self.format_endpoint(Some(v), &region))
+            .collect();
+        if !replica_endpoints.is_empty() {
+            debug!("backend use replica endpoints: {replica_endpoints:?}");
+        }
 
         // Setting all value from user input if available.
         if let Some(v) = self.config.access_key_id {
@@ -947,16 +1047,20 @@ impl Builder for S3Builder {
             })?
         };
 
-        let mut loader: Option<Box<dyn AwsCredentialLoad>> = None;
+        let mut provider = S3CredentialProvider::new(
+            client.client()?,
+            cfg.clone(),
+            self.config.disable_ec2_metadata,
+        );
         // If customized_credential_load is set, we will use it.
         if let Some(v) = self.customized_credential_load {
-            loader = Some(v);
+            provider = provider.with_customized_credential_load(v);
         }
 
         // If role_arn is set, we must use AssumeRoleLoad.
         if let Some(role_arn) = self.config.role_arn {
             // use current env as source credential loader.
-            let default_loader = AwsDefaultLoader::new(client.client(), cfg.clone());
+            let default_loader = AwsDefaultLoader::new(client.client()?, cfg);
 
             // Build the config for assume role.
             let mut assume_role_cfg = AwsConfig {
@@ -973,7 +1077,7 @@ impl Builder for S3Builder {
             }
 
             let assume_role_loader = AwsAssumeRoleLoader::new(
-                client.client(),
+                client.client()?,
                 assume_role_cfg,
                 Box::new(default_loader),
             )
@@ -985,23 +1089,22 @@ impl Builder for S3Builder {
                 .with_context("service", Scheme::S3)
                 .set_source(err)
             })?;
-            loader = Some(Box::new(assume_role_loader));
+            provider = provider.with_customized_credential_load(Box::new(assume_role_loader));
         }
-        // If loader is not set, we will use default loader.
-        let loader = match loader {
-            Some(v) => v,
-            None => {
-                let mut default_loader = AwsDefaultLoader::new(client.client(), cfg);
-                if self.config.disable_ec2_metadata {
-                    default_loader = default_loader.with_disable_ec2_metadata();
-                }
+        let loader = CredentialLoader::new(provider);
 
-                Box::new(default_loader)
-            }
+        // S3 Express One Zone requires requests to be signed as `s3express` service.
+        let (signer, express_session) = if self.config.enable_s3_express {
+            let provider =
+                S3ExpressSessionProvider::new(&endpoint, &region, client.clone(), loader.clone());
+            (
+                AwsV4Signer::new("s3express", &region),
+                Some(CredentialLoader::new(provider)),
+            )
+        } else {
+            (AwsV4Signer::new("s3", &region), None)
         };
 
-        let signer = AwsV4Signer::new("s3", &region);
-
         let batch_max_operations = self
             .config
             .batch_max_operations
@@ -1010,6 +1113,12 @@ impl Builder for S3Builder {
         Ok(S3Backend {
             core: Arc::new(S3Core {
                 bucket: bucket.to_string(),
+                region: region.clone(),
+                endpoints: S3Endpoints::new(
+                    endpoint.clone(),
+                    replica_endpoints,
+                    self.config.read_from_replica,
+                ),
                 endpoint,
                 root,
                 server_side_encryption,
@@ -1026,11 +1135,20 @@ impl Builder for S3Builder {
                 client,
                 batch_max_operations,
                 checksum_algorithm,
+                express_session,
             }),
         })
     }
 }
 
+/// Parse the availability zone id from directory bucket name.
+///
+/// For example, `usw2-az1` will be returned for `bucket-base-name--usw2-az1--x-s3`.
+fn parse_s3_express_az_id(bucket: &str) -> Option<&str> {
+    let (_, az_id) = bucket.strip_suffix("--x-s3")?.rsplit_once("--")?;
+    (!az_id.is_empty()).then_some(az_id)
+}
+
 /// Backend for s3 services.
 #[derive(Debug, Clone)]
 pub struct S3Backend {
@@ -1049,6 +1167,7 @@ impl Access for S3Backend {
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::S3)
             .set_root(&self.core.root)
+            .set_http_client(self.core.client.clone())
             .set_name(&self.core.bucket)
             .set_native_capability(Capability {
                 stat: true,
@@ -1071,6 +1190,10 @@ impl Access for S3Backend {
                 write_can_multi: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
+                write_with_if_match: true,
+                write_with_if_none_match: true,
+                write_with_ttl: true,
+                write_with_acl: true,
                 // The min multipart size of S3 is 5 MiB.
                 //
                 // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
@@ -1083,21 +1206,48 @@ impl Access for S3Backend {
                 } else {
                     Some(usize::MAX)
                 },
+                // The max multipart count of S3 is 10000.
+                //
+                // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
+                write_multi_max_parts: Some(10000),
+                // The max object size of S3 is 5 TiB.
+                //
+                // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
+                write_total_max_size: if cfg!(target_pointer_width = "64") {
+                    Some(5 * 1024 * 1024 * 1024 * 1024)
+                } else {
+                    Some(usize::MAX)
+                },
 
                 delete: true,
                 copy: true,
 
                 list: true,
                 list_with_limit: true,
-                list_with_start_after: true,
+                // Directory buckets don't support `start-after` in list.
+                list_with_start_after: self.core.express_session.is_none(),
                 list_with_recursive: true,
+                list_has_content_length: true,
+                list_has_etag: true,
+                list_has_last_modified: true,
 
-                presign: true,
-                presign_stat: true,
-                presign_read: true,
-                presign_write: true,
+                // Session credentials of S3 Express One Zone are too short-lived to presign.
+                presign: self.core.express_session.is_none(),
+                presign_stat: self.core.express_session.is_none(),
+                presign_read: self.core.express_session.is_none(),
+                presign_write: self.core.express_session.is_none(),
 
                 batch: true,
+                list_multipart_uploads: true,
+                abort_multipart_upload: true,
+
+                lifecycle: true,
+                retention: true,
+                acl: true,
+
+                create_bucket: true,
+                delete_bucket: true,
+                list_buckets: true,
                 batch_max_operations: Some(self.core.batch_max_operations),
 
                 ..Default::default()
@@ -1132,7 +1282,8 @@ impl Access for S3Backend {
         let status = resp.status();
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -1268,6 +1419,295 @@ impl Access for S3Backend {
             Err(parse_error(resp))
         }
     }
+
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        _: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let mut uploads = vec![];
+        let (mut key_marker, mut upload_id_marker) = (String::new(), String::new());
+
+        loop {
+            let resp = self
+                .core
+                .s3_list_multipart_uploads(path, &key_marker, &upload_id_marker)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let bs = resp.into_body();
+            let output: ListMultipartUploadsOutput =
+                quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+            for upload in output.upload {
+                let mut mu = MultipartUpload::new(
+                    &build_rel_path(&self.core.root, &upload.key),
+                    &upload.upload_id,
+                );
+                if let Some(initiated) = upload.initiated {
+                    mu = mu.with_initiated(parse_datetime_from_rfc3339(&initiated)?);
+                }
+                uploads.push(mu);
+            }
+
+            let next_key_marker = output.next_key_marker.unwrap_or_default();
+            let next_upload_id_marker = output.next_upload_id_marker.unwrap_or_default();
+            // Follow the same rules as `S3Lister`: trust `is_truncated` if
+            // returned, otherwise check the next markers. Stop if the markers
+            // don't move forward to avoid looping forever on broken services.
+            let done = output.is_truncated == Some(false)
+                || next_key_marker.is_empty()
+                || (next_key_marker == key_marker && next_upload_id_marker == upload_id_marker);
+            if done {
+                break;
+            }
+            key_marker = next_key_marker;
+            upload_id_marker = next_upload_id_marker;
+        }
+
+        Ok(RpListMultipartUploads::new(uploads))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let resp = self
+            .core
+            .s3_abort_multipart_upload(path, args.upload_id())
+            .await?;
+        match resp.status() {
+            // s3 returns code 204 if abort succeeds.
+            StatusCode::NO_CONTENT => Ok(RpAbortMultipartUpload::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn get_lifecycle(&self, _: OpGetLifecycle) -> Result<RpGetLifecycle> {
+        let resp = self.core.s3_get_bucket_lifecycle().await?;
+        let config: LifecycleConfiguration = match resp.status() {
+            StatusCode::OK => quick_xml::de::from_reader(resp.into_body().reader())
+                .map_err(new_xml_deserialize_error)?,
+            _ => {
+                let err = parse_error(resp);
+                // S3 returns `NoSuchLifecycleConfiguration` if no rules are set.
+                if err.kind() == ErrorKind::NotFound {
+                    return Ok(RpGetLifecycle::default());
+                }
+                return Err(err);
+            }
+        };
+
+        let rules = config
+            .rule
+            .into_iter()
+            .filter_map(|rule| from_lifecycle_configuration_rule(&self.core.root, rule))
+            .collect();
+        Ok(RpGetLifecycle::new(rules))
+    }
+
+    async fn set_lifecycle(&self, args: OpSetLifecycle) -> Result<RpSetLifecycle> {
+        // S3 doesn't accept an empty configuration, delete it instead.
+        if args.rules().is_empty() {
+            let resp = self.core.s3_delete_bucket_lifecycle().await?;
+            return match resp.status() {
+                StatusCode::NO_CONTENT | StatusCode::OK => Ok(RpSetLifecycle::default()),
+                _ => Err(parse_error(resp)),
+            };
+        }
+
+        let config = LifecycleConfiguration {
+            rule: args
+                .rules()
+                .iter()
+                .map(|rule| into_lifecycle_configuration_rule(&self.core.root, rule))
+                .collect(),
+        };
+        let resp = self.core.s3_put_bucket_lifecycle(&config).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetLifecycle::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn get_retention(&self, path: &str, _: OpGetRetention) -> Result<RpGetRetention> {
+        let resp = self.core.s3_head_object(path, OpStat::new()).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpGetRetention::new(parse_object_retention(resp.headers())?)),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        if let Some(retention) = args.retention() {
+            let mode = match retention.mode() {
+                RetentionMode::Governance => "GOVERNANCE",
+                RetentionMode::Compliance => "COMPLIANCE",
+            };
+            let retention = ObjectLockRetention {
+                mode: mode.to_string(),
+                retain_until_date: retention
+                    .retain_until()
+                    .to_rfc3339_opts(SecondsFormat::Secs, true),
+            };
+            let resp = self.core.s3_put_object_retention(path, &retention).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+        }
+
+        if let Some(legal_hold) = args.legal_hold() {
+            let legal_hold = ObjectLockLegalHold {
+                status: if legal_hold { "ON" } else { "OFF" }.to_string(),
+            };
+            let resp = self
+                .core
+                .s3_put_object_legal_hold(path, &legal_hold)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+        }
+
+        Ok(RpSetRetention::default())
+    }
+
+    async fn get_acl(&self, path: &str, _: OpGetAcl) -> Result<RpGetAcl> {
+        let resp = self.core.s3_get_object_acl(path).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+
+        let policy: AccessControlPolicy = quick_xml::de::from_reader(resp.into_body().reader())
+            .map_err(new_xml_deserialize_error)?;
+        Ok(RpGetAcl::new(parse_access_control_policy(&policy)))
+    }
+
+    async fn set_acl(&self, path: &str, args: OpSetAcl) -> Result<RpSetAcl> {
+        let resp = self.core.s3_put_object_acl(path, args.acl()).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetAcl::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn create_bucket(&self, _: OpCreateBucket) -> Result<RpCreateBucket> {
+        let resp = self.core.s3_create_bucket().await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpCreateBucket::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn delete_bucket(&self, _: OpDeleteBucket) -> Result<RpDeleteBucket> {
+        let resp = self.core.s3_delete_bucket().await?;
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(RpDeleteBucket::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn list_buckets(&self, _: OpListBuckets) -> Result<RpListBuckets> {
+        let mut buckets = vec![];
+        let mut token = String::new();
+
+        loop {
+            let resp = self.core.s3_list_buckets(&token).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListAllMyBucketsResult =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+            buckets.extend(output.buckets.bucket.into_iter().map(|v| v.name));
+
+            match output.continuation_token {
+                Some(v) if !v.is_empty() && v != token => token = v,
+                _ => break,
+            }
+        }
+
+        Ok(RpListBuckets::new(buckets))
+    }
+}
+
+/// Convert lifecycle rule into s3's rule, the prefix will be joined with root.
+fn into_lifecycle_configuration_rule(
+    root: &str,
+    rule: &LifecycleRule,
+) -> LifecycleConfigurationRule {
+    let prefix = Some(build_abs_path(root, rule.prefix())).filter(|v| !v.is_empty());
+    let tag = rule.tag().map(|(key, value)| LifecycleRuleTag {
+        key: key.to_string(),
+        value: value.to_string(),
+    });
+    let filter = match (prefix, tag) {
+        (Some(prefix), Some(tag)) => LifecycleRuleFilter {
+            and: Some(LifecycleRuleAnd {
+                prefix: Some(prefix),
+                tag: vec![tag],
+            }),
+            ..Default::default()
+        },
+        (prefix, tag) => LifecycleRuleFilter {
+            prefix: tag.is_none().then(|| prefix.unwrap_or_default()),
+            tag,
+            ..Default::default()
+        },
+    };
+
+    LifecycleConfigurationRule {
+        id: rule.id().to_string(),
+        prefix: None,
+        filter: Some(filter),
+        status: if rule.enabled() {
+            "Enabled"
+        } else {
+            "Disabled"
+        }
+        .to_string(),
+        expiration: rule
+            .expiration_days()
+            .map(|days| LifecycleRuleExpiration { days: Some(days) }),
+        abort_incomplete_multipart_upload: rule.abort_incomplete_upload_days().map(|days| {
+            LifecycleRuleAbortIncompleteMultipartUpload {
+                days_after_initiation: days,
+            }
+        }),
+    }
+}
+
+/// Convert s3's rule into lifecycle rule, returns `None` if the rule is
+/// outside of root.
+fn from_lifecycle_configuration_rule(
+    root: &str,
+    rule: LifecycleConfigurationRule,
+) -> Option<LifecycleRule> {
+    let filter = rule.filter.unwrap_or_default();
+    let (prefix, tag) = match filter.and {
+        Some(and) => (and.prefix, and.tag.into_iter().next()),
+        None => (filter.prefix.or(rule.prefix), filter.tag),
+    };
+
+    let abs_root = build_abs_path(root, "");
+    let prefix = prefix.unwrap_or_default();
+    let prefix = prefix.strip_prefix(&abs_root)?;
+
+    let mut lr = LifecycleRule::new(&rule.id, prefix).with_enabled(rule.status == "Enabled");
+    if let Some(tag) = tag {
+        lr = lr.with_tag(&tag.key, &tag.value);
+    }
+    if let Some(days) = rule.expiration.and_then(|v| v.days) {
+        lr = lr.with_expiration_days(days);
+    }
+    if let Some(v) = rule.abort_incomplete_multipart_upload {
+        lr = lr.with_abort_incomplete_upload_days(v.days_after_initiation);
+    }
+    Some(lr)
 }
 
 #[cfg(test)]
@@ -1329,6 +1769,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_s3_express_endpoint() {
+        assert_eq!(
+            parse_s3_express_az_id("bucket-base-name--usw2-az1--x-s3"),
+            Some("usw2-az1")
+        );
+        assert_eq!(parse_s3_express_az_id("bucket-base-name"), None);
+        assert_eq!(parse_s3_express_az_id("--x-s3"), None);
+
+        let b = S3Builder::default()
+            .bucket("bucket-base-name--usw2-az1--x-s3")
+            .enable_s3_express();
+        assert_eq!(
+            b.build_endpoint("us-west-2"),
+            "https://bucket-base-name--usw2-az1--x-s3.s3express-usw2-az1.us-west-2.amazonaws.com"
+        );
+
+        let b = S3Builder::default()
+            .bucket("bucket-base-name")
+            .region("us-west-2")
+            .enable_s3_express();
+        assert!(b.build().is_err());
+    }
+
     #[tokio::test]
     async fn test_detect_region() {
         let cases = vec![
@@ -1375,4 +1839,54 @@ mod tests {
             assert_eq!(region.as_deref(), expected, "{}", name);
         }
     }
+
+    #[test]
+    fn test_config_from_uri() {
+        let uri = OperatorUri::new("s3://bucket/path/to/dir?region=us-east-1", []).unwrap();
+        let cfg = S3Config::from_uri(&uri).unwrap();
+
+        assert_eq!(cfg.bucket, "bucket");
+        assert_eq!(cfg.root.as_deref(), Some("/path/to/dir"));
+        assert_eq!(cfg.region.as_deref(), Some("us-east-1"));
+    }
+
+    #[test]
+    fn test_lifecycle_configuration_rule() {
+        let rule = LifecycleRule::new("expire-tmp", "tmp/")
+            .with_expiration_days(7)
+            .with_abort_incomplete_upload_days(1);
+        let config = LifecycleConfiguration {
+            rule: vec![
+                into_lifecycle_configuration_rule("/data/", &rule),
+                into_lifecycle_configuration_rule(
+                    "/",
+                    &LifecycleRule::new("ttl", "")
+                        .with_enabled(false)
+                        .with_tag("opendal-ttl-days", "1"),
+                ),
+            ],
+        };
+
+        let content = quick_xml::se::to_string(&config).expect("must serialize");
+        assert_eq!(
+            content,
+            "<LifecycleConfiguration>\
+                <Rule><ID>expire-tmp</ID><Filter><Prefix>data/tmp/</Prefix></Filter>\
+                <Status>Enabled</Status><Expiration><Days>7</Days></Expiration>\
+                <AbortIncompleteMultipartUpload><DaysAfterInitiation>1</DaysAfterInitiation></AbortIncompleteMultipartUpload></Rule>\
+                <Rule><ID>ttl</ID><Filter><Tag><Key>opendal-ttl-days</Key><Value>1</Value></Tag></Filter>\
+                <Status>Disabled</Status></Rule>\
+            </LifecycleConfiguration>"
+        );
+
+        let config: LifecycleConfiguration =
+            quick_xml::de::from_str(&content).expect("must deserialize");
+        let rules: Vec<_> = config
+            .rule
+            .into_iter()
+            .filter_map(|v| from_lifecycle_configuration_rule("/data/", v))
+            .collect();
+        // The rule outside of root should be ignored.
+        assert_eq!(rules, vec![rule]);
+    }
 }
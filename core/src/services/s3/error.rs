@@ -53,12 +53,16 @@ pub fn parse_error(resp: Response<Buffer>) -> Error {
         .map(|s3_err| (format!("{s3_err:?}"), Some(s3_err)))
         .unwrap_or_else(|_| (String::from_utf8_lossy(body.chunk()).into_owned(), None));
 
-    if let Some(s3_err) = s3_err {
+    if let Some(s3_err) = &s3_err {
         (kind, retryable) = parse_s3_error_code(s3_err.code.as_str()).unwrap_or((kind, retryable));
     }
 
     let mut err = Error::new(kind, message);
 
+    if let Some(s3_err) = s3_err.filter(|v| !v.code.is_empty()) {
+        err = err.with_service_code(s3_err.code);
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
@@ -74,6 +78,10 @@ pub(crate) fn from_s3_error(s3_error: S3Error, parts: Parts) -> Error {
         parse_s3_error_code(s3_error.code.as_str()).unwrap_or((ErrorKind::Unexpected, false));
     let mut err = Error::new(kind, format!("{s3_error:?}"));
 
+    if !s3_error.code.is_empty() {
+        err = err.with_service_code(s3_error.code);
+    }
+
     err = with_error_response_context(err, parts);
 
     if retryable {
@@ -92,6 +100,12 @@ pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
         // Although the status code is 404, NoSuchBucket is
         // a config invalid error, and it's not retryable from OpenDAL.
         "NoSuchBucket" => Some((ErrorKind::ConfigInvalid, false)),
+        // > The requested bucket name is not available.
+        //
+        // Returned while creating a bucket that has been created.
+        "BucketAlreadyExists" | "BucketAlreadyOwnedByYou" => {
+            Some((ErrorKind::AlreadyExists, false))
+        }
         // > Your socket connection to the server was not read from
         // > or written to within the timeout period."
         //
@@ -112,10 +126,26 @@ pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
         // indicates a temporary issue with the service or server, such as high load,
         // maintenance, or an internal problem.
         "ServiceUnavailable" => Some((ErrorKind::Unexpected, true)),
+        // > The provided token has expired.
+        //
+        // The cached credential will be expired while seeing this error, it's
+        // Ok to retry with a new one.
+        "ExpiredToken" | "TokenRefreshRequired" => Some((ErrorKind::PermissionDenied, true)),
         _ => None,
     }
 }
 
+/// Check if the response reports that the credential has been expired.
+pub(crate) fn is_credential_expired(resp: &Response<Buffer>) -> bool {
+    if !matches!(resp.status().as_u16(), 400 | 403) {
+        return false;
+    }
+
+    de::from_reader::<_, S3Error>(resp.body().clone().reader())
+        .map(|err| matches!(err.code.as_str(), "ExpiredToken" | "TokenRefreshRequired"))
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +191,35 @@ mod tests {
         let out: S3Error = de::from_reader(bs.reader()).expect("must success");
         assert_eq!(out, S3Error::default());
     }
+
+    #[test]
+    fn test_is_credential_expired() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>ExpiredToken</Code>
+  <Message>The provided token has expired.</Message>
+  <RequestId>4442587FB7D0A2F9</RequestId>
+</Error>"#;
+
+        let resp = Response::builder()
+            .status(400)
+            .body(Buffer::from(body))
+            .unwrap();
+        assert!(is_credential_expired(&resp));
+
+        let resp = Response::builder()
+            .status(403)
+            .body(Buffer::from(body.replace("ExpiredToken", "AccessDenied")))
+            .unwrap();
+        assert!(!is_credential_expired(&resp));
+
+        let err = parse_error(
+            Response::builder()
+                .status(400)
+                .body(Buffer::from(body))
+                .unwrap(),
+        );
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(err.is_temporary());
+    }
 }
@@ -134,7 +134,7 @@ impl oio::MultipartWrite for S3Writer {
     }
 
     async fn complete_part(&self, upload_id: &str, parts: &[oio::MultipartPart]) -> Result<()> {
-        let parts = parts
+        let req_parts = parts
             .iter()
             .map(|p| match &self.core.checksum_algorithm {
                 None => CompleteMultipartUploadRequestPart {
@@ -154,7 +154,7 @@ impl oio::MultipartWrite for S3Writer {
 
         let resp = self
             .core
-            .s3_complete_multipart_upload(&self.path, upload_id, parts)
+            .s3_complete_multipart_upload(&self.path, upload_id, req_parts, &self.op)
             .await?;
 
         let status = resp.status();
@@ -163,14 +163,29 @@ impl oio::MultipartWrite for S3Writer {
             StatusCode::OK => {
                 // still check if there is any error because S3 might return error for status code 200
                 // https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html#API_CompleteMultipartUpload_Example_4
-                let (parts, body) = resp.into_parts();
-                let maybe_error: S3Error =
-                    quick_xml::de::from_reader(body.reader()).map_err(new_xml_deserialize_error)?;
+                let (resp_parts, body) = resp.into_parts();
+                let maybe_error: S3Error = quick_xml::de::from_reader(body.clone().reader())
+                    .map_err(new_xml_deserialize_error)?;
                 if !maybe_error.code.is_empty() {
-                    return Err(from_s3_error(maybe_error, parts));
+                    return Err(from_s3_error(maybe_error, resp_parts));
                 }
 
-                Ok(())
+                // Verify the composite checksum returned by S3 with the checksums of parts.
+                let Some(expected) = self.core.calculate_composite_checksum(parts) else {
+                    return Ok(());
+                };
+                let result: CompleteMultipartUploadResult =
+                    quick_xml::de::from_reader(body.reader()).map_err(new_xml_deserialize_error)?;
+                match result.checksum_crc32c {
+                    // Only composite checksum like `xxx-3` can be verified by parts.
+                    Some(actual) if actual.contains('-') && actual != expected => Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "uploaded checksum is mismatch",
+                    )
+                    .with_context("expected", expected)
+                    .with_context("actual", actual)),
+                    _ => Ok(()),
+                }
             }
             _ => Err(parse_error(resp)),
         }
@@ -292,6 +292,47 @@ impl kv::Adapter for Adapter {
         }
     }
 
+    async fn get_range(&self, path: &str, range: BytesRange) -> Result<Option<(Buffer, u64)>> {
+        let this = self.clone();
+        let path = path.to_string();
+
+        task::spawn_blocking(move || this.blocking_get_range(&path, range))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    /// Read part of the value inside sqlite so that large values won't be loaded
+    /// for range reads and stat.
+    fn blocking_get_range(&self, path: &str, range: BytesRange) -> Result<Option<(Buffer, u64)>> {
+        let conn = self.pool.get().map_err(parse_r2d2_error)?;
+
+        // `substr` counts from 1 and works on bytes for blob values.
+        let substr = match range.size() {
+            Some(_) => format!("substr({}, ?2, ?3)", self.value_field),
+            None => format!("substr({}, ?2)", self.value_field),
+        };
+        let query = format!(
+            "SELECT {}, length({}) FROM {} WHERE `{}` = ?1 LIMIT 1",
+            substr, self.value_field, self.table, self.key_field
+        );
+        let mut statement = conn.prepare(&query).map_err(parse_rusqlite_error)?;
+        let parse_row = |row: &rusqlite::Row<'_>| -> rusqlite::Result<(Option<Vec<u8>>, i64)> {
+            Ok((row.get(0)?, row.get(1)?))
+        };
+        let offset = range.offset() as i64 + 1;
+        let result = match range.size() {
+            Some(size) => statement.query_row(params![path, offset, size as i64], parse_row),
+            None => statement.query_row(params![path, offset], parse_row),
+        };
+
+        match result {
+            // `substr` returns NULL for empty values.
+            Ok((v, size)) => Ok(Some((Buffer::from(v.unwrap_or_default()), size as u64))),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(err) => Err(parse_rusqlite_error(err)),
+        }
+    }
+
     async fn set(&self, path: &str, value: Buffer) -> Result<()> {
         let this = self.clone();
         let path = path.to_string();
@@ -373,3 +414,66 @@ fn parse_rusqlite_error(err: rusqlite::Error) -> Error {
 fn parse_r2d2_error(err: r2d2::Error) -> Error {
     Error::new(ErrorKind::Unexpected, "unhandled error from r2d2").set_source(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::adapters::kv::Adapter as _;
+
+    fn new_adapter() -> Adapter {
+        // All connections of an in-memory database are isolated, keep only one.
+        let pool = r2d2::Pool::builder()
+            .max_size(1)
+            .build(SqliteConnectionManager {
+                connection_string: ":memory:".to_string(),
+            })
+            .unwrap();
+        pool.get()
+            .unwrap()
+            .execute("CREATE TABLE data (key TEXT PRIMARY KEY, value BLOB)", [])
+            .unwrap();
+
+        Adapter {
+            pool,
+            table: "data".to_string(),
+            key_field: "key".to_string(),
+            value_field: "value".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_blocking_get_range() {
+        let adapter = new_adapter();
+        adapter
+            .blocking_set("file", Buffer::from("0123456789"))
+            .unwrap();
+        adapter.blocking_set("empty", Buffer::new()).unwrap();
+
+        let cases = vec![
+            ("full", BytesRange::default(), "0123456789"),
+            ("offset", BytesRange::new(3, None), "3456789"),
+            ("offset and size", BytesRange::new(2, Some(3)), "234"),
+            ("size only", BytesRange::new(0, Some(4)), "0123"),
+            ("size exceeds end", BytesRange::new(8, Some(5)), "89"),
+            ("offset at end", BytesRange::new(10, None), ""),
+            ("offset beyond end", BytesRange::new(20, Some(5)), ""),
+        ];
+        for (name, range, expected) in cases {
+            let (bs, size) = adapter.blocking_get_range("file", range).unwrap().unwrap();
+            assert_eq!(bs.to_bytes(), expected.as_bytes(), "{name}");
+            assert_eq!(size, 10, "{name}");
+        }
+
+        let (bs, size) = adapter
+            .blocking_get_range("empty", BytesRange::new(0, Some(4)))
+            .unwrap()
+            .unwrap();
+        assert!(bs.is_empty());
+        assert_eq!(size, 0);
+
+        assert!(adapter
+            .blocking_get_range("not_exist", BytesRange::default())
+            .unwrap()
+            .is_none());
+    }
+}
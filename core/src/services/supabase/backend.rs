@@ -19,6 +19,8 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use bytes::Buf;
+use http::Request;
 use http::Response;
 use http::StatusCode;
 use log::debug;
@@ -183,6 +185,7 @@ impl Access for SupabaseBackend {
         am.set_scheme(Scheme::Supabase)
             .set_root(&self.core.root)
             .set_name(&self.core.bucket)
+            .set_http_client(self.core.http_client.clone())
             .set_native_capability(Capability {
                 stat: true,
 
@@ -191,6 +194,10 @@ impl Access for SupabaseBackend {
                 write: true,
                 delete: true,
 
+                // Creating signed urls requires the key.
+                presign: self.core.key.is_some(),
+                presign_read: self.core.key.is_some(),
+
                 ..Default::default()
             });
 
@@ -223,7 +230,10 @@ impl Access for SupabaseBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok((RpRead::new(), resp.into_body())),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
+            }
             _ => {
                 let (part, mut body) = resp.into_parts();
                 let buf = body.to_buffer().await?;
@@ -254,4 +264,40 @@ impl Access for SupabaseBackend {
             }
         }
     }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        match args.operation() {
+            PresignOperation::Read(_) => {}
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "supabase only supports presign read",
+                ))
+            }
+        }
+
+        let resp = self.core.supabase_sign_object(path, args.expire()).await?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let resp: SignedUrlResponse = serde_json::from_reader(resp.into_body().reader())
+            .map_err(new_json_deserialize_error)?;
+
+        let url = format!("{}/storage/v1{}", self.core.endpoint, resp.signed_url);
+
+        let req = Request::get(url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        // We don't need this request anymore, consume it directly.
+        let (parts, _) = req.into_parts();
+
+        Ok(RpPresign::new(PresignedRequest::new(
+            parts.method,
+            parts.uri,
+            parts.headers,
+        )))
+    }
 }
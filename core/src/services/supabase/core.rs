@@ -16,12 +16,17 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::time::Duration;
 
+use bytes::Bytes;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
+use http::header::RANGE;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use serde::Deserialize;
+use serde_json::json;
 
 use crate::raw::*;
 use crate::*;
@@ -126,7 +131,7 @@ impl SupabaseCore {
     pub fn supabase_get_object_public_request(
         &self,
         path: &str,
-        _: BytesRange,
+        range: BytesRange,
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let url = format!(
@@ -136,7 +141,11 @@ impl SupabaseCore {
             percent_encode_path(&p)
         );
 
-        let req = Request::get(&url);
+        let mut req = Request::get(&url);
+
+        if !range.is_full() {
+            req = req.header(RANGE, range.to_header());
+        }
 
         req.body(Buffer::new()).map_err(new_request_build_error)
     }
@@ -144,7 +153,7 @@ impl SupabaseCore {
     pub fn supabase_get_object_auth_request(
         &self,
         path: &str,
-        _: BytesRange,
+        range: BytesRange,
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let url = format!(
@@ -154,11 +163,36 @@ impl SupabaseCore {
             percent_encode_path(&p)
         );
 
-        let req = Request::get(&url);
+        let mut req = Request::get(&url);
+
+        if !range.is_full() {
+            req = req.header(RANGE, range.to_header());
+        }
 
         req.body(Buffer::new()).map_err(new_request_build_error)
     }
 
+    pub fn supabase_sign_object_request(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<Request<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/storage/v1/object/sign/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        let body = json!({ "expiresIn": expire.as_secs() });
+
+        Request::post(&url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(Buffer::from(Bytes::from(body.to_string())))
+            .map_err(new_request_build_error)
+    }
+
     pub fn supabase_head_object_public_request(&self, path: &str) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let url = format!(
@@ -261,4 +295,38 @@ impl SupabaseCore {
         self.sign(&mut req)?;
         self.send(req).await
     }
+
+    pub async fn supabase_sign_object(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.supabase_sign_object_request(path, expire)?;
+        self.sign(&mut req)?;
+        self.send(req).await
+    }
+}
+
+/// The response of creating a signed url.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct SignedUrlResponse {
+    /// The signed url relative to `/storage/v1`, like
+    /// `/object/sign/<bucket>/<path>?token=<token>`.
+    #[serde(rename = "signedURL")]
+    pub signed_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_signed_url_response() {
+        let bs = r#"{"signedURL":"/object/sign/test_bucket/a.txt?token=abc"}"#;
+
+        let resp: SignedUrlResponse = serde_json::from_str(bs).expect("must success");
+
+        assert_eq!(resp.signed_url, "/object/sign/test_bucket/a.txt?token=abc");
+    }
 }
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use http::Method;
 use http::Response;
 use http::StatusCode;
 use log::debug;
@@ -29,6 +30,7 @@ use super::core::*;
 use super::error::parse_error;
 use super::lister::SwiftLister;
 use super::writer::SwiftWriter;
+use super::writer::SwiftWriters;
 use crate::raw::*;
 use crate::*;
 
@@ -45,6 +47,36 @@ pub struct SwiftConfig {
     pub root: Option<String>,
     /// The token for Swift.
     pub token: Option<String>,
+
+    /// The keystone v3 auth url, e.g. `https://keystone.example.com:5000/v3`.
+    ///
+    /// If set, token will be issued by keystone instead of using `token`.
+    pub auth_url: Option<String>,
+    /// The region used to select object-store endpoint from keystone service catalog.
+    pub region: Option<String>,
+    /// The username for keystone password authentication.
+    pub username: Option<String>,
+    /// The password for keystone password authentication.
+    pub password: Option<String>,
+    /// The domain name of user, default to `Default`.
+    pub user_domain_name: Option<String>,
+    /// The project name to scope the token.
+    pub project_name: Option<String>,
+    /// The domain name of project, default to `Default`.
+    pub project_domain_name: Option<String>,
+    /// The id of keystone application credential.
+    pub application_credential_id: Option<String>,
+    /// The secret of keystone application credential.
+    pub application_credential_secret: Option<String>,
+
+    /// The container to store segments of static large objects.
+    ///
+    /// Multipart write is enabled only if this is set.
+    pub segment_container: Option<String>,
+    /// The key used to generate temp url.
+    ///
+    /// Presign is enabled only if this is set.
+    pub temp_url_key: Option<String>,
 }
 
 impl Debug for SwiftConfig {
@@ -54,10 +86,27 @@ impl Debug for SwiftConfig {
         ds.field("root", &self.root);
         ds.field("endpoint", &self.endpoint);
         ds.field("container", &self.container);
+        ds.field("auth_url", &self.auth_url);
+        ds.field("region", &self.region);
+        ds.field("username", &self.username);
+        ds.field("user_domain_name", &self.user_domain_name);
+        ds.field("project_name", &self.project_name);
+        ds.field("project_domain_name", &self.project_domain_name);
+        ds.field("application_credential_id", &self.application_credential_id);
+        ds.field("segment_container", &self.segment_container);
 
         if self.token.is_some() {
             ds.field("token", &"<redacted>");
         }
+        if self.password.is_some() {
+            ds.field("password", &"<redacted>");
+        }
+        if self.application_credential_secret.is_some() {
+            ds.field("application_credential_secret", &"<redacted>");
+        }
+        if self.temp_url_key.is_some() {
+            ds.field("temp_url_key", &"<redacted>");
+        }
 
         ds.finish()
     }
@@ -139,6 +188,108 @@ impl SwiftBuilder {
         }
         self
     }
+
+    /// Set the keystone v3 auth url of this backend, e.g. `https://keystone.example.com:5000/v3`.
+    ///
+    /// If auth url is set, token will be issued and refreshed by keystone with
+    /// either application credential or username and password. The endpoint
+    /// will be loaded from keystone service catalog if not set.
+    pub fn auth_url(mut self, auth_url: &str) -> Self {
+        if !auth_url.is_empty() {
+            self.config.auth_url = Some(auth_url.trim_end_matches('/').to_string());
+        }
+        self
+    }
+
+    /// Set the region used to select object-store endpoint from keystone service catalog.
+    pub fn region(mut self, region: &str) -> Self {
+        if !region.is_empty() {
+            self.config.region = Some(region.to_string());
+        }
+        self
+    }
+
+    /// Set the username for keystone password authentication.
+    pub fn username(mut self, username: &str) -> Self {
+        if !username.is_empty() {
+            self.config.username = Some(username.to_string());
+        }
+        self
+    }
+
+    /// Set the password for keystone password authentication.
+    pub fn password(mut self, password: &str) -> Self {
+        if !password.is_empty() {
+            self.config.password = Some(password.to_string());
+        }
+        self
+    }
+
+    /// Set the domain name of user for keystone password authentication.
+    ///
+    /// Default to `Default`.
+    pub fn user_domain_name(mut self, name: &str) -> Self {
+        if !name.is_empty() {
+            self.config.user_domain_name = Some(name.to_string());
+        }
+        self
+    }
+
+    /// Set the project name to scope the token of keystone password authentication.
+    pub fn project_name(mut self, name: &str) -> Self {
+        if !name.is_empty() {
+            self.config.project_name = Some(name.to_string());
+        }
+        self
+    }
+
+    /// Set the domain name of project for keystone password authentication.
+    ///
+    /// Default to `Default`.
+    pub fn project_domain_name(mut self, name: &str) -> Self {
+        if !name.is_empty() {
+            self.config.project_domain_name = Some(name.to_string());
+        }
+        self
+    }
+
+    /// Set the id of keystone application credential.
+    pub fn application_credential_id(mut self, id: &str) -> Self {
+        if !id.is_empty() {
+            self.config.application_credential_id = Some(id.to_string());
+        }
+        self
+    }
+
+    /// Set the secret of keystone application credential.
+    pub fn application_credential_secret(mut self, secret: &str) -> Self {
+        if !secret.is_empty() {
+            self.config.application_credential_secret = Some(secret.to_string());
+        }
+        self
+    }
+
+    /// Set the container to store segments of static large objects.
+    ///
+    /// Multipart write will be enabled if segment container is set. The
+    /// container must exist and be in the same account.
+    pub fn segment_container(mut self, container: &str) -> Self {
+        if !container.is_empty() {
+            self.config.segment_container = Some(container.trim_matches('/').to_string());
+        }
+        self
+    }
+
+    /// Set the temp url key of this backend.
+    ///
+    /// The key must match `X-Account-Meta-Temp-URL-Key` or `X-Container-Meta-Temp-URL-Key`
+    /// configured on swift. Presign will be enabled if temp url key is set.
+    pub fn temp_url_key(mut self, key: &str) -> Self {
+        if !key.is_empty() {
+            self.config.temp_url_key = Some(key.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for SwiftBuilder {
@@ -152,22 +303,70 @@ impl Builder for SwiftBuilder {
         let root = normalize_root(&self.config.root.unwrap_or_default());
         debug!("backend use root {}", root);
 
-        let endpoint = match self.config.endpoint {
-            Some(endpoint) => {
-                if endpoint.starts_with("http") {
-                    endpoint
-                } else {
-                    format!("https://{endpoint}")
-                }
+        let endpoint = self.config.endpoint.map(|endpoint| {
+            if endpoint.starts_with("http") {
+                endpoint
+            } else {
+                format!("https://{endpoint}")
+            }
+        });
+        debug!("backend use endpoint: {:?}", &endpoint);
+
+        let keystone = match self.config.auth_url {
+            Some(auth_url) => {
+                let method = match (
+                    self.config.application_credential_id,
+                    self.config.application_credential_secret,
+                    self.config.username,
+                    self.config.password,
+                ) {
+                    (Some(id), Some(secret), _, _) => {
+                        KeystoneAuthMethod::ApplicationCredential { id, secret }
+                    }
+                    (Some(_), None, _, _) => {
+                        return Err(Error::new(
+                            ErrorKind::ConfigInvalid,
+                            "missing application_credential_secret for Swift",
+                        ));
+                    }
+                    (None, _, Some(username), Some(password)) => KeystoneAuthMethod::Password {
+                        username,
+                        password,
+                        user_domain_name: self
+                            .config
+                            .user_domain_name
+                            .unwrap_or_else(|| "Default".to_string()),
+                        project_name: self.config.project_name,
+                        project_domain_name: self
+                            .config
+                            .project_domain_name
+                            .unwrap_or_else(|| "Default".to_string()),
+                    },
+                    (None, _, _, _) => {
+                        return Err(Error::new(
+                            ErrorKind::ConfigInvalid,
+                            "missing application credential or username and password for Swift keystone auth",
+                        ));
+                    }
+                };
+                debug!("backend use keystone auth: {}", &auth_url);
+
+                Some(KeystoneAuth {
+                    auth_url,
+                    region: self.config.region,
+                    method,
+                })
             }
             None => {
-                return Err(Error::new(
-                    ErrorKind::ConfigInvalid,
-                    "missing endpoint for Swift",
-                ));
+                if endpoint.is_none() {
+                    return Err(Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "missing endpoint for Swift",
+                    ));
+                }
+                None
             }
         };
-        debug!("backend use endpoint: {}", &endpoint);
 
         let container = match self.config.container {
             Some(container) => container,
@@ -180,16 +379,20 @@ impl Builder for SwiftBuilder {
         };
         debug!("backend use container: {}", &container);
 
-        let token = self.config.token.unwrap_or_default();
-
         let client = HttpClient::new()?;
 
         Ok(SwiftBackend {
             core: Arc::new(SwiftCore {
                 root,
-                endpoint,
                 container,
-                token,
+                segment_container: self.config.segment_container,
+                temp_url_key: self.config.temp_url_key,
+                signer: SwiftSigner {
+                    endpoint,
+                    token: self.config.token,
+                    keystone,
+                    cached: Default::default(),
+                },
                 client,
             }),
         })
@@ -204,7 +407,7 @@ pub struct SwiftBackend {
 
 impl Access for SwiftBackend {
     type Reader = HttpBody;
-    type Writer = oio::OneShotWriter<SwiftWriter>;
+    type Writer = SwiftWriters;
     type Lister = oio::PageLister<SwiftLister>;
     type BlockingReader = ();
     type BlockingWriter = ();
@@ -214,6 +417,7 @@ impl Access for SwiftBackend {
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::Swift)
             .set_root(&self.core.root)
+            .set_http_client(self.core.client.clone())
             .set_native_capability(Capability {
                 stat: true,
 
@@ -221,10 +425,17 @@ impl Access for SwiftBackend {
 
                 write: true,
                 write_can_empty: true,
+                write_can_multi: self.core.segment_container.is_some(),
                 delete: true,
 
                 list: true,
                 list_with_recursive: true,
+                list_with_start_after: true,
+
+                presign: self.core.temp_url_key.is_some(),
+                presign_stat: self.core.temp_url_key.is_some(),
+                presign_read: self.core.temp_url_key.is_some(),
+                presign_write: self.core.temp_url_key.is_some(),
 
                 ..Default::default()
             });
@@ -251,7 +462,10 @@ impl Access for SwiftBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK | StatusCode::PARTIAL_CONTENT => Ok((RpRead::new(), resp.into_body())),
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
+            }
             _ => {
                 let (part, mut body) = resp.into_parts();
                 let buf = body.to_buffer().await?;
@@ -263,7 +477,15 @@ impl Access for SwiftBackend {
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let writer = SwiftWriter::new(self.core.clone(), args.clone(), path.to_string());
 
-        let w = oio::OneShotWriter::new(writer);
+        let w = if self.core.segment_container.is_some() {
+            TwoWays::Two(oio::MultipartWriter::new(
+                writer,
+                args.executor().cloned(),
+                args.concurrent(),
+            ))
+        } else {
+            TwoWays::One(oio::OneShotWriter::new(writer))
+        };
 
         Ok((RpWrite::default(), w))
     }
@@ -286,6 +508,7 @@ impl Access for SwiftBackend {
             path.to_string(),
             args.recursive(),
             args.limit(),
+            args.start_after(),
         );
 
         Ok((RpList::default(), oio::PageLister::new(l)))
@@ -303,4 +526,26 @@ impl Access for SwiftBackend {
             _ => Err(parse_error(resp).await?),
         }
     }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let method = match args.operation() {
+            PresignOperation::Stat(_) => Method::HEAD,
+            PresignOperation::Read(_) => Method::GET,
+            PresignOperation::Write(_) => Method::PUT,
+        };
+
+        let req = self
+            .core
+            .swift_temp_url(method, path, args.expire())
+            .await?;
+
+        // We don't need this request anymore, consume it directly.
+        let (parts, _) = req.into_parts();
+
+        Ok(RpPresign::new(PresignedRequest::new(
+            parts.method,
+            parts.uri,
+            parts.headers,
+        )))
+    }
 }
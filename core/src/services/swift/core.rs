@@ -16,20 +16,43 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::time::Duration;
 
+use bytes::Buf;
+use chrono::DateTime;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header;
 use http::Request;
 use http::Response;
+use http::StatusCode;
+use http::Uri;
 use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use sha2::Sha256;
+use tokio::sync::RwLock;
 
+use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
+/// Header that carries the token for swift requests.
+const X_AUTH_TOKEN: &str = "X-Auth-Token";
+/// Header that carries the issued token in keystone responses.
+const X_SUBJECT_TOKEN: &str = "X-Subject-Token";
+
 pub struct SwiftCore {
     pub root: String,
-    pub endpoint: String,
     pub container: String,
-    pub token: String,
+    /// The container to store segments of static large objects.
+    ///
+    /// Multipart write is enabled only if `segment_container` is set.
+    pub segment_container: Option<String>,
+    /// The key used to generate temp url, presign is enabled only if it's set.
+    pub temp_url_key: Option<String>,
+    pub signer: SwiftSigner,
     pub client: HttpClient,
 }
 
@@ -37,32 +60,284 @@ impl Debug for SwiftCore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SwiftCore")
             .field("root", &self.root)
-            .field("endpoint", &self.endpoint)
             .field("container", &self.container)
+            .field("segment_container", &self.segment_container)
+            .field("signer", &self.signer)
+            .finish_non_exhaustive()
+    }
+}
+
+/// SwiftSigner loads the token and storage url used by swift requests.
+///
+/// - If keystone is not configured, the static `endpoint` and `token` will be used.
+/// - Otherwise, token will be issued by keystone and refreshed before expiring.
+pub struct SwiftSigner {
+    /// The storage url input by user, it takes precedence over the one from
+    /// keystone service catalog.
+    pub endpoint: Option<String>,
+    pub token: Option<String>,
+    pub keystone: Option<KeystoneAuth>,
+
+    pub cached: RwLock<Option<SwiftToken>>,
+}
+
+impl Debug for SwiftSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SwiftSigner")
+            .field("endpoint", &self.endpoint)
+            .field("keystone", &self.keystone)
             .finish_non_exhaustive()
     }
 }
 
+/// Keystone v3 auth for swift.
+#[derive(Clone)]
+pub struct KeystoneAuth {
+    /// The keystone v3 endpoint, e.g. `https://keystone.example.com:5000/v3`.
+    pub auth_url: String,
+    /// The region used to select storage url from service catalog.
+    pub region: Option<String>,
+    pub method: KeystoneAuthMethod,
+}
+
+impl Debug for KeystoneAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let method = match &self.method {
+            KeystoneAuthMethod::Password { .. } => "password",
+            KeystoneAuthMethod::ApplicationCredential { .. } => "application_credential",
+        };
+
+        f.debug_struct("KeystoneAuth")
+            .field("auth_url", &self.auth_url)
+            .field("region", &self.region)
+            .field("method", &method)
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+pub enum KeystoneAuthMethod {
+    Password {
+        username: String,
+        password: String,
+        user_domain_name: String,
+        project_name: Option<String>,
+        project_domain_name: String,
+    },
+    ApplicationCredential {
+        id: String,
+        secret: String,
+    },
+}
+
+impl KeystoneAuth {
+    /// Build the request body of [password authentication](https://docs.openstack.org/api-ref/identity/v3/#password-authentication-with-scoped-authorization)
+    /// or [application credential authentication](https://docs.openstack.org/api-ref/identity/v3/#authenticating-with-an-application-credential).
+    pub(super) fn build_auth_request(&self) -> serde_json::Value {
+        match &self.method {
+            KeystoneAuthMethod::Password {
+                username,
+                password,
+                user_domain_name,
+                project_name,
+                project_domain_name,
+            } => {
+                let identity = json!({
+                    "methods": ["password"],
+                    "password": {
+                        "user": {
+                            "name": username,
+                            "domain": {"name": user_domain_name},
+                            "password": password,
+                        }
+                    }
+                });
+
+                match project_name {
+                    Some(project_name) => json!({
+                        "auth": {
+                            "identity": identity,
+                            "scope": {
+                                "project": {
+                                    "name": project_name,
+                                    "domain": {"name": project_domain_name},
+                                }
+                            }
+                        }
+                    }),
+                    None => json!({"auth": {"identity": identity}}),
+                }
+            }
+            // Application credentials are always scoped, keystone will reject
+            // the request if scope is provided.
+            KeystoneAuthMethod::ApplicationCredential { id, secret } => json!({
+                "auth": {
+                    "identity": {
+                        "methods": ["application_credential"],
+                        "application_credential": {"id": id, "secret": secret}
+                    }
+                }
+            }),
+        }
+    }
+}
+
+/// The token and storage url to access swift.
+#[derive(Clone)]
+pub struct SwiftToken {
+    pub token: String,
+    /// Storage url like `https://swift.example.com/v1/AUTH_account`.
+    pub endpoint: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl SwiftToken {
+    /// Token is treated as expired 2 minutes earlier to avoid it expiring
+    /// while the request is inflight.
+    fn is_valid(&self) -> bool {
+        self.expires_at > Utc::now() + chrono::TimeDelta::try_minutes(2).expect("must be valid")
+    }
+}
+
+impl SwiftCore {
+    /// Load the token and storage url for current request.
+    pub async fn load_token(&self) -> Result<SwiftToken> {
+        let Some(keystone) = &self.signer.keystone else {
+            return self
+                .signer
+                .endpoint
+                .clone()
+                .map(|endpoint| SwiftToken {
+                    token: self.signer.token.clone().unwrap_or_default(),
+                    endpoint,
+                    expires_at: DateTime::<Utc>::MAX_UTC,
+                })
+                .ok_or_else(|| Error::new(ErrorKind::ConfigInvalid, "missing endpoint for Swift"));
+        };
+
+        if let Some(token) = self.signer.cached.read().await.as_ref() {
+            if token.is_valid() {
+                return Ok(token.clone());
+            }
+        }
+
+        let mut cached = self.signer.cached.write().await;
+        // Another task could have refreshed the token while we are waiting.
+        if let Some(token) = cached.as_ref() {
+            if token.is_valid() {
+                return Ok(token.clone());
+            }
+        }
+
+        let token = self.keystone_authenticate(keystone).await?;
+        *cached = Some(token.clone());
+        Ok(token)
+    }
+
+    /// Drop the cached token so that next request will authenticate again.
+    async fn expire_token(&self) {
+        if self.signer.keystone.is_some() {
+            self.signer.cached.write().await.take();
+        }
+    }
+
+    async fn keystone_authenticate(&self, keystone: &KeystoneAuth) -> Result<SwiftToken> {
+        let url = format!("{}/auth/tokens", keystone.auth_url);
+
+        let body =
+            serde_json::to_vec(&keystone.build_auth_request()).map_err(new_json_serialize_error)?;
+
+        let req = Request::post(&url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::CONTENT_LENGTH, body.len())
+            .body(Buffer::from(body))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.client.send(req).await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => {
+                let token = parse_header_to_str(resp.headers(), X_SUBJECT_TOKEN)?
+                    .ok_or_else(|| {
+                        Error::new(ErrorKind::Unexpected, "keystone doesn't return token")
+                    })?
+                    .to_string();
+
+                let body = resp.into_body();
+                let output: KeystoneTokenResponse =
+                    serde_json::from_reader(body.reader()).map_err(new_json_deserialize_error)?;
+
+                let endpoint = match &self.signer.endpoint {
+                    Some(endpoint) => endpoint.clone(),
+                    None => output
+                        .token
+                        .object_store_endpoint(keystone.region.as_deref())
+                        .ok_or_else(|| {
+                            Error::new(
+                                ErrorKind::ConfigInvalid,
+                                "object-store endpoint not found in keystone service catalog",
+                            )
+                            .with_context("region", keystone.region.as_deref().unwrap_or("*"))
+                        })?,
+                };
+
+                Ok(SwiftToken {
+                    token,
+                    endpoint,
+                    expires_at: parse_datetime_from_rfc3339(&output.token.expires_at)?,
+                })
+            }
+            _ => Err(parse_error(resp)
+                .await?
+                .with_operation("keystone::authenticate")),
+        }
+    }
+
+    pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
+        let resp = self.client.send(req).await?;
+        if resp.status() == StatusCode::UNAUTHORIZED {
+            self.expire_token().await;
+        }
+        Ok(resp)
+    }
+
+    pub async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+        let resp = self.client.fetch(req).await?;
+        if resp.status() == StatusCode::UNAUTHORIZED {
+            self.expire_token().await;
+        }
+        Ok(resp)
+    }
+}
+
 impl SwiftCore {
     pub async fn swift_delete(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let token = self.load_token().await?;
+
+        let mut url = format!(
             "{}/{}/{}",
-            &self.endpoint,
+            &token.endpoint,
             &self.container,
             percent_encode_path(&p)
         );
 
+        // Delete the segments of static large object together. Swift will delete
+        // normal objects as usual.
+        if self.segment_container.is_some() && !p.ends_with('/') {
+            url.push_str("?multipart-manifest=delete");
+        }
+
         let mut req = Request::delete(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
+        req = req.header(X_AUTH_TOKEN, &token.token);
 
         let body = Buffer::new();
 
         let req = req.body(body).map_err(new_request_build_error)?;
 
-        self.client.send(req).await
+        self.send(req).await
     }
 
     pub async fn swift_list(
@@ -74,11 +349,13 @@ impl SwiftCore {
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
+        let token = self.load_token().await?;
+
         // The delimiter is used to disable recursive listing.
         // Swift returns a 200 status code when there is no such pseudo directory in prefix.
         let mut url = format!(
             "{}/{}/?prefix={}&delimiter={}&format=json",
-            &self.endpoint,
+            &token.endpoint,
             &self.container,
             percent_encode_path(&p),
             delimiter
@@ -88,16 +365,16 @@ impl SwiftCore {
             url += &format!("&limit={}", limit);
         }
         if !marker.is_empty() {
-            url += &format!("&marker={}", marker);
+            url += &format!("&marker={}", percent_encode_path(marker));
         }
 
         let mut req = Request::get(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
+        req = req.header(X_AUTH_TOKEN, &token.token);
 
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
-        self.client.send(req).await
+        self.send(req).await
     }
 
     pub async fn swift_create_object(
@@ -108,21 +385,23 @@ impl SwiftCore {
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
+        let token = self.load_token().await?;
+
         let url = format!(
             "{}/{}/{}",
-            &self.endpoint,
+            &token.endpoint,
             &self.container,
             percent_encode_path(&p)
         );
 
         let mut req = Request::put(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
+        req = req.header(X_AUTH_TOKEN, &token.token);
         req = req.header(header::CONTENT_LENGTH, length);
 
         let req = req.body(body).map_err(new_request_build_error)?;
 
-        self.client.send(req).await
+        self.send(req).await
     }
 
     pub async fn swift_read(
@@ -135,16 +414,18 @@ impl SwiftCore {
             .trim_end_matches('/')
             .to_string();
 
+        let token = self.load_token().await?;
+
         let url = format!(
             "{}/{}/{}",
-            &self.endpoint,
+            &token.endpoint,
             &self.container,
             percent_encode_path(&p)
         );
 
         let mut req = Request::get(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
+        req = req.header(X_AUTH_TOKEN, &token.token);
 
         if !range.is_full() {
             req = req.header(header::RANGE, range.to_header());
@@ -152,7 +433,7 @@ impl SwiftCore {
 
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
-        self.client.fetch(req).await
+        self.fetch(req).await
     }
 
     pub async fn swift_copy(&self, src_p: &str, dst_p: &str) -> Result<Response<Buffer>> {
@@ -168,9 +449,11 @@ impl SwiftCore {
             .trim_end_matches('/')
             .to_string();
 
+        let token = self.load_token().await?;
+
         let url = format!(
             "{}/{}/{}",
-            &self.endpoint,
+            &token.endpoint,
             &self.container,
             percent_encode_path(&dst_p)
         );
@@ -179,7 +462,7 @@ impl SwiftCore {
         // Reference: https://docs.openstack.org/api-ref/object-store/#copy-object
         let mut req = Request::put(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
+        req = req.header(X_AUTH_TOKEN, &token.token);
         req = req.header("X-Copy-From", percent_encode_path(&src_p));
 
         // if use PUT method, we need to set the content-length to 0.
@@ -189,29 +472,291 @@ impl SwiftCore {
 
         let req = req.body(body).map_err(new_request_build_error)?;
 
-        self.client.send(req).await
+        self.send(req).await
     }
 
     pub async fn swift_get_metadata(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
+        let token = self.load_token().await?;
+
         let url = format!(
             "{}/{}/{}",
-            &self.endpoint,
+            &token.endpoint,
             &self.container,
             percent_encode_path(&p)
         );
 
         let mut req = Request::head(&url);
 
-        req = req.header("X-Auth-Token", &self.token);
+        req = req.header(X_AUTH_TOKEN, &token.token);
 
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
-        self.client.send(req).await
+        self.send(req).await
     }
 }
 
+/// Static large object support.
+///
+/// Reference: <https://docs.openstack.org/swift/latest/overview_large_objects.html#static-large-objects>
+impl SwiftCore {
+    fn segment_container(&self) -> Result<&str> {
+        self.segment_container.as_deref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unsupported,
+                "segment_container is required for multipart write",
+            )
+        })
+    }
+
+    /// Build the prefix of segments that belongs to given upload.
+    pub fn segment_prefix(&self, path: &str, upload_id: &str) -> String {
+        let p = build_abs_path(&self.root, path);
+        format!("{p}/{upload_id}/")
+    }
+
+    pub async fn swift_put_segment(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let segment_container = self.segment_container()?;
+        let segment = format!("{}{:08}", self.segment_prefix(path, upload_id), part_number);
+
+        let token = self.load_token().await?;
+
+        let url = format!(
+            "{}/{}/{}",
+            &token.endpoint,
+            segment_container,
+            percent_encode_path(&segment)
+        );
+
+        let mut req = Request::put(&url);
+
+        req = req.header(X_AUTH_TOKEN, &token.token);
+        req = req.header(header::CONTENT_LENGTH, size);
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
+    pub async fn swift_put_manifest(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[oio::MultipartPart],
+    ) -> Result<Response<Buffer>> {
+        let segment_container = self.segment_container()?;
+        let prefix = self.segment_prefix(path, upload_id);
+
+        let manifest: Vec<ManifestSegment> = parts
+            .iter()
+            .map(|part| ManifestSegment {
+                path: format!("/{}/{}{:08}", segment_container, prefix, part.part_number),
+                etag: part.etag.trim_matches('"').to_string(),
+            })
+            .collect();
+        let body = serde_json::to_vec(&manifest).map_err(new_json_serialize_error)?;
+
+        let p = build_abs_path(&self.root, path);
+
+        let token = self.load_token().await?;
+
+        let url = format!(
+            "{}/{}/{}?multipart-manifest=put",
+            &token.endpoint,
+            &self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url);
+
+        req = req.header(X_AUTH_TOKEN, &token.token);
+        req = req.header(header::CONTENT_LENGTH, body.len());
+
+        let req = req
+            .body(Buffer::from(body))
+            .map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
+    /// Delete all segments uploaded by given upload.
+    pub async fn swift_delete_segments(&self, path: &str, upload_id: &str) -> Result<()> {
+        let segment_container = self.segment_container()?;
+        let prefix = self.segment_prefix(path, upload_id);
+
+        let token = self.load_token().await?;
+
+        let url = format!(
+            "{}/{}/?prefix={}&format=json",
+            &token.endpoint,
+            segment_container,
+            percent_encode_path(&prefix),
+        );
+
+        let req = Request::get(&url)
+            .header(X_AUTH_TOKEN, &token.token)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        let resp = self.send(req).await?;
+        if !resp.status().is_success() {
+            return Err(parse_error(resp).await?);
+        }
+
+        let segments: Vec<ListOpResponse> = serde_json::from_reader(resp.into_body().reader())
+            .map_err(new_json_deserialize_error)?;
+
+        for segment in segments {
+            let ListOpResponse::FileInfo { name, .. } = segment else {
+                continue;
+            };
+
+            let url = format!(
+                "{}/{}/{}",
+                &token.endpoint,
+                segment_container,
+                percent_encode_path(&name)
+            );
+
+            let req = Request::delete(&url)
+                .header(X_AUTH_TOKEN, &token.token)
+                .body(Buffer::new())
+                .map_err(new_request_build_error)?;
+
+            let resp = self.send(req).await?;
+            match resp.status() {
+                StatusCode::NO_CONTENT | StatusCode::OK | StatusCode::NOT_FOUND => {}
+                _ => return Err(parse_error(resp).await?),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Temp url support.
+///
+/// Reference: <https://docs.openstack.org/swift/latest/api/temporary_url_middleware.html>
+impl SwiftCore {
+    pub async fn swift_temp_url(
+        &self,
+        method: http::Method,
+        path: &str,
+        expire: Duration,
+    ) -> Result<Request<Buffer>> {
+        let key = self.temp_url_key.as_deref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unsupported,
+                "temp_url_key is required for presign",
+            )
+        })?;
+
+        let p = build_abs_path(&self.root, path);
+
+        let token = self.load_token().await?;
+
+        let url = format!(
+            "{}/{}/{}",
+            &token.endpoint,
+            &self.container,
+            percent_encode_path(&p)
+        );
+
+        let expires = (Utc::now() + expire).timestamp();
+        let signature = sign_temp_url(key, method.as_str(), expires, &url)?;
+
+        let req = Request::builder()
+            .method(method)
+            .uri(format!(
+                "{url}?temp_url_sig={signature}&temp_url_expires={expires}"
+            ))
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+}
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Sign the temp url with `HMAC-SHA256` over `{method}\n{expires}\n{path}`.
+///
+/// `path` is the decoded path of the object url like `/v1/AUTH_account/container/object`.
+pub(super) fn sign_temp_url(key: &str, method: &str, expires: i64, url: &str) -> Result<String> {
+    let uri: Uri = url
+        .parse()
+        .map_err(|err| Error::new(ErrorKind::Unexpected, "parse temp url").set_source(err))?;
+    let path = percent_decode_path(uri.path());
+
+    let mut mac = HmacSha256::new_from_slice(key.as_bytes())
+        .map_err(|err| Error::new(ErrorKind::Unexpected, "invalid temp_url_key").set_source(err))?;
+    mac.update(format!("{method}\n{expires}\n{path}").as_bytes());
+
+    Ok(format!("{:x}", mac.finalize().into_bytes()))
+}
+
+/// Segment of static large object manifest.
+#[derive(Debug, Serialize)]
+struct ManifestSegment {
+    path: String,
+    etag: String,
+}
+
+/// Response of [keystone authentication](https://docs.openstack.org/api-ref/identity/v3/#password-authentication-with-scoped-authorization).
+#[derive(Debug, Deserialize)]
+pub(super) struct KeystoneTokenResponse {
+    pub token: KeystoneToken,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct KeystoneToken {
+    pub expires_at: String,
+    #[serde(default)]
+    pub catalog: Vec<KeystoneCatalogEntry>,
+}
+
+impl KeystoneToken {
+    /// Find the public object-store endpoint in service catalog.
+    pub fn object_store_endpoint(&self, region: Option<&str>) -> Option<String> {
+        self.catalog
+            .iter()
+            .filter(|entry| entry.service_type == "object-store")
+            .flat_map(|entry| entry.endpoints.iter())
+            .find(|endpoint| {
+                endpoint.interface == "public"
+                    && region.map_or(true, |region| {
+                        endpoint.region_id.as_deref() == Some(region)
+                            || endpoint.region.as_deref() == Some(region)
+                    })
+            })
+            .map(|endpoint| endpoint.url.trim_end_matches('/').to_string())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct KeystoneCatalogEntry {
+    #[serde(rename = "type")]
+    pub service_type: String,
+    #[serde(default)]
+    pub endpoints: Vec<KeystoneEndpoint>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct KeystoneEndpoint {
+    pub interface: String,
+    pub region: Option<String>,
+    pub region_id: Option<String>,
+    pub url: String,
+}
+
 #[derive(Debug, Eq, PartialEq, Deserialize)]
 #[serde(untagged)]
 pub enum ListOpResponse {
@@ -284,4 +829,96 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_sign_temp_url() -> Result<()> {
+        let signature = sign_temp_url(
+            "mykey",
+            "GET",
+            1323479485,
+            "https://swift.example.com/v1/AUTH_account/container/my%20object",
+        )?;
+
+        assert_eq!(
+            signature,
+            "3122d51d50a631a232d4930d33df82925e7727e59e3091981623a17fe4ab4d22"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystone_object_store_endpoint() -> Result<()> {
+        let resp = bytes::Bytes::from(
+            r#"
+            {
+                "token": {
+                    "expires_at": "2024-08-05T10:21:37.000000Z",
+                    "catalog": [
+                        {
+                            "type": "identity",
+                            "endpoints": [
+                                {"interface": "public", "region": "RegionOne", "region_id": "RegionOne", "url": "https://keystone.example.com/v3"}
+                            ]
+                        },
+                        {
+                            "type": "object-store",
+                            "endpoints": [
+                                {"interface": "internal", "region": "RegionOne", "region_id": "RegionOne", "url": "http://10.0.0.1:8080/v1/AUTH_test"},
+                                {"interface": "public", "region": "RegionOne", "region_id": "RegionOne", "url": "https://one.example.com/v1/AUTH_test"},
+                                {"interface": "public", "region": "RegionTwo", "region_id": "RegionTwo", "url": "https://two.example.com/v1/AUTH_test/"}
+                            ]
+                        }
+                    ]
+                }
+            }
+            "#,
+        );
+
+        let out: KeystoneTokenResponse =
+            serde_json::from_slice(&resp).map_err(new_json_deserialize_error)?;
+
+        assert_eq!(
+            out.token.object_store_endpoint(None),
+            Some("https://one.example.com/v1/AUTH_test".to_string())
+        );
+        assert_eq!(
+            out.token.object_store_endpoint(Some("RegionTwo")),
+            Some("https://two.example.com/v1/AUTH_test".to_string())
+        );
+        assert_eq!(out.token.object_store_endpoint(Some("RegionThree")), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystone_auth_request() {
+        let auth = KeystoneAuth {
+            auth_url: "https://keystone.example.com/v3".to_string(),
+            region: None,
+            method: KeystoneAuthMethod::ApplicationCredential {
+                id: "id".to_string(),
+                secret: "secret".to_string(),
+            },
+        };
+        let body = auth.build_auth_request();
+        assert_eq!(
+            body["auth"]["identity"]["methods"][0],
+            "application_credential"
+        );
+        assert!(body["auth"].get("scope").is_none());
+
+        let auth = KeystoneAuth {
+            method: KeystoneAuthMethod::Password {
+                username: "user".to_string(),
+                password: "password".to_string(),
+                user_domain_name: "Default".to_string(),
+                project_name: Some("demo".to_string()),
+                project_domain_name: "Default".to_string(),
+            },
+            ..auth
+        };
+        let body = auth.build_auth_request();
+        assert_eq!(body["auth"]["identity"]["methods"][0], "password");
+        assert_eq!(body["auth"]["identity"]["password"]["user"]["name"], "user");
+        assert_eq!(body["auth"]["scope"]["project"]["name"], "demo");
+    }
 }
@@ -29,25 +29,41 @@ pub struct SwiftLister {
     path: String,
     delimiter: &'static str,
     limit: Option<usize>,
+
+    /// Swift starts listing **after** this specified key.
+    start_after: Option<String>,
 }
 
 impl SwiftLister {
-    pub fn new(core: Arc<SwiftCore>, path: String, recursive: bool, limit: Option<usize>) -> Self {
+    pub fn new(
+        core: Arc<SwiftCore>,
+        path: String,
+        recursive: bool,
+        limit: Option<usize>,
+        start_after: Option<&str>,
+    ) -> Self {
         let delimiter = if recursive { "" } else { "/" };
+        let start_after = start_after.map(|v| build_abs_path(&core.root, v));
         Self {
             core,
             path,
             delimiter,
             limit,
+            start_after,
         }
     }
 }
 
 impl oio::PageList for SwiftLister {
     async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
+        let marker = match (&self.start_after, ctx.token.is_empty()) {
+            (Some(start_after), true) => start_after.as_str(),
+            _ => ctx.token.as_str(),
+        };
+
         let response = self
             .core
-            .swift_list(&self.path, self.delimiter, self.limit, &ctx.token)
+            .swift_list(&self.path, self.delimiter, self.limit, marker)
             .await?;
 
         let status_code = response.status();
@@ -24,6 +24,8 @@ use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
+pub type SwiftWriters = TwoWays<oio::OneShotWriter<SwiftWriter>, oio::MultipartWriter<SwiftWriter>>;
+
 pub struct SwiftWriter {
     core: Arc<SwiftCore>,
     path: String,
@@ -50,3 +52,72 @@ impl oio::OneShotWrite for SwiftWriter {
         }
     }
 }
+
+/// Write large objects via [static large object](https://docs.openstack.org/swift/latest/overview_large_objects.html#static-large-objects).
+///
+/// Parts are uploaded as segments into `segment_container`, and the manifest
+/// will be uploaded to the target path while completing.
+impl oio::MultipartWrite for SwiftWriter {
+    async fn write_once(&self, _: u64, body: Buffer) -> Result<()> {
+        oio::OneShotWrite::write_once(self, body).await
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        // Swift doesn't need to initiate the upload, we just generate a unique
+        // id to group the segments.
+        Ok(uuid::Uuid::new_v4().to_string())
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: Buffer,
+    ) -> Result<oio::MultipartPart> {
+        let resp = self
+            .core
+            .swift_put_segment(&self.path, upload_id, part_number, size, body)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                Ok(oio::MultipartPart {
+                    part_number,
+                    etag,
+                    checksum: None,
+                })
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn complete_part(&self, upload_id: &str, parts: &[oio::MultipartPart]) -> Result<()> {
+        let resp = self
+            .core
+            .swift_put_manifest(&self.path, upload_id, parts)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        self.core.swift_delete_segments(&self.path, upload_id).await
+    }
+}
@@ -0,0 +1,358 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Buf;
+use http::header;
+use http::Request;
+use http::StatusCode;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::NON_ALPHANUMERIC;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::error::parse_error;
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::*;
+
+/// Ask Upstash to return results in base64 so that binary values can be read back.
+const UPSTASH_ENCODING: &str = "upstash-encoding";
+
+/// Config for Upstash Redis services support.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct UpstashConfig {
+    /// The REST URL of the database, like `https://us1-example-12345.upstash.io`.
+    pub endpoint: Option<String>,
+    /// The REST token of the database.
+    pub token: Option<String>,
+    /// Root within this backend.
+    pub root: Option<String>,
+}
+
+impl Debug for UpstashConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("UpstashConfig");
+
+        ds.field("endpoint", &self.endpoint);
+        ds.field("root", &self.root);
+
+        if self.token.is_some() {
+            ds.field("token", &"<redacted>");
+        }
+
+        ds.finish()
+    }
+}
+
+impl Configurator for UpstashConfig {
+    type Builder = UpstashBuilder;
+    fn into_builder(self) -> Self::Builder {
+        UpstashBuilder {
+            config: self,
+            http_client: None,
+        }
+    }
+}
+
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct UpstashBuilder {
+    config: UpstashConfig,
+
+    /// The HTTP client used to communicate with Upstash.
+    http_client: Option<HttpClient>,
+}
+
+impl Debug for UpstashBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpstashBuilder")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl UpstashBuilder {
+    /// Set the REST URL of the database.
+    ///
+    /// For example: `https://us1-example-12345.upstash.io`
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        if !endpoint.is_empty() {
+            self.config.endpoint = Some(endpoint.trim_end_matches('/').to_string())
+        }
+        self
+    }
+
+    /// Set the REST token of the database.
+    pub fn token(mut self, token: &str) -> Self {
+        if !token.is_empty() {
+            self.config.token = Some(token.to_string())
+        }
+        self
+    }
+
+    /// Set the root within this backend.
+    pub fn root(mut self, root: &str) -> Self {
+        if !root.is_empty() {
+            self.config.root = Some(root.to_string())
+        }
+        self
+    }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+}
+
+impl Builder for UpstashBuilder {
+    const SCHEME: Scheme = Scheme::Upstash;
+    type Config = UpstashConfig;
+
+    fn build(self) -> Result<impl Access> {
+        let Some(endpoint) = self.config.endpoint.clone() else {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "endpoint is required")
+                .with_context("service", Scheme::Upstash));
+        };
+
+        let authorization = match &self.config.token {
+            Some(token) => format_authorization_by_bearer(token)?,
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "token is required")
+                    .with_context("service", Scheme::Upstash))
+            }
+        };
+
+        let client = if let Some(client) = self.http_client {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Upstash)
+            })?
+        };
+
+        let root = normalize_root(
+            self.config
+                .root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        Ok(UpstashBackend::new(Adapter {
+            endpoint,
+            authorization,
+            client,
+        })
+        .with_root(&root))
+    }
+}
+
+pub type UpstashBackend = kv::Backend<Adapter>;
+
+#[derive(Clone)]
+pub struct Adapter {
+    endpoint: String,
+    authorization: String,
+    client: HttpClient,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Adapter")
+            .field("endpoint", &self.endpoint)
+            .finish()
+    }
+}
+
+impl Adapter {
+    /// Send a redis command like `["GET", "key"]` and return its result.
+    async fn command<T: DeserializeOwned>(&self, args: &[&str]) -> Result<T> {
+        let body = serde_json::to_vec(args).map_err(new_json_serialize_error)?;
+        let req = Request::post(&self.endpoint)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Buffer::from(body))
+            .map_err(new_request_build_error)?;
+        self.send(req).await
+    }
+
+    async fn send<T: DeserializeOwned>(&self, mut req: Request<Buffer>) -> Result<T> {
+        req.headers_mut()
+            .insert(header::AUTHORIZATION, self.authorization.parse().unwrap());
+        req.headers_mut()
+            .insert(UPSTASH_ENCODING, "base64".parse().unwrap());
+
+        let resp = self.client.send(req).await?;
+        match resp.status() {
+            StatusCode::OK => {
+                let resp: UpstashResponse<T> = serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+                Ok(resp.result)
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::Upstash,
+            &self.endpoint,
+            Capability {
+                read: true,
+                write: true,
+                list: true,
+
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Buffer>> {
+        let value: Option<String> = self.command(&["GET", path]).await?;
+        match value {
+            Some(v) => Ok(Some(Buffer::from(decode_base64(&v)?))),
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, path: &str, value: Buffer) -> Result<()> {
+        // Values are sent as the request body to keep binary data as is, so the
+        // key has to be in the url.
+        let url = format!(
+            "{}/set/{}",
+            self.endpoint,
+            utf8_percent_encode(path, NON_ALPHANUMERIC)
+        );
+        let req = Request::post(&url)
+            .header(header::CONTENT_LENGTH, value.len())
+            .body(value)
+            .map_err(new_request_build_error)?;
+
+        let _: String = self.send(req).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let _: i64 = self.command(&["DEL", path]).await?;
+        Ok(())
+    }
+
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        let pattern = format!("{}*", escape_pattern(path));
+
+        let mut keys = vec![];
+        let mut cursor = "0".to_string();
+        loop {
+            let (next, batch): (String, Vec<String>) = self
+                .command(&["SCAN", &cursor, "MATCH", &pattern, "COUNT", "1000"])
+                .await?;
+            // Both the cursor and keys are encoded in base64.
+            for key in batch {
+                let key = decode_base64_string(&key)?;
+                if key != path {
+                    keys.push(key);
+                }
+            }
+
+            cursor = decode_base64_string(&next)?;
+            if cursor == "0" {
+                break;
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct UpstashResponse<T> {
+    result: T,
+}
+
+fn decode_base64(v: &str) -> Result<Vec<u8>> {
+    BASE64_STANDARD.decode(v).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "upstash returns invalid base64 data").set_source(err)
+    })
+}
+
+fn decode_base64_string(v: &str) -> Result<String> {
+    String::from_utf8(decode_base64(v)?).map_err(|err| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "upstash returns invalid utf-8 string",
+        )
+        .set_source(err)
+    })
+}
+
+/// Escape glob characters so that the path is matched literally by `SCAN MATCH`.
+fn escape_pattern(path: &str) -> String {
+    let mut s = String::with_capacity(path.len());
+    for c in path.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            s.push('\\');
+        }
+        s.push(c);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_pattern() {
+        assert_eq!(escape_pattern("dir/"), "dir/");
+        assert_eq!(escape_pattern("a*b?[c]\\"), "a\\*b\\?\\[c\\]\\\\");
+    }
+
+    #[test]
+    fn test_deserialize_response() {
+        let resp: UpstashResponse<Option<String>> =
+            serde_json::from_str(r#"{"result":"aGVsbG8="}"#).unwrap();
+        assert_eq!(
+            decode_base64(&resp.result.unwrap()).unwrap(),
+            b"hello".to_vec()
+        );
+
+        let resp: UpstashResponse<Option<String>> =
+            serde_json::from_str(r#"{"result":null}"#).unwrap();
+        assert!(resp.result.is_none());
+
+        let resp: UpstashResponse<(String, Vec<String>)> =
+            serde_json::from_str(r#"{"result":["MA==",["YS90eHQ=","Yg=="]]}"#).unwrap();
+        assert_eq!(resp.result.0, "MA==");
+        assert_eq!(resp.result.1, vec!["YS90eHQ=", "Yg=="]);
+    }
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::env;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
@@ -36,6 +37,9 @@ use super::writer::VercelBlobWriters;
 use crate::raw::*;
 use crate::*;
 
+/// The environment variable that vercel exposes the blob token with.
+const BLOB_READ_WRITE_TOKEN: &str = "BLOB_READ_WRITE_TOKEN";
+
 /// Config for VercelBlob services support.
 #[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(default)]
@@ -46,6 +50,8 @@ pub struct VercelBlobConfig {
     /// All operations will happen under this root.
     pub root: Option<String>,
     /// vercel blob token.
+    ///
+    /// Loaded from env `BLOB_READ_WRITE_TOKEN` if not set.
     pub token: String,
 }
 
@@ -104,7 +110,7 @@ impl VercelBlobBuilder {
     /// Vercel Blob token.
     ///
     /// Get from Vercel environment variable `BLOB_READ_WRITE_TOKEN`.
-    /// It is required.
+    /// Loaded from that environment variable if not set.
     pub fn token(mut self, token: &str) -> Self {
         self.config.token = token.to_string();
 
@@ -135,7 +141,12 @@ impl Builder for VercelBlobBuilder {
         debug!("backend use root {}", &root);
 
         // Handle token.
-        if self.config.token.is_empty() {
+        let token = if self.config.token.is_empty() {
+            env::var(BLOB_READ_WRITE_TOKEN).unwrap_or_default()
+        } else {
+            self.config.token.clone()
+        };
+        if token.is_empty() {
             return Err(Error::new(ErrorKind::ConfigInvalid, "token is empty")
                 .with_operation("Builder::build")
                 .with_context("service", Scheme::VercelBlob));
@@ -153,7 +164,7 @@ impl Builder for VercelBlobBuilder {
         Ok(VercelBlobBackend {
             core: Arc::new(VercelBlobCore {
                 root,
-                token: self.config.token.clone(),
+                token,
                 client,
             }),
         })
@@ -225,7 +236,8 @@ impl Access for VercelBlobBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -93,7 +93,7 @@ impl VercelBlobCore {
         let p = build_abs_path(&self.root, path);
         // Vercel blob use an unguessable random id url to download the file
         // So we use list to get the url of the file and then use it to download the file
-        let resp = self.list(&p, Some(1)).await?;
+        let resp = self.list(&p, Some(1), None).await?;
 
         // Use the mtach url to download the file
         let url = resolve_blob(resp.blobs, p);
@@ -151,7 +151,7 @@ impl VercelBlobCore {
     pub async fn delete(&self, path: &str) -> Result<()> {
         let p = build_abs_path(&self.root, path);
 
-        let resp = self.list(&p, Some(1)).await?;
+        let resp = self.list(&p, Some(1), None).await?;
 
         let url = resolve_blob(resp.blobs, p);
 
@@ -185,7 +185,7 @@ impl VercelBlobCore {
     pub async fn head(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let resp = self.list(&p, Some(1)).await?;
+        let resp = self.list(&p, Some(1), None).await?;
 
         let url = resolve_blob(resp.blobs, p);
 
@@ -209,7 +209,7 @@ impl VercelBlobCore {
     pub async fn copy(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
         let from = build_abs_path(&self.root, from);
 
-        let resp = self.list(&from, Some(1)).await?;
+        let resp = self.list(&from, Some(1), None).await?;
 
         let from_url = resolve_blob(resp.blobs, from);
 
@@ -235,7 +235,12 @@ impl VercelBlobCore {
         self.send(req).await
     }
 
-    pub async fn list(&self, prefix: &str, limit: Option<usize>) -> Result<ListResponse> {
+    pub async fn list(
+        &self,
+        prefix: &str,
+        limit: Option<usize>,
+        cursor: Option<&str>,
+    ) -> Result<ListResponse> {
         let prefix = if prefix == "/" { "" } else { prefix };
 
         let mut url = format!(
@@ -247,6 +252,10 @@ impl VercelBlobCore {
             url.push_str(&format!("&limit={}", limit))
         }
 
+        if let Some(cursor) = cursor {
+            url.push_str(&format!("&cursor={}", percent_encode_path(cursor)))
+        }
+
         let req = Request::get(&url);
 
         let req = self.sign(req);
@@ -44,7 +44,8 @@ impl oio::PageList for VercelBlobLister {
     async fn next_page(&self, ctx: &mut oio::PageContext) -> Result<()> {
         let p = build_abs_path(&self.core.root, &self.path);
 
-        let resp = self.core.list(&p, self.limit).await?;
+        let cursor = (!ctx.token.is_empty()).then_some(ctx.token.as_str());
+        let resp = self.core.list(&p, self.limit, cursor).await?;
 
         ctx.done = !resp.has_more;
 
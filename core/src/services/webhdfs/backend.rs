@@ -17,9 +17,12 @@
 
 use core::fmt::Debug;
 use std::fmt::Formatter;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use bytes::Buf;
+use futures::stream;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::Request;
@@ -30,8 +33,10 @@ use serde::Deserialize;
 use serde::Serialize;
 use tokio::sync::OnceCell;
 
+use super::error::is_standby_error;
 use super::error::parse_error;
 use super::lister::WebhdfsLister;
+use super::message::BlockLocationsWrapper;
 use super::message::BooleanResp;
 use super::message::FileStatusType;
 use super::message::FileStatusWrapper;
@@ -50,6 +55,8 @@ pub struct WebhdfsConfig {
     /// Root for webhdfs.
     pub root: Option<String>,
     /// Endpoint for webhdfs.
+    ///
+    /// Multiple namenodes could be separated by `,` for HA setups.
     pub endpoint: Option<String>,
     /// Delegation token for webhdfs.
     pub delegation: Option<String>,
@@ -117,6 +124,10 @@ impl WebhdfsBuilder {
     ///
     /// If user inputs endpoint without scheme, we will
     /// prepend `http://` to it.
+    ///
+    /// For HA setups, multiple namenodes could be separated by `,`, e.g.
+    /// `http://nn1:9870,http://nn2:9870`. Requests to namenodes will fail over
+    /// to the next one if the current one is in standby state or unreachable.
     pub fn endpoint(mut self, endpoint: &str) -> Self {
         if !endpoint.is_empty() {
             // trim tailing slash so we can accept `http://127.0.0.1:9870/`
@@ -182,17 +193,28 @@ impl Builder for WebhdfsBuilder {
         debug!("backend use root {root}");
 
         // check scheme
-        let endpoint = match self.config.endpoint {
-            Some(endpoint) => {
-                if endpoint.starts_with("http") {
-                    endpoint
+        let endpoints: Vec<String> = self
+            .config
+            .endpoint
+            .as_deref()
+            .unwrap_or(WEBHDFS_DEFAULT_ENDPOINT)
+            .split(',')
+            .map(|v| v.trim().trim_end_matches('/'))
+            .filter(|v| !v.is_empty())
+            .map(|v| {
+                if v.starts_with("http") {
+                    v.to_string()
                 } else {
-                    format!("http://{endpoint}")
+                    format!("http://{v}")
                 }
-            }
-            None => WEBHDFS_DEFAULT_ENDPOINT.to_string(),
-        };
-        debug!("backend use endpoint {}", endpoint);
+            })
+            .collect();
+        if endpoints.is_empty() {
+            return Err(Error::new(ErrorKind::ConfigInvalid, "endpoint is invalid")
+                .with_context("service", Scheme::Webhdfs)
+                .with_context("endpoint", self.config.endpoint.unwrap_or_default()));
+        }
+        debug!("backend use endpoints {:?}", endpoints);
 
         let atomic_write_dir = self.config.atomic_write_dir;
 
@@ -205,7 +227,8 @@ impl Builder for WebhdfsBuilder {
 
         let backend = WebhdfsBackend {
             root,
-            endpoint,
+            endpoints,
+            active_endpoint: Arc::new(AtomicUsize::new(0)),
             auth,
             client,
             root_checker: OnceCell::new(),
@@ -221,7 +244,9 @@ impl Builder for WebhdfsBuilder {
 #[derive(Debug, Clone)]
 pub struct WebhdfsBackend {
     root: String,
-    endpoint: String,
+    endpoints: Vec<String>,
+    /// Index of the namenode that serves requests currently, shared by all clones.
+    active_endpoint: Arc<AtomicUsize>,
     auth: Option<String>,
     root_checker: OnceCell<()>,
 
@@ -231,12 +256,86 @@ pub struct WebhdfsBackend {
 }
 
 impl WebhdfsBackend {
+    /// The endpoint of the namenode that is active currently.
+    fn endpoint(&self) -> &str {
+        &self.endpoints[self.active_endpoint.load(Ordering::Relaxed) % self.endpoints.len()]
+    }
+
+    /// Send a request and fail over to other namenodes if needed.
+    pub async fn send(&self, req: Request<Buffer>) -> Result<Response<Buffer>> {
+        let (parts, mut body) = self.fetch(req).await?.into_parts();
+        let buf = body.to_buffer().await?;
+        Ok(Response::from_parts(parts, buf))
+    }
+
+    /// Fetch a request and fail over to other namenodes if needed.
+    ///
+    /// Requests will be re-sent to the next namenode if the current one
+    /// returns `StandbyException` or can't be reached. Requests to datanodes,
+    /// like the locations returned by namenodes, will be sent as is.
+    pub async fn fetch(&self, req: Request<Buffer>) -> Result<Response<HttpBody>> {
+        if self.endpoints.len() == 1 {
+            return self.client.fetch(req).await;
+        }
+
+        let uri = req.uri().to_string();
+        let Some((mut idx, suffix)) = self.endpoints.iter().enumerate().find_map(|(idx, ep)| {
+            uri.strip_prefix(ep.as_str())
+                .filter(|v| v.starts_with('/'))
+                .map(|v| (idx, v.to_string()))
+        }) else {
+            return self.client.fetch(req).await;
+        };
+
+        let (parts, body) = req.into_parts();
+        for attempt in 1..=self.endpoints.len() {
+            let is_last = attempt == self.endpoints.len();
+
+            let mut req = Request::builder()
+                .method(parts.method.clone())
+                .uri(format!("{}{}", self.endpoints[idx], suffix))
+                .body(body.clone())
+                .map_err(new_request_build_error)?;
+            *req.headers_mut() = parts.headers.clone();
+
+            match self.client.fetch(req).await {
+                Err(err) if !is_last && err.is_temporary() => {
+                    debug!(
+                        "webhdfs namenode {} is unreachable: {err}",
+                        self.endpoints[idx]
+                    );
+                }
+                Err(err) => return Err(err),
+                Ok(resp) if resp.status() != StatusCode::FORBIDDEN => {
+                    self.active_endpoint.store(idx, Ordering::Relaxed);
+                    return Ok(resp);
+                }
+                Ok(resp) => {
+                    // Standby namenodes reject requests with `403 StandbyException`,
+                    // the body is tiny so it's fine to buffer it here.
+                    let (parts, mut body) = resp.into_parts();
+                    let buf = body.to_buffer().await?;
+                    if is_last || !is_standby_error(&buf) {
+                        let size = buf.len() as u64;
+                        let body = HttpBody::new(stream::iter(Some(Ok(buf))), Some(size));
+                        return Ok(Response::from_parts(parts, body));
+                    }
+                    debug!("webhdfs namenode {} is in standby", self.endpoints[idx]);
+                }
+            }
+
+            idx = (idx + 1) % self.endpoints.len();
+        }
+
+        unreachable!("webhdfs endpoints must not be empty")
+    }
+
     pub fn webhdfs_create_dir_request(&self, path: &str) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=MKDIRS&overwrite=true&noredirect=true",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
         );
         if let Some(auth) = &self.auth {
@@ -259,9 +358,19 @@ impl WebhdfsBackend {
 
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=CREATE&overwrite=true&noredirect=true",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
         );
+        if let Some(block_size) = args.block_size() {
+            url += &format!("&blocksize={block_size}");
+        }
+        if let Some(replication) = args.replication() {
+            url += &format!("&replication={replication}");
+        }
+        if let Some(permission) = args.permission() {
+            // WebHDFS accepts permission in octal.
+            url += &format!("&permission={permission:o}");
+        }
         if let Some(auth) = &self.auth {
             url += format!("&{auth}").as_str();
         }
@@ -270,7 +379,7 @@ impl WebhdfsBackend {
 
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
-        let resp = self.client.send(req).await?;
+        let resp = self.send(req).await?;
 
         let status = resp.status();
 
@@ -301,7 +410,7 @@ impl WebhdfsBackend {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=APPEND&noredirect=true",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
         );
         if let Some(auth) = &self.auth {
@@ -312,7 +421,7 @@ impl WebhdfsBackend {
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
 
-        let resp = self.client.send(req).await?;
+        let resp = self.send(req).await?;
 
         let status = resp.status();
 
@@ -334,7 +443,7 @@ impl WebhdfsBackend {
 
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=RENAME&destination={}",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&from),
             percent_encode_path(&to)
         );
@@ -347,7 +456,7 @@ impl WebhdfsBackend {
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
 
-        self.client.send(req).await
+        self.send(req).await
     }
 
     pub async fn webhdfs_append_request(
@@ -385,7 +494,7 @@ impl WebhdfsBackend {
 
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=CONCAT&sources={}",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
             percent_encode_path(&sources),
         );
@@ -407,7 +516,7 @@ impl WebhdfsBackend {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=OPEN",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
         );
         if let Some(auth) = &self.auth {
@@ -432,7 +541,7 @@ impl WebhdfsBackend {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=LISTSTATUS",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
         );
         if let Some(auth) = &self.auth {
@@ -442,7 +551,7 @@ impl WebhdfsBackend {
         let req = Request::get(&url)
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
-        self.client.send(req).await
+        self.send(req).await
     }
 
     pub async fn webhdfs_list_status_batch_request(
@@ -454,7 +563,7 @@ impl WebhdfsBackend {
 
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=LISTSTATUS_BATCH",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
         );
         if !start_after.is_empty() {
@@ -467,7 +576,7 @@ impl WebhdfsBackend {
         let req = Request::get(&url)
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
-        self.client.send(req).await
+        self.send(req).await
     }
 
     pub async fn webhdfs_read_file(
@@ -476,14 +585,14 @@ impl WebhdfsBackend {
         range: BytesRange,
     ) -> Result<Response<HttpBody>> {
         let req = self.webhdfs_open_request(path, &range).await?;
-        self.client.fetch(req).await
+        self.fetch(req).await
     }
 
     pub(super) async fn webhdfs_get_file_status(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=GETFILESTATUS",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
         );
 
@@ -495,14 +604,40 @@ impl WebhdfsBackend {
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
 
-        self.client.send(req).await
+        self.send(req).await
+    }
+
+    pub async fn webhdfs_get_file_block_locations(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=GETFILEBLOCKLOCATIONS&offset={}",
+            self.endpoint(),
+            percent_encode_path(&p),
+            range.offset(),
+        );
+        if let Some(size) = range.size() {
+            url += &format!("&length={size}");
+        }
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.send(req).await
     }
 
     pub async fn webhdfs_delete(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=DELETE&recursive=false",
-            self.endpoint,
+            self.endpoint(),
             percent_encode_path(&p),
         );
         if let Some(auth) = &self.auth {
@@ -513,7 +648,7 @@ impl WebhdfsBackend {
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
 
-        self.client.send(req).await
+        self.send(req).await
     }
 
     async fn check_root(&self) -> Result<()> {
@@ -562,12 +697,19 @@ impl Access for WebhdfsBackend {
                 write: true,
                 write_can_append: true,
                 write_can_multi: self.atomic_write_dir.is_some(),
+                write_with_block_size: true,
+                write_with_replication: true,
+                write_with_permission: true,
 
                 create_dir: true,
                 delete: true,
 
                 list: true,
 
+                concat: true,
+
+                locate: true,
+
                 ..Default::default()
             });
         am.into()
@@ -577,7 +719,7 @@ impl Access for WebhdfsBackend {
     async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
         let req = self.webhdfs_create_dir_request(path)?;
 
-        let resp = self.client.send(req).await?;
+        let resp = self.send(req).await?;
 
         let status = resp.status();
 
@@ -644,7 +786,8 @@ impl Access for WebhdfsBackend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                Ok((RpRead::default(), resp.into_body()))
+                let rp = parse_into_rp_read(resp.headers())?;
+                Ok((rp, resp.into_body()))
             }
             _ => {
                 let (part, mut body) = resp.into_parts();
@@ -679,6 +822,63 @@ impl Access for WebhdfsBackend {
         }
     }
 
+    /// HDFS's `CONCAT` moves all sources into the first one, which will be
+    /// renamed to the path later. So sources will be removed after concat.
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let Some((first, rest)) = args.sources().split_first() else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "concat requires at least one source",
+            ));
+        };
+
+        if !rest.is_empty() {
+            let req = self.webhdfs_concat_request(first, rest.to_vec())?;
+            let resp = self.send(req).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+        }
+
+        if first == path {
+            return Ok(RpConcat::default());
+        }
+
+        // RENAME will fail if the path exists, delete it first.
+        let resp = self.webhdfs_delete(path).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let resp = self.webhdfs_rename_object(first, path).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpConcat::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn locate(&self, path: &str, args: OpLocate) -> Result<RpLocate> {
+        let resp = self
+            .webhdfs_get_file_block_locations(path, args.range())
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body();
+                let locations = serde_json::from_reader::<_, BlockLocationsWrapper>(bs.reader())
+                    .map_err(new_json_deserialize_error)?
+                    .block_locations
+                    .block_location
+                    .into_iter()
+                    .map(|v| crate::BlockLocation::new(v.offset, v.length, v.hosts))
+                    .collect();
+
+                Ok(RpLocate::new(locations))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         if args.recursive() {
             return Err(Error::new(
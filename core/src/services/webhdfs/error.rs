@@ -24,6 +24,9 @@ use serde::Deserialize;
 use crate::raw::*;
 use crate::*;
 
+/// The exception returned by namenodes in standby state.
+const STANDBY_EXCEPTION: &str = "StandbyException";
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 struct WebHdfsErrorWrapper {
@@ -47,8 +50,25 @@ pub(super) async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
     parse_error_msg(parts, &s)
 }
 
+/// Check if the error body is a `StandbyException` returned by standby namenodes.
+pub(super) fn is_standby_error(body: &Buffer) -> bool {
+    serde_json::from_reader::<_, WebHdfsErrorWrapper>(body.clone().reader())
+        .map(|v| v.remote_exception.exception == STANDBY_EXCEPTION)
+        .unwrap_or_default()
+}
+
 pub(super) fn parse_error_msg(parts: Parts, body: &str) -> Result<Error> {
+    let wh_error = serde_json::from_str::<WebHdfsErrorWrapper>(body).ok();
+
     let (kind, retryable) = match parts.status {
+        // Standby namenodes could become active later, retry is worth.
+        StatusCode::FORBIDDEN
+            if wh_error
+                .as_ref()
+                .is_some_and(|v| v.remote_exception.exception == STANDBY_EXCEPTION) =>
+        {
+            (ErrorKind::Unexpected, true)
+        }
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
         StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
         // passing invalid arguments will return BAD_REQUEST
@@ -61,9 +81,9 @@ pub(super) fn parse_error_msg(parts: Parts, body: &str) -> Result<Error> {
         _ => (ErrorKind::Unexpected, false),
     };
 
-    let message = match serde_json::from_str::<WebHdfsErrorWrapper>(body) {
-        Ok(wh_error) => format!("{:?}", wh_error.remote_exception),
-        Err(_) => body.to_owned(),
+    let message = match wh_error {
+        Some(wh_error) => format!("{:?}", wh_error.remote_exception),
+        None => body.to_owned(),
     };
 
     let mut err = Error::new(kind, message);
@@ -124,4 +144,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_parse_standby_error() -> Result<()> {
+        let standby = bytes::Bytes::from(
+            r#"
+{
+  "RemoteException":
+  {
+    "exception"    : "StandbyException",
+    "javaClassName": "org.apache.hadoop.ipc.StandbyException",
+    "message"      : "Operation category READ is not supported in state standby"
+  }
+}
+    "#,
+        );
+        let body = Buffer::from(standby);
+        assert!(is_standby_error(&body));
+        assert!(!is_standby_error(&Buffer::from("forbidden")));
+
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(body)
+            .unwrap();
+        let err = parse_error(resp).await?;
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(err.is_temporary());
+
+        Ok(())
+    }
 }
@@ -80,6 +80,27 @@ pub enum FileStatusType {
     File,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct BlockLocationsWrapper {
+    pub block_locations: BlockLocations,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct BlockLocations {
+    #[serde(default)]
+    pub block_location: Vec<BlockLocation>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BlockLocation {
+    pub offset: u64,
+    pub length: u64,
+    pub hosts: Vec<String>,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -246,4 +267,51 @@ mod test {
             "bazfile"
         );
     }
+
+    /// Example from https://hadoop.apache.org/docs/stable/hadoop-project-dist/hadoop-hdfs/WebHDFS.html#Get_File_Block_Locations
+    #[test]
+    fn test_block_locations() {
+        let json = r#"
+{
+  "BlockLocations" :
+    {
+      "BlockLocation":
+        [
+          {
+            "cachedHosts" : [],
+            "corrupt" : false,
+            "hosts" : ["hadoop-01", "hadoop-02"],
+            "length" : 134217728,
+            "names" : ["10.0.0.1:9866", "10.0.0.2:9866"],
+            "offset" : 0,
+            "storageTypes" : ["DISK", "DISK"],
+            "topologyPaths" : ["/default-rack/10.0.0.1:9866", "/default-rack/10.0.0.2:9866"]
+          },
+          {
+            "cachedHosts" : [],
+            "corrupt" : false,
+            "hosts" : ["hadoop-03"],
+            "length" : 62599364,
+            "names" : ["10.0.0.3:9866"],
+            "offset" : 134217728,
+            "storageTypes" : ["DISK"],
+            "topologyPaths" : ["/default-rack/10.0.0.3:9866"]
+          }
+        ]
+    }
+}
+        "#;
+
+        let locations = serde_json::from_str::<BlockLocationsWrapper>(json)
+            .expect("must success")
+            .block_locations
+            .block_location;
+
+        assert_eq!(locations.len(), 2);
+        assert_eq!(locations[0].offset, 0);
+        assert_eq!(locations[0].length, 134217728);
+        assert_eq!(locations[0].hosts, vec!["hadoop-01", "hadoop-02"]);
+        assert_eq!(locations[1].offset, 134217728);
+        assert_eq!(locations[1].hosts, vec!["hadoop-03"]);
+    }
 }
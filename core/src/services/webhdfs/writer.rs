@@ -46,7 +46,7 @@ impl oio::BlockWrite for WebhdfsWriter {
             .webhdfs_create_object_request(&self.path, Some(size), &self.op, body)
             .await?;
 
-        let resp = self.backend.client.send(req).await?;
+        let resp = self.backend.send(req).await?;
 
         let status = resp.status();
         match status {
@@ -72,7 +72,7 @@ impl oio::BlockWrite for WebhdfsWriter {
             )
             .await?;
 
-        let resp = self.backend.client.send(req).await?;
+        let resp = self.backend.send(req).await?;
 
         let status = resp.status();
         match status {
@@ -99,7 +99,7 @@ impl oio::BlockWrite for WebhdfsWriter {
                 .backend
                 .webhdfs_concat_request(&first_block_id, sources)?;
 
-            let resp = self.backend.client.send(req).await?;
+            let resp = self.backend.send(req).await?;
 
             let status = resp.status();
 
@@ -162,7 +162,7 @@ impl oio::AppendWrite for WebhdfsWriter {
                     .webhdfs_create_object_request(&self.path, None, &self.op, Buffer::new())
                     .await?;
 
-                let resp = self.backend.client.send(req).await?;
+                let resp = self.backend.send(req).await?;
 
                 let status = resp.status();
 
@@ -181,7 +181,7 @@ impl oio::AppendWrite for WebhdfsWriter {
             .webhdfs_append_request(&location, size, body)
             .await?;
 
-        let resp = self.backend.client.send(req).await?;
+        let resp = self.backend.send(req).await?;
 
         let status = resp.status();
         match status {
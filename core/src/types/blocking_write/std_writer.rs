@@ -39,7 +39,7 @@ impl StdWriter {
     pub(crate) fn new(w: WriteGenerator<oio::BlockingWriter>) -> Self {
         StdWriter {
             w: Some(w),
-            buf: oio::FlexBuf::new(256 * 1024),
+            buf: oio::FlexBuf::with_pool(IO_WRITE_BUF_SIZE, IO_WRITE_BUF_POOL.clone()),
         }
     }
 
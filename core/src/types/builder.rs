@@ -120,6 +120,35 @@ impl Builder for () {
 ///     Ok(())
 /// }
 /// ```
+///
+/// All configurations implement `Serialize` and `Deserialize`, so they can be embedded in
+/// the application's own config files directly. Fields that are not set will use their
+/// default values.
+///
+/// ```
+/// # use anyhow::Result;
+/// use serde::Deserialize;
+///
+/// use opendal::services::MemoryConfig;
+/// use opendal::Operator;
+///
+/// #[derive(Deserialize)]
+/// struct AppConfig {
+///     name: String,
+///     storage: MemoryConfig,
+/// }
+///
+/// async fn test() -> Result<()> {
+///     let cfg: AppConfig = serde_json::from_str(
+///         r#"{"name": "demo", "storage": {"root": "/path/to/dir"}}"#,
+///     )?;
+///
+///     // Build an `Operator` to start operating the storage.
+///     let op: Operator = Operator::from_config(cfg.storage)?.finish();
+///
+///     Ok(())
+/// }
+/// ```
 pub trait Configurator: Serialize + DeserializeOwned + Debug + 'static {
     /// Associated builder for this configuration.
     type Builder: Builder;
@@ -135,6 +164,15 @@ pub trait Configurator: Serialize + DeserializeOwned + Debug + 'static {
         })
     }
 
+    /// Deserialize from a parsed uri.
+    ///
+    /// The default implementation fills `root` from the uri path and rejects uri
+    /// with name. Services that have a bucket like concept should override it to
+    /// fill the name into their own config.
+    fn from_uri(uri: &OperatorUri) -> Result<Self> {
+        Self::from_iter(uri.to_config_map(None)?)
+    }
+
     /// Convert this configuration into a service builder.
     fn into_builder(self) -> Self::Builder;
 }
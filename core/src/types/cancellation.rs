@@ -0,0 +1,137 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::pin::pin;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use tokio::sync::Notify;
+
+/// CancellationToken is used to cancel operations cooperatively.
+///
+/// Carry the token in [`OpContext`][crate::raw::OpContext] or via the `cancellation`
+/// option of operations like [`Operator::read_with`][crate::Operator::read_with], and
+/// call [`CancellationToken::cancel`] to abort them. In-flight requests will be
+/// dropped, and all following calls will fail.
+///
+/// CancellationToken is cheap to clone, all clones share the same state.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::CancellationToken;
+/// use opendal::Operator;
+///
+/// # async fn test(op: Operator) -> Result<()> {
+/// let token = CancellationToken::new();
+///
+/// let cloned = token.clone();
+/// tokio::spawn(async move { cloned.cancel() });
+///
+/// let res = op.read_with("path/to/file").cancellation(token).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancellationTokenInner>,
+}
+
+#[derive(Default)]
+struct CancellationTokenInner {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// Create a new token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all operations that carry this token.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Release);
+        self.inner.notify.notify_waiters();
+    }
+
+    /// Check if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Wait until this token has been cancelled.
+    pub async fn cancelled(&self) {
+        loop {
+            let mut notified = pin!(self.inner.notify.notified());
+            // Register the waiter before checking state, so that we won't miss
+            // the notification between them.
+            notified.as_mut().enable();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl Debug for CancellationToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Tokens are equal if they share the same state.
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for CancellationToken {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+
+        let cloned = token.clone();
+        let handle = tokio::spawn(async move { cloned.cancelled().await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        token.cancel();
+
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("waiter must be notified")
+            .unwrap();
+        assert!(token.is_cancelled());
+        // Wait on a cancelled token should return immediately.
+        token.cancelled().await;
+    }
+}
@@ -46,6 +46,35 @@ use std::fmt::Debug;
 /// Users can use full_capability to decide what operations can be used and use native_capability to
 /// decide if this operation optimized or not.
 ///
+/// # Runtime Introspection
+///
+/// Applications can adapt their behavior at runtime based on the capability. For example:
+///
+/// ```
+/// # use opendal::Operator;
+/// # use opendal::Result;
+/// # async fn test(op: Operator) -> Result<()> {
+/// let cap = op.info().full_capability();
+///
+/// // Pick a chunk size that satisfies the multipart limits of service.
+/// let chunk = cap.write_multi_min_size.unwrap_or(8 * 1024 * 1024).max(8 * 1024 * 1024);
+///
+/// // Skip extra stat if list already returns content length.
+/// let need_stat = !cap.list_has_content_length;
+///
+/// // Use native copy instead of read and write.
+/// let native_copy = op.info().native_capability().copy;
+///
+/// // Avoid overwriting existing files if the service supports it.
+/// if cap.write_with_if_none_match {
+///     op.write_with("path/to/file", vec![0; 4096])
+///         .if_none_match("*")
+///         .await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
 /// # Naming Style
 ///
 /// - Operation itself should be in lower case, like `read`, `write`.
@@ -53,6 +82,7 @@ use std::fmt::Debug;
 /// - Operation with variants should be named like `read_can_seek`.
 /// - Operation with arguments should be named like `read_with_range`.
 /// - Operation with limitations should be named like `batch_max_operations`.
+/// - Operation with result properties should be named like `list_has_etag`.
 #[derive(Copy, Clone, Default)]
 pub struct Capability {
     /// If operator supports stat.
@@ -74,6 +104,8 @@ pub struct Capability {
     pub read_with_if_match: bool,
     /// If operator supports read with if none match.
     pub read_with_if_none_match: bool,
+    /// If operator supports read with if generation match.
+    pub read_with_if_generation_match: bool,
     /// if operator supports read with override cache control.
     pub read_with_override_cache_control: bool,
     /// if operator supports read with override content disposition.
@@ -97,6 +129,22 @@ pub struct Capability {
     pub write_with_cache_control: bool,
     /// If operator supports write with user defined metadata
     pub write_with_user_metadata: bool,
+    /// If operator supports write with if match.
+    pub write_with_if_match: bool,
+    /// If operator supports write with if none match.
+    pub write_with_if_none_match: bool,
+    /// If operator supports write with if generation match.
+    pub write_with_if_generation_match: bool,
+    /// If operator supports write with block size.
+    pub write_with_block_size: bool,
+    /// If operator supports write with replication factor.
+    pub write_with_replication: bool,
+    /// If operator supports write with permission.
+    pub write_with_permission: bool,
+    /// If operator supports write with time to live.
+    pub write_with_ttl: bool,
+    /// If operator supports write with canned acl.
+    pub write_with_acl: bool,
     /// write_multi_max_size is the max size that services support in write_multi.
     ///
     /// For example, AWS S3 supports 5GiB as max in write_multi.
@@ -105,6 +153,11 @@ pub struct Capability {
     ///
     /// For example, AWS S3 requires at least 5MiB in write_multi expect the last one.
     pub write_multi_min_size: Option<usize>,
+    /// write_multi_max_parts is the max number of parts that services support in write_multi.
+    ///
+    /// For example, AWS S3 supports at most 10000 parts in write_multi. OpenDAL will scale
+    /// the chunk size while writing to avoid hitting this limit.
+    pub write_multi_max_parts: Option<usize>,
     /// write_multi_align_size is the align size that services required in write_multi.
     ///
     /// For example, Google GCS requires align size to 256KiB in write_multi.
@@ -112,6 +165,8 @@ pub struct Capability {
     /// write_total_max_size is the max size that services support in write_total.
     ///
     /// For example, Cloudflare D1 supports 1MB as max in write_total.
+    ///
+    /// Writers will return an error once the written size exceeds this limit.
     pub write_total_max_size: Option<usize>,
 
     /// If operator supports create dir.
@@ -119,6 +174,8 @@ pub struct Capability {
 
     /// If operator supports delete.
     pub delete: bool,
+    /// If operator supports delete with if generation match.
+    pub delete_with_if_generation_match: bool,
 
     /// If operator supports copy.
     pub copy: bool,
@@ -134,6 +191,20 @@ pub struct Capability {
     pub list_with_start_after: bool,
     /// If backend supports list with recursive.
     pub list_with_recursive: bool,
+    /// If entries returned by list are sorted in lexicographical order of their paths.
+    ///
+    /// Lister with `sorted(true)` will sort entries client-side if this is `false`.
+    pub list_sorted: bool,
+    /// If entries returned by list have content length.
+    ///
+    /// Users can use this to decide whether an extra `stat` is needed.
+    pub list_has_content_length: bool,
+    /// If entries returned by list have content type.
+    pub list_has_content_type: bool,
+    /// If entries returned by list have etag.
+    pub list_has_etag: bool,
+    /// If entries returned by list have last modified.
+    pub list_has_last_modified: bool,
 
     /// If operator supports presign.
     pub presign: bool,
@@ -148,9 +219,61 @@ pub struct Capability {
     pub batch: bool,
     /// If operator supports batch delete.
     pub batch_delete: bool,
+    /// If operator supports batch stat.
+    pub batch_stat: bool,
     /// The max operations that operator supports in batch.
     pub batch_max_operations: Option<usize>,
 
+    /// If operator supports listing in-progress multipart uploads.
+    pub list_multipart_uploads: bool,
+    /// If operator supports aborting an in-progress multipart upload.
+    pub abort_multipart_upload: bool,
+
+    /// If operator supports staging blocks with user-controlled block ids.
+    pub stage_block: bool,
+    /// If operator supports committing staged blocks into a file.
+    pub commit_block_list: bool,
+
+    /// If operator supports writing at given offset of a file.
+    pub write_at: bool,
+    /// write_at_align_size is the align size of offset and length that services
+    /// required in write_at.
+    ///
+    /// For example, Azure page blobs require align size to 512B in write_at.
+    pub write_at_align_size: Option<usize>,
+
+    /// If operator supports allocating a fixed-size file filled with zeros.
+    pub allocate: bool,
+    /// allocate_align_size is the align size of file size that services
+    /// required in allocate.
+    ///
+    /// For example, Azure page blobs require size to be aligned to 512B.
+    pub allocate_align_size: Option<usize>,
+
+    /// If operator supports concatenating files into a new one at server side.
+    pub concat: bool,
+    /// The max number of sources that services support in concat.
+    ///
+    /// For example, GCS supports at most 32 source objects in compose.
+    pub concat_max_sources: Option<usize>,
+
+    /// If operator supports locating the blocks of a file.
+    pub locate: bool,
+
+    /// If operator supports getting and setting bucket lifecycle rules.
+    pub lifecycle: bool,
+    /// If operator supports getting and setting object retention and legal hold.
+    pub retention: bool,
+    /// If operator supports getting and setting canned acl.
+    pub acl: bool,
+
+    /// If operator supports creating the bucket it's configured with.
+    pub create_bucket: bool,
+    /// If operator supports deleting the bucket it's configured with.
+    pub delete_bucket: bool,
+    /// If operator supports listing all buckets of the account.
+    pub list_buckets: bool,
+
     /// If operator supports blocking.
     pub blocking: bool,
 }
@@ -183,6 +306,9 @@ impl Debug for Capability {
         if self.list {
             s.push("List");
         }
+        if self.concat {
+            s.push("Concat");
+        }
         if self.presign {
             s.push("Presign");
         }
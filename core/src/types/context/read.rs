@@ -35,8 +35,18 @@ pub struct ReadContext {
 
 impl ReadContext {
     /// Create a new ReadContext.
+    ///
+    /// Sequential access pattern will always prefetch at least one chunk.
     #[inline]
     pub fn new(acc: Accessor, path: String, args: OpRead, options: OpReader) -> Self {
+        let options = match options.access() {
+            AccessPattern::Sequential => {
+                let prefetch = options.prefetch().max(1);
+                options.with_prefetch(prefetch)
+            }
+            _ => options,
+        };
+
         Self {
             acc,
             path,
@@ -82,6 +92,7 @@ impl ReadContext {
 pub struct ReadGenerator {
     ctx: Arc<ReadContext>,
 
+    chunk: Option<usize>,
     offset: u64,
     end: u64,
 }
@@ -91,56 +102,64 @@ impl ReadGenerator {
     #[inline]
     pub fn new(ctx: Arc<ReadContext>, range: Range<u64>) -> Self {
         Self {
+            chunk: ctx.options().chunk(),
             ctx,
             offset: range.start,
             end: range.end,
         }
     }
 
+    /// Override the chunk size of this generator.
+    #[inline]
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk.max(1));
+        self
+    }
+
     /// Generate next reader.
     pub async fn next_reader(&mut self) -> Result<Option<oio::Reader>> {
-        if self.offset >= self.end {
+        let Some(range) = self.next_range() else {
             return Ok(None);
-        }
+        };
 
-        let offset = self.offset;
-        let mut size = (self.end - self.offset) as usize;
-        if let Some(chunk) = self.ctx.options.chunk() {
-            size = size.min(chunk)
-        }
-
-        // Update self.offset before building future.
-        self.offset += size as u64;
-        let args = self
-            .ctx
-            .args
-            .clone()
-            .with_range(BytesRange::new(offset, Some(size as u64)));
+        let args = self.ctx.args.clone().with_range(range);
         let (_, r) = self.ctx.acc.read(&self.ctx.path, args).await?;
         Ok(Some(r))
     }
 
     /// Generate next blocking reader.
     pub fn next_blocking_reader(&mut self) -> Result<Option<oio::BlockingReader>> {
-        if self.offset >= self.end {
+        let Some(range) = self.next_range() else {
             return Ok(None);
+        };
+
+        let args = self.ctx.args.clone().with_range(range);
+        let (_, r) = self.ctx.acc.blocking_read(&self.ctx.path, args)?;
+        Ok(Some(r))
+    }
+
+    /// Generate the range of next reader.
+    ///
+    /// `end` is `u64::MAX` if the size is unknown, we will read until EOF in
+    /// one request since chunks after EOF can't be detected.
+    fn next_range(&mut self) -> Option<BytesRange> {
+        if self.offset >= self.end {
+            return None;
         }
 
         let offset = self.offset;
-        let mut size = (self.end - self.offset) as usize;
-        if let Some(chunk) = self.ctx.options.chunk() {
-            size = size.min(chunk)
+        if self.end == u64::MAX {
+            self.offset = u64::MAX;
+            return Some(BytesRange::new(offset, None));
         }
 
-        // Update self.offset before building future.
-        self.offset += size as u64;
-        let args = self
-            .ctx
-            .args
-            .clone()
-            .with_range(BytesRange::new(offset, Some(size as u64)));
-        let (_, r) = self.ctx.acc.blocking_read(&self.ctx.path, args)?;
-        Ok(Some(r))
+        let mut size = self.end - self.offset;
+        if let Some(chunk) = self.chunk {
+            size = size.min(chunk as u64)
+        }
+
+        self.offset += size;
+        Some(BytesRange::new(offset, Some(size)))
     }
 }
 
@@ -177,6 +196,31 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_next_reader_with_unknown_size() -> Result<()> {
+        let op = Operator::via_iter(Scheme::Memory, [])?;
+        op.write("test", "HelloWorld").await?;
+
+        let acc = op.into_inner();
+        let ctx = Arc::new(ReadContext::new(
+            acc,
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new().with_chunk(3),
+        ));
+        let mut generator = ReadGenerator::new(ctx, 2..u64::MAX);
+        let mut readers = vec![];
+        while let Some(r) = generator.next_reader().await? {
+            readers.push(r);
+        }
+
+        // Chunks are ignored since we can't know where the content ends.
+        pretty_assertions::assert_eq!(readers.len(), 1);
+        let bs = oio::Read::read_all(&mut readers[0]).await?;
+        pretty_assertions::assert_eq!(bs.to_bytes(), "lloWorld");
+        Ok(())
+    }
+
     #[test]
     fn test_next_blocking_reader() -> Result<()> {
         let op = Operator::via_iter(Scheme::Memory, [])?;
@@ -201,4 +245,30 @@ mod tests {
         pretty_assertions::assert_eq!(readers.len(), 4);
         Ok(())
     }
+
+    #[test]
+    fn test_sequential_access_options() -> Result<()> {
+        let op = Operator::via_iter(Scheme::Memory, [])?;
+        let acc = op.into_inner();
+
+        let ctx = ReadContext::new(
+            acc.clone(),
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new().with_access(AccessPattern::Sequential),
+        );
+        assert_eq!(ctx.options().prefetch(), 1);
+
+        let ctx = ReadContext::new(
+            acc,
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new()
+                .with_access(AccessPattern::Sequential)
+                .with_prefetch(4),
+        );
+        assert_eq!(ctx.options().prefetch(), 4);
+
+        Ok(())
+    }
 }
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::mem;
 use std::sync::Arc;
 
 use crate::raw::oio::Write;
@@ -75,7 +76,8 @@ impl WriteContext {
     fn calculate_chunk_size(&self) -> (Option<usize>, bool) {
         let cap = self.accessor().info().full_capability();
 
-        let exact = self.options().chunk().is_some();
+        // Services that require aligned parts must be written in exact chunks.
+        let exact = self.options().chunk().is_some() || cap.write_multi_align_size.is_some();
         let chunk_size = self
             .options()
             .chunk()
@@ -87,12 +89,62 @@ impl WriteContext {
                 if let Some(v) = cap.write_multi_min_size {
                     size = size.max(v);
                 }
+                if let Some(v) = cap.write_multi_align_size {
+                    size = size.div_ceil(v) * v;
+                }
 
                 size
             });
 
         (chunk_size, exact)
     }
+
+    /// Build the chunk scaler for this write process.
+    ///
+    /// Returns `None` if the service doesn't limit the number of parts.
+    fn chunk_scaler(&self, chunk_size: Option<usize>) -> Option<ChunkScaler> {
+        let cap = self.accessor().info().full_capability();
+
+        let chunk_size = chunk_size?;
+        let max_parts = cap.write_multi_max_parts?;
+        Some(ChunkScaler::new(
+            chunk_size,
+            max_parts,
+            cap.write_multi_max_size.unwrap_or(usize::MAX),
+        ))
+    }
+}
+
+/// ChunkScaler grows the chunk size while more parts have been written.
+///
+/// The chunk size will be doubled every `max_parts / 10` parts until reaching
+/// `max_size`, so that we can write about `1023 * chunk_size * max_parts / 10`
+/// bytes before hitting the parts limit. Small writes are not affected.
+#[derive(Debug, Clone, Copy)]
+struct ChunkScaler {
+    base: usize,
+    max_size: usize,
+    step: usize,
+    parts: usize,
+}
+
+impl ChunkScaler {
+    fn new(base: usize, max_parts: usize, max_size: usize) -> Self {
+        Self {
+            base,
+            max_size: max_size.max(base),
+            step: (max_parts / 10).max(1),
+            parts: 0,
+        }
+    }
+
+    /// Record a written part and return the chunk size for the next part.
+    fn next(&mut self) -> usize {
+        self.parts += 1;
+
+        let shift = (self.parts / self.step).min(usize::BITS as usize - 1) as u32;
+        self.base.saturating_mul(1 << shift).min(self.max_size)
+    }
 }
 
 pub struct WriteGenerator<W> {
@@ -103,20 +155,56 @@ pub struct WriteGenerator<W> {
     /// If `exact` is true, the size of the data written to the underlying storage is
     /// exactly `chunk_size` bytes.
     exact: bool,
+    /// The scaler to grow `chunk_size` for services that limit the number of parts.
+    scaler: Option<ChunkScaler>,
     buffer: oio::QueueBuf,
+
+    /// The max size that can be written, we will return error if exceeded.
+    max_size: Option<u64>,
+    /// The size that has been accepted by this writer.
+    written: u64,
+}
+
+impl<W> WriteGenerator<W> {
+    /// Check if the given size can be written without exceeding the limit of service.
+    fn check_size(&self, size: usize) -> Result<()> {
+        match self.max_size {
+            Some(max) if self.written + size as u64 > max => Err(Error::new(
+                ErrorKind::Unsupported,
+                "write size exceeds the max size that service supports",
+            )
+            .with_context("written", self.written)
+            .with_context("size", size)
+            .with_context("max_size", max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Update the chunk size after a chunk has been written.
+    fn on_chunk_written(&mut self) {
+        if let Some(scaler) = self.scaler.as_mut() {
+            self.chunk_size = Some(scaler.next());
+        }
+    }
 }
 
 impl WriteGenerator<oio::Writer> {
     /// Create a new exact buf writer.
     pub async fn create(ctx: Arc<WriteContext>) -> Result<Self> {
         let (chunk_size, exact) = ctx.calculate_chunk_size();
+        let scaler = ctx.chunk_scaler(chunk_size);
+        let max_size = Limits::from(ctx.accessor().info().full_capability()).max_write_size();
         let (_, w) = ctx.acc.write(ctx.path(), ctx.args().clone()).await?;
+        let w: oio::Writer = Box::new(AbortOnDropWriter::new(w, ctx.args().executor().cloned()));
 
         Ok(Self {
             w,
             chunk_size,
             exact,
+            scaler,
             buffer: oio::QueueBuf::new(),
+            max_size,
+            written: 0,
         })
     }
 
@@ -127,14 +215,33 @@ impl WriteGenerator<oio::Writer> {
             w,
             chunk_size,
             exact,
+            scaler: None,
             buffer: oio::QueueBuf::new(),
+            max_size: None,
+            written: 0,
         }
     }
+
+    /// Enable chunk scaling for easier testing.
+    #[cfg(test)]
+    fn with_scaler(mut self, max_parts: usize, max_size: usize) -> Self {
+        self.scaler = self
+            .chunk_size
+            .map(|v| ChunkScaler::new(v, max_parts, max_size));
+        self
+    }
 }
 
 impl WriteGenerator<oio::Writer> {
     /// Write the entire buffer into writer.
-    pub async fn write(&mut self, mut bs: Buffer) -> Result<usize> {
+    pub async fn write(&mut self, bs: Buffer) -> Result<usize> {
+        self.check_size(bs.len())?;
+        let n = self.write_inner(bs).await?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    async fn write_inner(&mut self, mut bs: Buffer) -> Result<usize> {
         let Some(chunk_size) = self.chunk_size else {
             let size = bs.len();
             self.w.write_dyn(bs).await?;
@@ -157,6 +264,7 @@ impl WriteGenerator<oio::Writer> {
             self.buffer.push(bs);
             let buf = self.buffer.take().collect();
             self.w.write_dyn(buf).await?;
+            self.on_chunk_written();
             return Ok(fill_size);
         }
 
@@ -169,6 +277,7 @@ impl WriteGenerator<oio::Writer> {
         if self.buffer.len() >= chunk_size {
             let buf = self.buffer.take().collect();
             self.w.write_dyn(buf).await?;
+            self.on_chunk_written();
         }
 
         // Condition
@@ -204,24 +313,103 @@ impl WriteGenerator<oio::Writer> {
     }
 }
 
+/// AbortOnDropWriter aborts the inner writer in background if it's dropped after
+/// written but before closed or aborted, so that uploaded parts can be cleaned up.
+///
+/// The abort will be executed by given executor, or the current tokio runtime if
+/// no executor is given.
+struct AbortOnDropWriter {
+    inner: oio::Writer,
+    executor: Option<Executor>,
+    /// dirty is true if data has been written but the writer is not closed or aborted.
+    dirty: bool,
+}
+
+impl AbortOnDropWriter {
+    fn new(inner: oio::Writer, executor: Option<Executor>) -> Self {
+        Self {
+            inner,
+            executor,
+            dirty: false,
+        }
+    }
+}
+
+impl oio::Write for AbortOnDropWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        self.dirty = true;
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        // Users will handle the result of abort by themselves.
+        self.dirty = false;
+        self.inner.abort().await
+    }
+}
+
+impl Drop for AbortOnDropWriter {
+    fn drop(&mut self) {
+        if !self.dirty {
+            return;
+        }
+
+        let mut w: oio::Writer = mem::replace(&mut self.inner, Box::new(()));
+        let fut = async move {
+            if let Err(err) = w.abort().await {
+                log::warn!("abort writer dropped before closed failed: {err}");
+            }
+        };
+
+        if let Some(executor) = self.executor.take() {
+            executor.into_inner().execute(Box::pin(fut));
+            return;
+        }
+        #[cfg(all(feature = "executors-tokio", not(target_arch = "wasm32")))]
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(fut);
+            return;
+        }
+        log::warn!("writer dropped before closed without executor, written data may be left");
+    }
+}
+
 impl WriteGenerator<oio::BlockingWriter> {
     /// Create a new exact buf writer.
     pub fn blocking_create(ctx: Arc<WriteContext>) -> Result<Self> {
         let (chunk_size, exact) = ctx.calculate_chunk_size();
+        let scaler = ctx.chunk_scaler(chunk_size);
+        let max_size = Limits::from(ctx.accessor().info().full_capability()).max_write_size();
         let (_, w) = ctx.acc.blocking_write(ctx.path(), ctx.args().clone())?;
 
         Ok(Self {
             w,
             chunk_size,
             exact,
+            scaler,
             buffer: oio::QueueBuf::new(),
+            max_size,
+            written: 0,
         })
     }
 }
 
 impl WriteGenerator<oio::BlockingWriter> {
     /// Write the entire buffer into writer.
-    pub fn write(&mut self, mut bs: Buffer) -> Result<usize> {
+    pub fn write(&mut self, bs: Buffer) -> Result<usize> {
+        self.check_size(bs.len())?;
+        let n = self.write_inner(bs)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn write_inner(&mut self, mut bs: Buffer) -> Result<usize> {
         let Some(chunk_size) = self.chunk_size else {
             let size = bs.len();
             self.w.write(bs)?;
@@ -244,6 +432,7 @@ impl WriteGenerator<oio::BlockingWriter> {
             self.buffer.push(bs);
             let buf = self.buffer.take().collect();
             self.w.write(buf)?;
+            self.on_chunk_written();
             return Ok(fill_size);
         }
 
@@ -256,6 +445,7 @@ impl WriteGenerator<oio::BlockingWriter> {
         if self.buffer.len() >= chunk_size {
             let buf = self.buffer.take().collect();
             self.w.write(buf)?;
+            self.on_chunk_written();
         }
 
         // Condition
@@ -519,4 +709,132 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_chunk_scaler() {
+        let mut scaler = ChunkScaler::new(5, 100, 40);
+
+        let sizes: Vec<_> = (0..50).map(|_| scaler.next()).collect();
+        assert_eq!(&sizes[..9], &[5; 9]);
+        assert_eq!(&sizes[9..19], &[10; 10]);
+        assert_eq!(&sizes[19..29], &[20; 10]);
+        // The chunk size will not exceed the max size.
+        assert_eq!(&sizes[29..], &[40; 21]);
+    }
+
+    struct PartsRecordWriter {
+        parts: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl Write for PartsRecordWriter {
+        async fn write(&mut self, bs: Buffer) -> Result<()> {
+            self.parts.lock().await.push(bs.len());
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exact_buf_writer_scale_chunk() -> Result<()> {
+        let parts = Arc::new(Mutex::new(vec![]));
+        let mut w = WriteGenerator::new(
+            Box::new(PartsRecordWriter {
+                parts: parts.clone(),
+            }),
+            Some(4),
+            true,
+        )
+        .with_scaler(20, 16);
+
+        let mut bs = Bytes::from(vec![0; 200]);
+        while !bs.is_empty() {
+            let n = w.write(bs.clone().into()).await?;
+            bs.advance(n);
+        }
+        w.close().await?;
+
+        let parts = parts.lock().await;
+        assert_eq!(parts.iter().sum::<usize>(), 200);
+        assert_eq!(*parts, [vec![4, 4, 8, 8], vec![16; 11]].concat());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_buf_writer_exceed_max_size() -> Result<()> {
+        let buf = Arc::new(Mutex::new(vec![]));
+        let mut w = WriteGenerator::new(Box::new(MockWriter { buf: buf.clone() }), Some(10), false);
+        w.max_size = Some(16);
+
+        assert_eq!(8, w.write(Buffer::from(vec![0; 8])).await?);
+        assert_eq!(8, w.write(Buffer::from(vec![0; 8])).await?);
+        let err = w.write(Buffer::from(vec![0; 1])).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        w.close().await?;
+        assert_eq!(buf.lock().await.len(), 16);
+        Ok(())
+    }
+
+    struct AbortRecordWriter {
+        aborted: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl Write for AbortRecordWriter {
+        async fn write(&mut self, _: Buffer) -> Result<()> {
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            self.aborted
+                .store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_abort_on_drop() -> Result<()> {
+        use std::sync::atomic::AtomicBool;
+        use std::sync::atomic::Ordering;
+
+        for (write, close, expected) in [
+            (false, false, false),
+            (true, false, true),
+            (true, true, false),
+        ] {
+            let aborted = Arc::new(AtomicBool::new(false));
+            let mut w = AbortOnDropWriter::new(
+                Box::new(AbortRecordWriter {
+                    aborted: aborted.clone(),
+                }),
+                None,
+            );
+            if write {
+                w.write(Buffer::from(vec![1; 8])).await?;
+            }
+            if close {
+                w.close().await?;
+            }
+            drop(w);
+
+            // Wait for the background abort.
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            assert_eq!(
+                aborted.load(Ordering::SeqCst),
+                expected,
+                "write: {write}, close: {close}"
+            );
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,276 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::mem;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// Deleter is designed to delete paths from a stream in an asynchronous manner.
+///
+/// Users can construct Deleter by [`Operator::deleter`] or [`Operator::deleter_with`].
+///
+/// - Paths will be grouped into native batch delete requests if the service supports
+///   batch delete, otherwise they will be deleted one by one.
+/// - At most `concurrent` delete tasks will be running at the same time.
+/// - Every input path will get its own result, failures won't stop the deletion.
+#[derive(Clone)]
+pub struct Deleter {
+    acc: Accessor,
+    use_batch: bool,
+    batch_size: usize,
+    concurrent: usize,
+    context: OpContext,
+}
+
+impl Deleter {
+    pub(crate) fn new(acc: Accessor, args: OpDeleter) -> Self {
+        let use_batch = acc.info().full_capability().batch;
+        let batch_size = if use_batch {
+            let limit = acc.info().full_capability().batch_max_operations;
+            let size = args.batch_size().unwrap_or(DEFAULT_BATCH_SIZE);
+            limit.map_or(size, |limit| size.min(limit)).max(1)
+        } else {
+            1
+        };
+
+        Self {
+            acc,
+            use_batch,
+            batch_size,
+            concurrent: args.concurrent().max(1),
+            context: args.context().clone(),
+        }
+    }
+
+    /// Delete paths from given stream.
+    ///
+    /// The returned [`DeleteStream`] yields the result of every path in the same order
+    /// as input. The input stream will only be polled while the returned stream is polled.
+    pub fn delete_stream<S>(&self, input: S) -> DeleteStream
+    where
+        S: Stream<Item = String> + MaybeSend + Unpin + 'static,
+    {
+        DeleteStream {
+            acc: self.acc.clone(),
+            use_batch: self.use_batch,
+            batch_size: self.batch_size,
+            context: self.context.clone(),
+            input: Some(Box::pin(input)),
+            buf: Vec::with_capacity(self.batch_size),
+            tasks: ConcurrentFutures::new(self.concurrent),
+            results: VecDeque::new(),
+        }
+    }
+
+    /// Delete paths from given iterator.
+    ///
+    /// This is a wrapper of [`Deleter::delete_stream`].
+    pub fn delete_iter<I>(&self, input: I) -> DeleteStream
+    where
+        I: IntoIterator<Item = String>,
+        I::IntoIter: MaybeSend + 'static,
+    {
+        self.delete_stream(futures::stream::iter(input))
+    }
+}
+
+/// The default batch size if users don't specify it.
+const DEFAULT_BATCH_SIZE: usize = 1000;
+
+/// The result of deleting a path.
+type DeleteResult = (String, Result<()>);
+
+/// DeleteStream is the stream returned by [`Deleter::delete_stream`].
+///
+/// - DeleteStream implements `Stream<Item = (String, Result<()>)>`.
+/// - DeleteStream will return `None` after all input paths have been handled.
+pub struct DeleteStream {
+    acc: Accessor,
+    use_batch: bool,
+    batch_size: usize,
+    context: OpContext,
+
+    input: Option<BoxedStaticStream<String>>,
+    /// buf is used to store the paths that waiting for submit.
+    buf: Vec<String>,
+    tasks: ConcurrentFutures<BoxedStaticFuture<Vec<DeleteResult>>>,
+    /// results is used to store the results that haven't been returned.
+    results: VecDeque<DeleteResult>,
+}
+
+/// # Safety
+///
+/// DeleteStream will only be accessed by `&mut Self`
+unsafe impl Sync for DeleteStream {}
+
+impl DeleteStream {
+    fn submit(&mut self) {
+        let paths = mem::replace(&mut self.buf, Vec::with_capacity(self.batch_size));
+        let acc = self.acc.clone();
+        let args = OpDelete::new().with_context(self.context.clone());
+
+        let fut: BoxedStaticFuture<_> = if self.use_batch && paths.len() > 1 {
+            Box::pin(delete_batch(acc, paths, args))
+        } else {
+            Box::pin(delete_one_by_one(acc, paths, args))
+        };
+        self.tasks.push_back(fut);
+    }
+}
+
+async fn delete_batch(acc: Accessor, paths: Vec<String>, args: OpDelete) -> Vec<DeleteResult> {
+    let batch = paths
+        .iter()
+        .map(|path| (path.clone(), args.clone().into()))
+        .collect();
+
+    // OpBatch doesn't carry context, make it current for the http client.
+    let ctx = args.context().clone();
+    match ctx.scope(acc.batch(OpBatch::new(batch))).await {
+        Ok(rp) => rp
+            .into_results()
+            .into_iter()
+            .map(|(path, res)| (path, res.map(|_| ())))
+            .collect(),
+        // The whole batch failed, every path in it should be reported.
+        Err(err) => paths
+            .into_iter()
+            .map(|path| {
+                let mut e = Error::new(err.kind(), err.to_string())
+                    .with_operation("Deleter::delete")
+                    .with_context("path", &path);
+                if err.is_temporary() {
+                    e = e.set_temporary();
+                }
+                (path, Err(e))
+            })
+            .collect(),
+    }
+}
+
+async fn delete_one_by_one(acc: Accessor, paths: Vec<String>, args: OpDelete) -> Vec<DeleteResult> {
+    let mut results = Vec::with_capacity(paths.len());
+    for path in paths {
+        let res = acc.delete(&path, args.clone()).await.map(|_| ());
+        results.push((path, res));
+    }
+    results
+}
+
+impl Stream for DeleteStream {
+    type Item = (String, Result<()>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(res) = this.results.pop_front() {
+                return Poll::Ready(Some(res));
+            }
+
+            // Only pull input while there are free task slots, so that
+            // slow deletions will apply backpressure to the input.
+            while this.tasks.has_remaining() {
+                let Some(input) = this.input.as_mut() else {
+                    break;
+                };
+
+                match input.poll_next_unpin(cx) {
+                    Poll::Ready(Some(path)) => {
+                        this.buf.push(normalize_path(&path));
+                        if this.buf.len() >= this.batch_size {
+                            this.submit();
+                        }
+                    }
+                    Poll::Ready(None) => this.input = None,
+                    Poll::Pending => {
+                        // Don't keep paths waiting for slow input if there is nothing running.
+                        if this.tasks.is_empty() && !this.buf.is_empty() {
+                            this.submit();
+                        }
+                        break;
+                    }
+                }
+            }
+
+            if this.input.is_none() && !this.buf.is_empty() && this.tasks.has_remaining() {
+                this.submit();
+            }
+
+            match this.tasks.poll_next_unpin(cx) {
+                Poll::Ready(Some(results)) => this.results.extend(results),
+                Poll::Ready(None) => {
+                    return if this.input.is_none() && this.buf.is_empty() {
+                        Poll::Ready(None)
+                    } else {
+                        // Input is pending and has registered the waker.
+                        Poll::Pending
+                    };
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+    use crate::services;
+
+    #[tokio::test]
+    async fn test_delete_stream() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        for i in 0..10 {
+            op.write(&format!("dir/{i}"), "hello").await?;
+        }
+
+        let deleter = op.deleter_with().concurrent(4).await?;
+        let results: Vec<_> = deleter
+            .delete_stream(stream::iter((0..10).map(|i| format!("/dir/{i}"))))
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 10);
+        for (i, (path, res)) in results.into_iter().enumerate() {
+            assert_eq!(path, format!("dir/{i}"));
+            assert!(res.is_ok());
+            assert!(!op.is_exist(&path).await?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_iter_empty() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+
+        let deleter = op.deleter().await?;
+        let results: Vec<_> = deleter.delete_iter(Vec::new()).collect().await;
+        assert!(results.is_empty());
+        Ok(())
+    }
+}
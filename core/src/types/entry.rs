@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use flagset::FlagSet;
+
 use crate::raw::*;
 use crate::*;
 
@@ -186,4 +188,81 @@ impl Entry {
     pub fn into_parts(self) -> (String, Metadata) {
         (self.path, self.metadata)
     }
+
+    /// Check if this entry's metadata already contains given metakey.
+    ///
+    /// Metadata returned by list only contains the fields that services
+    /// provide in list response, use this to decide whether a [`Entry::stat`]
+    /// is required before visiting them.
+    pub fn has_metakey(&self, metakey: impl Into<FlagSet<Metakey>>) -> bool {
+        self.metadata.contains_metakey(metakey)
+    }
+
+    /// Check if this entry's metadata contains all metadata that `stat` could return.
+    pub fn is_complete(&self) -> bool {
+        self.metadata.metakey().contains(Metakey::Complete)
+    }
+
+    /// Complete the metadata of this entry via `stat` on demand.
+    ///
+    /// No request will be sent if the metadata is already complete.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Metakey;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for mut entry in op.list("dir/").await? {
+    ///     if !entry.has_metakey(Metakey::ContentLength) {
+    ///         entry.stat(&op).await?;
+    ///     }
+    ///     println!("{}: {}", entry.path(), entry.metadata().content_length());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat(&mut self, op: &Operator) -> Result<&Metadata> {
+        if !self.is_complete() {
+            self.metadata = op.stat(&self.path).await?;
+        }
+
+        Ok(&self.metadata)
+    }
+
+    /// Complete the metadata of this entry via `stat` on demand in blocking way.
+    ///
+    /// No request will be sent if the metadata is already complete.
+    pub fn blocking_stat(&mut self, op: &BlockingOperator) -> Result<&Metadata> {
+        if !self.is_complete() {
+            self.metadata = op.stat(&self.path)?;
+        }
+
+        Ok(&self.metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services;
+
+    #[tokio::test]
+    async fn test_entry_stat() {
+        let op = Operator::new(services::Memory::default())
+            .expect("must init")
+            .finish();
+        op.write("file", "hello").await.expect("write must succeed");
+
+        let mut entry = Entry::new("file".to_string(), Metadata::new(EntryMode::FILE));
+        assert!(entry.has_metakey(Metakey::Mode));
+        assert!(!entry.has_metakey(Metakey::ContentLength));
+        assert!(!entry.is_complete());
+
+        let meta = entry.stat(&op).await.expect("stat must succeed");
+        assert_eq!(meta.content_length(), 5);
+        assert!(entry.is_complete());
+        assert!(entry.has_metakey(Metakey::ContentLength));
+    }
 }
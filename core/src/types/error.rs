@@ -41,6 +41,9 @@ use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::io;
+use std::time::Duration;
+
+use http::StatusCode;
 
 /// Result that is a wrapper of `Result<T, opendal::Error>`
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -86,6 +89,11 @@ pub enum ErrorKind {
     ///
     /// OpenDAL returns this error to indicate that the range of the read request is not satisfied.
     RangeNotSatisfied,
+    /// The operation has been cancelled by the [`CancellationToken`](crate::CancellationToken)
+    /// carried by its context.
+    Cancelled,
+    /// The deadline carried by the context of this operation has been exceeded.
+    DeadlineExceeded,
 }
 
 impl ErrorKind {
@@ -116,6 +124,8 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::IsSameFile => "IsSameFile",
             ErrorKind::ConditionNotMatch => "ConditionNotMatch",
             ErrorKind::RangeNotSatisfied => "RangeNotSatisfied",
+            ErrorKind::Cancelled => "Cancelled",
+            ErrorKind::DeadlineExceeded => "DeadlineExceeded",
         }
     }
 }
@@ -221,6 +231,18 @@ pub struct Error {
     context: Vec<(&'static str, String)>,
     source: Option<anyhow::Error>,
     backtrace: Backtrace,
+
+    http_detail: Option<Box<HttpErrorDetail>>,
+}
+
+/// HttpErrorDetail carries the details returned by http services.
+///
+/// They are boxed to keep [`Error`] small since most errors don't have them.
+#[derive(Default)]
+struct HttpErrorDetail {
+    http_status: Option<StatusCode>,
+    service_code: Option<String>,
+    retry_after: Option<Duration>,
 }
 
 impl Display for Error {
@@ -264,6 +286,15 @@ impl Debug for Error {
             de.field("operation", &self.operation);
             de.field("context", &self.context);
             de.field("source", &self.source);
+            if let Some(v) = self.http_status() {
+                de.field("http_status", &v);
+            }
+            if let Some(v) = self.service_code() {
+                de.field("service_code", &v);
+            }
+            if let Some(v) = self.retry_after() {
+                de.field("retry_after", &v);
+            }
             return de.finish();
         }
 
@@ -315,6 +346,8 @@ impl Error {
             // `Backtrace::capture()` will check if backtrace has been enabled
             // internally. It's zero cost if backtrace is disabled.
             backtrace: Backtrace::capture(),
+
+            http_detail: None,
         }
     }
 
@@ -400,6 +433,86 @@ impl Error {
     pub fn is_temporary(&self) -> bool {
         self.status == ErrorStatus::Temporary
     }
+
+    /// Set the http status code returned by service.
+    pub fn with_http_status(mut self, status: StatusCode) -> Self {
+        self.http_detail
+            .get_or_insert_with(Box::default)
+            .http_status = Some(status);
+        self
+    }
+
+    /// Return the http status code returned by service.
+    ///
+    /// `None` means this error is not caused by a http response.
+    pub fn http_status(&self) -> Option<StatusCode> {
+        self.http_detail.as_ref().and_then(|v| v.http_status)
+    }
+
+    /// Set the error code returned by service, like `NoSuchKey` of s3.
+    pub fn with_service_code(mut self, code: impl Into<String>) -> Self {
+        self.http_detail
+            .get_or_insert_with(Box::default)
+            .service_code = Some(code.into());
+        self
+    }
+
+    /// Return the error code returned by service, like `NoSuchKey` of s3.
+    pub fn service_code(&self) -> Option<&str> {
+        self.http_detail
+            .as_ref()
+            .and_then(|v| v.service_code.as_deref())
+    }
+
+    /// Set the duration that service asks us to wait before retrying.
+    pub fn with_retry_after(mut self, duration: Duration) -> Self {
+        self.http_detail
+            .get_or_insert_with(Box::default)
+            .retry_after = Some(duration);
+        self
+    }
+
+    /// Return the duration that service asks us to wait before retrying,
+    /// which is parsed from the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.http_detail.as_ref().and_then(|v| v.retry_after)
+    }
+
+    /// Return the source error if exists.
+    pub fn source_ref(&self) -> Option<&anyhow::Error> {
+        self.source.as_ref()
+    }
+
+    /// Consume the error and return the source error if exists.
+    pub fn into_source(self) -> Option<anyhow::Error> {
+        self.source
+    }
+
+    /// Find the first error of type `E` in the source error chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use opendal::Error;
+    /// use opendal::ErrorKind;
+    ///
+    /// let err = Error::new(ErrorKind::Unexpected, "read failed")
+    ///     .set_source(io::Error::new(io::ErrorKind::TimedOut, "timed out"));
+    ///
+    /// let io_err = err.downcast_source_ref::<io::Error>().unwrap();
+    /// assert_eq!(io_err.kind(), io::ErrorKind::TimedOut);
+    /// ```
+    pub fn downcast_source_ref<E>(&self) -> Option<&E>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        self.source
+            .as_ref()?
+            .chain()
+            .find_map(|err| err.downcast_ref::<E>())
+    }
 }
 
 impl From<Error> for io::Error {
@@ -433,6 +546,7 @@ mod tests {
         ],
         source: Some(anyhow!("networking error")),
         backtrace: Backtrace::disabled(),
+        http_detail: None,
     });
 
     #[test]
@@ -461,4 +575,31 @@ Source:
 "#
         )
     }
+
+    #[test]
+    fn test_error_fields() {
+        let err = Error::new(ErrorKind::RateLimited, "slow down")
+            .with_http_status(StatusCode::SERVICE_UNAVAILABLE)
+            .with_service_code("SlowDown")
+            .with_retry_after(Duration::from_secs(3));
+
+        assert_eq!(err.http_status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(err.service_code(), Some("SlowDown"));
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn test_downcast_source_ref() {
+        let err = Error::new(ErrorKind::Unexpected, "read failed").set_source(
+            anyhow::Error::new(io::Error::new(io::ErrorKind::TimedOut, "timed out"))
+                .context("sending request"),
+        );
+
+        let io_err = err.downcast_source_ref::<io::Error>().expect("must exist");
+        assert_eq!(io_err.kind(), io::ErrorKind::TimedOut);
+        assert!(err.downcast_source_ref::<fmt::Error>().is_none());
+
+        let err = Error::new(ErrorKind::Unexpected, "no source");
+        assert!(err.downcast_source_ref::<io::Error>().is_none());
+    }
 }
@@ -19,6 +19,7 @@ use std::future::Future;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
 
 use futures::future::RemoteHandle;
 use futures::FutureExt;
@@ -49,6 +50,31 @@ use crate::raw::BoxedStaticFuture;
 /// to the Tokio runtime.
 ///
 /// PLEASE raising an issue if you have a better solution.
+///
+/// # Custom Runtime
+///
+/// Timers used by opendal like [`TimeoutLayer`][crate::layers::TimeoutLayer] are driven by
+/// [`Execute::sleep`]. Users running on other runtimes should implement both `execute` and
+/// `sleep` on their runtime:
+///
+/// ```ignore
+/// use std::time::Duration;
+///
+/// use opendal::raw::BoxedStaticFuture;
+/// use opendal::Execute;
+///
+/// struct AsyncStdExecutor;
+///
+/// impl Execute for AsyncStdExecutor {
+///     fn execute(&self, f: BoxedStaticFuture<()>) {
+///         async_std::task::spawn(f);
+///     }
+///
+///     fn sleep(&self, dur: Duration) -> BoxedStaticFuture<()> {
+///         Box::pin(async_std::task::sleep(dur))
+///     }
+/// }
+/// ```
 pub trait Execute: Send + Sync + 'static {
     /// Execute async task in background.
     ///
@@ -64,12 +90,19 @@ pub trait Execute: Send + Sync + 'static {
     fn timeout(&self) -> Option<BoxedStaticFuture<()>> {
         None
     }
+
+    /// Return a future that will be resolved after the given duration.
+    fn sleep(&self, dur: Duration) -> BoxedStaticFuture<()>;
 }
 
 impl Execute for () {
     fn execute(&self, _: BoxedStaticFuture<()>) {
         panic!("concurrent tasks executed with no executor has been enabled")
     }
+
+    fn sleep(&self, _: Duration) -> BoxedStaticFuture<()> {
+        panic!("sleep with no executor has been enabled")
+    }
 }
 
 /// Task is generated by Executor that represents an executing task.
@@ -19,12 +19,14 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures::FutureExt;
 
 use super::*;
 use crate::raw::BoxedStaticFuture;
 use crate::raw::MaybeSend;
+use crate::raw::OpContext;
 
 /// Executor that runs futures in background.
 ///
@@ -44,6 +46,15 @@ impl Debug for Executor {
     }
 }
 
+/// Executors are equal if they share the same execute impl.
+impl PartialEq for Executor {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.executor, &other.executor)
+    }
+}
+
+impl Eq for Executor {}
+
 impl Default for Executor {
     fn default() -> Self {
         Self::new()
@@ -83,13 +94,20 @@ impl Executor {
         self.executor.timeout()
     }
 
+    /// Return a future that will be resolved after the given duration.
+    pub(crate) fn sleep(&self, dur: Duration) -> BoxedStaticFuture<()> {
+        self.executor.sleep(dur)
+    }
+
     /// Run given future in background immediately.
+    ///
+    /// The future inherits the [`OpContext`] of the running operation.
     pub(crate) fn execute<F>(&self, f: F) -> Task<F::Output>
     where
         F: Future + MaybeSend + 'static,
         F::Output: MaybeSend + 'static,
     {
-        let (fut, handle) = f.remote_handle();
+        let (fut, handle) = OpContext::current().scope(f).remote_handle();
         self.executor.execute(Box::pin(fut));
         Task::new(handle)
     }
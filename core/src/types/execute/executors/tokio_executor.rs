@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::time::Duration;
+
 use crate::raw::BoxedStaticFuture;
 use crate::*;
 
@@ -27,6 +29,10 @@ impl Execute for TokioExecutor {
     fn execute(&self, f: BoxedStaticFuture<()>) {
         let _handle = tokio::task::spawn(f);
     }
+
+    fn sleep(&self, dur: Duration) -> BoxedStaticFuture<()> {
+        Box::pin(tokio::time::sleep(dur))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,117 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::*;
+
+/// Limits of the underlying service that users need to follow while writing.
+///
+/// Limits are collected from the [`Capability`] reported by services, users can get it via
+/// [`OperatorInfo::limits`] to validate the workload before starting a large upload.
+///
+/// ```
+/// # use opendal::Operator;
+/// # use opendal::Result;
+/// # async fn test(op: Operator) -> Result<()> {
+/// let limits = op.info().limits();
+///
+/// // Fail early if this file can't be uploaded to the service.
+/// limits.check_write_size(6 * 1024 * 1024 * 1024 * 1024)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Limits {
+    /// The min size of every part except the last one in multipart writes.
+    pub min_part_size: Option<usize>,
+    /// The max size of every part in multipart writes.
+    pub max_part_size: Option<usize>,
+    /// The max number of parts in multipart writes.
+    pub max_part_count: Option<usize>,
+    /// The max size of an object.
+    pub max_object_size: Option<usize>,
+}
+
+impl Limits {
+    /// Get the max size that can be written into the service, returns `None` if unlimited.
+    pub fn max_write_size(&self) -> Option<u64> {
+        let multi = match (self.max_part_size, self.max_part_count) {
+            (Some(size), Some(count)) => Some((size as u64).saturating_mul(count as u64)),
+            _ => None,
+        };
+        let total = self.max_object_size.map(|v| v as u64);
+
+        match (multi, total) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Check if the data in given size can be written into the service.
+    ///
+    /// Returns [`ErrorKind::Unsupported`] if the size exceeds the limits.
+    pub fn check_write_size(&self, size: u64) -> Result<()> {
+        match self.max_write_size() {
+            Some(max) if size > max => Err(Error::new(
+                ErrorKind::Unsupported,
+                "write size exceeds the max size that service supports",
+            )
+            .with_context("size", size)
+            .with_context("max_size", max)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<Capability> for Limits {
+    fn from(cap: Capability) -> Self {
+        Self {
+            min_part_size: cap.write_multi_min_size,
+            max_part_size: cap.write_multi_max_size,
+            max_part_count: cap.write_multi_max_parts,
+            max_object_size: cap.write_total_max_size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_write_size() {
+        let limits = Limits::default();
+        assert_eq!(limits.max_write_size(), None);
+        assert!(limits.check_write_size(u64::MAX).is_ok());
+
+        let limits = Limits {
+            max_part_size: Some(10),
+            max_part_count: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(limits.max_write_size(), Some(1000));
+        assert!(limits.check_write_size(1000).is_ok());
+        let err = limits.check_write_size(1001).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        let limits = Limits {
+            max_object_size: Some(500),
+            ..limits
+        };
+        assert_eq!(limits.max_write_size(), Some(500));
+        assert!(limits.check_write_size(501).is_err());
+    }
+}
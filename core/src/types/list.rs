@@ -16,15 +16,22 @@
 // under the License.
 
 use std::cmp;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::future::Future;
 use std::pin::Pin;
+use std::str::FromStr;
 use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
 
+use base64::engine::general_purpose;
+use base64::Engine;
 use flagset::FlagSet;
 use futures::Stream;
 use futures::StreamExt;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::raw::*;
 use crate::*;
@@ -38,11 +45,14 @@ use crate::*;
 ///
 /// - Lister implements `Stream<Item = Result<Entry>>`.
 /// - Lister will return `None` if there is no more entries or error has been returned.
+/// - Lister can also be consumed page by page via [`Lister::next_page`].
 pub struct Lister {
     acc: Accessor,
     lister: Option<oio::Lister>,
     /// required_metakey is the metakey required by users.
     required_metakey: FlagSet<Metakey>,
+    /// page_size is the max entries returned by `next_page`.
+    page_size: usize,
 
     fut: Option<BoxedStaticFuture<(oio::Lister, Result<Option<oio::Entry>>)>>,
 
@@ -51,6 +61,8 @@ pub struct Lister {
     /// TODO: maybe we should move logic inside?
     tasks: ConcurrentFutures<StatTask>,
     errored: bool,
+    /// token is the continuation token after the last returned entry.
+    token: ListToken,
 }
 
 /// StatTask is used to store the task that is run in concurrent.
@@ -90,8 +102,8 @@ pub struct Lister {
 /// use opendal::Entry;
 /// use opendal::Result;
 ///
-/// assert_eq!(304, size_of::<(String, Result<opendal::raw::RpStat>)>());
-/// assert_eq!(304, size_of::<Option<Entry>>());
+/// assert_eq!(352, size_of::<(String, Result<opendal::raw::RpStat>)>());
+/// assert_eq!(352, size_of::<Option<Entry>>());
 /// ```
 ///
 /// So let's ignore this lint:
@@ -129,6 +141,8 @@ impl Lister {
     pub(crate) async fn create(acc: Accessor, path: &str, args: OpList) -> Result<Self> {
         let required_metakey = args.metakey();
         let concurrent = cmp::max(1, args.concurrent());
+        let page_size = args.limit().unwrap_or(DEFAULT_PAGE_SIZE).max(1);
+        let token = ListToken::new(path, args.recursive());
 
         let (_, lister) = acc.list(path, args).await?;
 
@@ -136,12 +150,105 @@ impl Lister {
             acc,
             lister: Some(lister),
             required_metakey,
+            page_size,
 
             fut: None,
             tasks: ConcurrentFutures::new(concurrent),
             errored: false,
+            token,
         })
     }
+
+    /// Stop listing before the given path.
+    pub(crate) fn set_end_before(&mut self, end_before: Option<String>) {
+        self.token.end_before = end_before;
+    }
+
+    /// Get the continuation token after the last returned entry.
+    ///
+    /// The token can be sent to another process or machine to continue the
+    /// listing via [`Operator::lister_from`].
+    pub fn continuation_token(&self) -> ListToken {
+        self.token.clone()
+    }
+
+    /// Fetch the next page of entries.
+    ///
+    /// Every page contains at most `limit` entries (or 1000 entries if `limit` is not
+    /// set) and a continuation token, returns `None` if there are no more entries.
+    ///
+    /// # Notes
+    ///
+    /// The continuation token is the path of the last entry in this page. Users can
+    /// store it as a checkpoint, and resume the listing by passing the token to
+    /// [`FutureLister::start_after`]. This only works for services that support
+    /// `list_with_start_after`, which return entries in lexicographical order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut lister = op.lister_with("dir/").limit(100).await?;
+    /// while let Some(page) = lister.next_page().await? {
+    ///     for entry in page.entries() {
+    ///         println!("{}", entry.path());
+    ///     }
+    ///     // Save the checkpoint to resume later.
+    ///     let _token = page.token();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn next_page(&mut self) -> Result<Option<ListPage>> {
+        let mut entries = Vec::with_capacity(self.page_size);
+        while entries.len() < self.page_size {
+            match self.next().await {
+                Some(entry) => entries.push(entry?),
+                None => break,
+            }
+        }
+
+        Ok(ListPage::new(entries))
+    }
+}
+
+/// The default page size of [`Lister::next_page`] and [`BlockingLister::next_page`].
+const DEFAULT_PAGE_SIZE: usize = 1000;
+
+/// ListPage is a page of entries returned by [`Lister::next_page`] and
+/// [`BlockingLister::next_page`].
+#[derive(Debug, Clone)]
+pub struct ListPage {
+    entries: Vec<Entry>,
+    token: String,
+}
+
+impl ListPage {
+    /// Build a new page, returns `None` if there is no entries.
+    fn new(entries: Vec<Entry>) -> Option<Self> {
+        let token = entries.last()?.path().to_string();
+        Some(Self { entries, token })
+    }
+
+    /// Entries of this page.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Consume this page into entries.
+    pub fn into_entries(self) -> Vec<Entry> {
+        self.entries
+    }
+
+    /// The continuation token of this page, which is the path of the last entry.
+    ///
+    /// Pass it to `start_after` to list entries after this page.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
 }
 
 impl Stream for Lister {
@@ -172,7 +279,10 @@ impl Stream for Lister {
                     match entry {
                         Ok(Some(oe)) => {
                             let (path, metadata) = oe.into_entry().into_parts();
-                            if metadata.contains_metakey(self.required_metakey) {
+                            if self.token.is_beyond_end(&path) {
+                                // Stop fetching once we reached the end of range.
+                                self.lister = None;
+                            } else if metadata.contains_metakey(self.required_metakey) {
                                 self.tasks
                                     .push_back(StatTask::Known(Some((path, metadata))));
                             } else {
@@ -199,6 +309,7 @@ impl Stream for Lister {
         // Try to poll tasks
         if let Some((path, rp)) = ready!(self.tasks.poll_next_unpin(cx)) {
             let metadata = rp?;
+            self.token.start_after = Some(path.clone());
             return Poll::Ready(Some(Ok(Entry::new(path, metadata))));
         }
 
@@ -222,8 +333,13 @@ pub struct BlockingLister {
     /// required_metakey is the metakey required by users.
     required_metakey: FlagSet<Metakey>,
 
+    /// page_size is the max entries returned by `next_page`.
+    page_size: usize,
+
     lister: oio::BlockingLister,
     errored: bool,
+    /// token is the continuation token after the last returned entry.
+    token: ListToken,
 }
 
 /// # Safety
@@ -235,16 +351,47 @@ impl BlockingLister {
     /// Create a new lister.
     pub(crate) fn create(acc: Accessor, path: &str, args: OpList) -> Result<Self> {
         let required_metakey = args.metakey();
+        let page_size = args.limit().unwrap_or(DEFAULT_PAGE_SIZE).max(1);
+        let token = ListToken::new(path, args.recursive());
         let (_, lister) = acc.blocking_list(path, args)?;
 
         Ok(Self {
             acc,
             required_metakey,
+            page_size,
 
             lister,
             errored: false,
+            token,
         })
     }
+
+    /// Stop listing before the given path.
+    pub(crate) fn set_end_before(&mut self, end_before: Option<String>) {
+        self.token.end_before = end_before;
+    }
+
+    /// Get the continuation token after the last returned entry.
+    ///
+    /// Refer to [`Lister::continuation_token`] for more details.
+    pub fn continuation_token(&self) -> ListToken {
+        self.token.clone()
+    }
+
+    /// Fetch the next page of entries.
+    ///
+    /// Refer to [`Lister::next_page`] for more details.
+    pub fn next_page(&mut self) -> Result<Option<ListPage>> {
+        let mut entries = Vec::with_capacity(self.page_size);
+        while entries.len() < self.page_size {
+            match self.next() {
+                Some(entry) => entries.push(entry?),
+                None => break,
+            }
+        }
+
+        Ok(ListPage::new(entries))
+    }
 }
 
 /// TODO: we can implement next_chunk.
@@ -267,21 +414,181 @@ impl Iterator for BlockingLister {
         };
 
         let (path, metadata) = entry.into_entry().into_parts();
-        if metadata.contains_metakey(self.required_metakey) {
-            return Some(Ok(Entry::new(path, metadata)));
+        if self.token.is_beyond_end(&path) {
+            // Stop listing once we reached the end of range.
+            self.errored = true;
+            return None;
         }
-
-        let metadata = match self.acc.blocking_stat(&path, OpStat::default()) {
-            Ok(rp) => rp.into_metadata(),
-            Err(err) => {
-                self.errored = true;
-                return Some(Err(err));
+        let metadata = if metadata.contains_metakey(self.required_metakey) {
+            metadata
+        } else {
+            match self.acc.blocking_stat(&path, OpStat::default()) {
+                Ok(rp) => rp.into_metadata(),
+                Err(err) => {
+                    self.errored = true;
+                    return Some(Err(err));
+                }
             }
         };
+
+        self.token.start_after = Some(path.clone());
         Some(Ok(Entry::new(path, metadata)))
     }
 }
 
+/// ListToken is an opaque continuation token of a listing.
+///
+/// It can be serialized via [`Display`] (or serde) and sent to another process
+/// or machine to continue the listing via [`Operator::lister_from`], which
+/// enables checkpointing and sharded scanning of huge buckets.
+///
+/// Continuing a listing requires the service to support `list_with_start_after`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use anyhow::Result;
+/// use futures::TryStreamExt;
+/// use opendal::ListToken;
+/// use opendal::Operator;
+///
+/// # async fn test(op: Operator) -> Result<()> {
+/// let mut lister = op.lister_with("dir/").recursive(true).await?;
+/// let _ = lister.try_next().await?;
+/// let token = lister.continuation_token().to_string();
+///
+/// // Send the token to another machine and continue listing there.
+/// let token: ListToken = token.parse()?;
+/// let mut lister = op.lister_from(&token).await?;
+/// while let Some(entry) = lister.try_next().await? {
+///     println!("{}", entry.path());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListToken {
+    path: String,
+    recursive: bool,
+    start_after: Option<String>,
+    end_before: Option<String>,
+}
+
+impl ListToken {
+    /// Create a new token that lists `path` from the beginning.
+    ///
+    /// Users can build tokens with different `start_after` to split a huge
+    /// listing into shards.
+    pub fn new(path: &str, recursive: bool) -> Self {
+        Self {
+            path: normalize_path(path),
+            recursive,
+            start_after: None,
+            end_before: None,
+        }
+    }
+
+    /// Split the listing of `prefix` into at most `n` tokens with disjoint key
+    /// ranges, which can be listed in parallel without overlap.
+    ///
+    /// # Notes
+    ///
+    /// The key space is split by the first char after `prefix` evenly over
+    /// printable ASCII chars, so at most 95 tokens will be returned. Keys are
+    /// not sampled, please use a larger `n` than workers if keys are skewed.
+    pub fn partitions(prefix: &str, n: usize, recursive: bool) -> Vec<ListToken> {
+        const FIRST: u8 = 0x20;
+        const LAST: u8 = 0x7e;
+
+        let prefix = normalize_path(prefix);
+        let prefix = if prefix == "/" { "" } else { &prefix };
+        let n = n.clamp(1, (LAST - FIRST + 1) as usize);
+
+        // The first char of every partition except the first one.
+        let bounds: Vec<String> = (1..n)
+            .map(|i| {
+                let c = FIRST as usize + i * (LAST - FIRST + 1) as usize / n;
+                format!("{prefix}{}", c as u8 as char)
+            })
+            .collect();
+
+        (0..n)
+            .map(|i| {
+                let mut token = ListToken::new(prefix, recursive);
+                if i > 0 {
+                    // The largest key before `bounds[i - 1]`.
+                    let mut start_after = bounds[i - 1].clone();
+                    let c = start_after.pop().expect("bound must not be empty");
+                    start_after.push((c as u8 - 1) as char);
+                    start_after.push(char::MAX);
+                    token.start_after = Some(start_after);
+                }
+                if i < n - 1 {
+                    token.end_before = Some(bounds[i].clone());
+                }
+                token
+            })
+            .collect()
+    }
+
+    /// Continue listing after the given path.
+    pub fn with_start_after(mut self, start_after: &str) -> Self {
+        self.start_after = Some(start_after.to_string());
+        self
+    }
+
+    /// The path to list.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Whether the listing is recursive.
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// The path of the last returned entry, `None` means the listing starts
+    /// from the beginning.
+    pub fn start_after(&self) -> Option<&str> {
+        self.start_after.as_deref()
+    }
+
+    /// Stop listing before the given path.
+    pub fn with_end_before(mut self, end_before: &str) -> Self {
+        self.end_before = Some(end_before.to_string());
+        self
+    }
+
+    /// The path that listing stops before, `None` means listing to the end.
+    pub fn end_before(&self) -> Option<&str> {
+        self.end_before.as_deref()
+    }
+
+    fn is_beyond_end(&self, path: &str) -> bool {
+        matches!(&self.end_before, Some(end) if path >= end.as_str())
+    }
+}
+
+impl Display for ListToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let bs = serde_json::to_vec(self).map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", general_purpose::URL_SAFE_NO_PAD.encode(bs))
+    }
+}
+
+impl FromStr for ListToken {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let bs = general_purpose::URL_SAFE_NO_PAD.decode(s).map_err(|err| {
+            Error::new(ErrorKind::ConfigInvalid, "list token is invalid").set_source(err)
+        })?;
+        serde_json::from_slice(&bs).map_err(|err| {
+            Error::new(ErrorKind::ConfigInvalid, "list token is invalid").set_source(err)
+        })
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "services-azblob")]
 mod tests {
@@ -48,6 +48,8 @@ pub struct Metadata {
     last_modified: Option<DateTime<Utc>>,
     version: Option<String>,
     user_metadata: Option<HashMap<String, String>>,
+    symlink_target: Option<String>,
+    request_id: Option<String>,
 }
 
 impl Metadata {
@@ -75,6 +77,8 @@ impl Metadata {
             content_disposition: None,
             version: None,
             user_metadata: None,
+            symlink_target: None,
+            request_id: None,
         }
     }
 
@@ -193,6 +197,11 @@ impl Metadata {
         self.content_length.unwrap_or_default()
     }
 
+    /// Content length of this entry, `None` if the service doesn't return it.
+    pub(crate) fn content_length_opt(&self) -> Option<u64> {
+        self.content_length
+    }
+
     /// Set content length of this entry.
     pub fn set_content_length(&mut self, v: u64) -> &mut Self {
         self.content_length = Some(v);
@@ -526,6 +535,54 @@ impl Metadata {
         self.metakey |= Metakey::UserMetaData;
         self
     }
+
+    /// Check if this entry is a symlink.
+    ///
+    /// This value is only available for services that surface symlinks instead of
+    /// following them, like fs with symlink policy `surface`. The mode of symlinks
+    /// will be [`EntryMode::Unknown`].
+    pub fn is_symlink(&self) -> bool {
+        self.symlink_target.is_some()
+    }
+
+    /// Target of this entry if it's a symlink.
+    pub fn symlink_target(&self) -> Option<&str> {
+        self.symlink_target.as_deref()
+    }
+
+    /// Set symlink target of this entry.
+    pub fn with_symlink_target(mut self, v: String) -> Self {
+        self.symlink_target = Some(v);
+        self
+    }
+
+    /// Set symlink target of this entry.
+    pub fn set_symlink_target(&mut self, v: &str) -> &mut Self {
+        self.symlink_target = Some(v.to_string());
+        self
+    }
+
+    /// Request ID returned by the service while fetching this metadata.
+    ///
+    /// Services like s3 return a request id like `x-amz-request-id` for every request,
+    /// users can quote it while asking for support.
+    ///
+    /// `None` means the service doesn't return a request id.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// Set request id of this entry.
+    pub fn with_request_id(mut self, v: String) -> Self {
+        self.request_id = Some(v);
+        self
+    }
+
+    /// Set request id of this entry.
+    pub fn set_request_id(&mut self, v: &str) -> &mut Self {
+        self.request_id = Some(v.to_string());
+        self
+    }
 }
 
 flags! {
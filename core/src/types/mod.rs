@@ -42,18 +42,48 @@ pub use blocking_write::*;
 
 mod list;
 pub use list::BlockingLister;
+pub use list::ListPage;
+pub use list::ListToken;
 pub use list::Lister;
 
+mod delete;
+pub use delete::DeleteStream;
+pub use delete::Deleter;
+
 mod execute;
 pub use execute::*;
 
+mod cancellation;
+pub use cancellation::CancellationToken;
+
 mod operator;
 pub use operator::operator_functions;
 pub use operator::operator_futures;
+pub use operator::Acl;
+pub use operator::BlockLocation;
 pub use operator::BlockingOperator;
+pub use operator::CheckReport;
+pub use operator::CheckStep;
+pub use operator::CheckStrategy;
+#[cfg(feature = "content-addressable")]
+pub use operator::ContentDigest;
+#[cfg(feature = "content-addressable")]
+pub use operator::ContentStore;
+pub use operator::CopyConflict;
+pub use operator::CopyDirReport;
+pub use operator::JoinReport;
+pub use operator::LifecycleRule;
+pub use operator::MultipartUpload;
+pub use operator::ObjectRetention;
 pub use operator::Operator;
 pub use operator::OperatorBuilder;
+pub use operator::OperatorFactory;
 pub use operator::OperatorInfo;
+pub use operator::OperatorRegistry;
+pub use operator::OperatorUri;
+pub use operator::RemoveAllReport;
+pub use operator::Retention;
+pub use operator::RetentionMode;
 
 mod builder;
 pub use builder::Builder;
@@ -70,5 +100,8 @@ pub use scheme::Scheme;
 mod capability;
 pub use capability::Capability;
 
+mod limits;
+pub use limits::Limits;
+
 mod context;
 pub(crate) use context::*;
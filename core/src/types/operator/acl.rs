@@ -0,0 +1,54 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// Acl is the canned access control of an object.
+///
+/// Services map it to their own predefined ACLs, `Unsupported` will be
+/// returned if there is no match.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acl {
+    /// Only the owner has access.
+    Private,
+    /// The owner has full control and everyone can read.
+    PublicRead,
+    /// Everyone can read and write.
+    PublicReadWrite,
+    /// The owner has full control and authenticated users can read.
+    AuthenticatedRead,
+    /// The object owner has full control and the bucket owner can read.
+    BucketOwnerRead,
+    /// Both the object owner and the bucket owner have full control.
+    BucketOwnerFullControl,
+}
+
+/// Display formats acl in the canned ACL names used by S3, like `public-read`.
+impl Display for Acl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Acl::Private => write!(f, "private"),
+            Acl::PublicRead => write!(f, "public-read"),
+            Acl::PublicReadWrite => write!(f, "public-read-write"),
+            Acl::AuthenticatedRead => write!(f, "authenticated-read"),
+            Acl::BucketOwnerRead => write!(f, "bucket-owner-read"),
+            Acl::BucketOwnerFullControl => write!(f, "bucket-owner-full-control"),
+        }
+    }
+}
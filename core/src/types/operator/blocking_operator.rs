@@ -1170,6 +1170,42 @@ impl BlockingOperator {
             |inner, path, args| BlockingLister::create(inner, &path, args),
         ))
     }
+
+    /// Continue a listing from the given [`ListToken`].
+    ///
+    /// Refer to [`Operator::lister_from`] for more details.
+    pub fn lister_from(&self, token: &ListToken) -> Result<BlockingLister> {
+        let f = self.lister_with(token.path()).recursive(token.recursive());
+
+        let mut lister = match token.start_after() {
+            None => f.call()?,
+            Some(start_after) => {
+                if !self.info().full_capability().list_with_start_after {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "service doesn't support list with start after",
+                    )
+                    .with_operation("BlockingOperator::lister_from")
+                    .with_context("service", self.info().scheme()));
+                }
+                f.start_after(start_after).call()?
+            }
+        };
+        lister.set_end_before(token.end_before().map(|v| v.to_string()));
+
+        Ok(lister)
+    }
+
+    /// Split the recursive listing of `prefix` into `n` listers with disjoint
+    /// key ranges.
+    ///
+    /// Refer to [`Operator::list_partitions`] for more details.
+    pub fn list_partitions(&self, prefix: &str, n: usize) -> Result<Vec<BlockingLister>> {
+        ListToken::partitions(prefix, n, true)
+            .iter()
+            .map(|token| self.lister_from(token))
+            .collect()
+    }
 }
 
 impl From<BlockingOperator> for Operator {
@@ -138,6 +138,9 @@ impl Operator {
     ///
     /// `via_iter` generates a `Operator` which allows building operator without generic type.
     ///
+    /// [`Scheme::Custom`] is supported if it has been registered via
+    /// [`OperatorRegistry::register_global`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -168,12 +171,16 @@ impl Operator {
         let op = match scheme {
             #[cfg(feature = "services-aliyun-drive")]
             Scheme::AliyunDrive => Self::from_iter::<services::AliyunDrive>(iter)?.finish(),
+            #[cfg(feature = "services-artifactory")]
+            Scheme::Artifactory => Self::from_iter::<services::Artifactory>(iter)?.finish(),
             #[cfg(feature = "services-atomicserver")]
             Scheme::Atomicserver => Self::from_iter::<services::Atomicserver>(iter)?.finish(),
             #[cfg(feature = "services-alluxio")]
             Scheme::Alluxio => Self::from_iter::<services::Alluxio>(iter)?.finish(),
             #[cfg(feature = "services-compfs")]
             Scheme::Compfs => Self::from_iter::<services::Compfs>(iter)?.finish(),
+            #[cfg(feature = "services-upstash")]
+            Scheme::Upstash => Self::from_iter::<services::Upstash>(iter)?.finish(),
             #[cfg(feature = "services-upyun")]
             Scheme::Upyun => Self::from_iter::<services::Upyun>(iter)?.finish(),
             #[cfg(feature = "services-koofr")]
@@ -202,6 +209,8 @@ impl Operator {
             Scheme::Dashmap => Self::from_iter::<services::Dashmap>(iter)?.finish(),
             #[cfg(feature = "services-dropbox")]
             Scheme::Dropbox => Self::from_iter::<services::Dropbox>(iter)?.finish(),
+            #[cfg(feature = "services-dynamodb")]
+            Scheme::Dynamodb => Self::from_iter::<services::Dynamodb>(iter)?.finish(),
             #[cfg(feature = "services-etcd")]
             Scheme::Etcd => Self::from_iter::<services::Etcd>(iter)?.finish(),
             #[cfg(feature = "services-foundationdb")]
@@ -238,6 +247,8 @@ impl Operator {
             Scheme::Memory => Self::from_iter::<services::Memory>(iter)?.finish(),
             #[cfg(feature = "services-mini-moka")]
             Scheme::MiniMoka => Self::from_iter::<services::MiniMoka>(iter)?.finish(),
+            #[cfg(feature = "services-mock")]
+            Scheme::Mock => Self::from_iter::<services::Mock>(iter)?.finish(),
             #[cfg(feature = "services-moka")]
             Scheme::Moka => Self::from_iter::<services::Moka>(iter)?.finish(),
             #[cfg(feature = "services-monoiofs")]
@@ -284,19 +295,24 @@ impl Operator {
             Scheme::Webdav => Self::from_iter::<services::Webdav>(iter)?.finish(),
             #[cfg(feature = "services-webhdfs")]
             Scheme::Webhdfs => Self::from_iter::<services::Webhdfs>(iter)?.finish(),
+            #[cfg(feature = "services-rados")]
+            Scheme::Rados => Self::from_iter::<services::Rados>(iter)?.finish(),
             #[cfg(feature = "services-redb")]
             Scheme::Redb => Self::from_iter::<services::Redb>(iter)?.finish(),
             #[cfg(feature = "services-mongodb")]
             Scheme::Mongodb => Self::from_iter::<services::Mongodb>(iter)?.finish(),
             #[cfg(feature = "services-hdfs-native")]
             Scheme::HdfsNative => Self::from_iter::<services::HdfsNative>(iter)?.finish(),
-            v => {
-                return Err(Error::new(
-                    ErrorKind::Unsupported,
-                    "scheme is not enabled or supported",
-                )
-                .with_context("scheme", v))
-            }
+            v => match OperatorRegistry::global_factory(v.into_static()) {
+                Some(factory) => factory(&OperatorUri::from_options(v.into_static(), iter))?,
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "scheme is not enabled or supported",
+                    )
+                    .with_context("scheme", v))
+                }
+            },
         };
 
         Ok(op)
@@ -410,6 +426,37 @@ impl Operator {
             TypeEraseLayer.layer(layer.layer(self.into_inner())),
         ))
     }
+
+    /// Apply layers declared by [`LayerConfig`] in order, the first layer will be the innermost one.
+    ///
+    /// See [`LayerConfig`] for examples.
+    pub fn with_layers(self, layers: impl IntoIterator<Item = LayerConfig>) -> Result<Self> {
+        layers
+            .into_iter()
+            .try_fold(self, |op, layer| layer.apply(op))
+    }
+
+    /// Create a new operator scoped into the given sub dir, see [`SubdirLayer`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::services::Memory;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test() -> Result<()> {
+    /// let op = Operator::new(Memory::default())?.finish();
+    /// let tenant = op.subdir("tenant/a");
+    /// // Writes to `tenant/a/hello.txt` of `op`.
+    /// tenant.write("hello.txt", "hello").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn subdir(&self, dir: &str) -> Self {
+        self.clone().layer(SubdirLayer::new(dir))
+    }
 }
 
 /// OperatorBuilder is a typed builder to build an Operator.
@@ -468,6 +515,7 @@ impl<A: Access> OperatorBuilder<A> {
         OperatorBuilder { accessor }
             .layer(ErrorContextLayer)
             .layer(CompleteLayer)
+            .layer(CancellationLayer)
     }
 
     /// Create a new layer with static dispatch.
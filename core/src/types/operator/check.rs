@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::time::Duration;
+
+use crate::*;
+
+/// CheckStrategy decides how [`Operator::check_with`] probes the service.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckStrategy {
+    /// Pick [`CheckStrategy::List`] if the service supports list, otherwise
+    /// fallback to [`CheckStrategy::Stat`].
+    #[default]
+    Auto,
+    /// Send a `list` request to the root and fetch the first entry.
+    ///
+    /// This is the same as [`Operator::check`].
+    List,
+    /// Send a `stat` request to a random path that doesn't exist.
+    ///
+    /// `NotFound` is considered as success since the service has been
+    /// reached and accepted our credentials.
+    Stat,
+    /// Write a small probe file, stat it and delete it.
+    ///
+    /// This is the most expensive strategy but it verifies that current
+    /// credential has the write permission.
+    Write,
+}
+
+impl Display for CheckStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckStrategy::Auto => write!(f, "auto"),
+            CheckStrategy::List => write!(f, "list"),
+            CheckStrategy::Stat => write!(f, "stat"),
+            CheckStrategy::Write => write!(f, "write"),
+        }
+    }
+}
+
+/// CheckReport is the diagnostics returned by [`Operator::check_with`].
+#[derive(Debug, Clone)]
+pub struct CheckReport {
+    pub(crate) scheme: Scheme,
+    pub(crate) root: String,
+    pub(crate) name: String,
+    pub(crate) strategy: CheckStrategy,
+    pub(crate) steps: Vec<CheckStep>,
+}
+
+impl CheckReport {
+    /// Scheme of the checked operator.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Root of the checked operator.
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    /// Name of the checked operator, like bucket name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The strategy that actually used.
+    ///
+    /// [`CheckStrategy::Auto`] will be resolved into a concrete strategy.
+    pub fn strategy(&self) -> CheckStrategy {
+        self.strategy
+    }
+
+    /// All steps that have been executed during check.
+    pub fn steps(&self) -> &[CheckStep] {
+        &self.steps
+    }
+
+    /// Total time spent during check.
+    pub fn elapsed(&self) -> Duration {
+        self.steps.iter().map(|v| v.elapsed).sum()
+    }
+}
+
+/// CheckStep is a single request sent while checking.
+#[derive(Debug, Clone)]
+pub struct CheckStep {
+    pub(crate) operation: &'static str,
+    pub(crate) path: String,
+    pub(crate) elapsed: Duration,
+}
+
+impl CheckStep {
+    /// The operation of this step, like `list`, `stat`.
+    pub fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    /// The path that this step operated on.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Time spent on this step.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
@@ -0,0 +1,221 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::*;
+
+/// ContentDigest is the sha256 digest of the content stored in [`ContentStore`].
+///
+/// It's displayed as `sha256:<hex>` and can be parsed from the same format or
+/// from the bare hex string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContentDigest([u8; 32]);
+
+impl ContentDigest {
+    /// Calculate the digest of given content.
+    pub fn of(bs: &Buffer) -> Self {
+        let mut hasher = Sha256::new();
+        for chunk in bs.clone() {
+            hasher.update(&chunk);
+        }
+        Self(hasher.finalize().into())
+    }
+
+    /// Get the raw bytes of this digest.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Format this digest into lower case hex string.
+    pub fn to_hex(&self) -> String {
+        let mut s = String::with_capacity(64);
+        for b in self.0 {
+            write!(s, "{b:02x}").expect("write into string must succeed");
+        }
+        s
+    }
+}
+
+impl Display for ContentDigest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sha256:{}", self.to_hex())
+    }
+}
+
+impl FromStr for ContentDigest {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let hex = s.strip_prefix("sha256:").unwrap_or(s);
+        let invalid = || {
+            Error::new(ErrorKind::Unexpected, "content digest is invalid").with_context("digest", s)
+        };
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+
+        let mut digest = [0; 32];
+        for (i, v) in digest.iter_mut().enumerate() {
+            *v = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(Self(digest))
+    }
+}
+
+/// ContentStore stores objects under the sha256 digest of their content.
+///
+/// Objects are stored at `sha256/<first 2 hex>/<next 2 hex>/<full hex>`, for example
+/// `sha256/2c/f2/2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824`.
+///
+/// - Writing the same content twice only stores it once.
+/// - Reading verifies the content against the digest, and returns an error if the
+///   stored content has been corrupted.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::services::Memory;
+/// use opendal::ContentStore;
+/// use opendal::Operator;
+///
+/// # async fn test() -> Result<()> {
+/// let op = Operator::new(Memory::default())?.finish();
+/// let store = ContentStore::new(op);
+///
+/// let digest = store.write("hello").await?;
+/// assert_eq!(store.read(&digest).await?.to_vec(), b"hello");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContentStore {
+    op: Operator,
+}
+
+impl ContentStore {
+    /// Create a new ContentStore on top of the given operator.
+    pub fn new(op: Operator) -> Self {
+        Self { op }
+    }
+
+    /// Get the operator used by this store.
+    pub fn operator(&self) -> &Operator {
+        &self.op
+    }
+
+    /// Get the path that stores the content of given digest.
+    pub fn path(&self, digest: &ContentDigest) -> String {
+        let hex = digest.to_hex();
+        format!("sha256/{}/{}/{hex}", &hex[..2], &hex[2..4])
+    }
+
+    /// Write the content and return its digest.
+    ///
+    /// The content won't be written again if it already exists.
+    pub async fn write(&self, bs: impl Into<Buffer>) -> Result<ContentDigest> {
+        let bs = bs.into();
+        let digest = ContentDigest::of(&bs);
+        let path = self.path(&digest);
+
+        if !self.op.is_exist(&path).await? {
+            self.op.write(&path, bs).await?;
+        }
+        Ok(digest)
+    }
+
+    /// Read the content of given digest.
+    ///
+    /// Returns [`ErrorKind::NotFound`] if the content doesn't exist, and
+    /// [`ErrorKind::Unexpected`] if the stored content doesn't match the digest.
+    pub async fn read(&self, digest: &ContentDigest) -> Result<Buffer> {
+        let path = self.path(digest);
+        let bs = self.op.read(&path).await?;
+
+        let actual = ContentDigest::of(&bs);
+        if actual != *digest {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "content digest is mismatch")
+                    .with_context("path", path)
+                    .with_context("expected", digest.to_string())
+                    .with_context("actual", actual.to_string()),
+            );
+        }
+        Ok(bs)
+    }
+
+    /// Check whether the content of given digest exists.
+    pub async fn exists(&self, digest: &ContentDigest) -> Result<bool> {
+        self.op.is_exist(&self.path(digest)).await
+    }
+
+    /// Delete the content of given digest.
+    ///
+    /// Contents are shared by all writers of the same bytes, please make sure no one
+    /// depends on it anymore.
+    pub async fn delete(&self, digest: &ContentDigest) -> Result<()> {
+        self.op.delete(&self.path(digest)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[test]
+    fn test_content_digest() {
+        let digest = ContentDigest::of(&Buffer::from("hello"));
+        assert_eq!(
+            digest.to_string(),
+            "sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert_eq!(digest.to_string().parse::<ContentDigest>().unwrap(), digest);
+        assert_eq!(digest.to_hex().parse::<ContentDigest>().unwrap(), digest);
+        assert!("sha256:abc".parse::<ContentDigest>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_content_store() {
+        let op = Operator::new(Memory::default()).unwrap().finish();
+        let store = ContentStore::new(op.clone());
+
+        let digest = store.write("hello").await.unwrap();
+        assert_eq!(
+            store.path(&digest),
+            "sha256/2c/f2/2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert_eq!(store.write("hello").await.unwrap(), digest);
+        assert!(store.exists(&digest).await.unwrap());
+        assert_eq!(store.read(&digest).await.unwrap().to_vec(), b"hello");
+
+        // Corrupted content should be detected.
+        op.write(&store.path(&digest), "world").await.unwrap();
+        let err = store.read(&digest).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+
+        store.delete(&digest).await.unwrap();
+        assert!(!store.exists(&digest).await.unwrap());
+    }
+}
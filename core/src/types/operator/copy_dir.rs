@@ -0,0 +1,176 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use futures::TryStreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// CopyConflict decides what [`Operator::copy_dir_with`] will do if the target
+/// file already exists.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CopyConflict {
+    /// Overwrite the target file, this is the same as [`Operator::copy`].
+    #[default]
+    Overwrite,
+    /// Keep the target file and skip this copy.
+    Skip,
+    /// Return an `AlreadyExists` error and stop copying.
+    Fail,
+}
+
+impl Display for CopyConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CopyConflict::Overwrite => write!(f, "overwrite"),
+            CopyConflict::Skip => write!(f, "skip"),
+            CopyConflict::Fail => write!(f, "fail"),
+        }
+    }
+}
+
+/// CopyDirReport is the result returned by [`Operator::copy_dir_with`].
+#[derive(Debug, Clone, Default)]
+pub struct CopyDirReport {
+    copied: usize,
+    skipped: usize,
+}
+
+impl CopyDirReport {
+    /// The number of files that have been copied.
+    pub fn copied(&self) -> usize {
+        self.copied
+    }
+
+    /// The number of files that have been skipped by [`CopyConflict::Skip`].
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+/// The outcome of copying one entry.
+enum CopyOutcome {
+    Copied,
+    Skipped,
+    /// Dirs are not counted in report.
+    Dir,
+}
+
+/// Copy all entries under `from` into `args.to()` recursively.
+///
+/// `from` and `to` must be normalized dirs.
+pub(crate) async fn copy_dir(
+    acc: Accessor,
+    from: String,
+    args: OpCopyDir,
+) -> Result<CopyDirReport> {
+    let to = args.to().to_string();
+    let new_error = |kind, message| {
+        Error::new(kind, message)
+            .with_operation("Operator::copy_dir")
+            .with_context("service", acc.info().scheme())
+            .with_context("from", &from)
+            .with_context("to", &to)
+    };
+
+    if !validate_path(&from, EntryMode::DIR) {
+        return Err(new_error(
+            ErrorKind::NotADirectory,
+            "from path is not a directory",
+        ));
+    }
+    if !validate_path(&to, EntryMode::DIR) {
+        return Err(new_error(
+            ErrorKind::NotADirectory,
+            "to path is not a directory",
+        ));
+    }
+    if from == to {
+        return Err(new_error(
+            ErrorKind::IsSameFile,
+            "from and to paths are same",
+        ));
+    }
+    // Copy into a nested dir will list the copied files again.
+    if from == "/" || to.starts_with(&from) {
+        return Err(new_error(
+            ErrorKind::Unsupported,
+            "to path is inside from path",
+        ));
+    }
+
+    let op = Operator::from_inner(acc.clone()).with_default_context(args.context().clone());
+    let create_dir = acc.info().full_capability().create_dir;
+    let concurrent = args.concurrent().max(1);
+    let to_prefix = if to == "/" { "" } else { to.as_str() };
+
+    op.lister_with(&from)
+        .recursive(true)
+        .await?
+        .try_filter(|entry| futures::future::ready(entry.path() != from))
+        .map_ok(|entry| {
+            let op = op.clone();
+            let target = format!("{to_prefix}{}", &entry.path()[from.len()..]);
+            copy_entry(op, entry, target, create_dir, args.conflict())
+        })
+        .try_buffer_unordered(concurrent)
+        .try_fold(CopyDirReport::default(), |mut report, outcome| async move {
+            match outcome {
+                CopyOutcome::Copied => report.copied += 1,
+                CopyOutcome::Skipped => report.skipped += 1,
+                CopyOutcome::Dir => {}
+            }
+            Ok(report)
+        })
+        .await
+}
+
+async fn copy_entry(
+    op: Operator,
+    entry: Entry,
+    target: String,
+    create_dir: bool,
+    conflict: CopyConflict,
+) -> Result<CopyOutcome> {
+    if entry.metadata().is_dir() {
+        // Keep empty dirs for services that have real dirs.
+        if create_dir {
+            op.create_dir(&target).await?;
+        }
+        return Ok(CopyOutcome::Dir);
+    }
+
+    if conflict != CopyConflict::Overwrite && op.is_exist(&target).await? {
+        if conflict == CopyConflict::Skip {
+            return Ok(CopyOutcome::Skipped);
+        }
+        return Err(
+            Error::new(ErrorKind::AlreadyExists, "target path already exists")
+                .with_operation("Operator::copy_dir")
+                .with_context("service", op.info().scheme())
+                .with_context("from", entry.path())
+                .with_context("to", target),
+        );
+    }
+
+    op.copy(entry.path(), &target).await?;
+    Ok(CopyOutcome::Copied)
+}
@@ -0,0 +1,312 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use futures::stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// JoinReport is the result returned by [`Operator::join_all`].
+///
+/// Failed paths won't stop the other operations, every path will have its own
+/// result in the same order as the input.
+#[derive(Debug)]
+pub struct JoinReport<T> {
+    results: Vec<(String, Result<T>)>,
+}
+
+impl<T> JoinReport<T> {
+    /// The results of all paths in the same order as the input.
+    pub fn results(&self) -> &[(String, Result<T>)] {
+        &self.results
+    }
+
+    /// Consume the report and return the results of all paths.
+    pub fn into_results(self) -> Vec<(String, Result<T>)> {
+        self.results
+    }
+
+    /// The paths that failed along with their errors.
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &Error)> {
+        self.results
+            .iter()
+            .filter_map(|(path, res)| res.as_ref().err().map(|err| (path.as_str(), err)))
+    }
+
+    /// Return true if all operations have succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.results.iter().all(|(_, res)| res.is_ok())
+    }
+
+    /// Consume the report and return the outputs of all paths, or the first
+    /// error in input order if any operation failed.
+    pub fn into_result(self) -> Result<Vec<(String, T)>> {
+        self.results
+            .into_iter()
+            .map(|(path, res)| res.map(|v| (path, v)))
+            .collect()
+    }
+}
+
+/// Run `f` on every path with at most `concurrent` operations in flight.
+///
+/// All futures are polled by the returned future itself, so dropping it
+/// cancels all the pending operations.
+pub(crate) async fn join_all<T, F, Fut>(
+    op: &Operator,
+    paths: impl IntoIterator<Item = String>,
+    concurrent: usize,
+    f: F,
+) -> JoinReport<T>
+where
+    F: Fn(Operator, String) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let results = stream::iter(paths)
+        .map(|path| {
+            let fut = f(op.clone(), path.clone());
+            async move { (path, fut.await) }
+        })
+        .buffered(concurrent.max(1))
+        .collect()
+        .await;
+
+    JoinReport { results }
+}
+
+/// Stat every path with at most `concurrent` requests in flight.
+///
+/// Services that support `batch_stat` will stat files in batches of
+/// [`Operator::limit`] paths. Dirs, and batches that failed as a whole, will
+/// fall back to stat one by one.
+pub(crate) async fn stat_many(
+    op: &Operator,
+    paths: impl IntoIterator<Item = String>,
+    concurrent: usize,
+) -> JoinReport<Metadata> {
+    if !op.info().full_capability().batch_stat {
+        return join_all(op, paths, concurrent, |op, path| async move {
+            op.stat(&path).await
+        })
+        .await;
+    }
+
+    let results = stream::iter(paths)
+        .chunks(op.limit().max(1))
+        .map(|paths| batch_stat(op, paths, concurrent))
+        .buffered(concurrent.max(1))
+        .flat_map(|results| stream::iter(results.into_results()))
+        .collect()
+        .await;
+
+    JoinReport { results }
+}
+
+async fn batch_stat(op: &Operator, paths: Vec<String>, concurrent: usize) -> JoinReport<Metadata> {
+    let fallback = |paths: Vec<String>| {
+        join_all(op, paths, concurrent, |op, path| async move {
+            op.stat(&path).await
+        })
+    };
+
+    let ops: Vec<(String, BatchOperation)> = paths
+        .iter()
+        .map(|path| {
+            (
+                normalize_path(path),
+                OpStat::new()
+                    .with_context(op.default_context().clone())
+                    .into(),
+            )
+        })
+        .collect();
+    // Stat on dirs has special semantics which can't be batched.
+    if ops.iter().any(|(path, _)| path.ends_with('/')) {
+        return fallback(paths).await;
+    }
+
+    let replies = match op.inner().batch(OpBatch::new(ops)).await {
+        Ok(rp) if rp.results().len() == paths.len() => rp.into_results(),
+        _ => return fallback(paths).await,
+    };
+
+    let results = paths
+        .into_iter()
+        .zip(replies)
+        .map(|(path, (_, res))| {
+            let res = res.and_then(|reply| match reply {
+                BatchedReply::Stat(rp) => Ok(rp.into_metadata()),
+                _ => Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "batch stat returned a reply that is not stat",
+                )),
+            });
+            (path, res)
+        })
+        .collect();
+
+    JoinReport { results }
+}
+
+/// The minimal number of paths sharing the same parent dir to check their
+/// existence by listing the parent dir instead of stat them one by one.
+const EXISTS_LIST_THRESHOLD: usize = 16;
+
+/// Check the existence of every path with at most `concurrent` requests in flight.
+///
+/// Paths are grouped by their parent dir. Groups with at least
+/// [`EXISTS_LIST_THRESHOLD`] paths will be checked by listing the parent dir
+/// once if the service supports `list`, others will be checked via stat.
+pub(crate) async fn exists_many(
+    op: &Operator,
+    paths: impl IntoIterator<Item = String>,
+    concurrent: usize,
+) -> Result<Vec<bool>> {
+    let paths: Vec<String> = paths.into_iter().map(|p| normalize_path(&p)).collect();
+    let mut exists = vec![false; paths.len()];
+
+    let mut groups: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut stat_idxs = Vec::new();
+    for (idx, path) in paths.iter().enumerate() {
+        let parent = get_parent(path);
+        // Root can't be found by listing its parent.
+        if parent == path {
+            stat_idxs.push(idx);
+        } else {
+            groups.entry(parent).or_default().push(idx);
+        }
+    }
+
+    let can_list = op.info().full_capability().list;
+    let mut list_groups = Vec::new();
+    for (parent, idxs) in groups {
+        if can_list && idxs.len() >= EXISTS_LIST_THRESHOLD {
+            list_groups.push((parent, idxs));
+        } else {
+            stat_idxs.extend(idxs);
+        }
+    }
+
+    let listed: Vec<Vec<usize>> = stream::iter(list_groups)
+        .map(|(parent, idxs)| {
+            let paths = &paths;
+            async move {
+                let mut wanted: HashMap<&str, usize> =
+                    idxs.iter().map(|&idx| (paths[idx].as_str(), idx)).collect();
+                let mut found = Vec::with_capacity(wanted.len());
+
+                let mut lister = match op.lister(parent).await {
+                    Ok(lister) => lister,
+                    Err(err) if err.kind() == ErrorKind::NotFound => return Ok(found),
+                    Err(err) => return Err(err),
+                };
+                while let Some(entry) = lister.try_next().await? {
+                    if let Some(idx) = wanted.remove(entry.path()) {
+                        found.push(idx);
+                    }
+                    if wanted.is_empty() {
+                        break;
+                    }
+                }
+                Ok(found)
+            }
+        })
+        .buffer_unordered(concurrent.max(1))
+        .try_collect()
+        .await?;
+    for idx in listed.into_iter().flatten() {
+        exists[idx] = true;
+    }
+
+    let stated: Vec<Option<usize>> = stream::iter(stat_idxs)
+        .map(|idx| {
+            let path = &paths[idx];
+            async move { op.is_exist(path).await.map(|v| v.then_some(idx)) }
+        })
+        .buffer_unordered(concurrent.max(1))
+        .try_collect()
+        .await?;
+    for idx in stated.into_iter().flatten() {
+        exists[idx] = true;
+    }
+
+    Ok(exists)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services;
+
+    #[tokio::test]
+    async fn test_join_all() {
+        let op = Operator::new(services::Memory::default())
+            .expect("must init")
+            .finish();
+        op.write("a", "hello").await.expect("write must succeed");
+        op.write("c", "world").await.expect("write must succeed");
+
+        let paths = ["a", "b", "c"].map(String::from);
+        let report = op.stat_many(paths, 2).await;
+
+        assert!(!report.is_ok());
+        let failed: Vec<_> = report
+            .failed()
+            .map(|(path, err)| (path, err.kind()))
+            .collect();
+        assert_eq!(failed, vec![("b", ErrorKind::NotFound)]);
+
+        let results = report.into_results();
+        assert_eq!(results[0].0, "a");
+        assert_eq!(results[0].1.as_ref().unwrap().content_length(), 5);
+        assert_eq!(results[2].0, "c");
+        assert_eq!(results[2].1.as_ref().unwrap().content_length(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_exists_many() {
+        let op = Operator::new(services::Memory::default())
+            .expect("must init")
+            .finish();
+        for i in (0..EXISTS_LIST_THRESHOLD * 2).step_by(2) {
+            op.write(&format!("dir/{i}"), "hello")
+                .await
+                .expect("write must succeed");
+        }
+        op.write("file", "hello").await.expect("write must succeed");
+
+        let mut paths: Vec<String> = (0..EXISTS_LIST_THRESHOLD * 2)
+            .map(|i| format!("dir/{i}"))
+            .collect();
+        paths.extend(["file", "not_exist", "/"].map(String::from));
+
+        let exists = op.exists_many(paths, 4).await.expect("must succeed");
+
+        for (i, exist) in exists.iter().take(EXISTS_LIST_THRESHOLD * 2).enumerate() {
+            assert_eq!(*exist, i % 2 == 0, "dir/{i}");
+        }
+        assert_eq!(
+            exists[EXISTS_LIST_THRESHOLD * 2..].to_vec(),
+            vec![true, false, true]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// LifecycleRule describes how objects under a prefix will be expired by
+/// the service, used by [`Operator::lifecycle`] and [`Operator::set_lifecycle`].
+///
+/// [`Operator::lifecycle`]: crate::Operator::lifecycle
+/// [`Operator::set_lifecycle`]: crate::Operator::set_lifecycle
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LifecycleRule {
+    id: String,
+    prefix: String,
+    enabled: bool,
+    tag: Option<(String, String)>,
+    expiration_days: Option<u32>,
+    abort_incomplete_upload_days: Option<u32>,
+}
+
+impl LifecycleRule {
+    /// Create a new enabled lifecycle rule with given id, applied to all
+    /// objects under `prefix`.
+    ///
+    /// The prefix is relative to the root of operator.
+    pub fn new(id: &str, prefix: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            prefix: prefix.to_string(),
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Set whether this rule is enabled.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Only apply this rule to objects that have the given tag.
+    ///
+    /// For example, objects written with `ttl` on s3 are tagged with
+    /// `opendal-ttl-days=<days>`.
+    pub fn with_tag(mut self, key: &str, value: &str) -> Self {
+        self.tag = Some((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Expire objects after given days since they are created.
+    pub fn with_expiration_days(mut self, days: u32) -> Self {
+        self.expiration_days = Some(days);
+        self
+    }
+
+    /// Abort incomplete multipart uploads after given days since they are initiated.
+    pub fn with_abort_incomplete_upload_days(mut self, days: u32) -> Self {
+        self.abort_incomplete_upload_days = Some(days);
+        self
+    }
+
+    /// The id of this rule.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The prefix that this rule applies to.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Whether this rule is enabled.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// The tag that this rule applies to.
+    pub fn tag(&self) -> Option<(&str, &str)> {
+        self.tag.as_ref().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// The days after which objects will be expired.
+    pub fn expiration_days(&self) -> Option<u32> {
+        self.expiration_days
+    }
+
+    /// The days after which incomplete multipart uploads will be aborted.
+    pub fn abort_incomplete_upload_days(&self) -> Option<u32> {
+        self.abort_incomplete_upload_days
+    }
+}
@@ -0,0 +1,55 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// BlockLocation describes where a range of a file is stored, returned by
+/// [`Operator::locate`].
+///
+/// Compute engines can use the hosts to schedule tasks near the data.
+///
+/// [`Operator::locate`]: crate::Operator::locate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockLocation {
+    offset: u64,
+    length: u64,
+    hosts: Vec<String>,
+}
+
+impl BlockLocation {
+    /// Create a new block location.
+    pub fn new(offset: u64, length: u64, hosts: Vec<String>) -> Self {
+        Self {
+            offset,
+            length,
+            hosts,
+        }
+    }
+
+    /// The offset of this block in the file.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The length of this block.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// The hosts that hold replicas of this block.
+    pub fn hosts(&self) -> &[String] {
+        &self.hosts
+    }
+}
@@ -58,4 +58,11 @@ impl OperatorInfo {
     pub fn native_capability(&self) -> Capability {
         self.0.native_capability()
     }
+
+    /// Get [`Limits`] of operator.
+    ///
+    /// Users can use limits to validate the workload before writing.
+    pub fn limits(&self) -> Limits {
+        self.0.full_capability().into()
+    }
 }
@@ -30,5 +30,54 @@ pub use builder::OperatorBuilder;
 mod metadata;
 pub use metadata::OperatorInfo;
 
+mod registry;
+pub use registry::OperatorFactory;
+pub use registry::OperatorRegistry;
+pub use registry::OperatorUri;
+
+mod check;
+pub use check::CheckReport;
+pub use check::CheckStep;
+pub use check::CheckStrategy;
+
+mod copy_dir;
+pub(crate) use copy_dir::copy_dir;
+pub use copy_dir::CopyConflict;
+pub use copy_dir::CopyDirReport;
+
+mod remove_all;
+pub(crate) use remove_all::remove_all;
+pub use remove_all::RemoveAllReport;
+
+mod join;
+pub(crate) use join::exists_many;
+pub(crate) use join::join_all;
+pub(crate) use join::stat_many;
+pub use join::JoinReport;
+
+mod multipart;
+pub use multipart::MultipartUpload;
+
+mod locate;
+pub use locate::BlockLocation;
+
+mod lifecycle;
+pub use lifecycle::LifecycleRule;
+
+mod acl;
+pub use acl::Acl;
+
+mod retention;
+pub use retention::ObjectRetention;
+pub use retention::Retention;
+pub use retention::RetentionMode;
+
+#[cfg(feature = "content-addressable")]
+mod content_store;
+#[cfg(feature = "content-addressable")]
+pub use content_store::ContentDigest;
+#[cfg(feature = "content-addressable")]
+pub use content_store::ContentStore;
+
 pub mod operator_functions;
 pub mod operator_futures;
@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// MultipartUpload is an in-progress multipart upload returned by
+/// [`Operator::list_multipart_uploads`].
+///
+/// Uploads that are never completed or aborted keep their parts stored (and
+/// billed) on services like s3 until they are cleaned up.
+///
+/// [`Operator::list_multipart_uploads`]: crate::Operator::list_multipart_uploads
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipartUpload {
+    path: String,
+    upload_id: String,
+    initiated: Option<DateTime<Utc>>,
+}
+
+impl MultipartUpload {
+    /// Create a new multipart upload.
+    pub fn new(path: &str, upload_id: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            upload_id: upload_id.to_string(),
+            initiated: None,
+        }
+    }
+
+    /// Set the time this upload has been initiated.
+    pub fn with_initiated(mut self, initiated: DateTime<Utc>) -> Self {
+        self.initiated = Some(initiated);
+        self
+    }
+
+    /// The path this upload will be written to, relative to the operator root.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The service specific upload id.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// The time this upload has been initiated, `None` if the service doesn't
+    /// return it.
+    pub fn initiated(&self) -> Option<DateTime<Utc>> {
+        self.initiated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use chrono::TimeDelta;
+
+    use super::*;
+    use crate::raw::*;
+    use crate::*;
+
+    #[derive(Debug, Default)]
+    struct MockService {
+        aborted: Mutex<Vec<String>>,
+    }
+
+    impl Access for MockService {
+        type Reader = oio::Reader;
+        type Writer = oio::Writer;
+        type Lister = oio::Lister;
+        type BlockingReader = oio::BlockingReader;
+        type BlockingWriter = oio::BlockingWriter;
+        type BlockingLister = oio::BlockingLister;
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let mut info = AccessorInfo::default();
+            info.set_native_capability(Capability {
+                list_multipart_uploads: true,
+                abort_multipart_upload: true,
+                ..Default::default()
+            });
+
+            info.into()
+        }
+
+        async fn list_multipart_uploads(
+            &self,
+            _: &str,
+            _: OpListMultipartUploads,
+        ) -> Result<RpListMultipartUploads> {
+            let now = Utc::now();
+            Ok(RpListMultipartUploads::new(vec![
+                MultipartUpload::new("old", "1").with_initiated(now - TimeDelta::hours(2)),
+                MultipartUpload::new("fresh", "2").with_initiated(now),
+                MultipartUpload::new("unknown", "3"),
+                MultipartUpload::new("gone", "4").with_initiated(now - TimeDelta::hours(2)),
+            ]))
+        }
+
+        async fn abort_multipart_upload(
+            &self,
+            path: &str,
+            args: OpAbortMultipartUpload,
+        ) -> Result<RpAbortMultipartUpload> {
+            if path == "gone" {
+                return Err(Error::new(ErrorKind::NotFound, "upload not found"));
+            }
+            self.aborted
+                .lock()
+                .unwrap()
+                .push(args.upload_id().to_string());
+            Ok(RpAbortMultipartUpload::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_abort_stale_uploads() {
+        let srv = Arc::new(MockService::default());
+        let op = Operator::from_inner(srv.clone());
+
+        let aborted = op
+            .abort_stale_uploads("/", Duration::from_secs(3600))
+            .await
+            .expect("abort must succeed");
+        assert_eq!(aborted, 1);
+        assert_eq!(*srv.aborted.lock().unwrap(), vec!["1".to_string()]);
+    }
+}
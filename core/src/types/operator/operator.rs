@@ -16,18 +16,34 @@
 // under the License.
 
 use std::future::Future;
+use std::ops::RangeBounds;
 use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
+use chrono::Utc;
 use futures::stream;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
-
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use super::copy_dir;
+use super::exists_many;
+use super::join_all;
+use super::remove_all;
+use super::stat_many;
 use super::BlockingOperator;
 use crate::operator_futures::*;
+use crate::raw::oio::Read;
 use crate::raw::*;
 use crate::*;
 
+/// The default chunk size used while reading local files.
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_LOCAL_FILE_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
 /// Operator is the entry for all public async APIs.
 ///
 /// Developer should manipulate the data from storage service through Operator only by right.
@@ -65,6 +81,8 @@ pub struct Operator {
     limit: usize,
     /// The default executor that used to run futures in background.
     default_executor: Option<Executor>,
+    /// The default context that carried by operations.
+    default_context: OpContext,
 }
 
 /// # Operator basic API.
@@ -83,6 +101,7 @@ impl Operator {
             accessor,
             limit,
             default_executor: None,
+            default_context: OpContext::default(),
         }
     }
 
@@ -111,12 +130,51 @@ impl Operator {
     }
 
     /// Specify the default executor.
+    ///
+    /// The executor is also used to track the deadline of operations.
     pub fn with_default_executor(&self, executor: Executor) -> Self {
         let mut op = self.clone();
+        op.default_context = op.default_context.with_executor(executor.clone());
         op.default_executor = Some(executor);
         op
     }
 
+    /// Get the default context.
+    pub fn default_context(&self) -> &OpContext {
+        &self.default_context
+    }
+
+    /// Specify the default context that carried by all operations.
+    ///
+    /// The context can be overridden per operation, for example
+    /// `op.read_with(path).context(ctx)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::raw::OpContext;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let op = op.with_default_context(
+    ///     OpContext::new()
+    ///         .with_request_id("req-1")
+    ///         .with_tag("tenant", "alice"),
+    /// );
+    /// op.stat("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_default_context(&self, context: OpContext) -> Self {
+        let mut op = self.clone();
+        op.default_context = match &self.default_executor {
+            Some(executor) => context.with_executor(executor.clone()),
+            None => context,
+        };
+        op
+    }
+
     /// Get information of underlying accessor.
     ///
     /// # Examples
@@ -168,6 +226,104 @@ impl Operator {
         }
     }
 
+    /// Check if this operator can work correctly with given strategy.
+    ///
+    /// Returns a [`CheckReport`] with the executed steps and their latency
+    /// if check succeeded. Returned errors will carry the `check_strategy`
+    /// and `check_step` context so that users can tell which step failed.
+    ///
+    /// - [`CheckStrategy::Auto`]: use `List` if supported, otherwise `Stat`.
+    /// - [`CheckStrategy::List`]: list the root and fetch the first entry.
+    /// - [`CheckStrategy::Stat`]: stat a random path that doesn't exist.
+    /// - [`CheckStrategy::Write`]: write, stat and delete a small probe file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::CheckStrategy;
+    /// use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let report = op.check_with(CheckStrategy::Stat).await?;
+    /// println!("{} is healthy in {:?}", report.scheme(), report.elapsed());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_with(&self, strategy: CheckStrategy) -> Result<CheckReport> {
+        let info = self.info();
+        let cap = info.full_capability();
+
+        let strategy = match strategy {
+            CheckStrategy::Auto if cap.list => CheckStrategy::List,
+            CheckStrategy::Auto => CheckStrategy::Stat,
+            v => v,
+        };
+
+        let mut report = CheckReport {
+            scheme: info.scheme(),
+            root: info.root().to_string(),
+            name: info.name().to_string(),
+            strategy,
+            steps: vec![],
+        };
+
+        let res = match strategy {
+            CheckStrategy::List => self.check_via_list(&mut report.steps).await,
+            CheckStrategy::Stat => self.check_via_stat(&mut report.steps).await,
+            CheckStrategy::Write => self.check_via_write(&mut report.steps).await,
+            CheckStrategy::Auto => unreachable!("auto strategy must be resolved"),
+        };
+
+        res.map(|_| report)
+            .map_err(|err| err.with_context("check_strategy", strategy))
+    }
+
+    async fn check_via_list(&self, steps: &mut Vec<CheckStep>) -> Result<()> {
+        check_step(steps, "list", "/", async {
+            let mut ds = self.lister("/").await?;
+
+            match ds.next().await {
+                Some(Err(e)) if e.kind() != ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        })
+        .await
+    }
+
+    async fn check_via_stat(&self, steps: &mut Vec<CheckStep>) -> Result<()> {
+        let path = format!(".opendal-check-{}", uuid::Uuid::new_v4());
+
+        check_step(steps, "stat", &path, async {
+            match self.stat(&path).await {
+                Err(e) if e.kind() != ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        })
+        .await
+    }
+
+    async fn check_via_write(&self, steps: &mut Vec<CheckStep>) -> Result<()> {
+        let path = format!(".opendal-check-{}", uuid::Uuid::new_v4());
+        let content = "opendal check";
+
+        check_step(steps, "write", &path, self.write(&path, content)).await?;
+        let meta = check_step(steps, "stat", &path, self.stat(&path)).await?;
+        check_step(steps, "delete", &path, self.delete(&path)).await?;
+
+        if meta.content_length() != content.len() as u64 {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "content length of probe file mismatch",
+            )
+            .with_context("path", &path)
+            .with_context("expect", content.len())
+            .with_context("actual", meta.content_length()));
+        }
+
+        Ok(())
+    }
+
     /// Get given path's metadata.
     ///
     /// # Notes
@@ -319,7 +475,7 @@ impl Operator {
         OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpStat::default(),
+            OpStat::default().with_context(self.default_context.clone()),
             |inner, path, args| async move {
                 let rp = inner.stat(&path, args).await?;
                 Ok(rp.into_metadata())
@@ -389,7 +545,12 @@ impl Operator {
             .with_context("path", &path));
         }
 
-        self.inner().create_dir(&path, OpCreateDir::new()).await?;
+        self.inner()
+            .create_dir(
+                &path,
+                OpCreateDir::new().with_context(self.default_context.clone()),
+            )
+            .await?;
 
         Ok(())
     }
@@ -458,6 +619,9 @@ impl Operator {
     /// # }
     /// ```
     ///
+    /// The returned buffer doesn't carry the range that has been returned, use
+    /// [`Operator::read_partial_with`] if callers need to know it.
+    ///
     /// ## `if_match`
     ///
     /// Set `if_match` for this `read` request.
@@ -552,7 +716,9 @@ impl Operator {
             self.inner().clone(),
             path,
             (
-                OpRead::default().merge_executor(self.default_executor.clone()),
+                OpRead::default()
+                    .with_context(self.default_context.clone())
+                    .merge_executor(self.default_executor.clone()),
                 OpReader::default(),
             ),
             |inner, path, (args, options)| async move {
@@ -574,6 +740,221 @@ impl Operator {
         )
     }
 
+    /// Read the given range of path and return the effective range alongside the data.
+    ///
+    /// This is a wrapper of [`Operator::read_partial_with`] without any options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let res = op.read_partial("path/to/file", 1024..).await?;
+    /// println!("got {:?} of {:?}", res.range(), res.total_size());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_partial(
+        &self,
+        path: &str,
+        range: impl RangeBounds<u64>,
+    ) -> Result<PartialRead> {
+        self.read_partial_with(path).range(range).await
+    }
+
+    /// Read the given range of path with extra options and return the effective
+    /// range alongside the data.
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`Operator::read_with`], this function sends exactly one read request
+    /// so that the returned range is the one reported by the service (via `Content-Range`
+    /// on http based services). The data is not split into chunks or read concurrently.
+    ///
+    /// - [`PartialRead::range`] is the range that has actually been returned.
+    /// - [`PartialRead::total_size`] is the size of the whole content if known.
+    ///
+    /// If the service reports a range but returns less data than it, an error with
+    /// kind [`ErrorKind::Unexpected`] will be returned and marked as temporary so that
+    /// truncated responses won't be treated as valid data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator, etag: &str) -> Result<()> {
+    /// let res = op
+    ///     .read_partial_with("path/to/file")
+    ///     .range(1024..)
+    ///     .if_match(etag)
+    ///     .await?;
+    /// if res.is_end() == Some(false) {
+    ///     // There are more data after `res.range().end`.
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_partial_with(
+        &self,
+        path: &str,
+    ) -> FutureReadPartial<impl Future<Output = Result<PartialRead>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpRead::default()
+                .with_context(self.default_context.clone())
+                .merge_executor(self.default_executor.clone()),
+            |inner, path, args| async move {
+                if !validate_path(&path, EntryMode::FILE) {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "read path is a directory")
+                            .with_operation("read")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("path", &path),
+                    );
+                }
+
+                let offset = args.range().offset();
+                let (rp, mut r) = inner.read(&path, args).await?;
+                let buf = r.read_all().await?;
+                let received = buf.len() as u64;
+
+                let content_range = rp.range().unwrap_or_default();
+                let range = match content_range.range() {
+                    Some(range) if range.end - range.start != received => {
+                        let mut err = Error::new(
+                            ErrorKind::Unexpected,
+                            "read response doesn't match the returned content range",
+                        )
+                        .with_operation("read")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", &path)
+                        .with_context("content_range", content_range.to_header())
+                        .with_context("received", received);
+                        if received < range.end - range.start {
+                            err = err.set_temporary();
+                        }
+                        return Err(err);
+                    }
+                    Some(range) => range,
+                    None => offset..offset + received,
+                };
+
+                Ok(PartialRead::new(buf, range, content_range.size()))
+            },
+        )
+    }
+
+    /// Download the whole path into a local file.
+    ///
+    /// # Notes
+    ///
+    /// The local file will be created if not exist, and overwritten if exists.
+    ///
+    /// [`Operator::read_to_file`] is a wrapper of [`Operator::read_to_file_with`] without any
+    /// options. To use extra options like `concurrent` and `resume`, please use
+    /// [`Operator::read_to_file_with`] instead.
+    ///
+    /// This function is not available on `wasm32` targets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.read_to_file("path/to/file", "/tmp/local_file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn read_to_file(
+        &self,
+        path: &str,
+        local_path: impl AsRef<std::path::Path>,
+    ) -> Result<()> {
+        self.read_to_file_with(path, local_path).await
+    }
+
+    /// Download the whole path into a local file with extra options.
+    ///
+    /// The local file will be preallocated to the size of the file, and the content
+    /// will be written at the offsets of the ranges being fetched.
+    ///
+    /// This function is not available on `wasm32` targets.
+    ///
+    /// # Options
+    ///
+    /// ## `concurrent`
+    ///
+    /// Set `concurrent` to fetch ranges concurrently. Please also set `chunk` to control
+    /// the size of each range request.
+    ///
+    /// ## `resume`
+    ///
+    /// Set `resume` to record the progress in a `<local_path>.opendal-progress` file,
+    /// so that a failed download can be continued by calling again with `resume`.
+    /// The progress will be discarded if the file has been changed (whose etag differs).
+    /// The progress file will be removed after download succeeded.
+    ///
+    /// ## `checksum`
+    ///
+    /// Set `checksum` to verify the md5 of the local file with `content_md5` returned by
+    /// services after download. The verification will be skipped if the service doesn't
+    /// return `content_md5`, in which case only the size will be verified.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.read_to_file_with("path/to/file", "/tmp/local_file")
+    ///     .concurrent(8)
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .resume(true)
+    ///     .checksum(true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read_to_file_with(
+        &self,
+        path: &str,
+        local_path: impl AsRef<std::path::Path>,
+    ) -> FutureReadToFile<impl Future<Output = Result<()>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (
+                OpRead::default()
+                    .with_context(self.default_context.clone())
+                    .merge_executor(self.default_executor.clone()),
+                OpReader::default(),
+                OpReadToFile::new(local_path),
+            ),
+            |inner, path, (args, options, file_args)| async move {
+                if !validate_path(&path, EntryMode::FILE) {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "read path is a directory")
+                            .with_operation("Operator::read_to_file")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("path", &path),
+                    );
+                }
+
+                read_to_file(inner, path, args, options, file_args).await
+            },
+        )
+    }
+
     /// Create a new reader which can read the whole path.
     ///
     /// # Notes
@@ -650,6 +1031,48 @@ impl Operator {
     /// # }
     /// ```
     ///
+    /// ## `access`
+    ///
+    /// Set the [`AccessPattern`] hint for the reader.
+    ///
+    /// - `Sequential` will read data in chunks and prefetch the next chunks in background.
+    /// - `Random` will disable buffering and only fetch the exact ranges that being read.
+    ///
+    /// ```no_run
+    /// # use opendal::AccessPattern;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let r = op
+    ///     .reader_with("path/to/file")
+    ///     .access(AccessPattern::Random)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `prefetch`
+    ///
+    /// Set the number of chunks to prefetch in background. The memory usage is bounded by
+    /// `(prefetch + 1) * chunk`, chunk will be 4MiB if not set.
+    ///
+    /// - Streams like [`Reader::into_bytes_stream`] will prefetch from the beginning.
+    /// - [`Reader::into_futures_async_read`] will only start prefetching once it detected
+    ///   the reads are sequential, and stop prefetching after seek.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let r = op
+    ///     .reader_with("path/to/file")
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .prefetch(4)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -668,7 +1091,9 @@ impl Operator {
             self.inner().clone(),
             path,
             (
-                OpRead::default().merge_executor(self.default_executor.clone()),
+                OpRead::default()
+                    .with_context(self.default_context.clone())
+                    .merge_executor(self.default_executor.clone()),
                 OpReader::default(),
             ),
             |inner, path, (args, options)| async move {
@@ -727,6 +1152,103 @@ impl Operator {
         self.write_with(path, bs).await
     }
 
+    /// Write the content of a local file into path.
+    ///
+    /// # Notes
+    ///
+    /// The local file will be read in chunks that fit the multipart limits of the
+    /// service, and every chunk is handed to [`Writer`] without extra copy. So
+    /// uploading a large file will not load the whole file into memory.
+    ///
+    /// This function is not available on `wasm32` targets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.write_from_file("path/to/file", "/tmp/local_file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn write_from_file(
+        &self,
+        path: &str,
+        local_path: impl AsRef<std::path::Path>,
+    ) -> Result<()> {
+        use bytes::BytesMut;
+        use tokio::io::AsyncReadExt;
+
+        let local_path = local_path.as_ref();
+        let with_context = |err: std::io::Error| {
+            new_std_io_error(err)
+                .with_operation("Operator::write_from_file")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path)
+                .with_context("local_path", local_path.display())
+        };
+
+        let mut f = tokio::fs::File::open(local_path)
+            .await
+            .map_err(with_context)?;
+        let size = f.metadata().await.map_err(with_context)?.len();
+        // Fail before uploading if the file is too large for the service.
+        self.info().limits().check_write_size(size).map_err(|err| {
+            err.with_operation("Operator::write_from_file")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path)
+        })?;
+
+        let cap = self.info().full_capability();
+        let chunk = if cap.write_can_multi {
+            let chunk = cap
+                .write_multi_min_size
+                .unwrap_or(DEFAULT_LOCAL_FILE_CHUNK_SIZE)
+                .max(DEFAULT_LOCAL_FILE_CHUNK_SIZE);
+            cap.write_multi_max_size.map_or(chunk, |v| chunk.min(v))
+        } else {
+            // Services that can't write in multiple parts will get the whole file at once.
+            size as usize
+        };
+
+        let mut w = if cap.write_can_multi {
+            self.writer_with(path).chunk(chunk).await?
+        } else {
+            self.writer(path).await?
+        };
+
+        let mut remaining = size;
+        while remaining > 0 {
+            let n = chunk.min(remaining as usize);
+            let mut buf = BytesMut::with_capacity(n);
+            while buf.len() < n {
+                match f.read_buf(&mut buf).await {
+                    // The file has been truncated while reading.
+                    Ok(0) => break,
+                    Ok(_) => {}
+                    Err(err) => {
+                        let _ = w.abort().await;
+                        return Err(with_context(err));
+                    }
+                }
+            }
+            if buf.is_empty() {
+                break;
+            }
+
+            remaining -= buf.len() as u64;
+            if let Err(err) = w.write(buf.freeze()).await {
+                let _ = w.abort().await;
+                return Err(err);
+            }
+        }
+
+        w.close().await
+    }
+
     /// Copy a file from `from` to `to`.
     ///
     /// # Notes
@@ -780,27 +1302,108 @@ impl Operator {
             );
         }
 
-        self.inner().copy(&from, &to, OpCopy::new()).await?;
+        self.inner()
+            .copy(
+                &from,
+                &to,
+                OpCopy::new().with_context(self.default_context.clone()),
+            )
+            .await?;
 
         Ok(())
     }
 
-    /// Rename a file from `from` to `to`.
+    /// Copy all files under dir `from` into dir `to` recursively.
+    ///
+    /// Every file will be copied by the native copy of service, see [`Operator::copy`].
     ///
     /// # Notes
     ///
-    /// - `from` and `to` must be a file.
-    /// - `to` will be overwritten if it exists.
-    /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
+    /// - `from` and `to` must be dirs that ends with `/`.
+    /// - `to` can't be inside `from`.
+    /// - Files in `to` will be overwritten if they exist.
     ///
     /// # Examples
     ///
-    /// ```no_run
-    /// # use opendal::Result;
+    /// ```
+    /// # use anyhow::Result;
     /// # use opendal::Operator;
-    ///
+    /// #
     /// # async fn test(op: Operator) -> Result<()> {
-    /// op.rename("path/to/file", "path/to/file2").await?;
+    /// op.copy_dir("path/to/dir/", "path/to/backup/").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_dir(&self, from: &str, to: &str) -> Result<()> {
+        let _ = self.copy_dir_with(from, to).await?;
+        Ok(())
+    }
+
+    /// Copy all files under dir `from` into dir `to` recursively with extra options.
+    ///
+    /// Errors happened while copying will stop the operation, files that have been
+    /// copied won't be removed.
+    ///
+    /// # Options
+    ///
+    /// ## `concurrent`
+    ///
+    /// Set `concurrent` to copy files concurrently. Defaults to `1`.
+    ///
+    /// ## `conflict`
+    ///
+    /// Set `conflict` to decide what to do if the target file exists. See [`CopyConflict`]
+    /// for details. Defaults to [`CopyConflict::Overwrite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use opendal::CopyConflict;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let report = op
+    ///     .copy_dir_with("path/to/dir/", "path/to/backup/")
+    ///     .concurrent(8)
+    ///     .conflict(CopyConflict::Skip)
+    ///     .await?;
+    /// println!("copied {}, skipped {}", report.copied(), report.skipped());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_dir_with(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> FutureCopyDir<impl Future<Output = Result<CopyDirReport>>> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            from,
+            OpCopyDir::new(&to).with_context(self.default_context.clone()),
+            |inner, from, args| async move { copy_dir(inner, from, args).await },
+        )
+    }
+
+    /// Rename a file from `from` to `to`.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be a file.
+    /// - `to` will be overwritten if it exists.
+    /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.rename("path/to/file", "path/to/file2").await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -837,7 +1440,13 @@ impl Operator {
             );
         }
 
-        self.inner().rename(&from, &to, OpRename::new()).await?;
+        self.inner()
+            .rename(
+                &from,
+                &to,
+                OpRename::new().with_context(self.default_context.clone()),
+            )
+            .await?;
 
         Ok(())
     }
@@ -1080,7 +1689,9 @@ impl Operator {
             self.inner().clone(),
             path,
             (
-                OpWrite::default().merge_executor(self.default_executor.clone()),
+                OpWrite::default()
+                    .with_context(self.default_context.clone())
+                    .merge_executor(self.default_executor.clone()),
                 OpWriter::default(),
             ),
             |inner, path, (args, options)| async move {
@@ -1229,7 +1840,9 @@ impl Operator {
             self.inner().clone(),
             path,
             (
-                OpWrite::default().merge_executor(self.default_executor.clone()),
+                OpWrite::default()
+                    .with_context(self.default_context.clone())
+                    .merge_executor(self.default_executor.clone()),
                 OpWriter::default(),
                 bs,
             ),
@@ -1252,6 +1865,85 @@ impl Operator {
         )
     }
 
+    /// Write bytes at given offset of the file, like `pwrite`.
+    ///
+    /// The file will be created if it doesn't exist, and extended if the write goes
+    /// beyond its end. Different ranges of the same file can be written out of order
+    /// and concurrently, which is useful to fill sparse files like VM images.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::write_at`], returns `Unsupported` otherwise.
+    /// - Offset and size must be aligned to [`Capability::write_at_align_size`] if set,
+    ///   returns `RangeNotSatisfied` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.write_at("path/to/image", 4096, vec![0; 512]).await?;
+    /// op.write_at("path/to/image", 0, vec![1; 512]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_at(&self, path: &str, offset: u64, bs: impl Into<Buffer>) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                    .with_operation("Operator::write_at")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let args = OpWriteAt::new(offset, bs.into()).with_context(self.default_context.clone());
+        self.inner().write_at(&path, args).await?;
+        Ok(())
+    }
+
+    /// Allocate a fixed-size file filled with zeros, replacing the existing one.
+    ///
+    /// Combined with [`Operator::write_at`] and ranged reads, the allocated file can be
+    /// used as a virtual disk, for example, an Azure page blob.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::allocate`], returns `Unsupported` otherwise.
+    /// - Size must be aligned to [`Capability::allocate_align_size`] if set,
+    ///   returns `RangeNotSatisfied` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.allocate("path/to/disk.vhd", 1024 * 1024 * 1024).await?;
+    /// op.write_at("path/to/disk.vhd", 4096, vec![1; 512]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn allocate(&self, path: &str, size: u64) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "allocate path is a directory")
+                    .with_operation("Operator::allocate")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let args = OpAllocate::new(size).with_context(self.default_context.clone());
+        self.inner().allocate(&path, args).await?;
+        Ok(())
+    }
+
     /// Delete the given path.
     ///
     /// # Notes
@@ -1297,7 +1989,7 @@ impl Operator {
         OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpDelete::default(),
+            OpDelete::default().with_context(self.default_context.clone()),
             |inner, path, args| async move {
                 let _ = inner.delete(&path, args).await?;
                 Ok(())
@@ -1305,6 +1997,85 @@ impl Operator {
         )
     }
 
+    /// Create a new deleter which can delete paths from a stream.
+    ///
+    /// # Notes
+    ///
+    /// ## Extra Options
+    ///
+    /// [`Operator::deleter`] is a wrapper of [`Operator::deleter_with`] without any options. To use
+    /// extra options like `concurrent`, please use [`Operator::deleter_with`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use futures::stream;
+    /// # use futures::StreamExt;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let deleter = op.deleter().await?;
+    /// let paths = stream::iter(vec!["abc".to_string(), "def".to_string()]);
+    ///
+    /// let mut results = deleter.delete_stream(paths);
+    /// while let Some((path, res)) = results.next().await {
+    ///     if let Err(err) = res {
+    ///         println!("failed to delete {path}: {err}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn deleter(&self) -> Result<Deleter> {
+        self.deleter_with().await
+    }
+
+    /// Create a new deleter with extra options.
+    ///
+    /// Paths will be grouped into native batch delete requests if the service supports
+    /// batch delete, otherwise they will be deleted one by one. The input stream is only
+    /// polled while there are free task slots, so slow deletions will slow down the input.
+    ///
+    /// # Options
+    ///
+    /// ## `concurrent`
+    ///
+    /// Set `concurrent` to run delete tasks concurrently. Defaults to `1`.
+    ///
+    /// ## `batch_size`
+    ///
+    /// Set `batch_size` to control how many paths will be sent in one batch delete
+    /// request. Defaults to [`Operator::limit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use futures::StreamExt;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let deleter = op.deleter_with().concurrent(8).batch_size(100).await?;
+    ///
+    /// let paths = (0..1000).map(|i| format!("dir/{i}"));
+    /// let failed = deleter
+    ///     .delete_iter(paths)
+    ///     .filter(|(_, res)| futures::future::ready(res.is_err()))
+    ///     .count()
+    ///     .await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deleter_with(&self) -> FutureDeleter<impl Future<Output = Result<Deleter>>> {
+        OperatorFuture::new(
+            self.inner().clone(),
+            String::new(),
+            OpDeleter::new()
+                .with_batch_size(self.limit())
+                .with_context(self.default_context.clone()),
+            |inner, _, args| async move { Ok(Deleter::new(inner, args)) },
+        )
+    }
+
     ///
     /// # Notes
     ///
@@ -1358,7 +2129,14 @@ impl Operator {
 
         if self.info().full_capability().batch {
             let mut input = input
-                .map(|v| (v, OpDelete::default().into()))
+                .map(|v| {
+                    (
+                        v,
+                        OpDelete::default()
+                            .with_context(self.default_context.clone())
+                            .into(),
+                    )
+                })
                 .chunks(self.limit());
 
             while let Some(batches) = input.next().await {
@@ -1377,7 +2155,13 @@ impl Operator {
             input
                 .map(Ok)
                 .try_for_each_concurrent(self.limit, |path| async move {
-                    let _ = self.inner().delete(&path, OpDelete::default()).await?;
+                    let _ = self
+                        .inner()
+                        .delete(
+                            &path,
+                            OpDelete::default().with_context(self.default_context.clone()),
+                        )
+                        .await?;
                     Ok::<(), Error>(())
                 })
                 .await?;
@@ -1406,154 +2190,308 @@ impl Operator {
     /// # }
     /// ```
     pub async fn remove_all(&self, path: &str) -> Result<()> {
-        match self.stat(path).await {
-            // If object exists.
-            Ok(metadata) => {
-                // If the object is a file, we can delete it.
-                if metadata.mode() != EntryMode::DIR {
-                    self.delete(path).await?;
-                    // There may still be objects prefixed with the path in some backend, so we can't return here.
-                }
-            }
-
-            // If dir not found, it may be a prefix in object store like S3,
-            // and we still need to delete objects under the prefix.
-            Err(e) if e.kind() == ErrorKind::NotFound => {}
-
-            // Pass on any other error.
-            Err(e) => return Err(e),
-        };
-
-        let obs = self.lister_with(path).recursive(true).await?;
-
-        if self.info().full_capability().batch {
-            let mut obs = obs.try_chunks(self.limit());
-
-            while let Some(batches) = obs.next().await {
-                let batches = batches
-                    .map_err(|err| err.1)?
-                    .into_iter()
-                    .map(|v| (v.path().to_string(), OpDelete::default().into()))
-                    .collect();
-
-                let results = self
-                    .inner()
-                    .batch(OpBatch::new(batches))
-                    .await?
-                    .into_results();
+        let report = self.remove_all_with(path).await?;
 
-                // TODO: return error here directly seems not a good idea?
-                for (_, result) in results {
-                    let _ = result?;
-                }
-            }
-        } else {
-            obs.try_for_each(|v| async move { self.delete(v.path()).await })
-                .await?;
+        // Keep the old behavior that returns the first error.
+        match report.into_failed().into_iter().next() {
+            Some((_, err)) => Err(err),
+            None => Ok(()),
         }
-
-        // Remove the directory itself.
-        self.delete(path).await?;
-
-        Ok(())
     }
 
-    /// List entries that starts with given `path` in parent dir.
+    /// Remove the path and all nested dirs and files recursively with extra options.
     ///
-    /// # Notes
+    /// Files will be deleted while listing the path, and failed paths won't stop the
+    /// operation. Users can find them in returned [`RemoveAllReport`]. Errors happened
+    /// while listing will be returned directly.
     ///
-    /// ## Recursively List
+    /// # Options
     ///
-    /// This function only read the children of the given directory. To read
-    /// all entries recursively, use `Operator::list_with("path").recursive(true)`
-    /// instead.
+    /// ## `concurrent`
     ///
-    /// ## Streaming List
+    /// Set `concurrent` to run delete tasks concurrently. Defaults to `1`.
     ///
-    /// This function will read all entries in the given directory. It could
-    /// take very long time and consume a lot of memory if the directory
-    /// contains a lot of entries.
+    /// ## `batch_size`
     ///
-    /// In order to avoid this, you can use [`Operator::lister`] to list entries in
-    /// a streaming way.
+    /// Set `batch_size` to control how many paths will be sent in one batch delete
+    /// request. Defaults to [`Operator::limit`].
     ///
-    /// ## Reuse Metadata
+    /// ## `progress`
     ///
-    /// The only metadata that is guaranteed to be available is the `Mode`.
-    /// For fetching more metadata, please use [`Operator::list_with`] and `metakey`.
+    /// Set `progress` to get the current report after every path has been handled.
     ///
     /// # Examples
     ///
-    /// ## List entries under a dir
-    ///
-    /// This example will list all entries under the dir `path/to/dir/`.
-    ///
-    /// ```no_run
+    /// ```
     /// # use anyhow::Result;
-    /// use opendal::EntryMode;
-    /// use opendal::Metakey;
-    /// use opendal::Operator;
+    /// # use opendal::Operator;
+    /// #
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut entries = op.list("path/to/dir/").await?;
-    /// for entry in entries {
-    ///     match entry.metadata().mode() {
-    ///         EntryMode::FILE => {
-    ///             println!("Handling file")
-    ///         }
-    ///         EntryMode::DIR => {
-    ///             println!("Handling dir {}", entry.path())
-    ///         }
-    ///         EntryMode::Unknown => continue,
-    ///     }
+    /// let report = op
+    ///     .remove_all_with("path/to/dir")
+    ///     .concurrent(8)
+    ///     .progress(|report| println!("deleted {} paths", report.deleted()))
+    ///     .await?;
+    ///
+    /// for (path, err) in report.failed() {
+    ///     println!("failed to delete {path}: {err}");
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    pub fn remove_all_with(
+        &self,
+        path: &str,
+    ) -> FutureRemoveAll<impl Future<Output = Result<RemoveAllReport>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpRemoveAll::new()
+                .with_batch_size(self.limit())
+                .with_context(self.default_context.clone()),
+            |inner, path, args| async move { remove_all(inner, path, args).await },
+        )
+    }
+
+    /// Run `f` on every path with at most `concurrent` operations in flight.
     ///
-    /// ## List entries with prefix
+    /// Results are collected into a [`JoinReport`] in the same order as the input.
+    /// Failed paths won't stop the other operations, use [`JoinReport::failed`] or
+    /// [`JoinReport::into_result`] to handle errors.
     ///
-    /// This example will list all entries under the prefix `path/to/prefix`.
+    /// No task will be spawned, all operations are driven by the returned future,
+    /// so dropping it cancels all pending operations.
     ///
-    /// NOTE: it's possible that the prefix itself is also a dir. In this case, you could get
-    /// `path/to/prefix/`, `path/to/prefix_1` and so on. If you do want to list a dir, please
-    /// make sure the path is end with `/`.
+    /// # Examples
     ///
-    /// ```no_run
+    /// ```
     /// # use anyhow::Result;
-    /// use opendal::EntryMode;
-    /// use opendal::Metakey;
-    /// use opendal::Operator;
+    /// # use opendal::Operator;
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut entries = op.list("path/to/prefix").await?;
-    /// for entry in entries {
-    ///     match entry.metadata().mode() {
-    ///         EntryMode::FILE => {
-    ///             println!("Handling file")
-    ///         }
-    ///         EntryMode::DIR => {
-    ///             println!("Handling dir {}", entry.path())
-    ///         }
-    ///         EntryMode::Unknown => continue,
-    ///     }
+    /// let paths = ["a.txt", "b.txt"].map(String::from);
+    /// let report = op
+    ///     .join_all(paths, 8, |op, path| async move {
+    ///         op.write(&path, "hello").await
+    ///     })
+    ///     .await;
+    ///
+    /// for (path, err) in report.failed() {
+    ///     println!("failed to write {path}: {err}");
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list(&self, path: &str) -> Result<Vec<Entry>> {
-        self.list_with(path).await
+    pub async fn join_all<T, F, Fut>(
+        &self,
+        paths: impl IntoIterator<Item = String>,
+        concurrent: usize,
+        f: F,
+    ) -> JoinReport<T>
+    where
+        F: Fn(Operator, String) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        join_all(self, paths, concurrent, f).await
     }
 
-    /// List entries that starts with given `path` in parent dir with more options.
+    /// Stat all paths with at most `concurrent` requests in flight.
     ///
-    /// # Notes
+    /// This is a shortcut of [`Operator::join_all`] with [`Operator::stat`].
     ///
-    /// ## Streaming list
+    /// If the service supports `batch_stat` (like gcs), files will be stat in
+    /// batches of [`Operator::limit`] paths natively, which saves a lot of
+    /// requests. Dirs will still be stat one by one.
     ///
-    /// This function will read all entries in the given directory. It could
-    /// take very long time and consume a lot of memory if the directory
-    /// contains a lot of entries.
+    /// # Examples
     ///
-    /// In order to avoid this, you can use [`Operator::lister`] to list entries in
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let paths = (0..1000).map(|i| format!("path/to/file-{i}"));
+    /// for (path, meta) in op.stat_many(paths, 16).await.into_result()? {
+    ///     println!("{path}: {}", meta.content_length());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat_many(
+        &self,
+        paths: impl IntoIterator<Item = String>,
+        concurrent: usize,
+    ) -> JoinReport<Metadata> {
+        stat_many(self, paths, concurrent).await
+    }
+
+    /// Read all paths with at most `concurrent` requests in flight.
+    ///
+    /// This is a shortcut of [`Operator::join_all`] with [`Operator::read`], the whole
+    /// content of every file will be held in memory, so it's suitable for small files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let paths = (0..50).map(|i| format!("path/to/small-{i}.json"));
+    /// let report = op.read_many(paths, 8).await;
+    /// for (path, res) in report.into_results() {
+    ///     match res {
+    ///         Ok(bs) => println!("{path}: {} bytes", bs.len()),
+    ///         Err(err) => println!("{path}: {err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_many(
+        &self,
+        paths: impl IntoIterator<Item = String>,
+        concurrent: usize,
+    ) -> JoinReport<Buffer> {
+        self.join_all(
+            paths,
+            concurrent,
+            |op, path| async move { op.read(&path).await },
+        )
+        .await
+    }
+
+    /// Check the existence of all paths with at most `concurrent` requests in flight.
+    ///
+    /// The returned bitmap has the same order as the input paths. Any error
+    /// other than `NotFound` will fail the whole check.
+    ///
+    /// # Notes
+    ///
+    /// Paths sharing the same parent dir will be checked by listing the parent
+    /// dir once instead of stat them one by one if there are many of them, which
+    /// is much faster while reconciling a large number of cached files. Please
+    /// avoid mixing a few wanted paths with a huge parent dir in one call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let paths: Vec<_> = (0..1000).map(|i| format!("cache/{i}")).collect();
+    /// let exists = op.exists_many(paths.clone(), 16).await?;
+    /// for (path, exist) in paths.iter().zip(exists) {
+    ///     if !exist {
+    ///         println!("{path} is missing");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exists_many(
+        &self,
+        paths: impl IntoIterator<Item = String>,
+        concurrent: usize,
+    ) -> Result<Vec<bool>> {
+        exists_many(self, paths, concurrent).await
+    }
+
+    /// List entries that starts with given `path` in parent dir.
+    ///
+    /// # Notes
+    ///
+    /// ## Recursively List
+    ///
+    /// This function only read the children of the given directory. To read
+    /// all entries recursively, use `Operator::list_with("path").recursive(true)`
+    /// instead.
+    ///
+    /// ## Streaming List
+    ///
+    /// This function will read all entries in the given directory. It could
+    /// take very long time and consume a lot of memory if the directory
+    /// contains a lot of entries.
+    ///
+    /// In order to avoid this, you can use [`Operator::lister`] to list entries in
+    /// a streaming way.
+    ///
+    /// ## Reuse Metadata
+    ///
+    /// The only metadata that is guaranteed to be available is the `Mode`.
+    /// For fetching more metadata, please use [`Operator::list_with`] and `metakey`.
+    ///
+    /// # Examples
+    ///
+    /// ## List entries under a dir
+    ///
+    /// This example will list all entries under the dir `path/to/dir/`.
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::EntryMode;
+    /// use opendal::Metakey;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut entries = op.list("path/to/dir/").await?;
+    /// for entry in entries {
+    ///     match entry.metadata().mode() {
+    ///         EntryMode::FILE => {
+    ///             println!("Handling file")
+    ///         }
+    ///         EntryMode::DIR => {
+    ///             println!("Handling dir {}", entry.path())
+    ///         }
+    ///         EntryMode::Unknown => continue,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## List entries with prefix
+    ///
+    /// This example will list all entries under the prefix `path/to/prefix`.
+    ///
+    /// NOTE: it's possible that the prefix itself is also a dir. In this case, you could get
+    /// `path/to/prefix/`, `path/to/prefix_1` and so on. If you do want to list a dir, please
+    /// make sure the path is end with `/`.
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::EntryMode;
+    /// use opendal::Metakey;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut entries = op.list("path/to/prefix").await?;
+    /// for entry in entries {
+    ///     match entry.metadata().mode() {
+    ///         EntryMode::FILE => {
+    ///             println!("Handling file")
+    ///         }
+    ///         EntryMode::DIR => {
+    ///             println!("Handling dir {}", entry.path())
+    ///         }
+    ///         EntryMode::Unknown => continue,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self, path: &str) -> Result<Vec<Entry>> {
+        self.list_with(path).await
+    }
+
+    /// List entries that starts with given `path` in parent dir with more options.
+    ///
+    /// # Notes
+    ///
+    /// ## Streaming list
+    ///
+    /// This function will read all entries in the given directory. It could
+    /// take very long time and consume a lot of memory if the directory
+    /// contains a lot of entries.
+    ///
+    /// In order to avoid this, you can use [`Operator::lister`] to list entries in
     /// a streaming way.
     ///
     /// # Options
@@ -1698,7 +2636,7 @@ impl Operator {
         OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpList::default(),
+            OpList::default().with_context(self.default_context.clone()),
             |inner, path, args| async move {
                 let lister = Lister::create(inner, &path, args).await?;
 
@@ -1909,126 +2847,756 @@ impl Operator {
         OperatorFuture::new(
             self.inner().clone(),
             path,
-            OpList::default(),
+            OpList::default().with_context(self.default_context.clone()),
             |inner, path, args| async move { Lister::create(inner, &path, args).await },
         )
     }
-}
 
-/// Operator presign API.
-impl Operator {
-    /// Presign an operation for stat(head).
-    ///
-    /// # Example
+    /// Continue a listing from the given [`ListToken`].
     ///
-    /// ```no_run
-    /// use anyhow::Result;
-    /// use futures::io;
-    /// use opendal::Operator;
-    /// use std::time::Duration;
+    /// The token is obtained from [`Lister::continuation_token`], possibly in
+    /// another process or machine, or built by [`ListToken::new`] to split a
+    /// huge listing into shards.
     ///
-    /// async fn test(op: Operator) -> Result<()> {
-    ///     let signed_req = op.presign_stat("test",Duration::from_secs(3600)).await?;
-    ///     let req = http::Request::builder()
-    ///         .method(signed_req.method())
-    ///         .uri(signed_req.uri())
-    ///         .body(())?;
+    /// # Notes
     ///
-    /// #    Ok(())
-    /// # }
-    /// ```
-    pub async fn presign_stat(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
-        let path = normalize_path(path);
-
-        let op = OpPresign::new(OpStat::new(), expire);
-
-        let rp = self.inner().presign(&path, op).await?;
-        Ok(rp.into_presigned_request())
-    }
-
-    /// Presign an operation for stat(head).
+    /// Continuing a started listing requires `list_with_start_after`, otherwise an
+    /// `Unsupported` error will be returned. Options like `metakey` are not
+    /// carried by the token, use [`Operator::lister_with`] along with
+    /// [`ListToken::start_after`] if they are needed.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```no_run
-    /// use anyhow::Result;
-    /// use futures::io;
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::ListToken;
     /// use opendal::Operator;
-    /// use std::time::Duration;
     ///
-    /// async fn test(op: Operator) -> Result<()> {
-    ///     let signed_req = op.presign_stat_with("test",Duration::from_secs(3600)).override_content_disposition("attachment; filename=\"othertext.txt\"").await?;
-    /// #    Ok(())
+    /// # async fn test(op: Operator, token: &str) -> Result<()> {
+    /// let token: ListToken = token.parse()?;
+    /// let mut lister = op.lister_from(&token).await?;
+    /// while let Some(entry) = lister.try_next().await? {
+    ///     println!("{}", entry.path());
+    /// }
+    /// # Ok(())
     /// # }
     /// ```
-    pub fn presign_stat_with(
-        &self,
-        path: &str,
-        expire: Duration,
-    ) -> FuturePresignStat<impl Future<Output = Result<PresignedRequest>>> {
-        let path = normalize_path(path);
+    pub async fn lister_from(&self, token: &ListToken) -> Result<Lister> {
+        let fut = self.lister_with(token.path()).recursive(token.recursive());
+
+        let mut lister = match token.start_after() {
+            None => fut.await?,
+            Some(start_after) => {
+                if !self.info().full_capability().list_with_start_after {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "service doesn't support list with start after",
+                    )
+                    .with_operation("Operator::lister_from")
+                    .with_context("service", self.info().scheme()));
+                }
+                fut.start_after(start_after).await?
+            }
+        };
+        lister.set_end_before(token.end_before().map(|v| v.to_string()));
 
-        OperatorFuture::new(
-            self.inner().clone(),
-            path,
-            (OpStat::default(), expire),
-            |inner, path, (args, dur)| async move {
-                let op = OpPresign::new(args, dur);
-                let rp = inner.presign(&path, op).await?;
-                Ok(rp.into_presigned_request())
-            },
-        )
+        Ok(lister)
     }
 
-    /// Presign an operation for read.
+    /// Split the recursive listing of `prefix` into `n` listers with disjoint
+    /// key ranges, so that a bucket can be scanned in parallel without overlap.
     ///
     /// # Notes
     ///
-    /// ## Extra Options
-    ///
-    /// `presign_read` is a wrapper of [`Self::presign_read_with`] without any options. To use
-    /// extra options like `override_content_disposition`, please use [`Self::presign_read_with`]
-    /// instead.
+    /// - Refer to [`ListToken::partitions`] for how the key space is split.
+    ///   Use it directly to get serializable tokens if partitions will be
+    ///   scanned on other machines.
+    /// - Partitions other than the first one requires `list_with_start_after`,
+    ///   which services supporting it return entries in lexicographical order.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```no_run
-    /// use anyhow::Result;
-    /// use futures::io;
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
     /// use opendal::Operator;
-    /// use std::time::Duration;
     ///
-    /// async fn test(op: Operator) -> Result<()> {
-    ///     let signed_req = op.presign_read("test.txt", Duration::from_secs(3600)).await?;
-    /// #    Ok(())
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let listers = op.list_partitions("data/", 8).await?;
+    /// let counts = futures::future::try_join_all(
+    ///     listers
+    ///         .into_iter()
+    ///         .map(|lister| lister.try_fold(0usize, |n, _| async move { Ok(n + 1) })),
+    /// )
+    /// .await?;
+    /// println!("total entries: {}", counts.iter().sum::<usize>());
+    /// # Ok(())
     /// # }
     /// ```
+    pub async fn list_partitions(&self, prefix: &str, n: usize) -> Result<Vec<Lister>> {
+        let mut listers = Vec::with_capacity(n);
+        for token in ListToken::partitions(prefix, n, true) {
+            listers.push(self.lister_from(&token).await?);
+        }
+        Ok(listers)
+    }
+}
+
+/// Operator multipart upload API.
+impl Operator {
+    /// List in-progress multipart uploads under the given path prefix.
     ///
-    /// - `signed_req.method()`: `GET`
-    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>`
-    /// - `signed_req.headers()`: `{ "host": "s3.amazonaws.com" }`
+    /// Uploads started by [`Writer`] that never got closed or aborted (for
+    /// example, because the process crashed) will stay on services like s3
+    /// until they are cleaned up. This API lists them so that users can
+    /// inspect or abort them.
     ///
-    /// We can download this file via `curl` or other tools without credentials:
+    /// # Notes
+    ///
+    /// - Require [`Capability::list_multipart_uploads`], returns `Unsupported` otherwise.
+    /// - `path` is used as a prefix, use `""` or `"/"` to list all uploads under root.
+    ///
+    /// # Examples
     ///
-    /// ```shell
-    /// curl "https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>" -O /tmp/test.txt
     /// ```
-    pub async fn presign_read(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for upload in op.list_multipart_uploads("path/to/dir/").await? {
+    ///     println!("{} {}", upload.path(), upload.upload_id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_multipart_uploads(&self, path: &str) -> Result<Vec<MultipartUpload>> {
         let path = normalize_path(path);
 
-        let op = OpPresign::new(OpRead::new(), expire);
-
-        let rp = self.inner().presign(&path, op).await?;
-        Ok(rp.into_presigned_request())
+        let rp = self
+            .inner()
+            .list_multipart_uploads(
+                &path,
+                OpListMultipartUploads::new().with_context(self.default_context.clone()),
+            )
+            .await?;
+        Ok(rp.into_uploads())
     }
 
-    /// Presign an operation for read with extra options.
+    /// Abort in-progress multipart uploads under the given path prefix that
+    /// have been initiated more than `older_than` ago.
     ///
-    /// # Options
+    /// Returns the number of aborted uploads. Uploads whose initiated time is
+    /// unknown are kept, and uploads that have been completed or aborted
+    /// concurrently are ignored.
     ///
-    /// ## `override_content_disposition`
+    /// # Notes
     ///
-    /// Override the [`content-disposition`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Disposition) header returned by storage services.
+    /// - Require [`Capability::list_multipart_uploads`] and [`Capability::abort_multipart_upload`].
+    /// - Pick an `older_than` that is longer than your longest running write, otherwise
+    ///   active uploads will be aborted and their writers will fail on close.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let aborted = op
+    ///     .abort_stale_uploads("/", Duration::from_secs(24 * 3600))
+    ///     .await?;
+    /// println!("aborted {aborted} stale uploads");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn abort_stale_uploads(&self, path: &str, older_than: Duration) -> Result<usize> {
+        let now = Utc::now();
+
+        let mut aborted = 0;
+        for upload in self.list_multipart_uploads(path).await? {
+            let Some(initiated) = upload.initiated() else {
+                continue;
+            };
+            // `to_std` fails if the upload is initiated in the future, which
+            // can happen with clock skew, treat them as fresh.
+            match now.signed_duration_since(initiated).to_std() {
+                Ok(age) if age >= older_than => {}
+                _ => continue,
+            }
+
+            let res = self
+                .inner()
+                .abort_multipart_upload(
+                    upload.path(),
+                    OpAbortMultipartUpload::new(upload.upload_id())
+                        .with_context(self.default_context.clone()),
+                )
+                .await;
+            match res {
+                Ok(_) => aborted += 1,
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(aborted)
+    }
+}
+
+/// Operator concat API.
+impl Operator {
+    /// Concat the sources in order into a new file at given path at server side.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::concat`], returns `Unsupported` otherwise.
+    /// - The target will be overwritten if exists, sources will be kept except on
+    ///   services that move the data of sources like `webhdfs`.
+    /// - Services may limit the number of sources, see [`Capability::concat_max_sources`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.concat("path/to/file", &["path/to/part-1", "path/to/part-2"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn concat(&self, path: &str, sources: &[&str]) -> Result<()> {
+        self.concat_with(path, sources).await
+    }
+
+    /// Concat the sources in order into a new file at given path with extra options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// // Only succeed if the target doesn't exist.
+    /// op.concat_with("path/to/file", &["path/to/part-1", "path/to/part-2"])
+    ///     .if_generation_match(0)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn concat_with(
+        &self,
+        path: &str,
+        sources: &[&str],
+    ) -> FutureConcat<impl Future<Output = Result<()>>> {
+        let path = normalize_path(path);
+        let sources: Vec<String> = sources.iter().map(|v| normalize_path(v)).collect();
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpConcat::new(sources).with_context(self.default_context.clone()),
+            |inner, path, args| async move {
+                let invalid = std::iter::once(path.as_str())
+                    .chain(args.sources().iter().map(|v| v.as_str()))
+                    .find(|v| !validate_path(v, EntryMode::FILE));
+                if let Some(v) = invalid {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "concat path is a directory")
+                            .with_operation("concat")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("path", v),
+                    );
+                }
+                if args.sources().is_empty() {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "concat requires at least one source",
+                    )
+                    .with_operation("concat")
+                    .with_context("service", inner.info().scheme())
+                    .with_context("path", &path));
+                }
+
+                inner.concat(&path, args).await?;
+                Ok(())
+            },
+        )
+    }
+}
+
+/// Operator data locality API.
+impl Operator {
+    /// Locate the blocks of the file at given path that overlap with range.
+    ///
+    /// Services like HDFS store files as blocks on different hosts, compute
+    /// engines can use the returned hosts to schedule tasks near the data.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::locate`], returns `Unsupported` otherwise.
+    /// - The returned locations are hints only, blocks could be moved at any time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for block in op.locate("path/to/file", ..).await? {
+    ///     println!("{}+{} at {:?}", block.offset(), block.length(), block.hosts());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn locate(
+        &self,
+        path: &str,
+        range: impl RangeBounds<u64>,
+    ) -> Result<Vec<BlockLocation>> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "locate path is a directory")
+                    .with_operation("locate")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let rp = self
+            .inner()
+            .locate(&path, OpLocate::new().with_range(range.into()))
+            .await?;
+        Ok(rp.into_locations())
+    }
+}
+
+/// Operator data protection API.
+impl Operator {
+    /// Get the lifecycle rules of the underlying bucket.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::lifecycle`], returns `Unsupported` otherwise.
+    /// - Only rules under root are returned, and their prefixes are relative to root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for rule in op.lifecycle().await? {
+    ///     println!("{} {} {:?}", rule.id(), rule.prefix(), rule.expiration_days());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn lifecycle(&self) -> Result<Vec<LifecycleRule>> {
+        let rp = self.inner().get_lifecycle(OpGetLifecycle::new()).await?;
+        Ok(rp.into_rules())
+    }
+
+    /// Replace the lifecycle rules of the underlying bucket.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::lifecycle`], returns `Unsupported` otherwise.
+    /// - All existing rules of the bucket will be replaced, including those outside of
+    ///   root. Use an empty list to remove all rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use opendal::LifecycleRule;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.set_lifecycle(vec![LifecycleRule::new("expire-tmp", "tmp/")
+    ///     .with_expiration_days(7)
+    ///     .with_abort_incomplete_upload_days(1)])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_lifecycle(&self, rules: Vec<LifecycleRule>) -> Result<()> {
+        self.inner()
+            .set_lifecycle(OpSetLifecycle::new(rules))
+            .await?;
+        Ok(())
+    }
+
+    /// Get the retention and legal hold of the file at given path.
+    ///
+    /// # Notes
+    ///
+    /// Require [`Capability::retention`], returns `Unsupported` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let retention = op.retention("path/to/file").await?;
+    /// if let Some(v) = retention.retention() {
+    ///     println!("retained until {}", v.retain_until());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retention(&self, path: &str) -> Result<ObjectRetention> {
+        let path = self.check_retention_path(path, "retention")?;
+
+        let rp = self
+            .inner()
+            .get_retention(&path, OpGetRetention::new())
+            .await?;
+        Ok(rp.into_retention())
+    }
+
+    /// Set the retention of the file at given path.
+    ///
+    /// The file can't be overwritten or deleted until the retention expires.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::retention`], returns `Unsupported` otherwise.
+    /// - Services usually require object lock (or version level immutability) to be
+    ///   enabled on the bucket (or container) first.
+    /// - Retention in [`RetentionMode::Compliance`] can't be shortened or removed by anyone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use chrono::Duration;
+    /// use chrono::Utc;
+    /// use opendal::Retention;
+    /// use opendal::RetentionMode;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let until = Utc::now() + Duration::days(30);
+    /// op.set_retention(
+    ///     "path/to/file",
+    ///     Retention::new(RetentionMode::Governance, until),
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_retention(&self, path: &str, retention: Retention) -> Result<()> {
+        let path = self.check_retention_path(path, "set_retention")?;
+
+        self.inner()
+            .set_retention(&path, OpSetRetention::new().with_retention(retention))
+            .await?;
+        Ok(())
+    }
+
+    /// Place or remove the legal hold of the file at given path.
+    ///
+    /// The file can't be deleted while it's under legal hold.
+    ///
+    /// # Notes
+    ///
+    /// Require [`Capability::retention`], returns `Unsupported` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.set_legal_hold("path/to/file", true).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_legal_hold(&self, path: &str, legal_hold: bool) -> Result<()> {
+        let path = self.check_retention_path(path, "set_legal_hold")?;
+
+        self.inner()
+            .set_retention(&path, OpSetRetention::new().with_legal_hold(legal_hold))
+            .await?;
+        Ok(())
+    }
+
+    fn check_retention_path(&self, path: &str, op: &'static str) -> Result<String> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "retention path is a directory")
+                    .with_operation(op)
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+        Ok(path)
+    }
+}
+
+/// Operator acl API.
+impl Operator {
+    /// Get the canned acl of the file at given path.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::acl`], returns `Unsupported` otherwise.
+    /// - Services will return the closest canned acl if the real acl can't be
+    ///   represented by [`Acl`], for example, an object readable by everyone will be
+    ///   returned as [`Acl::PublicRead`].
+    /// - Azure Blob only supports public access level of the whole container, use `/`
+    ///   as path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use opendal::Acl;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let acl = op.acl("path/to/file").await?;
+    /// if acl == Acl::PublicRead {
+    ///     println!("file is public");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn acl(&self, path: &str) -> Result<Acl> {
+        let path = normalize_path(path);
+
+        let rp = self.inner().get_acl(&path, OpGetAcl::new()).await?;
+        Ok(rp.into_acl())
+    }
+
+    /// Set the canned acl of the file at given path.
+    ///
+    /// Use [`FutureWrite::acl`] to set acl while writing instead.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::acl`], returns `Unsupported` otherwise.
+    /// - Services will return `Unsupported` if the acl can't be mapped to their
+    ///   predefined acls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use opendal::Acl;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.set_acl("path/to/file", Acl::PublicRead).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_acl(&self, path: &str, acl: Acl) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner().set_acl(&path, OpSetAcl::new(acl)).await?;
+        Ok(())
+    }
+}
+
+/// Operator bucket management API.
+impl Operator {
+    /// Create the bucket (or container) that this operator is configured with.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::create_bucket`], returns `Unsupported` otherwise.
+    /// - Returns `AlreadyExists` if the bucket has been created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::ErrorKind;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// match op.create_bucket().await {
+    ///     Ok(_) => println!("bucket {} created", op.info().name()),
+    ///     Err(err) if err.kind() == ErrorKind::AlreadyExists => {}
+    ///     Err(err) => return Err(err.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_bucket(&self) -> Result<()> {
+        self.inner().create_bucket(OpCreateBucket::new()).await?;
+        Ok(())
+    }
+
+    /// Delete the bucket (or container) that this operator is configured with.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::delete_bucket`], returns `Unsupported` otherwise.
+    /// - Most services refuse to delete a bucket that is not empty, use
+    ///   [`Operator::remove_all`] to clean it up first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.remove_all("/").await?;
+    /// op.delete_bucket().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_bucket(&self) -> Result<()> {
+        self.inner().delete_bucket(OpDeleteBucket::new()).await?;
+        Ok(())
+    }
+
+    /// List the names of all buckets (or containers) that are visible to the
+    /// configured credential.
+    ///
+    /// # Notes
+    ///
+    /// Require [`Capability::list_buckets`], returns `Unsupported` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for name in op.list_buckets().await? {
+    ///     println!("{name}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_buckets(&self) -> Result<Vec<String>> {
+        let rp = self.inner().list_buckets(OpListBuckets::new()).await?;
+        Ok(rp.into_buckets())
+    }
+}
+
+/// Operator presign API.
+impl Operator {
+    /// Presign an operation for stat(head).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::io;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op.presign_stat("test",Duration::from_secs(3600)).await?;
+    ///     let req = http::Request::builder()
+    ///         .method(signed_req.method())
+    ///         .uri(signed_req.uri())
+    ///         .body(())?;
+    ///
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn presign_stat(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpStat::new(), expire);
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for stat(head).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::io;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op.presign_stat_with("test",Duration::from_secs(3600)).override_content_disposition("attachment; filename=\"othertext.txt\"").await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn presign_stat_with(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> FuturePresignStat<impl Future<Output = Result<PresignedRequest>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (OpStat::default(), expire),
+            |inner, path, (args, dur)| async move {
+                let op = OpPresign::new(args, dur);
+                let rp = inner.presign(&path, op).await?;
+                Ok(rp.into_presigned_request())
+            },
+        )
+    }
+
+    /// Presign an operation for read.
+    ///
+    /// # Notes
+    ///
+    /// ## Extra Options
+    ///
+    /// `presign_read` is a wrapper of [`Self::presign_read_with`] without any options. To use
+    /// extra options like `override_content_disposition`, please use [`Self::presign_read_with`]
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use futures::io;
+    /// use opendal::Operator;
+    /// use std::time::Duration;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op.presign_read("test.txt", Duration::from_secs(3600)).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `GET`
+    /// - `signed_req.uri()`: `https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>`
+    /// - `signed_req.headers()`: `{ "host": "s3.amazonaws.com" }`
+    ///
+    /// We can download this file via `curl` or other tools without credentials:
+    ///
+    /// ```shell
+    /// curl "https://s3.amazonaws.com/examplebucket/test.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=access_key_id/20130721/us-east-1/s3/aws4_request&X-Amz-Date=20130721T201207Z&X-Amz-Expires=86400&X-Amz-SignedHeaders=host&X-Amz-Signature=<signature-value>" -O /tmp/test.txt
+    /// ```
+    pub async fn presign_read(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpRead::new(), expire);
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for read with extra options.
+    ///
+    /// # Options
+    ///
+    /// ## `override_content_disposition`
+    ///
+    /// Override the [`content-disposition`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Disposition) header returned by storage services.
     ///
     /// ```no_run
     /// use std::time::Duration;
@@ -2233,4 +3801,66 @@ impl Operator {
             },
         )
     }
+
+    /// Send a presigned request with given body.
+    ///
+    /// The http client of underlying service will be used if possible, so that
+    /// requests presigned on one node could be sent on another node with
+    /// the same settings like timeout and proxy. Otherwise, a default http
+    /// client will be used.
+    ///
+    /// # Notes
+    ///
+    /// The response will be returned as is, callers should check the status code by themselves.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_write("test.txt", Duration::from_secs(3600))
+    ///         .await?;
+    ///     let resp = op.send_presigned(signed_req, "Hello, World!").await?;
+    ///     assert!(resp.status().is_success());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_presigned(
+        &self,
+        req: PresignedRequest,
+        body: impl Into<Buffer>,
+    ) -> Result<http::Response<Buffer>> {
+        let client = match self.inner().info().http_client() {
+            Some(client) => client,
+            None => HttpClient::new()?,
+        };
+
+        req.send(&client, body.into()).await
+    }
+}
+
+/// Execute the given check step and record the elapsed time.
+async fn check_step<T>(
+    steps: &mut Vec<CheckStep>,
+    operation: &'static str,
+    path: &str,
+    fut: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let start = Instant::now();
+    let res = fut.await;
+    steps.push(CheckStep {
+        operation,
+        path: path.to_string(),
+        elapsed: start.elapsed(),
+    });
+
+    res.map_err(|err| {
+        err.with_context("check_step", operation)
+            .with_context("check_path", path)
+    })
 }
@@ -20,6 +20,7 @@
 //! By using functions, users can add more options for operation.
 
 use std::ops::RangeBounds;
+use std::time::Duration;
 
 use flagset::FlagSet;
 
@@ -135,6 +136,87 @@ impl FunctionWrite {
         self
     }
 
+    /// Set the If-Match for this write request.
+    ///
+    /// The write will only succeed if the etag of existing object matches,
+    /// otherwise `ConditionNotMatch` will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_match` is not supported.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_if_match(v), options, bs));
+        self
+    }
+
+    /// Set the If-None-Match for this write request.
+    ///
+    /// Use `*` to make sure the write will only succeed if the object doesn't
+    /// exist, otherwise `ConditionNotMatch` will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_none_match` is not supported.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_if_none_match(v), options, bs));
+        self
+    }
+
+    /// Set the block size of the file to create.
+    ///
+    /// This is a hint for Hadoop-like services and will be ignored if
+    /// `write_with_block_size` is not supported.
+    pub fn block_size(mut self, v: u64) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_block_size(v), options, bs));
+        self
+    }
+
+    /// Set the replication factor of the file to create.
+    ///
+    /// This is a hint for Hadoop-like services and will be ignored if
+    /// `write_with_replication` is not supported.
+    pub fn replication(mut self, v: u16) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_replication(v), options, bs));
+        self
+    }
+
+    /// Set the permission of the file to create, like `0o644`.
+    ///
+    /// This will be ignored if `write_with_permission` is not supported.
+    pub fn permission(mut self, v: u32) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_permission(v), options, bs));
+        self
+    }
+
+    /// Set the time to live of the object to create.
+    ///
+    /// The object will be expired by the service after the given duration, which
+    /// makes cache like usage possible without cleaning up by hand.
+    ///
+    /// Service will return `Unsupported` if `write_with_ttl` is not supported.
+    pub fn ttl(mut self, v: Duration) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_ttl(v), options, bs));
+        self
+    }
+
+    /// Set the canned acl of the object to create.
+    ///
+    /// Service will return `Unsupported` if `write_with_acl` is not supported.
+    pub fn acl(mut self, v: Acl) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options, bs)| (args.with_acl(v), options, bs));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<()> {
@@ -218,6 +300,87 @@ impl FunctionWriter {
         self
     }
 
+    /// Set the If-Match for this write request.
+    ///
+    /// The write will only succeed if the etag of existing object matches,
+    /// otherwise `ConditionNotMatch` will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_match` is not supported.
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_if_match(v), options));
+        self
+    }
+
+    /// Set the If-None-Match for this write request.
+    ///
+    /// Use `*` to make sure the write will only succeed if the object doesn't
+    /// exist, otherwise `ConditionNotMatch` will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_none_match` is not supported.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_if_none_match(v), options));
+        self
+    }
+
+    /// Set the block size of the file to create.
+    ///
+    /// This is a hint for Hadoop-like services and will be ignored if
+    /// `write_with_block_size` is not supported.
+    pub fn block_size(mut self, v: u64) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_block_size(v), options));
+        self
+    }
+
+    /// Set the replication factor of the file to create.
+    ///
+    /// This is a hint for Hadoop-like services and will be ignored if
+    /// `write_with_replication` is not supported.
+    pub fn replication(mut self, v: u16) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_replication(v), options));
+        self
+    }
+
+    /// Set the permission of the file to create, like `0o644`.
+    ///
+    /// This will be ignored if `write_with_permission` is not supported.
+    pub fn permission(mut self, v: u32) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_permission(v), options));
+        self
+    }
+
+    /// Set the time to live of the object to create.
+    ///
+    /// The object will be expired by the service after the given duration, which
+    /// makes cache like usage possible without cleaning up by hand.
+    ///
+    /// Service will return `Unsupported` if `write_with_ttl` is not supported.
+    pub fn ttl(mut self, v: Duration) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_ttl(v), options));
+        self
+    }
+
+    /// Set the canned acl of the object to create.
+    ///
+    /// Service will return `Unsupported` if `write_with_acl` is not supported.
+    pub fn acl(mut self, v: Acl) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, options)| (args.with_acl(v), options));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<BlockingWriter> {
@@ -277,6 +440,19 @@ impl FunctionList {
         self
     }
 
+    /// The sorted is used to control whether entries must be returned in
+    /// lexicographical order of their paths.
+    ///
+    /// If the service can't guarantee it (check `list_sorted` in [`Capability`]),
+    /// entries will be fetched and sorted client-side, which will spill to
+    /// local temp dir for huge listings.
+    ///
+    /// Default to `false`.
+    pub fn sorted(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sorted(v));
+        self
+    }
+
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
@@ -330,6 +506,19 @@ impl FunctionLister {
         self
     }
 
+    /// The sorted is used to control whether entries must be returned in
+    /// lexicographical order of their paths.
+    ///
+    /// If the service can't guarantee it (check `list_sorted` in [`Capability`]),
+    /// entries will be fetched and sorted client-side, which will spill to
+    /// local temp dir for huge listings.
+    ///
+    /// Default to `false`.
+    pub fn sorted(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sorted(v));
+        self
+    }
+
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
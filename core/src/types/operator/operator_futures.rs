@@ -22,10 +22,15 @@
 use std::collections::HashMap;
 use std::future::IntoFuture;
 use std::ops::RangeBounds;
+use std::sync::Arc;
 use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
 use flagset::FlagSet;
 use futures::Future;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
 
 use crate::raw::*;
 use crate::*;
@@ -109,6 +114,11 @@ impl<F: Future<Output = Result<Metadata>>> FutureStat<F> {
     pub fn version(self, v: &str) -> Self {
         self.map(|args| args.with_version(v))
     }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|args| args.with_context(context))
+    }
 }
 
 /// Future that generated by [`Operator::presign_stat_with`].
@@ -218,6 +228,13 @@ impl<F: Future<Output = Result<Buffer>>> FutureRead<F> {
         self.map(|(args, op_reader)| (args.with_version(v), op_reader))
     }
 
+    /// Set the if generation match for this operation.
+    ///
+    /// Service will return `Unsupported` if `read_with_if_generation_match` is not supported.
+    pub fn if_generation_match(self, v: u64) -> Self {
+        self.map(|(args, op_reader)| (args.with_if_generation_match(v), op_reader))
+    }
+
     /// Set the executor for this operation.
     pub fn executor(self, executor: Executor) -> Self {
         self.map(|(args, op_reader)| (args.with_executor(executor), op_reader))
@@ -237,6 +254,109 @@ impl<F: Future<Output = Result<Buffer>>> FutureRead<F> {
     pub fn chunk(self, chunk_size: usize) -> Self {
         self.map(|(args, op_reader)| (args, op_reader.with_chunk(chunk_size)))
     }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|(args, op_reader)| (args.with_context(context), op_reader))
+    }
+
+    /// Set the deadline for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the deadline exceeded.
+    pub fn deadline(self, v: Instant) -> Self {
+        self.map(|(args, op_reader)| {
+            let ctx = args.context().clone().with_deadline(v);
+            (args.with_context(ctx), op_reader)
+        })
+    }
+
+    /// Set the cancellation token for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the token cancelled.
+    pub fn cancellation(self, v: CancellationToken) -> Self {
+        self.map(|(args, op_reader)| {
+            let ctx = args.context().clone().with_cancellation(v);
+            (args.with_context(ctx), op_reader)
+        })
+    }
+}
+
+/// Future that generated by [`Operator::read_partial_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureReadPartial<F> = OperatorFuture<OpRead, PartialRead, F>;
+
+impl<F: Future<Output = Result<PartialRead>>> FutureReadPartial<F> {
+    /// Set the If-Match for this operation.
+    pub fn if_match(self, v: &str) -> Self {
+        self.map(|args| args.with_if_match(v))
+    }
+
+    /// Set the If-None-Match for this operation.
+    pub fn if_none_match(self, v: &str) -> Self {
+        self.map(|args| args.with_if_none_match(v))
+    }
+
+    /// Set the version for this operation.
+    pub fn version(self, v: &str) -> Self {
+        self.map(|args| args.with_version(v))
+    }
+
+    /// Set the range header for this operation.
+    pub fn range(self, range: impl RangeBounds<u64>) -> Self {
+        self.map(|args| args.with_range(range.into()))
+    }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|args| args.with_context(context))
+    }
+}
+
+/// Future that generated by [`Operator::read_to_file_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureReadToFile<F> = OperatorFuture<(OpRead, OpReader, OpReadToFile), (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureReadToFile<F> {
+    /// Set the version for this operation.
+    pub fn version(self, v: &str) -> Self {
+        self.map(|(args, op_reader, op_file)| (args.with_version(v), op_reader, op_file))
+    }
+
+    /// Set the executor for this operation.
+    pub fn executor(self, executor: Executor) -> Self {
+        self.map(|(args, op_reader, op_file)| (args.with_executor(executor), op_reader, op_file))
+    }
+
+    /// Set the concurrent read task amount.
+    pub fn concurrent(self, concurrent: usize) -> Self {
+        self.map(|(args, op_reader, op_file)| {
+            (args, op_reader.with_concurrent(concurrent), op_file)
+        })
+    }
+
+    /// Set the chunk size for this operation.
+    pub fn chunk(self, chunk_size: usize) -> Self {
+        self.map(|(args, op_reader, op_file)| (args, op_reader.with_chunk(chunk_size), op_file))
+    }
+
+    /// Resume the download from the progress recorded by previous call.
+    pub fn resume(self, v: bool) -> Self {
+        self.map(|(args, op_reader, op_file)| (args, op_reader, op_file.with_resume(v)))
+    }
+
+    /// Verify the checksum of local file after download.
+    pub fn checksum(self, v: bool) -> Self {
+        self.map(|(args, op_reader, op_file)| (args, op_reader, op_file.with_checksum(v)))
+    }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|(args, op_reader, op_file)| (args.with_context(context), op_reader, op_file))
+    }
 }
 
 /// Future that generated by [`Operator::read_with`] or [`Operator::reader_with`].
@@ -254,6 +374,13 @@ impl<F: Future<Output = Result<Reader>>> FutureReader<F> {
         self.map(|(op_read, op_reader)| (op_read.with_version(v), op_reader))
     }
 
+    /// Set the if generation match for this operation.
+    ///
+    /// Service will return `Unsupported` if `read_with_if_generation_match` is not supported.
+    pub fn if_generation_match(self, v: u64) -> Self {
+        self.map(|(op_read, op_reader)| (op_read.with_if_generation_match(v), op_reader))
+    }
+
     /// Set the concurrent read task amount.
     pub fn concurrent(self, concurrent: usize) -> Self {
         self.map(|(op_read, op_reader)| (op_read, op_reader.with_concurrent(concurrent)))
@@ -268,6 +395,43 @@ impl<F: Future<Output = Result<Reader>>> FutureReader<F> {
     pub fn gap(self, gap_size: usize) -> Self {
         self.map(|(op_read, op_reader)| (op_read, op_reader.with_gap(gap_size)))
     }
+
+    /// Set the access pattern hint for this reader.
+    pub fn access(self, access: AccessPattern) -> Self {
+        self.map(|(op_read, op_reader)| (op_read, op_reader.with_access(access)))
+    }
+
+    /// Set the number of chunks to prefetch in background while reading sequentially.
+    pub fn prefetch(self, prefetch: usize) -> Self {
+        self.map(|(op_read, op_reader)| (op_read, op_reader.with_prefetch(prefetch)))
+    }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|(args, op_reader)| (args.with_context(context), op_reader))
+    }
+
+    /// Set the deadline for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the deadline exceeded.
+    pub fn deadline(self, v: Instant) -> Self {
+        self.map(|(args, op_reader)| {
+            let ctx = args.context().clone().with_deadline(v);
+            (args.with_context(ctx), op_reader)
+        })
+    }
+
+    /// Set the cancellation token for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the token cancelled.
+    pub fn cancellation(self, v: CancellationToken) -> Self {
+        self.map(|(args, op_reader)| {
+            let ctx = args.context().clone().with_cancellation(v);
+            (args.with_context(ctx), op_reader)
+        })
+    }
 }
 
 /// Future that generated by [`Operator::write_with`].
@@ -339,6 +503,104 @@ impl<F: Future<Output = Result<()>>> FutureWrite<F> {
             )
         })
     }
+
+    /// Set the If-Match for this write request.
+    ///
+    /// The write will only succeed if the etag of existing object matches,
+    /// otherwise `ConditionNotMatch` will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_match` is not supported.
+    pub fn if_match(self, v: &str) -> Self {
+        self.map(|(args, options, bs)| (args.with_if_match(v), options, bs))
+    }
+
+    /// Set the If-None-Match for this write request.
+    ///
+    /// Use `*` to make sure the write will only succeed if the object doesn't
+    /// exist, otherwise `ConditionNotMatch` will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_none_match` is not supported.
+    pub fn if_none_match(self, v: &str) -> Self {
+        self.map(|(args, options, bs)| (args.with_if_none_match(v), options, bs))
+    }
+
+    /// Set the if generation match for this write request.
+    ///
+    /// The write will only succeed if the generation of existing object matches,
+    /// use `0` to make sure the object doesn't exist. Otherwise `ConditionNotMatch`
+    /// will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_generation_match` is not supported.
+    pub fn if_generation_match(self, v: u64) -> Self {
+        self.map(|(args, options, bs)| (args.with_if_generation_match(v), options, bs))
+    }
+
+    /// Set the block size of the file to create.
+    ///
+    /// This is a hint for Hadoop-like services and will be ignored if
+    /// `write_with_block_size` is not supported.
+    pub fn block_size(self, v: u64) -> Self {
+        self.map(|(args, options, bs)| (args.with_block_size(v), options, bs))
+    }
+
+    /// Set the replication factor of the file to create.
+    ///
+    /// This is a hint for Hadoop-like services and will be ignored if
+    /// `write_with_replication` is not supported.
+    pub fn replication(self, v: u16) -> Self {
+        self.map(|(args, options, bs)| (args.with_replication(v), options, bs))
+    }
+
+    /// Set the permission of the file to create, like `0o644`.
+    ///
+    /// This will be ignored if `write_with_permission` is not supported.
+    pub fn permission(self, v: u32) -> Self {
+        self.map(|(args, options, bs)| (args.with_permission(v), options, bs))
+    }
+
+    /// Set the time to live of the object to create.
+    ///
+    /// The object will be expired by the service after the given duration, which
+    /// makes cache like usage possible without cleaning up by hand.
+    ///
+    /// Service will return `Unsupported` if `write_with_ttl` is not supported.
+    pub fn ttl(self, v: Duration) -> Self {
+        self.map(|(args, options, bs)| (args.with_ttl(v), options, bs))
+    }
+
+    /// Set the canned acl of the object to create.
+    ///
+    /// Service will return `Unsupported` if `write_with_acl` is not supported.
+    pub fn acl(self, v: Acl) -> Self {
+        self.map(|(args, options, bs)| (args.with_acl(v), options, bs))
+    }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|(args, options, bs)| (args.with_context(context), options, bs))
+    }
+
+    /// Set the deadline for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the deadline exceeded.
+    pub fn deadline(self, v: Instant) -> Self {
+        self.map(|(args, options, bs)| {
+            let ctx = args.context().clone().with_deadline(v);
+            (args.with_context(ctx), options, bs)
+        })
+    }
+
+    /// Set the cancellation token for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the token cancelled.
+    pub fn cancellation(self, v: CancellationToken) -> Self {
+        self.map(|(args, options, bs)| {
+            let ctx = args.context().clone().with_cancellation(v);
+            (args.with_context(ctx), options, bs)
+        })
+    }
 }
 
 /// Future that generated by [`Operator::writer_with`].
@@ -373,6 +635,10 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
     /// - GCS requires the part size to be aligned with 256 KiB.
     ///
     /// The services will alter the chunk size to meet their requirements.
+    ///
+    /// For services that limit the number of parts like AWS S3, the chunk size will
+    /// be doubled every 1/10 of the parts limit until reaching the max part size, so
+    /// that large uploads won't run out of parts.
     pub fn chunk(self, v: usize) -> Self {
         self.map(|(args, options)| (args, options.with_chunk(v)))
     }
@@ -411,6 +677,104 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
     pub fn user_metadata(self, data: impl IntoIterator<Item = (String, String)>) -> Self {
         self.map(|(args, options)| (args.with_user_metadata(HashMap::from_iter(data)), options))
     }
+
+    /// Set the If-Match for this write request.
+    ///
+    /// The write will only succeed if the etag of existing object matches,
+    /// otherwise `ConditionNotMatch` will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_match` is not supported.
+    pub fn if_match(self, v: &str) -> Self {
+        self.map(|(args, options)| (args.with_if_match(v), options))
+    }
+
+    /// Set the If-None-Match for this write request.
+    ///
+    /// Use `*` to make sure the write will only succeed if the object doesn't
+    /// exist, otherwise `ConditionNotMatch` will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_none_match` is not supported.
+    pub fn if_none_match(self, v: &str) -> Self {
+        self.map(|(args, options)| (args.with_if_none_match(v), options))
+    }
+
+    /// Set the if generation match for this write request.
+    ///
+    /// The write will only succeed if the generation of existing object matches,
+    /// use `0` to make sure the object doesn't exist. Otherwise `ConditionNotMatch`
+    /// will be returned.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_generation_match` is not supported.
+    pub fn if_generation_match(self, v: u64) -> Self {
+        self.map(|(args, options)| (args.with_if_generation_match(v), options))
+    }
+
+    /// Set the block size of the file to create.
+    ///
+    /// This is a hint for Hadoop-like services and will be ignored if
+    /// `write_with_block_size` is not supported.
+    pub fn block_size(self, v: u64) -> Self {
+        self.map(|(args, options)| (args.with_block_size(v), options))
+    }
+
+    /// Set the replication factor of the file to create.
+    ///
+    /// This is a hint for Hadoop-like services and will be ignored if
+    /// `write_with_replication` is not supported.
+    pub fn replication(self, v: u16) -> Self {
+        self.map(|(args, options)| (args.with_replication(v), options))
+    }
+
+    /// Set the permission of the file to create, like `0o644`.
+    ///
+    /// This will be ignored if `write_with_permission` is not supported.
+    pub fn permission(self, v: u32) -> Self {
+        self.map(|(args, options)| (args.with_permission(v), options))
+    }
+
+    /// Set the time to live of the object to create.
+    ///
+    /// The object will be expired by the service after the given duration, which
+    /// makes cache like usage possible without cleaning up by hand.
+    ///
+    /// Service will return `Unsupported` if `write_with_ttl` is not supported.
+    pub fn ttl(self, v: Duration) -> Self {
+        self.map(|(args, options)| (args.with_ttl(v), options))
+    }
+
+    /// Set the canned acl of the object to create.
+    ///
+    /// Service will return `Unsupported` if `write_with_acl` is not supported.
+    pub fn acl(self, v: Acl) -> Self {
+        self.map(|(args, options)| (args.with_acl(v), options))
+    }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|(args, options)| (args.with_context(context), options))
+    }
+
+    /// Set the deadline for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the deadline exceeded.
+    pub fn deadline(self, v: Instant) -> Self {
+        self.map(|(args, options)| {
+            let ctx = args.context().clone().with_deadline(v);
+            (args.with_context(ctx), options)
+        })
+    }
+
+    /// Set the cancellation token for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the token cancelled.
+    pub fn cancellation(self, v: CancellationToken) -> Self {
+        self.map(|(args, options)| {
+            let ctx = args.context().clone().with_cancellation(v);
+            (args.with_context(ctx), options)
+        })
+    }
 }
 
 /// Future that generated by [`Operator::delete_with`].
@@ -423,6 +787,101 @@ impl<F: Future<Output = Result<()>>> FutureDelete<F> {
     pub fn version(self, v: &str) -> Self {
         self.map(|args| args.with_version(v))
     }
+
+    /// Set the if generation match for this delete operation.
+    ///
+    /// Service will return `Unsupported` if `delete_with_if_generation_match` is not supported.
+    pub fn if_generation_match(self, v: u64) -> Self {
+        self.map(|args| args.with_if_generation_match(v))
+    }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|args| args.with_context(context))
+    }
+}
+
+/// Future that generated by [`Operator::concat_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureConcat<F> = OperatorFuture<OpConcat, (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureConcat<F> {
+    /// Set the if generation match of the target for this operation.
+    ///
+    /// Service will return `Unsupported` if `write_with_if_generation_match` is not supported.
+    pub fn if_generation_match(self, v: u64) -> Self {
+        self.map(|args| args.with_if_generation_match(v))
+    }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|args| args.with_context(context))
+    }
+}
+
+/// Future that generated by [`Operator::deleter_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureDeleter<F> = OperatorFuture<OpDeleter, Deleter, F>;
+
+impl<F: Future<Output = Result<Deleter>>> FutureDeleter<F> {
+    /// Set the concurrent delete tasks for this deleter.
+    ///
+    /// Each task will delete one batch of paths.
+    pub fn concurrent(self, concurrent: usize) -> Self {
+        self.map(|args| args.with_concurrent(concurrent))
+    }
+
+    /// Set the max paths to delete in one batch request.
+    ///
+    /// This option will be ignored if the service doesn't support batch delete.
+    pub fn batch_size(self, batch_size: usize) -> Self {
+        self.map(|args| args.with_batch_size(batch_size))
+    }
+}
+
+/// Future that generated by [`Operator::remove_all_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureRemoveAll<F> = OperatorFuture<OpRemoveAll, RemoveAllReport, F>;
+
+impl<F: Future<Output = Result<RemoveAllReport>>> FutureRemoveAll<F> {
+    /// Set the concurrent delete tasks for this operation.
+    pub fn concurrent(self, concurrent: usize) -> Self {
+        self.map(|args| args.with_concurrent(concurrent))
+    }
+
+    /// Set the max paths to delete in one batch request.
+    ///
+    /// This option will be ignored if the service doesn't support batch delete.
+    pub fn batch_size(self, batch_size: usize) -> Self {
+        self.map(|args| args.with_batch_size(batch_size))
+    }
+
+    /// Set the callback to report progress.
+    ///
+    /// The callback will be called with current report after every path has been handled.
+    pub fn progress(self, f: impl Fn(&RemoveAllReport) + Send + Sync + 'static) -> Self {
+        self.map(|args| args.with_progress(Arc::new(f)))
+    }
+}
+
+/// Future that generated by [`Operator::copy_dir_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureCopyDir<F> = OperatorFuture<OpCopyDir, CopyDirReport, F>;
+
+impl<F: Future<Output = Result<CopyDirReport>>> FutureCopyDir<F> {
+    /// Set the concurrent copy tasks for this operation.
+    pub fn concurrent(self, concurrent: usize) -> Self {
+        self.map(|args| args.with_concurrent(concurrent))
+    }
+
+    /// Set the policy for the files that already exist in target dir.
+    pub fn conflict(self, conflict: CopyConflict) -> Self {
+        self.map(|args| args.with_conflict(conflict))
+    }
 }
 
 /// Future that generated by [`Operator::list_with`] or [`Operator::lister_with`].
@@ -455,6 +914,17 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
         self.map(|args| args.with_recursive(v))
     }
 
+    /// The sorted is used to control whether entries must be returned in
+    /// lexicographical order of their paths.
+    ///
+    /// If the service can't guarantee it (check `list_sorted` in [`Capability`]),
+    /// all entries will be fetched and sorted in memory client-side.
+    ///
+    /// Default to `false`.
+    pub fn sorted(self, v: bool) -> Self {
+        self.map(|args| args.with_sorted(v))
+    }
+
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
@@ -475,6 +945,33 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
     pub fn concurrent(self, v: usize) -> Self {
         self.map(|args| args.with_concurrent(v))
     }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|args| args.with_context(context))
+    }
+
+    /// Set the deadline for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the deadline exceeded.
+    pub fn deadline(self, v: Instant) -> Self {
+        self.map(|args| {
+            let ctx = args.context().clone().with_deadline(v);
+            args.with_context(ctx)
+        })
+    }
+
+    /// Set the cancellation token for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the token cancelled.
+    pub fn cancellation(self, v: CancellationToken) -> Self {
+        self.map(|args| {
+            let ctx = args.context().clone().with_cancellation(v);
+            args.with_context(ctx)
+        })
+    }
 }
 
 /// Future that generated by [`Operator::list_with`] or [`Operator::lister_with`].
@@ -507,6 +1004,17 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
         self.map(|args| args.with_recursive(v))
     }
 
+    /// The sorted is used to control whether entries must be returned in
+    /// lexicographical order of their paths.
+    ///
+    /// If the service can't guarantee it (check `list_sorted` in [`Capability`]),
+    /// all entries will be fetched and sorted in memory client-side.
+    ///
+    /// Default to `false`.
+    pub fn sorted(self, v: bool) -> Self {
+        self.map(|args| args.with_sorted(v))
+    }
+
     /// Metakey is used to control which meta should be returned.
     ///
     /// Lister will make sure the result for specified meta is **known**:
@@ -527,4 +1035,31 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
     pub fn concurrent(self, v: usize) -> Self {
         self.map(|args| args.with_concurrent(v))
     }
+
+    /// Set the context for this operation.
+    pub fn context(self, context: OpContext) -> Self {
+        self.map(|args| args.with_context(context))
+    }
+
+    /// Set the deadline for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the deadline exceeded.
+    pub fn deadline(self, v: Instant) -> Self {
+        self.map(|args| {
+            let ctx = args.context().clone().with_deadline(v);
+            args.with_context(ctx)
+        })
+    }
+
+    /// Set the cancellation token for this operation.
+    ///
+    /// The operation and all calls of its returned reader/writer/lister will fail
+    /// once the token cancelled.
+    pub fn cancellation(self, v: CancellationToken) -> Self {
+        self.map(|args| {
+            let ctx = args.context().clone().with_cancellation(v);
+            args.with_context(ctx)
+        })
+    }
 }
@@ -0,0 +1,568 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+use percent_encoding::percent_decode_str;
+
+use crate::*;
+
+/// Global registry used by [`Operator::from_uri`] and [`Operator::via_iter`].
+///
+/// It contains all services enabled at build time and services registered
+/// via [`OperatorRegistry::register_global`].
+static GLOBAL_OPERATOR_REGISTRY: Lazy<RwLock<OperatorRegistry>> =
+    Lazy::new(|| RwLock::new(OperatorRegistry::new()));
+
+/// Factory used to build an [`Operator`] from the parsed uri.
+pub type OperatorFactory = fn(&OperatorUri) -> Result<Operator>;
+
+/// OperatorRegistry maps uri schemes to the factories that build operators for them.
+///
+/// [`OperatorRegistry::new`] registers all services enabled at build time, users can
+/// register their own services for custom schemes.
+///
+/// Out-of-tree services can be registered into the global registry via
+/// [`OperatorRegistry::register_global`], so that [`Operator::from_uri`] and
+/// [`Operator::via_map`] with [`Scheme::Custom`] can build them too.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::services::Memory;
+/// use opendal::OperatorRegistry;
+///
+/// fn test() -> Result<()> {
+///     let mut registry = OperatorRegistry::new();
+///     // Register an alias, custom `Builder` can be registered in the same way.
+///     registry.register::<Memory>("mem");
+///
+///     let op = registry.parse("mem:///path/to/dir", [])?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct OperatorRegistry {
+    factories: HashMap<String, OperatorFactory>,
+}
+
+impl Default for OperatorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OperatorRegistry {
+    /// Create a new registry with all enabled services registered.
+    pub fn new() -> Self {
+        let mut registry = Self {
+            factories: HashMap::new(),
+        };
+        registry.register_enabled_services();
+        registry
+    }
+
+    /// Register the builder `B` for the given scheme.
+    ///
+    /// The config of `B` will be built via [`Configurator::from_uri`]. Existing
+    /// registration of the same scheme will be replaced.
+    pub fn register<B: Builder>(&mut self, scheme: &str) {
+        self.register_factory(scheme, build_operator::<B>);
+    }
+
+    /// Register the factory for the given scheme.
+    ///
+    /// Existing registration of the same scheme will be replaced.
+    pub fn register_factory(&mut self, scheme: &str, factory: OperatorFactory) {
+        self.factories.insert(scheme.to_lowercase(), factory);
+    }
+
+    /// Parse the uri and build an operator with the registered factory.
+    ///
+    /// `options` will be merged into the options from uri query, and take
+    /// precedence over them.
+    pub fn parse(
+        &self,
+        uri: &str,
+        options: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Operator> {
+        let uri = OperatorUri::new(uri, options)?;
+
+        let factory = self.factories.get(uri.scheme()).ok_or_else(|| {
+            Error::new(ErrorKind::Unsupported, "scheme is not registered")
+                .with_context("scheme", uri.scheme())
+        })?;
+        factory(&uri)
+    }
+
+    /// Register the builder `B` for the given scheme into the global registry.
+    ///
+    /// The scheme must be in lower case and should not overwrite any existing
+    /// services, see [`Scheme::Custom`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::services::Memory;
+    /// use opendal::Operator;
+    /// use opendal::OperatorRegistry;
+    /// use opendal::Scheme;
+    ///
+    /// fn test() -> Result<()> {
+    ///     // Users should register their own builder instead.
+    ///     OperatorRegistry::register_global::<Memory>("my-memory");
+    ///
+    ///     let op = Operator::from_uri("my-memory:///path/to/dir", [])?;
+    ///     let op = Operator::via_iter(Scheme::Custom("my-memory"), [])?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn register_global<B: Builder>(scheme: &str) {
+        Self::register_global_factory(scheme, build_operator::<B>);
+    }
+
+    /// Register the factory for the given scheme into the global registry.
+    pub fn register_global_factory(scheme: &str, factory: OperatorFactory) {
+        GLOBAL_OPERATOR_REGISTRY
+            .write()
+            .expect("operator registry lock must not be poisoned")
+            .register_factory(scheme, factory);
+    }
+
+    /// Get the factory of given scheme from the global registry.
+    pub(crate) fn global_factory(scheme: &str) -> Option<OperatorFactory> {
+        GLOBAL_OPERATOR_REGISTRY
+            .read()
+            .expect("operator registry lock must not be poisoned")
+            .factories
+            .get(&scheme.to_lowercase())
+            .copied()
+    }
+
+    fn register_enabled_services(&mut self) {
+        #[cfg(feature = "services-aliyun-drive")]
+        self.register::<services::AliyunDrive>(Scheme::AliyunDrive.into_static());
+        #[cfg(feature = "services-artifactory")]
+        self.register::<services::Artifactory>(Scheme::Artifactory.into_static());
+        #[cfg(feature = "services-atomicserver")]
+        self.register::<services::Atomicserver>(Scheme::Atomicserver.into_static());
+        #[cfg(feature = "services-alluxio")]
+        self.register::<services::Alluxio>(Scheme::Alluxio.into_static());
+        #[cfg(feature = "services-compfs")]
+        self.register::<services::Compfs>(Scheme::Compfs.into_static());
+        #[cfg(feature = "services-upstash")]
+        self.register::<services::Upstash>(Scheme::Upstash.into_static());
+        #[cfg(feature = "services-upyun")]
+        self.register::<services::Upyun>(Scheme::Upyun.into_static());
+        #[cfg(feature = "services-koofr")]
+        self.register::<services::Koofr>(Scheme::Koofr.into_static());
+        #[cfg(feature = "services-yandex-disk")]
+        self.register::<services::YandexDisk>(Scheme::YandexDisk.into_static());
+        #[cfg(feature = "services-pcloud")]
+        self.register::<services::Pcloud>(Scheme::Pcloud.into_static());
+        #[cfg(feature = "services-chainsafe")]
+        self.register::<services::Chainsafe>(Scheme::Chainsafe.into_static());
+        #[cfg(feature = "services-azblob")]
+        self.register::<services::Azblob>(Scheme::Azblob.into_static());
+        #[cfg(feature = "services-azdls")]
+        self.register::<services::Azdls>(Scheme::Azdls.into_static());
+        #[cfg(feature = "services-azfile")]
+        self.register::<services::Azfile>(Scheme::Azfile.into_static());
+        #[cfg(feature = "services-b2")]
+        self.register::<services::B2>(Scheme::B2.into_static());
+        #[cfg(feature = "services-cacache")]
+        self.register::<services::Cacache>(Scheme::Cacache.into_static());
+        #[cfg(feature = "services-cos")]
+        self.register::<services::Cos>(Scheme::Cos.into_static());
+        #[cfg(feature = "services-d1")]
+        self.register::<services::D1>(Scheme::D1.into_static());
+        #[cfg(feature = "services-dashmap")]
+        self.register::<services::Dashmap>(Scheme::Dashmap.into_static());
+        #[cfg(feature = "services-dropbox")]
+        self.register::<services::Dropbox>(Scheme::Dropbox.into_static());
+        #[cfg(feature = "services-dynamodb")]
+        self.register::<services::Dynamodb>(Scheme::Dynamodb.into_static());
+        #[cfg(feature = "services-etcd")]
+        self.register::<services::Etcd>(Scheme::Etcd.into_static());
+        #[cfg(feature = "services-foundationdb")]
+        self.register::<services::Foundationdb>(Scheme::Foundationdb.into_static());
+        #[cfg(feature = "services-fs")]
+        self.register::<services::Fs>(Scheme::Fs.into_static());
+        #[cfg(feature = "services-ftp")]
+        self.register::<services::Ftp>(Scheme::Ftp.into_static());
+        #[cfg(feature = "services-gcs")]
+        self.register::<services::Gcs>(Scheme::Gcs.into_static());
+        #[cfg(feature = "services-ghac")]
+        self.register::<services::Ghac>(Scheme::Ghac.into_static());
+        #[cfg(feature = "services-gridfs")]
+        self.register::<services::Gridfs>(Scheme::Gridfs.into_static());
+        #[cfg(feature = "services-github")]
+        self.register::<services::Github>(Scheme::Github.into_static());
+        #[cfg(feature = "services-hdfs")]
+        self.register::<services::Hdfs>(Scheme::Hdfs.into_static());
+        #[cfg(feature = "services-http")]
+        self.register::<services::Http>(Scheme::Http.into_static());
+        #[cfg(feature = "services-huggingface")]
+        self.register::<services::Huggingface>(Scheme::Huggingface.into_static());
+        #[cfg(feature = "services-ipfs")]
+        self.register::<services::Ipfs>(Scheme::Ipfs.into_static());
+        #[cfg(feature = "services-ipmfs")]
+        self.register::<services::Ipmfs>(Scheme::Ipmfs.into_static());
+        #[cfg(feature = "services-icloud")]
+        self.register::<services::Icloud>(Scheme::Icloud.into_static());
+        #[cfg(feature = "services-libsql")]
+        self.register::<services::Libsql>(Scheme::Libsql.into_static());
+        #[cfg(feature = "services-memcached")]
+        self.register::<services::Memcached>(Scheme::Memcached.into_static());
+        #[cfg(feature = "services-memory")]
+        self.register::<services::Memory>(Scheme::Memory.into_static());
+        #[cfg(feature = "services-mini-moka")]
+        self.register::<services::MiniMoka>(Scheme::MiniMoka.into_static());
+        #[cfg(feature = "services-mock")]
+        self.register::<services::Mock>(Scheme::Mock.into_static());
+        #[cfg(feature = "services-moka")]
+        self.register::<services::Moka>(Scheme::Moka.into_static());
+        #[cfg(feature = "services-monoiofs")]
+        self.register::<services::Monoiofs>(Scheme::Monoiofs.into_static());
+        #[cfg(feature = "services-mysql")]
+        self.register::<services::Mysql>(Scheme::Mysql.into_static());
+        #[cfg(feature = "services-obs")]
+        self.register::<services::Obs>(Scheme::Obs.into_static());
+        #[cfg(feature = "services-onedrive")]
+        self.register::<services::Onedrive>(Scheme::Onedrive.into_static());
+        #[cfg(feature = "services-postgresql")]
+        self.register::<services::Postgresql>(Scheme::Postgresql.into_static());
+        #[cfg(feature = "services-gdrive")]
+        self.register::<services::Gdrive>(Scheme::Gdrive.into_static());
+        #[cfg(feature = "services-oss")]
+        self.register::<services::Oss>(Scheme::Oss.into_static());
+        #[cfg(feature = "services-persy")]
+        self.register::<services::Persy>(Scheme::Persy.into_static());
+        #[cfg(feature = "services-redis")]
+        self.register::<services::Redis>(Scheme::Redis.into_static());
+        #[cfg(feature = "services-rocksdb")]
+        self.register::<services::Rocksdb>(Scheme::Rocksdb.into_static());
+        #[cfg(feature = "services-s3")]
+        self.register::<services::S3>(Scheme::S3.into_static());
+        #[cfg(feature = "services-seafile")]
+        self.register::<services::Seafile>(Scheme::Seafile.into_static());
+        #[cfg(feature = "services-sftp")]
+        self.register::<services::Sftp>(Scheme::Sftp.into_static());
+        #[cfg(feature = "services-sled")]
+        self.register::<services::Sled>(Scheme::Sled.into_static());
+        #[cfg(feature = "services-sqlite")]
+        self.register::<services::Sqlite>(Scheme::Sqlite.into_static());
+        #[cfg(feature = "services-supabase")]
+        self.register::<services::Supabase>(Scheme::Supabase.into_static());
+        #[cfg(feature = "services-swift")]
+        self.register::<services::Swift>(Scheme::Swift.into_static());
+        #[cfg(feature = "services-tikv")]
+        self.register::<services::Tikv>(Scheme::Tikv.into_static());
+        #[cfg(feature = "services-vercel-artifacts")]
+        self.register::<services::VercelArtifacts>(Scheme::VercelArtifacts.into_static());
+        #[cfg(feature = "services-vercel-blob")]
+        self.register::<services::VercelBlob>(Scheme::VercelBlob.into_static());
+        #[cfg(feature = "services-webdav")]
+        self.register::<services::Webdav>(Scheme::Webdav.into_static());
+        #[cfg(feature = "services-webhdfs")]
+        self.register::<services::Webhdfs>(Scheme::Webhdfs.into_static());
+        #[cfg(feature = "services-rados")]
+        self.register::<services::Rados>(Scheme::Rados.into_static());
+        #[cfg(feature = "services-redb")]
+        self.register::<services::Redb>(Scheme::Redb.into_static());
+        #[cfg(feature = "services-mongodb")]
+        self.register::<services::Mongodb>(Scheme::Mongodb.into_static());
+        #[cfg(feature = "services-hdfs-native")]
+        self.register::<services::HdfsNative>(Scheme::HdfsNative.into_static());
+    }
+}
+
+fn build_operator<B: Builder>(uri: &OperatorUri) -> Result<Operator> {
+    let cfg = B::Config::from_uri(uri)?;
+    Ok(Operator::from_config(cfg)?.finish())
+}
+
+/// OperatorUri is the parsed form of uri like `s3://bucket/path/to/dir?region=us-east-1`.
+///
+/// - `scheme` is the part before `://`, in lower case.
+/// - `name` is the authority after `://`, usually the bucket or container name.
+/// - `root` is the percent decoded path after name.
+/// - `options` are the percent decoded query pairs, merged with options passed by users.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperatorUri {
+    scheme: String,
+    name: Option<String>,
+    root: Option<String>,
+    options: HashMap<String, String>,
+}
+
+impl OperatorUri {
+    /// Parse the uri and merge the given options into it.
+    pub fn new(uri: &str, options: impl IntoIterator<Item = (String, String)>) -> Result<Self> {
+        let new_error =
+            |msg: &'static str| Error::new(ErrorKind::ConfigInvalid, msg).with_context("uri", uri);
+
+        let (scheme, rest) = uri
+            .split_once("://")
+            .ok_or_else(|| new_error("uri must be in the format of scheme://[name][/path]"))?;
+        if scheme.is_empty() {
+            return Err(new_error("uri scheme must not be empty"));
+        }
+
+        // Fragment is meaningless to services, just drop it.
+        let rest = rest.split_once('#').map_or(rest, |(v, _)| v);
+        let (rest, query) = match rest.split_once('?') {
+            Some((rest, query)) => (rest, Some(query)),
+            None => (rest, None),
+        };
+        let (name, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx..]),
+            None => (rest, ""),
+        };
+
+        let mut opts = HashMap::new();
+        for pair in query.unwrap_or_default().split('&') {
+            if pair.is_empty() {
+                continue;
+            }
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            opts.insert(
+                percent_decode(key).map_err(|_| new_error("uri query is not valid utf-8"))?,
+                percent_decode(value).map_err(|_| new_error("uri query is not valid utf-8"))?,
+            );
+        }
+        opts.extend(options);
+
+        let root = if path.is_empty() || path == "/" {
+            None
+        } else {
+            Some(percent_decode(path).map_err(|_| new_error("uri path is not valid utf-8"))?)
+        };
+
+        Ok(OperatorUri {
+            scheme: scheme.to_lowercase(),
+            name: (!name.is_empty()).then(|| name.to_string()),
+            root,
+            options: opts,
+        })
+    }
+
+    /// Build an uri from the given scheme and options directly, without name and root.
+    pub(crate) fn from_options(
+        scheme: &str,
+        options: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        OperatorUri {
+            scheme: scheme.to_lowercase(),
+            name: None,
+            root: None,
+            options: options.into_iter().collect(),
+        }
+    }
+
+    /// Get the scheme of this uri.
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// Get the name of this uri, returns `None` if not set.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the root of this uri, returns `None` if not set.
+    pub fn root(&self) -> Option<&str> {
+        self.root.as_deref()
+    }
+
+    /// Get the options of this uri.
+    pub fn options(&self) -> &HashMap<String, String> {
+        &self.options
+    }
+
+    /// Collect options of this uri into config map, with `root` filled from the
+    /// uri path and `name` filled into the given key.
+    ///
+    /// Returns error if the uri has a name, but `name_key` is `None`.
+    pub fn to_config_map(&self, name_key: Option<&str>) -> Result<HashMap<String, String>> {
+        let mut map = self.options.clone();
+        if let Some(root) = &self.root {
+            map.insert("root".to_string(), root.clone());
+        }
+        match (&self.name, name_key) {
+            (Some(name), Some(key)) => {
+                map.insert(key.to_string(), name.clone());
+            }
+            (Some(_), None) => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "uri name is not supported by this service",
+                )
+                .with_context("scheme", &self.scheme)
+                .with_context("name", self.name().unwrap_or_default()))
+            }
+            (None, _) => {}
+        }
+        Ok(map)
+    }
+}
+
+fn percent_decode(v: &str) -> std::result::Result<String, std::str::Utf8Error> {
+    // `+` is used as space in query string.
+    let v = v.replace('+', " ");
+    percent_decode_str(&v).decode_utf8().map(|v| v.into_owned())
+}
+
+impl Operator {
+    /// Create a new operator from the given uri like `s3://bucket/path?region=us-east-1`.
+    ///
+    /// Services enabled at build time and services registered via
+    /// [`OperatorRegistry::register_global`] are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// fn test() -> Result<()> {
+    ///     let op = Operator::from_uri("memory:///path/to/dir", [])?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_uri(
+        uri: &str,
+        options: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Operator> {
+        let uri = OperatorUri::new(uri, options)?;
+
+        let factory = OperatorRegistry::global_factory(uri.scheme()).ok_or_else(|| {
+            Error::new(ErrorKind::Unsupported, "scheme is not registered")
+                .with_context("scheme", uri.scheme())
+        })?;
+        factory(&uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_uri() {
+        let cases = vec![
+            (
+                "s3://bucket/path/to/dir?region=us-east-1",
+                (
+                    "s3",
+                    Some("bucket"),
+                    Some("/path/to/dir"),
+                    vec![("region", "us-east-1")],
+                ),
+            ),
+            (
+                "fs:///tmp/opendal",
+                ("fs", None, Some("/tmp/opendal"), vec![]),
+            ),
+            ("Memory://", ("memory", None, None, vec![])),
+            ("gcs://bucket/", ("gcs", Some("bucket"), None, vec![])),
+            (
+                "azblob://container/a%20b?endpoint=http%3A%2F%2F127.0.0.1&x=&y",
+                (
+                    "azblob",
+                    Some("container"),
+                    Some("/a b"),
+                    vec![("endpoint", "http://127.0.0.1"), ("x", ""), ("y", "")],
+                ),
+            ),
+        ];
+
+        for (input, (scheme, name, root, options)) in cases {
+            let uri = OperatorUri::new(input, []).expect("uri must be valid");
+            assert_eq!(uri.scheme(), scheme, "{input}");
+            assert_eq!(uri.name(), name, "{input}");
+            assert_eq!(uri.root(), root, "{input}");
+            let expected: HashMap<String, String> = options
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            assert_eq!(uri.options(), &expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_uri_invalid() {
+        for input in ["", "/tmp/opendal", "://bucket"] {
+            let err = OperatorUri::new(input, []).expect_err("uri must be invalid");
+            assert_eq!(err.kind(), ErrorKind::ConfigInvalid, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_uri_options_take_precedence() {
+        let uri = OperatorUri::new(
+            "s3://bucket?region=us-east-1",
+            [("region".to_string(), "us-west-2".to_string())],
+        )
+        .unwrap();
+        assert_eq!(uri.options()["region"], "us-west-2");
+
+        let map = uri.to_config_map(Some("bucket")).unwrap();
+        assert_eq!(map["bucket"], "bucket");
+        assert!(!map.contains_key("root"));
+
+        let err = uri
+            .to_config_map(None)
+            .expect_err("name must not be supported");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[cfg(feature = "services-memory")]
+    #[test]
+    fn test_registry_parse() {
+        let op = Operator::from_uri("memory:///path/to/dir", []).unwrap();
+        assert_eq!(op.info().scheme(), Scheme::Memory);
+        assert_eq!(op.info().root(), "/path/to/dir/");
+
+        let err = Operator::from_uri("unknown:///", []).expect_err("scheme must be unknown");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        let mut registry = OperatorRegistry::new();
+        registry.register::<services::Memory>("mem");
+        let op = registry.parse("mem://", []).unwrap();
+        assert_eq!(op.info().scheme(), Scheme::Memory);
+    }
+
+    #[cfg(feature = "services-memory")]
+    #[test]
+    fn test_registry_register_global() {
+        let scheme = Scheme::Custom("test-global-memory");
+        let err = Operator::via_iter(scheme, []).expect_err("scheme must not be registered");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        OperatorRegistry::register_global::<services::Memory>(scheme.into_static());
+
+        let op = Operator::via_iter(scheme, [("root".to_string(), "/a".to_string())]).unwrap();
+        assert_eq!(op.info().root(), "/a/");
+        let op = Operator::from_uri("test-global-memory:///b", []).unwrap();
+        assert_eq!(op.info().root(), "/b/");
+    }
+}
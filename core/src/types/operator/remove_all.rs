@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use futures::future;
+use futures::StreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// RemoveAllReport is the result returned by [`Operator::remove_all_with`].
+///
+/// Paths that failed to delete won't stop the whole operation, they will be
+/// collected in [`RemoveAllReport::failed`] instead.
+#[derive(Debug, Default)]
+pub struct RemoveAllReport {
+    deleted: usize,
+    failed: Vec<(String, Error)>,
+}
+
+impl RemoveAllReport {
+    /// The number of paths that have been deleted.
+    pub fn deleted(&self) -> usize {
+        self.deleted
+    }
+
+    /// The paths that failed to delete along with their errors.
+    pub fn failed(&self) -> &[(String, Error)] {
+        &self.failed
+    }
+
+    /// Consume the report and return the failed paths.
+    pub fn into_failed(self) -> Vec<(String, Error)> {
+        self.failed
+    }
+
+    /// Return true if all paths have been deleted.
+    pub fn is_ok(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    fn record(&mut self, path: String, res: Result<()>, args: &OpRemoveAll) {
+        match res {
+            Ok(()) => self.deleted += 1,
+            Err(err) => self.failed.push((path, err)),
+        }
+        if let Some(progress) = args.progress() {
+            progress(self);
+        }
+    }
+}
+
+/// Remove the path and all nested dirs and files recursively.
+///
+/// Files are deleted by [`Deleter`] while listing, dirs are deferred until
+/// all files have been deleted since services like fs can't delete non-empty
+/// dirs. Recursive list returns nested dirs before parents, so we can remove
+/// them in the listed order.
+pub(crate) async fn remove_all(
+    acc: Accessor,
+    path: String,
+    args: OpRemoveAll,
+) -> Result<RemoveAllReport> {
+    let op = Operator::from_inner(acc.clone()).with_default_context(args.context().clone());
+    let mut report = RemoveAllReport::default();
+    let mut removed = false;
+
+    match op.stat(&path).await {
+        // If the object is a file, we can delete it.
+        //
+        // There may still be objects prefixed with the path in some backend, so we can't return here.
+        Ok(metadata) if metadata.mode() != EntryMode::DIR => {
+            let res = op.delete(&path).await;
+            report.record(path.clone(), res, &args);
+            removed = true;
+        }
+        Ok(_) => {}
+        // If dir not found, it may be a prefix in object store like S3,
+        // and we still need to delete objects under the prefix.
+        Err(e) if e.kind() == ErrorKind::NotFound => {}
+        // Pass on any other error.
+        Err(e) => return Err(e),
+    };
+
+    let lister = op.lister_with(&path).recursive(true).await?;
+
+    // Lister will stop after returning an error, keep it for later.
+    let list_err = Arc::new(Mutex::new(None));
+    let dirs = Arc::new(Mutex::new(Vec::new()));
+    let input = {
+        let list_err = list_err.clone();
+        let dirs = dirs.clone();
+        lister.filter_map(move |res| {
+            let path = match res {
+                Ok(entry) if entry.metadata().is_dir() => {
+                    dirs.lock().unwrap().push(entry.path().to_string());
+                    None
+                }
+                Ok(entry) => Some(entry.path().to_string()),
+                Err(err) => {
+                    *list_err.lock().unwrap() = Some(err);
+                    None
+                }
+            };
+            future::ready(path)
+        })
+    };
+
+    let deleter = Deleter::new(acc, args.deleter());
+    let mut results = deleter.delete_stream(input);
+    while let Some((path, res)) = results.next().await {
+        report.record(path, res, &args);
+    }
+
+    if let Some(err) = list_err.lock().unwrap().take() {
+        return Err(err);
+    }
+
+    let mut dirs = std::mem::take(&mut *dirs.lock().unwrap());
+    // Remove the directory itself.
+    if !removed && !dirs.contains(&path) {
+        dirs.push(path);
+    }
+    for dir in dirs {
+        let res = op.delete(&dir).await;
+        report.record(dir, res, &args);
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,92 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// RetentionMode decides whether a retention can be shortened or removed
+/// before it expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RetentionMode {
+    /// Users with special permissions can still shorten or remove the retention.
+    ///
+    /// Maps to `GOVERNANCE` on S3 and `Unlocked` on Azure Blob.
+    Governance,
+    /// Nobody can shorten or remove the retention, including the root user.
+    ///
+    /// Maps to `COMPLIANCE` on S3 and `Locked` on Azure Blob.
+    Compliance,
+}
+
+/// Retention protects an object from being overwritten or deleted until
+/// the given time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Retention {
+    mode: RetentionMode,
+    retain_until: DateTime<Utc>,
+}
+
+impl Retention {
+    /// Create a new retention.
+    pub fn new(mode: RetentionMode, retain_until: DateTime<Utc>) -> Self {
+        Self { mode, retain_until }
+    }
+
+    /// The mode of this retention.
+    pub fn mode(&self) -> RetentionMode {
+        self.mode
+    }
+
+    /// The time until which the object is protected.
+    pub fn retain_until(&self) -> DateTime<Utc> {
+        self.retain_until
+    }
+}
+
+/// ObjectRetention is the retention state of an object, returned by
+/// [`Operator::retention`].
+///
+/// [`Operator::retention`]: crate::Operator::retention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ObjectRetention {
+    retention: Option<Retention>,
+    legal_hold: bool,
+}
+
+impl ObjectRetention {
+    /// Create a new object retention.
+    pub fn new(retention: Option<Retention>, legal_hold: bool) -> Self {
+        Self {
+            retention,
+            legal_hold,
+        }
+    }
+
+    /// The retention of this object, `None` if not set.
+    pub fn retention(&self) -> Option<Retention> {
+        self.retention
+    }
+
+    /// Whether this object is under legal hold.
+    ///
+    /// Objects under legal hold can't be deleted until the hold is removed,
+    /// no matter whether retention has been expired.
+    pub fn legal_hold(&self) -> bool {
+        self.legal_hold
+    }
+}
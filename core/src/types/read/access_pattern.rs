@@ -0,0 +1,37 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// AccessPattern is the hint of how the data will be read by [`Reader`](crate::Reader).
+///
+/// It works like `posix_fadvise`: OpenDAL will tune the internal reader pipeline
+/// based on the declared pattern.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessPattern {
+    /// No special hint, the reader will stream the data in the given range.
+    #[default]
+    Normal,
+    /// Data will be read sequentially from lower offsets to higher ones.
+    ///
+    /// The reader will fetch data in chunks and prefetch the next chunks in background.
+    Sequential,
+    /// Data will be read in random order.
+    ///
+    /// The reader will disable buffering and only send requests for the exact
+    /// ranges that are being read.
+    Random,
+}
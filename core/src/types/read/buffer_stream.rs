@@ -86,20 +86,15 @@ impl ChunkedReader {
     /// # Notes
     ///
     /// We don't need to handle `Executor::timeout` since we are outside of the layer.
-    fn new(ctx: Arc<ReadContext>, range: Range<u64>) -> Self {
-        let tasks = ConcurrentTasks::new(
-            ctx.args().executor().cloned().unwrap_or_default(),
-            ctx.options().concurrent(),
-            |mut r: oio::Reader| {
-                Box::pin(async {
-                    match r.read_all().await {
-                        Ok(buf) => (r, Ok(buf)),
-                        Err(err) => (r, Err(err)),
-                    }
-                })
-            },
-        );
-        let generator = ReadGenerator::new(ctx, range);
+    fn new(generator: ReadGenerator, executor: Executor, concurrent: usize) -> Self {
+        let tasks = ConcurrentTasks::new(executor, concurrent, |mut r: oio::Reader| {
+            Box::pin(async {
+                match r.read_all().await {
+                    Ok(buf) => (r, Ok(buf)),
+                    Err(err) => (r, Err(err)),
+                }
+            })
+        });
         Self {
             generator,
             tasks,
@@ -131,9 +126,15 @@ impl oio::Read for ChunkedReader {
 ///
 /// The underlying reader is either a StreamingReader or a ChunkedReader.
 ///
-/// - If chunk is None, BufferStream will use StreamingReader to iterate
-///   data in streaming way.
+/// - If chunk is None and prefetch is disabled, BufferStream will use StreamingReader
+///   to iterate data in streaming way.
 /// - Otherwise, BufferStream will use ChunkedReader to read data in chunks.
+///
+/// # Prefetch
+///
+/// With `prefetch` set to `N`, BufferStream will keep at most `N` chunks being fetched
+/// in the background besides the one being consumed. So the memory usage is bounded
+/// by `(N + 1) * chunk`.
 pub struct BufferStream {
     state: State,
 }
@@ -143,11 +144,33 @@ enum State {
     Reading(BoxedStaticFuture<(TwoWays<StreamingReader, ChunkedReader>, Result<Buffer>)>),
 }
 
+/// The default chunk size while prefetch is enabled without chunk.
+const DEFAULT_PREFETCH_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
 impl BufferStream {
     /// Create a new buffer stream.
     pub fn new(ctx: Arc<ReadContext>, range: Range<u64>) -> Self {
-        let reader = if ctx.options().chunk().is_some() {
-            TwoWays::Two(ChunkedReader::new(ctx, range))
+        let prefetch = ctx.options().prefetch();
+        Self::with_prefetch(ctx, range, prefetch)
+    }
+
+    /// Create a new buffer stream with given prefetch window, `0` means disable prefetch.
+    pub fn with_prefetch(ctx: Arc<ReadContext>, range: Range<u64>, prefetch: usize) -> Self {
+        let executor = ctx.args().executor().cloned().unwrap_or_default();
+        let concurrent = ctx.options().concurrent();
+        let chunk = ctx.options().chunk();
+
+        let reader = if prefetch > 0 {
+            let generator = ReadGenerator::new(ctx, range)
+                .with_chunk(chunk.unwrap_or(DEFAULT_PREFETCH_CHUNK_SIZE));
+            TwoWays::Two(ChunkedReader::new(
+                generator,
+                executor,
+                concurrent.max(prefetch + 1),
+            ))
+        } else if chunk.is_some() {
+            let generator = ReadGenerator::new(ctx, range);
+            TwoWays::Two(ChunkedReader::new(generator, executor, concurrent))
         } else {
             TwoWays::One(StreamingReader::new(ctx, range))
         };
@@ -242,4 +265,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_buffer_stream_with_prefetch() -> Result<()> {
+        let op = Operator::via_iter(Scheme::Memory, [])?;
+        op.write(
+            "test",
+            Buffer::from(vec![Bytes::from("Hello"), Bytes::from("World")]),
+        )
+        .await?;
+
+        let acc = op.into_inner();
+        let ctx = Arc::new(ReadContext::new(
+            acc,
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new().with_chunk(3),
+        ));
+
+        let s = BufferStream::with_prefetch(ctx, 1..10, 2);
+        let bufs: Vec<_> = s.try_collect().await.unwrap();
+        assert_eq!(bufs.len(), 3);
+
+        let buf: Buffer = bufs.into_iter().flatten().collect();
+        assert_eq!(&buf.to_vec(), "elloWorld".as_bytes());
+
+        Ok(())
+    }
 }
@@ -49,11 +49,25 @@ pub struct FuturesAsyncReader {
     start: u64,
     end: u64,
     pos: u64,
+    /// The end position of current stream, relative to `start`.
+    stream_end: u64,
+    /// The count of continuous reads without seeking.
+    sequential_reads: usize,
+    /// Whether current stream is prefetching.
+    prefetching: bool,
 }
 
 /// Safety: FuturesAsyncReader only exposes `&mut self` to the outside world,
 unsafe impl Sync for FuturesAsyncReader {}
 
+/// The default size of each request while access pattern is random and the
+/// size to read is unknown (e.g. `fill_buf`).
+const DEFAULT_RANDOM_READ_SIZE: usize = 64 * 1024;
+
+/// The count of continuous reads before we consider the reads are sequential
+/// and start prefetching.
+const SEQUENTIAL_READS_THRESHOLD: usize = 2;
+
 impl FuturesAsyncReader {
     /// NOTE: don't allow users to create FuturesAsyncReader directly.
     ///
@@ -63,15 +77,87 @@ impl FuturesAsyncReader {
     #[inline]
     pub(super) fn new(ctx: Arc<ReadContext>, range: Range<u64>) -> Self {
         let (start, end) = (range.start, range.end);
-        let stream = BufferStream::new(ctx.clone(), range);
-
-        FuturesAsyncReader {
+        let mut r = FuturesAsyncReader {
+            stream: BufferStream::new(ctx.clone(), start..start),
             ctx,
-            stream,
             buf: Buffer::new(),
             start,
             end,
             pos: 0,
+            stream_end: 0,
+            sequential_reads: 0,
+            prefetching: false,
+        };
+        r.prefetching = r.is_sequential();
+        r.reset_stream(None);
+        r
+    }
+
+    fn is_random(&self) -> bool {
+        self.ctx.options().access() == AccessPattern::Random
+    }
+
+    fn is_sequential(&self) -> bool {
+        self.ctx.options().access() == AccessPattern::Sequential
+    }
+
+    /// Reset the stream to start from current pos.
+    ///
+    /// While the access pattern is random, the stream only covers the next `size`
+    /// bytes. Otherwise, it covers all the remaining data.
+    fn reset_stream(&mut self, size: Option<usize>) {
+        let len = self.end - self.start;
+        self.stream_end = match size {
+            Some(size) if self.is_random() => (self.pos + size as u64).min(len),
+            _ if self.is_random() => self.pos,
+            _ => len,
+        };
+        let prefetch = if self.prefetching {
+            self.ctx.options().prefetch()
+        } else {
+            0
+        };
+        self.stream = BufferStream::with_prefetch(
+            self.ctx.clone(),
+            self.start + self.pos..self.start + self.stream_end,
+            prefetch,
+        );
+    }
+
+    /// Poll next buffer from the stream.
+    ///
+    /// `size` is the hint of the size that users want to read, random access
+    /// reader will send a new request of exactly this size.
+    fn poll_next_buf(&mut self, cx: &mut Context<'_>, size: usize) -> Poll<io::Result<Buffer>> {
+        // Start prefetching from current pos once the reads are sequential.
+        if !self.prefetching
+            && !self.is_random()
+            && self.ctx.options().prefetch() > 0
+            && self.sequential_reads >= SEQUENTIAL_READS_THRESHOLD
+        {
+            self.prefetching = true;
+            self.reset_stream(None);
+        }
+
+        loop {
+            match ready!(self.stream.poll_next_unpin(cx)) {
+                Some(Ok(buf)) => {
+                    self.sequential_reads += 1;
+                    return Poll::Ready(Ok(buf));
+                }
+                Some(Err(err)) => return Poll::Ready(Err(format_std_io_error(err))),
+                None => {
+                    if self.is_random()
+                        && self.pos == self.stream_end
+                        && self.pos < self.end - self.start
+                        && size > 0
+                    {
+                        self.reset_stream(Some(size));
+                        continue;
+                    }
+                    return Poll::Ready(Ok(Buffer::new()));
+                }
+            }
         }
     }
 }
@@ -84,11 +170,15 @@ impl AsyncBufRead for FuturesAsyncReader {
                 return Poll::Ready(Ok(this.buf.chunk()));
             }
 
-            this.buf = match ready!(this.stream.poll_next_unpin(cx)) {
-                Some(Ok(buf)) => buf,
-                Some(Err(err)) => return Poll::Ready(Err(format_std_io_error(err))),
-                None => return Poll::Ready(Ok(&[])),
-            };
+            let size = this
+                .ctx
+                .options()
+                .chunk()
+                .unwrap_or(DEFAULT_RANDOM_READ_SIZE);
+            this.buf = ready!(this.poll_next_buf(cx, size))?;
+            if this.buf.is_empty() {
+                return Poll::Ready(Ok(&[]));
+            }
         }
     }
 
@@ -104,7 +194,6 @@ impl AsyncBufRead for FuturesAsyncReader {
     }
 }
 
-/// TODO: implement vectored read.
 impl AsyncRead for FuturesAsyncReader {
     fn poll_read(
         self: Pin<&mut Self>,
@@ -121,11 +210,10 @@ impl AsyncRead for FuturesAsyncReader {
                 return Poll::Ready(Ok(size));
             }
 
-            this.buf = match ready!(this.stream.poll_next_unpin(cx)) {
-                Some(Ok(buf)) => buf,
-                Some(Err(err)) => return Poll::Ready(Err(format_std_io_error(err))),
-                None => return Poll::Ready(Ok(0)),
-            };
+            this.buf = ready!(this.poll_next_buf(cx, buf.len()))?;
+            if this.buf.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
         }
     }
 }
@@ -155,12 +243,15 @@ impl AsyncSeek for FuturesAsyncReader {
         if (self.pos..self.pos + self.buf.remaining() as u64).contains(&new_pos) {
             let cnt = new_pos - self.pos;
             self.buf.advance(cnt as _);
+            self.pos = new_pos;
         } else {
             self.buf = Buffer::new();
-            self.stream = BufferStream::new(self.ctx.clone(), new_pos + self.start..self.end);
+            self.pos = new_pos;
+            self.sequential_reads = 0;
+            self.prefetching = self.is_sequential();
+            self.reset_stream(None);
         }
 
-        self.pos = new_pos;
         Poll::Ready(Ok(self.pos))
     }
 }
@@ -282,4 +373,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_futures_async_read_with_random_access() -> Result<()> {
+        let op = Operator::via_iter(Scheme::Memory, [])?;
+        op.write(
+            "test",
+            Buffer::from(vec![Bytes::from("Hello"), Bytes::from("World")]),
+        )
+        .await?;
+
+        let acc = op.into_inner();
+        let ctx = Arc::new(ReadContext::new(
+            acc,
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new().with_access(AccessPattern::Random),
+        ));
+
+        let mut fr = FuturesAsyncReader::new(ctx, 2..9);
+        let mut bs = vec![0; 2];
+        fr.read_exact(&mut bs).await.unwrap();
+        assert_eq!(&bs, "ll".as_bytes());
+        // Only the exact range should be fetched.
+        assert_eq!(fr.stream_end, 2);
+
+        fr.seek(SeekFrom::Start(5)).await.unwrap();
+        let mut bs = vec![];
+        fr.read_to_end(&mut bs).await.unwrap();
+        assert_eq!(&bs, "rl".as_bytes());
+
+        let chunk = fr.fill_buf().await.unwrap();
+        assert!(chunk.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_futures_async_read_with_prefetch() -> Result<()> {
+        let op = Operator::via_iter(Scheme::Memory, [])?;
+        op.write(
+            "test",
+            Buffer::from(vec![Bytes::from("Hello"), Bytes::from("World")]),
+        )
+        .await?;
+
+        let acc = op.into_inner();
+        let ctx = Arc::new(ReadContext::new(
+            acc,
+            "test".to_string(),
+            OpRead::new(),
+            OpReader::new().with_chunk(2).with_prefetch(2),
+        ));
+
+        let mut fr = FuturesAsyncReader::new(ctx, 0..10);
+        let mut bs = vec![0; 2];
+        fr.read_exact(&mut bs).await.unwrap();
+        assert!(!fr.prefetching);
+        fr.read_exact(&mut bs).await.unwrap();
+        assert!(!fr.prefetching);
+
+        let mut bs = vec![];
+        fr.read_to_end(&mut bs).await.unwrap();
+        assert!(fr.prefetching);
+        assert_eq!(&bs, "oWorld".as_bytes());
+
+        // Seek will stop prefetching until reads are sequential again.
+        fr.seek(SeekFrom::Start(1)).await.unwrap();
+        assert!(!fr.prefetching);
+        let mut bs = vec![];
+        fr.read_to_end(&mut bs).await.unwrap();
+        assert_eq!(&bs, "elloWorld".as_bytes());
+
+        Ok(())
+    }
 }
@@ -15,10 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod access_pattern;
+pub use access_pattern::AccessPattern;
+
 #[allow(clippy::module_inception)]
 mod reader;
 pub use reader::Reader;
 
+mod partial_read;
+pub use partial_read::PartialRead;
+
 mod buffer_stream;
 pub(crate) use buffer_stream::BufferStream;
 
@@ -27,3 +33,8 @@ pub use futures_async_reader::FuturesAsyncReader;
 
 mod futures_bytes_stream;
 pub use futures_bytes_stream::FuturesBytesStream;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod to_file;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use to_file::read_to_file;
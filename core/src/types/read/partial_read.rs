@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+
+use crate::*;
+
+/// PartialRead is the result returned by [`Operator::read_partial_with`].
+///
+/// Besides the data, it carries the range that has been returned by the
+/// service so that callers issuing open-ended ranges like `1024..` know
+/// exactly what they received.
+#[derive(Debug, Clone)]
+pub struct PartialRead {
+    buffer: Buffer,
+    range: Range<u64>,
+    total_size: Option<u64>,
+}
+
+impl PartialRead {
+    pub(crate) fn new(buffer: Buffer, range: Range<u64>, total_size: Option<u64>) -> Self {
+        Self {
+            buffer,
+            range,
+            total_size,
+        }
+    }
+
+    /// The data that has been read.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Consume the result to get the data that has been read.
+    pub fn into_buffer(self) -> Buffer {
+        self.buffer
+    }
+
+    /// The effective range of the returned data inside the whole content.
+    ///
+    /// This range could be different from the requested one: it's clamped to
+    /// the content size and services that don't support range read will return
+    /// the whole content.
+    pub fn range(&self) -> Range<u64> {
+        self.range.clone()
+    }
+
+    /// The size of the whole content, `None` if the service doesn't return it.
+    pub fn total_size(&self) -> Option<u64> {
+        self.total_size
+    }
+
+    /// Return true if the returned data reaches the end of the content.
+    ///
+    /// Returns `None` if the total size is unknown.
+    pub fn is_end(&self) -> Option<bool> {
+        self.total_size.map(|size| self.range.end >= size)
+    }
+}
@@ -136,15 +136,21 @@ impl Reader {
                         op_stat = op_stat.with_version(v);
                     }
 
-                    let size = self
+                    let meta = self
                         .ctx
                         .accessor()
                         .stat(self.ctx.path(), op_stat)
                         .await?
-                        .into_metadata()
-                        .content_length();
-                    self.size.store(size);
-                    size
+                        .into_metadata();
+                    // The size could be unknown, for example, http responses
+                    // with content encoding. Read until EOF in this case.
+                    match meta.content_length_opt() {
+                        Some(size) => {
+                            self.size.store(size);
+                            size
+                        }
+                        None => u64::MAX,
+                    }
                 }
             },
         };
@@ -161,6 +167,43 @@ impl Reader {
         Ok(bufs.into_iter().flatten().collect())
     }
 
+    /// Read at most `len` bytes starting from `offset`, like `pread`.
+    ///
+    /// `read_at` doesn't maintain a cursor, so it's cheap to clone the reader and
+    /// issue positional reads from multiple tasks at the same time. Every call is
+    /// mapped to independent range reads of the underlying storage.
+    ///
+    /// The returned buffer will be shorter than `len` if the file ends before
+    /// `offset + len`, and empty if `offset` is beyond the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::future::try_join_all;
+    /// use opendal::Operator;
+    /// use opendal::Result;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let r = op.reader("path/to/file").await?;
+    ///     let tasks = (0..4).map(|i| {
+    ///         let r = r.clone();
+    ///         async move { r.read_at(i * 1024, 1024).await }
+    ///     });
+    ///     let bufs = try_join_all(tasks).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn read_at(&self, offset: u64, len: usize) -> Result<Buffer> {
+        // Bound the read by the size of file, which is cached after the first stat.
+        let range = self.parse_range(offset..).await?;
+        let end = range.end.min(offset.saturating_add(len as u64));
+        if offset >= end {
+            return Ok(Buffer::new());
+        }
+
+        self.read(offset..end).await
+    }
+
     /// Read all data from reader into given [`BufMut`].
     ///
     /// This operation will copy and write bytes into given [`BufMut`]. Allocation happens while
@@ -212,7 +255,12 @@ impl Reader {
 
     /// Merge given ranges into a list of non-overlapping ranges.
     fn merge_ranges(&self, mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
-        let gap = self.ctx.options().gap().unwrap_or(1024 * 1024) as u64;
+        // Don't merge ranges with gap for random access unless users set it explicitly.
+        let default_gap = match self.ctx.options().access() {
+            AccessPattern::Random => 0,
+            _ => 1024 * 1024,
+        };
+        let gap = self.ctx.options().gap().unwrap_or(default_gap) as u64;
         // We don't care about the order of range with same start, they
         // will be merged in the next step.
         ranges.sort_unstable_by(|a, b| a.start.cmp(&b.start));
@@ -500,6 +548,37 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_reader_read_at() -> Result<()> {
+        let op = Operator::via_iter(Scheme::Memory, [])?;
+        let path = "test_file";
+
+        let content = gen_fixed_bytes(4096);
+        op.write(path, content.clone())
+            .await
+            .expect("write must succeed");
+
+        let reader = op.reader(path).await.unwrap();
+        let tasks: Vec<_> = (0..8u64)
+            .map(|i| {
+                let reader = reader.clone();
+                tokio::spawn(async move { reader.read_at(i * 512, 512).await })
+            })
+            .collect();
+        for (i, task) in tasks.into_iter().enumerate() {
+            let buf = task.await.expect("task must succeed")?;
+            assert_eq!(buf.to_bytes(), content[i * 512..(i + 1) * 512]);
+        }
+
+        let buf = reader.read_at(4000, 1024).await?;
+        assert_eq!(buf.to_bytes(), content[4000..]);
+        let buf = reader.read_at(4096, 1024).await?;
+        assert!(buf.is_empty());
+        let buf = reader.read_at(0, 0).await?;
+        assert!(buf.is_empty());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_merge_ranges() -> Result<()> {
         let op = Operator::new(services::Memory::default()).unwrap().finish();
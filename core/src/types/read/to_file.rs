@@ -0,0 +1,223 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Write;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use futures::TryStreamExt;
+use md5::Digest;
+use md5::Md5;
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncSeekExt;
+use tokio::io::AsyncWriteExt;
+
+use super::BufferStream;
+use crate::raw::*;
+use crate::*;
+
+/// Download the file at `path` into a local file.
+///
+/// - The local file is preallocated to the size of the file and the content
+///   is written at the offsets of the ranges that being fetched.
+/// - The ranges are fetched concurrently by [`BufferStream`] according to `OpReader`.
+/// - The progress will be recorded in a sidecar file if `resume` is enabled, so
+///   that the following calls can continue from the last written offset.
+pub(crate) async fn read_to_file(
+    acc: Accessor,
+    path: String,
+    args: OpRead,
+    options: OpReader,
+    file_args: OpReadToFile,
+) -> Result<()> {
+    let local_path = file_args.local_path();
+    let with_context = |err: Error| {
+        err.with_operation("Operator::read_to_file")
+            .with_context("service", acc.info().scheme())
+            .with_context("path", &path)
+            .with_context("local_path", local_path.display())
+    };
+    let io_context = |err: std::io::Error| with_context(new_std_io_error(err));
+
+    let mut op_stat = OpStat::new();
+    if let Some(v) = args.version() {
+        op_stat = op_stat.with_version(v);
+    }
+    let meta = acc
+        .stat(&path, op_stat)
+        .await
+        .map_err(with_context)?
+        .into_metadata();
+    let total = meta.content_length();
+    let etag = meta.etag().unwrap_or_default().to_string();
+
+    let progress_path = progress_path(local_path);
+    let mut offset = if file_args.resume() {
+        load_progress(&progress_path, &etag, total).await
+    } else {
+        0
+    };
+
+    let mut f = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .read(true)
+        .write(true)
+        .open(local_path)
+        .await
+        .map_err(io_context)?;
+    // The local file must be preallocated by the previous download.
+    if offset > 0 && f.metadata().await.map_err(io_context)?.len() != total {
+        offset = 0;
+    }
+    f.set_len(total).await.map_err(io_context)?;
+    f.seek(SeekFrom::Start(offset)).await.map_err(io_context)?;
+
+    // Make sure the file is not changed while downloading in ranges.
+    let mut args = args;
+    if !etag.is_empty()
+        && args.if_match().is_none()
+        && acc.info().full_capability().read_with_if_match
+    {
+        args = args.with_if_match(&etag);
+    }
+
+    if offset < total {
+        let ctx = Arc::new(ReadContext::new(acc.clone(), path.clone(), args, options));
+        let mut s = BufferStream::new(ctx, offset..total);
+        while let Some(buf) = s.try_next().await.map_err(with_context)? {
+            offset += buf.len() as u64;
+            for bs in buf {
+                f.write_all(&bs).await.map_err(io_context)?;
+            }
+
+            if file_args.resume() {
+                f.flush().await.map_err(io_context)?;
+                save_progress(&progress_path, &etag, offset)
+                    .await
+                    .map_err(io_context)?;
+            }
+        }
+    }
+    f.sync_all().await.map_err(io_context)?;
+
+    if offset != total {
+        return Err(with_context(
+            Error::new(ErrorKind::Unexpected, "downloaded size is mismatch")
+                .with_context("expect", total)
+                .with_context("actual", offset),
+        ));
+    }
+
+    if file_args.checksum() {
+        if let Some(expect) = meta.content_md5() {
+            f.seek(SeekFrom::Start(0)).await.map_err(io_context)?;
+            let digest = md5_digest(&mut f).await.map_err(io_context)?;
+            if !md5_matches(&digest, expect) {
+                let _ = fs::remove_file(&progress_path).await;
+                return Err(with_context(
+                    Error::new(ErrorKind::Unexpected, "downloaded checksum is mismatch")
+                        .with_context("expect", expect)
+                        .with_context("actual", general_purpose::STANDARD.encode(digest)),
+                ));
+            }
+        }
+    }
+
+    if file_args.resume() {
+        let _ = fs::remove_file(&progress_path).await;
+    }
+    Ok(())
+}
+
+/// Build the path of progress file which is placed besides the local file.
+fn progress_path(local_path: &Path) -> PathBuf {
+    let mut name = local_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".opendal-progress");
+    local_path.with_file_name(name)
+}
+
+/// Load the offset from progress file.
+///
+/// Returns `0` if the progress is not found or recorded for another version of file.
+async fn load_progress(progress_path: &Path, etag: &str, total: u64) -> u64 {
+    let Ok(content) = fs::read_to_string(progress_path).await else {
+        return 0;
+    };
+    let Some((recorded_etag, offset)) = content.rsplit_once('\n') else {
+        return 0;
+    };
+    match offset.parse::<u64>() {
+        Ok(offset) if recorded_etag == etag && offset <= total => offset,
+        _ => 0,
+    }
+}
+
+async fn save_progress(progress_path: &Path, etag: &str, offset: u64) -> std::io::Result<()> {
+    fs::write(progress_path, format!("{etag}\n{offset}")).await
+}
+
+async fn md5_digest(f: &mut fs::File) -> std::io::Result<Vec<u8>> {
+    let mut hasher = Md5::new();
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = f.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher.finalize().to_vec())
+}
+
+/// Services could return md5 in both base64 (like `Content-MD5`) and hex format.
+fn md5_matches(digest: &[u8], expect: &str) -> bool {
+    let expect = expect.trim_matches('"');
+    let hex = digest.iter().fold(String::new(), |mut s, v| {
+        let _ = write!(s, "{v:02x}");
+        s
+    });
+    general_purpose::STANDARD.encode(digest) == expect || hex.eq_ignore_ascii_case(expect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_md5_matches() {
+        let digest = Md5::digest(b"Hello, World!").to_vec();
+
+        assert!(md5_matches(&digest, "ZajifYh5KDgxtmS9i38K1A=="));
+        assert!(md5_matches(&digest, "65a8e27d8879283831b664bd8b7f0ad4"));
+        assert!(md5_matches(&digest, "\"65A8E27D8879283831B664BD8B7F0AD4\""));
+        assert!(!md5_matches(&digest, "d41d8cd98f00b204e9800998ecf8427e"));
+    }
+
+    #[test]
+    fn test_progress_path() {
+        assert_eq!(
+            progress_path(Path::new("/tmp/abc.txt")),
+            PathBuf::from("/tmp/abc.txt.opendal-progress")
+        );
+    }
+}
@@ -34,6 +34,8 @@ use crate::Error;
 pub enum Scheme {
     /// [aliyun_drive][crate::services::AliyunDrive]: Aliyun Drive services.
     AliyunDrive,
+    /// [artifactory][crate::services::Artifactory]: JFrog Artifactory services.
+    Artifactory,
     /// [atomicserver][crate::services::Atomicserver]: Atomicserver services.
     Atomicserver,
     /// [azblob][crate::services::Azblob]: Azure Storage Blob services.
@@ -46,6 +48,8 @@ pub enum Scheme {
     Compfs,
     /// [Seafile][crate::services::Seafile]: Seafile Services.
     Seafile,
+    /// [Upstash][crate::services::Upstash]: Upstash Redis REST API Services.
+    Upstash,
     /// [Upyun][crate::services::Upyun]: Upyun Services.
     Upyun,
     /// [VercelBlob][crate::services::VercelBlob]: VercelBlob Services.
@@ -103,6 +107,8 @@ pub enum Scheme {
     Memory,
     /// [mini-moka][crate::services::MiniMoka]: Mini Moka backend support.
     MiniMoka,
+    /// [mock][crate::services::Mock]: In memory test double with programmable failures.
+    Mock,
     /// [moka][crate::services::Moka]: moka backend support.
     Moka,
     /// [monoiofs][crate::services::Monoiofs]: monoio fs services.
@@ -115,6 +121,8 @@ pub enum Scheme {
     Gdrive,
     /// [dropbox][crate::services::Dropbox]: Dropbox services.
     Dropbox,
+    /// [dynamodb][crate::services::Dynamodb]: DynamoDB services.
+    Dynamodb,
     /// [oss][crate::services::Oss]: Aliyun Object Storage Services
     Oss,
     /// [persy][crate::services::Persy]: persy backend support.
@@ -147,6 +155,8 @@ pub enum Scheme {
     Webdav,
     /// [webhdfs][crate::services::Webhdfs]: WebHDFS RESTful API Services
     Webhdfs,
+    /// [rados][crate::services::Rados]: Ceph RADOS Services
+    Rados,
     /// [redb][crate::services::Redb]: Redb Services
     Redb,
     /// [tikv][crate::services::Tikv]: Tikv Services
@@ -197,6 +207,8 @@ impl Scheme {
         HashSet::from([
             #[cfg(feature = "services-aliyun-drive")]
             Scheme::AliyunDrive,
+            #[cfg(feature = "services-artifactory")]
+            Scheme::Artifactory,
             #[cfg(feature = "services-atomicserver")]
             Scheme::Atomicserver,
             #[cfg(feature = "services-alluxio")]
@@ -219,6 +231,8 @@ impl Scheme {
             Scheme::Dashmap,
             #[cfg(feature = "services-dropbox")]
             Scheme::Dropbox,
+            #[cfg(feature = "services-dynamodb")]
+            Scheme::Dynamodb,
             #[cfg(feature = "services-etcd")]
             Scheme::Etcd,
             #[cfg(feature = "services-foundationdb")]
@@ -251,6 +265,8 @@ impl Scheme {
             Scheme::Memory,
             #[cfg(feature = "services-mini-moka")]
             Scheme::MiniMoka,
+            #[cfg(feature = "services-mock")]
+            Scheme::Mock,
             #[cfg(feature = "services-moka")]
             Scheme::Moka,
             #[cfg(feature = "services-monoiofs")]
@@ -277,6 +293,8 @@ impl Scheme {
             Scheme::S3,
             #[cfg(feature = "services-seafile")]
             Scheme::Seafile,
+            #[cfg(feature = "services-upstash")]
+            Scheme::Upstash,
             #[cfg(feature = "services-upyun")]
             Scheme::Upyun,
             #[cfg(feature = "services-yandex-disk")]
@@ -303,6 +321,8 @@ impl Scheme {
             Scheme::Webdav,
             #[cfg(feature = "services-webhdfs")]
             Scheme::Webhdfs,
+            #[cfg(feature = "services-rados")]
+            Scheme::Rados,
             #[cfg(feature = "services-redb")]
             Scheme::Redb,
             #[cfg(feature = "services-mongodb")]
@@ -334,6 +354,7 @@ impl FromStr for Scheme {
         let s = s.to_lowercase();
         match s.as_str() {
             "aliyun_drive" => Ok(Scheme::AliyunDrive),
+            "artifactory" => Ok(Scheme::Artifactory),
             "atomicserver" => Ok(Scheme::Atomicserver),
             "azblob" => Ok(Scheme::Azblob),
             "alluxio" => Ok(Scheme::Alluxio),
@@ -351,6 +372,7 @@ impl FromStr for Scheme {
             "d1" => Ok(Scheme::D1),
             "dashmap" => Ok(Scheme::Dashmap),
             "dropbox" => Ok(Scheme::Dropbox),
+            "dynamodb" => Ok(Scheme::Dynamodb),
             "etcd" => Ok(Scheme::Etcd),
             "dbfs" => Ok(Scheme::Dbfs),
             "fs" => Ok(Scheme::Fs),
@@ -373,17 +395,20 @@ impl FromStr for Scheme {
             "mysql" => Ok(Scheme::Mysql),
             "sqlite" => Ok(Scheme::Sqlite),
             "mini_moka" => Ok(Scheme::MiniMoka),
+            "mock" => Ok(Scheme::Mock),
             "moka" => Ok(Scheme::Moka),
             "monoiofs" => Ok(Scheme::Monoiofs),
             "obs" => Ok(Scheme::Obs),
             "onedrive" => Ok(Scheme::Onedrive),
             "persy" => Ok(Scheme::Persy),
             "postgresql" => Ok(Scheme::Postgresql),
+            "rados" => Ok(Scheme::Rados),
             "redb" => Ok(Scheme::Redb),
             "redis" => Ok(Scheme::Redis),
             "rocksdb" => Ok(Scheme::Rocksdb),
             "s3" => Ok(Scheme::S3),
             "seafile" => Ok(Scheme::Seafile),
+            "upstash" => Ok(Scheme::Upstash),
             "upyun" => Ok(Scheme::Upyun),
             "yandex_disk" => Ok(Scheme::YandexDisk),
             "pcloud" => Ok(Scheme::Pcloud),
@@ -410,6 +435,7 @@ impl From<Scheme> for &'static str {
     fn from(v: Scheme) -> Self {
         match v {
             Scheme::AliyunDrive => "aliyun_drive",
+            Scheme::Artifactory => "artifactory",
             Scheme::Atomicserver => "atomicserver",
             Scheme::Azblob => "azblob",
             Scheme::Azdls => "azdls",
@@ -440,6 +466,7 @@ impl From<Scheme> for &'static str {
             Scheme::Memcached => "memcached",
             Scheme::Memory => "memory",
             Scheme::MiniMoka => "mini_moka",
+            Scheme::Mock => "mock",
             Scheme::Moka => "moka",
             Scheme::Monoiofs => "monoiofs",
             Scheme::Obs => "obs",
@@ -450,6 +477,7 @@ impl From<Scheme> for &'static str {
             Scheme::Gdrive => "gdrive",
             Scheme::Github => "github",
             Scheme::Dropbox => "dropbox",
+            Scheme::Dynamodb => "dynamodb",
             Scheme::Redis => "redis",
             Scheme::Rocksdb => "rocksdb",
             Scheme::S3 => "s3",
@@ -463,12 +491,14 @@ impl From<Scheme> for &'static str {
             Scheme::Oss => "oss",
             Scheme::Webdav => "webdav",
             Scheme::Webhdfs => "webhdfs",
+            Scheme::Rados => "rados",
             Scheme::Redb => "redb",
             Scheme::Tikv => "tikv",
             Scheme::Azfile => "azfile",
             Scheme::Sqlite => "sqlite",
             Scheme::Mongodb => "mongodb",
             Scheme::Alluxio => "alluxio",
+            Scheme::Upstash => "upstash",
             Scheme::Upyun => "upyun",
             Scheme::YandexDisk => "yandex_disk",
             Scheme::Pcloud => "pcloud",
@@ -43,7 +43,7 @@ impl FuturesAsyncWriter {
     pub(crate) fn new(w: WriteGenerator<oio::Writer>) -> Self {
         FuturesAsyncWriter {
             sink: BufferSink::new(w),
-            buf: oio::FlexBuf::new(256 * 1024),
+            buf: oio::FlexBuf::with_pool(IO_WRITE_BUF_SIZE, IO_WRITE_BUF_POOL.clone()),
         }
     }
 }
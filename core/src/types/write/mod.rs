@@ -15,6 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+
+use crate::raw::oio;
+
 mod writer;
 pub use writer::Writer;
 
@@ -24,3 +30,11 @@ mod futures_async_writer;
 pub use futures_async_writer::FuturesAsyncWriter;
 mod futures_bytes_sink;
 pub use futures_bytes_sink::FuturesBytesSink;
+
+/// The buffer size of the io adapters like [`FuturesAsyncWriter`].
+pub(crate) const IO_WRITE_BUF_SIZE: usize = 256 * 1024;
+
+/// The buffer pool shared by the io adapters like [`FuturesAsyncWriter`], so that
+/// creating a new writer doesn't need to allocate a new buffer every time.
+pub(crate) static IO_WRITE_BUF_POOL: Lazy<Arc<oio::PooledBuf>> =
+    Lazy::new(|| Arc::new(oio::PooledBuf::new(16).with_initial_capacity(IO_WRITE_BUF_SIZE)));
@@ -30,6 +30,14 @@ use crate::*;
 /// Please make sure either `close` or `abort` has been called before
 /// dropping the writer otherwise the data could be lost.
 ///
+/// ## Cancellation
+///
+/// Dropping a writer that has written data but not been closed or aborted, for example
+/// cancelling the future that owns it, will abort the writer in background so that
+/// uploaded parts of multipart uploads are cleaned up. The abort runs on the executor
+/// of this write, or the current tokio runtime if no executor is set. Failures are
+/// only logged, please call [`Writer::abort`] explicitly to handle them.
+///
 /// ## Usage
 ///
 /// ### Write Multiple Chunks
@@ -161,6 +169,8 @@ impl Writer {
 
     /// Abort the writer and clean up all written data.
     ///
+    /// For multipart uploads, the uploaded parts will be removed from the service.
+    ///
     /// ## Notes
     ///
     /// Abort should only be called when the writer is not closed or